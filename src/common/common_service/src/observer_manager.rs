@@ -29,12 +29,21 @@ pub struct ObserverManager<T: NotificationClient, S: ObserverState> {
     rx: T::Channel,
     client: T,
     observer_states: S,
+    /// The version of the latest notification handled, acked to the meta node on
+    /// re-subscription so that it can serve an incremental catch-up instead of a
+    /// full snapshot. 0 until the first versioned notification is handled.
+    last_acked_version: u64,
 }
 
 pub trait ObserverState: Send + 'static {
     fn subscribe_type() -> SubscribeType;
-    /// modify data after receiving notification from meta
-    fn handle_notification(&mut self, resp: SubscribeResponse);
+    /// modify data after receiving notification from meta.
+    ///
+    /// Returning [`ObserverError::Desync`] means the notification itself is valid but
+    /// cannot be applied to the local state, e.g. a partial diff produced by a newer
+    /// meta node. The manager then re-subscribes for a full snapshot so that
+    /// `handle_initialization_notification` can rebuild the state from scratch.
+    fn handle_notification(&mut self, resp: SubscribeResponse) -> Result<(), ObserverError>;
 
     /// Initialize data from the meta. It will be called at start or resubscribe
     fn handle_initialization_notification(&mut self, resp: SubscribeResponse);
@@ -53,6 +62,12 @@ pub enum ObserverError {
     #[error("notification channel closed")]
     ChannelClosed,
 
+    /// The local state has diverged from the notification stream: a notification is
+    /// valid under the protocol but cannot be applied, e.g. a relation diff for a
+    /// relation the receiver does not hold or with fields it does not understand.
+    #[error("cannot apply notification to local state: {0}")]
+    Desync(String),
+
     #[error(transparent)]
     Rpc(
         #[from]
@@ -73,11 +88,12 @@ where
     S: ObserverState,
 {
     pub async fn new(client: T, observer_states: S) -> Self {
-        let rx = client.subscribe(S::subscribe_type()).await.unwrap();
+        let rx = client.subscribe(S::subscribe_type(), None).await.unwrap();
         Self {
             rx,
             client,
             observer_states,
+            last_acked_version: 0,
         }
     }
 
@@ -86,13 +102,14 @@ where
         let init_notification = loop {
             // notification before init notification must be received successfully.
             match self.rx.message().await? {
-                Some(notification) => {
-                    if !matches!(notification.info.as_ref().unwrap(), &Info::Snapshot(_)) {
-                        notification_vec.push(notification);
-                    } else {
-                        break notification;
-                    }
-                }
+                Some(notification) => match notification.info.as_ref().unwrap() {
+                    Info::Snapshot(_) => break notification,
+                    // The meta node accepted our last acked version: the missed
+                    // notifications follow as regular stream messages, so there is
+                    // no snapshot to wait for.
+                    Info::CatchUp(_) => return Ok(()),
+                    _ => notification_vec.push(notification),
+                },
                 None => return Err(ObserverError::ChannelClosed),
             }
         };
@@ -101,10 +118,17 @@ where
             unreachable!();
         };
 
+        let version = info.version.clone().unwrap_or_default();
+        self.last_acked_version = version
+            .catalog_version
+            .max(version.worker_node_version)
+            .max(version.streaming_worker_slot_mapping_version);
+
         notification_vec.retain_mut(|notification| match notification.info.as_ref().unwrap() {
             Info::Database(_)
             | Info::Schema(_)
             | Info::RelationGroup(_)
+            | Info::RelationDiffGroup(_)
             | Info::User(_)
             | Info::Connection(_)
             | Info::Secret(_)
@@ -120,9 +144,10 @@ where
             Info::HummockSnapshot(_) => true,
             Info::MetaBackupManifestId(_) => true,
             Info::SystemParams(_) | Info::SessionParam(_) => true,
-            Info::Snapshot(_) | Info::HummockWriteLimits(_) => unreachable!(),
+            Info::Snapshot(_) | Info::HummockWriteLimits(_) | Info::CatchUp(_) => unreachable!(),
             Info::HummockStats(_) => true,
             Info::Recovery(_) => true,
+            Info::LeaderHandover(_) => true,
             Info::StreamingWorkerSlotMapping(_) => {
                 notification.version
                     > info
@@ -138,17 +163,39 @@ where
             .handle_initialization_notification(init_notification);
 
         for notification in notification_vec {
-            self.observer_states.handle_notification(notification);
+            self.handle_notification(notification)?;
         }
 
         Ok(())
     }
 
+    fn handle_notification(
+        &mut self,
+        notification: SubscribeResponse,
+    ) -> Result<(), ObserverError> {
+        let version = notification.version;
+        self.observer_states.handle_notification(notification)?;
+        if version > self.last_acked_version {
+            self.last_acked_version = version;
+        }
+        Ok(())
+    }
+
+    /// Discards the acked version when the local state has diverged, so that the next
+    /// subscription gets a full snapshot instead of a catch-up that would replay the
+    /// same unapplicable notification.
+    fn reset_on_desync(&mut self, err: &ObserverError) {
+        if matches!(err, ObserverError::Desync(_)) {
+            self.last_acked_version = 0;
+        }
+    }
+
     /// `start` is used to spawn a new asynchronous task which receives meta's notification and
     /// call the `handle_initialization_notification` and `handle_notification` to update node data.
     pub async fn start(mut self) -> JoinHandle<()> {
         if let Err(err) = self.wait_init_notification().await {
             tracing::warn!(error = %err.as_report(), "Receives meta's notification err");
+            self.reset_on_desync(&err);
             self.re_subscribe().await;
         }
 
@@ -161,7 +208,14 @@ where
                             self.re_subscribe().await;
                             continue;
                         }
-                        self.observer_states.handle_notification(resp.unwrap());
+                        if let Err(err) = self.handle_notification(resp.unwrap()) {
+                            tracing::warn!(
+                                error = %err.as_report(),
+                                "Failed to apply meta's notification"
+                            );
+                            self.reset_on_desync(&err);
+                            self.re_subscribe().await;
+                        }
                     }
                     Err(err) => {
                         tracing::warn!(error = %err.as_report(), "Receives meta's notification err");
@@ -175,12 +229,19 @@ where
     /// `re_subscribe` is used to re-subscribe to the meta's notification.
     async fn re_subscribe(&mut self) {
         loop {
-            match self.client.subscribe(S::subscribe_type()).await {
+            let last_acked_version =
+                (self.last_acked_version > 0).then_some(self.last_acked_version);
+            match self
+                .client
+                .subscribe(S::subscribe_type(), last_acked_version)
+                .await
+            {
                 Ok(rx) => {
                     tracing::debug!("re-subscribe success");
                     self.rx = rx;
                     if let Err(err) = self.wait_init_notification().await {
                         tracing::warn!(error = %err.as_report(), "Receives meta's notification err");
+                        self.reset_on_desync(&err);
                         continue;
                     } else {
                         break;
@@ -214,9 +275,13 @@ impl<T: Send + 'static> Channel for Streaming<T> {
 #[async_trait::async_trait]
 pub trait NotificationClient: Send + Sync + 'static {
     type Channel: Channel<Item = SubscribeResponse>;
+    /// Subscribes to the meta node's notifications. `last_acked_version` asks for an
+    /// incremental catch-up from that version instead of a full snapshot, which the
+    /// meta node may or may not be able to serve.
     async fn subscribe(
         &self,
         subscribe_type: SubscribeType,
+        last_acked_version: Option<u64>,
     ) -> Result<Self::Channel, ObserverError>;
 }
 
@@ -237,9 +302,10 @@ impl NotificationClient for RpcNotificationClient {
     async fn subscribe(
         &self,
         subscribe_type: SubscribeType,
+        last_acked_version: Option<u64>,
     ) -> Result<Self::Channel, ObserverError> {
         self.meta_client
-            .subscribe(subscribe_type)
+            .subscribe(subscribe_type, last_acked_version)
             .await
             .map_err(Into::into)
     }
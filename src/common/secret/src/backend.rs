@@ -0,0 +1,116 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::RwLock;
+
+use super::error::{SecretError, SecretResult};
+
+/// A pluggable backend resolving references to secrets kept in an external store,
+/// e.g. HashiCorp Vault or AWS Secrets Manager.
+///
+/// Only the reference (e.g. `vault://path#key`) is persisted in the meta store; the
+/// plaintext is resolved lazily on the node that actually uses the secret. Resolved
+/// values are cached in the [`SecretBackendRegistry`] and re-resolved once their TTL
+/// expires, so secrets rotated in the external store are picked up without any
+/// catalog change.
+pub trait SecretBackend: Send + Sync + 'static {
+    /// The URI scheme served by this backend, e.g. `"vault"`.
+    fn scheme(&self) -> &str;
+
+    /// Resolves the reference to the plaintext secret value.
+    fn resolve(&self, reference: &str) -> SecretResult<Vec<u8>>;
+
+    /// How long a resolved value may be served from the cache before it must be
+    /// re-resolved.
+    fn cache_ttl(&self) -> Duration {
+        Duration::from_secs(300)
+    }
+}
+
+struct CachedValue {
+    value: Vec<u8>,
+    expires_at: Instant,
+}
+
+/// Process-wide registry of [`SecretBackend`]s, keyed by the URI scheme of the
+/// references they serve.
+pub struct SecretBackendRegistry {
+    backends: RwLock<HashMap<String, Arc<dyn SecretBackend>>>,
+    cache: RwLock<HashMap<String, CachedValue>>,
+}
+
+static REGISTRY: std::sync::OnceLock<SecretBackendRegistry> = std::sync::OnceLock::new();
+
+impl SecretBackendRegistry {
+    fn new() -> Self {
+        Self {
+            backends: RwLock::new(HashMap::new()),
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get the global secret backend registry instance.
+    pub fn global() -> &'static SecretBackendRegistry {
+        REGISTRY.get_or_init(Self::new)
+    }
+
+    /// Registers a backend for its scheme, replacing any previously registered one.
+    pub fn register(&self, backend: Arc<dyn SecretBackend>) {
+        self.backends
+            .write()
+            .insert(backend.scheme().to_string(), backend);
+    }
+
+    /// Resolves an external reference, serving from the cache while the cached value
+    /// is within the backend's TTL.
+    pub fn resolve(&self, reference: &str) -> SecretResult<Vec<u8>> {
+        {
+            let cache = self.cache.read();
+            if let Some(cached) = cache.get(reference) {
+                if cached.expires_at > Instant::now() {
+                    return Ok(cached.value.clone());
+                }
+            }
+        }
+
+        let (scheme, _) = reference
+            .split_once("://")
+            .ok_or_else(|| SecretError::malformed_reference(reference))?;
+        let backend = self
+            .backends
+            .read()
+            .get(scheme)
+            .cloned()
+            .ok_or_else(|| SecretError::backend_not_found(scheme))?;
+
+        let value = backend.resolve(reference)?;
+        self.cache.write().insert(
+            reference.to_string(),
+            CachedValue {
+                value: value.clone(),
+                expires_at: Instant::now() + backend.cache_ttl(),
+            },
+        );
+        Ok(value)
+    }
+
+    /// Drops a cached value so that the next use re-resolves it from the backend.
+    pub fn invalidate(&self, reference: &str) {
+        self.cache.write().remove(reference);
+    }
+}
@@ -34,6 +34,12 @@ pub enum SecretError {
     #[error("unspecified secret ref type: {0}")]
     UnspecifiedRefType(SecretId),
 
+    #[error("malformed external secret reference: {0}")]
+    MalformedReference(String),
+
+    #[error("no secret backend registered for scheme \"{0}\"")]
+    BackendNotFound(String),
+
     #[error("fail to encrypt/decrypt secret")]
     AesError,
 
@@ -14,6 +14,8 @@
 
 type SecretId = u32;
 
+mod backend;
+pub use backend::*;
 mod secret_manager;
 pub use secret_manager::*;
 mod encryption;
@@ -25,6 +25,7 @@ use risingwave_pb::secret::secret_ref::RefAsType;
 use risingwave_pb::secret::PbSecretRef;
 use thiserror_ext::AsReport;
 
+use super::backend::SecretBackendRegistry;
 use super::error::{SecretError, SecretResult};
 use super::SecretId;
 
@@ -77,6 +78,14 @@ impl LocalSecretManager {
         secret_guard.insert(secret_id, secret);
     }
 
+    /// Replaces the value of a rotated secret and removes the stale secret file, if any,
+    /// so that it gets re-materialized with the new value on the next use.
+    pub fn update_secret(&self, secret_id: SecretId, secret: Vec<u8>) {
+        let mut secret_guard = self.secrets.write();
+        secret_guard.insert(secret_id, secret);
+        self.remove_secret_file_if_exist(&secret_id);
+    }
+
     pub fn init_secrets(&self, secrets: Vec<PbSecret>) {
         let mut secret_guard = self.secrets.write();
         // Reset the secrets
@@ -181,6 +190,11 @@ impl LocalSecretManager {
             risingwave_pb::secret::secret::SecretBackend::HashicorpVault(_) => {
                 return Err(anyhow!("hashicorp_vault backend is not implemented yet").into())
             }
+            risingwave_pb::secret::secret::SecretBackend::External(backend) => {
+                // Resolved lazily against the backend registered for the reference's
+                // scheme, with caching and TTL-based refresh.
+                SecretBackendRegistry::global().resolve(&backend.reference)?
+            }
         };
         Ok(secret_value)
     }
@@ -205,6 +205,25 @@ pub struct MetaConfig {
     #[serde(default = "default::meta::vacuum_interval_sec")]
     pub vacuum_interval_sec: u64,
 
+    /// How long a soft-dropped relation is kept restorable before it is dropped for real.
+    /// Set to 0 to keep soft-dropped relations forever.
+    #[serde(default = "default::meta::soft_drop_retention_sec")]
+    pub soft_drop_retention_sec: u64,
+
+    /// Whether to block dangerous privilege grants: grants that target every
+    /// non-superuser of the cluster at once, and superuser-equivalent grants where
+    /// every granted action on a whole database or on all relations of a schema
+    /// carries the grant option. Users listed in `dangerous_grant_allowlist` may
+    /// still perform them.
+    #[serde(default = "default::meta::enable_dangerous_grant_check")]
+    pub enable_dangerous_grant_check: bool,
+
+    /// Names of users that may perform dangerous grants even when
+    /// `enable_dangerous_grant_check` is set. Each overridden warning is recorded
+    /// in the DDL audit log.
+    #[serde(default)]
+    pub dangerous_grant_allowlist: Vec<String>,
+
     /// The spin interval inside a vacuum job. It avoids the vacuum job monopolizing resources of
     /// meta node.
     #[serde(default = "default::meta::vacuum_spin_interval_ms")]
@@ -284,6 +303,33 @@ pub struct MetaConfig {
     #[serde(default = "default::meta::node_num_monitor_interval_sec")]
     pub node_num_monitor_interval_sec: u64,
 
+    /// Interval of the periodic GC for dirty streaming job metadata, e.g. fragments
+    /// without catalog entries left behind by interrupted DDL. 0 disables the GC and
+    /// leaves the cleanup to recovery only.
+    #[serde(default = "default::meta::dirty_job_gc_interval_sec")]
+    pub dirty_job_gc_interval_sec: u64,
+
+    /// Interval of the periodic health check for `Connection` catalog objects, e.g.
+    /// AWS private link endpoints. 0 disables the health checker.
+    #[serde(default = "default::meta::connection_health_check_interval_sec")]
+    pub connection_health_check_interval_sec: u64,
+
+    /// Interval of scheduled automatic meta backup. A tick is skipped if a backup job
+    /// is already running. 0 disables scheduled backup.
+    #[serde(default = "default::meta::auto_backup_interval_sec")]
+    pub auto_backup_interval_sec: u64,
+
+    /// Interval of the background compaction that physically removes meta-store keys
+    /// left behind by long-dropped catalog objects. Only used by the kv meta backend.
+    /// 0 disables the compaction.
+    #[serde(default = "default::meta::catalog_compaction_interval_sec")]
+    pub catalog_compaction_interval_sec: u64,
+
+    /// Soft limit on the encoded size of the in-memory catalog, in bytes. Exceeding it
+    /// only triggers warnings. 0 disables the check.
+    #[serde(default = "default::meta::catalog_memory_soft_limit_bytes")]
+    pub catalog_memory_soft_limit_bytes: u64,
+
     #[serde(default = "default::meta::backend")]
     pub backend: MetaBackend,
 
@@ -368,6 +414,16 @@ pub struct MetaConfig {
     #[serde(default = "default::meta::event_log_channel_max_size")]
     pub event_log_channel_max_size: u32,
 
+    /// Per-client rate limit on read-heavy catalog RPCs (e.g. `GetTables`), in requests per
+    /// second. Clients are identified by the `x-rw-client-identity` gRPC metadata. 0 disables
+    /// rate limiting.
+    #[serde(default = "default::meta::catalog_read_rate_limit_per_client")]
+    pub catalog_read_rate_limit_per_client: u64,
+
+    /// Per-client cap on in-flight read-heavy catalog RPCs. 0 disables the cap.
+    #[serde(default = "default::meta::catalog_read_concurrency_per_client")]
+    pub catalog_read_concurrency_per_client: u64,
+
     #[serde(default, with = "meta_prefix")]
     #[config_doc(omitted)]
     pub developer: MetaDeveloperConfig,
@@ -1351,6 +1407,14 @@ pub mod default {
             30
         }
 
+        pub fn soft_drop_retention_sec() -> u64 {
+            3600 * 24
+        }
+
+        pub fn enable_dangerous_grant_check() -> bool {
+            false
+        }
+
         pub fn vacuum_spin_interval_ms() -> u64 {
             200
         }
@@ -1387,6 +1451,26 @@ pub mod default {
             10
         }
 
+        pub fn dirty_job_gc_interval_sec() -> u64 {
+            600
+        }
+
+        pub fn connection_health_check_interval_sec() -> u64 {
+            60
+        }
+
+        pub fn auto_backup_interval_sec() -> u64 {
+            0
+        }
+
+        pub fn catalog_compaction_interval_sec() -> u64 {
+            3600
+        }
+
+        pub fn catalog_memory_soft_limit_bytes() -> u64 {
+            0
+        }
+
         pub fn backend() -> MetaBackend {
             MetaBackend::Mem
         }
@@ -1459,6 +1543,14 @@ pub mod default {
             10
         }
 
+        pub fn catalog_read_rate_limit_per_client() -> u64 {
+            0
+        }
+
+        pub fn catalog_read_concurrency_per_client() -> u64 {
+            0
+        }
+
         pub fn parallelism_control_batch_size() -> usize {
             10
         }
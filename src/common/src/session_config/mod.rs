@@ -139,6 +139,11 @@ pub struct SessionConfig {
     #[parameter(default = "UTC", check_hook = check_timezone)]
     timezone: String,
 
+    /// Collation of the current session. RisingWave does not perform locale-aware
+    /// comparison, so the value is informational only.
+    #[parameter(default = "C")]
+    lc_collate: String,
+
     /// If `STREAMING_PARALLELISM` is non-zero, CREATE MATERIALIZED VIEW/TABLE/INDEX will use it as
     /// streaming parallelism.
     #[serde_as(as = "DisplayFromStr")]
@@ -279,6 +284,16 @@ pub struct SessionConfig {
     #[parameter(default = false)]
     background_ddl: bool,
 
+    /// Convert in-flight foreground DDL statements to background jobs instead of cancelling
+    /// them when the session disconnects or the statement is cancelled.
+    #[parameter(default = false)]
+    background_ddl_on_disconnect: bool,
+
+    /// Expand `GRANT` on a table or materialized view to also cover its indexes and internal
+    /// state tables, applied atomically in a single grant.
+    #[parameter(default = false)]
+    expand_grant_to_owned_relations: bool,
+
     /// Enable shared source. Currently only for Kafka.
     ///
     /// When enabled, `CREATE SOURCE` will create a source streaming job, and `CREATE MATERIALIZED VIEWS` from the source
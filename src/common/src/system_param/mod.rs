@@ -92,6 +92,11 @@ macro_rules! for_all_params {
             { use_new_object_prefix_strategy,           bool,                           None,                           false,  "Whether to split object prefix.", },
             { license_key,                              risingwave_license::LicenseKey, Some(Default::default()),       true,   "The license key to activate enterprise features.", },
             { time_travel_retention_ms,                 u64,                            Some(0_u64),                    true,   "The data retention period for time travel, where 0 indicates that it's disabled.", },
+            { adaptive_checkpoint_frequency_min,        u64,                            Some(1_u64),                    true,   "Lower bound of the adaptive checkpoint frequency.", },
+            { adaptive_checkpoint_frequency_max,        u64,                            Some(0_u64),                    true,   "Upper bound of the adaptive checkpoint frequency. When non-zero, the barriers per checkpoint are adjusted within the bounds based on the observed barrier latency. 0 disables the adjustment.", },
+            { barrier_backpressure_max_stretch,         u64,                            Some(1_u64),                    true,   "Maximum factor by which the barrier interval may be stretched when compute nodes report barrier backpressure, so that barriers slow down instead of piling up in flight. 1 disables the stretching.", },
+            { compaction_group_auto_split_size_mb,      u64,                            Some(0_u64),                    true,   "Size threshold in MB above which the state of a single streaming job is split into a dedicated compaction group. 0 disables automatic splitting.", },
+            { compaction_group_auto_merge_size_mb,      u64,                            Some(0_u64),                    true,   "Size threshold in MB below which small compaction groups are merged back together. 0 disables automatic merging.", },
         }
     };
 }
@@ -406,6 +411,14 @@ impl ValidateOnSet for OverrideValidateOnSet {
         Self::expect_range(*v, 1..)
     }
 
+    fn adaptive_checkpoint_frequency_min(v: &u64) -> Result<()> {
+        Self::expect_range(*v, 1..)
+    }
+
+    fn barrier_backpressure_max_stretch(v: &u64) -> Result<()> {
+        Self::expect_range(*v, 1..)
+    }
+
     fn backup_storage_directory(v: &String) -> Result<()> {
         if v.trim().is_empty() {
             return Err("backup_storage_directory cannot be empty".into());
@@ -450,6 +463,8 @@ mod tests {
             (USE_NEW_OBJECT_PREFIX_STRATEGY_KEY, "false"),
             (LICENSE_KEY_KEY, "foo"),
             (TIME_TRAVEL_RETENTION_MS_KEY, "0"),
+            (ADAPTIVE_CHECKPOINT_FREQUENCY_MIN_KEY, "1"),
+            (ADAPTIVE_CHECKPOINT_FREQUENCY_MAX_KEY, "0"),
             ("a_deprecated_param", "foo"),
         ];
 
@@ -212,4 +212,34 @@ where
             .time_travel_retention_ms
             .unwrap_or_else(default::time_travel_retention_ms)
     }
+
+    fn adaptive_checkpoint_frequency_min(&self) -> u64 {
+        self.inner()
+            .adaptive_checkpoint_frequency_min
+            .unwrap_or_else(default::adaptive_checkpoint_frequency_min)
+    }
+
+    fn adaptive_checkpoint_frequency_max(&self) -> u64 {
+        self.inner()
+            .adaptive_checkpoint_frequency_max
+            .unwrap_or_else(default::adaptive_checkpoint_frequency_max)
+    }
+
+    fn barrier_backpressure_max_stretch(&self) -> u64 {
+        self.inner()
+            .barrier_backpressure_max_stretch
+            .unwrap_or_else(default::barrier_backpressure_max_stretch)
+    }
+
+    fn compaction_group_auto_split_size_mb(&self) -> u64 {
+        self.inner()
+            .compaction_group_auto_split_size_mb
+            .unwrap_or_else(default::compaction_group_auto_split_size_mb)
+    }
+
+    fn compaction_group_auto_merge_size_mb(&self) -> u64 {
+        self.inner()
+            .compaction_group_auto_merge_size_mb
+            .unwrap_or_else(default::compaction_group_auto_merge_size_mb)
+    }
 }
@@ -68,6 +68,17 @@ impl TracingContext {
         span
     }
 
+    /// Returns the trace id of the context, if it carries a valid remote or active span.
+    pub fn trace_id(&self) -> Option<String> {
+        use opentelemetry::trace::TraceContextExt;
+
+        let span = self.0.span();
+        let span_context = span.span_context();
+        span_context
+            .is_valid()
+            .then(|| span_context.trace_id().to_string())
+    }
+
     /// Convert the tracing context to the W3C trace context format.
     fn to_w3c(&self) -> HashMap<String, String> {
         let mut fields = HashMap::new();
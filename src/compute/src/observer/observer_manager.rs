@@ -14,7 +14,7 @@
 
 use risingwave_common::secret::LocalSecretManager;
 use risingwave_common::system_param::local_manager::LocalSystemParamsManagerRef;
-use risingwave_common_service::ObserverState;
+use risingwave_common_service::{ObserverError, ObserverState};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::SubscribeResponse;
 
@@ -27,7 +27,7 @@ impl ObserverState for ComputeObserverNode {
         risingwave_pb::meta::SubscribeType::Compute
     }
 
-    fn handle_notification(&mut self, resp: SubscribeResponse) {
+    fn handle_notification(&mut self, resp: SubscribeResponse) -> Result<(), ObserverError> {
         if let Some(info) = resp.info.as_ref() {
             match info.to_owned() {
                 Info::SystemParams(p) => self.system_params_manager.try_set_params(p),
@@ -38,6 +38,9 @@ impl ObserverState for ComputeObserverNode {
                     Operation::Delete => {
                         LocalSecretManager::global().remove_secret(s.id);
                     }
+                    Operation::Update => {
+                        LocalSecretManager::global().update_secret(s.id, s.value);
+                    }
                     _ => {
                         panic!("error type notification");
                     }
@@ -47,6 +50,7 @@ impl ObserverState for ComputeObserverNode {
                 }
             }
         };
+        Ok(())
     }
 
     fn handle_initialization_notification(&mut self, resp: SubscribeResponse) {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use await_tree::InstrumentAwait;
 use futures::{Stream, StreamExt, TryStreamExt};
 use risingwave_pb::stream_service::stream_service_server::StreamService;
@@ -61,6 +63,31 @@ impl StreamService for StreamServiceImpl {
         Ok(Response::new(WaitEpochCommitResponse { status: None }))
     }
 
+    #[cfg_attr(coverage, coverage(off))]
+    async fn sample_changelog(
+        &self,
+        request: Request<SampleChangelogRequest>,
+    ) -> Result<Response<SampleChangelogResponse>, Status> {
+        let req = request.into_inner();
+        let registry = self.env.changelog_sampler_registry();
+
+        let rx = registry.register(req.relation_id, req.max_records as usize, req.filter);
+        let records = match tokio::time::timeout(Duration::from_millis(req.timeout_ms), rx)
+            .instrument_await(format!("sample_changelog (relation {})", req.relation_id))
+            .await
+        {
+            Ok(Ok(records)) => {
+                // Drop the exhausted sampler from the registry.
+                registry.finish(req.relation_id);
+                records
+            }
+            // Timed out: return the records captured so far.
+            _ => registry.finish(req.relation_id),
+        };
+
+        Ok(Response::new(SampleChangelogResponse { records }))
+    }
+
     async fn streaming_control_stream(
         &self,
         request: Request<Streaming<StreamingControlStreamRequest>>,
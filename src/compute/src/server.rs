@@ -41,6 +41,7 @@ use risingwave_common::{GIT_SHA, RW_VERSION};
 use risingwave_common_heap_profiling::HeapProfiler;
 use risingwave_common_service::{MetricsManager, ObserverManager, TracingExtractLayer};
 use risingwave_connector::source::monitor::GLOBAL_SOURCE_METRICS;
+use risingwave_connector::source::GLOBAL_SOURCE_ERROR_BUFFER;
 use risingwave_dml::dml_manager::DmlManager;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::compute::config_service_server::ConfigServiceServer;
@@ -65,6 +66,7 @@ use risingwave_storage::opts::StorageOpts;
 use risingwave_storage::StateStoreImpl;
 use risingwave_stream::executor::monitor::global_streaming_metrics;
 use risingwave_stream::task::{LocalStreamManager, StreamEnvironment};
+use thiserror_ext::AsReport;
 use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
 use tower::Layer;
@@ -83,6 +85,9 @@ use crate::rpc::service::stream_service::StreamServiceImpl;
 use crate::telemetry::ComputeTelemetryCreator;
 use crate::ComputeNodeOpts;
 
+/// Interval of reporting source decode-error deltas to meta.
+const SOURCE_ERROR_REPORT_INTERVAL_SEC: u64 = 10;
+
 /// Bootstraps the compute-node.
 ///
 /// Returns when the `shutdown` token is triggered.
@@ -284,6 +289,33 @@ pub async fn compute_node_serve(
         extra_info_sources,
     ));
 
+    // Periodically report the decode-error deltas accumulated by source parsers, so
+    // that meta keeps the per-source error counters and the dead-letter event log
+    // up to date.
+    sub_tasks.push({
+        let meta_client = meta_client.clone();
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+        let join_handle = tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(SOURCE_ERROR_REPORT_INTERVAL_SEC));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = &mut shutdown_rx => return,
+                    _ = interval.tick() => {}
+                }
+                let stats = GLOBAL_SOURCE_ERROR_BUFFER.drain();
+                if stats.is_empty() {
+                    continue;
+                }
+                if let Err(e) = meta_client.report_source_error_stats(stats).await {
+                    tracing::warn!(error = %e.as_report(), "failed to report source error stats");
+                }
+            }
+        });
+        (join_handle, shutdown_tx)
+    });
+
     // Initialize the managers.
     let batch_mgr = Arc::new(BatchManager::new(
         config.batch.clone(),
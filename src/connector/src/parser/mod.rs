@@ -68,6 +68,7 @@ use crate::source::monitor::GLOBAL_SOURCE_METRICS;
 use crate::source::{
     extract_source_struct, BoxSourceStream, ChunkSourceStream, SourceColumnDesc, SourceColumnType,
     SourceContext, SourceContextRef, SourceEncode, SourceFormat, SourceMessage, SourceMeta,
+    GLOBAL_SOURCE_ERROR_BUFFER,
 };
 use crate::with_options::WithOptionsSecResolved;
 
@@ -778,6 +779,7 @@ async fn into_chunk_stream_inner<P: ByteStreamSourceParser>(
             }
 
             let old_len = builder.len();
+            let payload_len = msg.payload.as_ref().map_or(0, |payload| payload.len());
             match parser
                 .parse_one_with_txn(
                     msg.key,
@@ -822,6 +824,16 @@ async fn into_chunk_stream_inner<P: ByteStreamSourceParser>(
                             context.source_name.clone(),
                             context.fragment_id.to_string(),
                         ]);
+                        // Record the skipped record as a dead letter: the delta is
+                        // periodically reported to meta, which appends it to the
+                        // event log and the per-source error counters.
+                        GLOBAL_SOURCE_ERROR_BUFFER.record(
+                            context.source_id.table_id,
+                            payload_len,
+                            &msg.split_id,
+                            &msg.offset,
+                            &error.to_report_string(),
+                        );
                     }
                 }
 
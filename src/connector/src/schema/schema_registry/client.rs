@@ -105,6 +105,7 @@ impl Client {
         &'a self,
         method: Method,
         path: &'a [&'a (impl AsRef<str> + ?Sized + Debug + ToString)],
+        body: Option<Vec<u8>>,
     ) -> SrResult<T>
     where
         T: DeserializeOwned + Send + Sync + 'static,
@@ -116,6 +117,7 @@ impl Client {
             password: self.password.clone(),
             client: self.inner.clone(),
             path: path.iter().map(|p| p.to_string()).collect_vec(),
+            body,
         });
         for url in &self.url {
             fut_req.push(tokio::spawn(req_inner(
@@ -147,7 +149,7 @@ impl Client {
     /// get schema by id
     pub async fn get_schema_by_id(&self, id: i32) -> SrResult<ConfluentSchema> {
         let res: GetByIdResp = self
-            .concurrent_req(Method::GET, &["schemas", "ids", &id.to_string()])
+            .concurrent_req(Method::GET, &["schemas", "ids", &id.to_string()], None)
             .await?;
         Ok(ConfluentSchema {
             id,
@@ -163,7 +165,11 @@ impl Client {
     /// get the latest version of the subject
     pub async fn get_subject(&self, subject: &str) -> SrResult<Subject> {
         let res: GetBySubjectResp = self
-            .concurrent_req(Method::GET, &["subjects", subject, "versions", "latest"])
+            .concurrent_req(
+                Method::GET,
+                &["subjects", subject, "versions", "latest"],
+                None,
+            )
             .await?;
         tracing::debug!("update schema: {:?}", res);
         Ok(Subject {
@@ -187,7 +193,11 @@ impl Client {
         // use bfs to get all references
         while let Some((subject, version)) = queue.pop() {
             let res: GetBySubjectResp = self
-                .concurrent_req(Method::GET, &["subjects", &subject, "versions", &version])
+                .concurrent_req(
+                    Method::GET,
+                    &["subjects", &subject, "versions", &version],
+                    None,
+                )
                 .await?;
             let ref_subject = Subject {
                 schema: ConfluentSchema {
@@ -210,6 +220,27 @@ impl Client {
 
         Ok((origin_subject, subjects))
     }
+
+    /// register a new schema version under the subject, returning the assigned schema id
+    pub async fn publish_schema(
+        &self,
+        subject: &str,
+        schema: &str,
+        schema_type: &str,
+    ) -> SrResult<i32> {
+        let body = serde_json::json!({
+            "schema": schema,
+            "schemaType": schema_type,
+        });
+        let res: PostSubjectVersionResp = self
+            .concurrent_req(
+                Method::POST,
+                &["subjects", subject, "versions"],
+                Some(body.to_string().into_bytes()),
+            )
+            .await?;
+        Ok(res.id)
+    }
 }
 
 #[cfg(test)]
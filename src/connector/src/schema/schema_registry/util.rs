@@ -82,6 +82,8 @@ pub(crate) struct SchemaRegistryCtx {
     pub password: Option<String>,
     pub client: reqwest::Client,
     pub path: Vec<String>,
+    /// JSON body to send with the request, for schema registration.
+    pub body: Option<Vec<u8>>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -112,6 +114,11 @@ where
     if let Some(ref username) = ctx.username {
         request_builder = request_builder.basic_auth(username, ctx.password.as_ref());
     }
+    if let Some(ref body) = ctx.body {
+        request_builder = request_builder
+            .header("content-type", "application/vnd.schemaregistry.v1+json")
+            .body(body.clone());
+    }
     request(request_builder).await
 }
 
@@ -167,6 +174,11 @@ pub struct GetByIdResp {
     pub schema: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PostSubjectVersionResp {
+    pub id: i32,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GetBySubjectResp {
     pub id: i32,
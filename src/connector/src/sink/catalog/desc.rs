@@ -114,6 +114,7 @@ impl SinkDesc {
             initialized_at_cluster_version: None,
             create_type: self.create_type,
             original_target_columns: vec![],
+            description: None,
         }
     }
 
@@ -138,6 +139,9 @@ impl SinkDesc {
             target_table: self.target_table.map(|table_id| table_id.table_id()),
             extra_partition_col_idx: self.extra_partition_col_idx.map(|idx| idx as u64),
             secret_refs: self.secret_refs.clone(),
+            // The rate limit is only configurable afterwards via `ALTER SINK`, which
+            // updates the persisted stream node directly.
+            rate_limit: None,
         }
     }
 }
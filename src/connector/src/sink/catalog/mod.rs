@@ -26,7 +26,8 @@ use risingwave_common::catalog::{
 use risingwave_common::util::epoch::Epoch;
 use risingwave_common::util::sort_util::ColumnOrder;
 use risingwave_pb::catalog::{
-    PbCreateType, PbSink, PbSinkFormatDesc, PbSinkType, PbStreamJobStatus,
+    PbCreateType, PbSchemaRegistryPublishStatus, PbSink, PbSinkFormatDesc, PbSinkType,
+    PbStreamJobStatus,
 };
 use risingwave_pb::secret::PbSecretRef;
 use serde_derive::Serialize;
@@ -364,6 +365,9 @@ pub struct SinkCatalog {
 
     /// Only for the sink whose target is a table. Columns of the target table when the sink is created. At this point all the default columns of the target table are all handled by the project operator in the sink plan.
     pub original_target_columns: Vec<ColumnCatalog>,
+
+    /// Description set by the `comment on` clause.
+    pub description: Option<String>,
 }
 
 impl SinkCatalog {
@@ -401,6 +405,7 @@ impl SinkCatalog {
             db_name: self.db_name.clone(),
             sink_from_name: self.sink_from_name.clone(),
             stream_job_status: PbStreamJobStatus::Creating.into(),
+            schema_registry_publish_status: PbSchemaRegistryPublishStatus::Unspecified.into(),
             target_table: self.target_table.map(|table_id| table_id.table_id()),
             created_at_cluster_version: self.created_at_cluster_version.clone(),
             initialized_at_cluster_version: self.initialized_at_cluster_version.clone(),
@@ -411,6 +416,8 @@ impl SinkCatalog {
                 .iter()
                 .map(|c| c.to_protobuf())
                 .collect_vec(),
+            labels: Default::default(),
+            description: self.description.clone(),
         }
     }
 
@@ -515,6 +522,7 @@ impl From<PbSink> for SinkCatalog {
                 .into_iter()
                 .map(ColumnCatalog::from)
                 .collect_vec(),
+            description: pb.description,
         }
     }
 }
@@ -37,6 +37,7 @@ pub mod nats;
 pub mod pulsar;
 pub mod redis;
 pub mod remote;
+pub mod schema_publish;
 pub mod snowflake;
 pub mod sqlserver;
 pub mod starrocks;
@@ -0,0 +1,106 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Best-effort export of a sink's output schema to an external (Confluent-compatible)
+//! schema registry, enabled by the `schema.registry.publish.*` sink options.
+
+use anyhow::Context;
+use risingwave_common::types::DataType;
+use risingwave_pb::catalog::{PbSchemaRegistryPublishStatus, PbSink};
+use thiserror_ext::AsReport;
+
+use crate::schema::schema_registry::{handle_sr_list, Client, SchemaRegistryAuth};
+
+/// Registry endpoint(s) to publish the sink schema to, comma-separated.
+pub const SINK_SCHEMA_PUBLISH_URL: &str = "schema.registry.publish.url";
+/// Subject under which the sink schema is registered.
+pub const SINK_SCHEMA_PUBLISH_SUBJECT: &str = "schema.registry.publish.subject";
+
+/// Publishes the JSON schema of the sink's visible columns to the registry configured by the
+/// `schema.registry.publish.*` options, returning the status to be tracked in the sink
+/// catalog, or `None` if publishing is not enabled for the sink. Failures are logged and
+/// recorded instead of propagated, so that the DDL proceeds and the publish is retried the
+/// next time the sink schema changes, e.g. in replace-table flows.
+pub async fn publish_sink_schema(sink: &PbSink) -> Option<PbSchemaRegistryPublishStatus> {
+    let url = sink.properties.get(SINK_SCHEMA_PUBLISH_URL)?;
+    let subject = sink.properties.get(SINK_SCHEMA_PUBLISH_SUBJECT)?;
+
+    let status = match try_publish(sink, url, subject).await {
+        Ok(schema_id) => {
+            tracing::info!(
+                sink_id = sink.id,
+                subject,
+                schema_id,
+                "published sink schema to schema registry"
+            );
+            PbSchemaRegistryPublishStatus::Published
+        }
+        Err(e) => {
+            tracing::warn!(
+                error = %e.as_report(),
+                sink_id = sink.id,
+                subject,
+                "failed to publish sink schema to schema registry"
+            );
+            PbSchemaRegistryPublishStatus::Failed
+        }
+    };
+    Some(status)
+}
+
+async fn try_publish(sink: &PbSink, url: &str, subject: &str) -> anyhow::Result<i32> {
+    let urls = handle_sr_list(url)?;
+    let client = Client::new(urls, &SchemaRegistryAuth::from(&sink.properties))?;
+    let schema = build_json_schema(sink)?;
+    Ok(client
+        .publish_schema(subject, &schema.to_string(), "JSON")
+        .await?)
+}
+
+/// Builds a JSON schema document describing the sink's visible columns.
+fn build_json_schema(sink: &PbSink) -> anyhow::Result<serde_json::Value> {
+    let mut properties = serde_json::Map::new();
+    for column in &sink.columns {
+        if column.is_hidden {
+            continue;
+        }
+        let desc = column
+            .column_desc
+            .as_ref()
+            .context("column desc not found")?;
+        let data_type = DataType::from(desc.column_type.as_ref().context("data type not found")?);
+        properties.insert(
+            desc.name.clone(),
+            serde_json::json!({ "type": json_schema_type(&data_type) }),
+        );
+    }
+    Ok(serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": sink.name,
+        "type": "object",
+        "properties": properties,
+    }))
+}
+
+fn json_schema_type(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int16 | DataType::Int32 | DataType::Int64 | DataType::Serial => "integer",
+        DataType::Float32 | DataType::Float64 | DataType::Decimal => "number",
+        DataType::List(_) => "array",
+        DataType::Struct(_) | DataType::Jsonb => "object",
+        // Dates, times, intervals and everything else are rendered as strings.
+        _ => "string",
+    }
+}
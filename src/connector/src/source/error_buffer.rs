@@ -0,0 +1,97 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use risingwave_pb::meta::source_error_stats::DeadLetter;
+use risingwave_pb::meta::SourceErrorStats;
+
+/// The maximum number of dead letters kept per source between two reports. Further
+/// failures within the window are only counted.
+const MAX_DEAD_LETTERS_PER_REPORT: usize = 8;
+
+/// Process-global buffer of decode-error deltas, filled by source parsers and drained
+/// periodically by the compute node to report to meta.
+pub static GLOBAL_SOURCE_ERROR_BUFFER: LazyLock<SourceErrorBuffer> =
+    LazyLock::new(SourceErrorBuffer::default);
+
+#[derive(Default)]
+struct SourceErrorDelta {
+    record_count: u64,
+    byte_count: u64,
+    last_error: String,
+    last_error_timestamp_ms: u64,
+    dead_letters: Vec<DeadLetter>,
+}
+
+/// Accumulates per-source decode errors between two reports to meta. Each failed record
+/// is counted, and a bounded number of them are kept with their split and offset so
+/// they can be appended to the meta event log as dead letters.
+#[derive(Default)]
+pub struct SourceErrorBuffer {
+    inner: Mutex<HashMap<u32, SourceErrorDelta>>,
+}
+
+impl SourceErrorBuffer {
+    /// Records a record that failed to decode and was skipped by the parser.
+    pub fn record(
+        &self,
+        source_id: u32,
+        payload_bytes: usize,
+        split_id: &str,
+        offset: &str,
+        error: &str,
+    ) {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let mut inner = self.inner.lock();
+        let delta = inner.entry(source_id).or_default();
+        delta.record_count += 1;
+        delta.byte_count += payload_bytes as u64;
+        delta.last_error = error.to_string();
+        delta.last_error_timestamp_ms = now_ms;
+        if delta.dead_letters.len() < MAX_DEAD_LETTERS_PER_REPORT {
+            delta.dead_letters.push(DeadLetter {
+                split_id: split_id.to_string(),
+                offset: offset.to_string(),
+                error: error.to_string(),
+            });
+        }
+    }
+
+    /// Takes all the deltas accumulated since the previous drain, to be reported to
+    /// meta. Returns an empty vector if no error has been recorded.
+    pub fn drain(&self) -> Vec<SourceErrorStats> {
+        let mut stats: Vec<_> = self
+            .inner
+            .lock()
+            .drain()
+            .map(|(source_id, delta)| SourceErrorStats {
+                source_id,
+                dlq_record_count: delta.record_count,
+                dlq_byte_count: delta.byte_count,
+                last_error: delta.last_error,
+                last_error_timestamp_ms: delta.last_error_timestamp_ms,
+                dead_letters: delta.dead_letters,
+            })
+            .collect();
+        stats.sort_unstable_by_key(|s| s.source_id);
+        stats
+    }
+}
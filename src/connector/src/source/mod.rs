@@ -16,6 +16,7 @@ pub mod base;
 pub mod cdc;
 pub mod data_gen_util;
 pub mod datagen;
+pub mod error_buffer;
 pub mod filesystem;
 pub mod google_pubsub;
 pub mod kafka;
@@ -28,6 +29,7 @@ pub mod pulsar;
 
 pub use base::{UPSTREAM_SOURCE_KEY, *};
 pub(crate) use common::*;
+pub use error_buffer::GLOBAL_SOURCE_ERROR_BUFFER;
 use google_cloud_pubsub::subscription::Subscription;
 pub use google_pubsub::GOOGLE_PUBSUB_CONNECTOR;
 pub use kafka::KAFKA_CONNECTOR;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use risingwave_common::bail;
@@ -40,6 +41,15 @@ pub struct SourceDesc {
     pub metrics: Arc<SourceMetrics>,
 }
 
+impl SourceDesc {
+    /// Rebuilds the connector config of the inner [`SourceReader`] from the given plaintext
+    /// properties, keeping columns and parser config unchanged. Used when connector
+    /// `with_properties` are altered at runtime.
+    pub fn update_reader(&mut self, new_props: HashMap<String, String>) -> ConnectorResult<()> {
+        self.source.update_reader(new_props)
+    }
+}
+
 /// `FsSourceDesc` describes a stream source.
 #[deprecated = "will be replaced by new fs source (list + fetch)"]
 #[expect(deprecated)]
@@ -82,6 +92,12 @@ impl SourceDescBuilder {
         }
     }
 
+    /// Replaces the `StreamSourceInfo` that parsers are built from. Used when the
+    /// format/encode of the source is altered at runtime.
+    pub fn update_source_info(&mut self, source_info: PbStreamSourceInfo) {
+        self.source_info = source_info;
+    }
+
     /// This function builds `SourceColumnDesc` from `ColumnCatalog`, and handle the creation
     /// of hidden columns like partition/file, offset that are not specified by user.
     pub fn column_catalogs_to_source_column_descs(&self) -> Vec<SourceColumnDesc> {
@@ -66,6 +66,15 @@ impl SourceReader {
         })
     }
 
+    /// Rebuilds the connector config from the given plaintext properties. Properties that
+    /// reference secrets are not supported here; meta rejects altering them.
+    pub fn update_reader(&mut self, new_props: HashMap<String, String>) -> ConnectorResult<()> {
+        let options_with_secret =
+            WithOptionsSecResolved::without_secrets(new_props.into_iter().collect());
+        self.config = ConnectorProperties::extract(options_with_secret, false)?;
+        Ok(())
+    }
+
     fn get_target_columns(
         &self,
         column_ids: Vec<ColumnId>,
@@ -13,17 +13,23 @@
 // limitations under the License.
 
 mod backup_meta;
+mod checkpoint;
 mod cluster_info;
 mod connection;
+mod leader;
 mod migration;
 mod pause_resume;
 mod reschedule;
 mod serving;
+mod state_dedup;
 
 pub use backup_meta::*;
+pub use checkpoint::*;
 pub use cluster_info::*;
 pub use connection::*;
+pub use leader::*;
 pub use migration::*;
 pub use pause_resume::*;
 pub use reschedule::*;
 pub use serving::*;
+pub use state_dedup::*;
@@ -0,0 +1,44 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::CtlContext;
+
+pub async fn create_named_checkpoint(context: &CtlContext, name: String) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let checkpoint = meta_client.create_named_checkpoint(name).await?;
+    println!(
+        "Created checkpoint {} at epoch {} (hummock version {})",
+        checkpoint.name, checkpoint.epoch, checkpoint.hummock_version_id
+    );
+    Ok(())
+}
+
+pub async fn list_named_checkpoints(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let checkpoints = meta_client.list_named_checkpoints().await?;
+    for checkpoint in checkpoints {
+        println!(
+            "name: {}, epoch: {}, hummock version: {}, pinned: {}",
+            checkpoint.name, checkpoint.epoch, checkpoint.hummock_version_id, checkpoint.pinned
+        );
+    }
+    Ok(())
+}
+
+pub async fn pin_named_checkpoint(context: &CtlContext, name: String) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    meta_client.pin_named_checkpoint(name.clone()).await?;
+    println!("Pinned checkpoint {}", name);
+    Ok(())
+}
@@ -0,0 +1,39 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::CtlContext;
+
+pub async fn leader_lease(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let resp = meta_client.get_leader_lease().await?;
+
+    match resp.leader.and_then(|leader| leader.address) {
+        Some(addr) => println!("Leader: {}:{}", addr.host, addr.port),
+        None => println!("Leader: none"),
+    }
+    println!("Lease TTL: {}s", resp.lease_ttl_secs);
+
+    Ok(())
+}
+
+pub async fn trigger_leader_handover(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client.trigger_leader_handover().await?;
+
+    println!("Done. The leader has finished outstanding barriers and is resigning.");
+
+    Ok(())
+}
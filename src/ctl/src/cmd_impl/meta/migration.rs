@@ -654,6 +654,7 @@ pub async fn migrate(from: EtcdBackend, target: String, force_clean: bool) -> an
                 GrantObject::DatabaseId(id) => *db_rewrite.get(id).unwrap(),
                 GrantObject::SchemaId(id) => *schema_rewrite.get(id).unwrap(),
                 GrantObject::FunctionId(id) => *function_rewrite.get(id).unwrap(),
+                GrantObject::SecretId(id) => *secret_rewrite.get(id).unwrap(),
                 GrantObject::TableId(id)
                 | GrantObject::SourceId(id)
                 | GrantObject::SinkId(id)
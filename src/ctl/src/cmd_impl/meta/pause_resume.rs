@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risingwave_common::catalog::DEFAULT_SUPER_USER_ID;
 use risingwave_pb::meta::PausedReason;
 
 use crate::CtlContext;
@@ -53,3 +54,39 @@ pub async fn resume(context: &CtlContext) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+pub async fn pause_job(context: &CtlContext, table_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client.pause_streaming_job(table_id).await?;
+
+    println!("Done. Streaming job {} is paused.", table_id);
+
+    Ok(())
+}
+
+pub async fn resume_job(context: &CtlContext, table_id: u32) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client.resume_streaming_job(table_id).await?;
+
+    println!("Done. Streaming job {} is resumed.", table_id);
+
+    Ok(())
+}
+
+pub async fn force_drop_job(
+    context: &CtlContext,
+    job_id: u32,
+    confirm: String,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    meta_client
+        .force_drop_job(job_id, DEFAULT_SUPER_USER_ID, confirm)
+        .await?;
+
+    println!("Done. Streaming job {} is force-dropped.", job_id);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+
+use crate::CtlContext;
+
+pub async fn analyze_duplicate_state(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let response = meta_client.analyze_duplicate_state().await?;
+
+    if response.groups.is_empty() {
+        println!("No duplicate internal state found.");
+        return Ok(());
+    }
+
+    let total_reclaimable: u64 = response
+        .groups
+        .iter()
+        .map(|group| group.approx_reclaimable_bytes)
+        .sum();
+    println!(
+        "Found {} group(s) of fragments with identical internal state, \
+         ~{} bytes reclaimable in total by sharing state within each group.\n",
+        response.groups.len(),
+        total_reclaimable
+    );
+
+    for (i, group) in response.groups.iter().enumerate() {
+        println!(
+            "Group {}: {} fragments, ~{} bytes reclaimable",
+            i + 1,
+            group.fragments.len(),
+            group.approx_reclaimable_bytes
+        );
+        for fragment in &group.fragments {
+            println!(
+                "  job {} fragment {}: state tables [{}], {} bytes",
+                fragment.job_id,
+                fragment.fragment_id,
+                fragment.state_table_ids.iter().join(", "),
+                fragment.state_size_bytes
+            );
+        }
+    }
+
+    Ok(())
+}
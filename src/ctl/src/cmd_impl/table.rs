@@ -17,3 +17,9 @@ pub use scan::*;
 
 mod list;
 pub use list::*;
+
+mod storage_class;
+pub use storage_class::*;
+
+mod schema_change;
+pub use schema_change::*;
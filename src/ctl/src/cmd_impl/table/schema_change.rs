@@ -0,0 +1,33 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use risingwave_pb::catalog::table::PbSchemaChangePolicy;
+
+use crate::CtlContext;
+
+pub async fn set_schema_change_policy(
+    context: &CtlContext,
+    table_id: u32,
+    policy: PbSchemaChangePolicy,
+) -> Result<()> {
+    let meta = context.meta_client().await?;
+    meta.alter_schema_change_policy(table_id, policy).await?;
+    println!(
+        "set schema change policy of table {} to {}",
+        table_id,
+        policy.as_str_name()
+    );
+    Ok(())
+}
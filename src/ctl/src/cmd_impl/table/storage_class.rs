@@ -0,0 +1,47 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use risingwave_pb::catalog::PbStorageClassPolicy;
+
+use crate::CtlContext;
+
+pub async fn set_storage_class_policy(
+    context: &CtlContext,
+    table_id: u32,
+    warm_after_sec: u64,
+    cold_after_sec: u64,
+) -> Result<()> {
+    let meta = context.meta_client().await?;
+    meta.alter_storage_class_policy(
+        table_id,
+        Some(PbStorageClassPolicy {
+            warm_after_sec,
+            cold_after_sec,
+        }),
+    )
+    .await?;
+    println!(
+        "set storage class policy of table {}: warm after {}s, cold after {}s",
+        table_id, warm_after_sec, cold_after_sec
+    );
+    Ok(())
+}
+
+pub async fn clear_storage_class_policy(context: &CtlContext, table_id: u32) -> Result<()> {
+    let meta = context.meta_client().await?;
+    meta.alter_storage_class_policy(table_id, None).await?;
+    println!("cleared storage class policy of table {}", table_id);
+    Ok(())
+}
@@ -15,7 +15,7 @@
 use risingwave_pb::meta::PbThrottleTarget;
 
 use crate::common::CtlContext;
-use crate::ThrottleCommandArgs;
+use crate::{ThrottleBoostCommandArgs, ThrottleCommandArgs};
 
 pub async fn apply_throttle(
     context: &CtlContext,
@@ -28,3 +28,14 @@ pub async fn apply_throttle(
         .await?;
     Ok(())
 }
+
+pub async fn boost_rate_limit(
+    context: &CtlContext,
+    params: ThrottleBoostCommandArgs,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    meta_client
+        .boost_rate_limit(params.id, params.rate, params.duration_secs * 1000)
+        .await?;
+    Ok(())
+}
@@ -23,6 +23,7 @@ use itertools::Itertools;
 use risingwave_common::util::tokio_util::sync::CancellationToken;
 use risingwave_hummock_sdk::{HummockEpoch, HummockVersionId};
 use risingwave_meta::backup_restore::RestoreOpts;
+use risingwave_pb::catalog::table::PbSchemaChangePolicy;
 use risingwave_pb::hummock::rise_ctl_update_compaction_config_request::CompressionAlgorithm;
 use risingwave_pb::meta::update_worker_node_schedulability_request::Schedulability;
 use thiserror_ext::AsReport;
@@ -373,6 +374,57 @@ enum TableCommands {
     },
     /// list all state tables
     List,
+    /// set the archival tier policy of a table. The policy currently only drives the
+    /// per-tier bytes gauges of the meta node; data is not moved between storage classes yet
+    SetStorageClassPolicy {
+        /// id of the table to operate on
+        table_id: u32,
+        /// age in seconds after which data counts as warm, 0 disables the tier
+        #[clap(long, default_value_t = 0)]
+        warm_after_sec: u64,
+        /// age in seconds after which data counts as cold, 0 disables the tier
+        #[clap(long, default_value_t = 0)]
+        cold_after_sec: u64,
+    },
+    /// clear the archival tier policy of a table
+    ClearStorageClassPolicy {
+        /// id of the table to operate on
+        table_id: u32,
+    },
+    /// set the policy applied to auto schema changes of a cdc table
+    SetSchemaChangePolicy {
+        /// id of the table to operate on
+        table_id: u32,
+        /// the policy to apply
+        #[clap(long, value_enum)]
+        policy: SchemaChangePolicyArg,
+    },
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+enum SchemaChangePolicyArg {
+    /// apply both column additions and column drops (the default)
+    Allow,
+    /// apply column additions only, reject column drops
+    AllowAddColumn,
+    /// apply column drops only, reject column additions
+    AllowDropColumn,
+    /// reject all automatic schema changes
+    Block,
+    /// never apply changes automatically, only record an event log entry
+    AlertOnly,
+}
+
+impl From<SchemaChangePolicyArg> for PbSchemaChangePolicy {
+    fn from(policy: SchemaChangePolicyArg) -> Self {
+        match policy {
+            SchemaChangePolicyArg::Allow => Self::Unspecified,
+            SchemaChangePolicyArg::AllowAddColumn => Self::AllowAddColumn,
+            SchemaChangePolicyArg::AllowDropColumn => Self::AllowDropColumn,
+            SchemaChangePolicyArg::Block => Self::Block,
+            SchemaChangePolicyArg::AlertOnly => Self::AlertOnly,
+        }
+    }
 }
 
 #[derive(Subcommand, Debug)]
@@ -409,8 +461,36 @@ enum MetaCommands {
     Pause,
     /// resume the stream graph
     Resume,
+    /// pause a single streaming job by freezing its source and backfill actors
+    PauseJob {
+        /// the table id of the streaming job
+        table_id: u32,
+    },
+    /// resume a single streaming job paused by `pause-job`
+    ResumeJob {
+        /// the table id of the streaming job
+        table_id: u32,
+    },
+    /// forcibly drop a stuck streaming job, bypassing normal ref-count and
+    /// in-progress checks; requires `--confirm force-drop-<job_id>`
+    ForceDropJob {
+        /// the id of the streaming job
+        job_id: u32,
+        /// confirmation token, must be exactly `force-drop-<job_id>`
+        #[clap(long)]
+        confirm: String,
+    },
+    /// scan all streaming jobs for fragments with identical internal state and
+    /// report the storage that a shared copy could reclaim. Report-only: state
+    /// tables are never merged or rewritten
+    AnalyzeDuplicateState,
     /// get cluster info
     ClusterInfo,
+    /// show the current meta leader and its lease
+    LeaderLease,
+    /// gracefully hand the meta leadership over to another member: finishes outstanding
+    /// barriers and all queued barrier commands, then releases the lease
+    LeaderHandover,
     /// get source split info
     SourceSplitInfo {
         #[clap(long)]
@@ -467,6 +547,19 @@ enum MetaCommands {
         snapshot_ids: Vec<u64>,
     },
 
+    /// Create a named checkpoint (savepoint) by forcing a checkpoint barrier
+    CreateCheckpoint {
+        /// The name of the checkpoint
+        name: String,
+    },
+    /// List all named checkpoints
+    ListCheckpoints,
+    /// Pin a named checkpoint to keep its hummock version from being vacuumed
+    PinCheckpoint {
+        /// The name of the checkpoint
+        name: String,
+    },
+
     /// List all existing connections in the catalog
     ListConnections,
 
@@ -533,6 +626,9 @@ enum MetaCommands {
 enum ThrottleCommands {
     Source(ThrottleCommandArgs),
     Mv(ThrottleCommandArgs),
+    /// Temporarily boost the rate limit of a streaming job, automatically
+    /// restoring the persisted rate limits after the given duration.
+    Boost(ThrottleBoostCommandArgs),
 }
 
 #[derive(Clone, Debug, Args)]
@@ -541,6 +637,16 @@ pub struct ThrottleCommandArgs {
     rate: Option<u32>,
 }
 
+#[derive(Clone, Debug, Args)]
+pub struct ThrottleBoostCommandArgs {
+    id: u32,
+    /// The boosted rate limit. Omit to lift the rate limit entirely for the duration.
+    rate: Option<u32>,
+    /// How long the boost lasts, in seconds.
+    #[clap(long, default_value_t = 60)]
+    duration_secs: u64,
+}
+
 #[derive(Subcommand, Clone, Debug)]
 pub enum ProfileCommands {
     /// CPU profile
@@ -828,9 +934,44 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
                 .await?
         }
         Commands::Table(TableCommands::List) => cmd_impl::table::list(context).await?,
+        Commands::Table(TableCommands::SetStorageClassPolicy {
+            table_id,
+            warm_after_sec,
+            cold_after_sec,
+        }) => {
+            cmd_impl::table::set_storage_class_policy(
+                context,
+                table_id,
+                warm_after_sec,
+                cold_after_sec,
+            )
+            .await?
+        }
+        Commands::Table(TableCommands::ClearStorageClassPolicy { table_id }) => {
+            cmd_impl::table::clear_storage_class_policy(context, table_id).await?
+        }
+        Commands::Table(TableCommands::SetSchemaChangePolicy { table_id, policy }) => {
+            cmd_impl::table::set_schema_change_policy(context, table_id, policy.into()).await?
+        }
         Commands::Bench(cmd) => cmd_impl::bench::do_bench(context, cmd).await?,
         Commands::Meta(MetaCommands::Pause) => cmd_impl::meta::pause(context).await?,
         Commands::Meta(MetaCommands::Resume) => cmd_impl::meta::resume(context).await?,
+        Commands::Meta(MetaCommands::LeaderLease) => cmd_impl::meta::leader_lease(context).await?,
+        Commands::Meta(MetaCommands::LeaderHandover) => {
+            cmd_impl::meta::trigger_leader_handover(context).await?
+        }
+        Commands::Meta(MetaCommands::PauseJob { table_id }) => {
+            cmd_impl::meta::pause_job(context, table_id).await?
+        }
+        Commands::Meta(MetaCommands::ResumeJob { table_id }) => {
+            cmd_impl::meta::resume_job(context, table_id).await?
+        }
+        Commands::Meta(MetaCommands::ForceDropJob { job_id, confirm }) => {
+            cmd_impl::meta::force_drop_job(context, job_id, confirm).await?
+        }
+        Commands::Meta(MetaCommands::AnalyzeDuplicateState) => {
+            cmd_impl::meta::analyze_duplicate_state(context).await?
+        }
         Commands::Meta(MetaCommands::ClusterInfo) => cmd_impl::meta::cluster_info(context).await?,
         Commands::Meta(MetaCommands::SourceSplitInfo { ignore_id }) => {
             cmd_impl::meta::source_split_info(context, ignore_id).await?
@@ -854,6 +995,15 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Meta(MetaCommands::DeleteMetaSnapshots { snapshot_ids }) => {
             cmd_impl::meta::delete_meta_snapshots(context, &snapshot_ids).await?
         }
+        Commands::Meta(MetaCommands::CreateCheckpoint { name }) => {
+            cmd_impl::meta::create_named_checkpoint(context, name).await?
+        }
+        Commands::Meta(MetaCommands::ListCheckpoints) => {
+            cmd_impl::meta::list_named_checkpoints(context).await?
+        }
+        Commands::Meta(MetaCommands::PinCheckpoint { name }) => {
+            cmd_impl::meta::pin_named_checkpoint(context, name).await?
+        }
         Commands::Meta(MetaCommands::ListConnections) => {
             cmd_impl::meta::list_connections(context).await?
         }
@@ -929,6 +1079,9 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Throttle(ThrottleCommands::Mv(args)) => {
             apply_throttle(context, risingwave_pb::meta::PbThrottleTarget::Mv, args).await?;
         }
+        Commands::Throttle(ThrottleCommands::Boost(args)) => {
+            cmd_impl::throttle::boost_rate_limit(context, args).await?;
+        }
     }
     Ok(())
 }
@@ -264,6 +264,11 @@ impl Binder {
         Self::resolve_single_name(name.0, "source name")
     }
 
+    /// return the `function_name`
+    pub fn resolve_function_name(name: ObjectName) -> Result<String> {
+        Self::resolve_single_name(name.0, "function name")
+    }
+
     /// return the `user_name`
     pub fn resolve_user_name(name: ObjectName) -> Result<String> {
         Self::resolve_single_name(name.0, "user name")
@@ -254,6 +254,13 @@ impl Binder {
         &mut self,
         view_catalog: &ViewCatalog,
     ) -> Result<(Relation, Vec<(bool, Field)>)> {
+        if let Some(reason) = &view_catalog.invalidated_reason {
+            return Err(ErrorCode::BindError(format!(
+                "view {} was invalidated by an incompatible upstream ALTER TABLE: {}. Please recreate the view",
+                view_catalog.name, reason
+            ))
+            .into());
+        }
         let ast = Parser::parse_sql(&view_catalog.sql)
             .expect("a view's sql should be parsed successfully");
         let Statement::Query(query) = ast
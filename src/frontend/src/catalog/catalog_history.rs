@@ -0,0 +1,70 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use risingwave_common::catalog::TableId;
+use risingwave_common::util::epoch::Epoch;
+
+use crate::catalog::table_catalog::TableCatalog;
+
+/// Bounded number of superseded relation versions kept for as-of resolution.
+const CATALOG_HISTORY_CAPACITY: usize = 1024;
+
+/// A superseded version of a relation, retained after it is renamed, altered or dropped so
+/// that subscription cursors replaying historical epochs can resolve the catalog as of the
+/// epoch being read and return coherent column names for old changelog rows.
+#[derive(Clone)]
+pub struct HistoricalRelation {
+    pub table: Arc<TableCatalog>,
+    /// The epoch at which this version was superseded. The version is effective for reads
+    /// at epochs strictly below it.
+    pub superseded_at_epoch: u64,
+}
+
+/// Bounded history of superseded relation versions, oldest at the front. Entries beyond the
+/// capacity are discarded, so resolution of sufficiently old epochs falls back to the
+/// current catalog.
+#[derive(Default)]
+pub struct CatalogHistory {
+    entries: VecDeque<HistoricalRelation>,
+}
+
+impl CatalogHistory {
+    /// Record a relation version that is about to be replaced or dropped.
+    pub fn record(&mut self, table: Arc<TableCatalog>) {
+        if self.entries.len() == CATALOG_HISTORY_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(HistoricalRelation {
+            table,
+            superseded_at_epoch: Epoch::now().0,
+        });
+    }
+
+    /// Resolve the version of the relation effective at `epoch`. Since entries are in
+    /// chronological order, the oldest version superseded after `epoch` is the effective
+    /// one. Returns `None` if the relation has not been superseded since `epoch`, i.e. the
+    /// current catalog applies, or the history has already been truncated past it.
+    pub fn resolve_as_of(&self, table_id: &TableId, epoch: u64) -> Option<&HistoricalRelation> {
+        self.entries
+            .iter()
+            .find(|entry| entry.table.id == *table_id && entry.superseded_at_epoch > epoch)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
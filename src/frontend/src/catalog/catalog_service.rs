@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::anyhow;
@@ -21,14 +22,16 @@ use risingwave_common::catalog::{CatalogVersion, FunctionId, IndexId};
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_pb::catalog::{
     PbComment, PbCreateType, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource,
-    PbSubscription, PbTable, PbView,
+    PbStreamJobPriority, PbSubscription, PbTable, PbView,
 };
+use risingwave_pb::ddl_service::alter_connector_props_request::ObjectType as ConnectorPropsObjectType;
 use risingwave_pb::ddl_service::alter_owner_request::Object;
 use risingwave_pb::ddl_service::{
     alter_name_request, alter_set_schema_request, create_connection_request, PbReplaceTablePlan,
     PbTableJobType, ReplaceTablePlan, TableJobType,
 };
 use risingwave_pb::meta::PbTableParallelism;
+use risingwave_pb::plan_common::PbColumnCatalog;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_rpc_client::MetaClient;
 use tokio::sync::watch::Receiver;
@@ -86,6 +89,11 @@ pub trait CatalogWriter: Send + Sync {
         job_type: PbTableJobType,
     ) -> Result<()>;
 
+    /// Acquire an exclusive lease for altering the given table, to be presented to
+    /// [`Self::replace_table`]. This fails fast if another session is already altering the
+    /// table, instead of racing until the replace procedure rejects one of them.
+    async fn acquire_alter_lease(&self, table_id: u32, force: bool) -> Result<u64>;
+
     async fn replace_table(
         &self,
         source: Option<PbSource>,
@@ -93,10 +101,29 @@ pub trait CatalogWriter: Send + Sync {
         graph: StreamFragmentGraph,
         mapping: ColIndexMapping,
         job_type: TableJobType,
+        alter_lease_token: u64,
     ) -> Result<()>;
 
     async fn alter_source_column(&self, source: PbSource) -> Result<()>;
 
+    /// Returns the ids of the streaming jobs reading from the source whose schemas were
+    /// fixed when they were created, i.e. those that will not see the new column until
+    /// they are recreated.
+    async fn alter_source_add_column(
+        &self,
+        source_id: u32,
+        column: PbColumnCatalog,
+        definition: String,
+        base_version: u64,
+    ) -> Result<Vec<u32>>;
+
+    async fn alter_connector_props(
+        &self,
+        object_id: u32,
+        object_type: ConnectorPropsObjectType,
+        changed_props: HashMap<String, String>,
+    ) -> Result<()>;
+
     async fn create_index(
         &self,
         index: PbIndex,
@@ -121,7 +148,7 @@ pub trait CatalogWriter: Send + Sync {
 
     async fn create_subscription(&self, subscription: PbSubscription) -> Result<()>;
 
-    async fn create_function(&self, function: PbFunction) -> Result<()>;
+    async fn create_function(&self, function: PbFunction, or_replace: bool) -> Result<()>;
 
     async fn create_connection(
         &self,
@@ -193,12 +220,20 @@ pub trait CatalogWriter: Send + Sync {
 
     async fn alter_source_name(&self, source_id: u32, source_name: &str) -> Result<()>;
 
+    async fn alter_function_name(&self, function_id: u32, function_name: &str) -> Result<()>;
+
     async fn alter_schema_name(&self, schema_id: u32, schema_name: &str) -> Result<()>;
 
     async fn alter_database_name(&self, database_id: u32, database_name: &str) -> Result<()>;
 
     async fn alter_owner(&self, object: Object, owner_id: u32) -> Result<()>;
 
+    async fn alter_schema_default_owner(
+        &self,
+        schema_id: u32,
+        default_owner_id: Option<u32>,
+    ) -> Result<()>;
+
     async fn alter_source_with_sr(&self, source: PbSource) -> Result<()>;
 
     async fn alter_parallelism(
@@ -208,6 +243,14 @@ pub trait CatalogWriter: Send + Sync {
         deferred: bool,
     ) -> Result<()>;
 
+    async fn alter_stream_job_priority(
+        &self,
+        table_id: u32,
+        priority: PbStreamJobPriority,
+    ) -> Result<()>;
+
+    async fn alter_table_ttl(&self, table_id: u32, retention_seconds: Option<u32>) -> Result<()>;
+
     async fn alter_set_schema(
         &self,
         object: alter_set_schema_request::Object,
@@ -230,6 +273,9 @@ impl CatalogWriter for CatalogWriterImpl {
                 name: db_name.to_string(),
                 id: 0,
                 owner,
+                barrier_interval_ms: None,
+                default_timezone: None,
+                default_collation: None,
             })
             .await?;
         self.wait_version(version).await
@@ -248,6 +294,7 @@ impl CatalogWriter for CatalogWriterImpl {
                 name: schema_name.to_string(),
                 database_id: db_id,
                 owner,
+                default_owner_id: None,
             })
             .await?;
         self.wait_version(version).await
@@ -304,6 +351,39 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
+    async fn alter_source_add_column(
+        &self,
+        source_id: u32,
+        column: PbColumnCatalog,
+        definition: String,
+        base_version: u64,
+    ) -> Result<Vec<u32>> {
+        let resp = self
+            .meta_client
+            .alter_source_add_column(source_id, column, definition, base_version)
+            .await?;
+        self.wait_version(resp.version).await?;
+        Ok(resp.fixed_downstream_job_ids)
+    }
+
+    async fn alter_connector_props(
+        &self,
+        object_id: u32,
+        object_type: ConnectorPropsObjectType,
+        changed_props: HashMap<String, String>,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .alter_connector_props(object_id, object_type, changed_props)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn acquire_alter_lease(&self, table_id: u32, force: bool) -> Result<u64> {
+        let token = self.meta_client.acquire_alter_lease(table_id, force).await?;
+        Ok(token)
+    }
+
     async fn replace_table(
         &self,
         source: Option<PbSource>,
@@ -311,10 +391,11 @@ impl CatalogWriter for CatalogWriterImpl {
         graph: StreamFragmentGraph,
         mapping: ColIndexMapping,
         job_type: TableJobType,
+        alter_lease_token: u64,
     ) -> Result<()> {
         let version = self
             .meta_client
-            .replace_table(source, table, graph, mapping, job_type)
+            .replace_table(source, table, graph, mapping, job_type, alter_lease_token)
             .await?;
         self.wait_version(version).await
     }
@@ -354,8 +435,11 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
-    async fn create_function(&self, function: PbFunction) -> Result<()> {
-        let version = self.meta_client.create_function(function).await?;
+    async fn create_function(&self, function: PbFunction, or_replace: bool) -> Result<()> {
+        let version = self
+            .meta_client
+            .create_function(function, or_replace)
+            .await?;
         self.wait_version(version).await
     }
 
@@ -537,6 +621,17 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
+    async fn alter_function_name(&self, function_id: u32, function_name: &str) -> Result<()> {
+        let version = self
+            .meta_client
+            .alter_name(
+                alter_name_request::Object::FunctionId(function_id),
+                function_name,
+            )
+            .await?;
+        self.wait_version(version).await
+    }
+
     async fn alter_schema_name(&self, schema_id: u32, schema_name: &str) -> Result<()> {
         let version = self
             .meta_client
@@ -561,6 +656,18 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
+    async fn alter_schema_default_owner(
+        &self,
+        schema_id: u32,
+        default_owner_id: Option<u32>,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .alter_schema_default_owner(schema_id, default_owner_id)
+            .await?;
+        self.wait_version(version).await
+    }
+
     async fn alter_set_schema(
         &self,
         object: alter_set_schema_request::Object,
@@ -591,6 +698,26 @@ impl CatalogWriter for CatalogWriterImpl {
 
         Ok(())
     }
+
+    async fn alter_stream_job_priority(
+        &self,
+        table_id: u32,
+        priority: PbStreamJobPriority,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .alter_stream_job_priority(table_id, priority)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn alter_table_ttl(&self, table_id: u32, retention_seconds: Option<u32>) -> Result<()> {
+        let version = self
+            .meta_client
+            .alter_table_ttl(table_id, retention_seconds)
+            .await?;
+        self.wait_version(version).await
+    }
 }
 
 impl CatalogWriterImpl {
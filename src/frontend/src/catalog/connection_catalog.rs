@@ -19,7 +19,7 @@ use anyhow::anyhow;
 use risingwave_connector::source::kafka::private_link::insert_privatelink_broker_rewrite_map;
 use risingwave_connector::WithPropertiesExt;
 use risingwave_pb::catalog::connection::private_link_service::PrivateLinkProvider;
-use risingwave_pb::catalog::connection::Info;
+use risingwave_pb::catalog::connection::{Info, PbHealthStatus};
 use risingwave_pb::catalog::{connection, PbConnection};
 
 use crate::catalog::{ConnectionId, OwnedByUserCatalog};
@@ -32,6 +32,9 @@ pub struct ConnectionCatalog {
     pub name: String,
     pub info: connection::Info,
     pub owner: UserId,
+    pub health_status: PbHealthStatus,
+    pub health_message: String,
+    pub description: Option<String>,
 }
 
 impl ConnectionCatalog {
@@ -55,6 +58,9 @@ impl From<&PbConnection> for ConnectionCatalog {
             name: prost.name.clone(),
             info: prost.info.clone().unwrap(),
             owner: prost.owner,
+            health_status: prost.health_status(),
+            health_message: prost.health_message.clone(),
+            description: prost.description.clone(),
         }
     }
 }
@@ -31,6 +31,10 @@ pub struct DatabaseCatalog {
     schema_by_name: HashMap<String, SchemaCatalog>,
     schema_name_by_id: HashMap<SchemaId, String>,
     pub owner: u32,
+    /// Default `timezone` applied to sessions connecting to this database.
+    pub default_timezone: Option<String>,
+    /// Default `lc_collate` applied to sessions connecting to this database.
+    pub default_collation: Option<String>,
 }
 
 impl DatabaseCatalog {
@@ -68,6 +72,7 @@ impl DatabaseCatalog {
                 database_id: self.id,
                 name: schema.name(),
                 owner: schema.owner(),
+                default_owner_id: schema.default_owner_id(),
             })
             .collect_vec()
     }
@@ -157,6 +162,8 @@ impl From<&PbDatabase> for DatabaseCatalog {
             schema_by_name: HashMap::new(),
             schema_name_by_id: HashMap::new(),
             owner: db.owner,
+            default_timezone: db.default_timezone.clone(),
+            default_collation: db.default_collation.clone(),
         }
     }
 }
@@ -39,6 +39,7 @@ pub struct FunctionCatalog {
     pub always_retry_on_network_error: bool,
     pub function_type: Option<String>,
     pub runtime: Option<String>,
+    pub description: Option<String>,
 }
 
 #[derive(Clone, Display, PartialEq, Eq, Hash, Debug, EnumAsInner)]
@@ -78,6 +79,7 @@ impl From<&PbFunction> for FunctionCatalog {
             always_retry_on_network_error: prost.always_retry_on_network_error,
             function_type: prost.function_type.clone(),
             runtime: prost.runtime.clone(),
+            description: prost.description.clone(),
         }
     }
 }
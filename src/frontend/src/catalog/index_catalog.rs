@@ -70,6 +70,8 @@ pub struct IndexCatalog {
     pub created_at_cluster_version: Option<String>,
 
     pub initialized_at_cluster_version: Option<String>,
+
+    pub description: Option<String>,
 }
 
 impl IndexCatalog {
@@ -127,6 +129,7 @@ impl IndexCatalog {
             initialized_at_epoch: index_prost.initialized_at_epoch.map(Epoch::from),
             created_at_cluster_version: index_prost.created_at_cluster_version.clone(),
             initialized_at_cluster_version: index_prost.initialized_at_cluster_version.clone(),
+            description: index_prost.description.clone(),
         }
     }
 
@@ -191,6 +194,7 @@ impl IndexCatalog {
             stream_job_status: PbStreamJobStatus::Creating.into(),
             initialized_at_cluster_version: self.initialized_at_cluster_version.clone(),
             created_at_cluster_version: self.created_at_cluster_version.clone(),
+            description: self.description.clone(),
         }
     }
 
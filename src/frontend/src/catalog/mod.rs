@@ -25,6 +25,7 @@ use risingwave_connector::sink::catalog::SinkCatalog;
 use thiserror::Error;
 
 use crate::error::{ErrorCode, Result, RwError};
+pub(crate) mod catalog_history;
 pub(crate) mod catalog_service;
 
 pub(crate) mod connection_catalog;
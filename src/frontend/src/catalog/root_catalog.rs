@@ -33,6 +33,7 @@ use super::view_catalog::ViewCatalog;
 use super::{
     CatalogError, CatalogResult, ConnectionId, SecretId, SinkId, SourceId, SubscriptionId, ViewId,
 };
+use crate::catalog::catalog_history::CatalogHistory;
 use crate::catalog::connection_catalog::ConnectionCatalog;
 use crate::catalog::database_catalog::DatabaseCatalog;
 use crate::catalog::schema_catalog::SchemaCatalog;
@@ -99,11 +100,17 @@ impl<'a> SchemaPath<'a> {
 ///        - column catalog
 pub struct Catalog {
     version: CatalogVersion,
+    /// Per-database version counters, tracked independently of the global `version` so
+    /// that sessions can wait on the version domain of their own database only.
+    version_by_database: HashMap<DatabaseId, CatalogVersion>,
     database_by_name: HashMap<String, DatabaseCatalog>,
     db_name_by_id: HashMap<DatabaseId, String>,
     /// all table catalogs in the cluster identified by universal unique table id.
     table_by_id: HashMap<TableId, Arc<TableCatalog>>,
     table_stats: HummockVersionStats,
+    /// bounded history of superseded relation versions, for as-of resolution by
+    /// subscription cursors replaying historical epochs.
+    history: CatalogHistory,
 }
 
 #[expect(clippy::derivable_impls)]
@@ -111,10 +118,12 @@ impl Default for Catalog {
     fn default() -> Self {
         Self {
             version: 0,
+            version_by_database: HashMap::new(),
             database_by_name: HashMap::new(),
             db_name_by_id: HashMap::new(),
             table_by_id: HashMap::new(),
             table_stats: HummockVersionStats::default(),
+            history: CatalogHistory::default(),
         }
     }
 }
@@ -129,6 +138,7 @@ impl Catalog {
         self.database_by_name.clear();
         self.db_name_by_id.clear();
         self.table_by_id.clear();
+        self.history.clear();
     }
 
     pub fn create_database(&mut self, db: &PbDatabase) {
@@ -289,6 +299,7 @@ impl Catalog {
 
     pub fn drop_database(&mut self, db_id: DatabaseId) {
         let name = self.db_name_by_id.remove(&db_id).unwrap();
+        self.version_by_database.remove(&db_id);
         let database = self.database_by_name.remove(&name).unwrap();
         database.iter_all_table_ids().for_each(|table| {
             self.table_by_id.remove(&table);
@@ -300,7 +311,9 @@ impl Catalog {
     }
 
     pub fn drop_table(&mut self, db_id: DatabaseId, schema_id: SchemaId, tb_id: TableId) {
-        self.table_by_id.remove(&tb_id);
+        if let Some(table) = self.table_by_id.remove(&tb_id) {
+            self.history.record(table);
+        }
         self.get_database_mut(db_id)
             .unwrap()
             .get_schema_mut(schema_id)
@@ -309,6 +322,9 @@ impl Catalog {
     }
 
     pub fn update_table(&mut self, proto: &PbTable) {
+        if let Some(table) = self.table_by_id.get(&proto.id.into()) {
+            self.history.record(table.clone());
+        }
         let database = self.get_database_mut(proto.database_id).unwrap();
         let schema = database.get_schema_mut(proto.schema_id).unwrap();
         let table = if schema.get_table_by_id(&proto.id.into()).is_some() {
@@ -339,12 +355,24 @@ impl Catalog {
             let mut database = self.database_by_name.remove(&old_database_name).unwrap();
             database.name.clone_from(&name);
             database.owner = proto.owner;
+            database
+                .default_timezone
+                .clone_from(&proto.default_timezone);
+            database
+                .default_collation
+                .clone_from(&proto.default_collation);
             self.database_by_name.insert(name.clone(), database);
             self.db_name_by_id.insert(id, name);
         } else {
             let database = self.get_database_mut(id).unwrap();
             database.name = name;
             database.owner = proto.owner;
+            database
+                .default_timezone
+                .clone_from(&proto.default_timezone);
+            database
+                .default_collation
+                .clone_from(&proto.default_collation);
         }
     }
 
@@ -675,6 +703,21 @@ impl Catalog {
             .ok_or_else(|| CatalogError::NotFound("table id", table_id.to_string()))
     }
 
+    /// Resolve the version of a table effective at `epoch`, using the bounded catalog
+    /// history to look past renames, schema changes and drops. Falls back to the current
+    /// catalog when the table has not been superseded since `epoch`, or the history has
+    /// been truncated past it. Returns an error only if the table is unknown to both.
+    pub fn get_table_by_id_as_of(
+        &self,
+        table_id: &TableId,
+        epoch: u64,
+    ) -> CatalogResult<Arc<TableCatalog>> {
+        if let Some(entry) = self.history.resolve_as_of(table_id, epoch) {
+            return Ok(entry.table.clone());
+        }
+        self.get_any_table_by_id(table_id).cloned()
+    }
+
     /// This function is similar to `get_table_by_id` expect that a table must be in a given database.
     pub fn get_created_table_by_id_with_db(
         &self,
@@ -1054,6 +1097,20 @@ impl Catalog {
         self.version = catalog_version;
     }
 
+    /// Get the catalog cache's version of the given database, or 0 if no database-scoped
+    /// notification has been received for it yet.
+    pub fn database_version(&self, database_id: DatabaseId) -> CatalogVersion {
+        self.version_by_database
+            .get(&database_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Set the catalog cache's version of the given database.
+    pub fn set_database_version(&mut self, database_id: DatabaseId, version: CatalogVersion) {
+        self.version_by_database.insert(database_id, version);
+    }
+
     pub fn table_stats(&self) -> &HummockVersionStats {
         &self.table_stats
     }
@@ -77,6 +77,11 @@ pub struct SchemaCatalog {
     // This field only available when schema is "pg_catalog". Meanwhile, others will be empty.
     system_table_by_name: HashMap<String, Arc<SystemTableCatalog>>,
     pub owner: u32,
+    /// If set, objects created in this schema are owned by this user instead of their
+    /// creator. See `ALTER SCHEMA ... DEFAULT OWNER TO ...`.
+    pub default_owner_id: Option<u32>,
+    /// Description set by the `comment on` clause.
+    pub description: Option<String>,
 }
 
 impl SchemaCatalog {
@@ -804,6 +809,10 @@ impl SchemaCatalog {
     pub fn name(&self) -> String {
         self.name.clone()
     }
+
+    pub fn default_owner_id(&self) -> Option<u32> {
+        self.default_owner_id
+    }
 }
 
 impl OwnedByUserCatalog for SchemaCatalog {
@@ -817,6 +826,8 @@ impl From<&PbSchema> for SchemaCatalog {
         Self {
             id: schema.id,
             owner: schema.owner,
+            default_owner_id: schema.default_owner_id,
+            description: schema.description.clone(),
             name: schema.name.clone(),
             database_id: schema.database_id,
             table_by_name: HashMap::new(),
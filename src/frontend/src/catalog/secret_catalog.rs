@@ -24,6 +24,7 @@ pub struct SecretCatalog {
     pub database_id: DatabaseId,
     pub value: Vec<u8>,
     pub owner: UserId,
+    pub description: Option<String>,
 }
 
 impl From<&PbSecret> for SecretCatalog {
@@ -34,6 +35,7 @@ impl From<&PbSecret> for SecretCatalog {
             owner: value.owner,
             name: value.name.clone(),
             value: value.value.clone(),
+            description: value.description.clone(),
         }
     }
 }
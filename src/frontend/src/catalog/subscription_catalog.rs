@@ -17,7 +17,7 @@ use core::str::FromStr;
 use risingwave_common::catalog::{TableId, UserId, OBJECT_ID_PLACEHOLDER};
 use risingwave_common::types::Interval;
 use risingwave_common::util::epoch::Epoch;
-use risingwave_pb::catalog::subscription::PbSubscriptionState;
+use risingwave_pb::catalog::subscription::{PbRetentionPolicy, PbSubscriptionState};
 use risingwave_pb::catalog::PbSubscription;
 use thiserror_ext::AsReport;
 
@@ -40,6 +40,16 @@ pub struct SubscriptionCatalog {
     /// The retention seconds of the subscription.
     pub retention_seconds: u64,
 
+    /// How the retention window of the subscription's log is computed.
+    pub retention_policy: RetentionPolicy,
+
+    /// Number of checkpointed epochs to retain. Only used by [`RetentionPolicy::EpochCount`].
+    pub retention_epoch_count: u64,
+
+    /// The max epoch that the consumer has acknowledged as fully consumed, maintained by
+    /// the meta node. Only used by [`RetentionPolicy::ConsumerAck`].
+    pub acked_epoch: u64,
+
     /// The database id
     pub database_id: u32,
 
@@ -59,6 +69,34 @@ pub struct SubscriptionCatalog {
     pub initialized_at_cluster_version: Option<String>,
 }
 
+/// See the documentation on the `RetentionPolicy` enum of the catalog proto for the
+/// meaning of the variants.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum RetentionPolicy {
+    #[default]
+    Time,
+    EpochCount,
+    ConsumerAck,
+}
+
+impl RetentionPolicy {
+    pub fn from_proto(policy: PbRetentionPolicy) -> Self {
+        match policy {
+            PbRetentionPolicy::Unspecified | PbRetentionPolicy::Time => Self::Time,
+            PbRetentionPolicy::EpochCount => Self::EpochCount,
+            PbRetentionPolicy::ConsumerAck => Self::ConsumerAck,
+        }
+    }
+
+    pub fn to_proto(self) -> PbRetentionPolicy {
+        match self {
+            Self::Time => PbRetentionPolicy::Time,
+            Self::EpochCount => PbRetentionPolicy::EpochCount,
+            Self::ConsumerAck => PbRetentionPolicy::ConsumerAck,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Hash, PartialOrd, PartialEq, Eq, Ord)]
 pub struct SubscriptionId {
     pub subscription_id: u32,
@@ -99,6 +137,54 @@ impl SubscriptionCatalog {
         Ok(())
     }
 
+    pub fn set_retention_policy(&mut self, properties: &WithOptions) -> Result<()> {
+        let retention_policy = match properties.get("retention_policy") {
+            None => RetentionPolicy::Time,
+            Some(policy) => match policy.to_lowercase().as_str() {
+                "time" => RetentionPolicy::Time,
+                "epoch_count" => RetentionPolicy::EpochCount,
+                "consumer_ack" => RetentionPolicy::ConsumerAck,
+                policy => return Err(ErrorCode::InvalidParameterValue(format!(
+                    "invalid retention_policy: {policy}, accept: time | epoch_count | consumer_ack"
+                ))
+                .into()),
+            },
+        };
+        let retention_epoch_count = match properties.get("retention_epoch_count") {
+            Some(count) => {
+                if retention_policy != RetentionPolicy::EpochCount {
+                    return Err(ErrorCode::InvalidParameterValue(
+                        "retention_epoch_count is only valid with retention_policy = 'epoch_count'"
+                            .to_string(),
+                    )
+                    .into());
+                }
+                count
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|count| *count > 0)
+                    .ok_or_else(|| {
+                        ErrorCode::InvalidParameterValue(format!(
+                            "retention_epoch_count must be a positive integer, got: {count}"
+                        ))
+                    })?
+            }
+            None => {
+                if retention_policy == RetentionPolicy::EpochCount {
+                    return Err(ErrorCode::InvalidParameterValue(
+                        "retention_epoch_count must be set when retention_policy = 'epoch_count'"
+                            .to_string(),
+                    )
+                    .into());
+                }
+                0
+            }
+        };
+        self.retention_policy = retention_policy;
+        self.retention_epoch_count = retention_epoch_count;
+        Ok(())
+    }
+
     pub fn create_sql(&self) -> String {
         self.definition.clone()
     }
@@ -118,6 +204,9 @@ impl SubscriptionCatalog {
             created_at_cluster_version: self.created_at_cluster_version.clone(),
             dependent_table_id: self.dependent_table_id.table_id,
             subscription_state: PbSubscriptionState::Init.into(),
+            retention_policy: self.retention_policy.to_proto().into(),
+            retention_epoch_count: self.retention_epoch_count,
+            acked_epoch: self.acked_epoch,
         }
     }
 }
@@ -129,6 +218,9 @@ impl From<&PbSubscription> for SubscriptionCatalog {
             name: prost.name.clone(),
             definition: prost.definition.clone(),
             retention_seconds: prost.retention_seconds,
+            retention_policy: RetentionPolicy::from_proto(prost.retention_policy()),
+            retention_epoch_count: prost.retention_epoch_count,
+            acked_epoch: prost.acked_epoch,
             database_id: prost.database_id,
             schema_id: prost.schema_id,
             dependent_table_id: TableId::new(prost.dependent_table_id),
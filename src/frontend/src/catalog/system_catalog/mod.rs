@@ -194,6 +194,8 @@ impl From<&BuiltinView> for ViewCatalog {
             sql: val.sql.clone(),
             owner: DEFAULT_SUPER_USER_ID,
             properties: Default::default(),
+            invalidated_reason: None,
+            description: None,
         }
     }
 }
@@ -17,6 +17,7 @@ mod rw_actors;
 mod rw_columns;
 mod rw_connections;
 mod rw_databases;
+mod rw_ddl_audit;
 mod rw_ddl_progress;
 mod rw_depend;
 mod rw_description;
@@ -40,10 +41,12 @@ mod rw_internal_tables;
 mod rw_materialized_views;
 mod rw_meta_snapshot;
 mod rw_relation_info;
+mod rw_relation_read_stats;
 mod rw_relations;
 mod rw_schemas;
 mod rw_secrets;
 mod rw_sinks;
+mod rw_source_error_stats;
 mod rw_sources;
 mod rw_streaming_parallelism;
 mod rw_subscriptions;
@@ -52,6 +55,7 @@ mod rw_table_fragments;
 mod rw_table_stats;
 mod rw_tables;
 pub(super) mod rw_types;
+mod rw_usage_records;
 mod rw_user_secrets;
 mod rw_users;
 mod rw_views;
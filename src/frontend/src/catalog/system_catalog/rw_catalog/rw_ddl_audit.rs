@@ -0,0 +1,55 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{Fields, Timestamptz};
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+#[derive(Fields)]
+struct RwDdlAudit {
+    #[primary_key]
+    id: i64,
+    event_time: Timestamptz,
+    command: String,
+    object_id: i32,
+    object_name: String,
+    user_id: i32,
+    definition: String,
+    success: bool,
+    error: String,
+}
+
+#[system_catalog(table, "rw_catalog.rw_ddl_audit")]
+async fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwDdlAudit>> {
+    let audit_logs = reader
+        .meta_client
+        .list_ddl_audit_logs()
+        .await?
+        .into_iter()
+        .map(|log| RwDdlAudit {
+            id: log.id as i64,
+            event_time: Timestamptz::from_millis(log.event_time_ms as i64).unwrap(),
+            command: log.command,
+            object_id: log.object_id as i32,
+            object_name: log.object_name,
+            user_id: log.user_id as i32,
+            definition: log.definition,
+            success: log.success,
+            error: log.error,
+        })
+        .collect();
+    Ok(audit_logs)
+}
@@ -50,29 +50,102 @@ fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwDescription>> {
         .iter_schemas(&reader.auth_context.database)?
         .filter(|schema| schema.id() != rw_catalog.id());
 
-    let rw_tables_id: i32 = rw_catalog
-        .get_system_table_by_name("rw_tables")
-        .map(|st| st.id.table_id)
-        .unwrap_or_default() as _;
+    let classoid = |table_name: &str| -> i32 {
+        rw_catalog
+            .get_system_table_by_name(table_name)
+            .map(|st| st.id.table_id)
+            .unwrap_or_default() as _
+    };
+    let rw_tables_id = classoid("rw_tables");
+    let rw_sources_id = classoid("rw_sources");
+    let rw_sinks_id = classoid("rw_sinks");
+    let rw_views_id = classoid("rw_views");
+    let rw_indexes_id = classoid("rw_indexes");
+    let rw_functions_id = classoid("rw_functions");
+    let rw_connections_id = classoid("rw_connections");
+    let rw_secrets_id = classoid("rw_secrets");
+    let rw_schemas_id = classoid("rw_schemas");
 
-    Ok(schemas
-        .flat_map(|schema| {
-            schema.iter_table().flat_map(|table| {
-                iter::once(build_row(
+    let mut rows = Vec::new();
+    for schema in schemas {
+        rows.push(build_row(
+            schema.id() as _,
+            rw_schemas_id,
+            None,
+            schema.description.as_deref().map(Into::into),
+        ));
+        rows.extend(schema.iter_table().flat_map(|table| {
+            iter::once(build_row(
+                table.id.table_id as _,
+                rw_tables_id,
+                None,
+                table.description.as_deref().map(Into::into),
+            ))
+            .chain(table.columns.iter().map(|col| {
+                build_row(
                     table.id.table_id as _,
                     rw_tables_id,
-                    None,
-                    table.description.as_deref().map(Into::into),
-                ))
-                .chain(table.columns.iter().map(|col| {
-                    build_row(
-                        table.id.table_id as _,
-                        rw_tables_id,
-                        Some(col.column_id().get_id() as _),
-                        col.column_desc.description.as_deref().map(Into::into),
-                    )
-                }))
-            })
-        })
-        .collect())
+                    Some(col.column_id().get_id() as _),
+                    col.column_desc.description.as_deref().map(Into::into),
+                )
+            }))
+        }));
+        rows.extend(schema.iter_source().map(|source| {
+            build_row(
+                source.id as _,
+                rw_sources_id,
+                None,
+                source.description.as_deref().map(Into::into),
+            )
+        }));
+        rows.extend(schema.iter_sink().map(|sink| {
+            build_row(
+                sink.id.sink_id as _,
+                rw_sinks_id,
+                None,
+                sink.description.as_deref().map(Into::into),
+            )
+        }));
+        rows.extend(schema.iter_view().map(|view| {
+            build_row(
+                view.id as _,
+                rw_views_id,
+                None,
+                view.description.as_deref().map(Into::into),
+            )
+        }));
+        rows.extend(schema.iter_index().map(|index| {
+            build_row(
+                index.id.index_id as _,
+                rw_indexes_id,
+                None,
+                index.description.as_deref().map(Into::into),
+            )
+        }));
+        rows.extend(schema.iter_function().map(|function| {
+            build_row(
+                function.id.0 as _,
+                rw_functions_id,
+                None,
+                function.description.as_deref().map(Into::into),
+            )
+        }));
+        rows.extend(schema.iter_connections().map(|connection| {
+            build_row(
+                connection.id as _,
+                rw_connections_id,
+                None,
+                connection.description.as_deref().map(Into::into),
+            )
+        }));
+        rows.extend(schema.iter_secret().map(|secret| {
+            build_row(
+                secret.id.secret_id() as _,
+                rw_secrets_id,
+                None,
+                secret.description.as_deref().map(Into::into),
+            )
+        }));
+    }
+    Ok(rows)
 }
@@ -63,6 +63,11 @@ fn event_type(e: &Event) -> String {
         Event::CollectBarrierFail(_) => "COLLECT_BARRIER_FAIL",
         Event::WorkerNodePanic(_) => "WORKER_NODE_PANIC",
         Event::AutoSchemaChangeFail(_) => "AUTO_SCHEMA_CHANGE_FAIL",
+        Event::MaintenanceMode(_) => "MAINTENANCE_MODE",
+        Event::SubscriptionLag(_) => "SUBSCRIPTION_LAG",
+        Event::ForegroundDdlToBackground(_) => "FOREGROUND_DDL_TO_BACKGROUND",
+        Event::AutoSchemaChangeReject(_) => "AUTO_SCHEMA_CHANGE_REJECT",
+        Event::ClusterCapacityChange(_) => "CLUSTER_CAPACITY_CHANGE",
     }
     .into()
 }
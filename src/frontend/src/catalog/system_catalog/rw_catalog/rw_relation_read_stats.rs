@@ -0,0 +1,45 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{Fields, Timestamptz};
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+/// Batch read statistics per relation, aggregated on meta across all frontends.
+/// Relations that have never been read since the meta node started do not appear here,
+/// so a missing row for a materialized view is a signal that it may be unused.
+#[derive(Fields)]
+struct RwRelationReadStats {
+    #[primary_key]
+    relation_id: i32,
+    read_count: i64,
+    total_latency_ms: i64,
+    last_read_at: Option<Timestamptz>,
+}
+
+#[system_catalog(table, "rw_catalog.rw_relation_read_stats")]
+async fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwRelationReadStats>> {
+    let stats = reader.meta_client.list_relation_read_stats().await?;
+    Ok(stats
+        .into_iter()
+        .map(|s| RwRelationReadStats {
+            relation_id: s.relation_id as i32,
+            read_count: s.read_count as i64,
+            total_latency_ms: s.total_latency_ms as i64,
+            last_read_at: Timestamptz::from_millis(s.last_read_at_ms as i64),
+        })
+        .collect())
+}
@@ -0,0 +1,45 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{Fields, Timestamptz};
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+#[derive(Fields)]
+struct RwSourceErrorStats {
+    #[primary_key]
+    source_id: i32,
+    dlq_record_count: i64,
+    dlq_byte_count: i64,
+    last_error: Option<String>,
+    last_error_at: Option<Timestamptz>,
+}
+
+#[system_catalog(table, "rw_catalog.rw_source_error_stats")]
+async fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwSourceErrorStats>> {
+    let stats = reader.meta_client.list_source_error_stats().await?;
+    Ok(stats
+        .into_iter()
+        .map(|stats| RwSourceErrorStats {
+            source_id: stats.source_id as i32,
+            dlq_record_count: stats.dlq_record_count as i64,
+            dlq_byte_count: stats.dlq_byte_count as i64,
+            last_error: (!stats.last_error.is_empty()).then_some(stats.last_error),
+            last_error_at: (stats.last_error_timestamp_ms != 0)
+                .then(|| Timestamptz::from_millis(stats.last_error_timestamp_ms as i64).unwrap()),
+        })
+        .collect())
+}
@@ -0,0 +1,53 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{Fields, Timestamptz};
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+#[derive(Fields)]
+struct RwUsageRecord {
+    #[primary_key]
+    id: i64,
+    database_id: i32,
+    window_start: Timestamptz,
+    window_end: Timestamptz,
+    state_bytes: i64,
+    actor_seconds: i64,
+    barrier_count: i64,
+    sink_egress_bytes: i64,
+}
+
+#[system_catalog(table, "rw_catalog.rw_usage_records")]
+async fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwUsageRecord>> {
+    let records = reader
+        .meta_client
+        .get_usage_report(None, 0, 0)
+        .await?
+        .into_iter()
+        .map(|record| RwUsageRecord {
+            id: record.id as i64,
+            database_id: record.database_id as i32,
+            window_start: Timestamptz::from_millis(record.window_start_ms as i64).unwrap(),
+            window_end: Timestamptz::from_millis(record.window_end_ms as i64).unwrap(),
+            state_bytes: record.state_bytes as i64,
+            actor_seconds: record.actor_seconds as i64,
+            barrier_count: record.barrier_count as i64,
+            sink_egress_bytes: record.sink_egress_bytes as i64,
+        })
+        .collect();
+    Ok(records)
+}
@@ -23,7 +23,7 @@ use risingwave_common::catalog::{
 use risingwave_common::util::epoch::Epoch;
 use risingwave_common::util::sort_util::ColumnOrder;
 use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, PbTableType, PbTableVersion};
-use risingwave_pb::catalog::{PbCreateType, PbStreamJobStatus, PbTable};
+use risingwave_pb::catalog::{PbCreateType, PbStreamJobPriority, PbStreamJobStatus, PbTable};
 use risingwave_pb::plan_common::column_desc::GeneratedOrDefaultColumn;
 use risingwave_pb::plan_common::DefaultColumnDesc;
 
@@ -170,6 +170,10 @@ pub struct TableCatalog {
     pub initialized_at_cluster_version: Option<String>,
 
     pub cdc_table_id: Option<String>,
+
+    /// Priority class of the streaming job, set with `ALTER MATERIALIZED VIEW
+    /// ... SET PRIORITY`. `Unspecified` is treated as normal.
+    pub priority: PbStreamJobPriority,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -428,6 +432,12 @@ impl TableCatalog {
             initialized_at_cluster_version: self.initialized_at_cluster_version.clone(),
             retention_seconds: self.retention_seconds,
             cdc_table_id: self.cdc_table_id.clone(),
+            priority: self.priority as _,
+            annotations: Default::default(),
+            soft_dropped_at_ms: None,
+            storage_class_policy: None,
+            schema_change_policy: 0,
+            labels: Default::default(),
         }
     }
 
@@ -529,6 +539,7 @@ impl From<PbTable> for TableCatalog {
             .get_stream_job_status()
             .unwrap_or(PbStreamJobStatus::Created);
         let create_type = tb.get_create_type().unwrap_or(PbCreateType::Foreground);
+        let priority = tb.priority();
         let associated_source_id = tb.optional_associated_source_id.map(|id| match id {
             OptionalAssociatedSourceId::AssociatedSourceId(id) => id,
         });
@@ -602,6 +613,7 @@ impl From<PbTable> for TableCatalog {
                 .map(TableId::from)
                 .collect_vec(),
             cdc_table_id: tb.cdc_table_id,
+            priority,
         }
     }
 }
@@ -692,6 +704,12 @@ mod tests {
             initialized_at_cluster_version: None,
             version_column_index: None,
             cdc_table_id: None,
+            priority: 0,
+            annotations: Default::default(),
+            soft_dropped_at_ms: None,
+            storage_class_policy: None,
+            schema_change_policy: 0,
+            labels: Default::default(),
         }
         .into();
 
@@ -755,6 +773,7 @@ mod tests {
                 dependent_relations: vec![],
                 version_column_index: None,
                 cdc_table_id: None,
+                priority: PbStreamJobPriority::Unspecified,
             }
         );
         assert_eq!(table, TableCatalog::from(table.to_prost(0, 0)));
@@ -28,6 +28,10 @@ pub struct ViewCatalog {
     pub properties: WithOptions,
     pub sql: String,
     pub columns: Vec<Field>,
+    /// Set with the reason when an incompatible upstream `ALTER TABLE` has invalidated
+    /// this view. Queries on the view are rejected until it is recreated.
+    pub invalidated_reason: Option<String>,
+    pub description: Option<String>,
 }
 
 impl From<&PbView> for ViewCatalog {
@@ -39,6 +43,8 @@ impl From<&PbView> for ViewCatalog {
             properties: WithOptions::new_with_options(view.properties.clone()),
             sql: view.sql.clone(),
             columns: view.columns.iter().map(|f| f.into()).collect(),
+            invalidated_reason: view.invalidated_reason.clone(),
+            description: view.description.clone(),
         }
     }
 }
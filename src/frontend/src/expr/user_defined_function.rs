@@ -62,6 +62,7 @@ impl UserDefinedFunction {
             always_retry_on_network_error: udf.always_retry_on_network_error,
             function_type: udf.function_type.clone(),
             runtime: udf.runtime.clone(),
+            description: None,
         };
 
         Ok(Self {
@@ -18,16 +18,16 @@ use pgwire::pg_response::StatementType;
 use risingwave_common::acl::AclMode;
 use risingwave_pb::ddl_service::alter_owner_request::Object;
 use risingwave_pb::user::grant_privilege;
-use risingwave_sqlparser::ast::{Ident, ObjectName};
+use risingwave_sqlparser::ast::{Ident, ObjectName, OperateFunctionArg};
 
 use super::{HandlerArgs, RwPgResponse};
 use crate::catalog::root_catalog::SchemaPath;
 use crate::catalog::{CatalogError, OwnedByUserCatalog};
 use crate::error::ErrorCode::PermissionDenied;
-use crate::error::Result;
+use crate::error::{ErrorCode, Result};
 use crate::session::SessionImpl;
 use crate::user::user_catalog::UserCatalog;
-use crate::Binder;
+use crate::{bind_data_type, Binder};
 
 pub fn check_schema_create_privilege(
     session: &Arc<SessionImpl>,
@@ -56,6 +56,7 @@ pub async fn handle_alter_owner(
     obj_name: ObjectName,
     new_owner_name: Ident,
     stmt_type: StatementType,
+    func_args: Option<Vec<OperateFunctionArg>>,
 ) -> Result<RwPgResponse> {
     let session = handler_args.session;
     let db_name = session.database();
@@ -66,6 +67,12 @@ pub async fn handle_alter_owner(
     let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
 
     let new_owner_name = Binder::resolve_user_name(vec![new_owner_name].into())?;
+    // `OWNER TO CURRENT_USER` resolves to the session user.
+    let new_owner_name = if new_owner_name.eq_ignore_ascii_case("current_user") {
+        user_name.clone()
+    } else {
+        new_owner_name
+    };
     let (object, owner_id) = {
         let catalog_reader = session.env().catalog_reader().read_guard();
         let user_reader = session.env().user_info_reader().read_guard();
@@ -146,6 +153,71 @@ pub async fn handle_alter_owner(
                     }
                     Object::SubscriptionId(subscription.id.subscription_id)
                 }
+                StatementType::ALTER_FUNCTION => {
+                    let (function, schema_name) = if let Some(args) = func_args {
+                        let mut arg_types = vec![];
+                        for arg in args {
+                            arg_types.push(bind_data_type(&arg.data_type)?);
+                        }
+                        catalog_reader.get_function_by_name_args(
+                            db_name,
+                            schema_path,
+                            &real_obj_name,
+                            &arg_types,
+                        )?
+                    } else {
+                        let (functions, schema_name) = catalog_reader.get_functions_by_name(
+                            db_name,
+                            schema_path,
+                            &real_obj_name,
+                        )?;
+                        if functions.len() > 1 {
+                            return Err(ErrorCode::CatalogError(format!("function name {real_obj_name:?} is not unique\nHINT: Specify the argument list to select the function unambiguously.").into()).into());
+                        }
+                        (
+                            functions.into_iter().next().expect("no functions"),
+                            schema_name,
+                        )
+                    };
+                    session.check_privilege_for_drop_alter(schema_name, &**function)?;
+                    let schema_id = catalog_reader
+                        .get_schema_by_name(db_name, schema_name)?
+                        .id();
+                    check_schema_create_privilege(&session, new_owner, schema_id)?;
+                    if function.owner() == owner_id {
+                        return Ok(RwPgResponse::empty_result(stmt_type));
+                    }
+                    Object::FunctionId(function.id.function_id())
+                }
+                StatementType::ALTER_CONNECTION => {
+                    let (connection, schema_name) = catalog_reader.get_connection_by_name(
+                        db_name,
+                        schema_path,
+                        &real_obj_name,
+                    )?;
+                    session.check_privilege_for_drop_alter(schema_name, &**connection)?;
+                    let schema_id = catalog_reader
+                        .get_schema_by_name(db_name, schema_name)?
+                        .id();
+                    check_schema_create_privilege(&session, new_owner, schema_id)?;
+                    if connection.owner() == owner_id {
+                        return Ok(RwPgResponse::empty_result(stmt_type));
+                    }
+                    Object::ConnectionId(connection.id)
+                }
+                StatementType::ALTER_SECRET => {
+                    let (secret, schema_name) =
+                        catalog_reader.get_secret_by_name(db_name, schema_path, &real_obj_name)?;
+                    session.check_privilege_for_drop_alter(schema_name, &**secret)?;
+                    let schema_id = catalog_reader
+                        .get_schema_by_name(db_name, schema_name)?
+                        .id();
+                    check_schema_create_privilege(&session, new_owner, schema_id)?;
+                    if secret.owner() == owner_id {
+                        return Ok(RwPgResponse::empty_result(stmt_type));
+                    }
+                    Object::SecretId(secret.id.secret_id())
+                }
                 StatementType::ALTER_DATABASE => {
                     let database = catalog_reader.get_database_by_name(&obj_name.real_value())?;
                     session.check_privilege_for_drop_alter_db_schema(database)?;
@@ -174,3 +246,49 @@ pub async fn handle_alter_owner(
 
     Ok(RwPgResponse::empty_result(stmt_type))
 }
+
+pub async fn handle_alter_schema_default_owner(
+    handler_args: HandlerArgs,
+    schema_name: ObjectName,
+    new_owner_name: Option<Ident>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let user_name = &session.auth_context().user_name;
+
+    let (schema_id, default_owner_id) = {
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let schema = catalog_reader.get_schema_by_name(db_name, &schema_name.real_value())?;
+        session.check_privilege_for_drop_alter_db_schema(schema)?;
+
+        let default_owner_id = match new_owner_name {
+            Some(new_owner_name) => {
+                let new_owner_name = Binder::resolve_user_name(vec![new_owner_name].into())?;
+                // `DEFAULT OWNER TO CURRENT_USER` resolves to the session user.
+                let new_owner_name = if new_owner_name.eq_ignore_ascii_case("current_user") {
+                    user_name.clone()
+                } else {
+                    new_owner_name
+                };
+                let user_reader = session.env().user_info_reader().read_guard();
+                let new_owner = user_reader
+                    .get_user_by_name(&new_owner_name)
+                    .ok_or(CatalogError::NotFound("user", new_owner_name))?;
+                check_schema_create_privilege(&session, new_owner, schema.id())?;
+                Some(new_owner.id)
+            }
+            None => None,
+        };
+        if schema.default_owner_id() == default_owner_id {
+            return Ok(RwPgResponse::empty_result(StatementType::ALTER_SCHEMA));
+        }
+        (schema.id(), default_owner_id)
+    };
+
+    let catalog_writer = session.catalog_writer()?;
+    catalog_writer
+        .alter_schema_default_owner(schema_id, default_owner_id)
+        .await?;
+
+    Ok(RwPgResponse::empty_result(StatementType::ALTER_SCHEMA))
+}
@@ -16,13 +16,13 @@ use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::acl::AclMode;
 use risingwave_common::catalog::is_system_schema;
 use risingwave_pb::user::grant_privilege::Object;
-use risingwave_sqlparser::ast::ObjectName;
+use risingwave_sqlparser::ast::{ObjectName, OperateFunctionArg};
 
 use super::{HandlerArgs, RwPgResponse};
 use crate::catalog::root_catalog::SchemaPath;
 use crate::catalog::table_catalog::TableType;
 use crate::error::{ErrorCode, Result};
-use crate::Binder;
+use crate::{bind_data_type, Binder};
 
 pub async fn handle_rename_table(
     handler_args: HandlerArgs,
@@ -231,6 +231,58 @@ pub async fn handle_rename_source(
     Ok(PgResponse::empty_result(StatementType::ALTER_SOURCE))
 }
 
+pub async fn handle_rename_function(
+    handler_args: HandlerArgs,
+    function_name: ObjectName,
+    new_function_name: ObjectName,
+    func_args: Option<Vec<OperateFunctionArg>>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_function_name) =
+        Binder::resolve_schema_qualified_name(db_name, function_name.clone())?;
+    let new_function_name = Binder::resolve_function_name(new_function_name)?;
+    let search_path = session.config().search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let function_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (function, schema_name) = if let Some(args) = func_args {
+            let mut arg_types = Vec::with_capacity(args.len());
+            for arg in args {
+                arg_types.push(bind_data_type(&arg.data_type)?);
+            }
+            reader.get_function_by_name_args(
+                db_name,
+                schema_path,
+                &real_function_name,
+                &arg_types,
+            )?
+        } else {
+            let (functions, schema_name) =
+                reader.get_functions_by_name(db_name, schema_path, &real_function_name)?;
+            if functions.len() > 1 {
+                return Err(ErrorCode::CatalogError(format!("function name {real_function_name:?} is not unique\nHINT: Specify the argument list to select the function unambiguously.").into()).into());
+            }
+            (
+                functions.into_iter().next().expect("no functions"),
+                schema_name,
+            )
+        };
+        session.check_privilege_for_drop_alter(schema_name, &**function)?;
+        function.id
+    };
+
+    let catalog_writer = session.catalog_writer()?;
+    catalog_writer
+        .alter_function_name(function_id.function_id(), &new_function_name)
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_FUNCTION))
+}
+
 pub async fn handle_rename_schema(
     handler_args: HandlerArgs,
     schema_name: ObjectName,
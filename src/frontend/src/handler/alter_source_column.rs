@@ -13,8 +13,7 @@
 // limitations under the License.
 
 use itertools::Itertools;
-use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::catalog::max_column_id;
+use pgwire::pg_response::StatementType;
 use risingwave_connector::source::{extract_source_struct, SourceEncode, SourceStruct};
 use risingwave_sqlparser::ast::{
     AlterSourceOperation, ColumnDef, CreateSourceStatement, ObjectName, Statement,
@@ -46,16 +45,13 @@ pub async fn handle_alter_source_column(
 
     let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
 
-    let (db_id, schema_id, mut catalog) = {
+    let catalog = {
         let reader = session.env().catalog_reader().read_guard();
         let (source, schema_name) =
             reader.get_source_by_name(db_name, schema_path, &real_source_name)?;
-        let db = reader.get_database_by_name(db_name)?;
-        let schema = db.get_schema_by_name(schema_name).unwrap();
-
         session.check_privilege_for_drop_alter(schema_name, &**source)?;
 
-        (db.id(), schema.id(), (**source).clone())
+        (**source).clone()
     };
 
     if catalog.associated_table_id.is_some() {
@@ -91,11 +87,11 @@ pub async fn handle_alter_source_column(
         _ => {}
     }
 
-    let columns = &mut catalog.columns;
-    match operation {
+    let (bound_column, definition) = match operation {
         AlterSourceOperation::AddColumn { column_def } => {
             let new_column_name = column_def.name.real_value();
-            if columns
+            if catalog
+                .columns
                 .iter()
                 .any(|c| c.column_desc.name == new_column_name)
             {
@@ -103,24 +99,36 @@ pub async fn handle_alter_source_column(
                     "column \"{new_column_name}\" of source \"{source_name}\" already exists"
                 )))?
             }
-            catalog.definition =
-                alter_definition_add_column(&catalog.definition, column_def.clone())?;
-            let mut bound_column = bind_sql_columns(&[column_def])?.remove(0);
-            bound_column.column_desc.column_id = max_column_id(columns).next();
-            columns.push(bound_column);
+            let definition = alter_definition_add_column(&catalog.definition, column_def.clone())?;
+            let bound_column = bind_sql_columns(&[column_def])?.remove(0);
+            (bound_column, definition)
         }
         _ => unreachable!(),
-    }
-
-    // update version
-    catalog.version += 1;
+    };
 
+    // The column id and the version bump are assigned on the meta side, which also
+    // re-validates the change against the latest catalog.
     let catalog_writer = session.catalog_writer()?;
-    catalog_writer
-        .alter_source_column(catalog.to_prost(schema_id, db_id))
+    let fixed_downstream_job_ids = catalog_writer
+        .alter_source_add_column(
+            catalog.id,
+            bound_column.to_protobuf(),
+            definition,
+            catalog.version,
+        )
         .await?;
 
-    Ok(PgResponse::empty_result(StatementType::ALTER_SOURCE))
+    let mut builder = RwPgResponse::builder(StatementType::ALTER_SOURCE);
+    if !fixed_downstream_job_ids.is_empty() {
+        builder = builder.notice(format!(
+            "The schemas of {} existing streaming job(s) reading from this source (ids: {}) \
+             were fixed when they were created, so they will not see the new column until \
+             they are recreated.",
+            fixed_downstream_job_ids.len(),
+            fixed_downstream_job_ids.iter().join(", ")
+        ));
+    }
+    Ok(builder.into())
 }
 
 /// `alter_definition_add_column` adds a new column to the definition of the relation.
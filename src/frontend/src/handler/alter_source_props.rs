@@ -0,0 +1,80 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_pb::ddl_service::alter_connector_props_request::ObjectType as ConnectorPropsObjectType;
+use risingwave_sqlparser::ast::{ObjectName, SqlOption};
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::error::{ErrorCode, Result};
+use crate::utils::WithOptions;
+use crate::Binder;
+
+/// Handle `ALTER SOURCE <name> SET PROPERTIES (...)` statements.
+///
+/// The changed properties are merged into the source's `with_properties` on meta, which
+/// validates them, commits the catalog change and pushes the merged properties to the
+/// running source executors through a barrier.
+pub async fn handle_alter_source_connector_props(
+    handler_args: HandlerArgs,
+    source_name: ObjectName,
+    with_properties: Vec<SqlOption>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_source_name) =
+        Binder::resolve_schema_qualified_name(db_name, source_name.clone())?;
+    let search_path = session.config().search_path();
+    let user_name = &session.auth_context().user_name;
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let source_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (source, schema_name) =
+            reader.get_source_by_name(db_name, schema_path, &real_source_name)?;
+
+        session.check_privilege_for_drop_alter(schema_name, &**source)?;
+
+        if source.associated_table_id.is_some() {
+            Err(ErrorCode::NotSupported(
+                "alter table with connector with ALTER SOURCE statement".to_string(),
+                "try to use ALTER TABLE instead".to_string(),
+            ))?
+        }
+
+        source.id
+    };
+
+    let (changed_props, secret_refs) =
+        WithOptions::try_from(with_properties.as_slice())?.into_parts();
+    if !secret_refs.is_empty() {
+        return Err(ErrorCode::NotSupported(
+            "setting connector properties to secret references".to_string(),
+            "use plain values instead".to_string(),
+        )
+        .into());
+    }
+    let changed_props: HashMap<String, String> = changed_props.into_iter().collect();
+
+    let catalog_writer = session.catalog_writer()?;
+    catalog_writer
+        .alter_connector_props(source_id, ConnectorPropsObjectType::Source, changed_props)
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_SOURCE))
+}
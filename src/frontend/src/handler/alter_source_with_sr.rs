@@ -25,7 +25,7 @@ use risingwave_pb::catalog::StreamSourceInfo;
 use risingwave_pb::plan_common::{EncodeType, FormatType};
 use risingwave_sqlparser::ast::{
     CompatibleSourceSchema, ConnectorSchema, CreateSourceStatement, Encode, Format, ObjectName,
-    SqlOption, Statement,
+    Statement,
 };
 use risingwave_sqlparser::parser::Parser;
 
@@ -117,7 +117,8 @@ pub fn fetch_source_catalog_with_db_schema_id(
 }
 
 /// Check if the original source is created with `FORMAT .. ENCODE ..` clause,
-/// and if the FORMAT and ENCODE are modified.
+/// and if the FORMAT and ENCODE are modified, that the new ones can be resolved
+/// from a schema registry.
 pub fn check_format_encode(
     original_source: &SourceCatalog,
     new_connector_schema: &ConnectorSchema,
@@ -140,11 +141,22 @@ pub fn check_format_encode(
     if new_connector_schema.format != old_format
         || new_connector_schema.row_encode != old_row_encode
     {
-        bail_not_implemented!(
-            "the original definition is FORMAT {:?} ENCODE {:?}, and altering them is not supported yet",
-            &old_format,
-            &old_row_encode,
-        );
+        // Switching parsers on the fly requires resolving the new columns from a schema
+        // registry, so that the new schema can be checked against the existing one.
+        let mut new_options = WithOptions::try_from(new_connector_schema.row_options())?;
+        if !new_options.inner_mut().contains_key("schema.registry") {
+            return Err(ErrorCode::NotSupported(
+                format!(
+                    "altering from FORMAT {:?} ENCODE {:?} to FORMAT {:?} ENCODE {:?} without a schema registry",
+                    old_format,
+                    old_row_encode,
+                    new_connector_schema.format,
+                    new_connector_schema.row_encode,
+                ),
+                "provide a schema registry in the new FORMAT .. ENCODE .. clause".to_string(),
+            )
+            .into());
+        }
     }
 
     Ok(())
@@ -255,7 +267,7 @@ pub async fn handle_alter_source_with_sr(
     source.info = source_info;
     source.columns.extend(added_columns);
     source.definition =
-        alter_definition_format_encode(&source.definition, connector_schema.row_options.clone())?;
+        alter_definition_format_encode(&source.definition, connector_schema.clone())?;
 
     let (format_encode_options, format_encode_secret_ref) = resolve_secret_ref_in_with_options(
         WithOptions::try_from(connector_schema.row_options())?,
@@ -283,10 +295,11 @@ pub async fn handle_alter_source_with_sr(
     Ok(RwPgResponse::empty_result(StatementType::ALTER_SOURCE))
 }
 
-/// Apply the new `format_encode_options` to the source/table definition.
+/// Apply the new `FORMAT .. ENCODE ..` clause, including its options, to the source/table
+/// definition.
 pub fn alter_definition_format_encode(
     definition: &str,
-    format_encode_options: Vec<SqlOption>,
+    connector_schema: ConnectorSchema,
 ) -> Result<String> {
     let ast = Parser::parse_sql(definition).expect("failed to parse relation definition");
     let mut stmt = ast
@@ -304,7 +317,7 @@ pub fn alter_definition_format_encode(
         } => {
             match source_schema {
                 CompatibleSourceSchema::V2(schema) => {
-                    schema.row_options = format_encode_options;
+                    *schema = connector_schema;
                 }
                 // TODO: Confirm the behavior of legacy source schema.
                 // Legacy source schema should be rejected by the handler and never reaches here.
@@ -368,7 +381,10 @@ pub mod tests {
             .await
             .unwrap_err()
             .to_string()
-            .contains("the original definition is FORMAT Plain ENCODE Protobuf"));
+            .contains(
+                "altering from FORMAT Plain ENCODE Protobuf to FORMAT Upsert ENCODE Protobuf \
+                 without a schema registry"
+            ));
 
         let sql = format!(
             r#"ALTER SOURCE src FORMAT PLAIN ENCODE PROTOBUF (
@@ -0,0 +1,76 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::StatementType;
+use risingwave_pb::catalog::PbStreamJobPriority;
+use risingwave_sqlparser::ast::{Ident, ObjectName};
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::error::{ErrorCode, Result};
+use crate::Binder;
+
+pub async fn handle_alter_stream_job_priority(
+    handler_args: HandlerArgs,
+    obj_name: ObjectName,
+    priority: Ident,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, obj_name.clone())?;
+    let search_path = session.config().search_path();
+    let user_name = &session.auth_context().user_name;
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let priority = match priority.real_value().to_lowercase().as_str() {
+        "low" => PbStreamJobPriority::Low,
+        "normal" => PbStreamJobPriority::Normal,
+        "high" => PbStreamJobPriority::High,
+        value => {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "invalid priority \"{}\", expected low, normal or high",
+                value
+            ))
+            .into());
+        }
+    };
+
+    let table_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_created_table_by_name(db_name, schema_path, &real_table_name)?;
+
+        if table.table_type() != TableType::MaterializedView {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "\"{}\" is not a materialized view",
+                table.name()
+            ))
+            .into());
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+        table.id.table_id()
+    };
+
+    let catalog_writer = session.catalog_writer()?;
+    catalog_writer
+        .alter_stream_job_priority(table_id, priority)
+        .await?;
+
+    Ok(RwPgResponse::empty_result(
+        StatementType::ALTER_MATERIALIZED_VIEW,
+    ))
+}
@@ -72,6 +72,13 @@ pub async fn handle_alter_streaming_rate_limit(
             };
             (StatementType::ALTER_SOURCE, source_id)
         }
+        PbThrottleTarget::Sink => {
+            let reader = session.env().catalog_reader().read_guard();
+            let (sink, schema_name) =
+                reader.get_sink_by_name(db_name, schema_path, &real_table_name)?;
+            session.check_privilege_for_drop_alter(schema_name, &**sink)?;
+            (StatementType::ALTER_SINK, sink.id.sink_id)
+        }
         PbThrottleTarget::CdcTable => {
             let reader = session.env().catalog_reader().read_guard();
             let (table, schema_name) =
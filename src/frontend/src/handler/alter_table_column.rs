@@ -29,7 +29,7 @@ use risingwave_pb::stream_plan::stream_node::PbNodeBody;
 use risingwave_pb::stream_plan::{ProjectNode, StreamFragmentGraph};
 use risingwave_sqlparser::ast::{
     AlterTableOperation, ColumnDef, ColumnOption, ConnectorSchema, DataType as AstDataType, Encode,
-    ObjectName, Statement, StructField,
+    Ident, ObjectName, Statement, StructField,
 };
 use risingwave_sqlparser::parser::Parser;
 
@@ -51,7 +51,17 @@ pub async fn replace_table_with_definition(
     definition: Statement,
     original_catalog: &Arc<TableCatalog>,
     source_schema: Option<ConnectorSchema>,
+    distributed_by: Option<Vec<String>>,
 ) -> Result<()> {
+    let catalog_writer = session.catalog_writer()?;
+
+    // Take the alter lease before the expensive planning below, so that concurrent `ALTER`s
+    // on the same table from other sessions fail fast instead of racing until one of them is
+    // rejected by the replace procedure.
+    let alter_lease_token = catalog_writer
+        .acquire_alter_lease(original_catalog.id().table_id, false)
+        .await?;
+
     let (source, table, graph, col_index_mapping, job_type) = get_replace_table_plan(
         session,
         table_name,
@@ -59,13 +69,19 @@ pub async fn replace_table_with_definition(
         original_catalog,
         source_schema,
         None,
+        distributed_by,
     )
     .await?;
 
-    let catalog_writer = session.catalog_writer()?;
-
     catalog_writer
-        .replace_table(source, table, graph, col_index_mapping, job_type)
+        .replace_table(
+            source,
+            table,
+            graph,
+            col_index_mapping,
+            job_type,
+            alter_lease_token,
+        )
         .await?;
     Ok(())
 }
@@ -166,6 +182,7 @@ pub async fn get_replace_table_plan(
     original_catalog: &Arc<TableCatalog>,
     source_schema: Option<ConnectorSchema>,
     new_version_columns: Option<Vec<ColumnCatalog>>, // only provided in auto schema change
+    distributed_by: Option<Vec<String>>,
 ) -> Result<(
     Option<Source>,
     Table,
@@ -207,6 +224,7 @@ pub async fn get_replace_table_plan(
         with_version_column,
         cdc_table_info,
         new_version_columns,
+        distributed_by,
     )
     .await?;
 
@@ -457,6 +475,136 @@ pub async fn handle_alter_table_column(
         definition,
         &original_catalog,
         source_schema,
+        None,
+    )
+    .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_TABLE))
+}
+
+/// Handles `ALTER TABLE [..] SET DISTRIBUTED BY (..)`.
+///
+/// The table is replaced with a plan whose distribution key is overridden by the given
+/// columns: shadow state with the new distribution is created and backfilled from the
+/// existing state tables, and the cut-over happens at a checkpoint barrier with the catalog
+/// and fragment updates committed atomically.
+pub async fn handle_alter_table_distribution(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+    columns: Vec<Ident>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let original_catalog = fetch_table_catalog_for_alter(session.as_ref(), &table_name)?;
+
+    if !original_catalog.incoming_sinks.is_empty() {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "alter distribution key of the target table of sinks is not supported".to_string(),
+        ))?;
+    }
+
+    let distributed_by = columns.iter().map(|c| c.real_value()).collect_vec();
+    if distributed_by.iter().duplicates().next().is_some() {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "duplicate column in distribution key".to_string(),
+        ))?;
+    }
+    if distributed_by
+        .iter()
+        .sorted()
+        .eq(original_catalog.distribution_key().iter().map(|idx| {
+            original_catalog.columns()[*idx].name().to_string()
+        }).collect_vec().iter().sorted())
+    {
+        return Ok(PgResponse::builder(StatementType::ALTER_TABLE)
+            .notice("distribution key is unchanged, skipping".to_string())
+            .into());
+    }
+
+    // Retrieve the original table definition and parse it to AST.
+    let [definition]: [_; 1] = Parser::parse_sql(&original_catalog.definition)
+        .context("unable to parse original table definition")?
+        .try_into()
+        .unwrap();
+    let Statement::CreateTable { source_schema, .. } = &definition else {
+        panic!("unexpected statement: {:?}", definition);
+    };
+    let source_schema = source_schema
+        .clone()
+        .map(|source_schema| source_schema.into_v2_with_warning());
+
+    replace_table_with_definition(
+        &session,
+        table_name,
+        definition,
+        &original_catalog,
+        source_schema,
+        Some(distributed_by),
+    )
+    .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_TABLE))
+}
+
+/// Handles `ALTER TABLE [..] SET APPEND ONLY <true|false>`.
+///
+/// The append-only property is toggled by rewriting the table definition and replacing
+/// the table with the re-generated stream plan, preserving the table id and data: the new
+/// state is backfilled from the existing state tables and the cut-over happens at a
+/// checkpoint barrier. Conflicting clauses in the definition, e.g. `ON CONFLICT`, are
+/// rejected by the planner as they would be on creation.
+pub async fn handle_alter_table_append_only(
+    handler_args: HandlerArgs,
+    table_name: ObjectName,
+    new_append_only: bool,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let original_catalog = fetch_table_catalog_for_alter(session.as_ref(), &table_name)?;
+
+    if original_catalog.append_only == new_append_only {
+        return Ok(PgResponse::builder(StatementType::ALTER_TABLE)
+            .notice("append only property is unchanged, skipping".to_string())
+            .into());
+    }
+    if !original_catalog.incoming_sinks.is_empty() {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "alter append only property of the target table of sinks is not supported".to_string(),
+        ))?;
+    }
+
+    // Retrieve the original table definition, parse it to AST and toggle the property, so
+    // that both the re-generated plan and the persisted definition reflect the new value.
+    let [mut definition]: [_; 1] = Parser::parse_sql(&original_catalog.definition)
+        .context("unable to parse original table definition")?
+        .try_into()
+        .unwrap();
+    let Statement::CreateTable {
+        append_only,
+        source_schema,
+        cdc_table_info,
+        ..
+    } = &mut definition
+    else {
+        panic!("unexpected statement: {:?}", definition);
+    };
+    if source_schema.is_some() || cdc_table_info.is_some() {
+        return Err(ErrorCode::NotSupported(
+            "alter append only property of a table with connector".to_string(),
+            "try recreating the table".to_string(),
+        )
+        .into());
+    }
+    *append_only = new_append_only;
+    let source_schema = source_schema
+        .clone()
+        .map(|source_schema| source_schema.into_v2_with_warning());
+
+    replace_table_with_definition(
+        &session,
+        table_name,
+        definition,
+        &original_catalog,
+        source_schema,
+        None,
     )
     .await?;
 
@@ -0,0 +1,86 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use pgwire::pg_response::StatementType;
+use risingwave_common::types::Interval;
+use risingwave_sqlparser::ast::ObjectName;
+use thiserror_ext::AsReport;
+
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::error::{ErrorCode, Result};
+use crate::Binder;
+
+/// Handles `ALTER TABLE <name> SET TTL TO '<interval>' | DEFAULT`, which stores a retention
+/// time in the table's [`TableOption`](risingwave_common::catalog::TableOption) so that state
+/// rows older than the retention are reclaimed by TTL compaction. `DEFAULT` clears the TTL.
+pub async fn handle_alter_table_ttl(
+    handler_args: HandlerArgs,
+    obj_name: ObjectName,
+    retention: Option<String>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let db_name = session.database();
+    let (schema_name, real_table_name) =
+        Binder::resolve_schema_qualified_name(db_name, obj_name.clone())?;
+    let search_path = session.config().search_path();
+    let user_name = &session.auth_context().user_name;
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let retention_seconds = retention
+        .map(|retention| {
+            let interval = Interval::from_str(&retention).map_err(|err| {
+                ErrorCode::InvalidInputSyntax(format!(
+                    "TTL needs to be set in interval format: {}",
+                    err.to_report_string()
+                ))
+            })?;
+            let seconds = interval.epoch_in_micros() / 1_000_000;
+            u32::try_from(seconds).ok().filter(|s| *s > 0).ok_or_else(|| {
+                ErrorCode::InvalidInputSyntax(format!(
+                    "invalid TTL \"{}\", expected a positive interval of at most {} seconds",
+                    retention,
+                    u32::MAX
+                ))
+            })
+        })
+        .transpose()?;
+
+    let table_id = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_created_table_by_name(db_name, schema_path, &real_table_name)?;
+
+        if table.table_type() != TableType::Table {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "\"{}\" is not a table",
+                table.name()
+            ))
+            .into());
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+        table.id.table_id()
+    };
+
+    let catalog_writer = session.catalog_writer()?;
+    catalog_writer
+        .alter_table_ttl(table_id, retention_seconds)
+        .await?;
+
+    Ok(RwPgResponse::empty_result(StatementType::ALTER_TABLE))
+}
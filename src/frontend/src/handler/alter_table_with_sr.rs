@@ -66,10 +66,8 @@ pub async fn handle_refresh_schema(
         connector_schema.unwrap()
     };
 
-    let definition = alter_definition_format_encode(
-        &original_table.definition,
-        connector_schema.row_options.clone(),
-    )?;
+    let definition =
+        alter_definition_format_encode(&original_table.definition, connector_schema.clone())?;
 
     let [definition]: [_; 1] = Parser::parse_sql(&definition)
         .context("unable to parse original table definition")?
@@ -82,6 +80,7 @@ pub async fn handle_refresh_schema(
         definition,
         &original_table,
         Some(connector_schema),
+        None,
     )
     .await;
 
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_pb::catalog::comment::PbObjectType;
 use risingwave_pb::catalog::PbComment;
 use risingwave_sqlparser::ast::{CommentObject, ObjectName};
 
 use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
 use crate::error::{ErrorCode, Result};
 use crate::Binder;
 
@@ -31,7 +33,6 @@ pub async fn handle_comment(
 
     let comment = {
         let mut binder = Binder::new_for_ddl(&session);
-        // only `Column` and `Table` object are now supported
         match object_type {
             CommentObject::Column => {
                 let [tab @ .., col] = object_name.0.as_slice() else {
@@ -60,6 +61,7 @@ pub async fn handle_comment(
                     database_id,
                     column_index: column.as_input_ref().map(|input_ref| input_ref.index as _),
                     description: comment,
+                    object_type: PbObjectType::Unspecified.into(),
                 }
             }
             CommentObject::Table => {
@@ -75,6 +77,88 @@ pub async fn handle_comment(
                     database_id,
                     column_index: None,
                     description: comment,
+                    object_type: PbObjectType::Unspecified.into(),
+                }
+            }
+            CommentObject::Schema => {
+                let schema_name = Binder::resolve_schema_name(object_name)?;
+                let reader = session.env().catalog_reader().read_guard();
+                let schema = reader.get_schema_by_name(session.database(), &schema_name)?;
+
+                PbComment {
+                    table_id: schema.id(),
+                    schema_id: schema.id(),
+                    database_id: schema.database_id(),
+                    column_index: None,
+                    description: comment,
+                    object_type: PbObjectType::Schema.into(),
+                }
+            }
+            object_type => {
+                let db_name = session.database();
+                let (schema_name, name) =
+                    Binder::resolve_schema_qualified_name(db_name, object_name)?;
+                let search_path = session.config().search_path();
+                let user_name = &session.auth_context().user_name;
+                let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+                let reader = session.env().catalog_reader().read_guard();
+                let (object_id, schema_name, pb_object_type) = match object_type {
+                    CommentObject::Source => {
+                        let (source, schema_name) =
+                            reader.get_source_by_name(db_name, schema_path, &name)?;
+                        (source.id, schema_name, PbObjectType::Source)
+                    }
+                    CommentObject::Sink => {
+                        let (sink, schema_name) =
+                            reader.get_sink_by_name(db_name, schema_path, &name)?;
+                        (sink.id.sink_id, schema_name, PbObjectType::Sink)
+                    }
+                    CommentObject::View => {
+                        let (view, schema_name) =
+                            reader.get_view_by_name(db_name, schema_path, &name)?;
+                        (view.id, schema_name, PbObjectType::View)
+                    }
+                    CommentObject::Index => {
+                        let (index, schema_name) =
+                            reader.get_index_by_name(db_name, schema_path, &name)?;
+                        (index.id.index_id, schema_name, PbObjectType::Index)
+                    }
+                    CommentObject::Function => {
+                        let (functions, schema_name) =
+                            reader.get_functions_by_name(db_name, schema_path, &name)?;
+                        if functions.len() > 1 {
+                            return Err(ErrorCode::BindError(format!(
+                                "function name \"{}\" is not unique\nHINT: could not choose a best candidate function",
+                                name
+                            ))
+                            .into());
+                        }
+                        (functions[0].id.0, schema_name, PbObjectType::Function)
+                    }
+                    CommentObject::Connection => {
+                        let (connection, schema_name) =
+                            reader.get_connection_by_name(db_name, schema_path, &name)?;
+                        (connection.id, schema_name, PbObjectType::Connection)
+                    }
+                    CommentObject::Secret => {
+                        let (secret, schema_name) =
+                            reader.get_secret_by_name(db_name, schema_path, &name)?;
+                        (secret.id.secret_id(), schema_name, PbObjectType::Secret)
+                    }
+                    CommentObject::Column | CommentObject::Table | CommentObject::Schema => {
+                        unreachable!()
+                    }
+                };
+                let schema = reader.get_schema_by_name(db_name, schema_name)?;
+
+                PbComment {
+                    table_id: object_id,
+                    schema_id: schema.id(),
+                    database_id: schema.database_id(),
+                    column_index: None,
+                    description: comment,
+                    object_type: pb_object_type.into(),
                 }
             }
         }
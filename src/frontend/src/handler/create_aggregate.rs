@@ -128,14 +128,14 @@ pub async fn handle_create_aggregate(
         link: link.map(|s| s.to_string()),
         body: output.body,
         compressed_binary: output.compressed_binary,
-        owner: session.user_id(),
+        owner: session.owner_for_create(database_id, schema_id)?,
         always_retry_on_network_error: false,
         runtime: None,
         function_type,
     };
 
     let catalog_writer = session.catalog_writer()?;
-    catalog_writer.create_function(function).await?;
+    catalog_writer.create_function(function, false).await?;
 
     Ok(PgResponse::empty_result(StatementType::CREATE_AGGREGATE))
 }
@@ -0,0 +1,39 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_sqlparser::ast::Ident;
+
+use super::RwPgResponse;
+use crate::error::Result;
+use crate::handler::HandlerArgs;
+
+pub async fn handle_create_checkpoint(
+    handler_args: HandlerArgs,
+    name: Ident,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let checkpoint = session
+        .env()
+        .meta_client()
+        .create_named_checkpoint(name.real_value())
+        .await?;
+
+    Ok(PgResponse::builder(StatementType::CREATE_CHECKPOINT)
+        .notice(format!(
+            "created checkpoint {} at epoch {} (hummock version {})",
+            checkpoint.name, checkpoint.epoch, checkpoint.hummock_version_id
+        ))
+        .into())
+}
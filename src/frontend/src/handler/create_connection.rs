@@ -134,7 +134,7 @@ pub async fn handle_create_connection(
             connection_name,
             database_id,
             schema_id,
-            session.user_id(),
+            session.owner_for_create(database_id, schema_id)?,
             create_connection_payload,
         )
         .await?;
@@ -150,7 +150,7 @@ pub fn gen_create_mv_plan_bound(
     let dependent_relations =
         RelationCollectorVisitor::collect_with(dependent_relations, plan.clone());
 
-    table.owner = session.user_id();
+    table.owner = session.owner_for_create(database_id, schema_id)?;
 
     // record dependent relations.
     table.dependent_relations = dependent_relations
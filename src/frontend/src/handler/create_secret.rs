@@ -26,6 +26,7 @@ const SECRET_BACKEND_KEY: &str = "backend";
 
 const SECRET_BACKEND_META: &str = "meta";
 const SECRET_BACKEND_HASHICORP_VAULT: &str = "hashicorp_vault";
+const SECRET_BACKEND_EXTERNAL: &str = "external";
 
 pub async fn handle_create_secret(
     handler_args: HandlerArgs,
@@ -65,6 +66,26 @@ pub async fn handle_create_secret(
                     };
                     backend.encode_to_vec()
                 }
+                SECRET_BACKEND_EXTERNAL => {
+                    // Only the reference (e.g. `vault://path#key`) is stored; the
+                    // plaintext is resolved lazily on the nodes using the secret.
+                    let reference = secret_to_str(&stmt.credential)?;
+                    if !reference.contains("://") {
+                        return Err(ErrorCode::InvalidParameterValue(format!(
+                            "external secret reference \"{}\" must be of the form \"scheme://path\"",
+                            reference
+                        ))
+                        .into());
+                    }
+                    let backend = risingwave_pb::secret::Secret {
+                        secret_backend: Some(
+                            risingwave_pb::secret::secret::SecretBackend::External(
+                                risingwave_pb::secret::SecretExternalBackend { reference },
+                            ),
+                        ),
+                    };
+                    backend.encode_to_vec()
+                }
                 SECRET_BACKEND_HASHICORP_VAULT => {
                     if stmt.credential != Value::Null {
                         return Err(ErrorCode::InvalidParameterValue(
@@ -78,7 +99,12 @@ pub async fn handle_create_secret(
                     return Err(ErrorCode::InvalidParameterValue(format!(
                         "secret backend \"{}\" is not supported. Supported backends are: {}",
                         backend,
-                        [SECRET_BACKEND_META, SECRET_BACKEND_HASHICORP_VAULT].join(",")
+                        [
+                            SECRET_BACKEND_META,
+                            SECRET_BACKEND_EXTERNAL,
+                            SECRET_BACKEND_HASHICORP_VAULT,
+                        ]
+                        .join(",")
                     ))
                     .into());
                 }
@@ -86,7 +112,12 @@ pub async fn handle_create_secret(
         } else {
             return Err(ErrorCode::InvalidParameterValue(format!(
                 "secret backend is not specified in with clause. Supported backends are: {}",
-                [SECRET_BACKEND_META, SECRET_BACKEND_HASHICORP_VAULT].join(",")
+                [
+                    SECRET_BACKEND_META,
+                    SECRET_BACKEND_EXTERNAL,
+                    SECRET_BACKEND_HASHICORP_VAULT,
+                ]
+                .join(",")
             ))
             .into());
         }
@@ -100,7 +131,7 @@ pub async fn handle_create_secret(
             stmt.secret_name.real_value(),
             database_id,
             schema_id,
-            session.user_id(),
+            session.owner_for_create(database_id, schema_id)?,
             secret_payload,
         )
         .await?;
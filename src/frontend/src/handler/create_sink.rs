@@ -265,7 +265,7 @@ pub async fn gen_sink_plan(
     let sink_catalog = sink_desc.into_catalog(
         SchemaId::new(sink_schema_id),
         DatabaseId::new(sink_database_id),
-        UserId::new(session.user_id()),
+        UserId::new(session.owner_for_create(sink_database_id, sink_schema_id)?),
         connection_id,
         dependent_relations.into_iter().collect_vec(),
     );
@@ -489,6 +489,8 @@ pub async fn handle_create_sink(
             fragment_graph: Some(graph),
             table_col_index_mapping: None,
             job_type: TableJobType::General as _,
+            // Sink-into-table replaces the table without acquiring an alter lease.
+            alter_lease_token: 0,
         });
     }
 
@@ -691,6 +693,7 @@ pub(crate) async fn reparse_table_for_sink(
         with_version_column,
         None,
         None,
+        None,
     )
     .await?;
 
@@ -1621,7 +1621,7 @@ pub async fn bind_create_source_or_table_with_connector(
         columns,
         pk_col_ids,
         append_only: row_id_index.is_some(),
-        owner: session.user_id(),
+        owner: session.owner_for_create(database_id, schema_id)?,
         info: source_info,
         row_id_index,
         with_properties,
@@ -1635,6 +1635,7 @@ pub async fn bind_create_source_or_table_with_connector(
         created_at_cluster_version: None,
         initialized_at_cluster_version: None,
         rate_limit: source_rate_limit,
+        description: None,
     };
     Ok((source, database_id, schema_id))
 }
@@ -127,10 +127,6 @@ pub async fn handle_create_sql_function(
     returns: Option<CreateFunctionReturns>,
     params: CreateFunctionBody,
 ) -> Result<RwPgResponse> {
-    if or_replace {
-        bail_not_implemented!("CREATE OR REPLACE FUNCTION");
-    }
-
     if temporary {
         bail_not_implemented!("CREATE TEMPORARY FUNCTION");
     }
@@ -222,16 +218,22 @@ pub async fn handle_create_sql_function(
     let (database_id, schema_id) = session.get_database_and_schema_id_for_create(schema_name)?;
 
     // check if function exists
-    if (session.env().catalog_reader().read_guard())
-        .get_schema_by_id(&database_id, &schema_id)?
-        .get_function_by_name_args(&function_name, &arg_types)
-        .is_some()
     {
-        let name = format!(
-            "{function_name}({})",
-            arg_types.iter().map(|t| t.to_string()).join(",")
-        );
-        return Err(CatalogError::Duplicated("function", name).into());
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let schema = catalog_reader.get_schema_by_id(&database_id, &schema_id)?;
+        if let Some(function) = schema.get_function_by_name_args(&function_name, &arg_types) {
+            if or_replace {
+                // `OR REPLACE` swaps the function in place, so it requires the same
+                // privileges as `ALTER FUNCTION`.
+                session.check_privilege_for_drop_alter(schema.name().as_str(), &**function)?;
+            } else {
+                let name = format!(
+                    "{function_name}({})",
+                    arg_types.iter().map(|t| t.to_string()).join(",")
+                );
+                return Err(CatalogError::Duplicated("function", name).into());
+            }
+        }
     }
 
     // Parse function body here
@@ -340,14 +342,14 @@ pub async fn handle_create_sql_function(
         body: Some(body),
         compressed_binary: None,
         link: None,
-        owner: session.user_id(),
+        owner: session.owner_for_create(database_id, schema_id)?,
         always_retry_on_network_error: false,
         runtime: None,
         function_type: None,
     };
 
     let catalog_writer = session.catalog_writer()?;
-    catalog_writer.create_function(function).await?;
+    catalog_writer.create_function(function, or_replace).await?;
 
     Ok(PgResponse::empty_result(StatementType::CREATE_FUNCTION))
 }
@@ -54,10 +54,15 @@ pub fn create_subscription_catalog(
         name: subscription_name,
         definition,
         retention_seconds: 0,
+        retention_policy: Default::default(),
+        retention_epoch_count: 0,
+        acked_epoch: 0,
         database_id: subscription_database_id,
         schema_id: subscription_schema_id,
         dependent_table_id,
-        owner: UserId::new(session.user_id()),
+        owner: UserId::new(
+            session.owner_for_create(subscription_database_id, subscription_schema_id)?,
+        ),
         initialized_at_epoch: None,
         created_at_epoch: None,
         created_at_cluster_version: None,
@@ -65,6 +70,7 @@ pub fn create_subscription_catalog(
     };
 
     subscription_catalog.set_retention_seconds(context.with_options())?;
+    subscription_catalog.set_retention_policy(context.with_options())?;
 
     Ok(subscription_catalog)
 }
@@ -543,6 +543,7 @@ pub(crate) fn gen_create_table_plan(
     append_only: bool,
     on_conflict: Option<OnConflict>,
     with_version_column: Option<String>,
+    distributed_by: Option<Vec<String>>,
 ) -> Result<(PlanRef, PbTable)> {
     let definition = context.normalized_sql().to_owned();
     let mut columns = bind_sql_columns(&column_defs)?;
@@ -567,6 +568,7 @@ pub(crate) fn gen_create_table_plan(
         on_conflict,
         with_version_column,
         Some(col_id_gen.into_version()),
+        distributed_by,
     )
 }
 
@@ -583,6 +585,7 @@ pub(crate) fn gen_create_table_plan_without_source(
     on_conflict: Option<OnConflict>,
     with_version_column: Option<String>,
     version: Option<TableVersion>,
+    distributed_by: Option<Vec<String>>,
 ) -> Result<(PlanRef, PbTable)> {
     ensure_table_constraints_supported(&constraints)?;
     let pk_names = bind_sql_pk_names(&column_defs, &constraints)?;
@@ -737,11 +740,12 @@ fn gen_table_plan_inner(
         is_external_source,
         retention_seconds,
         None,
+        distributed_by,
     )?;
 
     let mut table = materialize.table().to_prost(schema_id, database_id);
 
-    table.owner = session.user_id();
+    table.owner = session.owner_for_create(database_id, schema_id)?;
     Ok((materialize.into(), table))
 }
 
@@ -862,10 +866,11 @@ pub(crate) fn gen_create_table_plan_for_cdc_table(
         true,
         None,
         Some(cdc_table_id),
+        None,
     )?;
 
     let mut table = materialize.table().to_prost(schema_id, database_id);
-    table.owner = session.user_id();
+    table.owner = session.owner_for_create(database_id, schema_id)?;
     table.dependent_relations = vec![source.id];
 
     Ok((materialize.into(), table))
@@ -979,6 +984,7 @@ pub(super) async fn handle_create_table_plan(
                     append_only,
                     on_conflict,
                     with_version_column,
+                    None,
                 )?;
 
                 ((plan, None, table), TableJobType::General)
@@ -1324,9 +1330,18 @@ pub async fn generate_stream_graph_for_table(
     with_version_column: Option<String>,
     cdc_table_info: Option<CdcTableInfo>,
     new_version_columns: Option<Vec<ColumnCatalog>>,
+    distributed_by: Option<Vec<String>>,
 ) -> Result<(StreamFragmentGraph, Table, Option<PbSource>, TableJobType)> {
     use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 
+    if distributed_by.is_some() && (source_schema.is_some() || cdc_table_info.is_some()) {
+        return Err(ErrorCode::NotSupported(
+            "alter distribution key of a table with connector".to_string(),
+            "try recreating the table".to_string(),
+        )
+        .into());
+    }
+
     let ((plan, source, table), job_type) = match (source_schema, cdc_table_info.as_ref()) {
         (Some(source_schema), None) => (
             gen_create_table_plan_with_source(
@@ -1359,6 +1374,7 @@ pub async fn generate_stream_graph_for_table(
                 append_only,
                 on_conflict,
                 with_version_column,
+                distributed_by,
             )?;
             ((plan, None, table), TableJobType::General)
         }
@@ -108,6 +108,7 @@ pub async fn handle_create_as(
             on_conflict,
             with_version_column,
             Some(col_id_gen.into_version()),
+            None,
         )?;
         let graph = build_graph(plan)?;
 
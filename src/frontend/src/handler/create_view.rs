@@ -101,13 +101,14 @@ pub async fn handle_create_view(
         database_id,
         name: view_name,
         properties,
-        owner: session.user_id(),
+        owner: session.owner_for_create(database_id, schema_id)?,
         dependent_relations: dependent_relations
             .into_iter()
             .map(|t| t.table_id)
             .collect_vec(),
         sql: format!("{}", query),
         columns: columns.into_iter().map(|f| f.to_prost()).collect(),
+        invalidated_reason: None,
     };
 
     let catalog_writer = session.catalog_writer()?;
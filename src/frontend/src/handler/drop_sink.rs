@@ -99,6 +99,8 @@ pub async fn handle_drop_sink(
             fragment_graph: Some(graph),
             table_col_index_mapping: None,
             job_type: TableJobType::General as _,
+            // Sink-into-table replaces the table without acquiring an alter lease.
+            alter_lease_token: 0,
         });
     }
 
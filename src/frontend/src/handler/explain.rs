@@ -33,7 +33,7 @@ use crate::optimizer::plan_node::{Convention, Explain};
 use crate::optimizer::OptimizerContext;
 use crate::scheduler::BatchPlanFragmenter;
 use crate::stream_fragmenter::build_graph;
-use crate::utils::explain_stream_graph;
+use crate::utils::{explain_resource_estimate, explain_stream_graph};
 use crate::OptimizerContextRef;
 
 async fn do_handle_explain(
@@ -45,6 +45,7 @@ async fn do_handle_explain(
     // Workaround to avoid `Rc` across `await` point.
     let mut batch_plan_fragmenter = None;
     let session = handler_args.session.clone();
+    let explain_estimate = explain_options.estimate;
 
     {
         let (plan, context) = match stmt {
@@ -185,6 +186,26 @@ async fn do_handle_explain(
             blocks.extend(trace);
         }
 
+        if explain_estimate {
+            let plan = plan?;
+            if !matches!(plan.convention(), Convention::Stream) {
+                return Err(ErrorCode::NotSupported(
+                    "EXPLAIN (ESTIMATE) for batch queries".into(),
+                    "EXPLAIN (ESTIMATE) only applies to streaming DDL statements.".into(),
+                )
+                .into());
+            }
+            let graph = build_graph(plan)?;
+            let worker_nodes = session.env().worker_node_manager_ref().list_worker_nodes();
+            let total_parallelism = worker_nodes.iter().map(|node| node.parallelism()).sum();
+            blocks.push(explain_resource_estimate(
+                &graph,
+                worker_nodes.len(),
+                total_parallelism,
+            ));
+            return Ok(());
+        }
+
         match explain_type {
             ExplainType::DistSql => {
                 if let Ok(plan) = &plan {
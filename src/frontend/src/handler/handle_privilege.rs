@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_pb::user::grant_privilege::{ActionWithGrantOption, PbObject};
-use risingwave_pb::user::PbGrantPrivilege;
-use risingwave_sqlparser::ast::{GrantObjects, Privileges, Statement};
+use risingwave_common::acl;
+use risingwave_common::acl::AclMode;
+use risingwave_pb::user::default_privilege::PbRelationKind;
+use risingwave_pb::user::grant_privilege::{ActionWithGrantOption, PbAction, PbObject};
+use risingwave_pb::user::{AlterDefaultPrivilegesRequest, PbGrantPrivilege};
+use risingwave_sqlparser::ast::{DefaultPrivilegeObjects, GrantObjects, Privileges, Statement};
 
 use super::RwPgResponse;
 use crate::binder::Binder;
@@ -136,6 +140,35 @@ fn make_prost_privilege(
                 grant_objs.push(PbObject::SourceId(source.id));
             }
         }
+        GrantObjects::Subscriptions(subscriptions) => {
+            let db_name = session.database();
+            let search_path = session.config().search_path();
+            let user_name = &session.auth_context().user_name;
+
+            for name in subscriptions {
+                let (schema_name, subscription_name) =
+                    Binder::resolve_schema_qualified_name(db_name, name)?;
+                let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+                let (subscription, _) =
+                    reader.get_subscription_by_name(db_name, schema_path, &subscription_name)?;
+                grant_objs.push(PbObject::SubscriptionId(subscription.id.subscription_id));
+            }
+        }
+        GrantObjects::Secrets(secrets) => {
+            let db_name = session.database();
+            let search_path = session.config().search_path();
+            let user_name = &session.auth_context().user_name;
+
+            for name in secrets {
+                let (schema_name, secret_name) =
+                    Binder::resolve_schema_qualified_name(db_name, name)?;
+                let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+                let (secret, _) = reader.get_secret_by_name(db_name, schema_path, &secret_name)?;
+                grant_objs.push(PbObject::SecretId(secret.id.secret_id()));
+            }
+        }
         GrantObjects::AllSourcesInSchema { schemas } => {
             for schema in schemas {
                 let schema_name = Binder::resolve_schema_name(schema)?;
@@ -221,7 +254,13 @@ pub async fn handle_grant_privilege(
     let privileges = make_prost_privilege(&session, privileges, objects)?;
     let user_info_writer = session.user_info_writer()?;
     user_info_writer
-        .grant_privilege(users, privileges, with_grant_option, session.user_id())
+        .grant_privilege(
+            users,
+            privileges,
+            with_grant_option,
+            session.user_id(),
+            session.config().expand_grant_to_owned_relations(),
+        )
         .await?;
     Ok(PgResponse::empty_result(StatementType::GRANT_PRIVILEGE))
 }
@@ -278,6 +317,181 @@ pub async fn handle_revoke_privilege(
     Ok(PgResponse::empty_result(StatementType::REVOKE_PRIVILEGE))
 }
 
+pub async fn handle_grant_role(handler_args: HandlerArgs, stmt: Statement) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let Statement::GrantRole {
+        roles,
+        grantees,
+        granted_by,
+    } = stmt
+    else {
+        return Err(ErrorCode::BindError("Invalid grant statement".to_string()).into());
+    };
+    let mut role_ids = vec![];
+    let mut users = vec![];
+    {
+        let user_reader = session.env().user_info_reader();
+        let reader = user_reader.read_guard();
+        for role in roles {
+            if let Some(role) = reader.get_user_by_name(&role.real_value()) {
+                role_ids.push(role.id);
+            } else {
+                return Err(ErrorCode::BindError("Role does not exist".to_string()).into());
+            }
+        }
+        for grantee in grantees {
+            if let Some(user) = reader.get_user_by_name(&grantee.real_value()) {
+                users.push(user.id);
+            } else {
+                return Err(ErrorCode::BindError("Grantee does not exist".to_string()).into());
+            }
+        }
+        if let Some(granted_by) = &granted_by {
+            // We remark that the user name is always case-sensitive.
+            if reader.get_user_by_name(&granted_by.real_value()).is_none() {
+                return Err(ErrorCode::BindError("Grantor does not exist".to_string()).into());
+            }
+        }
+    };
+
+    let user_info_writer = session.user_info_writer()?;
+    user_info_writer
+        .grant_role(role_ids, users, session.user_id())
+        .await?;
+    Ok(PgResponse::empty_result(StatementType::GRANT_PRIVILEGE))
+}
+
+pub async fn handle_revoke_role(
+    handler_args: HandlerArgs,
+    stmt: Statement,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let Statement::RevokeRole {
+        roles,
+        grantees,
+        granted_by,
+    } = stmt
+    else {
+        return Err(ErrorCode::BindError("Invalid revoke statement".to_string()).into());
+    };
+    let mut role_ids = vec![];
+    let mut users = vec![];
+    {
+        let user_reader = session.env().user_info_reader();
+        let reader = user_reader.read_guard();
+        for role in roles {
+            if let Some(role) = reader.get_user_by_name(&role.real_value()) {
+                role_ids.push(role.id);
+            } else {
+                return Err(ErrorCode::BindError("Role does not exist".to_string()).into());
+            }
+        }
+        for grantee in grantees {
+            if let Some(user) = reader.get_user_by_name(&grantee.real_value()) {
+                users.push(user.id);
+            } else {
+                return Err(ErrorCode::BindError("Grantee does not exist".to_string()).into());
+            }
+        }
+        if let Some(granted_by) = &granted_by {
+            if reader.get_user_by_name(&granted_by.real_value()).is_none() {
+                return Err(ErrorCode::BindError("Grantor does not exist".to_string()).into());
+            }
+        }
+    };
+
+    let user_info_writer = session.user_info_writer()?;
+    user_info_writer
+        .revoke_role(role_ids, users, session.user_id())
+        .await?;
+    Ok(PgResponse::empty_result(StatementType::REVOKE_PRIVILEGE))
+}
+
+pub async fn handle_alter_default_privileges(
+    handler_args: HandlerArgs,
+    stmt: Statement,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session;
+    let Statement::AlterDefaultPrivileges {
+        schemas,
+        grant,
+        privileges,
+        objects,
+        grantees,
+        with_grant_option,
+    } = stmt
+    else {
+        return Err(ErrorCode::BindError("Invalid alter statement".to_string()).into());
+    };
+
+    let (kind, acl_set) = match objects {
+        DefaultPrivilegeObjects::Tables => {
+            (PbRelationKind::Tables, &acl::ALL_AVAILABLE_TABLE_MODES)
+        }
+        DefaultPrivilegeObjects::Sources => {
+            (PbRelationKind::Sources, &acl::ALL_AVAILABLE_SOURCE_MODES)
+        }
+        DefaultPrivilegeObjects::Sinks => (PbRelationKind::Sinks, &acl::ALL_AVAILABLE_SINK_MODES),
+    };
+    let actions = match privileges {
+        Privileges::All { .. } => acl_set
+            .iter()
+            .map(|mode| <AclMode as Into<PbAction>>::into(mode) as i32)
+            .collect(),
+        Privileges::Actions(actions) => {
+            let actions = actions.iter().map(get_prost_action).collect_vec();
+            if actions
+                .iter()
+                .any(|action| !acl_set.has_mode((*action).into()))
+            {
+                return Err(ErrorCode::BindError(
+                    "Invalid privilege type for the given object.".to_string(),
+                )
+                .into());
+            }
+            actions.into_iter().map(|action| action as i32).collect()
+        }
+    };
+
+    let mut schema_ids = vec![];
+    {
+        let catalog_reader = session.env().catalog_reader();
+        let reader = catalog_reader.read_guard();
+        for schema in schemas {
+            let schema_name = Binder::resolve_schema_name(schema)?;
+            let schema = reader.get_schema_by_name(session.database(), &schema_name)?;
+            schema_ids.push(schema.id());
+        }
+    }
+    let mut grantee_ids = vec![];
+    {
+        let user_reader = session.env().user_info_reader();
+        let reader = user_reader.read_guard();
+        for grantee in grantees {
+            if let Some(user) = reader.get_user_by_name(&grantee.real_value()) {
+                grantee_ids.push(user.id);
+            } else {
+                return Err(ErrorCode::BindError("Grantee does not exist".to_string()).into());
+            }
+        }
+    }
+
+    let request = AlterDefaultPrivilegesRequest {
+        schema_ids,
+        kind: kind as i32,
+        actions,
+        grantee_ids,
+        granted_by: session.user_id(),
+        with_grant_option,
+        revoke: !grant,
+    };
+    let user_info_writer = session.user_info_writer()?;
+    user_info_writer.alter_default_privileges(request).await?;
+    Ok(PgResponse::empty_result(
+        StatementType::ALTER_DEFAULT_PRIVILEGES,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::catalog::DEFAULT_SUPER_USER_ID;
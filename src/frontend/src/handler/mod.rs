@@ -44,16 +44,20 @@ mod alter_parallelism;
 mod alter_rename;
 mod alter_set_schema;
 mod alter_source_column;
+mod alter_source_props;
 mod alter_source_with_sr;
+mod alter_stream_job_priority;
 mod alter_streaming_rate_limit;
 mod alter_system;
 mod alter_table_column;
+mod alter_table_ttl;
 mod alter_table_with_sr;
 pub mod alter_user;
 pub mod cancel_job;
 pub mod close_cursor;
 mod comment;
 pub mod create_aggregate;
+mod create_checkpoint;
 pub mod create_connection;
 mod create_database;
 pub mod create_function;
@@ -415,6 +419,15 @@ pub async fn handle(
         Statement::Revoke { .. } => {
             handle_privilege::handle_revoke_privilege(handler_args, stmt).await
         }
+        Statement::GrantRole { .. } => {
+            handle_privilege::handle_grant_role(handler_args, stmt).await
+        }
+        Statement::RevokeRole { .. } => {
+            handle_privilege::handle_revoke_role(handler_args, stmt).await
+        }
+        Statement::AlterDefaultPrivileges { .. } => {
+            handle_privilege::handle_alter_default_privileges(handler_args, stmt).await
+        }
         Statement::Describe { name } => describe::handle_describe(handler_args, name),
         Statement::Discard(..) => discard::handle_discard(handler_args),
         Statement::ShowObjects {
@@ -571,6 +584,9 @@ pub async fn handle(
             }
         }
         Statement::Flush => flush::handle_flush(handler_args).await,
+        Statement::CreateCheckpoint { name } => {
+            create_checkpoint::handle_create_checkpoint(handler_args, name).await
+        }
         Statement::Wait => wait::handle_wait(handler_args).await,
         Statement::Recover => recover::handle_recover(handler_args).await,
         Statement::SetVariable {
@@ -619,6 +635,7 @@ pub async fn handle(
                 name,
                 new_owner_name,
                 StatementType::ALTER_DATABASE,
+                None,
             )
             .await
         }
@@ -635,15 +652,35 @@ pub async fn handle(
                 name,
                 new_owner_name,
                 StatementType::ALTER_SCHEMA,
+                None,
             )
             .await
         }
+        Statement::AlterSchema {
+            name,
+            operation: AlterSchemaOperation::ChangeDefaultOwner { new_owner_name },
+        } => {
+            alter_owner::handle_alter_schema_default_owner(handler_args, name, new_owner_name).await
+        }
         Statement::AlterTable {
             name,
             operation:
                 operation @ (AlterTableOperation::AddColumn { .. }
                 | AlterTableOperation::DropColumn { .. }),
         } => alter_table_column::handle_alter_table_column(handler_args, name, operation).await,
+        Statement::AlterTable {
+            name,
+            operation: AlterTableOperation::SetDistributedBy { columns },
+        } => {
+            alter_table_column::handle_alter_table_distribution(handler_args, name, columns).await
+        }
+        Statement::AlterTable {
+            name,
+            operation: AlterTableOperation::SetAppendOnly { append_only },
+        } => {
+            alter_table_column::handle_alter_table_append_only(handler_args, name, append_only)
+                .await
+        }
         Statement::AlterTable {
             name,
             operation: AlterTableOperation::RenameTable { table_name },
@@ -660,6 +697,7 @@ pub async fn handle(
                 name,
                 new_owner_name,
                 StatementType::ALTER_TABLE,
+                None,
             )
             .await
         }
@@ -721,6 +759,10 @@ pub async fn handle(
             )
             .await
         }
+        Statement::AlterTable {
+            name,
+            operation: AlterTableOperation::SetTtl { retention },
+        } => alter_table_ttl::handle_alter_table_ttl(handler_args, name, retention).await,
         Statement::AlterIndex {
             name,
             operation: AlterIndexOperation::RenameIndex { index_name },
@@ -788,6 +830,7 @@ pub async fn handle(
                     name,
                     new_owner_name,
                     StatementType::ALTER_MATERIALIZED_VIEW,
+                    None,
                 )
                 .await
             } else {
@@ -796,6 +839,7 @@ pub async fn handle(
                     name,
                     new_owner_name,
                     StatementType::ALTER_VIEW,
+                    None,
                 )
                 .await
             }
@@ -838,6 +882,18 @@ pub async fn handle(
             )
             .await
         }
+        Statement::AlterView {
+            materialized,
+            name,
+            operation: AlterViewOperation::SetPriority { priority },
+        } if materialized => {
+            alter_stream_job_priority::handle_alter_stream_job_priority(
+                handler_args,
+                name,
+                priority,
+            )
+            .await
+        }
         Statement::AlterSink {
             name,
             operation: AlterSinkOperation::RenameSink { sink_name },
@@ -852,6 +908,7 @@ pub async fn handle(
                 name,
                 new_owner_name,
                 StatementType::ALTER_SINK,
+                None,
             )
             .await
         }
@@ -885,6 +942,18 @@ pub async fn handle(
             )
             .await
         }
+        Statement::AlterSink {
+            name,
+            operation: AlterSinkOperation::SetSinkRateLimit { rate_limit },
+        } => {
+            alter_streaming_rate_limit::handle_alter_streaming_rate_limit(
+                handler_args,
+                PbThrottleTarget::Sink,
+                name,
+                rate_limit,
+            )
+            .await
+        }
         Statement::AlterSubscription {
             name,
             operation: AlterSubscriptionOperation::RenameSubscription { subscription_name },
@@ -898,6 +967,7 @@ pub async fn handle(
                 name,
                 new_owner_name,
                 StatementType::ALTER_SUBSCRIPTION,
+                None,
             )
             .await
         }
@@ -931,6 +1001,7 @@ pub async fn handle(
                 name,
                 new_owner_name,
                 StatementType::ALTER_SOURCE,
+                None,
             )
             .await
         }
@@ -958,6 +1029,17 @@ pub async fn handle(
             name,
             operation: AlterSourceOperation::RefreshSchema,
         } => alter_source_with_sr::handler_refresh_schema(handler_args, name).await,
+        Statement::AlterSource {
+            name,
+            operation: AlterSourceOperation::SetWithProperties { with_properties },
+        } => {
+            alter_source_props::handle_alter_source_connector_props(
+                handler_args,
+                name,
+                with_properties,
+            )
+            .await
+        }
         Statement::AlterSource {
             name,
             operation: AlterSourceOperation::SetSourceRateLimit { rate_limit },
@@ -970,6 +1052,11 @@ pub async fn handle(
             )
             .await
         }
+        Statement::AlterFunction {
+            name,
+            args,
+            operation: AlterFunctionOperation::RenameFunction { function_name },
+        } => alter_rename::handle_rename_function(handler_args, name, function_name, args).await,
         Statement::AlterFunction {
             name,
             args,
@@ -984,6 +1071,20 @@ pub async fn handle(
             )
             .await
         }
+        Statement::AlterFunction {
+            name,
+            args,
+            operation: AlterFunctionOperation::ChangeOwner { new_owner_name },
+        } => {
+            alter_owner::handle_alter_owner(
+                handler_args,
+                name,
+                new_owner_name,
+                StatementType::ALTER_FUNCTION,
+                args,
+            )
+            .await
+        }
         Statement::AlterConnection {
             name,
             operation: AlterConnectionOperation::SetSchema { new_schema_name },
@@ -997,6 +1098,32 @@ pub async fn handle(
             )
             .await
         }
+        Statement::AlterConnection {
+            name,
+            operation: AlterConnectionOperation::ChangeOwner { new_owner_name },
+        } => {
+            alter_owner::handle_alter_owner(
+                handler_args,
+                name,
+                new_owner_name,
+                StatementType::ALTER_CONNECTION,
+                None,
+            )
+            .await
+        }
+        Statement::AlterSecret {
+            name,
+            operation: AlterSecretOperation::ChangeOwner { new_owner_name },
+        } => {
+            alter_owner::handle_alter_owner(
+                handler_args,
+                name,
+                new_owner_name,
+                StatementType::ALTER_SECRET,
+                None,
+            )
+            .await
+        }
         Statement::AlterSystem { param, value } => {
             alter_system::handle_alter_system(handler_args, param, value).await
         }
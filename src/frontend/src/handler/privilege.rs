@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{HashSet, VecDeque};
+
 use risingwave_common::acl::AclMode;
 use risingwave_pb::user::grant_privilege::PbObject;
 
@@ -148,7 +150,26 @@ impl SessionImpl {
                 if item.owner == user.id {
                     continue;
                 }
-                let has_privilege = user.check_privilege(&item.object, item.mode);
+                let mut has_privilege = user.check_privilege(&item.object, item.mode);
+                if !has_privilege {
+                    // Fall back to privileges inherited from granted roles, resolved
+                    // transitively.
+                    let mut visited = HashSet::from([user.id]);
+                    let mut queue: VecDeque<UserId> = user.granted_roles.iter().copied().collect();
+                    while let Some(role_id) = queue.pop_front() {
+                        if !visited.insert(role_id) {
+                            continue;
+                        }
+                        let Some(role) = reader.get_user_by_id(role_id) else {
+                            continue;
+                        };
+                        if role.check_privilege(&item.object, item.mode) {
+                            has_privilege = true;
+                            break;
+                        }
+                        queue.extend(role.granted_roles.iter().copied());
+                    }
+                }
                 if !has_privilege {
                     return Err(PermissionDenied("Do not have the privilege".to_string()).into());
                 }
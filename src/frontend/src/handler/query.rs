@@ -311,7 +311,7 @@ pub struct BatchPlanFragmenterResult {
     pub(crate) query_mode: QueryMode,
     pub(crate) schema: Schema,
     pub(crate) stmt_type: StatementType,
-    pub(crate) _dependent_relations: Vec<TableId>,
+    pub(crate) dependent_relations: Vec<TableId>,
 }
 
 pub fn gen_batch_plan_fragmenter(
@@ -347,7 +347,7 @@ pub fn gen_batch_plan_fragmenter(
         query_mode,
         schema,
         stmt_type,
-        _dependent_relations: dependent_relations,
+        dependent_relations,
     })
 }
 
@@ -420,6 +420,7 @@ async fn execute(
     let first_field_format = formats.first().copied().unwrap_or(Format::Text);
     let query_mode = plan_fragmenter_result.query_mode;
     let stmt_type = plan_fragmenter_result.stmt_type;
+    let dependent_relations = plan_fragmenter_result.dependent_relations.clone();
 
     let query_start_time = Instant::now();
     let (row_stream, pg_descs) =
@@ -466,6 +467,15 @@ async fn execute(
             }
         }
 
+        // Record the relations read by this query. The stats are periodically reported
+        // to meta for aggregation.
+        if !stmt_type.is_dml() {
+            session
+                .env()
+                .relation_read_stats()
+                .record_read(&dependent_relations, query_start_time.elapsed());
+        }
+
         Ok(())
     };
 
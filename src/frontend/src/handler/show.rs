@@ -27,6 +27,7 @@ use risingwave_common::util::addr::HostAddr;
 use risingwave_connector::source::kafka::PRIVATELINK_CONNECTION;
 use risingwave_expr::scalar::like::{i_like_default, like_default};
 use risingwave_pb::catalog::connection;
+use risingwave_pb::catalog::connection::PbHealthStatus;
 use risingwave_sqlparser::ast::{
     display_comma_separated, Ident, ObjectName, ShowCreateType, ShowObject, ShowStatementFilter,
 };
@@ -170,6 +171,7 @@ impl ShowColumnRow {
 struct ShowConnectionRow {
     name: String,
     r#type: String,
+    health: String,
     properties: String,
 }
 
@@ -229,6 +231,15 @@ struct ShowJobRow {
     progress: String,
 }
 
+#[derive(Fields)]
+#[fields(style = "Title Case")]
+struct ShowIndexProgressRow {
+    id: i64,
+    name: String,
+    statement: String,
+    progress: String,
+}
+
 #[derive(Fields)]
 #[fields(style = "Title Case")]
 struct ShowProcessListRow {
@@ -240,6 +251,14 @@ struct ShowProcessListRow {
     info: Option<String>,
 }
 
+#[derive(Fields)]
+#[fields(style = "Title Case")]
+struct ShowBackpressureRow {
+    actor_id: i32,
+    worker_id: i32,
+    collect_latency_ms: i64,
+}
+
 #[derive(Fields)]
 #[fields(style = "Title Case")]
 struct ShowCreateObjectRow {
@@ -256,7 +275,9 @@ pub fn infer_show_object(objects: &ShowObject) -> Vec<PgFieldDescriptor> {
         ShowObject::Indexes { .. } => ShowIndexRow::fields(),
         ShowObject::Cluster => ShowClusterRow::fields(),
         ShowObject::Jobs => ShowJobRow::fields(),
+        ShowObject::IndexProgress => ShowIndexProgressRow::fields(),
         ShowObject::ProcessList => ShowProcessListRow::fields(),
+        ShowObject::Backpressure => ShowBackpressureRow::fields(),
         _ => ShowObjectRow::fields(),
     })
 }
@@ -375,6 +396,16 @@ pub async fn handle_show_object(
                             PRIVATELINK_CONNECTION.to_string()
                         },
                     };
+                    let health = match c.health_status {
+                        PbHealthStatus::Healthy => "healthy".to_string(),
+                        PbHealthStatus::Unhealthy if c.health_message.is_empty() => {
+                            "unhealthy".to_string()
+                        }
+                        PbHealthStatus::Unhealthy => {
+                            format!("unhealthy: {}", c.health_message)
+                        }
+                        PbHealthStatus::Unspecified => "unknown".to_string(),
+                    };
                     let source_names = schema
                         .get_source_ids_by_connection(c.id)
                         .unwrap_or(Vec::new())
@@ -403,6 +434,7 @@ pub async fn handle_show_object(
                     ShowConnectionRow {
                         name,
                         r#type,
+                        health,
                         properties,
                     }
                 });
@@ -460,6 +492,18 @@ pub async fn handle_show_object(
                 .rows(rows)
                 .into());
         }
+        ShowObject::IndexProgress => {
+            let resp = session.env().meta_client().get_index_progress().await?;
+            let rows = resp.into_iter().map(|index| ShowIndexProgressRow {
+                id: index.index_table_id as i64,
+                name: index.name,
+                statement: index.statement,
+                progress: index.progress,
+            });
+            return Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
+                .rows(rows)
+                .into());
+        }
         ShowObject::ProcessList => {
             let sessions_map = session.env().sessions_map().read();
             let rows = sessions_map.values().map(|s| {
@@ -482,6 +526,21 @@ pub async fn handle_show_object(
                 .rows(rows)
                 .into());
         }
+        ShowObject::Backpressure => {
+            let stats = session
+                .env()
+                .meta_client()
+                .list_actor_backpressure()
+                .await?;
+            let rows = stats.into_iter().map(|stats| ShowBackpressureRow {
+                actor_id: stats.actor_id as i32,
+                worker_id: stats.worker_id as i32,
+                collect_latency_ms: stats.collect_latency_ms as i64,
+            });
+            return Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
+                .rows(rows)
+                .into());
+        }
         ShowObject::Cursor => {
             let (rows, pg_descs) = session.get_cursor_manager().get_all_query_cursors().await;
             return Ok(PgResponse::builder(StatementType::SHOW_COMMAND)
@@ -23,19 +23,24 @@ use risingwave_hummock_sdk::HummockVersionId;
 use risingwave_pb::backup_service::MetaSnapshotMetadata;
 use risingwave_pb::catalog::Table;
 use risingwave_pb::common::WorkerNode;
-use risingwave_pb::ddl_service::DdlProgress;
+use risingwave_pb::ddl_service::{DdlProgress, IndexProgress, PbDdlAuditLog};
 use risingwave_pb::hummock::write_limits::WriteLimit;
 use risingwave_pb::hummock::{
     BranchedObject, CompactTaskAssignment, CompactTaskProgress, CompactionGroupInfo,
     HummockSnapshot,
 };
 use risingwave_pb::meta::cancel_creating_jobs_request::PbJobs;
+use risingwave_pb::meta::event_log::PbEventSubscriptionLag;
+use risingwave_pb::meta::list_actor_backpressure_response::ActorBackpressure;
 use risingwave_pb::meta::list_actor_states_response::ActorState;
 use risingwave_pb::meta::list_fragment_distribution_response::FragmentDistribution;
 use risingwave_pb::meta::list_object_dependencies_response::PbObjectDependencies;
 use risingwave_pb::meta::list_table_fragment_states_response::TableFragmentState;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
-use risingwave_pb::meta::{EventLog, PbThrottleTarget, RecoveryStatus};
+use risingwave_pb::meta::{
+    convert_creating_jobs_to_background_request, EventLog, NamedCheckpoint, PbThrottleTarget,
+    PbUsageRecord, RecoveryStatus, RelationReadStats, SourceErrorStats,
+};
 use risingwave_rpc_client::error::Result;
 use risingwave_rpc_client::{HummockMetaClient, MetaClient};
 
@@ -54,12 +59,23 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn flush(&self, checkpoint: bool) -> Result<HummockSnapshot>;
 
+    async fn create_named_checkpoint(&self, name: String) -> Result<NamedCheckpoint>;
+
+    async fn list_named_checkpoints(&self) -> Result<Vec<NamedCheckpoint>>;
+
+    async fn pin_named_checkpoint(&self, name: String) -> Result<()>;
+
     async fn wait(&self) -> Result<()>;
 
     async fn recover(&self) -> Result<()>;
 
     async fn cancel_creating_jobs(&self, jobs: PbJobs) -> Result<Vec<u32>>;
 
+    async fn convert_creating_jobs_to_background(
+        &self,
+        jobs: convert_creating_jobs_to_background_request::Jobs,
+    ) -> Result<Vec<u32>>;
+
     async fn list_table_fragments(
         &self,
         table_ids: &[u32],
@@ -71,6 +87,10 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn list_actor_states(&self) -> Result<Vec<ActorState>>;
 
+    async fn list_actor_backpressure(&self) -> Result<Vec<ActorBackpressure>>;
+
+    async fn list_source_error_stats(&self) -> Result<Vec<SourceErrorStats>>;
+
     async fn list_object_dependencies(&self) -> Result<Vec<PbObjectDependencies>>;
 
     async fn unpin_snapshot(&self) -> Result<()>;
@@ -93,6 +113,8 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn get_ddl_progress(&self) -> Result<Vec<DdlProgress>>;
 
+    async fn get_index_progress(&self) -> Result<Vec<IndexProgress>>;
+
     async fn get_tables(&self, table_ids: &[u32]) -> Result<HashMap<u32, Table>>;
 
     /// Returns vector of (worker_id, min_pinned_version_id)
@@ -116,6 +138,17 @@ pub trait FrontendMetaClient: Send + Sync {
     async fn list_hummock_meta_configs(&self) -> Result<HashMap<String, String>>;
 
     async fn list_event_log(&self) -> Result<Vec<EventLog>>;
+
+    async fn list_ddl_audit_logs(&self) -> Result<Vec<PbDdlAuditLog>>;
+
+    async fn get_usage_report(
+        &self,
+        database_id: Option<u32>,
+        start_time_ms: u64,
+        end_time_ms: u64,
+    ) -> Result<Vec<PbUsageRecord>>;
+
+    async fn add_subscription_lag_event(&self, event: PbEventSubscriptionLag) -> Result<()>;
     async fn list_compact_task_assignment(&self) -> Result<Vec<CompactTaskAssignment>>;
 
     async fn list_all_nodes(&self) -> Result<Vec<WorkerNode>>;
@@ -139,6 +172,10 @@ pub trait FrontendMetaClient: Send + Sync {
     async fn get_cluster_recovery_status(&self) -> Result<RecoveryStatus>;
 
     async fn get_cluster_limits(&self) -> Result<Vec<ClusterLimit>>;
+
+    async fn report_relation_read_stats(&self, stats: Vec<RelationReadStats>) -> Result<()>;
+
+    async fn list_relation_read_stats(&self) -> Result<Vec<RelationReadStats>>;
 }
 
 pub struct FrontendMetaClientImpl(pub MetaClient);
@@ -161,6 +198,18 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.flush(checkpoint).await
     }
 
+    async fn create_named_checkpoint(&self, name: String) -> Result<NamedCheckpoint> {
+        self.0.create_named_checkpoint(name).await
+    }
+
+    async fn list_named_checkpoints(&self) -> Result<Vec<NamedCheckpoint>> {
+        self.0.list_named_checkpoints().await
+    }
+
+    async fn pin_named_checkpoint(&self, name: String) -> Result<()> {
+        self.0.pin_named_checkpoint(name).await
+    }
+
     async fn wait(&self) -> Result<()> {
         self.0.wait().await
     }
@@ -170,7 +219,14 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
     }
 
     async fn cancel_creating_jobs(&self, infos: PbJobs) -> Result<Vec<u32>> {
-        self.0.cancel_creating_jobs(infos).await
+        self.0.cancel_creating_jobs(infos, false).await
+    }
+
+    async fn convert_creating_jobs_to_background(
+        &self,
+        jobs: convert_creating_jobs_to_background_request::Jobs,
+    ) -> Result<Vec<u32>> {
+        self.0.convert_creating_jobs_to_background(jobs).await
     }
 
     async fn list_table_fragments(
@@ -192,6 +248,14 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.list_actor_states().await
     }
 
+    async fn list_actor_backpressure(&self) -> Result<Vec<ActorBackpressure>> {
+        self.0.list_actor_backpressure().await
+    }
+
+    async fn list_source_error_stats(&self) -> Result<Vec<SourceErrorStats>> {
+        self.0.list_source_error_stats().await
+    }
+
     async fn list_object_dependencies(&self) -> Result<Vec<PbObjectDependencies>> {
         self.0.list_object_dependencies().await
     }
@@ -237,6 +301,10 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         Ok(ddl_progress)
     }
 
+    async fn get_index_progress(&self) -> Result<Vec<IndexProgress>> {
+        self.0.get_index_progress().await
+    }
+
     async fn get_tables(&self, table_ids: &[u32]) -> Result<HashMap<u32, Table>> {
         let tables = self.0.get_tables(table_ids).await?;
         Ok(tables)
@@ -310,6 +378,25 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.list_event_log().await
     }
 
+    async fn list_ddl_audit_logs(&self) -> Result<Vec<PbDdlAuditLog>> {
+        self.0.list_ddl_audit_logs().await
+    }
+
+    async fn get_usage_report(
+        &self,
+        database_id: Option<u32>,
+        start_time_ms: u64,
+        end_time_ms: u64,
+    ) -> Result<Vec<PbUsageRecord>> {
+        self.0
+            .get_usage_report(database_id, start_time_ms, end_time_ms)
+            .await
+    }
+
+    async fn add_subscription_lag_event(&self, event: PbEventSubscriptionLag) -> Result<()> {
+        self.0.add_subscription_lag_event(event).await
+    }
+
     async fn list_compact_task_assignment(&self) -> Result<Vec<CompactTaskAssignment>> {
         self.0.list_compact_task_assignment().await
     }
@@ -352,4 +439,12 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
     async fn get_cluster_limits(&self) -> Result<Vec<ClusterLimit>> {
         self.0.get_cluster_limits().await
     }
+
+    async fn report_relation_read_stats(&self, stats: Vec<RelationReadStats>) -> Result<()> {
+        self.0.report_relation_read_stats(stats).await
+    }
+
+    async fn list_relation_read_stats(&self) -> Result<Vec<RelationReadStats>> {
+        self.0.list_relation_read_stats().await
+    }
 }
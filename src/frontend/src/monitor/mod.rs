@@ -12,5 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 //
+pub mod read_stats;
 pub mod stats;
+pub use read_stats::*;
 pub use stats::*;
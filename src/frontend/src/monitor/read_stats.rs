@@ -0,0 +1,64 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use risingwave_common::catalog::TableId;
+use risingwave_pb::meta::RelationReadStats;
+
+pub type RelationReadStatsCollectorRef = Arc<RelationReadStatsCollector>;
+
+/// Collects per-relation batch read statistics on this frontend. A background task
+/// periodically drains the collected stats and reports them to meta, which aggregates
+/// them across all frontends.
+#[derive(Default)]
+pub struct RelationReadStatsCollector {
+    stats: Mutex<HashMap<u32, RelationReadStats>>,
+}
+
+impl RelationReadStatsCollector {
+    /// Record one batch query that read the given relations.
+    pub fn record_read(&self, relations: &[TableId], latency: Duration) {
+        if relations.is_empty() {
+            return;
+        }
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock set before UNIX epoch")
+            .as_millis() as u64;
+        let latency_ms = latency.as_millis() as u64;
+        let mut stats = self.stats.lock();
+        for relation in relations {
+            let entry = stats
+                .entry(relation.table_id)
+                .or_insert_with(|| RelationReadStats {
+                    relation_id: relation.table_id,
+                    ..Default::default()
+                });
+            entry.read_count += 1;
+            entry.total_latency_ms += latency_ms;
+            entry.last_read_at_ms = entry.last_read_at_ms.max(now_ms);
+        }
+    }
+
+    /// Take all stats collected since the last drain.
+    pub fn drain(&self) -> Vec<RelationReadStats> {
+        std::mem::take(&mut *self.stats.lock())
+            .into_values()
+            .collect()
+    }
+}
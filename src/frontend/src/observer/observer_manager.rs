@@ -23,13 +23,13 @@ use risingwave_common::hash::WorkerSlotMapping;
 use risingwave_common::secret::LocalSecretManager;
 use risingwave_common::session_config::SessionConfig;
 use risingwave_common::system_param::local_manager::LocalSystemParamsManagerRef;
-use risingwave_common_service::ObserverState;
+use risingwave_common_service::{ObserverError, ObserverState};
 use risingwave_pb::common::WorkerNode;
 use risingwave_pb::hummock::HummockVersionStats;
 use risingwave_pb::meta::relation::RelationInfo;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::{FragmentWorkerSlotMapping, MetaSnapshot, SubscribeResponse};
-use risingwave_rpc_client::ComputeClientPoolRef;
+use risingwave_rpc_client::{ComputeClientPoolRef, MetaClient};
 use tokio::sync::watch::Sender;
 
 use crate::catalog::root_catalog::Catalog;
@@ -48,6 +48,7 @@ pub struct FrontendObserverNode {
     system_params_manager: LocalSystemParamsManagerRef,
     session_params: Arc<RwLock<SessionConfig>>,
     compute_client_pool: ComputeClientPoolRef,
+    meta_client: MetaClient,
 }
 
 impl ObserverState for FrontendObserverNode {
@@ -55,9 +56,9 @@ impl ObserverState for FrontendObserverNode {
         risingwave_pb::meta::SubscribeType::Frontend
     }
 
-    fn handle_notification(&mut self, resp: SubscribeResponse) {
+    fn handle_notification(&mut self, resp: SubscribeResponse) -> Result<(), ObserverError> {
         let Some(info) = resp.info.as_ref() else {
-            return;
+            return Ok(());
         };
 
         // TODO: this clone can be avoided
@@ -65,12 +66,13 @@ impl ObserverState for FrontendObserverNode {
             Info::Database(_)
             | Info::Schema(_)
             | Info::RelationGroup(_)
+            | Info::RelationDiffGroup(_)
             | Info::Function(_)
             | Info::Connection(_) => {
-                self.handle_catalog_notification(resp);
+                self.handle_catalog_notification(resp)?;
             }
             Info::Secret(_) => {
-                self.handle_catalog_notification(resp.clone());
+                self.handle_catalog_notification(resp.clone())?;
                 self.handle_secret_notification(resp);
             }
             Info::Node(node) => {
@@ -116,7 +118,23 @@ impl ObserverState for FrontendObserverNode {
             Info::Recovery(_) => {
                 self.compute_client_pool.invalidate_all();
             }
+            Info::LeaderHandover(handover) => {
+                tracing::info!(
+                    resigning_leader = handover.resigning_leader,
+                    "meta leader is resigning, refreshing the leader channel"
+                );
+                // Kick a member refresh right away so that the switch to the new leader
+                // happens proactively instead of on the first failed request.
+                let meta_client = self.meta_client.clone();
+                tokio::spawn(async move {
+                    meta_client.try_force_refresh_leader().await;
+                });
+            }
+            Info::CatchUp(_) => {
+                panic!("the catch-up marker is consumed by the observer manager");
+            }
         }
+        Ok(())
     }
 
     fn handle_initialization_notification(&mut self, resp: SubscribeResponse) {
@@ -223,6 +241,7 @@ impl FrontendObserverNode {
         system_params_manager: LocalSystemParamsManagerRef,
         session_params: Arc<RwLock<SessionConfig>>,
         compute_client_pool: ComputeClientPoolRef,
+        meta_client: MetaClient,
     ) -> Self {
         Self {
             worker_node_manager,
@@ -234,6 +253,7 @@ impl FrontendObserverNode {
             system_params_manager,
             session_params,
             compute_client_pool,
+            meta_client,
         }
     }
 
@@ -242,9 +262,12 @@ impl FrontendObserverNode {
         catalog_guard.set_table_stats(table_stats);
     }
 
-    fn handle_catalog_notification(&mut self, resp: SubscribeResponse) {
+    fn handle_catalog_notification(
+        &mut self,
+        resp: SubscribeResponse,
+    ) -> Result<(), ObserverError> {
         let Some(info) = resp.info.as_ref() else {
-            return;
+            return Ok(());
         };
 
         let mut catalog_guard = self.catalog.write();
@@ -338,6 +361,110 @@ impl FrontendObserverNode {
                     }
                 }
             }
+            Info::RelationDiffGroup(diff_group) => {
+                // A diff carries a partial proto with only the masked fields set. Rebuild
+                // the full proto from the cached catalog entry and run the normal update
+                // path, so that wide fields (e.g. columns) need not be re-deserialized.
+                //
+                // A diff this frontend cannot apply — a missing cache entry or a field
+                // mask from a newer meta node — is valid protocol input (e.g. during a
+                // rolling upgrade), not a bug: report a desync so that the observer
+                // manager rebuilds the catalog from a full snapshot instead of panicking.
+                if resp.operation() != Operation::Update {
+                    return Err(ObserverError::Desync(format!(
+                        "unsupported operation for relation diff {:?}",
+                        resp
+                    )));
+                }
+                for diff in &diff_group.diffs {
+                    let Some(relation) = diff
+                        .relation
+                        .as_ref()
+                        .and_then(|relation| relation.relation_info.as_ref())
+                    else {
+                        continue;
+                    };
+                    match relation {
+                        RelationInfo::Table(partial) => {
+                            let mut table =
+                                match catalog_guard.get_any_table_by_id(&partial.id.into()) {
+                                    Ok(table) => {
+                                        table.to_prost(partial.schema_id, partial.database_id)
+                                    }
+                                    Err(_) => {
+                                        return Err(ObserverError::Desync(format!(
+                                            "table {} in relation diff is not in the catalog",
+                                            partial.id
+                                        )))
+                                    }
+                                };
+                            for field in &diff.updated_fields {
+                                match field.as_str() {
+                                    "name" => table.name.clone_from(&partial.name),
+                                    "owner" => table.owner = partial.owner,
+                                    "definition" => {
+                                        table.definition.clone_from(&partial.definition)
+                                    }
+                                    "description" => {
+                                        table.description.clone_from(&partial.description)
+                                    }
+                                    "annotations" => {
+                                        table.annotations.clone_from(&partial.annotations)
+                                    }
+                                    "labels" => table.labels.clone_from(&partial.labels),
+                                    _ => {
+                                        return Err(ObserverError::Desync(format!(
+                                            "unknown field {} in relation diff for table {}",
+                                            field, partial.id
+                                        )))
+                                    }
+                                }
+                            }
+                            catalog_guard.update_table(&table);
+                        }
+                        RelationInfo::Source(partial) => {
+                            let mut source = match catalog_guard.get_source_by_id(
+                                &partial.database_id,
+                                &partial.schema_id,
+                                &partial.id,
+                            ) {
+                                Ok(source) => {
+                                    source.to_prost(partial.schema_id, partial.database_id)
+                                }
+                                Err(_) => {
+                                    return Err(ObserverError::Desync(format!(
+                                        "source {} in relation diff is not in the catalog",
+                                        partial.id
+                                    )))
+                                }
+                            };
+                            for field in &diff.updated_fields {
+                                match field.as_str() {
+                                    "name" => source.name.clone_from(&partial.name),
+                                    "owner" => source.owner = partial.owner,
+                                    "definition" => {
+                                        source.definition.clone_from(&partial.definition)
+                                    }
+                                    "rate_limit" => source.rate_limit = partial.rate_limit,
+                                    _ => {
+                                        return Err(ObserverError::Desync(format!(
+                                            "unknown field {} in relation diff for source {}",
+                                            field, partial.id
+                                        )))
+                                    }
+                                }
+                            }
+                            catalog_guard.update_source(&source);
+                        }
+                        _ => {
+                            return Err(ObserverError::Desync(format!(
+                                "unsupported relation type in relation diff {:?}",
+                                resp
+                            )))
+                        }
+                    }
+                }
+            }
             Info::Function(function) => match resp.operation() {
                 Operation::Add => catalog_guard.create_function(function),
                 Operation::Delete => catalog_guard.drop_function(
@@ -382,7 +509,13 @@ impl FrontendObserverNode {
             catalog_guard.version()
         );
         catalog_guard.set_version(resp.version);
+        if let Some(database_id) = resp.database_id {
+            // Database-scoped notifications additionally carry a version within the
+            // database's own version domain.
+            catalog_guard.set_database_version(database_id, resp.database_version);
+        }
         self.catalog_updated_tx.send(resp.version).unwrap();
+        Ok(())
     }
 
     fn handle_user_notification(&mut self, resp: SubscribeResponse) {
@@ -638,6 +638,7 @@ impl PlanRoot {
         with_external_source: bool,
         retention_seconds: Option<NonZeroU32>,
         cdc_table_id: Option<String>,
+        distributed_by: Option<Vec<String>>,
     ) -> Result<StreamMaterialize> {
         assert_eq!(self.phase, PlanPhase::Logical);
         assert_eq!(self.plan.convention(), Convention::Logical);
@@ -845,7 +846,33 @@ impl PlanRoot {
             ))?
         }
 
-        let table_required_dist = {
+        let table_required_dist = if let Some(distributed_by) = distributed_by {
+            // The distribution key is overridden by `[ALTER TABLE .. ]SET DISTRIBUTED BY`.
+            let dist_key = distributed_by
+                .iter()
+                .map(|name| {
+                    columns
+                        .iter()
+                        .position(|c| c.name() == name)
+                        .ok_or_else(|| {
+                            ErrorCode::InvalidInputSyntax(format!(
+                                "distribution key column \"{}\" not found in table",
+                                name
+                            ))
+                        })
+                })
+                .try_collect::<_, Vec<_>, _>()?;
+            for idx in &dist_key {
+                if !pk_column_indices.contains(idx) {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "distribution key column \"{}\" must be part of the primary key",
+                        columns[*idx].name()
+                    ))
+                    .into());
+                }
+            }
+            RequiredDist::PhysicalDist(Distribution::HashShard(dist_key))
+        } else {
             let mut bitset = FixedBitSet::with_capacity(columns.len());
             for idx in &pk_column_indices {
                 bitset.insert(*idx);
@@ -23,6 +23,7 @@ use risingwave_common::catalog::{
 };
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_common::util::sort_util::{ColumnOrder, OrderType};
+use risingwave_pb::catalog::PbStreamJobPriority;
 use risingwave_pb::stream_plan::stream_node::PbNodeBody;
 
 use super::derive::derive_columns;
@@ -86,7 +87,7 @@ impl StreamMaterialize {
         let input = reorganize_elements_id(input);
         let columns = derive_columns(input.schema(), out_names, &user_cols)?;
 
-        let create_type = if matches!(table_type, TableType::MaterializedView)
+        let create_type = if matches!(table_type, TableType::MaterializedView | TableType::Index)
             && input.ctx().session_ctx().config().background_ddl()
             && plan_has_backfill_leaf_nodes(&input)
         {
@@ -283,6 +284,7 @@ impl StreamMaterialize {
             created_at_cluster_version: None,
             retention_seconds: retention_seconds.map(|i| i.into()),
             cdc_table_id: None,
+            priority: PbStreamJobPriority::Unspecified,
         })
     }
 
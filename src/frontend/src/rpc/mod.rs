@@ -107,6 +107,7 @@ async fn get_new_table_plan(
         &original_catalog,
         None,
         Some(new_version_columns),
+        None,
     )
     .await?;
 
@@ -116,5 +117,7 @@ async fn get_new_table_plan(
         table_col_index_mapping: Some(col_index_mapping.to_protobuf()),
         source: None, // none for cdc table
         job_type: job_type as _,
+        // Auto schema change replaces the table without acquiring an alter lease.
+        alter_lease_token: 0,
     })
 }
@@ -478,6 +478,7 @@ pub(crate) mod tests {
     };
     use risingwave_common::hash::{WorkerSlotId, WorkerSlotMapping};
     use risingwave_common::types::DataType;
+    use risingwave_pb::catalog::PbStreamJobPriority;
     use risingwave_pb::common::worker_node::Property;
     use risingwave_pb::common::{HostAddress, WorkerNode, WorkerType};
     use risingwave_pb::plan_common::JoinType;
@@ -597,6 +598,7 @@ pub(crate) mod tests {
             initialized_at_cluster_version: None,
             created_at_cluster_version: None,
             cdc_table_id: None,
+            priority: PbStreamJobPriority::Unspecified,
         };
         let batch_plan_node: PlanRef = LogicalScan::create(
             "".to_string(),
@@ -22,6 +22,7 @@ use pgwire::pg_server::SessionId;
 use risingwave_pb::meta::cancel_creating_jobs_request::{
     CreatingJobInfo, CreatingJobInfos, PbJobs,
 };
+use risingwave_pb::meta::convert_creating_jobs_to_background_request::Jobs;
 use uuid::Uuid;
 
 use crate::catalog::{DatabaseId, SchemaId};
@@ -134,4 +135,25 @@ impl StreamingJobTracker {
                 .await
         });
     }
+
+    /// Converts the creating jobs of the session to background jobs instead of cancelling
+    /// them, so that they keep backfilling after the session is gone.
+    pub fn convert_jobs_to_background(&self, session_id: SessionId) {
+        let jobs = self
+            .creating_streaming_job
+            .read()
+            .values()
+            .filter(|job| job.session_id == session_id)
+            .cloned()
+            .collect_vec();
+
+        let client = self.meta_client.clone();
+        tokio::spawn(async move {
+            client
+                .convert_creating_jobs_to_background(Jobs::Infos(CreatingJobInfos {
+                    infos: jobs.into_iter().map(|job| job.info).collect_vec(),
+                }))
+                .await
+        });
+    }
 }
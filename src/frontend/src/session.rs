@@ -77,6 +77,7 @@ use risingwave_rpc_client::{ComputeClientPool, ComputeClientPoolRef, MetaClient}
 use risingwave_sqlparser::ast::{ObjectName, Statement};
 use risingwave_sqlparser::parser::Parser;
 use thiserror::Error;
+use thiserror_ext::AsReport;
 use tokio::runtime::Builder;
 use tokio::sync::oneshot::Sender;
 use tokio::sync::watch;
@@ -107,7 +108,10 @@ use crate::handler::variable::infer_show_variable;
 use crate::handler::{handle, RwPgResponse};
 use crate::health_service::HealthServiceImpl;
 use crate::meta_client::{FrontendMetaClient, FrontendMetaClientImpl};
-use crate::monitor::{FrontendMetrics, GLOBAL_FRONTEND_METRICS};
+use crate::monitor::{
+    FrontendMetrics, RelationReadStatsCollector, RelationReadStatsCollectorRef,
+    GLOBAL_FRONTEND_METRICS,
+};
 use crate::observer::FrontendObserverNode;
 use crate::rpc::FrontendServiceImpl;
 use crate::scheduler::streaming_manager::{StreamingJobTracker, StreamingJobTrackerRef};
@@ -152,6 +156,9 @@ pub struct FrontendEnv {
 
     pub frontend_metrics: Arc<FrontendMetrics>,
 
+    /// Per-relation batch read statistics, periodically reported to meta.
+    relation_read_stats: RelationReadStatsCollectorRef,
+
     source_metrics: Arc<SourceMetrics>,
 
     /// Batch spill metrics
@@ -232,6 +239,7 @@ impl FrontendEnv {
             client_pool,
             sessions_map: Arc::new(RwLock::new(HashMap::new())),
             frontend_metrics: Arc::new(FrontendMetrics::for_test()),
+            relation_read_stats: Arc::new(RelationReadStatsCollector::default()),
             batch_config: BatchConfig::default(),
             meta_config: MetaConfig::default(),
             streaming_config: StreamingConfig::default(),
@@ -348,6 +356,7 @@ impl FrontendEnv {
             system_params_manager.clone(),
             session_params.clone(),
             compute_client_pool.clone(),
+            meta_client.clone(),
         );
         let observer_manager =
             ObserverManager::new_with_meta_client(meta_client.clone(), frontend_observer_node)
@@ -427,6 +436,28 @@ impl FrontendEnv {
         });
         join_handles.push(join_handle);
 
+        // Periodically report the batch read statistics collected on this frontend to
+        // meta, which aggregates them across all frontends.
+        let relation_read_stats = Arc::new(RelationReadStatsCollector::default());
+        let report_stats = relation_read_stats.clone();
+        let report_meta_client = frontend_meta_client.clone();
+        let join_handle = tokio::spawn(async move {
+            let mut report_interval = tokio::time::interval(core::time::Duration::from_secs(60));
+            report_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            report_interval.reset();
+            loop {
+                report_interval.tick().await;
+                let stats = report_stats.drain();
+                if stats.is_empty() {
+                    continue;
+                }
+                if let Err(e) = report_meta_client.report_relation_read_stats(stats).await {
+                    tracing::warn!(error = %e.as_report(), "Failed to report relation read stats");
+                }
+            }
+        });
+        join_handles.push(join_handle);
+
         // Clean up the spill directory.
         #[cfg(not(madsim))]
         if config.batch.enable_spill {
@@ -461,6 +492,7 @@ impl FrontendEnv {
                 server_addr: frontend_address,
                 client_pool: compute_client_pool,
                 frontend_metrics,
+                relation_read_stats,
                 spill_metrics,
                 sessions_map,
                 batch_config: config.batch,
@@ -489,6 +521,10 @@ impl FrontendEnv {
         &self.catalog_reader
     }
 
+    pub fn relation_read_stats(&self) -> &RelationReadStatsCollector {
+        &self.relation_read_stats
+    }
+
     /// Get a reference to the frontend env's user info writer.
     ///
     /// This method is intentionally private, and a write guard is required for the caller to
@@ -958,6 +994,15 @@ impl SessionImpl {
         Ok((db_id, schema.id()))
     }
 
+    /// Returns the owner for a new object created in the given schema: the schema's
+    /// default owner if one is set (see `ALTER SCHEMA ... DEFAULT OWNER TO ...`),
+    /// otherwise the current user.
+    pub fn owner_for_create(&self, database_id: DatabaseId, schema_id: SchemaId) -> Result<UserId> {
+        let catalog_reader = self.env().catalog_reader().read_guard();
+        let schema = catalog_reader.get_schema_by_id(&database_id, &schema_id)?;
+        Ok(schema.default_owner_id().unwrap_or_else(|| self.user_id()))
+    }
+
     pub fn get_connection_by_name(
         &self,
         schema_name: Option<String>,
@@ -1036,6 +1081,18 @@ impl SessionImpl {
         Ok(catalog_reader.get_any_table_by_id(table_id)?.clone())
     }
 
+    /// Like [`Self::get_table_by_id`], but resolves the catalog as of the given epoch via
+    /// the bounded catalog history, so that subscription cursors replaying historical
+    /// changelog rows see the column names effective at the epoch being read.
+    pub fn get_table_by_id_as_of(
+        &self,
+        table_id: &TableId,
+        epoch: u64,
+    ) -> Result<Arc<TableCatalog>> {
+        let catalog_reader = self.env().catalog_reader().read_guard();
+        Ok(catalog_reader.get_table_by_id_as_of(table_id, epoch)?)
+    }
+
     pub fn get_table_by_name(
         &self,
         table_name: &str,
@@ -1123,7 +1180,12 @@ impl SessionImpl {
     }
 
     pub fn cancel_current_creating_job(&self) {
-        self.env.creating_streaming_job_tracker.abort_jobs(self.id);
+        let tracker = &self.env.creating_streaming_job_tracker;
+        if self.config().background_ddl_on_disconnect() {
+            tracker.convert_jobs_to_background(self.id);
+        } else {
+            tracker.abort_jobs(self.id);
+        }
         self.clear_notices()
     }
 
@@ -1362,15 +1424,13 @@ impl SessionManagerImpl {
     ) -> std::result::Result<Arc<SessionImpl>, BoxedError> {
         let catalog_reader = self.env.catalog_reader();
         let reader = catalog_reader.read_guard();
-        let database_name = reader
-            .get_database_by_id(&database_id)
-            .map_err(|_| {
-                Box::new(Error::new(
-                    ErrorKind::InvalidInput,
-                    format!("database \"{}\" does not exist", database_id),
-                ))
-            })?
-            .name();
+        let database = reader.get_database_by_id(&database_id).map_err(|_| {
+            Box::new(Error::new(
+                ErrorKind::InvalidInput,
+                format!("database \"{}\" does not exist", database_id),
+            ))
+        })?;
+        let database_name = database.name();
 
         let user_reader = self.env.user_info_reader();
         let reader = user_reader.read_guard();
@@ -1421,7 +1481,24 @@ impl SessionManagerImpl {
             // Use a trivial strategy: process_id and secret_key are equal.
             let id = (secret_key, secret_key);
             // Read session params snapshot from frontend env.
-            let session_config = self.env.session_params_snapshot();
+            let mut session_config = self.env.session_params_snapshot();
+
+            // Apply database-level session defaults, see `ALTER DATABASE ... SET`.
+            // Best-effort: a stale or invalid default must not block the connection.
+            for (key, value) in [
+                ("timezone", &database.default_timezone),
+                ("lc_collate", &database.default_collation),
+            ] {
+                if let Some(value) = value {
+                    if let Err(e) = session_config.set(key, value.clone(), &mut ()) {
+                        tracing::warn!(
+                            error = %e.as_report(),
+                            key,
+                            "failed to apply database-level session default"
+                        );
+                    }
+                }
+            }
 
             let session_impl: Arc<SessionImpl> = SessionImpl::new(
                 self.env.clone(),
@@ -17,7 +17,7 @@ use core::time::Duration;
 use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use bytes::Bytes;
 use fixedbitset::FixedBitSet;
@@ -29,7 +29,9 @@ use risingwave_common::catalog::Field;
 use risingwave_common::error::BoxedError;
 use risingwave_common::session_config::QueryMode;
 use risingwave_common::types::DataType;
+use risingwave_pb::meta::event_log::PbEventSubscriptionLag;
 use risingwave_sqlparser::ast::{Ident, ObjectName, Statement};
+use thiserror_ext::AsReport;
 
 use super::SessionImpl;
 use crate::catalog::subscription_catalog::SubscriptionCatalog;
@@ -214,6 +216,13 @@ pub struct SubscriptionCursor {
     // fields will be set in the table's catalog when the cursor is created,
     // and will be reset each time it is created chunk_stream, this is to avoid changes in the catalog due to alter.
     fields: Vec<Field>,
+    // The rw_timestamp of the last fetched row, used to compute the consumer lag.
+    last_fetched_epoch: u64,
+    // Total bytes delivered to the consumer through this cursor.
+    bytes_delivered: u64,
+    // Whether a lag warning has been emitted for the current threshold crossing,
+    // to avoid flooding the event log on every fetch.
+    lag_warned: bool,
 }
 
 impl SubscriptionCursor {
@@ -225,7 +234,11 @@ impl SubscriptionCursor {
         handle_args: &HandlerArgs,
     ) -> Result<Self> {
         let (state, fields) = if let Some(start_timestamp) = start_timestamp {
-            let table_catalog = handle_args.session.get_table_by_id(&dependent_table_id)?;
+            // Resolve the catalog as of the epoch being replayed, so that historical
+            // changelog rows are described with the column names effective back then.
+            let table_catalog = handle_args
+                .session
+                .get_table_by_id_as_of(&dependent_table_id, start_timestamp)?;
             let fields = table_catalog
                 .columns
                 .iter()
@@ -271,6 +284,11 @@ impl SubscriptionCursor {
 
         let cursor_need_drop_time =
             Instant::now() + Duration::from_secs(subscription.retention_seconds);
+        let last_fetched_epoch = match &state {
+            State::InitLogStoreQuery { seek_timestamp, .. } => *seek_timestamp,
+            State::Fetch { rw_timestamp, .. } => *rw_timestamp,
+            State::Invalid => 0,
+        };
         Ok(Self {
             cursor_name,
             subscription,
@@ -278,6 +296,9 @@ impl SubscriptionCursor {
             cursor_need_drop_time,
             state,
             fields,
+            last_fetched_epoch,
+            bytes_delivered: 0,
+            lag_warned: false,
         })
     }
 
@@ -363,6 +384,11 @@ impl SubscriptionCursor {
                     if let Some(row) = remaining_rows.pop_front() {
                         // 1. Fetch the next row
                         let new_row = row.take();
+                        self.last_fetched_epoch = rw_timestamp;
+                        self.bytes_delivered += new_row
+                            .iter()
+                            .map(|v| v.as_ref().map_or(0, |bytes| bytes.len() as u64))
+                            .sum::<u64>();
                         if from_snapshot {
                             return Ok(Some(Row::new(Self::build_row(
                                 new_row,
@@ -448,9 +474,62 @@ impl SubscriptionCursor {
             }
         }
 
+        self.report_lag_if_needed(&handle_args).await;
+
         Ok((ans, desc))
     }
 
+    /// Returns how far the last fetched epoch is behind the current time, in seconds.
+    fn lag_seconds(&self) -> u64 {
+        let now_millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time set before unix epoch")
+            .as_millis() as u64;
+        let fetched_millis = convert_logstore_u64_to_unix_millis(self.last_fetched_epoch);
+        now_millis.saturating_sub(fetched_millis) / 1000
+    }
+
+    /// Emits an event-log warning when the consumer lags beyond most of the retention
+    /// period, so it can be fixed before the log store data is recycled. The warning is
+    /// emitted once per threshold crossing to avoid flooding the event log on every fetch.
+    async fn report_lag_if_needed(&mut self, handle_args: &HandlerArgs) {
+        let lag_seconds = self.lag_seconds();
+        // Warn when the lag reaches 80% of the retention period.
+        let threshold = self.subscription.retention_seconds / 10 * 8;
+        if threshold == 0 || lag_seconds < threshold {
+            self.lag_warned = false;
+            return;
+        }
+        if self.lag_warned {
+            return;
+        }
+        self.lag_warned = true;
+        tracing::warn!(
+            cursor_name = self.cursor_name,
+            subscription_name = self.subscription.name,
+            lag_seconds,
+            retention_seconds = self.subscription.retention_seconds,
+            "subscription consumer is lagging close to the retention limit, data may be recycled before it is fetched"
+        );
+        let event = PbEventSubscriptionLag {
+            subscription_id: self.subscription.id.subscription_id,
+            subscription_name: self.subscription.name.clone(),
+            cursor_name: self.cursor_name.clone(),
+            last_fetched_epoch: self.last_fetched_epoch,
+            lag_seconds,
+            retention_seconds: self.subscription.retention_seconds,
+        };
+        if let Err(e) = handle_args
+            .session
+            .env()
+            .meta_client()
+            .add_subscription_lag_event(event)
+            .await
+        {
+            tracing::warn!(error = %e.as_report(), "failed to report subscription lag to the event log");
+        }
+    }
+
     async fn get_next_rw_timestamp(
         seek_timestamp: u64,
         table_id: &TableId,
@@ -805,4 +884,39 @@ impl CursorManager {
             ],
         )
     }
+
+    /// Returns the per-consumer lag of all subscription cursors in this session: the
+    /// last fetched epoch, how far it is behind now, and the bytes delivered so far.
+    pub async fn list_subscription_lag(&self) -> Vec<SubscriptionCursorLag> {
+        self.cursor_map
+            .lock()
+            .await
+            .iter()
+            .filter_map(|(cursor_name, cursor)| {
+                if let Cursor::Subscription(cursor) = cursor {
+                    Some(SubscriptionCursorLag {
+                        cursor_name: cursor_name.clone(),
+                        subscription_name: cursor.subscription.name.clone(),
+                        last_fetched_epoch: cursor.last_fetched_epoch,
+                        lag_seconds: cursor.lag_seconds(),
+                        bytes_delivered: cursor.bytes_delivered,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Per-consumer lag of a subscription cursor, returned by
+/// [`CursorManager::list_subscription_lag`].
+pub struct SubscriptionCursorLag {
+    pub cursor_name: String,
+    pub subscription_name: String,
+    /// The `rw_timestamp` of the last fetched row, or the epoch the cursor was declared
+    /// at if nothing has been fetched yet.
+    pub last_fetched_epoch: u64,
+    pub lag_seconds: u64,
+    pub bytes_delivered: u64,
 }
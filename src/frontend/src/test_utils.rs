@@ -36,14 +36,15 @@ use risingwave_hummock_sdk::version::{HummockVersion, HummockVersionDelta};
 use risingwave_pb::backup_service::MetaSnapshotMetadata;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
-    PbComment, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbStreamJobStatus,
-    PbSubscription, PbTable, PbView, Table,
+    PbComment, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbStreamJobPriority,
+    PbStreamJobStatus, PbSubscription, PbTable, PbView, Table,
 };
 use risingwave_pb::common::WorkerNode;
+use risingwave_pb::ddl_service::alter_connector_props_request::ObjectType as ConnectorPropsObjectType;
 use risingwave_pb::ddl_service::alter_owner_request::Object;
 use risingwave_pb::ddl_service::{
-    alter_set_schema_request, create_connection_request, DdlProgress, PbTableJobType,
-    ReplaceTablePlan, TableJobType,
+    alter_set_schema_request, create_connection_request, DdlProgress, IndexProgress,
+    PbDdlAuditLog, PbTableJobType, ReplaceTablePlan, TableJobType,
 };
 use risingwave_pb::hummock::write_limits::WriteLimit;
 use risingwave_pb::hummock::{
@@ -51,17 +52,22 @@ use risingwave_pb::hummock::{
     HummockSnapshot,
 };
 use risingwave_pb::meta::cancel_creating_jobs_request::PbJobs;
+use risingwave_pb::meta::event_log::PbEventSubscriptionLag;
+use risingwave_pb::meta::list_actor_backpressure_response::ActorBackpressure;
 use risingwave_pb::meta::list_actor_states_response::ActorState;
 use risingwave_pb::meta::list_fragment_distribution_response::FragmentDistribution;
 use risingwave_pb::meta::list_object_dependencies_response::PbObjectDependencies;
 use risingwave_pb::meta::list_table_fragment_states_response::TableFragmentState;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
 use risingwave_pb::meta::{
-    EventLog, PbTableParallelism, PbThrottleTarget, RecoveryStatus, SystemParams,
+    convert_creating_jobs_to_background_request, EventLog, NamedCheckpoint, PbTableParallelism,
+    PbThrottleTarget, PbUsageRecord, RecoveryStatus, RelationReadStats, SourceErrorStats,
+    SystemParams,
 };
+use risingwave_pb::plan_common::PbColumnCatalog;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_pb::user::update_user_request::UpdateField;
-use risingwave_pb::user::{GrantPrivilege, UserInfo};
+use risingwave_pb::user::{AlterDefaultPrivilegesRequest, GrantPrivilege, UserInfo};
 use risingwave_rpc_client::error::Result as RpcResult;
 use tempfile::{Builder, NamedTempFile};
 
@@ -243,6 +249,9 @@ impl CatalogWriter for MockCatalogWriter {
             name: db_name.to_string(),
             id: database_id,
             owner,
+            barrier_interval_ms: None,
+            default_timezone: None,
+            default_collation: None,
         });
         self.create_schema(database_id, DEFAULT_SCHEMA_NAME, owner)
             .await?;
@@ -265,6 +274,7 @@ impl CatalogWriter for MockCatalogWriter {
             name: schema_name.to_string(),
             database_id: db_id,
             owner,
+            default_owner_id: None,
         });
         self.add_schema_id(id, db_id);
         Ok(())
@@ -305,6 +315,10 @@ impl CatalogWriter for MockCatalogWriter {
         Ok(())
     }
 
+    async fn acquire_alter_lease(&self, _table_id: u32, _force: bool) -> Result<u64> {
+        Ok(0)
+    }
+
     async fn replace_table(
         &self,
         _source: Option<PbSource>,
@@ -312,6 +326,7 @@ impl CatalogWriter for MockCatalogWriter {
         _graph: StreamFragmentGraph,
         _mapping: ColIndexMapping,
         _job_type: TableJobType,
+        _alter_lease_token: u64,
     ) -> Result<()> {
         table.stream_job_status = PbStreamJobStatus::Created as _;
         self.catalog.write().update_table(&table);
@@ -364,7 +379,7 @@ impl CatalogWriter for MockCatalogWriter {
         Ok(())
     }
 
-    async fn create_function(&self, _function: PbFunction) -> Result<()> {
+    async fn create_function(&self, _function: PbFunction, _or_replace: bool) -> Result<()> {
         unreachable!()
     }
 
@@ -574,6 +589,54 @@ impl CatalogWriter for MockCatalogWriter {
         Ok(())
     }
 
+    async fn alter_source_add_column(
+        &self,
+        source_id: u32,
+        mut column: PbColumnCatalog,
+        definition: String,
+        _base_version: u64,
+    ) -> Result<Vec<u32>> {
+        // Apply the same mutation as the meta node: assign the next column id and
+        // bump the version.
+        let mut source = {
+            let reader = self.catalog.read();
+            reader
+                .iter_databases()
+                .flat_map(|db| {
+                    db.iter_schemas()
+                        .map(|schema| (db.id(), schema.id(), schema))
+                })
+                .find_map(|(db_id, schema_id, schema)| {
+                    schema
+                        .get_source_by_id(&source_id)
+                        .map(|source| source.to_prost(schema_id, db_id))
+                })
+                .unwrap()
+        };
+        let next_column_id = source
+            .columns
+            .iter()
+            .map(|col| col.column_desc.as_ref().unwrap().column_id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        column.column_desc.as_mut().unwrap().column_id = next_column_id;
+        source.columns.push(column);
+        source.definition = definition;
+        source.version += 1;
+        self.catalog.write().update_source(&source);
+        Ok(vec![])
+    }
+
+    async fn alter_connector_props(
+        &self,
+        _object_id: u32,
+        _object_type: ConnectorPropsObjectType,
+        _changed_props: HashMap<String, String>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     async fn alter_source_with_sr(&self, source: PbSource) -> Result<()> {
         self.catalog.write().update_source(&source);
         Ok(())
@@ -649,10 +712,22 @@ impl CatalogWriter for MockCatalogWriter {
         unreachable!()
     }
 
+    async fn alter_function_name(&self, _function_id: u32, _function_name: &str) -> Result<()> {
+        unreachable!()
+    }
+
     async fn alter_schema_name(&self, _schema_id: u32, _schema_name: &str) -> Result<()> {
         unreachable!()
     }
 
+    async fn alter_schema_default_owner(
+        &self,
+        _schema_id: u32,
+        _default_owner_id: Option<u32>,
+    ) -> Result<()> {
+        unreachable!()
+    }
+
     async fn alter_database_name(&self, _database_id: u32, _database_name: &str) -> Result<()> {
         unreachable!()
     }
@@ -665,6 +740,18 @@ impl CatalogWriter for MockCatalogWriter {
     ) -> Result<()> {
         todo!()
     }
+
+    async fn alter_stream_job_priority(
+        &self,
+        _table_id: u32,
+        _priority: PbStreamJobPriority,
+    ) -> Result<()> {
+        todo!()
+    }
+
+    async fn alter_table_ttl(&self, _table_id: u32, _retention_seconds: Option<u32>) -> Result<()> {
+        todo!()
+    }
 }
 
 impl MockCatalogWriter {
@@ -673,24 +760,30 @@ impl MockCatalogWriter {
             id: 0,
             name: DEFAULT_DATABASE_NAME.to_string(),
             owner: DEFAULT_SUPER_USER_ID,
+            barrier_interval_ms: None,
+            default_timezone: None,
+            default_collation: None,
         });
         catalog.write().create_schema(&PbSchema {
             id: 1,
             name: DEFAULT_SCHEMA_NAME.to_string(),
             database_id: 0,
             owner: DEFAULT_SUPER_USER_ID,
+            default_owner_id: None,
         });
         catalog.write().create_schema(&PbSchema {
             id: 2,
             name: PG_CATALOG_SCHEMA_NAME.to_string(),
             database_id: 0,
             owner: DEFAULT_SUPER_USER_ID,
+            default_owner_id: None,
         });
         catalog.write().create_schema(&PbSchema {
             id: 3,
             name: RW_CATALOG_SCHEMA_NAME.to_string(),
             database_id: 0,
             owner: DEFAULT_SUPER_USER_ID,
+            default_owner_id: None,
         });
         let mut map: HashMap<u32, DatabaseId> = HashMap::new();
         map.insert(1_u32, 0_u32);
@@ -872,6 +965,7 @@ impl UserInfoWriter for MockUserInfoWriter {
         privileges: Vec<GrantPrivilege>,
         with_grant_option: bool,
         _grantor: UserId,
+        _expand_relations: bool,
     ) -> Result<()> {
         let privileges = privileges
             .into_iter()
@@ -908,6 +1002,45 @@ impl UserInfoWriter for MockUserInfoWriter {
         }
         Ok(())
     }
+
+    async fn grant_role(
+        &self,
+        roles: Vec<UserId>,
+        users: Vec<UserId>,
+        _granted_by: UserId,
+    ) -> Result<()> {
+        for user_id in users {
+            if let Some(u) = self.user_info.write().get_user_mut(user_id) {
+                for role_id in &roles {
+                    if !u.granted_roles.contains(role_id) {
+                        u.granted_roles.push(*role_id);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn revoke_role(
+        &self,
+        roles: Vec<UserId>,
+        users: Vec<UserId>,
+        _revoke_by: UserId,
+    ) -> Result<()> {
+        for user_id in users {
+            if let Some(u) = self.user_info.write().get_user_mut(user_id) {
+                u.granted_roles.retain(|role_id| !roles.contains(role_id));
+            }
+        }
+        Ok(())
+    }
+
+    async fn alter_default_privileges(
+        &self,
+        _request: AlterDefaultPrivilegesRequest,
+    ) -> Result<()> {
+        Ok(())
+    }
 }
 
 impl MockUserInfoWriter {
@@ -950,6 +1083,23 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(HummockSnapshot { committed_epoch: 0 })
     }
 
+    async fn create_named_checkpoint(&self, name: String) -> RpcResult<NamedCheckpoint> {
+        Ok(NamedCheckpoint {
+            name,
+            epoch: 0,
+            hummock_version_id: 0,
+            pinned: false,
+        })
+    }
+
+    async fn list_named_checkpoints(&self) -> RpcResult<Vec<NamedCheckpoint>> {
+        Ok(vec![])
+    }
+
+    async fn pin_named_checkpoint(&self, _name: String) -> RpcResult<()> {
+        Ok(())
+    }
+
     async fn wait(&self) -> RpcResult<()> {
         Ok(())
     }
@@ -958,6 +1108,13 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(vec![])
     }
 
+    async fn convert_creating_jobs_to_background(
+        &self,
+        _jobs: convert_creating_jobs_to_background_request::Jobs,
+    ) -> RpcResult<Vec<u32>> {
+        Ok(vec![])
+    }
+
     async fn list_table_fragments(
         &self,
         _table_ids: &[u32],
@@ -977,6 +1134,14 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(vec![])
     }
 
+    async fn list_actor_backpressure(&self) -> RpcResult<Vec<ActorBackpressure>> {
+        Ok(vec![])
+    }
+
+    async fn list_source_error_stats(&self) -> RpcResult<Vec<SourceErrorStats>> {
+        Ok(vec![])
+    }
+
     async fn list_object_dependencies(&self) -> RpcResult<Vec<PbObjectDependencies>> {
         Ok(vec![])
     }
@@ -1017,6 +1182,10 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(vec![])
     }
 
+    async fn get_index_progress(&self) -> RpcResult<Vec<IndexProgress>> {
+        Ok(vec![])
+    }
+
     async fn get_tables(&self, _table_ids: &[u32]) -> RpcResult<HashMap<u32, Table>> {
         Ok(HashMap::new())
     }
@@ -1061,6 +1230,23 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         unimplemented!()
     }
 
+    async fn list_ddl_audit_logs(&self) -> RpcResult<Vec<PbDdlAuditLog>> {
+        unimplemented!()
+    }
+
+    async fn get_usage_report(
+        &self,
+        _database_id: Option<u32>,
+        _start_time_ms: u64,
+        _end_time_ms: u64,
+    ) -> RpcResult<Vec<PbUsageRecord>> {
+        unimplemented!()
+    }
+
+    async fn add_subscription_lag_event(&self, _event: PbEventSubscriptionLag) -> RpcResult<()> {
+        Ok(())
+    }
+
     async fn list_compact_task_assignment(&self) -> RpcResult<Vec<CompactTaskAssignment>> {
         unimplemented!()
     }
@@ -1102,6 +1288,14 @@ impl FrontendMetaClient for MockFrontendMetaClient {
     async fn get_cluster_limits(&self) -> RpcResult<Vec<ClusterLimit>> {
         Ok(vec![])
     }
+
+    async fn report_relation_read_stats(&self, _stats: Vec<RelationReadStats>) -> RpcResult<()> {
+        Ok(())
+    }
+
+    async fn list_relation_read_stats(&self) -> RpcResult<Vec<RelationReadStats>> {
+        Ok(vec![])
+    }
 }
 
 #[cfg(test)]
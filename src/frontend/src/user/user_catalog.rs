@@ -33,6 +33,9 @@ pub struct UserCatalog {
     pub can_login: bool,
     pub auth_info: Option<PbAuthInfo>,
     pub grant_privileges: Vec<PbGrantPrivilege>,
+    /// Ids of the roles granted to this user; privileges of these roles are inherited,
+    /// resolved transitively at check time.
+    pub granted_roles: Vec<UserId>,
 
     // User owned acl mode set, group by object id.
     // TODO: merge it after we fully migrate to sql-backend.
@@ -52,6 +55,7 @@ impl From<PbUserInfo> for UserCatalog {
             can_login: user.can_login,
             auth_info: user.auth_info,
             grant_privileges: user.grant_privileges,
+            granted_roles: user.granted_roles,
             database_acls: Default::default(),
             schema_acls: Default::default(),
             object_acls: Default::default(),
@@ -73,6 +77,7 @@ impl UserCatalog {
             can_login: self.can_login,
             auth_info: self.auth_info.clone(),
             grant_privileges: self.grant_privileges.clone(),
+            granted_roles: self.granted_roles.clone(),
         }
     }
 
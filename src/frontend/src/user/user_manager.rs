@@ -48,6 +48,11 @@ impl UserInfoManager {
         self.user_by_name.values().cloned().collect_vec()
     }
 
+    pub fn get_user_by_id(&self, id: UserId) -> Option<&UserCatalog> {
+        let name = self.user_name_by_id.get(&id)?;
+        self.user_by_name.get(name)
+    }
+
     pub fn get_user_by_name(&self, user_name: &str) -> Option<&UserCatalog> {
         self.user_by_name.get(user_name)
     }
@@ -57,6 +57,8 @@ fn get_all_available_modes(object: &GrantObjects) -> Result<&AclModeSet> {
             Ok(&acl::ALL_AVAILABLE_TABLE_MODES)
         }
         GrantObjects::Sinks(_) => Ok(&acl::ALL_AVAILABLE_SINK_MODES),
+        GrantObjects::Subscriptions(_) => Ok(&acl::ALL_AVAILABLE_SUBSCRIPTION_MODES),
+        GrantObjects::Secrets(_) => Ok(&acl::ALL_AVAILABLE_SECRET_MODES),
         _ => Err(
             ErrorCode::BindError("Invalid privilege type for the given object.".to_string()).into(),
         ),
@@ -97,6 +99,7 @@ pub fn available_prost_privilege(object: PbObject, for_dml_table: bool) -> PbGra
         PbObject::ViewId(_) => &acl::ALL_AVAILABLE_TABLE_MODES,
         PbObject::SinkId(_) => &acl::ALL_AVAILABLE_SINK_MODES,
         PbObject::SubscriptionId(_) => &acl::ALL_AVAILABLE_SUBSCRIPTION_MODES,
+        PbObject::SecretId(_) => &acl::ALL_AVAILABLE_SECRET_MODES,
         PbObject::FunctionId(_) => &acl::ALL_AVAILABLE_FUNCTION_MODES,
         _ => unreachable!("Invalid object type"),
     };
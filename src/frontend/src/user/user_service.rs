@@ -18,7 +18,7 @@ use anyhow::anyhow;
 use parking_lot::lock_api::ArcRwLockReadGuard;
 use parking_lot::{RawRwLock, RwLock};
 use risingwave_pb::user::update_user_request::UpdateField;
-use risingwave_pb::user::{GrantPrivilege, UserInfo};
+use risingwave_pb::user::{AlterDefaultPrivilegesRequest, GrantPrivilege, UserInfo};
 use risingwave_rpc_client::MetaClient;
 use tokio::sync::watch::Receiver;
 
@@ -55,6 +55,7 @@ pub trait UserInfoWriter: Send + Sync {
         privileges: Vec<GrantPrivilege>,
         with_grant_option: bool,
         grantor: UserId,
+        expand_relations: bool,
     ) -> Result<()>;
 
     async fn revoke_privilege(
@@ -66,6 +67,22 @@ pub trait UserInfoWriter: Send + Sync {
         revoke_grant_option: bool,
         cascade: bool,
     ) -> Result<()>;
+
+    async fn grant_role(
+        &self,
+        roles: Vec<UserId>,
+        users: Vec<UserId>,
+        granted_by: UserId,
+    ) -> Result<()>;
+
+    async fn revoke_role(
+        &self,
+        roles: Vec<UserId>,
+        users: Vec<UserId>,
+        revoke_by: UserId,
+    ) -> Result<()>;
+
+    async fn alter_default_privileges(&self, request: AlterDefaultPrivilegesRequest) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -97,10 +114,17 @@ impl UserInfoWriter for UserInfoWriterImpl {
         privileges: Vec<GrantPrivilege>,
         with_grant_option: bool,
         granted_by: UserId,
+        expand_relations: bool,
     ) -> Result<()> {
         let version = self
             .meta_client
-            .grant_privilege(users, privileges, with_grant_option, granted_by)
+            .grant_privilege(
+                users,
+                privileges,
+                with_grant_option,
+                granted_by,
+                expand_relations,
+            )
             .await?;
         self.wait_version(version).await
     }
@@ -127,6 +151,39 @@ impl UserInfoWriter for UserInfoWriterImpl {
             .await?;
         self.wait_version(version).await
     }
+
+    async fn grant_role(
+        &self,
+        roles: Vec<UserId>,
+        users: Vec<UserId>,
+        granted_by: UserId,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .grant_role(roles, users, granted_by)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn revoke_role(
+        &self,
+        roles: Vec<UserId>,
+        users: Vec<UserId>,
+        revoke_by: UserId,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .revoke_role(roles, users, revoke_by)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn alter_default_privileges(&self, request: AlterDefaultPrivilegesRequest) -> Result<()> {
+        // Templates only affect future relations, so there is no user info version to
+        // wait for here.
+        self.meta_client.alter_default_privileges(request).await?;
+        Ok(())
+    }
 }
 
 impl UserInfoWriterImpl {
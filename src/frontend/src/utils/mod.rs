@@ -23,6 +23,8 @@ mod connected_components;
 pub(crate) use connected_components::*;
 mod stream_graph_formatter;
 pub use stream_graph_formatter::*;
+mod stream_resource_estimate;
+pub use stream_resource_estimate::*;
 mod with_options;
 pub use with_options::*;
 mod rewrite_index;
@@ -0,0 +1,86 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::util::stream_graph_visitor::visit_internal_tables;
+use risingwave_pb::stream_plan::StreamFragmentGraph;
+
+/// Rough per-actor memory overhead, covering channels and executor bookkeeping.
+const ESTIMATED_ACTOR_OVERHEAD_BYTES: u64 = 8 << 20;
+/// Rough per-state-table memory share, covering the memtable and its slice of the
+/// block cache under steady writes.
+const ESTIMATED_STATE_TABLE_CACHE_BYTES: u64 = 32 << 20;
+
+/// Estimate the resources a streaming job would occupy from its fragment graph, before
+/// actually creating it. Used for `EXPLAIN (ESTIMATE) CREATE ...`.
+///
+/// The actor count assumes the parallelism requested in the graph, falling back to the
+/// total parallelism of the current compute nodes as the meta node would. The memory
+/// figure is a coarse upper-bound heuristic and is explicitly labeled as approximate.
+pub fn explain_resource_estimate(
+    graph: &StreamFragmentGraph,
+    worker_count: usize,
+    total_parallelism: usize,
+) -> String {
+    let parallelism = graph
+        .parallelism
+        .as_ref()
+        .map(|p| p.parallelism as usize)
+        .unwrap_or(total_parallelism)
+        .max(1);
+
+    let fragment_count = graph.fragments.len();
+    let actor_count: usize = graph
+        .fragments
+        .values()
+        .map(|fragment| {
+            if fragment.requires_singleton {
+                1
+            } else {
+                parallelism
+            }
+        })
+        .sum();
+
+    let mut state_table_names = vec![];
+    for fragment in graph.fragments.values() {
+        let mut fragment = fragment.clone();
+        visit_internal_tables(&mut fragment, |_table, name| {
+            state_table_names.push(name.to_string());
+        });
+    }
+
+    let worker_count = worker_count.max(1);
+    let estimated_memory_bytes = actor_count as u64 * ESTIMATED_ACTOR_OVERHEAD_BYTES
+        + state_table_names.len() as u64 * ESTIMATED_STATE_TABLE_CACHE_BYTES;
+    let estimated_memory_mb_per_worker = estimated_memory_bytes.div_ceil(worker_count as u64) >> 20;
+
+    let mut output = String::new();
+    output.push_str("Estimated resource usage of the streaming job:\n");
+    output.push_str(&format!("  fragments: {}\n", fragment_count));
+    output.push_str(&format!(
+        "  actors: {} (parallelism: {})\n",
+        actor_count, parallelism
+    ));
+    output.push_str(&format!(
+        "  state tables: {} ({})\n",
+        state_table_names.len(),
+        state_table_names.iter().sorted().dedup().join(", ")
+    ));
+    output.push_str(&format!(
+        "  memory per worker: approx. {} MiB (workers: {})\n",
+        estimated_memory_mb_per_worker, worker_count
+    ));
+    output
+}
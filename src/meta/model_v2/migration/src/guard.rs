@@ -0,0 +1,96 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{ConnectionTrait, DbErr};
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::MigratorTrait;
+
+use crate::Migrator;
+
+/// Meta-managed bookkeeping of the binary version that first applied each migration,
+/// used to produce a clear report when a binary downgrade crosses a migration boundary.
+#[derive(DeriveIden)]
+enum MigrationGuard {
+    Table,
+    MigrationVersion,
+    AppliedIn,
+}
+
+impl Migrator {
+    /// Records, for every applied migration without a record yet, the binary version that
+    /// applied it. Called on meta node startup right after [`Migrator::up`].
+    pub async fn record_applied_migrations(
+        db: &impl ConnectionTrait,
+        binary_version: &str,
+    ) -> Result<(), DbErr> {
+        let backend = db.get_database_backend();
+        let recorded = Self::guard_records(db).await?;
+        for migration in Self::get_migration_models(db).await? {
+            if recorded.contains_key(&migration.version) {
+                continue;
+            }
+            let stmt = Query::insert()
+                .into_table(MigrationGuard::Table)
+                .columns([MigrationGuard::MigrationVersion, MigrationGuard::AppliedIn])
+                .values_panic([migration.version.into(), binary_version.into()])
+                .to_owned();
+            db.execute(backend.build(&stmt)).await?;
+        }
+        Ok(())
+    }
+
+    /// Preflight check that every migration applied to the meta store is known to this
+    /// binary. An unknown applied migration means the binary has been downgraded across a
+    /// migration boundary and would misinterpret the migrated data, so the caller must
+    /// refuse to start. Performed before [`Migrator::up`].
+    pub async fn check_migration_compatibility(
+        db: &impl ConnectionTrait,
+        binary_version: &str,
+    ) -> Result<(), DbErr> {
+        let known: HashSet<_> = Self::migrations()
+            .iter()
+            .map(|m| m.name().to_owned())
+            .collect();
+        let unknown: Vec<_> = Self::get_migration_models(db)
+            .await?
+            .into_iter()
+            .filter(|m| !known.contains(&m.version))
+            .collect();
+        if unknown.is_empty() {
+            return Ok(());
+        }
+
+        // The guard table may not exist yet when downgrading across its own introduction,
+        // in which case the report simply omits the applying binary versions.
+        let recorded = Self::guard_records(db).await.unwrap_or_default();
+        let report = unknown
+            .iter()
+            .map(|m| match recorded.get(&m.version) {
+                Some(applied_in) => format!("- {} (applied in {})", m.version, applied_in),
+                None => format!("- {}", m.version),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Err(DbErr::Migration(format!(
+            "the meta store was migrated by a binary newer than {binary_version} and this \
+             binary would misinterpret the migrated data. Upgrade the binary or restore a \
+             meta store backup. Unknown migrations:\n{report}"
+        )))
+    }
+
+    /// Returns the recorded `migration version -> applying binary version` map.
+    async fn guard_records(db: &impl ConnectionTrait) -> Result<HashMap<String, String>, DbErr> {
+        let backend = db.get_database_backend();
+        let stmt = Query::select()
+            .columns([MigrationGuard::MigrationVersion, MigrationGuard::AppliedIn])
+            .from(MigrationGuard::Table)
+            .to_owned();
+        let mut records = HashMap::new();
+        for row in db.query_all(backend.build(&stmt)).await? {
+            records.insert(
+                row.try_get::<String>("", "migration_version")?,
+                row.try_get::<String>("", "applied_in")?,
+            );
+        }
+        Ok(records)
+    }
+}
@@ -2,6 +2,7 @@
 
 pub use sea_orm_migration::prelude::*;
 pub use sea_orm_migration::MigrationStatus;
+mod guard;
 mod m20230908_072257_init;
 mod m20231008_020431_hummock;
 mod m20240304_074901_subscription;
@@ -21,6 +22,22 @@ mod m20240702_084927_unnecessary_fk;
 mod m20240726_063833_auto_schema_change;
 mod m20240806_143329_add_rate_limit_to_source_catalog;
 mod m20240820_081248_add_time_travel_per_table_epoch;
+mod m20240825_090000_table_annotations;
+mod m20240828_101500_database_barrier_interval;
+mod m20240901_083000_view_invalidated_reason;
+mod m20240902_110000_migration_guard;
+mod m20240905_120000_table_soft_drop;
+mod m20240908_100000_user_granted_roles;
+mod m20240909_100000_schema_default_owner;
+mod m20240910_100000_secret_version;
+mod m20240912_100000_table_storage_class_policy;
+mod m20240913_100000_table_schema_change_policy;
+mod m20240914_100000_streaming_job_labels;
+mod m20240915_100000_database_session_defaults;
+mod m20240917_100000_subscription_retention_policy;
+mod m20240918_100000_ddl_audit_log;
+mod m20240919_100000_table_priority;
+mod m20240920_100000_usage_record;
 
 pub struct Migrator;
 
@@ -47,6 +64,22 @@ impl MigratorTrait for Migrator {
             Box::new(m20240726_063833_auto_schema_change::Migration),
             Box::new(m20240806_143329_add_rate_limit_to_source_catalog::Migration),
             Box::new(m20240820_081248_add_time_travel_per_table_epoch::Migration),
+            Box::new(m20240825_090000_table_annotations::Migration),
+            Box::new(m20240828_101500_database_barrier_interval::Migration),
+            Box::new(m20240901_083000_view_invalidated_reason::Migration),
+            Box::new(m20240902_110000_migration_guard::Migration),
+            Box::new(m20240905_120000_table_soft_drop::Migration),
+            Box::new(m20240908_100000_user_granted_roles::Migration),
+            Box::new(m20240909_100000_schema_default_owner::Migration),
+            Box::new(m20240910_100000_secret_version::Migration),
+            Box::new(m20240912_100000_table_storage_class_policy::Migration),
+            Box::new(m20240913_100000_table_schema_change_policy::Migration),
+            Box::new(m20240914_100000_streaming_job_labels::Migration),
+            Box::new(m20240915_100000_database_session_defaults::Migration),
+            Box::new(m20240917_100000_subscription_retention_policy::Migration),
+            Box::new(m20240918_100000_ddl_audit_log::Migration),
+            Box::new(m20240919_100000_table_priority::Migration),
+            Box::new(m20240920_100000_usage_record::Migration),
         ]
     }
 }
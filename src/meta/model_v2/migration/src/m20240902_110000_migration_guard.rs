@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MigrationGuard::Table)
+                    .col(
+                        ColumnDef::new(MigrationGuard::MigrationVersion)
+                            .string()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MigrationGuard::AppliedIn)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MigrationGuard::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MigrationGuard {
+    Table,
+    MigrationVersion,
+    AppliedIn,
+}
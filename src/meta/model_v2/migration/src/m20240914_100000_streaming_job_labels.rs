@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::{Table as MigrationTable, *};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                MigrationTable::alter()
+                    .table(Table::Table)
+                    .add_column(ColumnDef::new(Table::Labels).json_binary())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                MigrationTable::alter()
+                    .table(Sink::Table)
+                    .add_column(ColumnDef::new(Sink::Labels).json_binary())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                MigrationTable::alter()
+                    .table(Table::Table)
+                    .drop_column(Table::Labels)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                MigrationTable::alter()
+                    .table(Sink::Table)
+                    .drop_column(Sink::Labels)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Table {
+    Table,
+    Labels,
+}
+
+#[derive(DeriveIden)]
+enum Sink {
+    Table,
+    Labels,
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::{Table as MigrationTable, *};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                MigrationTable::alter()
+                    .table(Database::Table)
+                    .add_column(ColumnDef::new(Database::DefaultTimezone).string())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                MigrationTable::alter()
+                    .table(Database::Table)
+                    .add_column(ColumnDef::new(Database::DefaultCollation).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                MigrationTable::alter()
+                    .table(Database::Table)
+                    .drop_column(Database::DefaultTimezone)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                MigrationTable::alter()
+                    .table(Database::Table)
+                    .drop_column(Database::DefaultCollation)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Database {
+    Table,
+    DefaultTimezone,
+    DefaultCollation,
+}
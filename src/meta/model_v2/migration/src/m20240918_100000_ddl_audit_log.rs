@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DdlAuditLog::Table)
+                    .col(
+                        ColumnDef::new(DdlAuditLog::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(DdlAuditLog::EventTimeMs)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(DdlAuditLog::Command).string().not_null())
+                    .col(ColumnDef::new(DdlAuditLog::ObjectId).integer().not_null())
+                    .col(ColumnDef::new(DdlAuditLog::ObjectName).string().not_null())
+                    .col(ColumnDef::new(DdlAuditLog::UserId).integer().not_null())
+                    .col(ColumnDef::new(DdlAuditLog::Definition).text().not_null())
+                    .col(ColumnDef::new(DdlAuditLog::Success).boolean().not_null())
+                    .col(ColumnDef::new(DdlAuditLog::Error).text().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DdlAuditLog::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum DdlAuditLog {
+    Table,
+    Id,
+    EventTimeMs,
+    Command,
+    ObjectId,
+    ObjectName,
+    UserId,
+    Definition,
+    Success,
+    Error,
+}
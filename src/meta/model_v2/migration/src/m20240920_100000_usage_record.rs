@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UsageRecord::Table)
+                    .col(
+                        ColumnDef::new(UsageRecord::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UsageRecord::DatabaseId).integer().not_null())
+                    .col(
+                        ColumnDef::new(UsageRecord::WindowStartMs)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UsageRecord::WindowEndMs)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UsageRecord::StateBytes)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UsageRecord::ActorSeconds)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UsageRecord::BarrierCount)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UsageRecord::SinkEgressBytes)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UsageRecord::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UsageRecord {
+    Table,
+    Id,
+    DatabaseId,
+    WindowStartMs,
+    WindowEndMs,
+    StateBytes,
+    ActorSeconds,
+    BarrierCount,
+    SinkEgressBytes,
+}
@@ -26,6 +26,9 @@ pub struct Model {
     pub database_id: DatabaseId,
     #[sea_orm(unique)]
     pub name: String,
+    pub barrier_interval_ms: Option<i32>,
+    pub default_timezone: Option<String>,
+    pub default_collation: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -53,6 +56,9 @@ impl From<PbDatabase> for ActiveModel {
         Self {
             database_id: Set(db.id as _),
             name: Set(db.name),
+            barrier_interval_ms: Set(db.barrier_interval_ms.map(|i| i as _)),
+            default_timezone: Set(db.default_timezone),
+            default_collation: Set(db.default_collation),
         }
     }
 }
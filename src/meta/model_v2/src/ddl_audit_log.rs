@@ -0,0 +1,55 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::ddl_service::PbDdlAuditLog;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "ddl_audit_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub event_time_ms: i64,
+    pub command: String,
+    pub object_id: i32,
+    pub object_name: String,
+    pub user_id: i32,
+    #[sea_orm(column_type = "Text")]
+    pub definition: String,
+    pub success: bool,
+    #[sea_orm(column_type = "Text")]
+    pub error: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for PbDdlAuditLog {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id as _,
+            event_time_ms: model.event_time_ms as _,
+            command: model.command,
+            object_id: model.object_id as _,
+            object_name: model.object_name,
+            user_id: model.user_id as _,
+            definition: model.definition,
+            success: model.success,
+            error: model.error,
+        }
+    }
+}
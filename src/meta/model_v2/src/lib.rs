@@ -33,6 +33,7 @@ pub mod compaction_status;
 pub mod compaction_task;
 pub mod connection;
 pub mod database;
+pub mod ddl_audit_log;
 pub mod fragment;
 pub mod function;
 pub mod hummock_epoch_to_version;
@@ -57,6 +58,7 @@ pub mod streaming_job;
 pub mod subscription;
 pub mod system_parameter;
 pub mod table;
+pub mod usage_record;
 pub mod user;
 pub mod user_privilege;
 pub mod view;
@@ -318,7 +320,8 @@ macro_rules! derive_btreemap_from_blob {
     };
 }
 
-pub(crate) use {derive_array_from_blob, derive_from_blob};
+pub(crate) use derive_array_from_blob;
+pub(crate) use derive_from_blob;
 
 derive_from_json_struct!(I32Array, Vec<i32>);
 
@@ -392,6 +395,10 @@ derive_array_from_blob!(
 derive_from_blob!(SinkFormatDesc, risingwave_pb::catalog::PbSinkFormatDesc);
 derive_from_blob!(Cardinality, risingwave_pb::plan_common::PbCardinality);
 derive_from_blob!(TableVersion, risingwave_pb::catalog::table::PbTableVersion);
+derive_from_blob!(
+    StorageClassPolicy,
+    risingwave_pb::catalog::PbStorageClassPolicy
+);
 derive_from_blob!(
     PrivateLinkService,
     risingwave_pb::catalog::connection::PbPrivateLinkService
@@ -21,6 +21,7 @@ pub use super::compaction_status::Entity as CompactionStatus;
 pub use super::compaction_task::Entity as CompactionTask;
 pub use super::connection::Entity as Connection;
 pub use super::database::Entity as Database;
+pub use super::ddl_audit_log::Entity as DdlAuditLog;
 pub use super::fragment::Entity as Fragment;
 pub use super::function::Entity as Function;
 pub use super::hummock_pinned_snapshot::Entity as HummockPinnedSnapshot;
@@ -40,6 +41,7 @@ pub use super::streaming_job::Entity as StreamingJob;
 pub use super::subscription::Entity as Subscription;
 pub use super::system_parameter::Entity as SystemParameter;
 pub use super::table::Entity as Table;
+pub use super::usage_record::Entity as UsageRecord;
 pub use super::user::Entity as User;
 pub use super::user_privilege::Entity as UserPrivilege;
 pub use super::view::Entity as View;
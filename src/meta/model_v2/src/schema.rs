@@ -17,7 +17,7 @@ use sea_orm::entity::prelude::*;
 use sea_orm::ActiveValue::Set;
 use serde::{Deserialize, Serialize};
 
-use crate::SchemaId;
+use crate::{SchemaId, UserId};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "schema")]
@@ -25,6 +25,9 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub schema_id: SchemaId,
     pub name: String,
+    /// The default owner of objects created in this schema afterwards, if configured.
+    /// Nullable to keep compatibility with rows created before this column was added.
+    pub default_owner_id: Option<UserId>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -52,6 +55,7 @@ impl From<PbSchema> for ActiveModel {
         Self {
             schema_id: Set(schema.id as _),
             name: Set(schema.name),
+            default_owner_id: Set(schema.default_owner_id.map(|id| id as _)),
         }
     }
 }
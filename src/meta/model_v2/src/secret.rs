@@ -25,6 +25,7 @@ pub struct Model {
     pub name: String,
     #[sea_orm(column_type = "Binary(BlobSize::Blob(None))")]
     pub value: Vec<u8>,
+    pub version: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -53,6 +54,7 @@ impl From<PbSecret> for ActiveModel {
             secret_id: Set(secret.id as _),
             name: Set(secret.name),
             value: Set(secret.value),
+            version: Set(secret.version as _),
         }
     }
 }
@@ -75,6 +75,7 @@ pub struct Model {
     // `secret_ref` stores the mapping info mapping from property name to secret id and type.
     pub secret_ref: Option<SecretRef>,
     pub original_target_columns: Option<ColumnCatalogArray>,
+    pub labels: Option<Property>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -132,6 +133,7 @@ impl From<PbSink> for ActiveModel {
             target_table: Set(pb_sink.target_table.map(|x| x as _)),
             secret_ref: Set(Some(SecretRef::from(pb_sink.secret_refs))),
             original_target_columns: Set(Some(pb_sink.original_target_columns.into())),
+            labels: Set(Some(pb_sink.labels.into())),
         }
     }
 }
@@ -26,6 +26,9 @@ pub struct Model {
     pub subscription_id: SubscriptionId,
     pub name: String,
     pub retention_seconds: i64,
+    pub retention_policy: i32,
+    pub retention_epoch_count: i64,
+    pub acked_epoch: i64,
     pub definition: String,
     pub subscription_state: i32,
     pub dependent_table_id: i32,
@@ -57,6 +60,9 @@ impl From<PbSubscription> for ActiveModel {
             subscription_id: Set(pb_subscription.id as _),
             name: Set(pb_subscription.name),
             retention_seconds: Set(pb_subscription.retention_seconds as _),
+            retention_policy: Set(pb_subscription.retention_policy),
+            retention_epoch_count: Set(pb_subscription.retention_epoch_count as _),
+            acked_epoch: Set(pb_subscription.acked_epoch as _),
             definition: Set(pb_subscription.definition),
             subscription_state: Set(pb_subscription.subscription_state),
             dependent_table_id: Set(pb_subscription.dependent_table_id as _),
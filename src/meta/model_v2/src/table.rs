@@ -12,17 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use risingwave_common::catalog::OBJECT_ID_PLACEHOLDER;
-use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, PbTableType};
-use risingwave_pb::catalog::{PbHandleConflictBehavior, PbTable};
+use risingwave_pb::catalog::table::{
+    OptionalAssociatedSourceId, PbSchemaChangePolicy, PbTableType,
+};
+use risingwave_pb::catalog::{PbHandleConflictBehavior, PbStreamJobPriority, PbTable};
 use sea_orm::entity::prelude::*;
 use sea_orm::ActiveValue::Set;
 use sea_orm::NotSet;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    Cardinality, ColumnCatalogArray, ColumnOrderArray, FragmentId, I32Array, ObjectId, SourceId,
-    TableId, TableVersion,
+    Cardinality, ColumnCatalogArray, ColumnOrderArray, FragmentId, I32Array, ObjectId, Property,
+    SourceId, StorageClassPolicy, TableId, TableVersion,
 };
 
 #[derive(
@@ -101,6 +105,82 @@ impl From<PbHandleConflictBehavior> for HandleConflictBehavior {
     }
 }
 
+#[derive(
+    Clone, Debug, PartialEq, Hash, Copy, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum SchemaChangePolicy {
+    #[sea_orm(string_value = "ALLOW_ADD_COLUMN")]
+    AllowAddColumn,
+    #[sea_orm(string_value = "ALLOW_DROP_COLUMN")]
+    AllowDropColumn,
+    #[sea_orm(string_value = "BLOCK")]
+    Block,
+    #[sea_orm(string_value = "ALERT_ONLY")]
+    AlertOnly,
+}
+
+impl From<SchemaChangePolicy> for PbSchemaChangePolicy {
+    fn from(policy: SchemaChangePolicy) -> Self {
+        match policy {
+            SchemaChangePolicy::AllowAddColumn => Self::AllowAddColumn,
+            SchemaChangePolicy::AllowDropColumn => Self::AllowDropColumn,
+            SchemaChangePolicy::Block => Self::Block,
+            SchemaChangePolicy::AlertOnly => Self::AlertOnly,
+        }
+    }
+}
+
+impl From<PbSchemaChangePolicy> for SchemaChangePolicy {
+    fn from(policy: PbSchemaChangePolicy) -> Self {
+        match policy {
+            PbSchemaChangePolicy::AllowAddColumn => Self::AllowAddColumn,
+            PbSchemaChangePolicy::AllowDropColumn => Self::AllowDropColumn,
+            PbSchemaChangePolicy::Block => Self::Block,
+            PbSchemaChangePolicy::AlertOnly => Self::AlertOnly,
+            PbSchemaChangePolicy::Unspecified => {
+                unreachable!("Unspecified schema change policy")
+            }
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Hash, Copy, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum StreamJobPriority {
+    #[sea_orm(string_value = "LOW")]
+    Low,
+    #[sea_orm(string_value = "NORMAL")]
+    Normal,
+    #[sea_orm(string_value = "HIGH")]
+    High,
+}
+
+impl From<StreamJobPriority> for PbStreamJobPriority {
+    fn from(priority: StreamJobPriority) -> Self {
+        match priority {
+            StreamJobPriority::Low => Self::Low,
+            StreamJobPriority::Normal => Self::Normal,
+            StreamJobPriority::High => Self::High,
+        }
+    }
+}
+
+impl From<PbStreamJobPriority> for StreamJobPriority {
+    fn from(priority: PbStreamJobPriority) -> Self {
+        match priority {
+            PbStreamJobPriority::Low => Self::Low,
+            PbStreamJobPriority::Normal => Self::Normal,
+            PbStreamJobPriority::High => Self::High,
+            PbStreamJobPriority::Unspecified => {
+                unreachable!("Unspecified stream job priority")
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "table")]
 pub struct Model {
@@ -133,6 +213,12 @@ pub struct Model {
     pub retention_seconds: Option<i32>,
     pub incoming_sinks: I32Array,
     pub cdc_table_id: Option<String>,
+    pub annotations: Option<Property>,
+    pub soft_dropped_at_ms: Option<i64>,
+    pub storage_class_policy: Option<StorageClassPolicy>,
+    pub schema_change_policy: Option<SchemaChangePolicy>,
+    pub labels: Option<Property>,
+    pub priority: Option<StreamJobPriority>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -255,6 +341,30 @@ impl From<PbTable> for ActiveModel {
             retention_seconds: Set(pb_table.retention_seconds.map(|i| i as _)),
             incoming_sinks: Set(pb_table.incoming_sinks.into()),
             cdc_table_id: Set(pb_table.cdc_table_id),
+            annotations: Set(Some(
+                pb_table
+                    .annotations
+                    .into_iter()
+                    .collect::<BTreeMap<_, _>>()
+                    .into(),
+            )),
+            soft_dropped_at_ms: Set(pb_table.soft_dropped_at_ms.map(|ts| ts as _)),
+            storage_class_policy: Set(pb_table.storage_class_policy.as_ref().map(|p| p.into())),
+            schema_change_policy: Set(match pb_table.schema_change_policy() {
+                PbSchemaChangePolicy::Unspecified => None,
+                policy => Some(policy.into()),
+            }),
+            labels: Set(Some(
+                pb_table
+                    .labels
+                    .into_iter()
+                    .collect::<BTreeMap<_, _>>()
+                    .into(),
+            )),
+            priority: Set(match pb_table.priority() {
+                PbStreamJobPriority::Unspecified => None,
+                priority => Some(priority.into()),
+            }),
         }
     }
 }
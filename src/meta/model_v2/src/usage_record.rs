@@ -0,0 +1,51 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::meta::PbUsageRecord;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "usage_record")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub database_id: i32,
+    pub window_start_ms: i64,
+    pub window_end_ms: i64,
+    pub state_bytes: i64,
+    pub actor_seconds: i64,
+    pub barrier_count: i64,
+    pub sink_egress_bytes: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for PbUsageRecord {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id as _,
+            database_id: model.database_id as _,
+            window_start_ms: model.window_start_ms as _,
+            window_end_ms: model.window_end_ms as _,
+            state_bytes: model.state_bytes as _,
+            actor_seconds: model.actor_seconds as _,
+            barrier_count: model.barrier_count as _,
+            sink_egress_bytes: model.sink_egress_bytes as _,
+        }
+    }
+}
@@ -18,7 +18,7 @@ use sea_orm::ActiveValue::Set;
 use sea_orm::NotSet;
 use serde::{Deserialize, Serialize};
 
-use crate::{AuthInfo, UserId};
+use crate::{AuthInfo, I32Array, UserId};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "user")]
@@ -32,6 +32,9 @@ pub struct Model {
     pub can_create_user: bool,
     pub can_login: bool,
     pub auth_info: Option<AuthInfo>,
+    /// Ids of the roles granted to this user. Nullable for rows created before the
+    /// column was introduced.
+    pub granted_roles: Option<I32Array>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -63,6 +66,7 @@ impl From<PbUserInfo> for ActiveModel {
             can_create_user: Set(user.can_create_user),
             can_login: Set(user.can_login),
             auth_info: Set(user.auth_info.as_ref().map(AuthInfo::from)),
+            granted_roles: Set(Some(user.granted_roles.into())),
         }
     }
 }
@@ -78,6 +82,10 @@ impl From<Model> for PbUserInfo {
             can_login: val.can_login,
             auth_info: val.auth_info.map(|x| x.to_protobuf()),
             grant_privileges: vec![], // fill in later
+            granted_roles: val
+                .granted_roles
+                .map(|roles| roles.into_u32_array())
+                .unwrap_or_default(),
         }
     }
 }
@@ -28,6 +28,7 @@ pub struct Model {
     pub properties: Property,
     pub definition: String,
     pub columns: FieldArray,
+    pub invalidated_reason: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -58,6 +59,7 @@ impl From<PbView> for ActiveModel {
             properties: Set(Property(view.properties)),
             definition: Set(view.sql),
             columns: Set(view.columns.into()),
+            invalidated_reason: Set(view.invalidated_reason),
         }
     }
 }
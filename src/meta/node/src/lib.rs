@@ -373,6 +373,9 @@ pub fn start(
                 compaction_deterministic_test: config.meta.enable_compaction_deterministic,
                 default_parallelism: config.meta.default_parallelism,
                 vacuum_interval_sec: config.meta.vacuum_interval_sec,
+                soft_drop_retention_sec: config.meta.soft_drop_retention_sec,
+                enable_dangerous_grant_check: config.meta.enable_dangerous_grant_check,
+                dangerous_grant_allowlist: config.meta.dangerous_grant_allowlist.clone(),
                 vacuum_spin_interval_ms: config.meta.vacuum_spin_interval_ms,
                 hummock_version_checkpoint_interval_sec: config
                     .meta
@@ -392,6 +395,19 @@ pub fn start(
                 enable_committed_sst_sanity_check: config.meta.enable_committed_sst_sanity_check,
                 periodic_compaction_interval_sec: config.meta.periodic_compaction_interval_sec,
                 node_num_monitor_interval_sec: config.meta.node_num_monitor_interval_sec,
+                dirty_job_gc_interval_sec: config.meta.dirty_job_gc_interval_sec,
+                connection_health_check_interval_sec: config
+                    .meta
+                    .connection_health_check_interval_sec,
+                auto_backup_interval_sec: config.meta.auto_backup_interval_sec,
+                catalog_compaction_interval_sec: config.meta.catalog_compaction_interval_sec,
+                catalog_memory_soft_limit_bytes: config.meta.catalog_memory_soft_limit_bytes,
+                catalog_read_rate_limit_per_client: config
+                    .meta
+                    .catalog_read_rate_limit_per_client,
+                catalog_read_concurrency_per_client: config
+                    .meta
+                    .catalog_read_concurrency_per_client,
                 prometheus_endpoint: opts.prometheus_endpoint,
                 prometheus_selector: opts.prometheus_selector,
                 vpc_id: opts.vpc_id,
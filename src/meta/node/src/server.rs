@@ -33,7 +33,7 @@ use risingwave_meta::manager::{
     MetaStoreImpl, MetadataManager, SystemParamsManagerImpl, META_NODE_ID,
 };
 use risingwave_meta::rpc::election::dummy::DummyElectionClient;
-use risingwave_meta::rpc::intercept::MetricsMiddlewareLayer;
+use risingwave_meta::rpc::intercept::{CatalogRateLimitLayer, MetricsMiddlewareLayer};
 use risingwave_meta::rpc::ElectionClientRef;
 use risingwave_meta::stream::ScaleController;
 use risingwave_meta::MetaStoreBackend;
@@ -46,7 +46,7 @@ use risingwave_meta_service::event_log_service::EventLogServiceImpl;
 use risingwave_meta_service::health_service::HealthServiceImpl;
 use risingwave_meta_service::heartbeat_service::HeartbeatServiceImpl;
 use risingwave_meta_service::hummock_service::HummockServiceImpl;
-use risingwave_meta_service::meta_member_service::MetaMemberServiceImpl;
+use risingwave_meta_service::meta_member_service::{LeaderHandoverContext, MetaMemberServiceImpl};
 use risingwave_meta_service::notification_service::NotificationServiceImpl;
 use risingwave_meta_service::scale_service::ScaleServiceImpl;
 use risingwave_meta_service::serving_service::ServingServiceImpl;
@@ -87,10 +87,12 @@ use crate::barrier::{BarrierScheduler, GlobalBarrierManager};
 use crate::controller::system_param::SystemParamsController;
 use crate::controller::SqlMetaStore;
 use crate::hummock::HummockManager;
-use crate::manager::sink_coordination::SinkCoordinatorManager;
+use crate::manager::sink_coordination::{SinkCoordinatorManager, SinkTransactionLog};
 use crate::manager::{
-    CatalogManager, ClusterManager, FragmentManager, IdleManager, MetaOpts, MetaSrvEnv,
-    SystemParamsManager,
+    start_usage_report_sampler, CatalogManager, ClusterManager, ConnectionHealthChecker,
+    ConsistencyFenceManager, FragmentManager, IdleManager, MetaOpts, MetaSrvEnv,
+    NamedCheckpointManager, RateLimitBoostManager, SystemParamsManager,
+    UsageReportManager,
 };
 use crate::rpc::cloud_provider::AwsEc2Client;
 use crate::rpc::election::etcd::EtcdElectionClient;
@@ -98,9 +100,10 @@ use crate::rpc::election::sql::{
     MySqlDriver, PostgresDriver, SqlBackendElectionClient, SqliteDriver,
 };
 use crate::rpc::metrics::{
-    start_fragment_info_monitor, start_worker_info_monitor, GLOBAL_META_METRICS,
+    start_catalog_memory_monitor, start_fragment_info_monitor, start_worker_info_monitor,
+    GLOBAL_META_METRICS,
 };
-use crate::serving::ServingVnodeMapping;
+use crate::serving::{RelationReadStatsAggregator, ServingVnodeMapping};
 use crate::storage::{EtcdMetaStore, MemStore, MetaStoreBoxExt, WrappedEtcdClient as EtcdClient};
 use crate::stream::{GlobalStreamManager, SourceManager};
 use crate::telemetry::{MetaReportCreator, MetaTelemetryInfoFetcher};
@@ -297,6 +300,7 @@ pub async fn rpc_serve_with_store(
             follower_shutdown.clone(),
             address_info.clone(),
             election_client.clone(),
+            lease_interval_secs,
         ));
 
         // Watch and wait until we become the leader.
@@ -329,6 +333,7 @@ pub async fn rpc_serve_with_store(
         init_system_params,
         init_session_config,
         election_client,
+        lease_interval_secs,
         shutdown,
     )
     .await;
@@ -347,10 +352,11 @@ pub async fn start_service_as_election_follower(
     shutdown: CancellationToken,
     address_info: AddressInfo,
     election_client: ElectionClientRef,
+    lease_interval_secs: u64,
 ) {
     tracing::info!("starting follower services");
 
-    let meta_member_srv = MetaMemberServiceImpl::new(election_client);
+    let meta_member_srv = MetaMemberServiceImpl::new(election_client, lease_interval_secs, None);
 
     let health_srv = HealthServiceImpl::new();
 
@@ -391,6 +397,7 @@ pub async fn start_service_as_election_leader(
     init_system_params: SystemParams,
     init_session_config: SessionConfig,
     election_client: ElectionClientRef,
+    lease_interval_secs: u64,
     shutdown: CancellationToken,
 ) -> MetaResult<()> {
     tracing::info!("starting leader services");
@@ -437,6 +444,7 @@ pub async fn start_service_as_election_leader(
     };
 
     let serving_vnode_mapping = Arc::new(ServingVnodeMapping::default());
+    let relation_read_stats = Arc::new(RelationReadStatsAggregator::default());
     serving::on_meta_start(
         env.notification_manager_ref(),
         &metadata_manager,
@@ -468,8 +476,6 @@ pub async fn start_service_as_election_leader(
     .unwrap();
     let object_store_media_type = hummock_manager.object_store_media_type();
 
-    let meta_member_srv = MetaMemberServiceImpl::new(election_client.clone());
-
     let prometheus_client = opts.prometheus_endpoint.as_ref().map(|x| {
         use std::str::FromStr;
         prometheus_http_query::Client::from_str(x).unwrap()
@@ -510,6 +516,11 @@ pub async fn start_service_as_election_leader(
         hummock_manager.clone(),
         meta_metrics.clone(),
         system_params_reader.checkpoint_frequency() as usize,
+        (
+            system_params_reader.adaptive_checkpoint_frequency_min() as usize,
+            system_params_reader.adaptive_checkpoint_frequency_max() as usize,
+        ),
+        system_params_reader.barrier_backpressure_max_stretch() as u32,
     );
 
     // Initialize services.
@@ -547,7 +558,8 @@ pub async fn start_service_as_election_leader(
         .unwrap(),
     );
 
-    let (sink_manager, shutdown_handle) = SinkCoordinatorManager::start_worker();
+    let (sink_manager, shutdown_handle) =
+        SinkCoordinatorManager::start_worker(SinkTransactionLog::new(env.meta_store_ref()));
     // TODO(shutdown): remove this as there's no need to gracefully shutdown some of these sub-tasks.
     let mut sub_tasks = vec![shutdown_handle];
 
@@ -629,15 +641,50 @@ pub async fn start_service_as_election_leader(
         scale_controller.clone(),
     );
 
-    let cluster_srv =
-        ClusterServiceImpl::new(metadata_manager.clone(), barrier_manager.context().clone());
+    let usage_report_manager = Arc::new(UsageReportManager::new(env.clone()));
+    let cluster_srv = ClusterServiceImpl::new(
+        env.clone(),
+        metadata_manager.clone(),
+        barrier_manager.context().clone(),
+        usage_report_manager.clone(),
+    );
+    let named_checkpoint_manager = Arc::new(NamedCheckpointManager::new(
+        env.clone(),
+        hummock_manager.clone(),
+        barrier_scheduler.clone(),
+    ));
+    let rate_limit_boost_manager = Arc::new(RateLimitBoostManager::new(
+        env.clone(),
+        metadata_manager.clone(),
+        barrier_scheduler.clone(),
+    ));
+    let consistency_fence_manager = Arc::new(ConsistencyFenceManager::new(
+        hummock_manager.clone(),
+        barrier_scheduler.clone(),
+    ));
+    // Rebuild the revert timers of rate limit boosts persisted before a failover.
+    rate_limit_boost_manager.schedule_persisted_reverts().await?;
     let stream_srv = StreamServiceImpl::new(
         env.clone(),
         barrier_scheduler.clone(),
         stream_manager.clone(),
         metadata_manager.clone(),
+        barrier_manager.context().clone(),
+        named_checkpoint_manager,
+        rate_limit_boost_manager,
+        consistency_fence_manager,
+        hummock_manager.clone(),
     );
     let sink_coordination_srv = SinkCoordinationServiceImpl::new(sink_manager);
+    let meta_member_srv = MetaMemberServiceImpl::new(
+        election_client.clone(),
+        lease_interval_secs,
+        Some(LeaderHandoverContext {
+            barrier_scheduler: barrier_scheduler.clone(),
+            notification_manager: env.notification_manager_ref(),
+            shutdown: shutdown.clone(),
+        }),
+    );
     let hummock_srv = HummockServiceImpl::new(
         hummock_manager.clone(),
         vacuum_manager.clone(),
@@ -645,12 +692,17 @@ pub async fn start_service_as_election_leader(
     );
 
     let health_srv = HealthServiceImpl::new();
-    let backup_srv = BackupServiceImpl::new(backup_manager);
+    let backup_srv = BackupServiceImpl::new(backup_manager.clone());
     let telemetry_srv = TelemetryInfoServiceImpl::new(env.meta_store());
     let system_params_srv = SystemParamsServiceImpl::new(env.system_params_manager_impl_ref());
     let session_params_srv = SessionParamsServiceImpl::new(env.session_params_manager_impl_ref());
     let serving_srv =
-        ServingServiceImpl::new(serving_vnode_mapping.clone(), metadata_manager.clone());
+        ServingServiceImpl::new(
+        serving_vnode_mapping.clone(),
+        relation_read_stats.clone(),
+        metadata_manager.clone(),
+    );
+    let connection_aws_client = Arc::new(aws_cli.clone());
     let cloud_srv = CloudServiceImpl::new(metadata_manager.clone(), aws_cli);
     let event_log_srv = EventLogServiceImpl::new(env.event_log_manager_ref());
     let cluster_limit_srv = ClusterLimitServiceImpl::new(env.clone(), metadata_manager.clone());
@@ -677,6 +729,17 @@ pub async fn start_service_as_election_leader(
         hummock_manager.clone(),
         meta_metrics.clone(),
     ));
+    sub_tasks.push(start_catalog_memory_monitor(
+        metadata_manager.clone(),
+        meta_metrics.clone(),
+        env.opts.catalog_memory_soft_limit_bytes,
+    ));
+    sub_tasks.push(start_usage_report_sampler(
+        usage_report_manager,
+        metadata_manager.clone(),
+        hummock_manager.clone(),
+        meta_metrics.clone(),
+    ));
     match env.system_params_manager_impl_ref() {
         SystemParamsManagerImpl::Kv(mgr) => {
             sub_tasks.push(SystemParamsManager::start_params_notifier(mgr));
@@ -713,9 +776,45 @@ pub async fn start_service_as_election_leader(
         sub_tasks.push(task);
         sub_tasks.push(GlobalBarrierManager::start(barrier_manager));
 
+        if env.opts.dirty_job_gc_interval_sec > 0
+            && matches!(metadata_manager, MetadataManager::V1(_))
+        {
+            sub_tasks.push(
+                stream_manager
+                    .clone()
+                    .start_dirty_job_gc(Duration::from_secs(env.opts.dirty_job_gc_interval_sec)),
+            );
+        }
+
+        if env.opts.catalog_compaction_interval_sec > 0 {
+            if let MetadataManager::V1(mgr) = &metadata_manager {
+                sub_tasks.push(mgr.catalog_manager.clone().start_catalog_compaction(
+                    backup_manager.clone(),
+                    Duration::from_secs(env.opts.catalog_compaction_interval_sec),
+                ));
+            }
+        }
+
+        if env.opts.connection_health_check_interval_sec > 0 {
+            sub_tasks.push(
+                ConnectionHealthChecker::new(metadata_manager.clone(), connection_aws_client)
+                    .start(Duration::from_secs(
+                        env.opts.connection_health_check_interval_sec,
+                    )),
+            );
+        }
+
         if !env.opts.disable_automatic_parallelism_control {
             sub_tasks.push(stream_manager.start_auto_parallelism_monitor());
         }
+
+        if env.opts.auto_backup_interval_sec > 0 {
+            sub_tasks.push(
+                backup_manager
+                    .clone()
+                    .start_scheduled_backup(Duration::from_secs(env.opts.auto_backup_interval_sec)),
+            );
+        }
     }
 
     let _idle_checker_handle = IdleManager::start_idle_checker(
@@ -771,7 +870,12 @@ pub async fn start_service_as_election_leader(
     ]);
 
     let server_builder = tonic::transport::Server::builder()
-        .layer(MetricsMiddlewareLayer::new(meta_metrics))
+        .layer(MetricsMiddlewareLayer::new(meta_metrics.clone()))
+        .layer(CatalogRateLimitLayer::new(
+            env.opts.catalog_read_rate_limit_per_client,
+            env.opts.catalog_read_concurrency_per_client,
+            meta_metrics,
+        ))
         .layer(TracingExtractLayer::new())
         .add_service(HeartbeatServiceServer::new(heartbeat_srv))
         .add_service(ClusterServiceServer::new(cluster_srv))
@@ -18,7 +18,7 @@ use risingwave_pb::backup_service::backup_service_server::BackupService;
 use risingwave_pb::backup_service::{
     BackupMetaRequest, BackupMetaResponse, DeleteMetaSnapshotRequest, DeleteMetaSnapshotResponse,
     GetBackupJobStatusRequest, GetBackupJobStatusResponse, GetMetaSnapshotManifestRequest,
-    GetMetaSnapshotManifestResponse,
+    GetMetaSnapshotManifestResponse, RestoreMetaRequest, RestoreMetaResponse,
 };
 use tonic::{Request, Response, Status};
 
@@ -75,4 +75,14 @@ impl BackupService for BackupServiceImpl {
             manifest: Some(self.backup_manager.manifest().deref().into()),
         }))
     }
+
+    async fn restore_meta(
+        &self,
+        request: Request<RestoreMetaRequest>,
+    ) -> Result<Response<RestoreMetaResponse>, Status> {
+        self.backup_manager
+            .restore_meta(request.into_inner())
+            .await?;
+        Ok(Response::new(RestoreMetaResponse {}))
+    }
 }
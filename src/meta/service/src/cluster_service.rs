@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use risingwave_meta::barrier::BarrierManagerRef;
-use risingwave_meta::manager::MetadataManager;
+use risingwave_meta::manager::{MetaSrvEnv, MetadataManager, UsageReportManagerRef};
 use risingwave_meta_model_v2::WorkerId;
 use risingwave_pb::common::worker_node::State;
 use risingwave_pb::common::HostAddress;
@@ -21,9 +21,10 @@ use risingwave_pb::meta::cluster_service_server::ClusterService;
 use risingwave_pb::meta::{
     ActivateWorkerNodeRequest, ActivateWorkerNodeResponse, AddWorkerNodeRequest,
     AddWorkerNodeResponse, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse,
-    GetClusterRecoveryStatusRequest, GetClusterRecoveryStatusResponse, ListAllNodesRequest,
-    ListAllNodesResponse, UpdateWorkerNodeSchedulabilityRequest,
-    UpdateWorkerNodeSchedulabilityResponse,
+    EnterMaintenanceModeRequest, EnterMaintenanceModeResponse, ExitMaintenanceModeRequest,
+    ExitMaintenanceModeResponse, GetClusterRecoveryStatusRequest, GetClusterRecoveryStatusResponse,
+    GetUsageReportRequest, GetUsageReportResponse, ListAllNodesRequest, ListAllNodesResponse,
+    UpdateWorkerNodeSchedulabilityRequest, UpdateWorkerNodeSchedulabilityResponse,
 };
 use tonic::{Request, Response, Status};
 
@@ -31,15 +32,24 @@ use crate::MetaError;
 
 #[derive(Clone)]
 pub struct ClusterServiceImpl {
+    env: MetaSrvEnv,
     metadata_manager: MetadataManager,
     barrier_manager: BarrierManagerRef,
+    usage_report_manager: UsageReportManagerRef,
 }
 
 impl ClusterServiceImpl {
-    pub fn new(metadata_manager: MetadataManager, barrier_manager: BarrierManagerRef) -> Self {
+    pub fn new(
+        env: MetaSrvEnv,
+        metadata_manager: MetadataManager,
+        barrier_manager: BarrierManagerRef,
+        usage_report_manager: UsageReportManagerRef,
+    ) -> Self {
         ClusterServiceImpl {
+            env,
             metadata_manager,
             barrier_manager,
+            usage_report_manager,
         }
     }
 }
@@ -167,6 +177,7 @@ impl ClusterService for ClusterServiceImpl {
         Ok(Response::new(ListAllNodesResponse {
             status: None,
             nodes: node_list,
+            in_maintenance_mode: self.env.maintenance_mode().is_on(),
         }))
     }
 
@@ -178,4 +189,32 @@ impl ClusterService for ClusterServiceImpl {
             status: self.barrier_manager.get_recovery_status() as _,
         }))
     }
+
+    async fn enter_maintenance_mode(
+        &self,
+        _request: Request<EnterMaintenanceModeRequest>,
+    ) -> Result<Response<EnterMaintenanceModeResponse>, Status> {
+        self.env.enter_maintenance_mode().await?;
+        Ok(Response::new(EnterMaintenanceModeResponse { status: None }))
+    }
+
+    async fn exit_maintenance_mode(
+        &self,
+        _request: Request<ExitMaintenanceModeRequest>,
+    ) -> Result<Response<ExitMaintenanceModeResponse>, Status> {
+        self.env.exit_maintenance_mode().await?;
+        Ok(Response::new(ExitMaintenanceModeResponse { status: None }))
+    }
+
+    async fn get_usage_report(
+        &self,
+        request: Request<GetUsageReportRequest>,
+    ) -> Result<Response<GetUsageReportResponse>, Status> {
+        let req = request.into_inner();
+        let records = self
+            .usage_report_manager
+            .get_usage_report(req.database_id, req.start_time_ms, req.end_time_ms)
+            .await?;
+        Ok(Response::new(GetUsageReportResponse { records }))
+    }
 }
@@ -24,17 +24,20 @@ use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_connector::sink::catalog::SinkId;
 use risingwave_meta::manager::{EventLogManagerRef, MetadataManager};
 use risingwave_meta::rpc::ddl_controller::fill_table_stream_graph_info;
+use risingwave_meta::model::MetadataModel;
 use risingwave_meta::rpc::metrics::MetaMetrics;
+use risingwave_meta::rpc::property_schema::connector_schemas;
 use risingwave_pb::catalog::connection::private_link_service::{
     PbPrivateLinkProvider, PrivateLinkProvider,
 };
 use risingwave_pb::catalog::connection::PbPrivateLinkService;
-use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
+use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, PbSchemaChangePolicy, PbTableType};
 use risingwave_pb::catalog::{connection, Comment, Connection, CreateType, Secret, Table};
 use risingwave_pb::common::worker_node::State;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::ddl_service::ddl_service_server::DdlService;
 use risingwave_pb::ddl_service::drop_table_request::PbSourceId;
+use risingwave_pb::ddl_service::get_catalog_lock_stats_response::LockOperation;
 use risingwave_pb::ddl_service::*;
 use risingwave_pb::frontend_service::GetTableReplacePlanRequest;
 use risingwave_pb::meta::event_log;
@@ -43,7 +46,7 @@ use tonic::{Request, Response, Status};
 
 use crate::barrier::BarrierManagerRef;
 use crate::manager::sink_coordination::SinkCoordinatorManager;
-use crate::manager::{ConnectionId, MetaSrvEnv, StreamingJob};
+use crate::manager::{ConnectionId, DdlBatchOperation, MetaSrvEnv, StreamingJob};
 use crate::rpc::cloud_provider::AwsEc2Client;
 use crate::rpc::ddl_controller::{
     DdlCommand, DdlController, DropMode, ReplaceTableInfo, StreamingJobId,
@@ -115,6 +118,7 @@ impl DdlServiceImpl {
             streaming_job: stream_job,
             fragment_graph,
             col_index_mapping: table_col_index_mapping,
+            alter_lease_token: change.alter_lease_token,
         }
     }
 }
@@ -168,6 +172,7 @@ impl DdlService for DdlServiceImpl {
             value: req.get_value().clone(),
             owner: req.get_owner_id(),
             schema_id: req.get_schema_id(),
+            version: 1,
         };
         let version = self
             .ddl_controller
@@ -191,6 +196,19 @@ impl DdlService for DdlServiceImpl {
         Ok(Response::new(DropSecretResponse { version }))
     }
 
+    async fn rotate_secret(
+        &self,
+        request: Request<RotateSecretRequest>,
+    ) -> Result<Response<RotateSecretResponse>, Status> {
+        let req = request.into_inner();
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::RotateSecret(req.secret_id, req.value))
+            .await?;
+
+        Ok(Response::new(RotateSecretResponse { version }))
+    }
+
     async fn create_schema(
         &self,
         request: Request<CreateSchemaRequest>,
@@ -391,6 +409,28 @@ impl DdlService for DdlServiceImpl {
         }))
     }
 
+    async fn ack_subscription_epoch(
+        &self,
+        request: Request<AckSubscriptionEpochRequest>,
+    ) -> Result<Response<AckSubscriptionEpochResponse>, Status> {
+        let req = request.into_inner();
+
+        let version = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .ack_subscription_epoch(req.subscription_id, req.acked_epoch)
+                    .await?
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .ack_subscription_epoch(req.subscription_id as _, req.acked_epoch)
+                    .await?
+            }
+        };
+
+        Ok(Response::new(AckSubscriptionEpochResponse { version }))
+    }
+
     async fn create_materialized_view(
         &self,
         request: Request<CreateMaterializedViewRequest>,
@@ -427,7 +467,11 @@ impl DdlService for DdlServiceImpl {
 
         let request = request.into_inner();
         let table_id = request.table_id;
-        let drop_mode = DropMode::from_request_setting(request.cascade);
+        let drop_mode = if request.soft {
+            DropMode::SoftDelete
+        } else {
+            DropMode::from_request_setting(request.cascade)
+        };
 
         let version = self
             .ddl_controller
@@ -444,6 +488,24 @@ impl DdlService for DdlServiceImpl {
         }))
     }
 
+    async fn undrop_relation(
+        &self,
+        request: Request<UndropRelationRequest>,
+    ) -> Result<Response<UndropRelationResponse>, Status> {
+        let req = request.into_inner();
+        let relation = req.relation.unwrap();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::UndropRelation(relation))
+            .await?;
+
+        Ok(Response::new(UndropRelationResponse {
+            status: None,
+            version,
+        }))
+    }
+
     async fn create_index(
         &self,
         request: Request<CreateIndexRequest>,
@@ -505,7 +567,7 @@ impl DdlService for DdlServiceImpl {
 
         let version = self
             .ddl_controller
-            .run_command(DdlCommand::CreateFunction(function))
+            .run_command(DdlCommand::CreateFunction(function, req.or_replace))
             .await?;
 
         Ok(Response::new(CreateFunctionResponse {
@@ -566,7 +628,11 @@ impl DdlService for DdlServiceImpl {
         let source_id = request.source_id;
         let table_id = request.table_id;
 
-        let drop_mode = DropMode::from_request_setting(request.cascade);
+        let drop_mode = if request.soft {
+            DropMode::SoftDelete
+        } else {
+            DropMode::from_request_setting(request.cascade)
+        };
         let version = self
             .ddl_controller
             .run_command(DdlCommand::DropStreamingJob(
@@ -647,6 +713,22 @@ impl DdlService for DdlServiceImpl {
         }))
     }
 
+    async fn acquire_alter_lease(
+        &self,
+        request: Request<AcquireAlterLeaseRequest>,
+    ) -> Result<Response<AcquireAlterLeaseResponse>, Status> {
+        let req = request.into_inner();
+        let (lease_token, valid_for) = self
+            .ddl_controller
+            .acquire_alter_lease(req.table_id, req.force)?;
+
+        Ok(Response::new(AcquireAlterLeaseResponse {
+            status: None,
+            lease_token,
+            valid_for_ms: valid_for.as_millis() as u64,
+        }))
+    }
+
     async fn get_table(
         &self,
         request: Request<GetTableRequest>,
@@ -710,6 +792,73 @@ impl DdlService for DdlServiceImpl {
         }))
     }
 
+    async fn alter_source_add_column(
+        &self,
+        request: Request<AlterSourceAddColumnRequest>,
+    ) -> Result<Response<AlterSourceAddColumnResponse>, Status> {
+        let req = request.into_inner();
+        let source_id = req.source_id;
+        let column = req.get_column()?.clone();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterSourceAddColumn(
+                source_id,
+                column,
+                req.definition,
+                req.base_version,
+            ))
+            .await?;
+
+        // Preview which downstream jobs will not see the new column: the schemas of all
+        // jobs reading from the source were fixed when they were created.
+        let fixed_downstream_job_ids = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .list_source_dependent_job_ids(source_id)
+                    .await?
+            }
+            MetadataManager::V2(mgr) => mgr
+                .catalog_controller
+                .list_source_dependent_job_ids(source_id as _)
+                .await?
+                .into_iter()
+                .map(|id| id as u32)
+                .collect(),
+        };
+
+        Ok(Response::new(AlterSourceAddColumnResponse {
+            status: None,
+            version,
+            fixed_downstream_job_ids,
+        }))
+    }
+
+    async fn alter_connector_props(
+        &self,
+        request: Request<AlterConnectorPropsRequest>,
+    ) -> Result<Response<AlterConnectorPropsResponse>, Status> {
+        let request = request.into_inner();
+        let object_type = request.object_type();
+        if let MetadataManager::V2(_) = &self.metadata_manager {
+            return Err(Status::unimplemented(
+                "alter connector props is only supported by the kv meta backend",
+            ));
+        }
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterConnectorProps(
+                request.object_id,
+                object_type,
+                request.changed_props,
+            ))
+            .await?;
+        Ok(Response::new(AlterConnectorPropsResponse {
+            status: None,
+            version,
+        }))
+    }
+
     async fn alter_owner(
         &self,
         request: Request<AlterOwnerRequest>,
@@ -725,6 +874,27 @@ impl DdlService for DdlServiceImpl {
         }))
     }
 
+    async fn alter_schema_default_owner(
+        &self,
+        request: Request<AlterSchemaDefaultOwnerRequest>,
+    ) -> Result<Response<AlterSchemaDefaultOwnerResponse>, Status> {
+        let AlterSchemaDefaultOwnerRequest {
+            schema_id,
+            default_owner_id,
+        } = request.into_inner();
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterSchemaDefaultOwner(
+                schema_id,
+                default_owner_id,
+            ))
+            .await?;
+        Ok(Response::new(AlterSchemaDefaultOwnerResponse {
+            status: None,
+            version,
+        }))
+    }
+
     async fn alter_set_schema(
         &self,
         request: Request<AlterSetSchemaRequest>,
@@ -752,6 +922,50 @@ impl DdlService for DdlServiceImpl {
         }))
     }
 
+    async fn get_index_progress(
+        &self,
+        _request: Request<GetIndexProgressRequest>,
+    ) -> Result<Response<GetIndexProgressResponse>, Status> {
+        let ddl_progress: HashMap<u64, String> = self
+            .ddl_controller
+            .get_ddl_progress()
+            .await?
+            .into_iter()
+            .map(|progress| (progress.id, progress.progress))
+            .collect();
+        let creating_index_tables: Vec<(u32, String, String)> = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr
+                .catalog_manager
+                .list_persisted_creating_tables()
+                .await
+                .into_iter()
+                .filter(|table| table.table_type == PbTableType::Index as i32)
+                .map(|table| (table.id, table.name, table.definition))
+                .collect(),
+            MetadataManager::V2(mgr) => mgr
+                .catalog_controller
+                .list_creating_index_tables()
+                .await?
+                .into_iter()
+                .map(|table| (table.table_id as u32, table.name, table.definition))
+                .collect(),
+        };
+        let progress = creating_index_tables
+            .into_iter()
+            .map(|(index_table_id, name, statement)| IndexProgress {
+                index_table_id,
+                name,
+                statement,
+                // If not in the tracker, the first barrier has not been collected yet.
+                progress: ddl_progress
+                    .get(&(index_table_id as u64))
+                    .cloned()
+                    .unwrap_or_else(|| "0.0%".into()),
+            })
+            .collect();
+        Ok(Response::new(GetIndexProgressResponse { progress }))
+    }
+
     async fn create_connection(
         &self,
         request: Request<CreateConnectionRequest>,
@@ -808,6 +1022,7 @@ impl DdlService for DdlServiceImpl {
                     name: req.name,
                     owner: req.owner_id,
                     info: Some(connection::Info::PrivateLinkService(private_link_svc)),
+                    ..Default::default()
                 };
 
                 // save private link info to catalog
@@ -876,6 +1091,153 @@ impl DdlService for DdlServiceImpl {
         }))
     }
 
+    async fn alter_annotation(
+        &self,
+        request: Request<AlterAnnotationRequest>,
+    ) -> Result<Response<AlterAnnotationResponse>, Status> {
+        let req = request.into_inner();
+        let annotation = req.get_annotation()?.clone();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterAnnotation(annotation))
+            .await?;
+
+        Ok(Response::new(AlterAnnotationResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn alter_label(
+        &self,
+        request: Request<AlterLabelRequest>,
+    ) -> Result<Response<AlterLabelResponse>, Status> {
+        let req = request.into_inner();
+        let label = req.get_label()?.clone();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterLabel(label))
+            .await?;
+
+        Ok(Response::new(AlterLabelResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn alter_storage_class_policy(
+        &self,
+        request: Request<AlterStorageClassPolicyRequest>,
+    ) -> Result<Response<AlterStorageClassPolicyResponse>, Status> {
+        let req = request.into_inner();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterStorageClassPolicy(req.table_id, req.policy))
+            .await?;
+
+        Ok(Response::new(AlterStorageClassPolicyResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn alter_schema_change_policy(
+        &self,
+        request: Request<AlterSchemaChangePolicyRequest>,
+    ) -> Result<Response<AlterSchemaChangePolicyResponse>, Status> {
+        let req = request.into_inner();
+        let policy = req.policy();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterSchemaChangePolicy(req.table_id, policy))
+            .await?;
+
+        Ok(Response::new(AlterSchemaChangePolicyResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn alter_stream_job_priority(
+        &self,
+        request: Request<AlterStreamJobPriorityRequest>,
+    ) -> Result<Response<AlterStreamJobPriorityResponse>, Status> {
+        let req = request.into_inner();
+        let priority = req.priority();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterStreamJobPriority(req.table_id, priority))
+            .await?;
+
+        Ok(Response::new(AlterStreamJobPriorityResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn alter_table_ttl(
+        &self,
+        request: Request<AlterTableTtlRequest>,
+    ) -> Result<Response<AlterTableTtlResponse>, Status> {
+        let req = request.into_inner();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterTableTtl(req.table_id, req.retention_seconds))
+            .await?;
+
+        Ok(Response::new(AlterTableTtlResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn alter_database_barrier_interval(
+        &self,
+        request: Request<AlterDatabaseBarrierIntervalRequest>,
+    ) -> Result<Response<AlterDatabaseBarrierIntervalResponse>, Status> {
+        let req = request.into_inner();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterDatabaseBarrierInterval(
+                req.database_id,
+                req.barrier_interval_ms,
+            ))
+            .await?;
+
+        Ok(Response::new(AlterDatabaseBarrierIntervalResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn alter_database_session_default(
+        &self,
+        request: Request<AlterDatabaseSessionDefaultRequest>,
+    ) -> Result<Response<AlterDatabaseSessionDefaultResponse>, Status> {
+        let req = request.into_inner();
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::AlterDatabaseSessionDefault(
+                req.database_id,
+                req.name,
+                req.value,
+            ))
+            .await?;
+
+        Ok(Response::new(AlterDatabaseSessionDefaultResponse {
+            status: None,
+            version,
+        }))
+    }
+
     #[cfg_attr(coverage, coverage(off))]
     async fn get_tables(
         &self,
@@ -908,6 +1270,55 @@ impl DdlService for DdlServiceImpl {
         Ok(Response::new(GetTablesResponse { tables }))
     }
 
+    async fn get_catalog_memory_stats(
+        &self,
+        _request: Request<GetCatalogMemoryStatsRequest>,
+    ) -> Result<Response<GetCatalogMemoryStatsResponse>, Status> {
+        let stats = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.catalog_memory_stats().await,
+            MetadataManager::V2(_) => {
+                // The SQL meta backend does not keep the catalog in memory.
+                return Err(Status::unimplemented(
+                    "catalog memory stats are not supported with the SQL meta backend",
+                ));
+            }
+        };
+        Ok(Response::new(GetCatalogMemoryStatsResponse { stats }))
+    }
+
+    async fn export_ddl(
+        &self,
+        request: Request<ExportDdlRequest>,
+    ) -> Result<Response<ExportDdlResponse>, Status> {
+        let req = request.into_inner();
+        let statements = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .export_ddl(req.relation_id, req.include_dependencies)
+                    .await?
+            }
+            MetadataManager::V2(mgr) => mgr
+                .catalog_controller
+                .export_ddl(req.relation_id as _, req.include_dependencies)
+                .await?
+                .into_iter()
+                .map(|(id, create_sql)| (id as u32, create_sql))
+                .collect(),
+        };
+
+        Ok(Response::new(ExportDdlResponse {
+            statements: statements
+                .into_iter()
+                .map(
+                    |(relation_id, create_sql)| export_ddl_response::DdlStatement {
+                        relation_id,
+                        create_sql,
+                    },
+                )
+                .collect(),
+        }))
+    }
+
     async fn wait(&self, _request: Request<WaitRequest>) -> Result<Response<WaitResponse>, Status> {
         self.ddl_controller.wait().await?;
         Ok(Response::new(WaitResponse {}))
@@ -997,6 +1408,32 @@ impl DdlService for DdlServiceImpl {
                         "New columns should be a subset or superset of the original columns",
                     ));
                 }
+                // reject the schema change if the table's policy does not allow it
+                let policy = table.schema_change_policy();
+                let is_add_column = original_columns.is_subset(&new_columns);
+                let rejected = match policy {
+                    PbSchemaChangePolicy::Unspecified => false,
+                    PbSchemaChangePolicy::AllowAddColumn => !is_add_column,
+                    PbSchemaChangePolicy::AllowDropColumn => is_add_column,
+                    PbSchemaChangePolicy::Block | PbSchemaChangePolicy::AlertOnly => true,
+                };
+                if rejected && original_columns != new_columns {
+                    tracing::warn!(target: "auto_schema_change",
+                                   table_id = table.id,
+                                   cdc_table_id = table.cdc_table_id,
+                                   upstream_ddl = table_change.upstream_ddl,
+                                   policy = policy.as_str_name(),
+                                   "Schema change rejected by the table's schema change policy");
+                    add_auto_schema_change_reject_event_log(
+                        table.id,
+                        table.name.clone(),
+                        table_change.cdc_table_id.clone(),
+                        table_change.upstream_ddl.clone(),
+                        policy,
+                        &self.env.event_log_manager_ref(),
+                    );
+                    continue;
+                }
                 // skip the schema change if there is no change to original columns
                 if original_columns == new_columns {
                     tracing::warn!(target: "auto_schema_change",
@@ -1106,6 +1543,141 @@ impl DdlService for DdlServiceImpl {
 
         Ok(Response::new(AutoSchemaChangeResponse {}))
     }
+
+    async fn list_connector_property_schemas(
+        &self,
+        _request: Request<ListConnectorPropertySchemasRequest>,
+    ) -> Result<Response<ListConnectorPropertySchemasResponse>, Status> {
+        Ok(Response::new(ListConnectorPropertySchemasResponse {
+            schemas: connector_schemas(),
+        }))
+    }
+
+    async fn get_catalog_lock_stats(
+        &self,
+        _request: Request<GetCatalogLockStatsRequest>,
+    ) -> Result<Response<GetCatalogLockStatsResponse>, Status> {
+        let stats = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.catalog_lock_stats(),
+            MetadataManager::V2(_) => {
+                // The SQL backend serializes catalog mutations in the database instead of
+                // behind a single in-memory mutex.
+                return Err(Status::unimplemented(
+                    "catalog lock stats is only supported by the kv meta backend",
+                ));
+            }
+        };
+        let to_operation = |(operation, duration): (String, std::time::Duration)| LockOperation {
+            operation,
+            duration_ms: duration.as_millis() as u64,
+        };
+        Ok(Response::new(GetCatalogLockStatsResponse {
+            holder: stats.holder.map(to_operation),
+            queued: stats.waiters.into_iter().map(to_operation).collect(),
+        }))
+    }
+
+    async fn list_object_lints(
+        &self,
+        request: Request<ListObjectLintsRequest>,
+    ) -> Result<Response<ListObjectLintsResponse>, Status> {
+        let req = request.into_inner();
+        let lints = match &self.metadata_manager {
+            MetadataManager::V1(_) => {
+                ObjectLints::select(self.env.meta_store().as_kv(), &req.relation_id)
+                    .await
+                    .map_err(MetaError::from)?
+                    .map(|object_lints| object_lints.lints)
+                    .unwrap_or_default()
+            }
+            MetadataManager::V2(_) => {
+                return Err(Status::unimplemented(
+                    "object lints are only supported by the kv meta backend",
+                ));
+            }
+        };
+        Ok(Response::new(ListObjectLintsResponse { lints }))
+    }
+
+    async fn set_streaming_job_quota(
+        &self,
+        request: Request<SetStreamingJobQuotaRequest>,
+    ) -> Result<Response<SetStreamingJobQuotaResponse>, Status> {
+        let quota = request
+            .into_inner()
+            .quota
+            .ok_or_else(|| Status::invalid_argument("quota is not set"))?;
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager.set_streaming_job_quota(quota).await?;
+            }
+            MetadataManager::V2(_) => {
+                return Err(Status::unimplemented(
+                    "streaming job quotas are only supported by the kv meta backend",
+                ));
+            }
+        }
+        Ok(Response::new(SetStreamingJobQuotaResponse {}))
+    }
+
+    async fn list_streaming_job_quotas(
+        &self,
+        _request: Request<ListStreamingJobQuotasRequest>,
+    ) -> Result<Response<ListStreamingJobQuotasResponse>, Status> {
+        let quotas = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.list_streaming_job_quotas().await,
+            MetadataManager::V2(_) => {
+                return Err(Status::unimplemented(
+                    "streaming job quotas are only supported by the kv meta backend",
+                ));
+            }
+        };
+        Ok(Response::new(ListStreamingJobQuotasResponse { quotas }))
+    }
+
+    async fn list_ddl_audit_logs(
+        &self,
+        _request: Request<ListDdlAuditLogsRequest>,
+    ) -> Result<Response<ListDdlAuditLogsResponse>, Status> {
+        let audit_logs = self.ddl_controller.list_ddl_audit_logs().await?;
+        Ok(Response::new(ListDdlAuditLogsResponse { audit_logs }))
+    }
+
+    async fn run_ddl_batch(
+        &self,
+        request: Request<RunDdlBatchRequest>,
+    ) -> Result<Response<RunDdlBatchResponse>, Status> {
+        let req = request.into_inner();
+        let mut operations = Vec::with_capacity(req.commands.len());
+        for command in req.commands {
+            let operation = match command.command {
+                Some(ddl_batch_command::Command::CreateSource(source)) => {
+                    DdlBatchOperation::CreateSource(source)
+                }
+                Some(ddl_batch_command::Command::DropSourceId(source_id)) => {
+                    DdlBatchOperation::DropSource(source_id)
+                }
+                Some(ddl_batch_command::Command::CreateView(view)) => {
+                    DdlBatchOperation::CreateView(view)
+                }
+                Some(ddl_batch_command::Command::DropViewId(view_id)) => {
+                    DdlBatchOperation::DropView(view_id)
+                }
+                None => return Err(Status::invalid_argument("DDL batch command is not set")),
+            };
+            operations.push(operation);
+        }
+
+        let version = self
+            .ddl_controller
+            .run_command(DdlCommand::RunDdlBatch(operations))
+            .await?;
+
+        Ok(Response::new(RunDdlBatchResponse {
+            status: None,
+            version,
+        }))
+    }
 }
 
 impl DdlServiceImpl {
@@ -1162,3 +1734,21 @@ fn add_auto_schema_change_fail_event_log(
     };
     event_log_manager.add_event_logs(vec![event_log::Event::AutoSchemaChangeFail(event)]);
 }
+
+fn add_auto_schema_change_reject_event_log(
+    table_id: u32,
+    table_name: String,
+    cdc_table_id: String,
+    upstream_ddl: String,
+    policy: PbSchemaChangePolicy,
+    event_log_manager: &EventLogManagerRef,
+) {
+    let event = event_log::EventAutoSchemaChangeReject {
+        table_id,
+        table_name,
+        cdc_table_id,
+        upstream_ddl,
+        policy: policy.as_str_name().to_string(),
+    };
+    event_log_manager.add_event_logs(vec![event_log::Event::AutoSchemaChangeReject(event)]);
+}
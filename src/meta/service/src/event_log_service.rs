@@ -50,6 +50,9 @@ impl EventLogService for EventLogServiceImpl {
             risingwave_pb::meta::add_event_log_request::Event::WorkerNodePanic(e) => {
                 risingwave_pb::meta::event_log::Event::WorkerNodePanic(e)
             }
+            risingwave_pb::meta::add_event_log_request::Event::SubscriptionLag(e) => {
+                risingwave_pb::meta::event_log::Event::SubscriptionLag(e)
+            }
         };
         self.event_log_manager.add_event_logs(vec![e]);
         Ok(Response::new(AddEventLogResponse {}))
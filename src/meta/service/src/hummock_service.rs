@@ -730,6 +730,31 @@ impl HummockManagerService for HummockServiceImpl {
             .await?;
         Ok(Response::new(MergeCompactionGroupResponse {}))
     }
+
+    async fn list_retention_watermarks(
+        &self,
+        _request: Request<ListRetentionWatermarksRequest>,
+    ) -> Result<Response<ListRetentionWatermarksResponse>, Status> {
+        let mut watermarks = self
+            .hummock_manager
+            .resolve_retention_watermarks()
+            .await?
+            .into_iter()
+            .map(|(table_id, retention)| TableRetentionWatermark {
+                table_id,
+                safe_watermark_epoch: retention.safe_watermark_epoch,
+                blockers: retention
+                    .blockers
+                    .iter()
+                    .map(|blocker| blocker.to_string())
+                    .collect(),
+            })
+            .collect_vec();
+        watermarks.sort_by_key(|watermark| watermark.table_id);
+        Ok(Response::new(ListRetentionWatermarksResponse {
+            watermarks,
+        }))
+    }
 }
 
 #[cfg(test)]
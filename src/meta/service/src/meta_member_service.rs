@@ -13,20 +13,49 @@
 // limitations under the License.
 
 use risingwave_common::util::addr::HostAddr;
+use risingwave_common::util::tokio_util::sync::CancellationToken;
+use risingwave_meta::manager::NotificationManagerRef;
 use risingwave_meta::rpc::ElectionClientRef;
 use risingwave_pb::common::HostAddress;
 use risingwave_pb::meta::meta_member_service_server::MetaMemberService;
-use risingwave_pb::meta::{MembersRequest, MembersResponse, MetaMember};
+use risingwave_pb::meta::subscribe_response::{Info, Operation};
+use risingwave_pb::meta::{
+    GetLeaderLeaseRequest, GetLeaderLeaseResponse, LeaderHandover, MembersRequest,
+    MembersResponse, MetaMember, TriggerLeaderHandoverRequest, TriggerLeaderHandoverResponse,
+};
 use tonic::{Request, Response, Status};
 
+use crate::barrier::BarrierScheduler;
+
+/// Handles that the leader needs for a graceful leadership handover. Unset on followers,
+/// which makes them reject [`MetaMemberService::trigger_leader_handover`].
+#[derive(Clone)]
+pub struct LeaderHandoverContext {
+    pub barrier_scheduler: BarrierScheduler,
+    pub notification_manager: NotificationManagerRef,
+    /// Shutdown token of the leader services. Cancelling it stops the leader service and
+    /// the election loop, which releases the lease on the way out.
+    pub shutdown: CancellationToken,
+}
+
 #[derive(Clone)]
 pub struct MetaMemberServiceImpl {
     election_client: ElectionClientRef,
+    lease_ttl_secs: u64,
+    handover: Option<LeaderHandoverContext>,
 }
 
 impl MetaMemberServiceImpl {
-    pub fn new(election_client: ElectionClientRef) -> Self {
-        MetaMemberServiceImpl { election_client }
+    pub fn new(
+        election_client: ElectionClientRef,
+        lease_ttl_secs: u64,
+        handover: Option<LeaderHandoverContext>,
+    ) -> Self {
+        MetaMemberServiceImpl {
+            election_client,
+            lease_ttl_secs,
+            handover,
+        }
     }
 }
 
@@ -54,4 +83,79 @@ impl MetaMemberService for MetaMemberServiceImpl {
 
         Ok(Response::new(MembersResponse { members }))
     }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn get_leader_lease(
+        &self,
+        _request: Request<GetLeaderLeaseRequest>,
+    ) -> Result<Response<GetLeaderLeaseResponse>, Status> {
+        let leader = match self.election_client.leader().await? {
+            Some(leader) => {
+                let host_addr = leader
+                    .id
+                    .parse::<HostAddr>()
+                    .map_err(|err| Status::from_error(err.into()))?;
+                Some(MetaMember {
+                    address: Some(HostAddress {
+                        host: host_addr.host,
+                        port: host_addr.port.into(),
+                    }),
+                    is_leader: leader.is_leader,
+                })
+            }
+            None => None,
+        };
+
+        Ok(Response::new(GetLeaderLeaseResponse {
+            leader,
+            lease_ttl_secs: self.lease_ttl_secs,
+            is_leader: self.election_client.is_leader(),
+        }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn trigger_leader_handover(
+        &self,
+        _request: Request<TriggerLeaderHandoverRequest>,
+    ) -> Result<Response<TriggerLeaderHandoverResponse>, Status> {
+        let Some(handover) = &self.handover else {
+            return Err(Status::failed_precondition(
+                "this meta node is not the leader",
+            ));
+        };
+        if !self.election_client.is_leader() {
+            return Err(Status::failed_precondition(
+                "this meta node has already lost leadership",
+            ));
+        }
+
+        let resigning_leader = self
+            .election_client
+            .id()
+            .map_err(|err| Status::from_error(err.into()))?;
+        tracing::info!(%resigning_leader, "leader handover triggered");
+
+        // Let frontends know early so they can re-resolve the leader proactively instead
+        // of waiting for their requests to fail over.
+        handover.notification_manager.notify_frontend_without_version(
+            Operation::Update,
+            Info::LeaderHandover(LeaderHandover { resigning_leader }),
+        );
+
+        // Finish everything still waiting in the scheduling queue: each flush waits for
+        // one more barrier to be injected and collected, and injection pops from the
+        // queue in order.
+        while !handover.barrier_scheduler.list_scheduled_commands().is_empty() {
+            handover.barrier_scheduler.flush(false).await?;
+        }
+        // Finish with a checkpoint so that the new leader recovers from a fully
+        // committed state.
+        handover.barrier_scheduler.flush(true).await?;
+
+        // Stop the leader services. The election loop resigns and releases the lease
+        // once they have shut down, letting another member take over.
+        handover.shutdown.cancel();
+
+        Ok(Response::new(TriggerLeaderHandoverResponse { status: None }))
+    }
 }
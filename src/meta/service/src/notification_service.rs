@@ -25,8 +25,9 @@ use risingwave_pb::hummock::WriteLimits;
 use risingwave_pb::meta::meta_snapshot::SnapshotVersion;
 use risingwave_pb::meta::notification_service_server::NotificationService;
 use risingwave_pb::meta::{
-    FragmentWorkerSlotMapping, GetSessionParamsResponse, MetaSnapshot, SubscribeRequest,
-    SubscribeType,
+    FragmentWorkerSlotMapping, GetCatalogDeltasRequest, GetCatalogDeltasResponse,
+    GetSessionParamsResponse, MetaSnapshot, SubscribeRequest, SubscribeTableChangeRequest,
+    SubscribeType, TableChangeNotification,
 };
 use risingwave_pb::user::UserInfo;
 use tokio::sync::mpsc;
@@ -87,6 +88,11 @@ impl NotificationServiceImpl {
                     secrets,
                 ) = catalog_guard.database.get_catalog();
                 let users = catalog_guard.user.list_users();
+                // Soft-dropped tables are invisible to frontends until undropped.
+                let tables = tables
+                    .into_iter()
+                    .filter(|t| t.soft_dropped_at_ms.is_none())
+                    .collect();
                 let notification_version = self.env.notification_manager().current_version().await;
                 Ok((
                     (
@@ -124,6 +130,11 @@ impl NotificationServiceImpl {
                     ),
                     users,
                 ) = catalog_guard.snapshot().await?;
+                // Soft-dropped tables are invisible to frontends until undropped.
+                let tables = tables
+                    .into_iter()
+                    .filter(|t| t.soft_dropped_at_ms.is_none())
+                    .collect();
                 let notification_version = self.env.notification_manager().current_version().await;
                 Ok((
                     (
@@ -386,6 +397,8 @@ impl NotificationServiceImpl {
 #[async_trait::async_trait]
 impl NotificationService for NotificationServiceImpl {
     type SubscribeStream = UnboundedReceiverStream<Notification>;
+    type SubscribeTableChangeStream =
+        UnboundedReceiverStream<Result<TableChangeNotification, Status>>;
 
     #[cfg_attr(coverage, coverage(off))]
     async fn subscribe(
@@ -399,25 +412,49 @@ impl NotificationService for NotificationServiceImpl {
         let worker_key = WorkerKey(host_address);
 
         let (tx, rx) = mpsc::unbounded_channel();
-        self.env
-            .notification_manager()
-            .insert_sender(subscribe_type, worker_key.clone(), tx)
-            .await;
 
-        let meta_snapshot = match subscribe_type {
-            SubscribeType::Compactor => self.compactor_subscribe().await?,
+        // Pinning is required regardless of whether the subscriber catches up
+        // incrementally or re-syncs a full snapshot.
+        match subscribe_type {
             SubscribeType::Frontend => {
                 self.hummock_manager
                     .pin_snapshot(req.get_worker_id())
                     .await?;
-                self.frontend_subscribe().await?
             }
             SubscribeType::Hummock => {
                 self.hummock_manager
                     .pin_version(req.get_worker_id())
                     .await?;
-                self.hummock_subscribe().await?
             }
+            _ => {}
+        }
+
+        // A reconnecting subscriber still within the backlog's retention window
+        // catches up incrementally from its last acked version.
+        if let Some(last_acked_version) = req.last_acked_version
+            && self
+                .env
+                .notification_manager()
+                .insert_sender_with_catch_up(
+                    subscribe_type,
+                    worker_key.clone(),
+                    tx.clone(),
+                    last_acked_version,
+                )
+                .await
+        {
+            return Ok(Response::new(UnboundedReceiverStream::new(rx)));
+        }
+
+        self.env
+            .notification_manager()
+            .insert_sender(subscribe_type, worker_key.clone(), tx)
+            .await;
+
+        let meta_snapshot = match subscribe_type {
+            SubscribeType::Compactor => self.compactor_subscribe().await?,
+            SubscribeType::Frontend => self.frontend_subscribe().await?,
+            SubscribeType::Hummock => self.hummock_subscribe().await?,
             SubscribeType::Compute => self.compute_subscribe().await?,
             SubscribeType::Unspecified => unreachable!(),
         };
@@ -428,4 +465,34 @@ impl NotificationService for NotificationServiceImpl {
 
         Ok(Response::new(UnboundedReceiverStream::new(rx)))
     }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn subscribe_table_change(
+        &self,
+        request: Request<SubscribeTableChangeRequest>,
+    ) -> Result<Response<Self::SubscribeTableChangeStream>, Status> {
+        let req = request.into_inner();
+        let rx = self
+            .env
+            .table_change_notifier()
+            .subscribe(req.table_ids.into_iter().collect());
+        Ok(Response::new(UnboundedReceiverStream::new(rx)))
+    }
+
+    async fn get_catalog_deltas(
+        &self,
+        request: Request<GetCatalogDeltasRequest>,
+    ) -> Result<Response<GetCatalogDeltasResponse>, Status> {
+        let req = request.into_inner();
+        let (deltas, truncated) = self
+            .env
+            .notification_manager()
+            .catalog_delta_log()
+            .list(req.from_version)
+            .await;
+        Ok(Response::new(GetCatalogDeltasResponse {
+            deltas,
+            truncated,
+        }))
+    }
 }
@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use risingwave_common::catalog::TableId;
 use risingwave_meta::manager::MetadataManager;
@@ -24,22 +25,36 @@ use risingwave_meta_model_v2::FragmentId;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::meta::scale_service_server::ScaleService;
 use risingwave_pb::meta::{
-    GetClusterInfoRequest, GetClusterInfoResponse, GetServerlessStreamingJobsStatusRequest,
-    GetServerlessStreamingJobsStatusResponse, PbWorkerReschedule, RescheduleRequest,
-    RescheduleResponse, UpdateStreamingJobNodeLabelsRequest, UpdateStreamingJobNodeLabelsResponse,
+    ApplyRescheduleRequest, ApplyRescheduleResponse, GetClusterInfoRequest, GetClusterInfoResponse,
+    GetServerlessStreamingJobsStatusRequest, GetServerlessStreamingJobsStatusResponse,
+    MigrateActorsRequest, MigrateActorsResponse, PbWorkerReschedule, PlanRescheduleRequest,
+    PlanRescheduleResponse, RescheduleRequest, RescheduleResponse,
+    UpdateStreamingJobNodeLabelsRequest, UpdateStreamingJobNodeLabelsResponse,
 };
 use risingwave_pb::source::{ConnectorSplit, ConnectorSplits};
+use tokio::sync::Mutex;
 use tonic::{Request, Response, Status};
 
 use crate::barrier::BarrierManagerRef;
 use crate::model::MetadataModel;
 use crate::stream::{GlobalStreamManagerRef, SourceManagerRef};
 
+/// A reschedule plan generated by `PlanReschedule`, kept until it's applied or invalidated
+/// by a revision change.
+struct PendingReschedulePlan {
+    job_id: u32,
+    target_parallelism: usize,
+    worker_reschedules: HashMap<u32, WorkerReschedule>,
+    revision: u64,
+}
+
 pub struct ScaleServiceImpl {
     metadata_manager: MetadataManager,
     source_manager: SourceManagerRef,
     stream_manager: GlobalStreamManagerRef,
     barrier_manager: BarrierManagerRef,
+    reschedule_plans: Mutex<HashMap<u64, PendingReschedulePlan>>,
+    next_plan_id: AtomicU64,
 }
 
 impl ScaleServiceImpl {
@@ -55,6 +70,8 @@ impl ScaleServiceImpl {
             source_manager,
             stream_manager,
             barrier_manager,
+            reschedule_plans: Mutex::new(HashMap::new()),
+            next_plan_id: AtomicU64::new(1),
         }
     }
 
@@ -221,6 +238,212 @@ impl ScaleService for ScaleServiceImpl {
         }))
     }
 
+    #[cfg_attr(coverage, coverage(off))]
+    async fn plan_reschedule(
+        &self,
+        request: Request<PlanRescheduleRequest>,
+    ) -> Result<Response<PlanRescheduleResponse>, Status> {
+        self.barrier_manager.check_status_running()?;
+
+        let req = request.into_inner();
+        if req.target_parallelism == 0 {
+            return Err(Status::invalid_argument(
+                "target parallelism must be greater than 0",
+            ));
+        }
+
+        let worker_reschedules = self
+            .stream_manager
+            .plan_reschedule(req.job_id, req.target_parallelism as usize)
+            .await?;
+        let revision = self.get_revision().await.inner();
+
+        let plan_id = self.next_plan_id.fetch_add(1, Ordering::Relaxed);
+        let mut plans = self.reschedule_plans.lock().await;
+        // Plans generated against an older revision can never be applied anymore.
+        plans.retain(|_, plan| plan.revision == revision);
+        plans.insert(
+            plan_id,
+            PendingReschedulePlan {
+                job_id: req.job_id,
+                target_parallelism: req.target_parallelism as usize,
+                worker_reschedules: worker_reschedules.clone(),
+                revision,
+            },
+        );
+
+        Ok(Response::new(PlanRescheduleResponse {
+            plan_id,
+            worker_reschedules: worker_reschedules
+                .into_iter()
+                .map(|(fragment_id, reschedule)| {
+                    (
+                        fragment_id,
+                        PbWorkerReschedule {
+                            worker_actor_diff: reschedule
+                                .worker_actor_diff
+                                .into_iter()
+                                .map(|(worker_id, diff)| (worker_id as _, diff as _))
+                                .collect(),
+                        },
+                    )
+                })
+                .collect(),
+            revision,
+        }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn apply_reschedule(
+        &self,
+        request: Request<ApplyRescheduleRequest>,
+    ) -> Result<Response<ApplyRescheduleResponse>, Status> {
+        self.barrier_manager.check_status_running()?;
+
+        let req = request.into_inner();
+        let Some(plan) = self.reschedule_plans.lock().await.remove(&req.plan_id) else {
+            return Err(Status::not_found(format!(
+                "reschedule plan {} not found",
+                req.plan_id
+            )));
+        };
+
+        let _reschedule_job_lock = self.stream_manager.reschedule_lock_write_guard().await;
+
+        let current_revision = self.get_revision().await;
+        if plan.revision != current_revision.inner() {
+            return Ok(Response::new(ApplyRescheduleResponse {
+                success: false,
+                revision: current_revision.inner(),
+            }));
+        }
+
+        if !plan.worker_reschedules.is_empty() {
+            self.stream_manager
+                .reschedule_actors(
+                    plan.worker_reschedules,
+                    RescheduleOptions {
+                        resolve_no_shuffle_upstream: false,
+                        skip_create_new_actors: false,
+                    },
+                    Some(HashMap::from([(
+                        TableId::new(plan.job_id),
+                        TableParallelism::Fixed(plan.target_parallelism),
+                    )])),
+                )
+                .await?;
+        }
+
+        let next_revision = self.get_revision().await;
+
+        Ok(Response::new(ApplyRescheduleResponse {
+            success: true,
+            revision: next_revision.into(),
+        }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn migrate_actors(
+        &self,
+        request: Request<MigrateActorsRequest>,
+    ) -> Result<Response<MigrateActorsResponse>, Status> {
+        self.barrier_manager.check_status_running()?;
+
+        let req = request.into_inner();
+        if req.from_worker_id == req.to_worker_id {
+            return Err(Status::invalid_argument(
+                "source and target workers must differ",
+            ));
+        }
+        let count = if req.count == 0 {
+            1
+        } else {
+            req.count as isize
+        };
+
+        // Validate the target worker upfront for a clearer error than the generic
+        // reschedule failure.
+        let workers = self
+            .metadata_manager
+            .list_active_streaming_compute_nodes()
+            .await?;
+        if !workers.iter().any(|w| w.id == req.to_worker_id) {
+            return Err(Status::invalid_argument(format!(
+                "worker {} is not an active streaming compute node",
+                req.to_worker_id
+            )));
+        }
+
+        let _reschedule_job_lock = self.stream_manager.reschedule_lock_write_guard().await;
+
+        // Migration keeps the total actor count, but the resulting distribution is
+        // custom, so opt the job out of automatic parallelism control like a manual
+        // reschedule does.
+        let table_parallelisms = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                let guard = mgr.fragment_manager.get_fragment_read_guard().await;
+
+                let mut table_parallelisms = HashMap::new();
+                for (table_id, table) in guard.table_fragments() {
+                    if table
+                        .fragment_ids()
+                        .any(|fragment_id| fragment_id == req.fragment_id)
+                    {
+                        table_parallelisms.insert(*table_id, TableParallelism::Custom);
+                    }
+                }
+                table_parallelisms
+            }
+            MetadataManager::V2(mgr) => {
+                let streaming_job_ids = mgr
+                    .catalog_controller
+                    .get_fragment_job_id(vec![req.fragment_id as FragmentId])
+                    .await?;
+
+                streaming_job_ids
+                    .into_iter()
+                    .map(|id| (TableId::new(id as _), TableParallelism::Custom))
+                    .collect()
+            }
+        };
+        if table_parallelisms.is_empty() {
+            return Err(Status::not_found(format!(
+                "fragment {} not found",
+                req.fragment_id
+            )));
+        }
+
+        let reschedules = HashMap::from([(
+            req.fragment_id,
+            WorkerReschedule {
+                worker_actor_diff: BTreeMap::from([
+                    (req.from_worker_id as _, -count),
+                    (req.to_worker_id as _, count),
+                ]),
+            },
+        )]);
+
+        self.stream_manager
+            .reschedule_actors(
+                reschedules,
+                RescheduleOptions {
+                    // A no-shuffle fragment can't be migrated on its own; resolve to its
+                    // root so the whole chain moves together.
+                    resolve_no_shuffle_upstream: true,
+                    skip_create_new_actors: false,
+                },
+                Some(table_parallelisms),
+            )
+            .await?;
+
+        let next_revision = self.get_revision().await;
+
+        Ok(Response::new(MigrateActorsResponse {
+            success: true,
+            revision: next_revision.into(),
+        }))
+    }
+
     async fn update_streaming_job_node_labels(
         &self,
         _request: Request<UpdateStreamingJobNodeLabelsRequest>,
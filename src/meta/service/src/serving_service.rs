@@ -17,23 +17,28 @@ use risingwave_meta::manager::MetadataManager;
 use risingwave_pb::meta::serving_service_server::ServingService;
 use risingwave_pb::meta::{
     FragmentWorkerSlotMapping, GetServingVnodeMappingsRequest, GetServingVnodeMappingsResponse,
+    ListRelationReadStatsRequest, ListRelationReadStatsResponse, ReportRelationReadStatsRequest,
+    ReportRelationReadStatsResponse,
 };
 use tonic::{Request, Response, Status};
 
-use crate::serving::ServingVnodeMappingRef;
+use crate::serving::{RelationReadStatsAggregatorRef, ServingVnodeMappingRef};
 
 pub struct ServingServiceImpl {
     serving_vnode_mapping: ServingVnodeMappingRef,
+    relation_read_stats: RelationReadStatsAggregatorRef,
     metadata_manager: MetadataManager,
 }
 
 impl ServingServiceImpl {
     pub fn new(
         serving_vnode_mapping: ServingVnodeMappingRef,
+        relation_read_stats: RelationReadStatsAggregatorRef,
         metadata_manager: MetadataManager,
     ) -> Self {
         Self {
             serving_vnode_mapping,
+            relation_read_stats,
             metadata_manager,
         }
     }
@@ -82,4 +87,21 @@ impl ServingService for ServingServiceImpl {
             worker_slot_mappings: mappings,
         }))
     }
+
+    async fn report_relation_read_stats(
+        &self,
+        request: Request<ReportRelationReadStatsRequest>,
+    ) -> Result<Response<ReportRelationReadStatsResponse>, Status> {
+        self.relation_read_stats.report(request.into_inner().stats);
+        Ok(Response::new(ReportRelationReadStatsResponse {}))
+    }
+
+    async fn list_relation_read_stats(
+        &self,
+        _request: Request<ListRelationReadStatsRequest>,
+    ) -> Result<Response<ListRelationReadStatsResponse>, Status> {
+        Ok(Response::new(ListRelationReadStatsResponse {
+            stats: self.relation_read_stats.list(),
+        }))
+    }
 }
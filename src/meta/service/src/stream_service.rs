@@ -14,12 +14,18 @@
 
 use std::collections::{HashMap, HashSet};
 
+use anyhow::anyhow;
+use futures::future::try_join_all;
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
-use risingwave_meta::manager::{LocalNotification, MetadataManager};
-use risingwave_meta::model;
+use risingwave_meta::hummock::HummockManagerRef;
+use risingwave_meta::manager::{
+    ConsistencyFenceManagerRef, LocalNotification, MetadataManager, NamedCheckpointManagerRef,
+    RateLimitBoostManagerRef,
+};
 use risingwave_meta::model::ActorId;
 use risingwave_meta::stream::ThrottleConfig;
+use risingwave_meta::{model, stream, MetaError};
 use risingwave_meta_model_v2::{SourceId, StreamingParallelism};
 use risingwave_pb::meta::cancel_creating_jobs_request::Jobs;
 use risingwave_pb::meta::list_table_fragments_response::{
@@ -32,18 +38,27 @@ use risingwave_pb::meta::table_fragments::PbState;
 use risingwave_pb::meta::*;
 use tonic::{Request, Response, Status};
 
-use crate::barrier::{BarrierScheduler, Command};
+use crate::barrier::{BarrierManagerRef, BarrierScheduler, Command};
 use crate::manager::MetaSrvEnv;
 use crate::stream::GlobalStreamManagerRef;
 
 pub type TonicResponse<T> = Result<Response<T>, Status>;
 
+/// Default time to wait for a changelog sample before returning the records captured
+/// so far, if the request does not specify one.
+const DEFAULT_SAMPLE_CHANGELOG_TIMEOUT_MS: u64 = 10_000;
+
 #[derive(Clone)]
 pub struct StreamServiceImpl {
     env: MetaSrvEnv,
     barrier_scheduler: BarrierScheduler,
     stream_manager: GlobalStreamManagerRef,
     metadata_manager: MetadataManager,
+    barrier_manager: BarrierManagerRef,
+    named_checkpoint_manager: NamedCheckpointManagerRef,
+    rate_limit_boost_manager: RateLimitBoostManagerRef,
+    consistency_fence_manager: ConsistencyFenceManagerRef,
+    hummock_manager: HummockManagerRef,
 }
 
 impl StreamServiceImpl {
@@ -52,12 +67,22 @@ impl StreamServiceImpl {
         barrier_scheduler: BarrierScheduler,
         stream_manager: GlobalStreamManagerRef,
         metadata_manager: MetadataManager,
+        barrier_manager: BarrierManagerRef,
+        named_checkpoint_manager: NamedCheckpointManagerRef,
+        rate_limit_boost_manager: RateLimitBoostManagerRef,
+        consistency_fence_manager: ConsistencyFenceManagerRef,
+        hummock_manager: HummockManagerRef,
     ) -> Self {
         StreamServiceImpl {
             env,
             barrier_scheduler,
             stream_manager,
             metadata_manager,
+            barrier_manager,
+            named_checkpoint_manager,
+            rate_limit_boost_manager,
+            consistency_fence_manager,
+            hummock_manager,
         }
     }
 }
@@ -123,6 +148,11 @@ impl StreamManagerService for StreamServiceImpl {
                     .update_mv_rate_limit_by_table_id(TableId::from(request.id), request.rate)
                     .await?
             }
+            ThrottleTarget::Sink => {
+                self.metadata_manager
+                    .update_sink_rate_limit_by_sink_id(request.id, request.rate)
+                    .await?
+            }
             ThrottleTarget::Unspecified => {
                 return Err(Status::invalid_argument("unspecified throttle target"))
             }
@@ -148,6 +178,341 @@ impl StreamManagerService for StreamServiceImpl {
         Ok(Response::new(ApplyThrottleResponse { status: None }))
     }
 
+    #[cfg_attr(coverage, coverage(off))]
+    async fn boost_rate_limit(
+        &self,
+        request: Request<BoostRateLimitRequest>,
+    ) -> Result<Response<BoostRateLimitResponse>, Status> {
+        if let MetadataManager::V2(_) = &self.metadata_manager {
+            return Err(Status::unimplemented(
+                "rate limit boosts are only supported by the kv meta backend",
+            ));
+        }
+        let req = request.into_inner();
+        self.rate_limit_boost_manager
+            .boost_rate_limit(
+                req.table_id,
+                req.rate,
+                std::time::Duration::from_millis(req.duration_ms),
+            )
+            .await?;
+        Ok(Response::new(BoostRateLimitResponse { status: None }))
+    }
+
+    async fn list_jobs_by_label(
+        &self,
+        request: Request<ListJobsByLabelRequest>,
+    ) -> Result<Response<ListJobsByLabelResponse>, Status> {
+        let req = request.into_inner();
+        if req.selector.is_empty() {
+            return Err(Status::invalid_argument("label selector must not be empty"));
+        }
+        let (table_ids, sink_ids) = self
+            .metadata_manager
+            .list_streaming_job_ids_by_label(&req.selector)
+            .await?;
+        Ok(Response::new(ListJobsByLabelResponse {
+            table_ids,
+            sink_ids,
+        }))
+    }
+
+    async fn apply_throttle_by_label(
+        &self,
+        request: Request<ApplyThrottleByLabelRequest>,
+    ) -> Result<Response<ApplyThrottleByLabelResponse>, Status> {
+        let req = request.into_inner();
+        if req.selector.is_empty() {
+            return Err(Status::invalid_argument("label selector must not be empty"));
+        }
+        // Sinks carry no rate limit of their own, so only the table jobs are throttled.
+        let (table_ids, _) = self
+            .metadata_manager
+            .list_streaming_job_ids_by_label(&req.selector)
+            .await?;
+        for &table_id in &table_ids {
+            let actor_to_apply = self
+                .metadata_manager
+                .update_mv_rate_limit_by_table_id(TableId::from(table_id), req.rate)
+                .await?;
+            let mutation: ThrottleConfig = actor_to_apply
+                .iter()
+                .map(|(fragment_id, actors)| {
+                    (
+                        *fragment_id,
+                        actors
+                            .iter()
+                            .map(|actor_id| (*actor_id, req.rate))
+                            .collect::<HashMap<ActorId, Option<u32>>>(),
+                    )
+                })
+                .collect();
+            let _i = self
+                .barrier_scheduler
+                .run_command(Command::Throttle(mutation))
+                .await?;
+        }
+        Ok(Response::new(ApplyThrottleByLabelResponse {
+            status: None,
+            table_ids,
+        }))
+    }
+
+    async fn pause_jobs_by_label(
+        &self,
+        request: Request<PauseJobsByLabelRequest>,
+    ) -> Result<Response<PauseJobsByLabelResponse>, Status> {
+        let req = request.into_inner();
+        if req.selector.is_empty() {
+            return Err(Status::invalid_argument("label selector must not be empty"));
+        }
+        let (matched_table_ids, _) = self
+            .metadata_manager
+            .list_streaming_job_ids_by_label(&req.selector)
+            .await?;
+        let mut table_ids = Vec::with_capacity(matched_table_ids.len());
+        for id in matched_table_ids {
+            let table_id = TableId::from(id);
+            // Freeze each job by zeroing the rate limit of its throttleable actors, as in
+            // `pause_streaming_job`. Jobs without throttleable actors are skipped.
+            let rate_limits = self
+                .metadata_manager
+                .get_mv_rate_limit_by_table_id(table_id)
+                .await?;
+            if rate_limits.is_empty() {
+                continue;
+            }
+            // As in `pause_streaming_job`: a finished job with stream scan fragments
+            // cannot actually be paused via rate limits, so fail instead of silently
+            // reporting it as paused.
+            if !self
+                .metadata_manager
+                .is_job_pausable_by_rate_limit(table_id)
+                .await?
+            {
+                return Err(Status::failed_precondition(format!(
+                    "job {table_id} matched by the selector has finished backfilling and \
+                     contains stream scan fragments, which no longer honor rate limits; \
+                     it cannot be paused"
+                )));
+            }
+            let config: ThrottleConfig = rate_limits
+                .into_iter()
+                .map(|(fragment_id, actors)| {
+                    (
+                        fragment_id,
+                        actors
+                            .into_keys()
+                            .map(|actor_id| (actor_id, Some(0)))
+                            .collect(),
+                    )
+                })
+                .collect();
+            let _i = self
+                .barrier_scheduler
+                .run_command(Command::PauseStreamingJob { table_id, config })
+                .await?;
+            table_ids.push(id);
+        }
+        Ok(Response::new(PauseJobsByLabelResponse { table_ids }))
+    }
+
+    async fn pause_streaming_job(
+        &self,
+        request: Request<PauseStreamingJobRequest>,
+    ) -> Result<Response<PauseStreamingJobResponse>, Status> {
+        let req = request.into_inner();
+        let table_id = TableId::from(req.table_id);
+
+        // Freeze the job by zeroing the rate limit of its throttleable actors. The rate
+        // limits persisted in the catalog are untouched, so they can be restored on resume.
+        let rate_limits = self
+            .metadata_manager
+            .get_mv_rate_limit_by_table_id(table_id)
+            .await?;
+        if rate_limits.is_empty() {
+            return Err(Status::invalid_argument(format!(
+                "stream scan node or source node not found in job id {table_id}"
+            )));
+        }
+        // Stream scan executors only honor the throttle mutation while backfilling, so
+        // zeroing the rate limits of a finished job with scan fragments would be a silent
+        // no-op. Reject such jobs instead of reporting a pause that never happened.
+        if !self
+            .metadata_manager
+            .is_job_pausable_by_rate_limit(table_id)
+            .await?
+        {
+            return Err(Status::failed_precondition(format!(
+                "job {table_id} has finished backfilling and contains stream scan fragments, \
+                 which no longer honor rate limits; it cannot be paused"
+            )));
+        }
+        let config: ThrottleConfig = rate_limits
+            .into_iter()
+            .map(|(fragment_id, actors)| {
+                (
+                    fragment_id,
+                    actors
+                        .into_keys()
+                        .map(|actor_id| (actor_id, Some(0)))
+                        .collect(),
+                )
+            })
+            .collect();
+        let _i = self
+            .barrier_scheduler
+            .run_command(Command::PauseStreamingJob { table_id, config })
+            .await?;
+
+        Ok(Response::new(PauseStreamingJobResponse {}))
+    }
+
+    async fn resume_streaming_job(
+        &self,
+        request: Request<ResumeStreamingJobRequest>,
+    ) -> Result<Response<ResumeStreamingJobResponse>, Status> {
+        let req = request.into_inner();
+        let table_id = TableId::from(req.table_id);
+
+        // Restore the rate limits persisted in the job's stream nodes.
+        let config = self
+            .metadata_manager
+            .get_mv_rate_limit_by_table_id(table_id)
+            .await?;
+        if config.is_empty() {
+            return Err(Status::invalid_argument(format!(
+                "stream scan node or source node not found in job id {table_id}"
+            )));
+        }
+        let _i = self
+            .barrier_scheduler
+            .run_command(Command::ResumeStreamingJob { table_id, config })
+            .await?;
+
+        Ok(Response::new(ResumeStreamingJobResponse {}))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn sample_changelog(
+        &self,
+        request: Request<SampleChangelogRequest>,
+    ) -> Result<Response<SampleChangelogResponse>, Status> {
+        let req = request.into_inner();
+        if req.max_records == 0 {
+            return Err(Status::invalid_argument("max_records must be positive"));
+        }
+        let timeout_ms = if req.timeout_ms == 0 {
+            DEFAULT_SAMPLE_CHANGELOG_TIMEOUT_MS
+        } else {
+            req.timeout_ms
+        };
+
+        // The id of the streaming job hosting the materialize or sink executor of the
+        // relation is the relation id itself.
+        let worker_actors = self
+            .metadata_manager
+            .get_worker_actor_ids(HashSet::from([TableId::from(req.relation_id)]))
+            .await?;
+        if worker_actors.is_empty() {
+            return Err(Status::not_found(format!(
+                "relation id {} not found or has no running actors",
+                req.relation_id
+            )));
+        }
+
+        let futures = worker_actors.into_keys().map(|worker_id| {
+            let worker_request = risingwave_pb::stream_service::SampleChangelogRequest {
+                relation_id: req.relation_id,
+                max_records: req.max_records,
+                filter: req.filter.clone(),
+                timeout_ms,
+            };
+            async move {
+                let worker = self
+                    .metadata_manager
+                    .get_worker_by_id(worker_id)
+                    .await?
+                    .ok_or_else(|| MetaError::from(anyhow!("worker {} not found", worker_id)))?;
+                let client = self.env.stream_client_pool().get(&worker).await?;
+                let resp = client.sample_changelog(worker_request).await?;
+                Ok::<_, MetaError>(resp.records)
+            }
+        });
+
+        let mut records = vec![];
+        for worker_records in try_join_all(futures).await? {
+            records.extend(worker_records.into_iter().map(|record| {
+                sample_changelog_response::Record {
+                    op: record.op,
+                    old_value: record.old_value,
+                    new_value: record.new_value,
+                }
+            }));
+        }
+        // Each worker captures up to `max_records` on its own, so trim the merged result.
+        records.truncate(req.max_records as usize);
+
+        Ok(Response::new(SampleChangelogResponse { records }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn force_drop_job(
+        &self,
+        request: Request<ForceDropJobRequest>,
+    ) -> TonicResponse<ForceDropJobResponse> {
+        let MetadataManager::V1(mgr) = &self.metadata_manager else {
+            return Err(Status::unimplemented(
+                "force-dropping jobs is only supported by the kv meta backend",
+            ));
+        };
+        let req = request.into_inner();
+
+        let expected = format!("force-drop-{}", req.job_id);
+        if req.confirmation != expected {
+            return Err(Status::invalid_argument(format!(
+                "force-dropping job {} must be confirmed with the token {:?}",
+                req.job_id, expected
+            )));
+        }
+        mgr.catalog_manager.ensure_super_user(req.user_id).await?;
+
+        self.stream_manager
+            .force_drop_job(TableId::from(req.job_id))
+            .await?;
+        Ok(Response::new(ForceDropJobResponse { status: None }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn analyze_duplicate_state(
+        &self,
+        _request: Request<AnalyzeDuplicateStateRequest>,
+    ) -> TonicResponse<AnalyzeDuplicateStateResponse> {
+        let MetadataManager::V1(mgr) = &self.metadata_manager else {
+            return Err(Status::unimplemented(
+                "duplicate state analysis is only supported by the kv meta backend",
+            ));
+        };
+
+        let table_fragments = mgr.fragment_manager.list_table_fragments().await;
+        let state_table_ids = table_fragments
+            .iter()
+            .flat_map(|job| job.fragments.values())
+            .flat_map(|fragment| fragment.state_table_ids.iter().copied())
+            .collect_vec();
+        let state_tables = mgr
+            .catalog_manager
+            .get_tables(&state_table_ids)
+            .await
+            .into_iter()
+            .map(|table| (table.id, table))
+            .collect();
+        let version_stats = self.hummock_manager.get_version_stats().await;
+
+        let groups = stream::find_duplicate_state(&table_fragments, &state_tables, &version_stats);
+        Ok(Response::new(AnalyzeDuplicateStateResponse { groups }))
+    }
+
     async fn cancel_creating_jobs(
         &self,
         request: Request<CancelCreatingJobsRequest>,
@@ -171,6 +536,63 @@ impl StreamManagerService for StreamServiceImpl {
             Jobs::Ids(jobs) => jobs.job_ids,
         };
 
+        if req.suspend {
+            // Suspending keeps the creating jobs alive instead of cancelling them: the
+            // jobs are converted to background so they no longer block the issuing
+            // session, and their backfill is frozen by zeroing the rate limit of the
+            // throttleable actors. The backfill state tables are untouched, so
+            // `resume_streaming_job` restores the persisted rate limits and the
+            // creation continues from the positions stored in those state tables.
+            match &self.metadata_manager {
+                MetadataManager::V1(mgr) => {
+                    mgr.catalog_manager
+                        .convert_creating_jobs_to_background(table_ids.clone())
+                        .await?;
+                }
+                MetadataManager::V2(mgr) => {
+                    mgr.catalog_controller
+                        .convert_creating_jobs_to_background(
+                            table_ids.iter().map(|id| *id as _).collect_vec(),
+                        )
+                        .await?;
+                }
+            }
+            let mut suspended_jobs = Vec::with_capacity(table_ids.len());
+            for id in table_ids {
+                let table_id = TableId::from(id);
+                let rate_limits = self
+                    .metadata_manager
+                    .get_mv_rate_limit_by_table_id(table_id)
+                    .await?;
+                if rate_limits.is_empty() {
+                    // The job has no throttleable actors, e.g. it finished creating
+                    // concurrently. Leave it running.
+                    continue;
+                }
+                let config: ThrottleConfig = rate_limits
+                    .into_iter()
+                    .map(|(fragment_id, actors)| {
+                        (
+                            fragment_id,
+                            actors
+                                .into_keys()
+                                .map(|actor_id| (actor_id, Some(0)))
+                                .collect(),
+                        )
+                    })
+                    .collect();
+                let _i = self
+                    .barrier_scheduler
+                    .run_command(Command::PauseStreamingJob { table_id, config })
+                    .await?;
+                suspended_jobs.push(id);
+            }
+            return Ok(Response::new(CancelCreatingJobsResponse {
+                status: None,
+                canceled_jobs: suspended_jobs,
+            }));
+        }
+
         let canceled_jobs = self
             .stream_manager
             .cancel_streaming_jobs(table_ids.into_iter().map(TableId::from).collect_vec())
@@ -184,6 +606,68 @@ impl StreamManagerService for StreamServiceImpl {
         }))
     }
 
+    async fn convert_creating_jobs_to_background(
+        &self,
+        request: Request<ConvertCreatingJobsToBackgroundRequest>,
+    ) -> TonicResponse<ConvertCreatingJobsToBackgroundResponse> {
+        let req = request.into_inner();
+        let job_ids = match req.jobs.unwrap() {
+            convert_creating_jobs_to_background_request::Jobs::Infos(infos) => {
+                match &self.metadata_manager {
+                    MetadataManager::V1(mgr) => {
+                        mgr.catalog_manager
+                            .find_creating_streaming_job_ids(infos.infos)
+                            .await
+                    }
+                    MetadataManager::V2(mgr) => mgr
+                        .catalog_controller
+                        .find_creating_streaming_job_ids(infos.infos)
+                        .await?
+                        .into_iter()
+                        .map(|id| id as _)
+                        .collect(),
+                }
+            }
+            convert_creating_jobs_to_background_request::Jobs::Ids(jobs) => jobs.job_ids,
+        };
+
+        let converted = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr
+                .catalog_manager
+                .convert_creating_jobs_to_background(job_ids)
+                .await?
+                .into_iter()
+                .map(|table| (table.id, table.name, table.definition))
+                .collect_vec(),
+            MetadataManager::V2(mgr) => mgr
+                .catalog_controller
+                .convert_creating_jobs_to_background(
+                    job_ids.into_iter().map(|id| id as _).collect_vec(),
+                )
+                .await?
+                .into_iter()
+                .map(|(id, name, definition)| (id as u32, name, definition))
+                .collect_vec(),
+        };
+
+        let event_log_manager = self.env.event_log_manager_ref();
+        for (id, name, definition) in &converted {
+            let event = risingwave_pb::meta::event_log::EventForegroundDdlToBackground {
+                id: *id,
+                name: name.clone(),
+                definition: definition.clone(),
+            };
+            event_log_manager.add_event_logs(vec![
+                risingwave_pb::meta::event_log::Event::ForegroundDdlToBackground(event),
+            ]);
+        }
+
+        Ok(Response::new(ConvertCreatingJobsToBackgroundResponse {
+            status: None,
+            converted_jobs: converted.into_iter().map(|(id, _, _)| id).collect_vec(),
+        }))
+    }
+
     #[cfg_attr(coverage, coverage(off))]
     async fn list_table_fragments(
         &self,
@@ -417,6 +901,43 @@ impl StreamManagerService for StreamServiceImpl {
         }))
     }
 
+    #[cfg_attr(coverage, coverage(off))]
+    async fn get_dependency_graph(
+        &self,
+        request: Request<GetDependencyGraphRequest>,
+    ) -> Result<Response<GetDependencyGraphResponse>, Status> {
+        let req = request.into_inner();
+        let mut graph = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.get_dependency_graph().await,
+            MetadataManager::V2(mgr) => mgr.catalog_controller.get_dependency_graph().await?,
+        };
+
+        if let Some(root) = req.depends_on_object_id {
+            // Keep only the reverse-reachable closure of the given object: the objects
+            // that directly or transitively depend on it, and the edges among them.
+            let mut reachable = HashSet::from([root]);
+            let mut changed = true;
+            while changed {
+                changed = false;
+                for edge in &graph.edges {
+                    if reachable.contains(&edge.referenced_object_id)
+                        && reachable.insert(edge.object_id)
+                    {
+                        changed = true;
+                    }
+                }
+            }
+            graph
+                .edges
+                .retain(|edge| reachable.contains(&edge.referenced_object_id));
+            graph
+                .nodes
+                .retain(|node| reachable.contains(&node.object_id));
+        }
+
+        Ok(Response::new(graph))
+    }
+
     #[cfg_attr(coverage, coverage(off))]
     async fn recover(
         &self,
@@ -428,4 +949,222 @@ impl StreamManagerService for StreamServiceImpl {
             .await;
         Ok(Response::new(RecoverResponse {}))
     }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn get_source_split_history(
+        &self,
+        request: Request<GetSourceSplitHistoryRequest>,
+    ) -> Result<Response<GetSourceSplitHistoryResponse>, Status> {
+        let req = request.into_inner();
+        let discoveries = self
+            .stream_manager
+            .source_manager
+            .get_source_split_history(req.source_id)
+            .await?;
+        Ok(Response::new(GetSourceSplitHistoryResponse { discoveries }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn list_source_partitions(
+        &self,
+        request: Request<ListSourcePartitionsRequest>,
+    ) -> Result<Response<ListSourcePartitionsResponse>, Status> {
+        let req = request.into_inner();
+        let partitions = self
+            .stream_manager
+            .source_manager
+            .list_source_partitions(req.source_id)
+            .await?;
+        Ok(Response::new(ListSourcePartitionsResponse { partitions }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn add_source_partition(
+        &self,
+        request: Request<AddSourcePartitionRequest>,
+    ) -> Result<Response<AddSourcePartitionResponse>, Status> {
+        let req = request.into_inner();
+        self.stream_manager
+            .source_manager
+            .add_source_partition(req.source_id, req.partition_name)
+            .await?;
+        Ok(Response::new(AddSourcePartitionResponse {}))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn invalidate_source_partition(
+        &self,
+        request: Request<InvalidateSourcePartitionRequest>,
+    ) -> Result<Response<InvalidateSourcePartitionResponse>, Status> {
+        let req = request.into_inner();
+        self.stream_manager
+            .source_manager
+            .invalidate_source_partition(req.source_id, &req.partition_name)
+            .await?;
+        Ok(Response::new(InvalidateSourcePartitionResponse {}))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn report_source_error_stats(
+        &self,
+        request: Request<ReportSourceErrorStatsRequest>,
+    ) -> Result<Response<ReportSourceErrorStatsResponse>, Status> {
+        let req = request.into_inner();
+        // Append the reported dead letters to the event log, so the identity of every
+        // skipped record stays inspectable.
+        let event_log_manager = self.env.event_log_manager_ref();
+        for stats in &req.stats {
+            let events = stats
+                .dead_letters
+                .iter()
+                .map(|dead_letter| {
+                    risingwave_pb::meta::event_log::Event::SourceDeadLetter(
+                        risingwave_pb::meta::event_log::EventSourceDeadLetter {
+                            source_id: stats.source_id,
+                            split_id: dead_letter.split_id.clone(),
+                            offset: dead_letter.offset.clone(),
+                            error: dead_letter.error.clone(),
+                        },
+                    )
+                })
+                .collect_vec();
+            if !events.is_empty() {
+                event_log_manager.add_event_logs(events);
+            }
+        }
+        self.stream_manager
+            .source_manager
+            .report_source_error_stats(req.stats)
+            .await;
+        Ok(Response::new(ReportSourceErrorStatsResponse {}))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn list_source_error_stats(
+        &self,
+        _request: Request<ListSourceErrorStatsRequest>,
+    ) -> Result<Response<ListSourceErrorStatsResponse>, Status> {
+        let stats = self
+            .stream_manager
+            .source_manager
+            .list_source_error_stats()
+            .await;
+        Ok(Response::new(ListSourceErrorStatsResponse { stats }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn get_worker_barrier_latency(
+        &self,
+        request: Request<GetWorkerBarrierLatencyRequest>,
+    ) -> Result<Response<GetWorkerBarrierLatencyResponse>, Status> {
+        let req = request.into_inner();
+        let latency = self
+            .barrier_manager
+            .get_worker_barrier_latency(req.worker_id)
+            .await?;
+        Ok(Response::new(GetWorkerBarrierLatencyResponse {
+            latency_us: latency
+                .into_iter()
+                .map(|latency| latency.as_micros() as u64)
+                .collect(),
+        }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn get_epoch_trace_ids(
+        &self,
+        _request: Request<GetEpochTraceIdsRequest>,
+    ) -> Result<Response<GetEpochTraceIdsResponse>, Status> {
+        let trace_ids = self.barrier_manager.get_epoch_trace_ids().await?;
+        Ok(Response::new(GetEpochTraceIdsResponse { trace_ids }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn list_inflight_barriers(
+        &self,
+        _request: Request<ListInflightBarriersRequest>,
+    ) -> Result<Response<ListInflightBarriersResponse>, Status> {
+        let barriers = self.barrier_manager.list_inflight_barriers().await?;
+        Ok(Response::new(ListInflightBarriersResponse { barriers }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn list_actor_backpressure(
+        &self,
+        _request: Request<ListActorBackpressureRequest>,
+    ) -> Result<Response<ListActorBackpressureResponse>, Status> {
+        let stats = self.barrier_manager.list_actor_backpressure().await?;
+        Ok(Response::new(ListActorBackpressureResponse { stats }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn create_named_checkpoint(
+        &self,
+        request: Request<CreateNamedCheckpointRequest>,
+    ) -> Result<Response<CreateNamedCheckpointResponse>, Status> {
+        if let MetadataManager::V2(_) = &self.metadata_manager {
+            return Err(Status::unimplemented(
+                "named checkpoints are only supported by the kv meta backend",
+            ));
+        }
+        let req = request.into_inner();
+        let checkpoint = self
+            .named_checkpoint_manager
+            .create_checkpoint(req.name)
+            .await?;
+        Ok(Response::new(CreateNamedCheckpointResponse {
+            status: None,
+            checkpoint: Some(checkpoint),
+        }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn list_named_checkpoints(
+        &self,
+        _request: Request<ListNamedCheckpointsRequest>,
+    ) -> Result<Response<ListNamedCheckpointsResponse>, Status> {
+        if let MetadataManager::V2(_) = &self.metadata_manager {
+            return Err(Status::unimplemented(
+                "named checkpoints are only supported by the kv meta backend",
+            ));
+        }
+        let checkpoints = self.named_checkpoint_manager.list_checkpoints().await?;
+        Ok(Response::new(ListNamedCheckpointsResponse { checkpoints }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn pin_named_checkpoint(
+        &self,
+        request: Request<PinNamedCheckpointRequest>,
+    ) -> Result<Response<PinNamedCheckpointResponse>, Status> {
+        if let MetadataManager::V2(_) = &self.metadata_manager {
+            return Err(Status::unimplemented(
+                "named checkpoints are only supported by the kv meta backend",
+            ));
+        }
+        let req = request.into_inner();
+        self.named_checkpoint_manager
+            .pin_checkpoint(&req.name)
+            .await?;
+        Ok(Response::new(PinNamedCheckpointResponse { status: None }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn create_fence(
+        &self,
+        _request: Request<CreateFenceRequest>,
+    ) -> Result<Response<CreateFenceResponse>, Status> {
+        let (token, fence_epoch) = self.consistency_fence_manager.create_fence();
+        Ok(Response::new(CreateFenceResponse { token, fence_epoch }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn await_fence(
+        &self,
+        request: Request<AwaitFenceRequest>,
+    ) -> Result<Response<AwaitFenceResponse>, Status> {
+        let req = request.into_inner();
+        let committed_epoch = self.consistency_fence_manager.await_fence(req.token).await?;
+        Ok(Response::new(AwaitFenceResponse { committed_epoch }))
+    }
 }
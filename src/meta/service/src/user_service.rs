@@ -19,9 +19,11 @@ use risingwave_pb::user::grant_privilege::Object;
 use risingwave_pb::user::update_user_request::UpdateField;
 use risingwave_pb::user::user_service_server::UserService;
 use risingwave_pb::user::{
-    CreateUserRequest, CreateUserResponse, DropUserRequest, DropUserResponse, GrantPrivilege,
-    GrantPrivilegeRequest, GrantPrivilegeResponse, RevokePrivilegeRequest, RevokePrivilegeResponse,
-    UpdateUserRequest, UpdateUserResponse,
+    AlterDefaultPrivilegesRequest, AlterDefaultPrivilegesResponse, CreateUserRequest,
+    CreateUserResponse, DropUserRequest, DropUserResponse, GrantPrivilege, GrantPrivilegeRequest,
+    GrantPrivilegeResponse, GrantRoleRequest, GrantRoleResponse, RevokePrivilegeRequest,
+    RevokePrivilegeResponse, RevokeRoleRequest, RevokeRoleResponse, UpdateUserRequest,
+    UpdateUserResponse,
 };
 use tonic::{Request, Response, Status};
 
@@ -47,6 +49,7 @@ impl UserServiceImpl {
         &self,
         privileges: &[GrantPrivilege],
         with_grant_option: Option<bool>,
+        expand_relations: bool,
     ) -> MetaResult<Vec<GrantPrivilege>> {
         let mut expanded_privileges = Vec::new();
         for privilege in privileges {
@@ -151,12 +154,54 @@ impl UserServiceImpl {
                         p.with_grant_option = with_grant_option;
                     });
                 }
+                // In relation-expansion mode, a privilege on a table or materialized view also
+                // covers its indexes and internal state tables, so that e.g. SELECT granted on
+                // an MV allows querying through the indexes created on it. All expanded
+                // privileges are applied in the same transaction below, with a single
+                // notification version.
+                if expand_relations {
+                    if let Some(Object::TableId(table_id)) = privilege.object {
+                        for owned_table_id in self.list_expanded_table_ids(table_id).await? {
+                            let mut owned_privilege = privilege.clone();
+                            owned_privilege.object = Some(Object::TableId(owned_table_id));
+                            expanded_privileges.push(owned_privilege);
+                        }
+                    }
+                }
                 expanded_privileges.push(privilege);
             }
         }
 
         Ok(expanded_privileges)
     }
+
+    /// Returns the ids of the indexes' tables and internal state tables belonging to the given
+    /// table, for expanding a privilege grant on the table to all objects owned by it.
+    async fn list_expanded_table_ids(&self, table_id: u32) -> MetaResult<Vec<u32>> {
+        let expanded_table_ids = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                let mut table_ids = mgr.catalog_manager.list_index_table_ids_on(table_id).await;
+                // Tables that are not streaming jobs, e.g. index tables, have no fragments and
+                // thus no internal tables.
+                if let Ok(fragments) = mgr
+                    .fragment_manager
+                    .select_table_fragments_by_table_id(&table_id.into())
+                    .await
+                {
+                    table_ids.extend(fragments.internal_table_ids());
+                }
+                table_ids
+            }
+            MetadataManager::V2(mgr) => mgr
+                .catalog_controller
+                .list_expanded_table_ids(table_id as _)
+                .await?
+                .into_iter()
+                .map(|id| id as _)
+                .collect(),
+        };
+        Ok(expanded_table_ids)
+    }
 }
 
 #[async_trait::async_trait]
@@ -248,7 +293,11 @@ impl UserService for UserServiceImpl {
     ) -> Result<Response<GrantPrivilegeResponse>, Status> {
         let req = request.into_inner();
         let new_privileges = self
-            .expand_privilege(req.get_privileges(), Some(req.with_grant_option))
+            .expand_privilege(
+                req.get_privileges(),
+                Some(req.with_grant_option),
+                req.expand_relations,
+            )
             .await?;
         let version = match &self.metadata_manager {
             MetadataManager::V1(mgr) => {
@@ -270,13 +319,91 @@ impl UserService for UserServiceImpl {
         }))
     }
 
+    #[cfg_attr(coverage, coverage(off))]
+    async fn grant_role(
+        &self,
+        request: Request<GrantRoleRequest>,
+    ) -> Result<Response<GrantRoleResponse>, Status> {
+        let req = request.into_inner();
+        let version = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .grant_role(&req.role_ids, &req.user_ids, req.granted_by)
+                    .await?
+            }
+            MetadataManager::V2(mgr) => {
+                let role_ids: Vec<_> = req.role_ids.iter().map(|id| *id as UserId).collect();
+                let user_ids: Vec<_> = req.user_ids.iter().map(|id| *id as UserId).collect();
+                mgr.catalog_controller
+                    .grant_role(role_ids, user_ids, req.granted_by as _)
+                    .await?
+            }
+        };
+
+        Ok(Response::new(GrantRoleResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn revoke_role(
+        &self,
+        request: Request<RevokeRoleRequest>,
+    ) -> Result<Response<RevokeRoleResponse>, Status> {
+        let req = request.into_inner();
+        let version = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .revoke_role(&req.role_ids, &req.user_ids, req.revoke_by)
+                    .await?
+            }
+            MetadataManager::V2(mgr) => {
+                let role_ids: Vec<_> = req.role_ids.iter().map(|id| *id as UserId).collect();
+                let user_ids: Vec<_> = req.user_ids.iter().map(|id| *id as UserId).collect();
+                mgr.catalog_controller
+                    .revoke_role(role_ids, user_ids, req.revoke_by as _)
+                    .await?
+            }
+        };
+
+        Ok(Response::new(RevokeRoleResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn alter_default_privileges(
+        &self,
+        request: Request<AlterDefaultPrivilegesRequest>,
+    ) -> Result<Response<AlterDefaultPrivilegesResponse>, Status> {
+        let req = request.into_inner();
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager.alter_default_privileges(req).await?;
+            }
+            MetadataManager::V2(_) => {
+                return Err(Status::unimplemented(
+                    "ALTER DEFAULT PRIVILEGES is not supported with the SQL meta backend yet",
+                ));
+            }
+        }
+
+        Ok(Response::new(AlterDefaultPrivilegesResponse {
+            status: None,
+        }))
+    }
+
     #[cfg_attr(coverage, coverage(off))]
     async fn revoke_privilege(
         &self,
         request: Request<RevokePrivilegeRequest>,
     ) -> Result<Response<RevokePrivilegeResponse>, Status> {
         let req = request.into_inner();
-        let privileges = self.expand_privilege(req.get_privileges(), None).await?;
+        let privileges = self
+            .expand_privilege(req.get_privileges(), None, false)
+            .await?;
         let version = match &self.metadata_manager {
             MetadataManager::V1(mgr) => {
                 mgr.catalog_manager
@@ -14,25 +14,30 @@
 
 use std::collections::HashSet;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
+use anyhow::anyhow;
 use arc_swap::ArcSwap;
 use risingwave_backup::error::BackupError;
 use risingwave_backup::storage::{MetaSnapshotStorage, ObjectStoreMetaSnapshotStorage};
 use risingwave_backup::{MetaBackupJobId, MetaSnapshotId, MetaSnapshotManifest};
 use risingwave_common::bail;
-use risingwave_common::config::ObjectStoreConfig;
+use risingwave_common::config::{MetaBackend, ObjectStoreConfig};
 use risingwave_common::system_param::reader::SystemParamsRead;
 use risingwave_hummock_sdk::HummockSstableObjectId;
 use risingwave_object_store::object::build_remote_object_store;
 use risingwave_object_store::object::object_metrics::ObjectStoreMetrics;
-use risingwave_pb::backup_service::{BackupJobStatus, MetaBackupManifestId};
+use risingwave_pb::backup_service::{BackupJobStatus, MetaBackupManifestId, RestoreMetaRequest};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use thiserror_ext::AsReport;
+use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
 
 use crate::backup_restore::metrics::BackupManagerMetrics;
-use crate::backup_restore::{meta_snapshot_builder, meta_snapshot_builder_v2};
+use crate::backup_restore::{
+    meta_snapshot_builder, meta_snapshot_builder_v2, restore, RestoreOpts,
+};
 use crate::hummock::sequence::next_meta_backup_id;
 use crate::hummock::{HummockManagerRef, HummockVersionSafePoint};
 use crate::manager::{LocalNotification, MetaSrvEnv, MetaStoreImpl};
@@ -243,6 +248,45 @@ impl BackupManager {
         Ok(job_id)
     }
 
+    /// Starts a worker that periodically takes an automatic meta snapshot.
+    pub fn start_scheduled_backup(
+        self: Arc<Self>,
+        interval: Duration,
+    ) -> (JoinHandle<()>, Sender<()>) {
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+        let join_handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // The first tick fires immediately. Skip it.
+            ticker.tick().await;
+            loop {
+                tokio::select! {
+                    _ = &mut shutdown_rx => {
+                        tracing::info!("scheduled backup worker is stopped");
+                        return;
+                    }
+                    _ = ticker.tick() => {}
+                }
+                // A failed attempt, e.g. because a backup job started elsewhere is still
+                // running, is not retried until the next tick.
+                match self.start_backup_job(Some("scheduled backup".into())).await {
+                    Ok(job_id) => {
+                        tracing::info!(job_id, "started scheduled backup job");
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e.as_report(), "failed to start scheduled backup job");
+                    }
+                }
+            }
+        });
+        (join_handle, shutdown_tx)
+    }
+
+    /// Returns whether a backup job is currently running.
+    pub async fn is_backup_job_running(&self) -> bool {
+        self.running_job_handle.lock().await.is_some()
+    }
+
     pub fn get_backup_job_status(&self, job_id: MetaBackupJobId) -> (BackupJobStatus, String) {
         let last = self.latest_job_info.load();
         if last.0 == job_id {
@@ -315,6 +359,41 @@ impl BackupManager {
         Ok(())
     }
 
+    /// Restores a meta snapshot to the target meta store specified in `request`.
+    ///
+    /// The target meta store must be empty, i.e. belong to a cluster that has never been
+    /// started; restoring into a meta store in use is rejected by the underlying writer.
+    /// The snapshot is also checked against this binary's `Migrator` version list, so a
+    /// snapshot taken by a newer cluster cannot be restored by an older one.
+    pub async fn restore_meta(&self, request: RestoreMetaRequest) -> MetaResult<()> {
+        let meta_store_type =
+            <MetaBackend as clap::ValueEnum>::from_str(&request.meta_store_type, true)
+                .map_err(|e| anyhow!("invalid meta store type: {e}"))?;
+        let current_config = self.backup_store.load().1.clone();
+        let opts = RestoreOpts {
+            meta_snapshot_id: request.meta_snapshot_id,
+            meta_store_type,
+            sql_endpoint: request.sql_endpoint,
+            sql_username: request.sql_username,
+            sql_password: request.sql_password,
+            sql_database: request.sql_database,
+            etcd_endpoints: request.etcd_endpoints,
+            etcd_auth: request.etcd_auth,
+            etcd_username: request.etcd_username,
+            etcd_password: request.etcd_password,
+            backup_storage_url: request.backup_storage_url.unwrap_or(current_config.0),
+            backup_storage_directory: request.backup_storage_directory.unwrap_or(current_config.1),
+            hummock_storage_url: request.hummock_storage_url,
+            hummock_storage_directory: request.hummock_storage_directory,
+            dry_run: request.dry_run,
+            // Same defaults as the restore CLI.
+            read_attempt_timeout_ms: 600000,
+            read_retry_attempts: 3,
+        };
+        restore(opts).await?;
+        Ok(())
+    }
+
     /// List all `SSTables` required by backups.
     pub fn list_pinned_ssts(&self) -> HashSet<HummockSstableObjectId> {
         self.backup_store
@@ -17,6 +17,7 @@ use risingwave_backup::meta_snapshot::MetaSnapshot;
 use risingwave_backup::meta_snapshot_v2::{MetaSnapshotV2, MetadataV2};
 use risingwave_backup::storage::{MetaSnapshotStorage, MetaSnapshotStorageRef};
 use risingwave_backup::MetaSnapshotId;
+use risingwave_meta_model_migration::{MigrationName, Migrator, MigratorTrait};
 use sea_orm::{DatabaseBackend, DbBackend, DbErr, Statement};
 
 use crate::backup_restore::restore_impl::{Loader, Writer};
@@ -79,10 +80,34 @@ impl Loader<MetadataV2> for LoaderV2 {
             );
             tracing::debug!("{target_snapshot}");
         }
+        check_migration_compatibility(&target_snapshot.metadata)?;
         Ok(target_snapshot)
     }
 }
 
+/// Checks that all migrations recorded in the snapshot are known to this binary's `Migrator`,
+/// i.e. rejects a snapshot taken by a newer cluster, whose models this binary may not
+/// understand.
+fn check_migration_compatibility(metadata: &MetadataV2) -> BackupResult<()> {
+    let known: std::collections::HashSet<_> = Migrator::migrations()
+        .iter()
+        .map(|m| m.name().to_string())
+        .collect();
+    let unknown = metadata
+        .seaql_migrations
+        .iter()
+        .filter(|m| !known.contains(&m.version))
+        .map(|m| m.version.as_str())
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        return Err(BackupError::Other(anyhow::anyhow!(
+            "snapshot contains migrations unknown to this version: {}. The snapshot was likely taken by a newer cluster",
+            unknown.join(", ")
+        )));
+    }
+    Ok(())
+}
+
 pub struct WriterModelV2ToMetaStoreV2 {
     meta_store: SqlMetaStore,
 }
@@ -23,19 +23,21 @@ use risingwave_common::types::Timestamptz;
 use risingwave_common::util::epoch::Epoch;
 use risingwave_connector::source::SplitImpl;
 use risingwave_hummock_sdk::HummockEpoch;
-use risingwave_pb::catalog::{CreateType, Table};
+use risingwave_pb::catalog::{CreateType, PbStreamSourceInfo, Table};
 use risingwave_pb::common::PbWorkerNode;
 use risingwave_pb::meta::table_fragments::PbActorStatus;
 use risingwave_pb::meta::PausedReason;
 use risingwave_pb::source::{ConnectorSplit, ConnectorSplits};
 use risingwave_pb::stream_plan::barrier::BarrierKind as PbBarrierKind;
 use risingwave_pb::stream_plan::barrier_mutation::Mutation;
+use risingwave_pb::stream_plan::connector_props_change_mutation::ConnectorProps;
 use risingwave_pb::stream_plan::throttle_mutation::RateLimit;
 use risingwave_pb::stream_plan::update_mutation::*;
 use risingwave_pb::stream_plan::{
-    AddMutation, BarrierMutation, CombinedMutation, Dispatcher, Dispatchers,
-    DropSubscriptionsMutation, PauseMutation, ResumeMutation, SourceChangeSplitMutation,
-    StopMutation, StreamActor, SubscriptionUpstreamInfo, ThrottleMutation, UpdateMutation,
+    AddMutation, BarrierMutation, CombinedMutation, ConnectorPropsChangeMutation, Dispatcher,
+    Dispatchers, DropSubscriptionsMutation, PauseMutation, ResumeMutation,
+    SourceChangeSplitMutation, SourceFormatChangeMutation, StopMutation, StreamActor,
+    SubscriptionUpstreamInfo, ThrottleMutation, UpdateMutation,
 };
 use risingwave_pb::stream_service::WaitEpochCommitRequest;
 use thiserror_ext::AsReport;
@@ -287,6 +289,33 @@ pub enum Command {
     /// the `rate_limit` of `FlowControl` Executor after `StreamScan` or Source.
     Throttle(ThrottleConfig),
 
+    /// `ConnectorPropsChange` command generates a `ConnectorPropsChange` barrier that pushes the
+    /// updated connector `with_properties` of sources or sinks, keyed by object id, to the
+    /// executors that own them. The catalog has already been updated when this command is issued.
+    ConnectorPropsChange(HashMap<u32, HashMap<String, String>>),
+
+    /// `SourceFormatChange` command generates a `SourceFormatChange` barrier that pushes the
+    /// updated format/encode info of sources, keyed by source id, to the source executors that
+    /// own them, so they switch parsers at the barrier epoch. The catalog has already been
+    /// updated when this command is issued.
+    SourceFormatChange(HashMap<u32, PbStreamSourceInfo>),
+
+    /// `PauseStreamingJob` command generates a `Throttle` barrier that sets the rate limit
+    /// of the job's source and backfill actors to zero, freezing a single misbehaving job.
+    /// Barriers keep flowing through its actors to preserve epoch alignment, so the rest of
+    /// the DAG is unaffected. The rate limits persisted in the catalog are untouched.
+    PauseStreamingJob {
+        table_id: TableId,
+        config: ThrottleConfig,
+    },
+
+    /// `ResumeStreamingJob` command reverts [`Command::PauseStreamingJob`] by restoring the
+    /// rate limits persisted in the job's stream nodes.
+    ResumeStreamingJob {
+        table_id: TableId,
+        config: ThrottleConfig,
+    },
+
     /// `CreateSubscription` command generates a `CreateSubscriptionMutation` to notify
     /// materialize executor to start storing old value for subscription.
     CreateSubscription {
@@ -381,6 +410,10 @@ impl Command {
             Command::MergeSnapshotBackfillStreamingJobs(_) => None,
             Command::SourceSplitAssignment(_) => None,
             Command::Throttle(_) => None,
+            Command::ConnectorPropsChange(_) => None,
+            Command::SourceFormatChange(_) => None,
+            Command::PauseStreamingJob { .. } => None,
+            Command::ResumeStreamingJob { .. } => None,
             Command::CreateSubscription { .. } => None,
             Command::DropSubscription { .. } => None,
         }
@@ -533,7 +566,9 @@ impl Command {
                     }))
                 }
 
-                Command::Throttle(config) => {
+                Command::Throttle(config)
+                | Command::PauseStreamingJob { config, .. }
+                | Command::ResumeStreamingJob { config, .. } => {
                     let mut actor_to_apply = HashMap::new();
                     for per_fragment in config.values() {
                         actor_to_apply.extend(per_fragment.iter().map(|(actor_id, limit)| {
@@ -546,6 +581,28 @@ impl Command {
                     }))
                 }
 
+                Command::ConnectorPropsChange(config) => Some(Mutation::ConnectorPropsChange(
+                    ConnectorPropsChangeMutation {
+                        object_props: config
+                            .iter()
+                            .map(|(object_id, props)| {
+                                (
+                                    *object_id,
+                                    ConnectorProps {
+                                        props: props.clone(),
+                                    },
+                                )
+                            })
+                            .collect(),
+                    },
+                )),
+
+                Command::SourceFormatChange(source_infos) => Some(Mutation::SourceFormatChange(
+                    SourceFormatChangeMutation {
+                        source_infos: source_infos.clone(),
+                    },
+                )),
+
                 Command::DropStreamingJobs { actors, .. } => Some(Mutation::Stop(StopMutation {
                     actors: actors.clone(),
                 })),
@@ -970,6 +1027,18 @@ impl CommandContext {
 
             Command::Throttle(_) => {}
 
+            Command::ConnectorPropsChange(_) => {}
+
+            Command::SourceFormatChange(_) => {}
+
+            Command::PauseStreamingJob { table_id, .. } => {
+                tracing::info!(table_id = table_id.table_id, "paused streaming job");
+            }
+
+            Command::ResumeStreamingJob { table_id, .. } => {
+                tracing::info!(table_id = table_id.table_id, "resumed streaming job");
+            }
+
             Command::Pause(reason) => {
                 if let PausedReason::ConfigChange = reason {
                     // After the `Pause` barrier is collected and committed, we must ensure that the
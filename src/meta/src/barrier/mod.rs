@@ -14,11 +14,11 @@
 
 use std::assert_matches::assert_matches;
 use std::collections::hash_map::Entry;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::future::pending;
 use std::mem::{replace, take};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context};
 use arc_swap::ArcSwap;
@@ -30,7 +30,7 @@ use risingwave_common::catalog::TableId;
 use risingwave_common::system_param::reader::SystemParamsRead;
 use risingwave_common::system_param::PAUSE_ON_NEXT_BOOTSTRAP_KEY;
 use risingwave_common::util::epoch::{Epoch, INVALID_EPOCH};
-use risingwave_common::{bail, must_match};
+use risingwave_common::bail;
 use risingwave_hummock_sdk::change_log::build_table_change_log_delta;
 use risingwave_hummock_sdk::sstable_info::SstableInfo;
 use risingwave_hummock_sdk::table_stats::from_prost_table_stats_map;
@@ -41,6 +41,8 @@ use risingwave_hummock_sdk::{HummockSstableObjectId, LocalSstableInfo};
 use risingwave_pb::catalog::table::TableType;
 use risingwave_pb::ddl_service::DdlProgress;
 use risingwave_pb::hummock::HummockVersionStats;
+use risingwave_pb::meta::list_actor_backpressure_response::ActorBackpressure;
+use risingwave_pb::meta::list_inflight_barriers_response::InflightBarrier;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::{PausedReason, PbRecoveryStatus};
 use risingwave_pb::stream_service::barrier_complete_response::CreateMviewProgress;
@@ -67,7 +69,7 @@ use crate::manager::{
     MetadataManager, SystemParamsManagerImpl, WorkerId,
 };
 use crate::rpc::metrics::MetaMetrics;
-use crate::stream::{ScaleControllerRef, SourceManagerRef};
+use crate::stream::{ScaleControllerRef, SourceManagerRef, ThrottleConfig};
 use crate::{MetaError, MetaResult};
 
 mod command;
@@ -86,7 +88,7 @@ pub use self::command::{
     Reschedule, SnapshotBackfillInfo,
 };
 pub use self::info::InflightSubscriptionInfo;
-pub use self::schedule::BarrierScheduler;
+pub use self::schedule::{BarrierScheduler, ScheduledCommandInfo};
 pub use self::trace::TracedEpoch;
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -128,6 +130,9 @@ enum BarrierManagerStatus {
 
 /// Scheduled command with its notifiers.
 struct Scheduled {
+    /// Queue-wide unique identifier, for inspecting and cancelling the command while it is
+    /// still waiting in the scheduling queue.
+    id: u64,
     command: Command,
     notifiers: Vec<Notifier>,
     send_latency_timer: HistogramTimer,
@@ -151,6 +156,10 @@ impl From<&BarrierManagerStatus> for PbRecoveryStatus {
 
 pub enum BarrierManagerRequest {
     GetDdlProgress(Sender<HashMap<u32, DdlProgress>>),
+    GetWorkerBarrierLatency(WorkerId, Sender<Vec<Duration>>),
+    GetEpochTraceIds(Sender<HashMap<u64, String>>),
+    ListInflightBarriers(Sender<Vec<InflightBarrier>>),
+    ListActorBackpressure(Sender<Vec<ActorBackpressure>>),
 }
 
 #[derive(Clone)]
@@ -206,6 +215,12 @@ pub struct GlobalBarrierManager {
     /// The `prev_epoch` of pending non checkpoint barriers
     pending_non_checkpoint_barriers: Vec<u64>,
 
+    /// Creating jobs whose backfill has been automatically paused under sustained
+    /// backpressure, to be resumed once the backpressure is relieved. Cleared on
+    /// recovery, which rebuilds the actors with the rate limits persisted in the
+    /// catalog anyway.
+    auto_paused_backfills: HashSet<TableId>,
+
     active_streaming_nodes: ActiveStreamingWorkerNodes,
 
     control_stream_manager: ControlStreamManager,
@@ -223,13 +238,33 @@ struct CheckpointControl {
     /// The join handle of the completing future is stored.
     completing_command: CompletingCommand,
 
+    /// Chains the hummock commits of pipelined global barrier completions, so that
+    /// epochs are committed in ascending order.
+    commit_permits: CommitPermitChain,
+
     hummock_version_stats: HummockVersionStats,
 
     create_mview_tracker: CreateMviewProgressTracker,
 
+    /// Per-actor barrier collect latency reported by compute nodes, for serving
+    /// `SHOW BACKPRESSURE`. Keyed by actor id.
+    actor_backpressure: HashMap<u32, ActorBackpressureState>,
+
     context: GlobalBarrierManagerContext,
 }
 
+/// Smoothed barrier collect latency of one actor, together with its provenance.
+struct ActorBackpressureState {
+    worker_id: u32,
+    /// Exponential moving average of the collect latency.
+    avg_latency: Duration,
+    updated_at: Instant,
+}
+
+/// Entries not refreshed for this long are dropped from the `SHOW BACKPRESSURE` output,
+/// e.g. after the actor has been dropped or its worker has left the cluster.
+const ACTOR_BACKPRESSURE_RETENTION: Duration = Duration::from_secs(60);
+
 impl CheckpointControl {
     async fn new(
         context: GlobalBarrierManagerContext,
@@ -239,8 +274,10 @@ impl CheckpointControl {
             command_ctx_queue: Default::default(),
             creating_streaming_job_controls: Default::default(),
             completing_command: CompletingCommand::None,
+            commit_permits: CommitPermitChain::default(),
             hummock_version_stats: context.hummock_manager.get_version_stats().await,
             create_mview_tracker,
+            actor_backpressure: Default::default(),
             context,
         }
     }
@@ -248,7 +285,9 @@ impl CheckpointControl {
     fn total_command_num(&self) -> usize {
         self.command_ctx_queue.len()
             + match &self.completing_command {
-                CompletingCommand::GlobalStreamingGraph { .. } => 1,
+                CompletingCommand::GlobalStreamingGraph {
+                    completing_barriers,
+                } => completing_barriers.len(),
                 _ => 0,
             }
     }
@@ -333,6 +372,7 @@ impl CheckpointControl {
             command_ctx.prev_epoch.value().0,
             EpochNode {
                 enqueue_time: timer,
+                enqueue_instant: Instant::now(),
                 state: BarrierEpochState {
                     node_to_collect,
                     resps: vec![],
@@ -351,6 +391,21 @@ impl CheckpointControl {
     fn barrier_collected(&mut self, resp: BarrierCompleteResponse) {
         let worker_id = resp.worker_id;
         let prev_epoch = resp.epoch;
+        for stats in &resp.actor_backpressure {
+            let latency = Duration::from_millis(stats.collect_latency_ms);
+            self.actor_backpressure
+                .entry(stats.actor_id)
+                .and_modify(|state| {
+                    state.worker_id = worker_id;
+                    state.avg_latency = (state.avg_latency * 3 + latency) / 4;
+                    state.updated_at = Instant::now();
+                })
+                .or_insert_with(|| ActorBackpressureState {
+                    worker_id,
+                    avg_latency: latency,
+                    updated_at: Instant::now(),
+                });
+        }
         tracing::trace!(
             worker_id,
             prev_epoch,
@@ -405,9 +460,11 @@ impl CheckpointControl {
             .map(|(_, x)| &x.command_ctx)
             .or(match &self.completing_command {
                 CompletingCommand::None
-                | CompletingCommand::Err(_)
+                | CompletingCommand::Err { .. }
                 | CompletingCommand::CreatingStreamingJob { .. } => None,
-                CompletingCommand::GlobalStreamingGraph { command_ctx, .. } => Some(command_ctx),
+                CompletingCommand::GlobalStreamingGraph {
+                    completing_barriers,
+                } => completing_barriers.back().map(|barrier| &barrier.command_ctx),
             })
             .map(|command_ctx| command_ctx.command.should_pause_inject_barrier())
             .unwrap_or(false);
@@ -418,12 +475,18 @@ impl CheckpointControl {
                 .chain(
                     match &self.completing_command {
                         CompletingCommand::None
-                        | CompletingCommand::Err(_)
+                        | CompletingCommand::Err { .. }
                         | CompletingCommand::CreatingStreamingJob { .. } => None,
-                        CompletingCommand::GlobalStreamingGraph { command_ctx, .. } =>
-                            Some(command_ctx),
+                        CompletingCommand::GlobalStreamingGraph {
+                            completing_barriers,
+                        } => Some(
+                            completing_barriers
+                                .iter()
+                                .map(|barrier| &barrier.command_ctx)
+                        ),
                     }
                     .into_iter()
+                    .flatten()
                 )
                 .any(|command_ctx| command_ctx.command.should_pause_inject_barrier()),
             should_pause
@@ -438,28 +501,56 @@ impl CheckpointControl {
         let is_err = match replace(&mut self.completing_command, CompletingCommand::None) {
             CompletingCommand::None => false,
             CompletingCommand::GlobalStreamingGraph {
-                command_ctx,
-                join_handle,
-                ..
+                completing_barriers,
             } => {
-                info!(
-                    prev_epoch = ?command_ctx.prev_epoch,
-                    curr_epoch = ?command_ctx.curr_epoch,
-                    "waiting for completing command to finish in recovery"
-                );
-                match join_handle.await {
-                    Err(e) => {
-                        warn!(err = ?e.as_report(), "failed to join completing task");
-                        true
+                let mut is_err = false;
+                for CompletingGlobalBarrier {
+                    command_ctx,
+                    join_handle,
+                    ..
+                } in completing_barriers
+                {
+                    info!(
+                        prev_epoch = ?command_ctx.prev_epoch,
+                        curr_epoch = ?command_ctx.curr_epoch,
+                        "waiting for completing command to finish in recovery"
+                    );
+                    match join_handle.await {
+                        Err(e) => {
+                            warn!(err = ?e.as_report(), "failed to join completing task");
+                            is_err = true;
+                        }
+                        Ok(Err(e)) => {
+                            warn!(err = ?e.as_report(), "failed to complete barrier during clear");
+                            is_err = true;
+                        }
+                        Ok(Ok(_)) => {}
                     }
-                    Ok(Err(e)) => {
-                        warn!(err = ?e.as_report(), "failed to complete barrier during clear");
-                        true
+                }
+                is_err
+            }
+            CompletingCommand::Err {
+                detached_barriers, ..
+            } => {
+                // A detached successor may already hold its commit permit, so wait for
+                // it to settle before recovery tears state down.
+                for CompletingGlobalBarrier {
+                    command_ctx,
+                    join_handle,
+                    ..
+                } in detached_barriers
+                {
+                    info!(
+                        prev_epoch = ?command_ctx.prev_epoch,
+                        curr_epoch = ?command_ctx.curr_epoch,
+                        "waiting for detached completing task to finish in recovery"
+                    );
+                    if let Err(e) = join_handle.await {
+                        warn!(err = ?e.as_report(), "failed to join detached completing task");
                     }
-                    Ok(Ok(_)) => false,
                 }
+                true
             }
-            CompletingCommand::Err(_) => true,
             CompletingCommand::CreatingStreamingJob { join_handle, .. } => {
                 match join_handle.await {
                     Err(e) => {
@@ -474,6 +565,7 @@ impl CheckpointControl {
                 }
             }
         };
+        self.commit_permits.reset();
         if !is_err {
             // continue to finish the pending collected barrier.
             while let Some((_, EpochNode { state, .. })) = self.command_ctx_queue.first_key_value()
@@ -487,10 +579,13 @@ impl CheckpointControl {
                 let finished_jobs = self
                     .create_mview_tracker
                     .apply_collected_command(&node, &self.hummock_version_stats);
+                // The pending barriers are completed one by one here, so there is no
+                // pipelined predecessor to wait for.
+                let (commit_done_tx, _) = oneshot::channel();
                 if let Err(e) = self
                     .context
                     .clone()
-                    .complete_barrier(node, finished_jobs, HashMap::new())
+                    .complete_barrier(node, finished_jobs, HashMap::new(), None, commit_done_tx)
                     .await
                 {
                     error!(
@@ -515,6 +610,11 @@ impl CheckpointControl {
             node.enqueue_time.observe_duration();
         }
         self.create_mview_tracker.abort_all();
+        // All tracked backfills are aborted; restore the compaction pick order.
+        self.context
+            .hummock_manager
+            .update_backfill_compaction_groups(HashSet::new(), HashSet::new())
+            .await;
     }
 
     /// Return the earliest command waiting on the `worker_id`.
@@ -526,12 +626,62 @@ impl CheckpointControl {
         }
         None
     }
+
+    /// Returns a snapshot of the in-flight barriers for inspection, oldest first. The
+    /// pending actors are resolved from the current inflight graph: those located on
+    /// workers that have not yet collected the barrier.
+    fn list_inflight_barriers(&self, graph_info: &InflightGraphInfo) -> Vec<InflightBarrier> {
+        self.command_ctx_queue
+            .values()
+            .map(|node| {
+                let uncollected_worker_ids = node
+                    .state
+                    .node_to_collect
+                    .iter()
+                    .copied()
+                    .sorted()
+                    .collect_vec();
+                let pending_actor_ids = uncollected_worker_ids
+                    .iter()
+                    .filter_map(|worker_id| graph_info.actor_map.get(worker_id))
+                    .flatten()
+                    .copied()
+                    .sorted()
+                    .collect_vec();
+                InflightBarrier {
+                    prev_epoch: node.command_ctx.prev_epoch.value().0,
+                    curr_epoch: node.command_ctx.curr_epoch.value().0,
+                    command: node.command_ctx.command.to_string(),
+                    inflight_duration_ms: node.enqueue_instant.elapsed().as_millis() as u64,
+                    uncollected_worker_ids,
+                    pending_actor_ids,
+                }
+            })
+            .collect()
+    }
+
+    /// Serving `SHOW BACKPRESSURE`. Stale entries are skipped.
+    fn list_actor_backpressure(&self) -> Vec<ActorBackpressure> {
+        self.actor_backpressure
+            .iter()
+            .filter(|(_, state)| state.updated_at.elapsed() < ACTOR_BACKPRESSURE_RETENTION)
+            .map(|(actor_id, state)| ActorBackpressure {
+                actor_id: *actor_id,
+                worker_id: state.worker_id,
+                collect_latency_ms: state.avg_latency.as_millis() as u64,
+            })
+            .sorted_by_key(|stats| stats.actor_id)
+            .collect()
+    }
 }
 
 /// The state and message of this barrier, a node for concurrent checkpoint.
 struct EpochNode {
     /// Timer for recording barrier latency, taken after `complete_barriers`.
     enqueue_time: HistogramTimer,
+    /// Instant of enqueue, for feeding the barrier latency back to the adaptive
+    /// checkpoint frequency controller.
+    enqueue_instant: Instant,
 
     /// Whether this barrier is in-flight or completed.
     state: BarrierEpochState,
@@ -561,25 +711,108 @@ impl BarrierEpochState {
     }
 }
 
+/// A collected global barrier whose completion has been spawned.
+struct CompletingGlobalBarrier {
+    command_ctx: Arc<CommandContext>,
+    table_ids_to_finish: HashSet<TableId>,
+    require_next_checkpoint: bool,
+    enqueue_instant: Instant,
+
+    // The join handle of a spawned task that completes the barrier.
+    // The return value indicate whether there is some create streaming job command
+    // that has finished but not checkpointed. If there is any, we will force checkpoint on the next barrier
+    join_handle: JoinHandle<MetaResult<Option<HummockVersionStats>>>,
+}
+
+/// The maximum number of global barrier completions that may be in flight at the same
+/// time. With a depth of 2, the completion of the next collected barrier is pipelined
+/// with the hummock commit of the previous one.
+const MAX_COMPLETING_BARRIER_NUMS: usize = 2;
+
+/// Whether another global barrier completion may be pipelined alongside
+/// `num_completing_barriers` already-running ones.
+fn has_completion_capacity(num_completing_barriers: usize) -> bool {
+    num_completing_barriers < MAX_COMPLETING_BARRIER_NUMS
+}
+
+/// Chains the hummock commits of pipelined global barrier completions. Each spawned
+/// completion takes the permit of its predecessor and leaves a fresh one behind for
+/// its successor; a permit resolves once the predecessor's epoch is committed and
+/// errors if the predecessor failed before committing.
+#[derive(Default)]
+struct CommitPermitChain {
+    /// The permit to be handed to the next spawned completion, `None` when no global
+    /// barrier completion is in flight.
+    next_permit: Option<Receiver<()>>,
+}
+
+impl CommitPermitChain {
+    /// Returns the predecessor's permit (if any) together with the sender through
+    /// which the new completion signals its own commit.
+    fn chain(&mut self) -> (Option<Receiver<()>>, Sender<()>) {
+        let (commit_done_tx, commit_done_rx) = oneshot::channel();
+        let commit_permit = self.next_permit.replace(commit_done_rx);
+        (commit_permit, commit_done_tx)
+    }
+
+    /// Drops the pending permit, when the pipeline has drained or is torn down.
+    fn reset(&mut self) {
+        self.next_permit = None;
+    }
+}
+
+/// Wait until the pipelined predecessor has committed its epoch, so that epochs are
+/// still committed to hummock in ascending order. The sender is dropped without
+/// signalling when the predecessor fails, in which case the successor must not commit
+/// either; the subsequent recovery cleans everything up.
+async fn wait_commit_permit(commit_permit: Option<Receiver<()>>) -> MetaResult<()> {
+    match commit_permit {
+        Some(permit) if permit.await.is_err() => Err(anyhow!(
+            "previous barrier failed to complete, skip committing the current barrier"
+        )
+        .into()),
+        _ => Ok(()),
+    }
+}
+
 enum CompletingCommand {
     None,
     GlobalStreamingGraph {
-        command_ctx: Arc<CommandContext>,
-        table_ids_to_finish: HashSet<TableId>,
-        require_next_checkpoint: bool,
-
-        // The join handle of a spawned task that completes the barrier.
-        // The return value indicate whether there is some create streaming job command
-        // that has finished but not checkpointed. If there is any, we will force checkpoint on the next barrier
-        join_handle: JoinHandle<MetaResult<Option<HummockVersionStats>>>,
+        /// Barriers that are completing concurrently, in ascending epoch order. The
+        /// spawned tasks chain their hummock commits through commit permits, so that
+        /// epochs are still committed in ascending order while the rest of the
+        /// completion is pipelined.
+        completing_barriers: VecDeque<CompletingGlobalBarrier>,
     },
     CreatingStreamingJob {
         table_id: TableId,
         epoch: u64,
         join_handle: JoinHandle<MetaResult<()>>,
     },
-    #[expect(dead_code)]
-    Err(MetaError),
+    Err {
+        #[expect(dead_code)]
+        err: MetaError,
+        /// The pipelined successors of the failed completion. A successor may already
+        /// hold its commit permit (the predecessor can fail after signalling its
+        /// commit), so `clear_on_err` awaits these handles before recovery proceeds:
+        /// recovery must never overlap an in-flight hummock commit.
+        detached_barriers: VecDeque<CompletingGlobalBarrier>,
+    },
+}
+
+impl CompletingCommand {
+    /// Whether another global barrier completion may be started: completions of the
+    /// same kind are pipelined up to [`MAX_COMPLETING_BARRIER_NUMS`], while creating
+    /// job completions and the error state stay exclusive.
+    fn can_start_global_barrier(&self) -> bool {
+        match self {
+            CompletingCommand::None => true,
+            CompletingCommand::GlobalStreamingGraph {
+                completing_barriers,
+            } => has_completion_capacity(completing_barriers.len()),
+            CompletingCommand::CreatingStreamingJob { .. } | CompletingCommand::Err { .. } => false,
+        }
+    }
 }
 
 impl GlobalBarrierManager {
@@ -636,6 +869,7 @@ impl GlobalBarrierManager {
             checkpoint_control,
             request_rx,
             pending_non_checkpoint_barriers: Vec::new(),
+            auto_paused_backfills: HashSet::new(),
             active_streaming_nodes,
             control_stream_manager,
         }
@@ -682,12 +916,35 @@ impl GlobalBarrierManager {
         Ok(paused)
     }
 
+    /// Computes the effective barrier interval, taking per-database overrides into account.
+    /// Barriers are collected by a single global loop for now, so the tightest interval
+    /// among all databases governs the pace.
+    async fn effective_barrier_interval(&self) -> Duration {
+        let default_ms = self.env.system_params_reader().await.barrier_interval_ms() as u64;
+        let override_ms = match &self.context.metadata_manager {
+            MetadataManager::V1(mgr) => mgr
+                .catalog_manager
+                .list_databases()
+                .await
+                .into_iter()
+                .filter_map(|db| db.barrier_interval_ms)
+                .min(),
+            MetadataManager::V2(mgr) => mgr
+                .catalog_controller
+                .list_databases()
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|db| db.barrier_interval_ms)
+                .min(),
+        };
+        Duration::from_millis(override_ms.map_or(default_ms, |ms| default_ms.min(ms as u64)))
+    }
+
     /// Start an infinite loop to take scheduled barriers and send them.
     async fn run(mut self, mut shutdown_rx: Receiver<()>) {
         // Initialize the barrier manager.
-        let interval = Duration::from_millis(
-            self.env.system_params_reader().await.barrier_interval_ms() as u64,
-        );
+        let interval = self.effective_barrier_interval().await;
         self.scheduled_barriers.set_min_interval(interval);
         tracing::info!(
             "Starting barrier manager with: interval={:?}, enable_recovery={}, in_flight_barrier_nums={}",
@@ -773,6 +1030,30 @@ impl GlobalBarrierManager {
                                     error!("failed to send get ddl progress");
                                 }
                             }
+                            BarrierManagerRequest::GetWorkerBarrierLatency(worker_id, result_tx) => {
+                                let latency = self.control_stream_manager.worker_barrier_latency(worker_id);
+                                if result_tx.send(latency).is_err() {
+                                    error!("failed to send get worker barrier latency");
+                                }
+                            }
+                            BarrierManagerRequest::GetEpochTraceIds(result_tx) => {
+                                let trace_ids = self.control_stream_manager.epoch_trace_ids();
+                                if result_tx.send(trace_ids).is_err() {
+                                    error!("failed to send get epoch trace ids");
+                                }
+                            }
+                            BarrierManagerRequest::ListInflightBarriers(result_tx) => {
+                                let barriers = self.checkpoint_control.list_inflight_barriers(&self.state.inflight_graph_info);
+                                if result_tx.send(barriers).is_err() {
+                                    error!("failed to send list inflight barriers");
+                                }
+                            }
+                            BarrierManagerRequest::ListActorBackpressure(result_tx) => {
+                                let stats = self.checkpoint_control.list_actor_backpressure();
+                                if result_tx.send(stats).is_err() {
+                                    error!("failed to send list actor backpressure");
+                                }
+                            }
                         }
                     } else {
                         tracing::info!("end of request stream. meta node may be shutting down. Stop global barrier manager");
@@ -842,9 +1123,22 @@ impl GlobalBarrierManager {
                     match notification {
                         // Handle barrier interval and checkpoint frequency changes.
                         LocalNotification::SystemParamsChange(p) => {
-                            self.scheduled_barriers.set_min_interval(Duration::from_millis(p.barrier_interval_ms() as u64));
+                            let interval = self.effective_barrier_interval().await;
+                            self.scheduled_barriers.set_min_interval(interval);
                             self.scheduled_barriers
-                                .set_checkpoint_frequency(p.checkpoint_frequency() as usize)
+                                .set_checkpoint_frequency(p.checkpoint_frequency() as usize);
+                            self.scheduled_barriers.set_adaptive_checkpoint_frequency_bounds(
+                                p.adaptive_checkpoint_frequency_min() as usize,
+                                p.adaptive_checkpoint_frequency_max() as usize,
+                            );
+                            self.scheduled_barriers.set_backpressure_max_stretch(
+                                p.barrier_backpressure_max_stretch() as u32,
+                            );
+                        },
+                        // Handle per-database barrier interval override changes.
+                        LocalNotification::DatabaseBarrierIntervalChange => {
+                            let interval = self.effective_barrier_interval().await;
+                            self.scheduled_barriers.set_min_interval(interval);
                         },
                         // Handle adhoc recovery triggered by user.
                         LocalNotification::AdhocRecovery => {
@@ -856,8 +1150,16 @@ impl GlobalBarrierManager {
                 (worker_id, resp_result) = self.control_stream_manager.next_complete_barrier_response() => {
                     match resp_result {
                         Ok(resp) => {
+                            if let Some(max_latency_ms) = resp
+                                .actor_backpressure
+                                .iter()
+                                .map(|stats| stats.collect_latency_ms)
+                                .max()
+                            {
+                                self.scheduled_barriers
+                                    .on_actor_backpressure(Duration::from_millis(max_latency_ms));
+                            }
                             self.checkpoint_control.barrier_collected(resp);
-
                         }
                         Err(e) => {
                             let failed_command = self.checkpoint_control.command_wait_collect_from_worker(worker_id);
@@ -872,6 +1174,15 @@ impl GlobalBarrierManager {
                                 self.failure_recovery(err).await;
                             } else {
                                 warn!(e = %e.as_report(), worker_id, "no barrier to collect from worker, ignore err");
+                                // The error is likely a transient RPC failure. Reconnect the
+                                // control stream with backoff so that the next barrier round
+                                // can include the worker, instead of failing with an
+                                // unconnected worker and triggering a full recovery.
+                                if let Some(node) = self.active_streaming_nodes.current().get(&worker_id).cloned() {
+                                    self.control_stream_manager
+                                        .add_worker(node, &self.state.inflight_subscription_info)
+                                        .await;
+                                }
                             }
                         }
                     }
@@ -879,6 +1190,8 @@ impl GlobalBarrierManager {
                 complete_result = self.checkpoint_control.next_completed_barrier() => {
                     match complete_result {
                         Ok(Some(output)) => {
+                            self.scheduled_barriers.on_barrier_latency(output.barrier_latency);
+                            self.update_auto_paused_backfills().await;
                             // If there are remaining commands (that requires checkpoint to finish), we force
                             // the next barrier to be a checkpoint.
                             if output.require_next_checkpoint {
@@ -1101,6 +1414,85 @@ impl GlobalBarrierManager {
         Ok(())
     }
 
+    /// Auto-pause and resume the backfills of creating jobs based on backpressure and
+    /// their priority class. When the backpressure throttle has stretched the barrier
+    /// interval to its maximum, the backfill of one more non-high-priority creating job
+    /// is paused per completed barrier, lowest priority first; once the stretch is fully
+    /// relieved, all auto-paused backfills are resumed with their persisted rate limits.
+    async fn update_auto_paused_backfills(&mut self) {
+        if self.scheduled_barriers.is_backpressure_saturated() {
+            let Some(table_id) = self
+                .checkpoint_control
+                .create_mview_tracker
+                .next_backfill_to_auto_pause(&self.auto_paused_backfills)
+            else {
+                return;
+            };
+            // Exclude the job from further picks even if building the pause config
+            // fails, e.g. for jobs without throttleable nodes.
+            self.auto_paused_backfills.insert(table_id);
+            let rate_limits = match self
+                .context
+                .metadata_manager
+                .get_mv_rate_limit_by_table_id(table_id)
+                .await
+            {
+                Ok(rate_limits) if !rate_limits.is_empty() => rate_limits,
+                Ok(_) => return,
+                Err(e) => {
+                    warn!(e = %e.as_report(), %table_id, "failed to get rate limits to auto-pause backfill");
+                    return;
+                }
+            };
+            let config: ThrottleConfig = rate_limits
+                .into_iter()
+                .map(|(fragment_id, actors)| {
+                    (
+                        fragment_id,
+                        actors
+                            .into_keys()
+                            .map(|actor_id| (actor_id, Some(0)))
+                            .collect(),
+                    )
+                })
+                .collect();
+            info!(%table_id, "auto-pausing backfill under sustained backpressure");
+            if let Err(e) = self
+                .scheduled_barriers
+                .push_standalone_command(Command::PauseStreamingJob { table_id, config })
+            {
+                warn!(e = %e.as_report(), %table_id, "failed to schedule auto-pause of backfill");
+                self.auto_paused_backfills.remove(&table_id);
+            }
+        } else if !self.scheduled_barriers.is_backpressure_stretched()
+            && !self.auto_paused_backfills.is_empty()
+        {
+            for table_id in std::mem::take(&mut self.auto_paused_backfills) {
+                let config = match self
+                    .context
+                    .metadata_manager
+                    .get_mv_rate_limit_by_table_id(table_id)
+                    .await
+                {
+                    Ok(config) if !config.is_empty() => config,
+                    // The job may have finished or been cancelled while paused.
+                    Ok(_) => continue,
+                    Err(e) => {
+                        warn!(e = %e.as_report(), %table_id, "failed to get rate limits to resume auto-paused backfill");
+                        continue;
+                    }
+                };
+                info!(%table_id, "resuming auto-paused backfill on relieved backpressure");
+                if let Err(e) = self
+                    .scheduled_barriers
+                    .push_standalone_command(Command::ResumeStreamingJob { table_id, config })
+                {
+                    warn!(e = %e.as_report(), %table_id, "failed to schedule resume of auto-paused backfill");
+                }
+            }
+        }
+    }
+
     async fn failure_recovery(&mut self, err: MetaError) {
         self.checkpoint_control.clear_on_err(&err).await;
         self.pending_non_checkpoint_barriers.clear();
@@ -1203,6 +1595,8 @@ impl GlobalBarrierManagerContext {
         node: EpochNode,
         mut finished_jobs: Vec<TrackingJob>,
         backfill_pinned_log_epoch: HashMap<TableId, (u64, HashSet<TableId>)>,
+        commit_permit: Option<Receiver<()>>,
+        commit_done: Sender<()>,
     ) -> MetaResult<Option<HummockVersionStats>> {
         tracing::trace!(
             prev_epoch = node.command_ctx.prev_epoch.value().0,
@@ -1229,14 +1623,18 @@ impl GlobalBarrierManagerContext {
             })
         }));
 
-        let result = self
-            .update_snapshot(
-                &command_ctx,
-                state.table_ids_to_commit,
-                state.resps,
-                backfill_pinned_log_epoch,
-            )
-            .await;
+        let result = match wait_commit_permit(commit_permit).await {
+            Ok(()) => {
+                self.update_snapshot(
+                    &command_ctx,
+                    state.table_ids_to_commit,
+                    state.resps,
+                    backfill_pinned_log_epoch,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
 
         let version_stats = match result {
             Ok(version_stats) => version_stats,
@@ -1247,6 +1645,9 @@ impl GlobalBarrierManagerContext {
                 return Err(e);
             }
         };
+        // Allow the pipelined successor, if any, to start committing its own epoch
+        // while we notify the waiters and finish the jobs of this barrier.
+        let _ = commit_done.send(());
         notifiers.into_iter().for_each(|notifier| {
             notifier.notify_collected();
         });
@@ -1383,6 +1784,8 @@ struct BarrierCompleteOutput {
     command_ctx: Arc<CommandContext>,
     require_next_checkpoint: bool,
     table_ids_to_finish: HashSet<TableId>,
+    /// Time from enqueue to completion of the barrier.
+    barrier_latency: Duration,
 }
 
 impl CheckpointControl {
@@ -1414,110 +1817,155 @@ impl CheckpointControl {
     pub(super) async fn next_completed_barrier(
         &mut self,
     ) -> MetaResult<Option<BarrierCompleteOutput>> {
-        if matches!(&self.completing_command, CompletingCommand::None) {
-            // If there is no completing barrier, try to start completing the earliest barrier if
-            // it has been collected.
-            if let Some((_, EpochNode { state, .. })) = self.command_ctx_queue.first_key_value()
-                && !state.is_inflight()
-            {
-                let (_, node) = self.command_ctx_queue.pop_first().expect("non-empty");
-                assert!(node.state.creating_jobs_to_wait.is_empty());
-                let table_ids_to_finish = node.state.finished_table_ids.keys().cloned().collect();
-                let finished_jobs = self
-                    .create_mview_tracker
-                    .apply_collected_command(&node, &self.hummock_version_stats);
-                let command_ctx = node.command_ctx.clone();
-                let join_handle = tokio::spawn(self.context.clone().complete_barrier(
-                    node,
-                    finished_jobs,
-                    self.collect_backfill_pinned_upstream_log_epoch(),
-                ));
-                let require_next_checkpoint =
-                    if self.create_mview_tracker.has_pending_finished_jobs() {
-                        self.command_ctx_queue
-                            .values()
-                            .all(|node| !node.command_ctx.kind.is_checkpoint())
-                    } else {
-                        false
-                    };
-                self.completing_command = CompletingCommand::GlobalStreamingGraph {
-                    command_ctx,
-                    require_next_checkpoint,
-                    join_handle,
-                    table_ids_to_finish,
-                };
+        if self.completing_command.can_start_global_barrier()
+            && let Some((_, EpochNode { state, .. })) = self.command_ctx_queue.first_key_value()
+            && !state.is_inflight()
+        {
+            // Try to start completing the earliest barrier if it has been collected.
+            let (_, node) = self.command_ctx_queue.pop_first().expect("non-empty");
+            assert!(node.state.creating_jobs_to_wait.is_empty());
+            let table_ids_to_finish = node.state.finished_table_ids.keys().cloned().collect();
+            let finished_jobs = self
+                .create_mview_tracker
+                .apply_collected_command(&node, &self.hummock_version_stats);
+            // Keep compaction deprioritized for the upstream tables of large in-flight
+            // backfills and prioritized for those of high-priority creating jobs, and
+            // restore the pick order once they complete.
+            self.context
+                .hummock_manager
+                .update_backfill_compaction_groups(
+                    self.create_mview_tracker.large_backfill_upstream_table_ids(),
+                    self.create_mview_tracker
+                        .high_priority_backfill_upstream_table_ids(),
+                )
+                .await;
+            let command_ctx = node.command_ctx.clone();
+            let enqueue_instant = node.enqueue_instant;
+            // Chain the commit order through commit permits: the new task waits for the
+            // permit of the previously spawned one, if any, before committing its epoch.
+            let (commit_permit, commit_done_tx) = self.commit_permits.chain();
+            let join_handle = tokio::spawn(self.context.clone().complete_barrier(
+                node,
+                finished_jobs,
+                self.collect_backfill_pinned_upstream_log_epoch(),
+                commit_permit,
+                commit_done_tx,
+            ));
+            let require_next_checkpoint = if self.create_mview_tracker.has_pending_finished_jobs() {
+                self.command_ctx_queue
+                    .values()
+                    .all(|node| !node.command_ctx.kind.is_checkpoint())
             } else {
-                for (table_id, job) in &mut self.creating_streaming_job_controls {
-                    let (upstream_epochs_to_notify, commit_info) = job.start_completing();
-                    for upstream_epoch in upstream_epochs_to_notify {
-                        let wait_progress_timer = self
-                            .command_ctx_queue
-                            .get_mut(&upstream_epoch)
-                            .expect("should exist")
-                            .state
-                            .creating_jobs_to_wait
-                            .remove(table_id)
-                            .expect("should exist");
-                        if let Some(timer) = wait_progress_timer {
-                            timer.observe_duration();
-                        }
+                false
+            };
+            let completing_barrier = CompletingGlobalBarrier {
+                command_ctx,
+                require_next_checkpoint,
+                enqueue_instant,
+                join_handle,
+                table_ids_to_finish,
+            };
+            match &mut self.completing_command {
+                CompletingCommand::GlobalStreamingGraph {
+                    completing_barriers,
+                } => completing_barriers.push_back(completing_barrier),
+                completing_command @ CompletingCommand::None => {
+                    *completing_command = CompletingCommand::GlobalStreamingGraph {
+                        completing_barriers: VecDeque::from([completing_barrier]),
+                    };
+                }
+                _ => unreachable!("checked by can_start_global_barrier"),
+            }
+        } else if matches!(&self.completing_command, CompletingCommand::None) {
+            for (table_id, job) in &mut self.creating_streaming_job_controls {
+                let (upstream_epochs_to_notify, commit_info) = job.start_completing();
+                for upstream_epoch in upstream_epochs_to_notify {
+                    let wait_progress_timer = self
+                        .command_ctx_queue
+                        .get_mut(&upstream_epoch)
+                        .expect("should exist")
+                        .state
+                        .creating_jobs_to_wait
+                        .remove(table_id)
+                        .expect("should exist");
+                    if let Some(timer) = wait_progress_timer {
+                        timer.observe_duration();
                     }
-                    if let Some((epoch, resps, is_first_time)) = commit_info {
-                        let tables_to_commit = job
-                            .info
-                            .table_fragments
-                            .all_table_ids()
-                            .map(TableId::new)
-                            .collect();
-                        let join_handle =
-                            tokio::spawn(self.context.clone().complete_creating_job_barrier(
-                                epoch,
-                                resps,
-                                tables_to_commit,
-                                is_first_time,
-                            ));
-                        self.completing_command = CompletingCommand::CreatingStreamingJob {
-                            table_id: *table_id,
+                }
+                if let Some((epoch, resps, is_first_time)) = commit_info {
+                    let tables_to_commit = job
+                        .info
+                        .table_fragments
+                        .all_table_ids()
+                        .map(TableId::new)
+                        .collect();
+                    let join_handle =
+                        tokio::spawn(self.context.clone().complete_creating_job_barrier(
                             epoch,
-                            join_handle,
-                        };
-                        break;
-                    }
+                            resps,
+                            tables_to_commit,
+                            is_first_time,
+                        ));
+                    self.completing_command = CompletingCommand::CreatingStreamingJob {
+                        table_id: *table_id,
+                        epoch,
+                        join_handle,
+                    };
+                    break;
                 }
             }
         }
 
         match &mut self.completing_command {
-            CompletingCommand::GlobalStreamingGraph { join_handle, .. } => {
+            CompletingCommand::GlobalStreamingGraph {
+                completing_barriers,
+            } => {
                 let join_result: MetaResult<_> = try {
-                    join_handle
+                    completing_barriers
+                        .front_mut()
+                        .expect("non-empty")
+                        .join_handle
                         .await
                         .context("failed to join completing command")??
                 };
-                // It's important to reset the completing_command after await no matter the result is err
-                // or not, and otherwise the join handle will be polled again after ready.
-                let next_completing_command_status = if let Err(e) = &join_result {
-                    CompletingCommand::Err(e.clone())
-                } else {
-                    CompletingCommand::None
-                };
-                let completed_command =
-                    replace(&mut self.completing_command, next_completing_command_status);
-                join_result.map(move | version_stats| {
-                        if let Some(new_version_stats) = version_stats {
-                            self.hummock_version_stats = new_version_stats;
-                        }
-                        must_match!(
-                            completed_command,
-                            CompletingCommand::GlobalStreamingGraph { command_ctx, table_ids_to_finish, require_next_checkpoint, .. } => {
-                                Some(BarrierCompleteOutput {
-                                    command_ctx,
-                                    require_next_checkpoint,
-                                    table_ids_to_finish,
-                                })
-                            }
-                        )
+                // It's important to pop the completed barrier after await no matter the result is
+                // err or not, and otherwise the join handle will be polled again after ready.
+                let completed_barrier = completing_barriers.pop_front().expect("non-empty");
+                let no_remaining = completing_barriers.is_empty();
+                if let Err(e) = &join_result {
+                    // Carry the pipelined successors into the `Err` state rather than
+                    // dropping (and thereby detaching) their join handles: a successor
+                    // whose commit permit was already granted can still be committing
+                    // its epoch, and `clear_on_err` must be able to wait for it before
+                    // recovery tears state down.
+                    let detached_barriers = take(completing_barriers);
+                    self.completing_command = CompletingCommand::Err {
+                        err: e.clone(),
+                        detached_barriers,
+                    };
+                    self.commit_permits.reset();
+                } else if no_remaining {
+                    self.completing_command = CompletingCommand::None;
+                    self.commit_permits.reset();
+                }
+                join_result.map(move |version_stats| {
+                    if let Some(new_version_stats) = version_stats {
+                        self.hummock_version_stats = new_version_stats;
+                    }
+                    let CompletingGlobalBarrier {
+                        command_ctx,
+                        table_ids_to_finish,
+                        require_next_checkpoint,
+                        enqueue_instant,
+                        ..
+                    } = completed_barrier;
+                    Some(BarrierCompleteOutput {
+                        command_ctx,
+                        require_next_checkpoint,
+                        table_ids_to_finish,
+                        barrier_latency: enqueue_instant.elapsed(),
                     })
+                })
             }
             CompletingCommand::CreatingStreamingJob {
                 table_id,
@@ -1534,7 +1982,10 @@ impl CheckpointControl {
                 // It's important to reset the completing_command after await no matter the result is err
                 // or not, and otherwise the join handle will be polled again after ready.
                 let next_completing_command_status = if let Err(e) = &join_result {
-                    CompletingCommand::Err(e.clone())
+                    CompletingCommand::Err {
+                        err: e.clone(),
+                        detached_barriers: VecDeque::new(),
+                    }
                 } else {
                     CompletingCommand::None
                 };
@@ -1575,7 +2026,7 @@ impl CheckpointControl {
                 }
                 join_result.map(|_| None)
             }
-            CompletingCommand::None | CompletingCommand::Err(_) => pending().await,
+            CompletingCommand::None | CompletingCommand::Err { .. } => pending().await,
         }
     }
 }
@@ -1642,7 +2093,9 @@ impl GlobalBarrierManagerContext {
         match &self.metadata_manager {
             MetadataManager::V1(mgr) => {
                 for table in mgr.catalog_manager.list_persisted_creating_tables().await {
-                    if table.table_type != TableType::MaterializedView as i32 {
+                    if table.table_type != TableType::MaterializedView as i32
+                        && table.table_type != TableType::Index as i32
+                    {
                         continue;
                     }
                     if let Entry::Vacant(e) = ddl_progress.entry(table.id) {
@@ -1674,6 +2127,55 @@ impl GlobalBarrierManagerContext {
 
         Ok(ddl_progress.into_values().collect())
     }
+
+    /// Returns the time-to-collect of the worker's recently collected barriers, oldest first.
+    pub async fn get_worker_barrier_latency(&self, worker_id: WorkerId) -> MetaResult<Vec<Duration>> {
+        let (tx, rx) = oneshot::channel();
+        self.request_tx
+            .send(BarrierManagerRequest::GetWorkerBarrierLatency(
+                worker_id, tx,
+            ))
+            .context("failed to send get worker barrier latency request")?;
+        rx.await
+            .context("failed to receive get worker barrier latency")
+            .map_err(Into::into)
+    }
+
+    /// Returns the trace ids of recently injected barriers, keyed by `curr_epoch`. Empty
+    /// unless distributed tracing is enabled.
+    pub async fn get_epoch_trace_ids(&self) -> MetaResult<HashMap<u64, String>> {
+        let (tx, rx) = oneshot::channel();
+        self.request_tx
+            .send(BarrierManagerRequest::GetEpochTraceIds(tx))
+            .context("failed to send get epoch trace ids request")?;
+        rx.await
+            .context("failed to receive get epoch trace ids")
+            .map_err(Into::into)
+    }
+
+    /// Returns a snapshot of the in-flight barriers, oldest first, for diagnosing stuck
+    /// checkpoints.
+    pub async fn list_inflight_barriers(&self) -> MetaResult<Vec<InflightBarrier>> {
+        let (tx, rx) = oneshot::channel();
+        self.request_tx
+            .send(BarrierManagerRequest::ListInflightBarriers(tx))
+            .context("failed to send list inflight barriers request")?;
+        rx.await
+            .context("failed to receive list inflight barriers")
+            .map_err(Into::into)
+    }
+
+    /// Serving `SHOW BACKPRESSURE`: the smoothed per-actor barrier collect latency
+    /// reported by compute nodes.
+    pub async fn list_actor_backpressure(&self) -> MetaResult<Vec<ActorBackpressure>> {
+        let (tx, rx) = oneshot::channel();
+        self.request_tx
+            .send(BarrierManagerRequest::ListActorBackpressure(tx))
+            .context("failed to send list actor backpressure request")?;
+        rx.await
+            .context("failed to receive list actor backpressure")
+            .map_err(Into::into)
+    }
 }
 
 pub type BarrierManagerRef = GlobalBarrierManagerContext;
@@ -1803,3 +2305,87 @@ fn collect_commit_epoch_info(
         is_visible_table_committed_epoch: true,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_commit_permit_chain_orders_commits() {
+        let mut chain = CommitPermitChain::default();
+
+        // The first completion of a pipeline has no predecessor to wait for.
+        let (first_permit, first_done) = chain.chain();
+        assert!(first_permit.is_none());
+        let (second_permit, _second_done) = chain.chain();
+
+        // The successor may be ready first, but must not commit before the
+        // predecessor has signalled its own commit.
+        let committed = Arc::new(AtomicBool::new(false));
+        let successor = tokio::spawn({
+            let committed = committed.clone();
+            async move {
+                wait_commit_permit(second_permit).await.unwrap();
+                committed.store(true, Ordering::Relaxed);
+            }
+        });
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!committed.load(Ordering::Relaxed));
+
+        let _ = first_done.send(());
+        successor.await.unwrap();
+        assert!(committed.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_commit_permit_chain_propagates_predecessor_failure() {
+        let mut chain = CommitPermitChain::default();
+        let (_, first_done) = chain.chain();
+        let (second_permit, _second_done) = chain.chain();
+
+        // A failed predecessor drops its sender without signalling; the successor
+        // must refuse to commit, leaving the cleanup to recovery.
+        drop(first_done);
+        wait_commit_permit(second_permit).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_commit_permit_chain_reset_starts_fresh() {
+        let mut chain = CommitPermitChain::default();
+        let (_, _done) = chain.chain();
+
+        // After the pipeline has drained or recovery has torn it down, the next
+        // spawned completion must not wait for a permit of the old pipeline.
+        chain.reset();
+        let (permit, _) = chain.chain();
+        assert!(permit.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_completion_pipelining_backpressure() {
+        // Global barrier completions are pipelined up to `MAX_COMPLETING_BARRIER_NUMS`.
+        assert!(has_completion_capacity(0));
+        assert!(has_completion_capacity(MAX_COMPLETING_BARRIER_NUMS - 1));
+        assert!(!has_completion_capacity(MAX_COMPLETING_BARRIER_NUMS));
+
+        // Creating job completions and the error state stay exclusive.
+        assert!(CompletingCommand::None.can_start_global_barrier());
+        let join_handle: JoinHandle<MetaResult<()>> = tokio::spawn(async { Ok(()) });
+        let creating = CompletingCommand::CreatingStreamingJob {
+            table_id: TableId::new(1),
+            epoch: 1,
+            join_handle,
+        };
+        assert!(!creating.can_start_global_barrier());
+        let err = CompletingCommand::Err {
+            err: anyhow!("injected").into(),
+            detached_barriers: VecDeque::new(),
+        };
+        assert!(!err.can_start_global_barrier());
+    }
+}
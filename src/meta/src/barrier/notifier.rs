@@ -72,4 +72,15 @@ impl Notifier {
             tx.send(Err(err.clone())).ok();
         }
     }
+
+    /// Notify when the scheduled barrier is cancelled before it ever gets injected, failing
+    /// both the started and the collected waiters. This function consumes `self`.
+    pub fn notify_cancelled(self, err: MetaError) {
+        if let Some(tx) = self.started {
+            tx.send(Err(err.clone())).ok();
+        }
+        if let Some(tx) = self.collected {
+            tx.send(Err(err)).ok();
+        }
+    }
 }
@@ -13,13 +13,13 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::mem::take;
 
 use risingwave_common::catalog::TableId;
 use risingwave_common::util::epoch::Epoch;
 use risingwave_meta_model_v2::ObjectId;
-use risingwave_pb::catalog::{CreateType, Table};
+use risingwave_pb::catalog::{CreateType, StreamJobPriority, Table};
 use risingwave_pb::ddl_service::DdlProgress;
 use risingwave_pb::hummock::HummockVersionStats;
 use risingwave_pb::stream_service::barrier_complete_response::CreateMviewProgress;
@@ -35,6 +35,12 @@ use crate::MetaResult;
 
 type ConsumedRows = u64;
 
+/// A backfill is considered "large" when the total key count of its upstream tables
+/// exceeds this threshold. Compaction on the compaction groups serving those tables is
+/// deprioritized until the backfill completes, so that it does not compete with the
+/// backfill reads.
+const LARGE_BACKFILL_UPSTREAM_KEY_COUNT: u64 = 10_000_000;
+
 #[derive(Clone, Copy, Debug)]
 enum BackfillState {
     Init,
@@ -61,6 +67,9 @@ pub(super) struct Progress {
     /// Consumed rows
     consumed_rows: u64,
 
+    /// Priority class of the creating job, defaulting to normal when unset.
+    priority: StreamJobPriority,
+
     /// DDL definition
     definition: String,
 }
@@ -71,6 +80,7 @@ impl Progress {
         actors: impl IntoIterator<Item = ActorId>,
         upstream_mv_count: HashMap<TableId, usize>,
         upstream_total_key_count: u64,
+        priority: StreamJobPriority,
         definition: String,
     ) -> Self {
         let states = actors
@@ -85,6 +95,7 @@ impl Progress {
             upstream_mv_count,
             upstream_total_key_count,
             consumed_rows: 0,
+            priority,
             definition,
         }
     }
@@ -132,6 +143,12 @@ impl Progress {
         self.done_count == self.states.len()
     }
 
+    /// Returns whether this backfill is large enough to deprioritize compaction for its
+    /// upstream tables.
+    fn is_large(&self) -> bool {
+        !self.is_done() && self.upstream_total_key_count >= LARGE_BACKFILL_UPSTREAM_KEY_COUNT
+    }
+
     /// Returns the ids of all actors containing the backfill executors for the mview tracked by this
     /// [`Progress`].
     fn actors(&self) -> impl Iterator<Item = ActorId> + '_ {
@@ -323,17 +340,19 @@ impl CreateMviewProgressTracker {
                 states.insert(actor, BackfillState::ConsumingUpstream(Epoch(0), 0));
             }
 
+            let streaming_job = StreamingJob::MaterializedView(mview);
             let progress = Self::recover_progress(
                 states,
                 table_fragments.dependent_table_ids(),
-                mview.definition.clone(),
+                streaming_job.priority(),
+                streaming_job.definition(),
                 &version_stats,
             );
             let tracking_job = TrackingJob::RecoveredV1(RecoveredTrackingJobV1 {
                 fragments: table_fragments,
                 metadata_manager: metadata_manager.clone(),
                 internal_tables,
-                streaming_job: StreamingJob::MaterializedView(mview),
+                streaming_job,
             });
             progress_map.insert(creating_table_id, (progress, tracking_job));
         }
@@ -345,13 +364,13 @@ impl CreateMviewProgressTracker {
     }
 
     pub fn recover_v2(
-        mview_map: HashMap<TableId, (String, TableFragments)>,
+        mview_map: HashMap<TableId, (String, StreamJobPriority, TableFragments)>,
         version_stats: HummockVersionStats,
         metadata_manager: MetadataManagerV2,
     ) -> Self {
         let mut actor_map = HashMap::new();
         let mut progress_map = HashMap::new();
-        for (creating_table_id, (definition, table_fragments)) in mview_map {
+        for (creating_table_id, (definition, priority, table_fragments)) in mview_map {
             let mut states = HashMap::new();
             let actors = table_fragments.backfill_actor_ids();
             for actor in actors {
@@ -362,6 +381,7 @@ impl CreateMviewProgressTracker {
             let progress = Self::recover_progress(
                 states,
                 table_fragments.dependent_table_ids(),
+                priority,
                 definition,
                 &version_stats,
             );
@@ -381,6 +401,7 @@ impl CreateMviewProgressTracker {
     fn recover_progress(
         states: HashMap<ActorId, BackfillState>,
         upstream_mv_count: HashMap<TableId, usize>,
+        priority: StreamJobPriority,
         definition: String,
         version_stats: &HummockVersionStats,
     ) -> Progress {
@@ -400,10 +421,67 @@ impl CreateMviewProgressTracker {
             upstream_mv_count,
             upstream_total_key_count,
             consumed_rows: 0, // Fill only after first barrier pass
+            priority,
             definition,
         }
     }
 
+    /// Returns the upstream table ids of all tracked backfills that are still in flight
+    /// and large enough to deprioritize compaction for. The hummock manager keeps the
+    /// compaction groups serving these tables at low compaction priority, and restores
+    /// them once the backfills complete or are cancelled.
+    pub(super) fn large_backfill_upstream_table_ids(&self) -> HashSet<u32> {
+        self.progress_map
+            .values()
+            .filter(|(progress, _)| progress.is_large())
+            .flat_map(|(progress, _)| {
+                progress
+                    .upstream_mv_count
+                    .keys()
+                    .map(|table_id| table_id.table_id)
+            })
+            .collect()
+    }
+
+    /// Returns the upstream table ids of all tracked in-flight backfills of high-priority
+    /// jobs. The hummock manager gives the compaction groups serving these tables
+    /// compactor slots first, so that the backfills read from well-compacted levels.
+    pub(super) fn high_priority_backfill_upstream_table_ids(&self) -> HashSet<u32> {
+        self.progress_map
+            .values()
+            .filter(|(progress, _)| {
+                !progress.is_done() && progress.priority == StreamJobPriority::High
+            })
+            .flat_map(|(progress, _)| {
+                progress
+                    .upstream_mv_count
+                    .keys()
+                    .map(|table_id| table_id.table_id)
+            })
+            .collect()
+    }
+
+    /// Returns the creating job whose backfill should be auto-paused next under sustained
+    /// backpressure: low-priority jobs are picked before normal ones, and high-priority
+    /// jobs are never auto-paused. `excluded` contains the jobs that are already paused.
+    pub(super) fn next_backfill_to_auto_pause(
+        &self,
+        excluded: &HashSet<TableId>,
+    ) -> Option<TableId> {
+        self.progress_map
+            .iter()
+            .filter(|(table_id, (progress, _))| {
+                !excluded.contains(table_id)
+                    && !progress.is_done()
+                    && progress.priority != StreamJobPriority::High
+            })
+            .min_by_key(|(_, (progress, _))| match progress.priority {
+                StreamJobPriority::Low => 0,
+                _ => 1,
+            })
+            .map(|(table_id, _)| *table_id)
+    }
+
     pub fn gen_ddl_progress(&self) -> HashMap<u32, DdlProgress> {
         self.progress_map
             .iter()
@@ -569,6 +647,7 @@ impl CreateMviewProgressTracker {
             actors,
             upstream_mv_count,
             upstream_total_key_count,
+            info.streaming_job.priority(),
             definition.clone(),
         );
         if *ddl_type == DdlType::Sink && *create_type == CreateType::Background {
@@ -22,6 +22,7 @@ use risingwave_common::config::DefaultParallelism;
 use risingwave_common::hash::WorkerSlotId;
 use risingwave_common::util::epoch::Epoch;
 use risingwave_meta_model_v2::StreamingParallelism;
+use risingwave_pb::catalog::StreamJobPriority;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::table_fragments::State;
 use risingwave_pb::meta::{PausedReason, Recovery};
@@ -173,7 +174,13 @@ impl GlobalBarrierManagerContext {
                 .get_job_fragments_by_id(mview.table_id)
                 .await?;
             let table_fragments = TableFragments::from_protobuf(table_fragments);
-            mview_map.insert(table_id, (mview.definition.clone(), table_fragments));
+            let priority = mview
+                .priority
+                .map_or(StreamJobPriority::Normal, Into::into);
+            mview_map.insert(
+                table_id,
+                (mview.definition.clone(), priority, table_fragments),
+            );
         }
 
         let version_stats = self.hummock_manager.get_version_stats().await;
@@ -227,6 +234,9 @@ impl GlobalBarrierManager {
         // Mark blocked and abort buffered schedules, they might be dirty already.
         self.scheduled_barriers
             .abort_and_mark_blocked("cluster is under recovering");
+        // Recovery rebuilds the actors with the rate limits persisted in the catalog,
+        // implicitly resuming any auto-paused backfill.
+        self.auto_paused_backfills.clear();
         // Clear all control streams to release resources (connections to compute nodes) first.
         self.control_stream_manager.clear();
 
@@ -12,9 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::error::Error;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::anyhow;
 use fail::fail_point;
@@ -49,6 +49,10 @@ use crate::{MetaError, MetaResult};
 
 const COLLECT_ERROR_TIMEOUT: Duration = Duration::from_secs(3);
 
+/// Max number of barrier collection latency records kept per worker.
+const WORKER_BARRIER_LATENCY_HISTORY_SIZE: usize = 256;
+const EPOCH_TRACE_ID_HISTORY_SIZE: usize = 256;
+
 struct ControlStreamNode {
     worker: WorkerNode,
     sender: UnboundedSender<StreamingControlStreamRequest>,
@@ -100,6 +104,13 @@ pub(super) struct ControlStreamManager {
     context: GlobalBarrierManagerContext,
     nodes: HashMap<WorkerId, ControlStreamNode>,
     response_streams: FuturesUnordered<ResponseStreamFuture>,
+    /// Injection time of in-flight barriers, keyed by worker and `(partial_graph_id, prev_epoch)`.
+    inflight_barriers: HashMap<WorkerId, HashMap<(u32, u64), Instant>>,
+    /// Bounded history of per-worker time-to-collect, most recent at the back.
+    latency_history: HashMap<WorkerId, VecDeque<Duration>>,
+    /// Bounded history of trace ids of recently injected barriers, keyed by `curr_epoch`,
+    /// most recent at the back. Empty unless distributed tracing is enabled.
+    epoch_trace_ids: VecDeque<(u64, String)>,
 }
 
 impl ControlStreamManager {
@@ -108,6 +119,9 @@ impl ControlStreamManager {
             context,
             nodes: Default::default(),
             response_streams: FuturesUnordered::new(),
+            inflight_barriers: Default::default(),
+            latency_history: Default::default(),
+            epoch_trace_ids: Default::default(),
         }
     }
 
@@ -212,6 +226,56 @@ impl ControlStreamManager {
         Some((worker_id, result))
     }
 
+    /// Records the time-to-collect of a collected barrier into the bounded history.
+    fn record_collected(&mut self, worker_id: WorkerId, partial_graph_id: u32, prev_epoch: u64) {
+        let Some(injected_at) = self
+            .inflight_barriers
+            .get_mut(&worker_id)
+            .and_then(|inflight| inflight.remove(&(partial_graph_id, prev_epoch)))
+        else {
+            return;
+        };
+        let history = self.latency_history.entry(worker_id).or_default();
+        if history.len() >= WORKER_BARRIER_LATENCY_HISTORY_SIZE {
+            history.pop_front();
+        }
+        history.push_back(injected_at.elapsed());
+    }
+
+    /// Returns the recently collected barrier latencies of the worker, oldest first.
+    pub(super) fn worker_barrier_latency(&self, worker_id: WorkerId) -> Vec<Duration> {
+        self.latency_history
+            .get(&worker_id)
+            .map(|history| history.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Records the trace id of an injected barrier into the bounded history. No-op if
+    /// distributed tracing is disabled, or if the epoch has already been recorded
+    /// (e.g. injected again for another partial graph).
+    fn record_injected_trace_id(&mut self, curr_epoch: u64, tracing_context: &TracingContext) {
+        let Some(trace_id) = tracing_context.trace_id() else {
+            return;
+        };
+        if self
+            .epoch_trace_ids
+            .back()
+            .map(|(epoch, _)| *epoch == curr_epoch)
+            .unwrap_or(false)
+        {
+            return;
+        }
+        if self.epoch_trace_ids.len() >= EPOCH_TRACE_ID_HISTORY_SIZE {
+            self.epoch_trace_ids.pop_front();
+        }
+        self.epoch_trace_ids.push_back((curr_epoch, trace_id));
+    }
+
+    /// Returns the trace ids of recently injected barriers, keyed by `curr_epoch`.
+    pub(super) fn epoch_trace_ids(&self) -> HashMap<u64, String> {
+        self.epoch_trace_ids.iter().cloned().collect()
+    }
+
     pub(super) async fn next_complete_barrier_response(
         &mut self,
     ) -> (WorkerId, MetaResult<BarrierCompleteResponse>) {
@@ -223,6 +287,7 @@ impl ControlStreamManager {
                 Ok(resp) => match resp.response.unwrap() {
                     Response::CompleteBarrier(resp) => {
                         assert_eq!(worker_id, resp.worker_id);
+                        self.record_collected(worker_id, resp.partial_graph_id, resp.epoch);
                         Ok(resp)
                     }
                     Response::Shutdown(_) => {
@@ -240,6 +305,7 @@ impl ControlStreamManager {
                     .nodes
                     .remove(&worker_id)
                     .expect("should exist when get shutdown resp");
+                self.inflight_barriers.remove(&worker_id);
                 warn!(node = ?node.worker, err = %err.as_report(), "get error from response stream");
             }
             (worker_id, result)
@@ -335,9 +401,19 @@ impl ControlStreamManager {
             if !self.nodes.contains_key(&worker_id) {
                 return Err(anyhow!("unconnected worker node {}", worker_id).into());
             }
+            // Consult the heartbeat-derived liveness map so that actors on a known-dead worker
+            // short-circuit into recovery instead of waiting for barrier collection to fail.
+            if self.context.env.actor_liveness().is_expired(worker_id) {
+                return Err(anyhow!(
+                    "worker node {} is known dead by heartbeat, its actors cannot collect the barrier",
+                    worker_id
+                )
+                .into());
+            }
         }
 
         let mut node_need_collect = HashSet::new();
+        let mut failed_workers = Vec::new();
         let new_actors_location_to_broadcast = new_actors
             .iter()
             .flatten()
@@ -355,83 +431,112 @@ impl ControlStreamManager {
             })
             .collect_vec();
 
-        self.nodes
-            .iter_mut()
-            .try_for_each(|(node_id, node)| {
-                let actor_ids_to_collect: Vec<_> = pre_applied_graph_info
-                    .actor_ids_to_collect(*node_id)
-                    .collect();
-                let table_ids_to_sync = if let Some(graph_info) = applied_graph_info {
-                    graph_info
-                        .existing_table_ids()
-                        .map(|table_id| table_id.table_id)
-                        .collect()
-                } else {
-                    Default::default()
-                };
-
-                {
-                    let mutation = mutation.clone();
-                    let barrier = Barrier {
-                        epoch: Some(risingwave_pb::data::Epoch {
-                            curr: curr_epoch.value().0,
-                            prev: prev_epoch.value().0,
-                        }),
-                        mutation: mutation.clone().map(|_| BarrierMutation { mutation }),
-                        tracing_context: TracingContext::from_span(curr_epoch.span()).to_protobuf(),
-                        kind: kind.to_protobuf() as i32,
-                        passed_actors: vec![],
-                    };
-
-                    node.sender
-                        .send(StreamingControlStreamRequest {
-                            request: Some(
-                                streaming_control_stream_request::Request::InjectBarrier(
-                                    InjectBarrierRequest {
-                                        request_id: Uuid::new_v4().to_string(),
-                                        barrier: Some(barrier),
-                                        actor_ids_to_collect,
-                                        table_ids_to_sync,
-                                        partial_graph_id,
-                                        broadcast_info: new_actors_location_to_broadcast.clone(),
-                                        actors_to_build: new_actors
-                                            .as_mut()
-                                            .map(|new_actors| new_actors.remove(node_id))
-                                            .into_iter()
-                                            .flatten()
-                                            .flatten()
-                                            .collect(),
-                                        subscriptions_to_add: subscriptions_to_add.clone(),
-                                        subscriptions_to_remove: subscriptions_to_remove.clone(),
-                                    },
-                                ),
-                            ),
-                        })
-                        .map_err(|_| {
-                            MetaError::from(anyhow!(
-                                "failed to send request to {} {:?}",
-                                node.worker.id,
-                                node.worker.host
-                            ))
-                        })?;
+        let tracing_context = TracingContext::from_span(curr_epoch.span());
+        self.record_injected_trace_id(curr_epoch.value().0, &tracing_context);
+        let tracing_context = tracing_context.to_protobuf();
+
+        for (node_id, node) in &mut self.nodes {
+            let actor_ids_to_collect: Vec<_> = pre_applied_graph_info
+                .actor_ids_to_collect(*node_id)
+                .collect();
+            let table_ids_to_sync = if let Some(graph_info) = applied_graph_info {
+                graph_info
+                    .existing_table_ids()
+                    .map(|table_id| table_id.table_id)
+                    .collect()
+            } else {
+                Default::default()
+            };
+            let actors_to_build: Vec<_> = new_actors
+                .as_mut()
+                .map(|new_actors| new_actors.remove(node_id))
+                .into_iter()
+                .flatten()
+                .flatten()
+                .collect();
+            // The worker is required for this barrier iff it hosts actors to collect
+            // from or to build, or still holds in-flight barriers from previous rounds.
+            let required = !actor_ids_to_collect.is_empty()
+                || !actors_to_build.is_empty()
+                || self
+                    .inflight_barriers
+                    .get(node_id)
+                    .is_some_and(|inflight| !inflight.is_empty());
+
+            let mutation = mutation.clone();
+            let barrier = Barrier {
+                epoch: Some(risingwave_pb::data::Epoch {
+                    curr: curr_epoch.value().0,
+                    prev: prev_epoch.value().0,
+                }),
+                mutation: mutation.clone().map(|_| BarrierMutation { mutation }),
+                tracing_context: tracing_context.clone(),
+                kind: kind.to_protobuf() as i32,
+                passed_actors: vec![],
+            };
 
+            let result = node.sender.send(StreamingControlStreamRequest {
+                request: Some(streaming_control_stream_request::Request::InjectBarrier(
+                    InjectBarrierRequest {
+                        request_id: Uuid::new_v4().to_string(),
+                        barrier: Some(barrier),
+                        actor_ids_to_collect,
+                        table_ids_to_sync,
+                        partial_graph_id,
+                        broadcast_info: new_actors_location_to_broadcast.clone(),
+                        actors_to_build,
+                        subscriptions_to_add: subscriptions_to_add.clone(),
+                        subscriptions_to_remove: subscriptions_to_remove.clone(),
+                    },
+                )),
+            });
+
+            match result {
+                Ok(()) => {
                     node_need_collect.insert(*node_id);
-                    Result::<_, MetaError>::Ok(())
+                    self.inflight_barriers
+                        .entry(*node_id)
+                        .or_default()
+                        .insert((partial_graph_id, prev_epoch.value().0), Instant::now());
                 }
-            })
-            .inspect_err(|e| {
-                // Record failure in event log.
-                use risingwave_pb::meta::event_log;
-                let event = event_log::EventInjectBarrierFail {
-                    prev_epoch: prev_epoch.value().0,
-                    cur_epoch: curr_epoch.value().0,
-                    error: e.to_report_string(),
-                };
-                self.context
-                    .env
-                    .event_log_manager_ref()
-                    .add_event_logs(vec![event_log::Event::InjectBarrierFail(event)]);
-            })?;
+                Err(_) => {
+                    let err = MetaError::from(anyhow!(
+                        "failed to send request to {} {:?}",
+                        node.worker.id,
+                        node.worker.host
+                    ));
+                    if required {
+                        failed_workers.push((*node_id, err));
+                    } else {
+                        // The worker hosts nothing of this barrier, so a broken control
+                        // stream need not fail the round. The error will also surface
+                        // on the response stream, where the node is removed and
+                        // reconnected with backoff.
+                        warn!(
+                            node = ?node.worker,
+                            err = %err.as_report(),
+                            "skip injecting barrier to unneeded worker"
+                        );
+                    }
+                }
+            }
+        }
+
+        if !failed_workers.is_empty() {
+            let err = merge_node_rpc_errors("failed to inject barrier", failed_workers);
+            // Record failure in event log.
+            use risingwave_pb::meta::event_log;
+            let event = event_log::EventInjectBarrierFail {
+                prev_epoch: prev_epoch.value().0,
+                cur_epoch: curr_epoch.value().0,
+                error: err.to_report_string(),
+            };
+            self.context
+                .env
+                .event_log_manager_ref()
+                .add_event_logs(vec![event_log::Event::InjectBarrierFail(event)]);
+            return Err(err);
+        }
         Ok(node_need_collect)
     }
 
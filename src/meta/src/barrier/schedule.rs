@@ -14,6 +14,7 @@
 
 use std::collections::{HashSet, VecDeque};
 use std::iter::once;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -44,6 +45,9 @@ struct Inner {
     /// When `queue` is not empty anymore, all subscribers of this watcher will be notified.
     changed_tx: watch::Sender<()>,
 
+    /// The id to assign to the next scheduled command, for inspection and cancellation.
+    next_scheduled_id: AtomicU64,
+
     /// Used for recording send latency of each barrier.
     metrics: Arc<MetaMetrics>,
 }
@@ -116,6 +120,7 @@ impl Inner {
         };
 
         Scheduled {
+            id: self.next_scheduled_id.fetch_add(1, Ordering::Relaxed),
             command,
             notifiers: notifiers.into_iter().collect(),
             send_latency_timer: self.metrics.barrier_send_latency.start_timer(),
@@ -125,6 +130,18 @@ impl Inner {
     }
 }
 
+/// A snapshot of one command waiting in the barrier scheduling queue, returned by
+/// [`BarrierScheduler::list_scheduled_commands`].
+#[derive(Debug, Clone)]
+pub struct ScheduledCommandInfo {
+    /// The identifier to pass to [`BarrierScheduler::cancel_scheduled_command`].
+    pub id: u64,
+    /// The variant name of the command.
+    pub command: String,
+    /// Whether the command requires a checkpoint barrier.
+    pub checkpoint: bool,
+}
+
 /// The sender side of the barrier scheduling queue.
 /// Can be cloned and held by other managers to schedule and run barriers.
 #[derive(Clone)]
@@ -142,14 +159,18 @@ impl BarrierScheduler {
         hummock_manager: HummockManagerRef,
         metrics: Arc<MetaMetrics>,
         checkpoint_frequency: usize,
+        adaptive_checkpoint_frequency_bounds: (usize, usize),
+        backpressure_max_stretch: u32,
     ) -> (Self, ScheduledBarriers) {
         tracing::info!(
             "Starting barrier scheduler with: checkpoint_frequency={:?}",
             checkpoint_frequency,
         );
+        let (adaptive_min, adaptive_max) = adaptive_checkpoint_frequency_bounds;
         let inner = Arc::new(Inner {
             queue: Mutex::new(ScheduledQueue::new()),
             changed_tx: watch::channel(()).0,
+            next_scheduled_id: AtomicU64::new(1),
             metrics,
         });
 
@@ -162,6 +183,13 @@ impl BarrierScheduler {
                 num_uncheckpointed_barrier: 0,
                 force_checkpoint: false,
                 checkpoint_frequency,
+                adaptive_checkpoint_frequency: AdaptiveCheckpointFrequency::new(
+                    adaptive_min,
+                    adaptive_max,
+                ),
+                backpressure_throttle: BackpressureThrottle::new(backpressure_max_stretch),
+                backpressure_stretch: 1,
+                base_min_interval: None,
                 inner,
                 min_interval: None,
             },
@@ -200,6 +228,48 @@ impl BarrierScheduler {
         }
     }
 
+    /// List the commands currently waiting in the scheduling queue, front first. Note that a
+    /// listed command may be injected at any time, so a subsequent
+    /// [`Self::cancel_scheduled_command`] with its id can still fail.
+    pub fn list_scheduled_commands(&self) -> Vec<ScheduledCommandInfo> {
+        let queue = self.inner.queue.lock();
+        queue
+            .queue
+            .iter()
+            .map(|scheduled| ScheduledCommandInfo {
+                id: scheduled.id,
+                command: scheduled.command.to_string(),
+                checkpoint: scheduled.checkpoint,
+            })
+            .collect()
+    }
+
+    /// Cancel a command that is still waiting in the scheduling queue, e.g. when a user
+    /// realizes a mistaken DDL right after submitting it. All notifiers of the command are
+    /// failed with a `Cancelled` error, so the session that scheduled it sees its statement
+    /// fail. Returns `false` if the command is no longer queued, i.e. it has already been
+    /// injected or finished.
+    pub fn cancel_scheduled_command(&self, id: u64) -> bool {
+        let mut queue = self.inner.queue.lock();
+        let Some(idx) = queue
+            .queue
+            .iter()
+            .position(|scheduled| scheduled.id == id)
+        else {
+            return false;
+        };
+        let Scheduled {
+            command, notifiers, ..
+        } = queue.queue.remove(idx).unwrap();
+        let err = MetaError::cancelled(format!(
+            "scheduled command {command} is cancelled before injection"
+        ));
+        notifiers
+            .into_iter()
+            .for_each(|notifier| notifier.notify_cancelled(err.clone()));
+        true
+    }
+
     /// Attach `new_notifiers` to the very first scheduled barrier. If there's no one scheduled, a
     /// default barrier will be created. If `new_checkpoint` is true, the barrier will become a
     /// checkpoint.
@@ -339,33 +409,213 @@ impl BarrierScheduler {
     }
 }
 
+/// Feedback controller that adapts the checkpoint frequency to the observed barrier
+/// latency: under load the distance between checkpoints is increased to amortize the
+/// commit cost, and it is decreased again when the cluster catches up. Disabled unless
+/// the upper bound is non-zero.
+struct AdaptiveCheckpointFrequency {
+    min: usize,
+    max: usize,
+    /// Exponential moving average of the observed barrier latency.
+    avg_latency: Option<Duration>,
+}
+
+impl AdaptiveCheckpointFrequency {
+    fn new(min: usize, max: usize) -> Self {
+        Self {
+            min,
+            max,
+            avg_latency: None,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.max > 0
+    }
+
+    fn set_bounds(&mut self, min: usize, max: usize) {
+        self.min = min;
+        self.max = max;
+    }
+
+    /// Feed an observed barrier latency and return the adjusted checkpoint frequency,
+    /// if it should change.
+    fn observe(&mut self, current: usize, latency: Duration, target: Duration) -> Option<usize> {
+        let avg = match self.avg_latency {
+            Some(prev) => (prev * 3 + latency) / 4,
+            None => latency,
+        };
+        self.avg_latency = Some(avg);
+
+        let adjusted = if avg > target * 2 {
+            // Overloaded: back off aggressively.
+            current.saturating_mul(2)
+        } else if avg < target {
+            // Caught up: converge back slowly.
+            current.saturating_sub(1)
+        } else {
+            current
+        }
+        .clamp(self.min.max(1), self.max);
+        (adjusted != current).then_some(adjusted)
+    }
+}
+
+/// Feedback controller that stretches the barrier `min_interval` when the per-actor
+/// barrier collect latency reported by compute nodes shows that downstream is saturated,
+/// so that barriers slow down at the source instead of piling up in flight. Disabled
+/// unless the maximum stretch factor is greater than 1.
+struct BackpressureThrottle {
+    max_stretch: u32,
+    /// Exponential moving average of the reported collect latency.
+    avg_latency: Option<Duration>,
+}
+
+impl BackpressureThrottle {
+    fn new(max_stretch: u32) -> Self {
+        Self {
+            max_stretch,
+            avg_latency: None,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.max_stretch > 1
+    }
+
+    fn set_max_stretch(&mut self, max_stretch: u32) {
+        self.max_stretch = max_stretch;
+    }
+
+    /// Feed an observed collect latency and return the adjusted stretch factor, if it
+    /// should change. `target` is the unstretched barrier interval.
+    fn observe(&mut self, current: u32, latency: Duration, target: Duration) -> Option<u32> {
+        let avg = match self.avg_latency {
+            Some(prev) => (prev * 3 + latency) / 4,
+            None => latency,
+        };
+        self.avg_latency = Some(avg);
+
+        let adjusted = if avg > target * 2 {
+            // Saturated: slow down the barrier pace.
+            current.saturating_mul(2)
+        } else if avg < target {
+            // Caught up: converge back.
+            current / 2
+        } else {
+            current
+        }
+        .clamp(1, self.max_stretch.max(1));
+        (adjusted != current).then_some(adjusted)
+    }
+}
+
 /// The receiver side of the barrier scheduling queue.
 /// Held by the [`super::GlobalBarrierManager`] to execute these commands.
 pub struct ScheduledBarriers {
     min_interval: Option<Interval>,
 
+    /// The configured barrier interval, before backpressure stretching is applied.
+    base_min_interval: Option<Duration>,
+
     /// Force checkpoint in next barrier.
     force_checkpoint: bool,
 
     /// The numbers of barrier (checkpoint = false) since the last barrier (checkpoint = true)
     num_uncheckpointed_barrier: usize,
     checkpoint_frequency: usize,
+    adaptive_checkpoint_frequency: AdaptiveCheckpointFrequency,
+    backpressure_throttle: BackpressureThrottle,
+    /// Current stretch factor applied to `base_min_interval` under backpressure.
+    backpressure_stretch: u32,
     inner: Arc<Inner>,
 }
 
 impl ScheduledBarriers {
     pub(super) fn set_min_interval(&mut self, min_interval: Duration) {
+        self.base_min_interval = Some(min_interval);
+        self.apply_min_interval();
+    }
+
+    /// (Re)build the interval timer from the base interval and the current backpressure
+    /// stretch factor, if the effective period changed.
+    fn apply_min_interval(&mut self) {
+        let effective = self.base_min_interval.expect("should have set min interval")
+            * self.backpressure_stretch;
         let set_new_interval = match &self.min_interval {
             None => true,
-            Some(prev_min_interval) => min_interval != prev_min_interval.period(),
+            Some(prev_min_interval) => effective != prev_min_interval.period(),
         };
         if set_new_interval {
-            let mut min_interval = tokio::time::interval(min_interval);
+            let mut min_interval = tokio::time::interval(effective);
             min_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
             self.min_interval = Some(min_interval);
         }
     }
 
+    /// Update the maximum backpressure stretch factor. Resets the stretch when the
+    /// throttle is disabled.
+    pub(super) fn set_backpressure_max_stretch(&mut self, max_stretch: u32) {
+        self.backpressure_throttle.set_max_stretch(max_stretch);
+        if !self.backpressure_throttle.is_enabled() && self.backpressure_stretch != 1 {
+            self.backpressure_stretch = 1;
+            self.apply_min_interval();
+        }
+    }
+
+    /// Feed the per-actor barrier collect latency reported by a compute node into the
+    /// backpressure throttle, stretching the barrier interval when downstream is
+    /// saturated. No-op when the throttle is disabled.
+    pub(super) fn on_actor_backpressure(&mut self, collect_latency: Duration) {
+        if !self.backpressure_throttle.is_enabled() {
+            return;
+        }
+        let Some(base) = self.base_min_interval else {
+            return;
+        };
+        if let Some(stretch) =
+            self.backpressure_throttle
+                .observe(self.backpressure_stretch, collect_latency, base)
+        {
+            tracing::info!(
+                stretch,
+                ?collect_latency,
+                "adjust barrier interval stretch on actor backpressure"
+            );
+            self.backpressure_stretch = stretch;
+            self.apply_min_interval();
+        }
+    }
+
+    /// Whether the backpressure throttle has stretched the barrier interval to its
+    /// configured maximum, i.e. slowing down barriers alone no longer relieves the
+    /// saturation.
+    pub(super) fn is_backpressure_saturated(&self) -> bool {
+        self.backpressure_throttle.is_enabled()
+            && self.backpressure_stretch >= self.backpressure_throttle.max_stretch
+    }
+
+    /// Whether the backpressure throttle is currently stretching the barrier interval.
+    pub(super) fn is_backpressure_stretched(&self) -> bool {
+        self.backpressure_stretch > 1
+    }
+
+    /// Push a command into the queue without waiting for its completion. Used for
+    /// commands issued from within the barrier manager itself, where waiting like
+    /// [`BarrierScheduler::run_command`] does would deadlock the barrier loop.
+    pub(super) fn push_standalone_command(&self, command: Command) -> MetaResult<()> {
+        let mut queue = self.inner.queue.lock();
+        queue.push_back(self.inner.new_scheduled(
+            command.need_checkpoint(),
+            command,
+            std::iter::empty(),
+        ))?;
+        if queue.len() == 1 {
+            self.inner.changed_tx.send(()).ok();
+        }
+        Ok(())
+    }
+
     pub(super) async fn next_barrier(&mut self) -> Scheduled {
         let checkpoint = self.try_get_checkpoint();
         let scheduled = select! {
@@ -466,6 +716,35 @@ impl ScheduledBarriers {
         self.checkpoint_frequency = frequency;
     }
 
+    /// Update the bounds of the adaptive checkpoint frequency.
+    pub(super) fn set_adaptive_checkpoint_frequency_bounds(&mut self, min: usize, max: usize) {
+        self.adaptive_checkpoint_frequency.set_bounds(min, max);
+    }
+
+    /// Feed the latency of a completed barrier into the adaptive checkpoint frequency
+    /// controller, taking the barrier interval as the latency target. No-op when the
+    /// controller is disabled.
+    pub(super) fn on_barrier_latency(&mut self, latency: Duration) {
+        if !self.adaptive_checkpoint_frequency.is_enabled() {
+            return;
+        }
+        let Some(min_interval) = &self.min_interval else {
+            return;
+        };
+        if let Some(frequency) = self.adaptive_checkpoint_frequency.observe(
+            self.checkpoint_frequency,
+            latency,
+            min_interval.period(),
+        ) {
+            tracing::info!(
+                frequency,
+                ?latency,
+                "adjust checkpoint frequency on barrier latency"
+            );
+            self.checkpoint_frequency = frequency;
+        }
+    }
+
     /// Update the `num_uncheckpointed_barrier`
     fn update_num_uncheckpointed_barrier(&mut self, checkpoint: bool) {
         if checkpoint {
@@ -21,6 +21,7 @@ use anyhow::anyhow;
 use itertools::Itertools;
 use risingwave_common::catalog::{TableOption, DEFAULT_SCHEMA_NAME, SYSTEM_SCHEMAS};
 use risingwave_common::secret::LocalSecretManager;
+use risingwave_common::util::epoch::Epoch;
 use risingwave_common::util::stream_graph_visitor::visit_stream_node_cont_mut;
 use risingwave_common::{bail, current_cluster_version};
 use risingwave_connector::source::cdc::build_cdc_table_id;
@@ -31,24 +32,36 @@ use risingwave_meta_model_v2::table::TableType;
 use risingwave_meta_model_v2::{
     actor, connection, database, fragment, function, index, object, object_dependency, schema,
     secret, sink, source, streaming_job, subscription, table, user_privilege, view, ActorId,
-    ActorUpstreamActors, ColumnCatalogArray, ConnectionId, CreateType, DatabaseId, FragmentId,
+    ActorUpstreamActors, ColumnCatalogArray, ConnectionId, CreateType, DataTypeArray, DatabaseId,
+    FragmentId,
     FunctionId, I32Array, IndexId, JobStatus, ObjectId, PrivateLinkService, Property, SchemaId,
-    SecretId, SinkId, SourceId, StreamNode, StreamSourceInfo, StreamingParallelism, SubscriptionId,
-    TableId, UserId, ViewId,
+    SecretId, SinkId, SourceId, StorageClassPolicy, StreamNode, StreamSourceInfo,
+    StreamingParallelism, SubscriptionId, TableId, UserId, ViewId,
 };
-use risingwave_pb::catalog::subscription::SubscriptionState;
-use risingwave_pb::catalog::table::PbTableType;
+use risingwave_pb::catalog::comment::PbObjectType as PbCommentObjectType;
+use risingwave_pb::catalog::label::PbJob as LabelJob;
+use risingwave_common::system_param::reader::SystemParamsRead;
+use risingwave_pb::catalog::subscription::{PbRetentionPolicy, SubscriptionState};
+use risingwave_pb::catalog::table::{PbSchemaChangePolicy, PbTableType};
 use risingwave_pb::catalog::{
-    PbComment, PbConnection, PbDatabase, PbFunction, PbIndex, PbSchema, PbSecret, PbSink, PbSource,
-    PbStreamJobStatus, PbSubscription, PbTable, PbView,
+    PbAnnotation, PbComment, PbConnection, PbDatabase, PbFunction, PbIndex, PbLabel, PbSchema,
+    PbSecret,
+    PbSink, PbSource, PbStorageClassPolicy, PbStreamJobPriority, PbStreamJobStatus, PbSubscription,
+    PbTable, PbView,
 };
 use risingwave_pb::meta::cancel_creating_jobs_request::PbCreatingJobInfo;
+use risingwave_pb::meta::get_dependency_graph_response::{
+    PbDependencyEdge, PbDependencyKind, PbDependencyNode,
+};
 use risingwave_pb::meta::list_object_dependencies_response::PbObjectDependencies;
 use risingwave_pb::meta::relation::PbRelationInfo;
 use risingwave_pb::meta::subscribe_response::{
     Info as NotificationInfo, Info, Operation as NotificationOperation, Operation,
 };
-use risingwave_pb::meta::{PbFragmentWorkerSlotMapping, PbRelation, PbRelationGroup};
+use risingwave_pb::meta::{
+    PbFragmentWorkerSlotMapping, PbGetDependencyGraphResponse, PbRelation, PbRelationGroup,
+};
+use risingwave_pb::plan_common::PbColumnCatalog;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::FragmentTypeFlag;
 use risingwave_pb::user::PbUserInfo;
@@ -74,7 +87,11 @@ use crate::controller::utils::{
     resolve_source_register_info_for_jobs, PartialObject,
 };
 use crate::controller::ObjectModel;
-use crate::manager::{Catalog, MetaSrvEnv, NotificationVersion, IGNORED_NOTIFICATION_VERSION};
+use crate::manager::{
+    effective_subscription_retention_seconds, Catalog, DdlBatchOperation, LocalNotification,
+    MetaSrvEnv, NotificationVersion, IGNORED_NOTIFICATION_VERSION, MAX_ANNOTATIONS_PER_OBJECT,
+    MAX_LABELS_PER_OBJECT,
+};
 use crate::rpc::ddl_controller::DropMode;
 use crate::stream::SourceManagerRef;
 use crate::telemetry::MetaTelemetryJobDesc;
@@ -201,6 +218,11 @@ impl CatalogController {
     pub async fn get_inner_write_guard(&self) -> RwLockWriteGuard<'_, CatalogControllerInner> {
         self.inner.write().await
     }
+
+    /// Returns the number of active DDL waiters per creating job.
+    pub async fn count_ddl_waiters(&self) -> HashMap<ObjectId, usize> {
+        self.inner.read().await.count_ddl_waiters()
+    }
 }
 
 pub struct CatalogControllerInner {
@@ -620,6 +642,8 @@ impl CatalogController {
         Ok(())
     }
 
+    /// Lists the backing tables of materialized views and indexes that are being
+    /// created in the background.
     pub async fn list_background_creating_mviews(
         &self,
         include_initial: bool,
@@ -635,7 +659,7 @@ impl CatalogController {
             .join(JoinType::LeftJoin, object::Relation::StreamingJob.def())
             .filter(
                 table::Column::TableType
-                    .eq(TableType::MaterializedView)
+                    .is_in([TableType::MaterializedView, TableType::Index])
                     .and(
                         streaming_job::Column::CreateType
                             .eq(CreateType::Background)
@@ -647,6 +671,24 @@ impl CatalogController {
         Ok(tables)
     }
 
+    /// Lists the backing tables of indexes whose creation is still in progress, both
+    /// foreground and background. Serving `SHOW INDEX PROGRESS`.
+    pub async fn list_creating_index_tables(&self) -> MetaResult<Vec<table::Model>> {
+        let inner = self.inner.read().await;
+        let tables = Table::find()
+            .join(JoinType::LeftJoin, table::Relation::Object1.def())
+            .join(JoinType::LeftJoin, object::Relation::StreamingJob.def())
+            .filter(
+                table::Column::TableType.eq(TableType::Index).and(
+                    streaming_job::Column::JobStatus
+                        .is_in([JobStatus::Initial, JobStatus::Creating]),
+                ),
+            )
+            .all(&inner.db)
+            .await?;
+        Ok(tables)
+    }
+
     pub async fn list_object_dependencies(&self) -> MetaResult<Vec<PbObjectDependencies>> {
         let inner = self.inner.read().await;
 
@@ -721,6 +763,173 @@ impl CatalogController {
         Ok(obj_dependencies)
     }
 
+    /// Returns the full typed dependency graph of the catalog for lineage rendering: one
+    /// node per object and one edge per dependency, with the edge kind derived from the
+    /// types of its endpoints. Unlike [`Self::list_object_dependencies`], this includes
+    /// views, indexes and references to secrets, connections and functions, and does not
+    /// filter out creating jobs.
+    pub async fn get_dependency_graph(&self) -> MetaResult<PbGetDependencyGraphResponse> {
+        let inner = self.inner.read().await;
+
+        let objects: Vec<(ObjectId, ObjectType)> = Object::find()
+            .select_only()
+            .columns([object::Column::Oid, object::Column::ObjType])
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        let object_types: HashMap<ObjectId, ObjectType> = objects.iter().cloned().collect();
+        let nodes = objects
+            .iter()
+            .filter(|(_, obj_type)| {
+                !matches!(obj_type, ObjectType::Database | ObjectType::Schema)
+            })
+            .map(|(oid, obj_type)| PbDependencyNode {
+                object_id: *oid as _,
+                object_type: obj_type.as_str().to_string(),
+            })
+            .collect();
+
+        let dependencies: Vec<(ObjectId, ObjectId)> = ObjectDependency::find()
+            .select_only()
+            .columns([
+                object_dependency::Column::Oid,
+                object_dependency::Column::UsedBy,
+            ])
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        let mut edges = dependencies
+            .into_iter()
+            .map(|(oid, used_by)| {
+                let kind = match object_types.get(&oid) {
+                    Some(ObjectType::Secret) => PbDependencyKind::Secret,
+                    Some(ObjectType::Connection) => PbDependencyKind::Connection,
+                    Some(ObjectType::Function) => PbDependencyKind::Function,
+                    _ => PbDependencyKind::Relation,
+                };
+                PbDependencyEdge {
+                    object_id: used_by as _,
+                    referenced_object_id: oid as _,
+                    kind: kind as _,
+                }
+            })
+            .collect_vec();
+
+        let sink_dependencies: Vec<(SinkId, TableId)> = Sink::find()
+            .select_only()
+            .columns([sink::Column::SinkId, sink::Column::TargetTable])
+            .filter(sink::Column::TargetTable.is_not_null())
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        edges.extend(
+            sink_dependencies
+                .into_iter()
+                .map(|(sink_id, table_id)| PbDependencyEdge {
+                    object_id: table_id as _,
+                    referenced_object_id: sink_id as _,
+                    kind: PbDependencyKind::SinkIntoTable as _,
+                }),
+        );
+
+        let subscription_dependencies: Vec<(SubscriptionId, TableId)> = Subscription::find()
+            .select_only()
+            .columns([
+                subscription::Column::SubscriptionId,
+                subscription::Column::DependentTableId,
+            ])
+            .filter(subscription::Column::DependentTableId.is_not_null())
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        edges.extend(subscription_dependencies.into_iter().map(
+            |(subscription_id, table_id)| PbDependencyEdge {
+                object_id: subscription_id as _,
+                referenced_object_id: table_id as _,
+                kind: PbDependencyKind::Subscription as _,
+            },
+        ));
+
+        Ok(PbGetDependencyGraphResponse { nodes, edges })
+    }
+
+    /// Returns the `CREATE` statement of the given relation and, if `include_dependencies` is
+    /// set, those of all its upstream dependencies, sorted so that every statement appears
+    /// after the statements of the relations it depends on.
+    pub async fn export_ddl(
+        &self,
+        relation_id: ObjectId,
+        include_dependencies: bool,
+    ) -> MetaResult<Vec<(ObjectId, String)>> {
+        let inner = self.inner.read().await;
+
+        let dependencies: Vec<(ObjectId, ObjectId)> = ObjectDependency::find()
+            .select_only()
+            .columns([
+                object_dependency::Column::Oid,
+                object_dependency::Column::UsedBy,
+            ])
+            .all(&inner.db)
+            .await?
+            .into_iter()
+            .map(|dependency| (dependency.oid, dependency.used_by))
+            .collect();
+        let mut upstreams: HashMap<ObjectId, Vec<ObjectId>> = HashMap::new();
+        for (oid, used_by) in dependencies {
+            upstreams.entry(used_by).or_default().push(oid);
+        }
+
+        // Post-order DFS so that upstream definitions come first.
+        let mut visited = HashSet::new();
+        let mut ordered = vec![];
+        let mut stack = vec![(relation_id, false)];
+        while let Some((id, expanded)) = stack.pop() {
+            if expanded {
+                ordered.push(id);
+                continue;
+            }
+            if !visited.insert(id) {
+                continue;
+            }
+            stack.push((id, true));
+            if include_dependencies {
+                for &dependent_id in upstreams.get(&id).into_iter().flatten() {
+                    if !visited.contains(&dependent_id) {
+                        stack.push((dependent_id, false));
+                    }
+                }
+            }
+        }
+
+        let mut statements = Vec::with_capacity(ordered.len());
+        for id in ordered {
+            // The statement of an index lives on its index table.
+            let definition = if let Some(table) = Table::find_by_id(id).one(&inner.db).await? {
+                table.definition
+            } else if let Some(source) = Source::find_by_id(id).one(&inner.db).await? {
+                source.definition
+            } else if let Some(sink) = Sink::find_by_id(id).one(&inner.db).await? {
+                sink.definition
+            } else if let Some(view) = View::find_by_id(id).one(&inner.db).await? {
+                view.definition
+            } else if let Some(subscription) = Subscription::find_by_id(id).one(&inner.db).await? {
+                subscription.definition
+            } else if let Some(index) = Index::find_by_id(id).one(&inner.db).await? {
+                let index_table = Table::find_by_id(index.index_table_id)
+                    .one(&inner.db)
+                    .await?
+                    .ok_or_else(|| {
+                        MetaError::catalog_id_not_found("table", index.index_table_id)
+                    })?;
+                index_table.definition
+            } else {
+                return Err(MetaError::catalog_id_not_found("relation", id));
+            };
+            statements.push((id, definition));
+        }
+        Ok(statements)
+    }
+
     pub async fn has_any_streaming_jobs(&self) -> MetaResult<bool> {
         let inner = self.inner.read().await;
         let count = streaming_job::Entity::find().count(&inner.db).await?;
@@ -1177,6 +1386,7 @@ impl CatalogController {
     pub async fn create_function(
         &self,
         mut pb_function: PbFunction,
+        or_replace: bool,
     ) -> MetaResult<NotificationVersion> {
         let inner = self.inner.write().await;
         let owner_id = pb_function.owner as _;
@@ -1184,6 +1394,45 @@ impl CatalogController {
         ensure_user_id(owner_id, &txn).await?;
         ensure_object_id(ObjectType::Database, pb_function.database_id as _, &txn).await?;
         ensure_object_id(ObjectType::Schema, pb_function.schema_id as _, &txn).await?;
+
+        if or_replace {
+            let existing = Function::find()
+                .inner_join(Object)
+                .filter(
+                    object::Column::DatabaseId
+                        .eq(pb_function.database_id as DatabaseId)
+                        .and(object::Column::SchemaId.eq(pb_function.schema_id as SchemaId))
+                        .and(function::Column::Name.eq(&pb_function.name))
+                        .and(
+                            function::Column::ArgTypes
+                                .eq(DataTypeArray::from(pb_function.arg_types.clone())),
+                        ),
+                )
+                .one(&txn)
+                .await?;
+            if let Some(function) = existing {
+                // Swap the definition in place. The function keeps its id and owner, so
+                // references from expressions and privileges stay intact.
+                let obj = Object::find_by_id(function.function_id)
+                    .one(&txn)
+                    .await?
+                    .unwrap();
+                pb_function.id = function.function_id as _;
+                pb_function.owner = obj.owner_id as _;
+                let active_model: function::ActiveModel = pb_function.clone().into();
+                Function::update(active_model).exec(&txn).await?;
+                txn.commit().await?;
+
+                let version = self
+                    .notify_frontend(
+                        NotificationOperation::Update,
+                        NotificationInfo::Function(pb_function),
+                    )
+                    .await;
+                return Ok(version);
+            }
+        }
+
         check_function_signature_duplicate(&pb_function, &txn).await?;
 
         let function_obj = Self::create_object(
@@ -1351,6 +1600,49 @@ impl CatalogController {
         Ok(version)
     }
 
+    /// Replaces the value of an existing secret and bumps its version, then pushes the
+    /// rotated plain value to compute nodes so that connectors referencing the secret
+    /// pick up the new credentials without recreating the jobs.
+    pub async fn rotate_secret(
+        &self,
+        secret_id: SecretId,
+        encrypted_payload: Vec<u8>,
+        secret_plain_payload: Vec<u8>,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let (secret, secret_obj) = Secret::find_by_id(secret_id)
+            .find_also_related(Object)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("secret", secret_id))?;
+
+        let mut secret = secret.into_active_model();
+        let version = secret.version.take().unwrap() + 1;
+        secret.value = Set(encrypted_payload);
+        secret.version = Set(version);
+        let secret = secret.update(&txn).await?;
+        txn.commit().await?;
+
+        // Notify the compute and frontend node plain secret
+        let mut secret_plain: PbSecret = ObjectModel(secret, secret_obj.unwrap()).into();
+        secret_plain.value.clone_from(&secret_plain_payload);
+
+        LocalSecretManager::global().update_secret(secret_plain.id, secret_plain_payload);
+        self.env
+            .notification_manager()
+            .notify_compute_without_version(Operation::Update, Info::Secret(secret_plain.clone()));
+
+        let version = self
+            .notify_frontend(
+                NotificationOperation::Update,
+                NotificationInfo::Secret(secret_plain),
+            )
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn create_connection(
         &self,
         mut pb_connection: PbConnection,
@@ -1493,6 +1785,219 @@ impl CatalogController {
         Ok(version)
     }
 
+    /// Applies a batch of DDL operations in a single database transaction: either all
+    /// operations are committed and notified, or none are. Frontends observe the result
+    /// as one grouped relation notification per direction — dropped relations first,
+    /// then created ones.
+    ///
+    /// Operations are applied in order, so a name freed by an earlier drop can be
+    /// reused and a view may depend on a source created earlier in the same batch.
+    /// Drops follow restrict semantics.
+    ///
+    /// The new sources are registered with the source manager inside the transaction
+    /// and unregistered again if the batch fails; the ids of the dropped sources are
+    /// returned so that the caller can unregister them after the commit.
+    pub async fn run_ddl_batch(
+        &self,
+        operations: Vec<DdlBatchOperation>,
+        source_manager: SourceManagerRef,
+    ) -> MetaResult<(NotificationVersion, Vec<SourceId>)> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+
+        let mut registered_source_ids = vec![];
+        let result =
+            Self::apply_ddl_batch(&txn, &source_manager, operations, &mut registered_source_ids)
+                .await;
+        let (added_relations, to_drop_objects, user_infos) = match result {
+            Ok(applied) => applied,
+            Err(e) => {
+                source_manager
+                    .unregister_sources(registered_source_ids)
+                    .await;
+                txn.rollback().await?;
+                return Err(e);
+            }
+        };
+        txn.commit().await?;
+
+        self.notify_users_update(user_infos).await;
+        let dropped_source_ids = to_drop_objects
+            .iter()
+            .filter(|obj| obj.obj_type == ObjectType::Source)
+            .map(|obj| obj.oid)
+            .collect_vec();
+        let mut version = IGNORED_NOTIFICATION_VERSION;
+        if !to_drop_objects.is_empty() {
+            version = self
+                .notify_frontend(
+                    NotificationOperation::Delete,
+                    build_relation_group(to_drop_objects),
+                )
+                .await;
+        }
+        if !added_relations.is_empty() {
+            version = self
+                .notify_frontend(
+                    NotificationOperation::Add,
+                    NotificationInfo::RelationGroup(PbRelationGroup {
+                        relations: added_relations
+                            .into_iter()
+                            .map(|relation_info| PbRelation {
+                                relation_info: Some(relation_info),
+                            })
+                            .collect_vec(),
+                    }),
+                )
+                .await;
+        }
+
+        Ok((version, dropped_source_ids))
+    }
+
+    /// Applies the operations of a DDL batch to `txn`, recording the ids of the
+    /// sources it has registered with the source manager so that the caller can
+    /// unregister them again if the transaction is rolled back.
+    async fn apply_ddl_batch(
+        txn: &DatabaseTransaction,
+        source_manager: &SourceManagerRef,
+        operations: Vec<DdlBatchOperation>,
+        registered_source_ids: &mut Vec<u32>,
+    ) -> MetaResult<(Vec<PbRelationInfo>, Vec<PartialObject>, Vec<PbUserInfo>)> {
+        let mut added_relations = vec![];
+        let mut to_drop_objects: Vec<PartialObject> = vec![];
+        let mut to_update_user_ids = vec![];
+        for operation in operations {
+            match operation {
+                DdlBatchOperation::CreateSource(mut pb_source) => {
+                    let owner_id = pb_source.owner as _;
+                    ensure_user_id(owner_id, txn).await?;
+                    ensure_object_id(ObjectType::Database, pb_source.database_id as _, txn).await?;
+                    ensure_object_id(ObjectType::Schema, pb_source.schema_id as _, txn).await?;
+                    check_relation_name_duplicate(
+                        &pb_source.name,
+                        pb_source.database_id as _,
+                        pb_source.schema_id as _,
+                        txn,
+                    )
+                    .await?;
+
+                    let source_obj = Self::create_object(
+                        txn,
+                        ObjectType::Source,
+                        owner_id,
+                        Some(pb_source.database_id as _),
+                        Some(pb_source.schema_id as _),
+                    )
+                    .await?;
+                    pb_source.id = source_obj.oid as _;
+                    let source: source::ActiveModel = pb_source.clone().into();
+                    Source::insert(source).exec(txn).await?;
+
+                    source_manager.register_source(&pb_source).await?;
+                    registered_source_ids.push(pb_source.id);
+                    added_relations.push(PbRelationInfo::Source(pb_source));
+                }
+                DdlBatchOperation::DropSource(source_id) => {
+                    let object_id = source_id as ObjectId;
+                    let obj: PartialObject = Object::find_by_id(object_id)
+                        .into_partial_model()
+                        .one(txn)
+                        .await?
+                        .ok_or_else(|| MetaError::catalog_id_not_found("source", object_id))?;
+                    assert_eq!(obj.obj_type, ObjectType::Source);
+                    ensure_object_not_refer(ObjectType::Source, object_id, txn).await?;
+                    let source_info: Option<StreamSourceInfo> = Source::find_by_id(object_id)
+                        .select_only()
+                        .column(source::Column::SourceInfo)
+                        .into_tuple()
+                        .one(txn)
+                        .await?
+                        .ok_or_else(|| MetaError::catalog_id_not_found("source", object_id))?;
+                    if let Some(source_info) = source_info
+                        && source_info.to_protobuf().is_shared()
+                    {
+                        bail!("shared source {} cannot be dropped in a DDL batch", object_id);
+                    }
+
+                    let user_ids: Vec<UserId> = UserPrivilege::find()
+                        .select_only()
+                        .distinct()
+                        .column(user_privilege::Column::UserId)
+                        .filter(user_privilege::Column::Oid.eq(object_id))
+                        .into_tuple()
+                        .all(txn)
+                        .await?;
+                    to_update_user_ids.extend(user_ids);
+                    Object::delete_by_id(object_id).exec(txn).await?;
+                    to_drop_objects.push(obj);
+                }
+                DdlBatchOperation::CreateView(mut pb_view) => {
+                    let owner_id = pb_view.owner as _;
+                    ensure_user_id(owner_id, txn).await?;
+                    ensure_object_id(ObjectType::Database, pb_view.database_id as _, txn).await?;
+                    ensure_object_id(ObjectType::Schema, pb_view.schema_id as _, txn).await?;
+                    check_relation_name_duplicate(
+                        &pb_view.name,
+                        pb_view.database_id as _,
+                        pb_view.schema_id as _,
+                        txn,
+                    )
+                    .await?;
+
+                    let view_obj = Self::create_object(
+                        txn,
+                        ObjectType::View,
+                        owner_id,
+                        Some(pb_view.database_id as _),
+                        Some(pb_view.schema_id as _),
+                    )
+                    .await?;
+                    pb_view.id = view_obj.oid as _;
+                    let view: view::ActiveModel = pb_view.clone().into();
+                    View::insert(view).exec(txn).await?;
+
+                    for obj_id in &pb_view.dependent_relations {
+                        ObjectDependency::insert(object_dependency::ActiveModel {
+                            oid: Set(*obj_id as _),
+                            used_by: Set(view_obj.oid),
+                            ..Default::default()
+                        })
+                        .exec(txn)
+                        .await?;
+                    }
+                    added_relations.push(PbRelationInfo::View(pb_view));
+                }
+                DdlBatchOperation::DropView(view_id) => {
+                    let object_id = view_id as ObjectId;
+                    let obj: PartialObject = Object::find_by_id(object_id)
+                        .into_partial_model()
+                        .one(txn)
+                        .await?
+                        .ok_or_else(|| MetaError::catalog_id_not_found("view", object_id))?;
+                    assert_eq!(obj.obj_type, ObjectType::View);
+                    ensure_object_not_refer(ObjectType::View, object_id, txn).await?;
+
+                    let user_ids: Vec<UserId> = UserPrivilege::find()
+                        .select_only()
+                        .distinct()
+                        .column(user_privilege::Column::UserId)
+                        .filter(user_privilege::Column::Oid.eq(object_id))
+                        .into_tuple()
+                        .all(txn)
+                        .await?;
+                    to_update_user_ids.extend(user_ids);
+                    Object::delete_by_id(object_id).exec(txn).await?;
+                    to_drop_objects.push(obj);
+                }
+            }
+        }
+        let user_infos =
+            list_user_info_by_ids(to_update_user_ids.into_iter().unique().collect(), txn).await?;
+
+        Ok((added_relations, to_drop_objects, user_infos))
+    }
+
     pub async fn alter_owner(
         &self,
         object_type: ObjectType,
@@ -1701,36 +2206,126 @@ impl CatalogController {
                     .ok_or_else(|| MetaError::catalog_id_not_found("view", object_id))?;
                 relations.push(PbRelationInfo::View(ObjectModel(view, obj).into()));
             }
-            _ => unreachable!("not supported object type: {:?}", object_type),
-        };
-
-        txn.commit().await?;
-
-        let version = self
-            .notify_frontend(
-                NotificationOperation::Update,
-                NotificationInfo::RelationGroup(PbRelationGroup {
-                    relations: relations
-                        .into_iter()
-                        .map(|relation| PbRelation {
-                            relation_info: Some(relation),
-                        })
-                        .collect(),
-                }),
-            )
-            .await;
-        Ok(version)
-    }
+            ObjectType::Function => {
+                let function = Function::find_by_id(object_id)
+                    .one(&txn)
+                    .await?
+                    .ok_or_else(|| MetaError::catalog_id_not_found("function", object_id))?;
 
-    pub async fn alter_schema(
-        &self,
-        object_type: ObjectType,
-        object_id: ObjectId,
-        new_schema: SchemaId,
-    ) -> MetaResult<NotificationVersion> {
-        let inner = self.inner.write().await;
-        let txn = inner.db.begin().await?;
-        ensure_object_id(ObjectType::Schema, new_schema, &txn).await?;
+                txn.commit().await?;
+
+                let version = self
+                    .notify_frontend(
+                        NotificationOperation::Update,
+                        NotificationInfo::Function(ObjectModel(function, obj).into()),
+                    )
+                    .await;
+                return Ok(version);
+            }
+            ObjectType::Connection => {
+                let connection = Connection::find_by_id(object_id)
+                    .one(&txn)
+                    .await?
+                    .ok_or_else(|| MetaError::catalog_id_not_found("connection", object_id))?;
+
+                txn.commit().await?;
+
+                let version = self
+                    .notify_frontend(
+                        NotificationOperation::Update,
+                        NotificationInfo::Connection(ObjectModel(connection, obj).into()),
+                    )
+                    .await;
+                return Ok(version);
+            }
+            ObjectType::Secret => {
+                let secret = Secret::find_by_id(object_id)
+                    .one(&txn)
+                    .await?
+                    .ok_or_else(|| MetaError::catalog_id_not_found("secret", object_id))?;
+
+                txn.commit().await?;
+
+                let mut pb_secret: PbSecret = ObjectModel(secret, obj).into();
+                // Like secret creation and rotation, the frontend is notified with the
+                // plain value rather than the encrypted one persisted in the meta store.
+                if let Some(plain_value) = LocalSecretManager::global().get_secret(pb_secret.id) {
+                    pb_secret.value = plain_value;
+                }
+
+                let version = self
+                    .notify_frontend(
+                        NotificationOperation::Update,
+                        NotificationInfo::Secret(pb_secret),
+                    )
+                    .await;
+                return Ok(version);
+            }
+            _ => unreachable!("not supported object type: {:?}", object_type),
+        };
+
+        txn.commit().await?;
+
+        let version = self
+            .notify_frontend(
+                NotificationOperation::Update,
+                NotificationInfo::RelationGroup(PbRelationGroup {
+                    relations: relations
+                        .into_iter()
+                        .map(|relation| PbRelation {
+                            relation_info: Some(relation),
+                        })
+                        .collect(),
+                }),
+            )
+            .await;
+        Ok(version)
+    }
+
+    /// Set or clear the default owner template of a schema. Objects created in the
+    /// schema afterwards are owned by the template owner instead of their creator.
+    pub async fn alter_schema_default_owner(
+        &self,
+        schema_id: SchemaId,
+        default_owner_id: Option<UserId>,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        if let Some(owner_id) = default_owner_id {
+            ensure_user_id(owner_id, &txn).await?;
+        }
+
+        let (schema, schema_obj) = Schema::find_by_id(schema_id)
+            .find_also_related(Object)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("schema", schema_id))?;
+        if schema.default_owner_id == default_owner_id {
+            return Ok(IGNORED_NOTIFICATION_VERSION);
+        }
+        let mut schema = schema.into_active_model();
+        schema.default_owner_id = Set(default_owner_id);
+        let schema = schema.update(&txn).await?;
+        txn.commit().await?;
+
+        let version = self
+            .notify_frontend(
+                NotificationOperation::Update,
+                NotificationInfo::Schema(ObjectModel(schema, schema_obj.unwrap()).into()),
+            )
+            .await;
+        Ok(version)
+    }
+
+    pub async fn alter_schema(
+        &self,
+        object_type: ObjectType,
+        object_id: ObjectId,
+        new_schema: SchemaId,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        ensure_object_id(ObjectType::Schema, new_schema, &txn).await?;
 
         let obj = Object::find_by_id(object_id)
             .one(&txn)
@@ -2012,6 +2607,9 @@ impl CatalogController {
     }
 
     pub async fn comment_on(&self, comment: PbComment) -> MetaResult<NotificationVersion> {
+        if comment.object_type() != PbCommentObjectType::Unspecified {
+            bail!("comments on objects other than tables are not yet supported by the SQL meta backend");
+        }
         let inner = self.inner.write().await;
         let txn = inner.db.begin().await?;
         ensure_object_id(ObjectType::Database, comment.database_id as _, &txn).await?;
@@ -2057,19 +2655,483 @@ impl CatalogController {
             }
             .update(&txn)
             .await?
-        };
+        };
+        txn.commit().await?;
+
+        let version = self
+            .notify_frontend_relation_info(
+                NotificationOperation::Update,
+                PbRelationInfo::Table(ObjectModel(table, table_obj).into()),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    pub async fn alter_annotation(
+        &self,
+        annotation: PbAnnotation,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        ensure_object_id(ObjectType::Database, annotation.database_id as _, &txn).await?;
+        ensure_object_id(ObjectType::Schema, annotation.schema_id as _, &txn).await?;
+        let table_obj = Object::find_by_id(annotation.table_id as ObjectId)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", annotation.table_id))?;
+
+        let annotations: Option<Property> = Table::find_by_id(annotation.table_id as TableId)
+            .select_only()
+            .column(table::Column::Annotations)
+            .into_tuple()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", annotation.table_id))?;
+        let mut annotations = annotations.map(|a| a.into_inner()).unwrap_or_default();
+        if let Some(value) = annotation.value {
+            if !annotations.contains_key(&annotation.key)
+                && annotations.len() >= MAX_ANNOTATIONS_PER_OBJECT
+            {
+                return Err(anyhow!(
+                    "table {} already has {} annotations",
+                    annotation.table_id,
+                    MAX_ANNOTATIONS_PER_OBJECT
+                )
+                .into());
+            }
+            annotations.insert(annotation.key, value);
+        } else {
+            annotations.remove(&annotation.key);
+        }
+
+        let table = table::ActiveModel {
+            table_id: Set(annotation.table_id as _),
+            annotations: Set(Some(annotations.into())),
+            ..Default::default()
+        }
+        .update(&txn)
+        .await?;
+        txn.commit().await?;
+
+        let version = self
+            .notify_frontend_relation_info(
+                NotificationOperation::Update,
+                PbRelationInfo::Table(ObjectModel(table, table_obj).into()),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    /// Set or remove a single label on a table/materialized view or a sink.
+    pub async fn alter_label(&self, label: PbLabel) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+
+        match label.job {
+            Some(LabelJob::TableId(table_id)) => {
+                let table_obj = Object::find_by_id(table_id as ObjectId)
+                    .one(&txn)
+                    .await?
+                    .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+                let labels: Option<Property> = Table::find_by_id(table_id as TableId)
+                    .select_only()
+                    .column(table::Column::Labels)
+                    .into_tuple()
+                    .one(&txn)
+                    .await?
+                    .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+                let mut labels = labels.map(|labels| labels.into_inner()).unwrap_or_default();
+                if let Some(value) = label.value {
+                    if !labels.contains_key(&label.key) && labels.len() >= MAX_LABELS_PER_OBJECT {
+                        return Err(anyhow!(
+                            "table {} already has {} labels",
+                            table_id,
+                            MAX_LABELS_PER_OBJECT
+                        )
+                        .into());
+                    }
+                    labels.insert(label.key, value);
+                } else {
+                    labels.remove(&label.key);
+                }
+
+                let table = table::ActiveModel {
+                    table_id: Set(table_id as _),
+                    labels: Set(Some(labels.into())),
+                    ..Default::default()
+                }
+                .update(&txn)
+                .await?;
+                txn.commit().await?;
+
+                let version = self
+                    .notify_frontend_relation_info(
+                        NotificationOperation::Update,
+                        PbRelationInfo::Table(ObjectModel(table, table_obj).into()),
+                    )
+                    .await;
+                Ok(version)
+            }
+            Some(LabelJob::SinkId(sink_id)) => {
+                let sink_obj = Object::find_by_id(sink_id as ObjectId)
+                    .one(&txn)
+                    .await?
+                    .ok_or_else(|| MetaError::catalog_id_not_found("sink", sink_id))?;
+                let labels: Option<Property> = Sink::find_by_id(sink_id as SinkId)
+                    .select_only()
+                    .column(sink::Column::Labels)
+                    .into_tuple()
+                    .one(&txn)
+                    .await?
+                    .ok_or_else(|| MetaError::catalog_id_not_found("sink", sink_id))?;
+                let mut labels = labels.map(|labels| labels.into_inner()).unwrap_or_default();
+                if let Some(value) = label.value {
+                    if !labels.contains_key(&label.key) && labels.len() >= MAX_LABELS_PER_OBJECT {
+                        return Err(anyhow!(
+                            "sink {} already has {} labels",
+                            sink_id,
+                            MAX_LABELS_PER_OBJECT
+                        )
+                        .into());
+                    }
+                    labels.insert(label.key, value);
+                } else {
+                    labels.remove(&label.key);
+                }
+
+                let sink = sink::ActiveModel {
+                    sink_id: Set(sink_id as _),
+                    labels: Set(Some(labels.into())),
+                    ..Default::default()
+                }
+                .update(&txn)
+                .await?;
+                txn.commit().await?;
+
+                let version = self
+                    .notify_frontend_relation_info(
+                        NotificationOperation::Update,
+                        PbRelationInfo::Sink(ObjectModel(sink, sink_obj).into()),
+                    )
+                    .await;
+                Ok(version)
+            }
+            None => Err(anyhow!("no streaming job to label").into()),
+        }
+    }
+
+    /// Returns the ids of user tables/materialized views and sinks that carry all the
+    /// labels in `selector`.
+    pub async fn list_streaming_job_ids_by_label(
+        &self,
+        selector: &HashMap<String, String>,
+    ) -> MetaResult<(Vec<TableId>, Vec<SinkId>)> {
+        let inner = self.inner.read().await;
+        let selector_matches = |labels: Option<Property>| {
+            let labels = labels.map(|labels| labels.into_inner()).unwrap_or_default();
+            selector
+                .iter()
+                .all(|(key, value)| labels.get(key) == Some(value))
+        };
+
+        let table_ids = Table::find()
+            .select_only()
+            .column(table::Column::TableId)
+            .column(table::Column::Labels)
+            .filter(
+                table::Column::TableType.is_in([TableType::Table, TableType::MaterializedView]),
+            )
+            .into_tuple::<(TableId, Option<Property>)>()
+            .all(&inner.db)
+            .await?
+            .into_iter()
+            .filter_map(|(table_id, labels)| selector_matches(labels).then_some(table_id))
+            .collect();
+        let sink_ids = Sink::find()
+            .select_only()
+            .column(sink::Column::SinkId)
+            .column(sink::Column::Labels)
+            .into_tuple::<(SinkId, Option<Property>)>()
+            .all(&inner.db)
+            .await?
+            .into_iter()
+            .filter_map(|(sink_id, labels)| selector_matches(labels).then_some(sink_id))
+            .collect();
+        Ok((table_ids, sink_ids))
+    }
+
+    /// Set or clear the archival tier policy of a table. Besides frontends, compactors
+    /// are notified as well since they drive the placement of aged state on cheaper
+    /// storage classes.
+    pub async fn alter_storage_class_policy(
+        &self,
+        table_id: TableId,
+        policy: Option<PbStorageClassPolicy>,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let table_obj = Object::find_by_id(table_id as ObjectId)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+
+        let table = table::ActiveModel {
+            table_id: Set(table_id),
+            storage_class_policy: Set(policy.as_ref().map(|p| p.into())),
+            ..Default::default()
+        }
+        .update(&txn)
+        .await?;
+        txn.commit().await?;
+
+        let table: PbTable = ObjectModel(table, table_obj).into();
+        self.env
+            .notification_manager()
+            .notify_compactor_relation_info(
+                NotificationOperation::Update,
+                PbRelationInfo::Table(table.clone()),
+            )
+            .await;
+        let version = self
+            .notify_frontend_relation_info(
+                NotificationOperation::Update,
+                PbRelationInfo::Table(table),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    pub async fn alter_schema_change_policy(
+        &self,
+        table_id: TableId,
+        policy: PbSchemaChangePolicy,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let table_obj = Object::find_by_id(table_id as ObjectId)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+
+        let table = table::ActiveModel {
+            table_id: Set(table_id),
+            schema_change_policy: Set(match policy {
+                PbSchemaChangePolicy::Unspecified => None,
+                policy => Some(policy.into()),
+            }),
+            ..Default::default()
+        }
+        .update(&txn)
+        .await?;
+        txn.commit().await?;
+
+        // The policy is enforced on the meta node; frontends are only notified to
+        // keep their catalog caches consistent.
+        let table: PbTable = ObjectModel(table, table_obj).into();
+        let version = self
+            .notify_frontend_relation_info(
+                NotificationOperation::Update,
+                PbRelationInfo::Table(table),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    /// Set the priority class of a streaming job. The barrier manager and the compactor
+    /// scheduler pick it up from the catalog on the next barrier, so no fragment-level
+    /// mutation is needed here.
+    pub async fn alter_stream_job_priority(
+        &self,
+        table_id: TableId,
+        priority: PbStreamJobPriority,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let table_obj = Object::find_by_id(table_id as ObjectId)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+
+        let table = table::ActiveModel {
+            table_id: Set(table_id),
+            priority: Set(match priority {
+                PbStreamJobPriority::Unspecified => None,
+                priority => Some(priority.into()),
+            }),
+            ..Default::default()
+        }
+        .update(&txn)
+        .await?;
+        txn.commit().await?;
+
+        let table: PbTable = ObjectModel(table, table_obj).into();
+        let version = self
+            .notify_frontend_relation_info(
+                NotificationOperation::Update,
+                PbRelationInfo::Table(table),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    /// Sets or clears the state retention time of the table. The new option takes effect on
+    /// the next TTL compaction of the table's compaction group.
+    pub async fn alter_table_ttl(
+        &self,
+        table_id: TableId,
+        retention_seconds: Option<u32>,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let table_obj = Object::find_by_id(table_id as ObjectId)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+
+        let table = table::ActiveModel {
+            table_id: Set(table_id),
+            retention_seconds: Set(retention_seconds.map(|r| r as i32)),
+            ..Default::default()
+        }
+        .update(&txn)
+        .await?;
+        txn.commit().await?;
+
+        let table: PbTable = ObjectModel(table, table_obj).into();
+        let version = self
+            .notify_frontend_relation_info(
+                NotificationOperation::Update,
+                PbRelationInfo::Table(table),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    /// Returns the storage class policies of all tables that have one.
+    pub async fn get_table_storage_class_policies(
+        &self,
+    ) -> MetaResult<Vec<(TableId, PbStorageClassPolicy)>> {
+        let inner = self.inner.read().await;
+        let policies: Vec<(TableId, StorageClassPolicy)> = Table::find()
+            .select_only()
+            .column(table::Column::TableId)
+            .column(table::Column::StorageClassPolicy)
+            .filter(table::Column::StorageClassPolicy.is_not_null())
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        Ok(policies
+            .into_iter()
+            .map(|(table_id, policy)| (table_id, policy.to_protobuf()))
+            .collect())
+    }
+
+    /// Soft-drops a table or materialized view: marks it dropped and hides it from
+    /// frontends, while keeping its catalog and fragments so that it can be restored via
+    /// [`Self::undrop_table`] until the retention window elapses.
+    pub async fn soft_drop_table(&self, table_id: TableId) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let table_obj = Object::find_by_id(table_id as ObjectId)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+
+        let soft_dropped_at_ms: Option<Option<i64>> = Table::find_by_id(table_id)
+            .select_only()
+            .column(table::Column::SoftDroppedAtMs)
+            .into_tuple()
+            .one(&txn)
+            .await?;
+        if soft_dropped_at_ms
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?
+            .is_some()
+        {
+            bail!("table {} has already been soft-dropped", table_id);
+        }
+        // Like `DropMode::Restrict`, refuse when other relations still depend on it.
+        ensure_object_not_refer(ObjectType::Table, table_id as ObjectId, &txn).await?;
+
+        let table = table::ActiveModel {
+            table_id: Set(table_id),
+            soft_dropped_at_ms: Set(Some(Epoch::physical_now() as _)),
+            ..Default::default()
+        }
+        .update(&txn)
+        .await?;
+        txn.commit().await?;
+
+        // Hide the table from frontends. The catalog entry itself is kept.
+        let version = self
+            .notify_frontend_relation_info(
+                NotificationOperation::Delete,
+                PbRelationInfo::Table(ObjectModel(table, table_obj).into()),
+            )
+            .await;
+        Ok(version)
+    }
+
+    /// Restores a soft-dropped table or materialized view, making it visible to frontends
+    /// again.
+    pub async fn undrop_table(&self, table_id: TableId) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let table_obj = Object::find_by_id(table_id as ObjectId)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+
+        let soft_dropped_at_ms: Option<Option<i64>> = Table::find_by_id(table_id)
+            .select_only()
+            .column(table::Column::SoftDroppedAtMs)
+            .into_tuple()
+            .one(&txn)
+            .await?;
+        if soft_dropped_at_ms
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?
+            .is_none()
+        {
+            bail!("table {} is not soft-dropped", table_id);
+        }
+
+        let table = table::ActiveModel {
+            table_id: Set(table_id),
+            soft_dropped_at_ms: Set(None),
+            ..Default::default()
+        }
+        .update(&txn)
+        .await?;
         txn.commit().await?;
 
         let version = self
             .notify_frontend_relation_info(
-                NotificationOperation::Update,
+                NotificationOperation::Add,
                 PbRelationInfo::Table(ObjectModel(table, table_obj).into()),
             )
             .await;
-
         Ok(version)
     }
 
+    /// Returns the ids of soft-dropped tables whose drop time is older than the cutoff,
+    /// i.e. whose retention window has elapsed and which should be dropped for real.
+    pub async fn list_expired_soft_dropped_tables(&self, cutoff_ms: u64) -> MetaResult<Vec<TableId>> {
+        let inner = self.inner.read().await;
+        let table_ids: Vec<TableId> = Table::find()
+            .select_only()
+            .column(table::Column::TableId)
+            .filter(table::Column::SoftDroppedAtMs.lt(cutoff_ms as i64))
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        Ok(table_ids)
+    }
+
     pub async fn drop_relation(
         &self,
         object_type: ObjectType,
@@ -2086,6 +3148,9 @@ impl CatalogController {
         assert_eq!(obj.obj_type, object_type);
 
         let mut to_drop_objects = match drop_mode {
+            DropMode::SoftDelete => {
+                unreachable!("soft delete is handled in the ddl controller")
+            }
             DropMode::Cascade => get_referring_objects_cascade(object_id, &txn).await?,
             DropMode::Restrict => {
                 ensure_object_not_refer(object_type, object_id, &txn).await?;
@@ -2323,6 +3388,84 @@ impl CatalogController {
         let active_model = database::ActiveModel {
             database_id: Set(database_id),
             name: Set(name.to_string()),
+            ..Default::default()
+        };
+        let database = active_model.update(&txn).await?;
+
+        let obj = Object::find_by_id(database_id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("database", database_id))?;
+
+        txn.commit().await?;
+
+        let version = self
+            .notify_frontend(
+                NotificationOperation::Update,
+                NotificationInfo::Database(ObjectModel(database, obj).into()),
+            )
+            .await;
+        Ok(version)
+    }
+
+    pub async fn alter_database_barrier_interval(
+        &self,
+        database_id: DatabaseId,
+        barrier_interval_ms: Option<u32>,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+
+        let active_model = database::ActiveModel {
+            database_id: Set(database_id),
+            barrier_interval_ms: Set(barrier_interval_ms.map(|i| i as _)),
+            ..Default::default()
+        };
+        let database = active_model.update(&txn).await?;
+
+        let obj = Object::find_by_id(database_id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("database", database_id))?;
+
+        txn.commit().await?;
+
+        // Let the barrier loop pick up the new interval without restart.
+        self.env
+            .notification_manager()
+            .notify_local_subscribers(LocalNotification::DatabaseBarrierIntervalChange)
+            .await;
+
+        let version = self
+            .notify_frontend(
+                NotificationOperation::Update,
+                NotificationInfo::Database(ObjectModel(database, obj).into()),
+            )
+            .await;
+        Ok(version)
+    }
+
+    pub async fn alter_database_session_default(
+        &self,
+        database_id: DatabaseId,
+        name: &str,
+        value: Option<String>,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+
+        let active_model = match name {
+            "timezone" => database::ActiveModel {
+                database_id: Set(database_id),
+                default_timezone: Set(value),
+                ..Default::default()
+            },
+            "collation" => database::ActiveModel {
+                database_id: Set(database_id),
+                default_collation: Set(value),
+                ..Default::default()
+            },
+            _ => bail!("unsupported database session default: {}", name),
         };
         let database = active_model.update(&txn).await?;
 
@@ -2373,6 +3516,42 @@ impl CatalogController {
         Ok(version)
     }
 
+    async fn alter_function_name(
+        &self,
+        function_id: FunctionId,
+        name: &str,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+
+        let (function, obj) = Function::find_by_id(function_id)
+            .find_also_related(Object)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("function", function_id))?;
+        let mut pb_function: PbFunction = ObjectModel(function, obj.unwrap()).into();
+        pb_function.name = name.to_string();
+        // Overloads of the new name with different argument types are allowed.
+        check_function_signature_duplicate(&pb_function, &txn).await?;
+
+        let active_model = function::ActiveModel {
+            function_id: Set(function_id),
+            name: Set(name.to_string()),
+            ..Default::default()
+        };
+        active_model.update(&txn).await?;
+
+        txn.commit().await?;
+
+        let version = self
+            .notify_frontend(
+                NotificationOperation::Update,
+                NotificationInfo::Function(pb_function),
+            )
+            .await;
+        Ok(version)
+    }
+
     pub async fn alter_name(
         &self,
         object_type: ObjectType,
@@ -2383,6 +3562,8 @@ impl CatalogController {
             return self.alter_database_name(object_id as _, object_name).await;
         } else if object_type == ObjectType::Schema {
             return self.alter_schema_name(object_id as _, object_name).await;
+        } else if object_type == ObjectType::Function {
+            return self.alter_function_name(object_id as _, object_name).await;
         }
 
         let inner = self.inner.write().await;
@@ -2585,27 +3766,94 @@ impl CatalogController {
             .collect())
     }
 
+    /// Replaces the source catalog with the given one, checking that the version is bumped by
+    /// exactly one. Returns the notification version and whether the `StreamSourceInfo`
+    /// (format/encode and their options) was changed, so that the caller can notify the running
+    /// source executors of the new parser config.
     pub async fn alter_source_column(
         &self,
         pb_source: PbSource,
-    ) -> MetaResult<NotificationVersion> {
+    ) -> MetaResult<(NotificationVersion, bool)> {
         let source_id = pb_source.id as SourceId;
         let inner = self.inner.write().await;
         let txn = inner.db.begin().await?;
 
-        let original_version: i64 = Source::find_by_id(source_id)
-            .select_only()
-            .column(source::Column::Version)
-            .into_tuple()
+        let original_source = Source::find_by_id(source_id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("source", source_id))?;
+        if original_source.version + 1 != pb_source.version as i64 {
+            return Err(MetaError::permission_denied(
+                "source version is stale".to_string(),
+            ));
+        }
+        let info_changed =
+            original_source.source_info != pb_source.info.as_ref().map(StreamSourceInfo::from);
+
+        let source: source::ActiveModel = pb_source.clone().into();
+        source.update(&txn).await?;
+        txn.commit().await?;
+
+        let version = self
+            .notify_frontend_relation_info(
+                NotificationOperation::Update,
+                PbRelationInfo::Source(pb_source),
+            )
+            .await;
+        Ok((version, info_changed))
+    }
+
+    /// Adds `column` to the source, assigning the next free column id and bumping the source
+    /// version. Unlike [`Self::alter_source_column`], the catalog is mutated on the meta side,
+    /// so a stale frontend cannot clobber unrelated fields of the source.
+    pub async fn alter_source_add_column(
+        &self,
+        source_id: SourceId,
+        mut column: PbColumnCatalog,
+        definition: String,
+        base_version: u64,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+
+        let (source, obj) = Source::find_by_id(source_id)
+            .find_also_related(Object)
             .one(&txn)
             .await?
             .ok_or_else(|| MetaError::catalog_id_not_found("source", source_id))?;
-        if original_version + 1 != pb_source.version as i64 {
+        let mut pb_source: PbSource = ObjectModel(source, obj.unwrap()).into();
+        if pb_source.version != base_version {
             return Err(MetaError::permission_denied(
                 "source version is stale".to_string(),
             ));
         }
 
+        let column_desc = column
+            .column_desc
+            .as_mut()
+            .ok_or_else(|| MetaError::invalid_parameter("column desc is missing"))?;
+        if pb_source.columns.iter().any(|c| {
+            c.column_desc
+                .as_ref()
+                .is_some_and(|desc| desc.name == column_desc.name)
+        }) {
+            return Err(MetaError::invalid_parameter(format!(
+                "column \"{}\" of source \"{}\" already exists",
+                column_desc.name, pb_source.name
+            )));
+        }
+        column_desc.column_id = pb_source
+            .columns
+            .iter()
+            .filter_map(|c| c.column_desc.as_ref().map(|desc| desc.column_id))
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        pb_source.columns.push(column);
+        pb_source.definition = definition;
+        pb_source.version += 1;
+
         let source: source::ActiveModel = pb_source.clone().into();
         source.update(&txn).await?;
         txn.commit().await?;
@@ -2619,6 +3867,18 @@ impl CatalogController {
         Ok(version)
     }
 
+    /// List the ids of all objects (tables, materialized views, sinks, ...) that depend on
+    /// the source. Their schemas were fixed when they were created, so they do not see
+    /// columns added to the source afterwards.
+    pub async fn list_source_dependent_job_ids(
+        &self,
+        source_id: SourceId,
+    ) -> MetaResult<Vec<ObjectId>> {
+        let inner = self.inner.read().await;
+        let objs = get_referring_objects(source_id, &inner.db).await?;
+        Ok(objs.into_iter().map(|obj| obj.oid).collect())
+    }
+
     pub async fn list_databases(&self) -> MetaResult<Vec<PbDatabase>> {
         let inner = self.inner.read().await;
         inner.list_databases().await
@@ -2673,6 +3933,28 @@ impl CatalogController {
         Ok(table_ids)
     }
 
+    /// Returns the ids of the indexes' tables and internal state tables belonging to the given
+    /// table, for expanding a privilege grant on the table to all objects owned by it.
+    pub async fn list_expanded_table_ids(&self, table_id: TableId) -> MetaResult<Vec<TableId>> {
+        let inner = self.inner.read().await;
+        let mut expanded_table_ids: Vec<TableId> = Index::find()
+            .select_only()
+            .column(index::Column::IndexTableId)
+            .filter(index::Column::PrimaryTableId.eq(table_id))
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        let internal_table_ids: Vec<TableId> = Table::find()
+            .select_only()
+            .column(table::Column::TableId)
+            .filter(table::Column::BelongsToJobId.eq(table_id))
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        expanded_table_ids.extend(internal_table_ids);
+        Ok(expanded_table_ids)
+    }
+
     pub async fn list_view_ids(&self, schema_id: SchemaId) -> MetaResult<Vec<ViewId>> {
         let inner = self.inner.read().await;
         let view_ids: Vec<ViewId> = View::find()
@@ -2804,6 +4086,9 @@ impl CatalogController {
     pub async fn get_mv_depended_subscriptions(
         &self,
     ) -> MetaResult<HashMap<risingwave_common::catalog::TableId, HashMap<u32, u64>>> {
+        let params = self.env.system_params_reader().await;
+        let checkpoint_interval_ms =
+            params.barrier_interval_ms() as u64 * params.checkpoint_frequency();
         let inner = self.inner.read().await;
         let subscription_objs = Subscription::find()
             .find_also_related(Object)
@@ -2820,11 +4105,63 @@ impl CatalogController {
                 subscription.dependent_table_id,
             ))
             .or_insert(HashMap::new())
-            .insert(subscription.id, subscription.retention_seconds);
+            .insert(
+                subscription.id,
+                effective_subscription_retention_seconds(&subscription, checkpoint_interval_ms),
+            );
         }
         Ok(map)
     }
 
+    /// Record the max epoch that the consumer of a subscription has fully consumed, so
+    /// that log data at or below it can be truncated before the time-based retention
+    /// elapses. Only accepted for subscriptions created with
+    /// `retention_policy = 'consumer_ack'`.
+    pub async fn ack_subscription_epoch(
+        &self,
+        subscription_id: SubscriptionId,
+        acked_epoch: u64,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let (subscription, subscription_obj) = Subscription::find_by_id(subscription_id)
+            .find_also_related(Object)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("subscription", subscription_id))?;
+        if subscription.retention_policy != PbRetentionPolicy::ConsumerAck as i32 {
+            return Err(MetaError::invalid_parameter(format!(
+                "subscription {} is not created with retention_policy = 'consumer_ack'",
+                subscription_id
+            )));
+        }
+        if acked_epoch < subscription.acked_epoch as u64 {
+            return Err(MetaError::invalid_parameter(format!(
+                "acked epoch {} is less than the already acknowledged epoch {}",
+                acked_epoch, subscription.acked_epoch
+            )));
+        }
+
+        let mut subscription = subscription.into_active_model();
+        subscription.acked_epoch = Set(acked_epoch as _);
+        let subscription = subscription.update(&txn).await?;
+        txn.commit().await?;
+
+        let version = self
+            .notify_frontend(
+                NotificationOperation::Update,
+                NotificationInfo::RelationGroup(PbRelationGroup {
+                    relations: vec![PbRelation {
+                        relation_info: Some(PbRelationInfo::Subscription(
+                            ObjectModel(subscription, subscription_obj.unwrap()).into(),
+                        )),
+                    }],
+                }),
+            )
+            .await;
+        Ok(version)
+    }
+
     pub async fn find_creating_streaming_job_ids(
         &self,
         infos: Vec<PbCreatingJobInfo>,
@@ -2888,6 +4225,54 @@ impl CatalogController {
             .collect())
     }
 
+    /// Converts the given creating foreground materialized views to background jobs, so
+    /// that they keep backfilling after the creating session disconnects and survive
+    /// recovery instead of being cleaned up as dirty. Returns `(id, name, definition)` of
+    /// the jobs that were actually converted.
+    pub async fn convert_creating_jobs_to_background(
+        &self,
+        job_ids: Vec<ObjectId>,
+    ) -> MetaResult<Vec<(ObjectId, String, String)>> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+
+        let to_convert: Vec<(ObjectId, String, String)> = Table::find()
+            .select_only()
+            .columns([
+                table::Column::TableId,
+                table::Column::Name,
+                table::Column::Definition,
+            ])
+            .join(JoinType::InnerJoin, table::Relation::Object1.def())
+            .join(JoinType::InnerJoin, object::Relation::StreamingJob.def())
+            .filter(
+                table::Column::TableId
+                    .is_in(job_ids)
+                    .and(table::Column::TableType.eq(TableType::MaterializedView))
+                    .and(streaming_job::Column::JobStatus.eq(JobStatus::Creating))
+                    .and(streaming_job::Column::CreateType.eq(CreateType::Foreground)),
+            )
+            .into_tuple()
+            .all(&txn)
+            .await?;
+        if to_convert.is_empty() {
+            return Ok(vec![]);
+        }
+
+        for (job_id, _, _) in &to_convert {
+            streaming_job::ActiveModel {
+                job_id: Set(*job_id),
+                create_type: Set(CreateType::Background),
+                ..Default::default()
+            }
+            .update(&txn)
+            .await?;
+        }
+        txn.commit().await?;
+
+        Ok(to_convert)
+    }
+
     pub async fn list_connections(&self) -> MetaResult<Vec<PbConnection>> {
         let inner = self.inner.read().await;
         let conn_objs = Connection::find()
@@ -2964,6 +4349,24 @@ impl CatalogController {
             .collect())
     }
 
+    /// Returns the database each table (including internal state tables) belongs to,
+    /// keyed by table id.
+    pub async fn get_table_database_mapping(&self) -> MetaResult<HashMap<TableId, DatabaseId>> {
+        let inner = self.inner.read().await;
+        let table_databases: Vec<(TableId, Option<DatabaseId>)> = Table::find()
+            .select_only()
+            .column(table::Column::TableId)
+            .column(object::Column::DatabaseId)
+            .join(JoinType::InnerJoin, table::Relation::Object1.def())
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        Ok(table_databases
+            .into_iter()
+            .filter_map(|(table_id, database_id)| database_id.map(|id| (table_id, id)))
+            .collect())
+    }
+
     pub async fn get_table_by_cdc_table_id(
         &self,
         cdc_table_id: &String,
@@ -3315,12 +4718,33 @@ impl CatalogControllerInner {
         id: i32,
         sender: Sender<MetaResult<NotificationVersion>>,
     ) {
+        // Piggyback cleanup of notifiers whose waiter has gone away, so that abandoned
+        // entries don't linger until the job finishes.
+        self.cleanup_abandoned_finish_notifiers();
         self.creating_table_finish_notifier
             .entry(id)
             .or_default()
             .push(sender);
     }
 
+    /// Drops registered finish notifiers whose receiver side has been dropped, i.e. the
+    /// waiting frontend has disconnected or given up.
+    pub(crate) fn cleanup_abandoned_finish_notifiers(&mut self) {
+        self.creating_table_finish_notifier.retain(|_, txs| {
+            txs.retain(|tx| !tx.is_closed());
+            !txs.is_empty()
+        });
+    }
+
+    /// Returns the number of active DDL waiters per creating job, excluding abandoned ones.
+    pub(crate) fn count_ddl_waiters(&self) -> HashMap<ObjectId, usize> {
+        self.creating_table_finish_notifier
+            .iter()
+            .map(|(id, txs)| (*id, txs.iter().filter(|tx| !tx.is_closed()).count()))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
     pub(crate) async fn streaming_job_is_finished(&mut self, id: i32) -> MetaResult<bool> {
         let status = StreamingJob::find()
             .select_only()
@@ -3468,8 +4892,18 @@ mod tests {
             )),
             ..Default::default()
         };
-        mgr.create_function(pb_function.clone()).await?;
-        assert!(mgr.create_function(pb_function).await.is_err());
+        mgr.create_function(pb_function.clone(), false).await?;
+        assert!(mgr.create_function(pb_function.clone(), false).await.is_err());
+
+        // `or_replace` swaps the definition in place, keeping the function id.
+        let function_id = Function::find()
+            .one(&mgr.inner.read().await.db)
+            .await?
+            .unwrap()
+            .function_id;
+        let mut replacement = pb_function;
+        replacement.language = "javascript".to_string();
+        mgr.create_function(replacement, true).await?;
 
         let function = Function::find()
             .inner_join(Object)
@@ -3482,9 +4916,10 @@ mod tests {
             .one(&mgr.inner.read().await.db)
             .await?
             .unwrap();
+        assert_eq!(function.function_id, function_id);
         assert_eq!(function.return_type.to_protobuf(), test_data_type);
         assert_eq!(function.arg_types.to_protobuf().len(), 1);
-        assert_eq!(function.language, "python");
+        assert_eq!(function.language, "javascript");
 
         mgr.drop_function(function.function_id).await?;
         assert!(Object::find_by_id(function.function_id)
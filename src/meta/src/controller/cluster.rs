@@ -167,6 +167,7 @@ impl ClusterController {
     pub async fn delete_worker(&self, host_address: HostAddress) -> MetaResult<WorkerNode> {
         let mut inner = self.inner.write().await;
         let worker = inner.delete_worker(host_address).await?;
+        self.env.actor_liveness().remove_worker(worker.id);
         if worker.r#type() == PbWorkerType::ComputeNode {
             self.env
                 .notification_manager()
@@ -207,7 +208,11 @@ impl ClusterController {
         self.inner
             .write()
             .await
-            .heartbeat(worker_id, self.max_heartbeat_interval, info)
+            .heartbeat(worker_id, self.max_heartbeat_interval, info)?;
+        self.env
+            .actor_liveness()
+            .report_alive(worker_id as _, self.max_heartbeat_interval);
+        Ok(())
     }
 
     pub fn start_heartbeat_checker(
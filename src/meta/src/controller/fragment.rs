@@ -25,9 +25,9 @@ use risingwave_meta_model_v2::actor::ActorStatus;
 use risingwave_meta_model_v2::fragment::DistributionType;
 use risingwave_meta_model_v2::prelude::{Actor, ActorDispatcher, Fragment, Sink, StreamingJob};
 use risingwave_meta_model_v2::{
-    actor, actor_dispatcher, fragment, sink, streaming_job, ActorId, ActorUpstreamActors,
-    ConnectorSplits, ExprContext, FragmentId, I32Array, JobStatus, ObjectId, SinkId, SourceId,
-    StreamNode, StreamingParallelism, TableId, VnodeBitmap, WorkerId,
+    actor, actor_dispatcher, fragment, object, sink, streaming_job, ActorId, ActorUpstreamActors,
+    ConnectorSplits, DatabaseId, ExprContext, FragmentId, I32Array, JobStatus, ObjectId, SinkId,
+    SourceId, StreamNode, StreamingParallelism, TableId, VnodeBitmap, WorkerId,
 };
 use risingwave_pb::common::PbActorLocation;
 use risingwave_pb::meta::subscribe_response::{
@@ -712,6 +712,27 @@ impl CatalogController {
             .collect())
     }
 
+    /// Counts the running actors of each database, across all streaming jobs of the
+    /// database.
+    pub async fn count_actors_per_database(&self) -> MetaResult<HashMap<DatabaseId, usize>> {
+        let inner = self.inner.read().await;
+        let actor_cnt: Vec<(Option<DatabaseId>, i64)> = Actor::find()
+            .select_only()
+            .column(object::Column::DatabaseId)
+            .column_as(actor::Column::ActorId.count(), "count")
+            .join(JoinType::InnerJoin, actor::Relation::Fragment.def())
+            .join(JoinType::InnerJoin, fragment::Relation::Object.def())
+            .group_by(object::Column::DatabaseId)
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+
+        Ok(actor_cnt
+            .into_iter()
+            .filter_map(|(database_id, count)| database_id.map(|id| (id, count as usize)))
+            .collect())
+    }
+
     // TODO: This function is too heavy, we should avoid using it and implement others on demand.
     pub async fn table_fragments(&self) -> MetaResult<BTreeMap<ObjectId, PbTableFragments>> {
         let inner = self.inner.read().await;
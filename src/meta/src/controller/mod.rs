@@ -23,11 +23,13 @@ use risingwave_meta_model_v2::{
 use risingwave_pb::catalog::connection::PbInfo as PbConnectionInfo;
 use risingwave_pb::catalog::source::PbOptionalAssociatedTableId;
 use risingwave_pb::catalog::subscription::PbSubscriptionState;
-use risingwave_pb::catalog::table::{PbOptionalAssociatedSourceId, PbTableType};
+use risingwave_pb::catalog::table::{
+    PbOptionalAssociatedSourceId, PbSchemaChangePolicy, PbTableType,
+};
 use risingwave_pb::catalog::{
     PbConnection, PbCreateType, PbDatabase, PbFunction, PbHandleConflictBehavior, PbIndex,
-    PbSchema, PbSecret, PbSink, PbSinkType, PbSource, PbStreamJobStatus, PbSubscription, PbTable,
-    PbView,
+    PbSchema, PbSchemaRegistryPublishStatus, PbSecret, PbSink, PbSinkType, PbSource,
+    PbStreamJobPriority, PbStreamJobStatus, PbSubscription, PbTable, PbView,
 };
 use sea_orm::{DatabaseConnection, ModelTrait};
 
@@ -83,6 +85,9 @@ impl From<ObjectModel<database::Model>> for PbDatabase {
             id: value.0.database_id as _,
             name: value.0.name,
             owner: value.1.owner_id as _,
+            barrier_interval_ms: value.0.barrier_interval_ms.map(|i| i as _),
+            default_timezone: value.0.default_timezone,
+            default_collation: value.0.default_collation,
         }
     }
 }
@@ -96,6 +101,7 @@ impl From<ObjectModel<secret::Model>> for PbSecret {
             value: value.0.value,
             owner: value.1.owner_id as _,
             schema_id: value.1.schema_id.unwrap() as _,
+            version: value.0.version as _,
         }
     }
 }
@@ -107,6 +113,7 @@ impl From<ObjectModel<schema::Model>> for PbSchema {
             name: value.0.name,
             database_id: value.1.database_id.unwrap() as _,
             owner: value.1.owner_id as _,
+            default_owner_id: value.0.default_owner_id.map(|id| id as _),
         }
     }
 }
@@ -163,6 +170,28 @@ impl From<ObjectModel<table::Model>> for PbTable {
             created_at_cluster_version: value.1.created_at_cluster_version,
             retention_seconds: value.0.retention_seconds.map(|id| id as u32),
             cdc_table_id: value.0.cdc_table_id,
+            annotations: value
+                .0
+                .annotations
+                .map(|annotations| annotations.into_inner().into_iter().collect())
+                .unwrap_or_default(),
+            soft_dropped_at_ms: value.0.soft_dropped_at_ms.map(|ts| ts as _),
+            storage_class_policy: value.0.storage_class_policy.map(|p| p.to_protobuf()),
+            schema_change_policy: value
+                .0
+                .schema_change_policy
+                .map(|p| PbSchemaChangePolicy::from(p) as i32)
+                .unwrap_or_default(),
+            labels: value
+                .0
+                .labels
+                .map(|labels| labels.into_inner().into_iter().collect())
+                .unwrap_or_default(),
+            priority: value
+                .0
+                .priority
+                .map(|p| PbStreamJobPriority::from(p) as i32)
+                .unwrap_or_default(),
         }
     }
 }
@@ -203,6 +232,7 @@ impl From<ObjectModel<source::Model>> for PbSource {
             created_at_cluster_version: value.1.created_at_cluster_version,
             secret_refs: secret_ref_map,
             rate_limit: value.0.rate_limit.map(|v| v as _),
+            description: None,
         }
     }
 }
@@ -237,6 +267,8 @@ impl From<ObjectModel<sink::Model>> for PbSink {
             db_name: value.0.db_name,
             sink_from_name: value.0.sink_from_name,
             stream_job_status: PbStreamJobStatus::Created as _,
+            // The publish status is not persisted in the SQL backend yet.
+            schema_registry_publish_status: PbSchemaRegistryPublishStatus::Unspecified as _,
             format_desc: value.0.sink_format_desc.map(|desc| desc.to_protobuf()),
             target_table: value.0.target_table.map(|id| id as _),
             initialized_at_cluster_version: value.1.initialized_at_cluster_version,
@@ -248,6 +280,11 @@ impl From<ObjectModel<sink::Model>> for PbSink {
                 .original_target_columns
                 .map(|cols| cols.to_protobuf())
                 .unwrap_or_default(),
+            labels: value
+                .0
+                .labels
+                .map(|labels| labels.into_inner())
+                .unwrap_or_default(),
         }
     }
 }
@@ -272,6 +309,9 @@ impl From<ObjectModel<subscription::Model>> for PbSubscription {
             created_at_cluster_version: value.1.created_at_cluster_version,
             dependent_table_id: value.0.dependent_table_id as _,
             subscription_state: PbSubscriptionState::Init as _,
+            retention_policy: value.0.retention_policy,
+            retention_epoch_count: value.0.retention_epoch_count as _,
+            acked_epoch: value.0.acked_epoch as _,
         }
     }
 }
@@ -318,6 +358,7 @@ impl From<ObjectModel<view::Model>> for PbView {
             sql: value.0.definition,
             dependent_relations: vec![], // todo: deprecate it.
             columns: value.0.columns.to_protobuf(),
+            invalidated_reason: value.0.invalidated_reason,
         }
     }
 }
@@ -333,6 +374,7 @@ impl From<ObjectModel<connection::Model>> for PbConnection {
             info: Some(PbConnectionInfo::PrivateLinkService(
                 value.0.info.to_protobuf(),
             )),
+            ..Default::default()
         }
     }
 }
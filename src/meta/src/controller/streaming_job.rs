@@ -24,7 +24,7 @@ use risingwave_meta_model_v2::actor_dispatcher::DispatcherType;
 use risingwave_meta_model_v2::object::ObjectType;
 use risingwave_meta_model_v2::prelude::{
     Actor, ActorDispatcher, Fragment, Index, Object, ObjectDependency, Sink, Source,
-    StreamingJob as StreamingJobModel, Table,
+    StreamingJob as StreamingJobModel, Table, View,
 };
 use risingwave_meta_model_v2::table::TableType;
 use risingwave_meta_model_v2::{
@@ -68,7 +68,7 @@ use crate::controller::utils::{
     rebuild_fragment_mapping_from_actors, PartialObject,
 };
 use crate::controller::ObjectModel;
-use crate::manager::{NotificationVersion, SinkId, StreamingJob};
+use crate::manager::{replace_table_incompatibility, NotificationVersion, SinkId, StreamingJob};
 use crate::model::{StreamContext, TableParallelism};
 use crate::stream::SplitAssignment;
 use crate::{MetaError, MetaResult};
@@ -1129,6 +1129,41 @@ impl CatalogController {
 
         // 4. update catalogs and notify.
         let mut relations = vec![];
+
+        // Views read the table by column name at query time, so an incompatible change
+        // breaks them silently. Mark them invalid so that the frontend rejects queries
+        // on them until they are recreated.
+        if let Some(reason) = replace_table_incompatibility(
+            &original_table_catalogs.to_protobuf(),
+            &table.columns.to_protobuf(),
+        ) {
+            let reason = format!("{} of table \"{}\"", reason, table.name);
+            let dependent_ids: Vec<ObjectId> = ObjectDependency::find()
+                .select_only()
+                .column(object_dependency::Column::UsedBy)
+                .filter(object_dependency::Column::Oid.eq(job_id))
+                .into_tuple()
+                .all(txn)
+                .await?;
+            for dependent_id in dependent_ids {
+                let Some(view) = View::find_by_id(dependent_id).one(txn).await? else {
+                    continue;
+                };
+                let mut view = view.into_active_model();
+                view.invalidated_reason = Set(Some(reason.clone()));
+                let view = view.update(txn).await?;
+                let view_obj = Object::find_by_id(dependent_id)
+                    .one(txn)
+                    .await?
+                    .ok_or_else(|| MetaError::catalog_id_not_found("object", dependent_id))?;
+                relations.push(PbRelation {
+                    relation_info: Some(PbRelationInfo::View(
+                        ObjectModel(view, view_obj).into(),
+                    )),
+                });
+            }
+        }
+
         let table_obj = table
             .find_related(Object)
             .one(txn)
@@ -1378,6 +1413,171 @@ impl CatalogController {
         Ok(fragment_actors)
     }
 
+    // edit the `rate_limit` of the `Sink` node in given `sink_id`'s fragments
+    // return the actor_ids to be applied
+    pub async fn update_sink_rate_limit_by_sink_id(
+        &self,
+        sink_id: ObjectId,
+        rate_limit: Option<u32>,
+    ) -> MetaResult<HashMap<FragmentId, Vec<ActorId>>> {
+        let inner = self.inner.read().await;
+        let txn = inner.db.begin().await?;
+
+        let fragments: Vec<(FragmentId, i32, StreamNode)> = Fragment::find()
+            .select_only()
+            .columns([
+                fragment::Column::FragmentId,
+                fragment::Column::FragmentTypeMask,
+                fragment::Column::StreamNode,
+            ])
+            .filter(fragment::Column::JobId.eq(sink_id))
+            .into_tuple()
+            .all(&txn)
+            .await?;
+        let mut fragments = fragments
+            .into_iter()
+            .map(|(id, mask, stream_node)| (id, mask, stream_node.to_protobuf()))
+            .collect_vec();
+
+        fragments.retain_mut(|(_, fragment_type_mask, stream_node)| {
+            let mut found = false;
+            if *fragment_type_mask & PbFragmentTypeFlag::Sink as i32 != 0 {
+                visit_stream_node(stream_node, |node| {
+                    if let PbNodeBody::Sink(node) = node
+                        && let Some(sink_desc) = node.sink_desc.as_mut()
+                        && sink_desc.id == sink_id as u32
+                    {
+                        sink_desc.rate_limit = rate_limit;
+                        found = true;
+                    }
+                });
+            }
+            found
+        });
+
+        if fragments.is_empty() {
+            return Err(MetaError::invalid_parameter(format!(
+                "sink node not found in job id {sink_id}"
+            )));
+        }
+        let fragment_ids = fragments.iter().map(|(id, _, _)| *id).collect_vec();
+        for (id, _, stream_node) in fragments {
+            fragment::ActiveModel {
+                fragment_id: Set(id),
+                stream_node: Set(StreamNode::from(&stream_node)),
+                ..Default::default()
+            }
+            .update(&txn)
+            .await?;
+        }
+        let fragment_actors = get_fragment_actor_ids(&txn, fragment_ids).await?;
+
+        txn.commit().await?;
+
+        Ok(fragment_actors)
+    }
+
+    /// Collect the persisted rate limits of the job's source and backfill actors, keyed by
+    /// fragment. Used to freeze and unfreeze a single streaming job without clobbering the
+    /// rate limits configured by the user.
+    pub async fn get_mv_rate_limit_by_job_id(
+        &self,
+        job_id: ObjectId,
+    ) -> MetaResult<HashMap<FragmentId, HashMap<ActorId, Option<u32>>>> {
+        let inner = self.inner.read().await;
+
+        let fragments: Vec<(FragmentId, i32, StreamNode)> = Fragment::find()
+            .select_only()
+            .columns([
+                fragment::Column::FragmentId,
+                fragment::Column::FragmentTypeMask,
+                fragment::Column::StreamNode,
+            ])
+            .filter(fragment::Column::JobId.eq(job_id))
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+
+        let mut fragment_rate_limits = HashMap::new();
+        for (fragment_id, fragment_type_mask, stream_node) in fragments {
+            if (fragment_type_mask & PbFragmentTypeFlag::StreamScan as i32 == 0)
+                && (fragment_type_mask & PbFragmentTypeFlag::Source as i32 == 0)
+            {
+                continue;
+            }
+            let mut stream_node = stream_node.to_protobuf();
+            let mut rate_limit = None;
+            let mut found = false;
+            visit_stream_node(&mut stream_node, |node| match node {
+                PbNodeBody::StreamCdcScan(node) => {
+                    rate_limit = node.rate_limit;
+                    found = true;
+                }
+                PbNodeBody::StreamScan(node) => {
+                    rate_limit = node.rate_limit;
+                    found = true;
+                }
+                PbNodeBody::Source(node) => {
+                    if let Some(inner) = node.source_inner.as_ref() {
+                        rate_limit = inner.rate_limit;
+                        found = true;
+                    }
+                }
+                _ => {}
+            });
+            if found {
+                fragment_rate_limits.insert(fragment_id, rate_limit);
+            }
+        }
+
+        let fragment_actors =
+            get_fragment_actor_ids(&inner.db, fragment_rate_limits.keys().cloned().collect())
+                .await?;
+        Ok(fragment_rate_limits
+            .into_iter()
+            .map(|(fragment_id, rate_limit)| {
+                let actors = fragment_actors
+                    .get(&fragment_id)
+                    .map(|actors| {
+                        actors
+                            .iter()
+                            .map(|actor_id| (*actor_id, rate_limit))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                (fragment_id, actors)
+            })
+            .collect())
+    }
+
+    /// Whether zeroing the job's rate limits actually freezes it. Stream scan executors only
+    /// honor `Throttle` while their backfill loop is running, so a job that has finished
+    /// creating can only be paused if none of its fragments is a backfill fragment; source
+    /// executors honor rate limits for their whole lifetime.
+    pub async fn is_job_pausable_by_rate_limit(&self, job_id: ObjectId) -> MetaResult<bool> {
+        let inner = self.inner.read().await;
+        let job_status: JobStatus = StreamingJobModel::find_by_id(job_id)
+            .select_only()
+            .column(streaming_job::Column::JobStatus)
+            .into_tuple()
+            .one(&inner.db)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("streaming job", job_id))?;
+        if job_status != JobStatus::Created {
+            return Ok(true);
+        }
+        let fragment_type_masks: Vec<i32> = Fragment::find()
+            .select_only()
+            .column(fragment::Column::FragmentTypeMask)
+            .filter(fragment::Column::JobId.eq(job_id))
+            .into_tuple()
+            .all(&inner.db)
+            .await?;
+        Ok(fragment_type_masks
+            .iter()
+            .all(|mask| mask & PbFragmentTypeFlag::StreamScan as i32 == 0))
+    }
+
     pub async fn post_apply_reschedules(
         &self,
         reschedules: HashMap<FragmentId, Reschedule>,
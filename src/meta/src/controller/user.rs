@@ -16,9 +16,12 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 use risingwave_common::catalog::{DEFAULT_SUPER_USER, DEFAULT_SUPER_USER_FOR_PG};
-use risingwave_meta_model_v2::prelude::{Object, User, UserPrivilege};
+use risingwave_meta_model_v2::prelude::{Object, Schema, User, UserPrivilege};
 use risingwave_meta_model_v2::user_privilege::Action;
-use risingwave_meta_model_v2::{object, user, user_privilege, AuthInfo, PrivilegeId, UserId};
+use risingwave_meta_model_v2::{
+    object, schema, user, user_privilege, AuthInfo, I32Array, PrivilegeId, UserId,
+};
+use risingwave_pb::ddl_service::PbDdlAuditLog;
 use risingwave_pb::meta::subscribe_response::{
     Info as NotificationInfo, Operation as NotificationOperation,
 };
@@ -30,14 +33,17 @@ use sea_orm::{
     ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, PaginatorTrait, QueryFilter,
     QuerySelect, TransactionTrait,
 };
+use thiserror_ext::AsReport;
 
 use crate::controller::catalog::CatalogController;
 use crate::controller::utils::{
     check_user_name_duplicate, ensure_privileges_not_referred, ensure_user_id,
     extract_grant_obj_id, get_object_owner, get_referring_privileges_cascade, get_user_privilege,
-    list_user_info_by_ids, PartialUserPrivilege,
+    list_role_closure, list_user_info_by_ids, PartialUserPrivilege,
+};
+use crate::manager::{
+    check_dangerous_grant, DdlAuditManager, NotificationVersion, IGNORED_NOTIFICATION_VERSION,
 };
-use crate::manager::{NotificationVersion, IGNORED_NOTIFICATION_VERSION};
 use crate::{MetaError, MetaResult};
 
 impl CatalogController {
@@ -196,6 +202,36 @@ impl CatalogController {
             )));
         }
 
+        // check if the user is still granted as a role to other users.
+        let granted_roles: Vec<Option<I32Array>> = User::find()
+            .select_only()
+            .column(user::Column::GrantedRoles)
+            .into_tuple()
+            .all(&txn)
+            .await?;
+        if granted_roles
+            .iter()
+            .flatten()
+            .any(|roles| roles.inner_ref().contains(&user_id))
+        {
+            return Err(MetaError::permission_denied(format!(
+                "drop user {} is not allowed, because it is still granted as a role to other users",
+                user.name
+            )));
+        }
+
+        // check if the user is the default owner of any schemas.
+        let count = Schema::find()
+            .filter(schema::Column::DefaultOwnerId.eq(user_id))
+            .count(&txn)
+            .await?;
+        if count != 0 {
+            return Err(MetaError::permission_denied(format!(
+                "drop user {} is not allowed, because it is the default owner of {} schemas",
+                user.name, count
+            )));
+        }
+
         let res = User::delete_by_id(user_id).exec(&txn).await?;
         if res.rows_affected != 1 {
             return Err(MetaError::catalog_id_not_found("user", user_id));
@@ -246,13 +282,26 @@ impl CatalogController {
             .one(&txn)
             .await?
             .ok_or_else(|| MetaError::catalog_id_not_found("user", grantor))?;
+        let grantable_user_count = User::find()
+            .filter(user::Column::IsSuper.eq(false))
+            .count(&txn)
+            .await? as usize;
+        let overridden_warning = check_dangerous_grant(
+            &self.env.opts,
+            &user.name,
+            user_ids.len(),
+            grantable_user_count,
+            new_grant_privileges,
+        )?;
         if !user.is_super {
+            // Privileges inherited from the grantor's roles also qualify for granting.
+            let grantor_closure = list_role_closure(grantor, &txn).await?;
             for privilege in &mut privileges {
                 if grantor == get_object_owner(*privilege.oid.as_ref(), &txn).await? {
                     continue;
                 }
                 let filter = user_privilege::Column::UserId
-                    .eq(grantor)
+                    .is_in(grantor_closure.clone())
                     .and(user_privilege::Column::Oid.eq(*privilege.oid.as_ref()))
                     .and(user_privilege::Column::Action.eq(privilege.action.as_ref().clone()))
                     .and(user_privilege::Column::WithGrantOption.eq(true));
@@ -309,6 +358,136 @@ impl CatalogController {
 
         txn.commit().await?;
 
+        let version = self.notify_users_update(user_infos).await;
+
+        if let Some(warning) = overridden_warning {
+            let audit_entry = PbDdlAuditLog {
+                command: "GRANT".into(),
+                user_id: grantor as _,
+                definition: warning,
+                success: true,
+                ..Default::default()
+            };
+            if let Err(err) = DdlAuditManager::new(self.env.clone())
+                .record(audit_entry)
+                .await
+            {
+                tracing::warn!(
+                    error = %err.as_report(),
+                    "failed to record dangerous grant audit log"
+                );
+            }
+        }
+
+        Ok(version)
+    }
+
+    /// Makes `user_ids` members of the roles in `role_ids`. Members inherit the roles'
+    /// privileges, resolved transitively, when access is checked.
+    pub async fn grant_role(
+        &self,
+        role_ids: Vec<UserId>,
+        user_ids: Vec<UserId>,
+        granted_by: UserId,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let grantor = User::find_by_id(granted_by)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("user", granted_by))?;
+        if !grantor.is_super && !grantor.can_create_user {
+            return Err(MetaError::permission_denied(format!(
+                "user {} does not have the privilege to grant roles",
+                grantor.name
+            )));
+        }
+        for role_id in &role_ids {
+            ensure_user_id(*role_id, &txn).await?;
+            // Granting role R to user U would form a cycle iff U is already
+            // (transitively) a role of R.
+            let closure = list_role_closure(*role_id, &txn).await?;
+            if let Some(user_id) = user_ids.iter().find(|id| closure.contains(id)) {
+                return Err(MetaError::permission_denied(format!(
+                    "cannot grant role {} to user {} because it would create a membership cycle",
+                    role_id, user_id
+                )));
+            }
+        }
+
+        for user_id in &user_ids {
+            let user = User::find_by_id(*user_id)
+                .one(&txn)
+                .await?
+                .ok_or_else(|| MetaError::catalog_id_not_found("user", user_id))?;
+            let mut granted_roles = user
+                .granted_roles
+                .clone()
+                .map(|roles| roles.inner_ref().clone())
+                .unwrap_or_default();
+            let mut changed = false;
+            for role_id in &role_ids {
+                if !granted_roles.contains(role_id) {
+                    granted_roles.push(*role_id);
+                    changed = true;
+                }
+            }
+            if changed {
+                let mut user = user.into_active_model();
+                user.granted_roles = Set(Some(I32Array(granted_roles)));
+                user.update(&txn).await?;
+            }
+        }
+
+        let user_infos = list_user_info_by_ids(user_ids, &txn).await?;
+        txn.commit().await?;
+
+        let version = self.notify_users_update(user_infos).await;
+        Ok(version)
+    }
+
+    /// Removes `user_ids` from the roles in `role_ids`. Missing memberships are ignored.
+    pub async fn revoke_role(
+        &self,
+        role_ids: Vec<UserId>,
+        user_ids: Vec<UserId>,
+        revoke_by: UserId,
+    ) -> MetaResult<NotificationVersion> {
+        let inner = self.inner.write().await;
+        let txn = inner.db.begin().await?;
+        let revoker = User::find_by_id(revoke_by)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("user", revoke_by))?;
+        if !revoker.is_super && !revoker.can_create_user {
+            return Err(MetaError::permission_denied(format!(
+                "user {} does not have the privilege to revoke roles",
+                revoker.name
+            )));
+        }
+
+        for user_id in &user_ids {
+            let user = User::find_by_id(*user_id)
+                .one(&txn)
+                .await?
+                .ok_or_else(|| MetaError::catalog_id_not_found("user", user_id))?;
+            let mut granted_roles = user
+                .granted_roles
+                .clone()
+                .map(|roles| roles.inner_ref().clone())
+                .unwrap_or_default();
+            let prev_len = granted_roles.len();
+            granted_roles.retain(|role_id| !role_ids.contains(role_id));
+            if granted_roles.len() != prev_len {
+                let mut user = user.into_active_model();
+                user.granted_roles = Set(Some(I32Array(granted_roles)));
+                user.update(&txn).await?;
+            }
+        }
+
+        let user_infos = list_user_info_by_ids(user_ids, &txn).await?;
+        txn.commit().await?;
+
         let version = self.notify_users_update(user_infos).await;
         Ok(version)
     }
@@ -348,6 +348,34 @@ where
     Ok(())
 }
 
+/// `list_role_closure` collects `user_id` together with the ids of its granted roles,
+/// resolved transitively. Used to take role-inherited privileges into account.
+pub async fn list_role_closure<C>(user_id: UserId, db: &C) -> MetaResult<Vec<UserId>>
+where
+    C: ConnectionTrait,
+{
+    let mut closure = vec![user_id];
+    let mut visited: HashSet<UserId> = HashSet::from([user_id]);
+    let mut frontier = vec![user_id];
+    while !frontier.is_empty() {
+        let granted_roles: Vec<Option<I32Array>> = User::find()
+            .select_only()
+            .column(user::Column::GrantedRoles)
+            .filter(user::Column::UserId.is_in(frontier.clone()))
+            .into_tuple()
+            .all(db)
+            .await?;
+        frontier = granted_roles
+            .into_iter()
+            .flatten()
+            .flat_map(|roles| roles.inner_ref().clone())
+            .filter(|role_id| visited.insert(*role_id))
+            .collect();
+        closure.extend(frontier.iter().copied());
+    }
+    Ok(closure)
+}
+
 /// `check_database_name_duplicate` checks whether the database name is already used in the cluster.
 pub async fn check_database_name_duplicate<C>(name: &str, db: &C) -> MetaResult<()>
 where
@@ -793,7 +821,7 @@ where
                 ObjectType::Function => PbObject::FunctionId(oid),
                 ObjectType::Connection => unreachable!("connection is not supported yet"),
                 ObjectType::Subscription => PbObject::SubscriptionId(oid),
-                ObjectType::Secret => unreachable!("secret is not supported yet"),
+                ObjectType::Secret => PbObject::SecretId(oid),
             };
             PbGrantPrivilege {
                 action_with_opts: vec![PbActionWithGrantOption {
@@ -817,7 +845,8 @@ pub fn extract_grant_obj_id(object: &PbObject) -> ObjectId {
         | PbObject::SinkId(id)
         | PbObject::ViewId(id)
         | PbObject::FunctionId(id)
-        | PbObject::SubscriptionId(id) => *id as _,
+        | PbObject::SubscriptionId(id)
+        | PbObject::SecretId(id) => *id as _,
         _ => unreachable!("invalid object type: {:?}", object),
     }
 }
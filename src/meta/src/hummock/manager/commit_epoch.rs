@@ -311,6 +311,15 @@ impl HummockManager {
 
         drop(versioning_guard);
 
+        // Notify external subscribers (e.g. serving-layer result caches) that the committed
+        // tables have advanced to the new epoch.
+        self.env.table_change_notifier().notify(
+            &tables_to_commit
+                .iter()
+                .map(|table_id| (table_id.table_id, committed_epoch))
+                .collect(),
+        );
+
         // Don't trigger compactions if we enable deterministic compaction
         if !self.env.opts.compaction_deterministic_test {
             // commit_epoch may contains SSTs from any compaction group
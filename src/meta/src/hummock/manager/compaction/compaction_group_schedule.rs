@@ -17,6 +17,7 @@ use std::ops::DerefMut;
 
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
+use risingwave_common::system_param::reader::SystemParamsRead;
 use risingwave_hummock_sdk::compact_task::ReportTask;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::TableGroupInfo;
 use risingwave_hummock_sdk::compaction_group::{StateTableId, StaticCompactionGroupId};
@@ -24,6 +25,7 @@ use risingwave_hummock_sdk::version::{GroupDelta, GroupDeltas};
 use risingwave_hummock_sdk::{can_concat, CompactionGroupId};
 use risingwave_pb::hummock::compact_task::TaskStatus;
 use risingwave_pb::hummock::{PbGroupMerge, PbStateTableInfoDelta};
+use risingwave_pb::meta::event_log;
 use thiserror_ext::AsReport;
 
 use crate::hummock::error::{Error, Result};
@@ -356,4 +358,126 @@ impl HummockManager {
             }
         }
     }
+
+    /// Size-based split/merge policy over compaction groups, controlled by the
+    /// `compaction_group_auto_split_size_mb` and `compaction_group_auto_merge_size_mb`
+    /// system params (0 disables the respective half).
+    ///
+    /// A state table whose size exceeds the split threshold is moved out of its shared
+    /// group into a dedicated compaction group, so a single large materialized view does
+    /// not dominate the compaction of its neighbours. Groups that have shrunk below the
+    /// merge threshold are merged back together to keep the number of small groups in
+    /// check. At most one table per group is split and one pair of groups is merged per
+    /// round, so every decision is made on fresh statistics. Each decision is recorded
+    /// in the event log.
+    pub async fn schedule_auto_group_split_merge(&self) {
+        let params = self.env.system_params_reader().await;
+        let split_size_bytes = params.compaction_group_auto_split_size_mb() * 1024 * 1024;
+        let merge_size_bytes = params.compaction_group_auto_merge_size_mb() * 1024 * 1024;
+        if split_size_bytes == 0 && merge_size_bytes == 0 {
+            return;
+        }
+        let mut group_infos = self.calculate_compaction_group_statistic().await;
+        group_infos.sort_by_key(|group| group.group_id);
+
+        if split_size_bytes > 0 {
+            for group in &group_infos {
+                if group.table_statistic.len() <= 1 {
+                    // Already a dedicated group.
+                    continue;
+                }
+                // Split out the largest table exceeding the threshold, if any.
+                let Some((table_id, table_size)) = group
+                    .table_statistic
+                    .iter()
+                    .filter(|(_, table_size)| **table_size >= split_size_bytes)
+                    .max_by_key(|(table_id, table_size)| (**table_size, **table_id))
+                else {
+                    continue;
+                };
+                match self
+                    .split_compaction_group(
+                        group.group_id,
+                        &[*table_id],
+                        self.env.opts.partition_vnode_count,
+                    )
+                    .await
+                {
+                    Ok(new_group_id) => {
+                        tracing::info!(
+                            "auto-split state table [{}] ({} bytes) from group-{} to group-{}",
+                            table_id,
+                            table_size,
+                            group.group_id,
+                            new_group_id,
+                        );
+                        self.env.event_log_manager_ref().add_event_logs(vec![
+                            event_log::Event::CompactionGroupSchedule(
+                                event_log::EventCompactionGroupSchedule {
+                                    kind: "SPLIT".into(),
+                                    group_id: group.group_id,
+                                    other_group_id: new_group_id,
+                                    table_id: *table_id,
+                                    size_bytes: *table_size,
+                                },
+                            ),
+                        ]);
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            error = %e.as_report(),
+                            "failed to auto-split state table [{}] from group-{}",
+                            table_id,
+                            group.group_id,
+                        );
+                    }
+                }
+            }
+        }
+
+        if merge_size_bytes > 0 {
+            let small_groups = group_infos
+                .iter()
+                .filter(|group| group.group_size > 0 && group.group_size < merge_size_bytes)
+                .collect_vec();
+            // Groups are sorted by id, hence by member table id range; only adjacent
+            // groups can have mergeable (non-overlapping, consecutive) ranges.
+            for (left, right) in small_groups.iter().tuple_windows() {
+                match self
+                    .merge_compaction_group(left.group_id, right.group_id)
+                    .await
+                {
+                    Ok(()) => {
+                        let size_bytes = left.group_size + right.group_size;
+                        tracing::info!(
+                            "auto-merged group-{} and group-{} ({} bytes combined)",
+                            left.group_id,
+                            right.group_id,
+                            size_bytes,
+                        );
+                        self.env.event_log_manager_ref().add_event_logs(vec![
+                            event_log::Event::CompactionGroupSchedule(
+                                event_log::EventCompactionGroupSchedule {
+                                    kind: "MERGE".into(),
+                                    group_id: left.group_id,
+                                    other_group_id: right.group_id,
+                                    table_id: 0,
+                                    size_bytes,
+                                },
+                            ),
+                        ]);
+                        return;
+                    }
+                    Err(e) => {
+                        tracing::debug!(
+                            error = %e.as_report(),
+                            "group-{} and group-{} are not mergeable, skipping",
+                            left.group_id,
+                            right.group_id,
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
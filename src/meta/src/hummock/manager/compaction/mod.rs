@@ -118,6 +118,26 @@ static CANCEL_STATUS_SET: LazyLock<HashSet<TaskStatus>> = LazyLock::new(|| {
 
 type CompactionRequestChannelItem = (CompactionGroupId, compact_task::TaskType);
 
+/// Compaction groups whose pick order is adjusted for in-flight backfills. Groups serving
+/// the upstream tables of large backfills are deprioritized until the backfills complete,
+/// so that compaction does not compete with backfill reads for compactor and IO
+/// resources, while groups serving the upstream tables of high-priority creating jobs get
+/// compactor slots first. Maintained by the barrier manager via
+/// [`HummockManager::update_backfill_compaction_groups`].
+#[derive(Default)]
+pub(super) struct BackfillCompactionGroups {
+    /// Upstream table ids of the in-flight large backfills, as last reported by the
+    /// barrier manager. Kept to skip re-resolving groups when the sets are unchanged.
+    deprioritized_upstream_table_ids: HashSet<u32>,
+    /// Upstream table ids of in-flight backfills of high-priority creating jobs.
+    prioritized_upstream_table_ids: HashSet<u32>,
+    /// Compaction groups resolved from `deprioritized_upstream_table_ids`.
+    deprioritized_group_ids: HashSet<CompactionGroupId>,
+    /// Compaction groups resolved from `prioritized_upstream_table_ids`. Takes
+    /// precedence over deprioritization when a group serves both.
+    prioritized_group_ids: HashSet<CompactionGroupId>,
+}
+
 fn init_selectors() -> HashMap<compact_task::TaskType, Box<dyn CompactionSelector>> {
     let mut compaction_selectors: HashMap<compact_task::TaskType, Box<dyn CompactionSelector>> =
         HashMap::default();
@@ -547,11 +567,86 @@ impl HummockManager {
             .unwrap();
     }
 
+    /// Adjusts the compaction pick order for the groups serving the upstream tables of
+    /// in-flight backfills: the groups under `deprioritized_upstream_table_ids` (large
+    /// backfills) are picked last, and the groups under
+    /// `prioritized_upstream_table_ids` (backfills of high-priority jobs) are picked
+    /// first. Driven by the barrier manager as backfills start and finish; empty sets
+    /// restore the default order.
+    pub async fn update_backfill_compaction_groups(
+        &self,
+        deprioritized_upstream_table_ids: HashSet<u32>,
+        prioritized_upstream_table_ids: HashSet<u32>,
+    ) {
+        {
+            let guard = self.backfill_compaction_groups.read();
+            if guard.deprioritized_upstream_table_ids == deprioritized_upstream_table_ids
+                && guard.prioritized_upstream_table_ids == prioritized_upstream_table_ids
+            {
+                return;
+            }
+        }
+        let (deprioritized_group_ids, prioritized_group_ids) = {
+            let versioning = self.versioning.read().await;
+            let mapping = versioning
+                .current_version
+                .state_table_info
+                .build_table_compaction_group_id();
+            let resolve = |table_ids: &HashSet<u32>| {
+                table_ids
+                    .iter()
+                    .filter_map(|table_id| mapping.get(&(*table_id).into()).copied())
+                    .collect::<HashSet<CompactionGroupId>>()
+            };
+            (
+                resolve(&deprioritized_upstream_table_ids),
+                resolve(&prioritized_upstream_table_ids),
+            )
+        };
+        let mut guard = self.backfill_compaction_groups.write();
+        if guard.deprioritized_group_ids != deprioritized_group_ids
+            || guard.prioritized_group_ids != prioritized_group_ids
+        {
+            tracing::info!(
+                ?deprioritized_group_ids,
+                ?prioritized_group_ids,
+                "update compaction pick order for in-flight backfills"
+            );
+        }
+        *guard = BackfillCompactionGroups {
+            deprioritized_upstream_table_ids,
+            prioritized_upstream_table_ids,
+            deprioritized_group_ids,
+            prioritized_group_ids,
+        };
+    }
+
+    /// Reorders the pick order so that groups serving high-priority backfills get
+    /// compactor slots first and deprioritized groups only get them when no other group
+    /// needs compaction. The sort is stable, so the shuffled order is preserved within
+    /// each class.
+    fn reorder_backfill_groups(&self, compaction_group_ids: &mut [CompactionGroupId]) {
+        let guard = self.backfill_compaction_groups.read();
+        if guard.prioritized_group_ids.is_empty() && guard.deprioritized_group_ids.is_empty() {
+            return;
+        }
+        compaction_group_ids.sort_by_key(|cg_id| {
+            if guard.prioritized_group_ids.contains(cg_id) {
+                0
+            } else if guard.deprioritized_group_ids.contains(cg_id) {
+                2
+            } else {
+                1
+            }
+        });
+    }
+
     pub async fn auto_pick_compaction_group_and_type(
         &self,
     ) -> Option<(CompactionGroupId, compact_task::TaskType)> {
         let mut compaction_group_ids = self.compaction_group_ids().await;
         compaction_group_ids.shuffle(&mut thread_rng());
+        self.reorder_backfill_groups(&mut compaction_group_ids);
 
         for cg_id in compaction_group_ids {
             if let Some(pick_type) = self.compaction_state.auto_pick_type(cg_id) {
@@ -569,6 +664,7 @@ impl HummockManager {
     ) -> (Vec<CompactionGroupId>, compact_task::TaskType) {
         let mut compaction_group_ids = self.compaction_group_ids().await;
         compaction_group_ids.shuffle(&mut thread_rng());
+        self.reorder_backfill_groups(&mut compaction_group_ids);
 
         let mut normal_groups = vec![];
         for cg_id in compaction_group_ids {
@@ -500,6 +500,22 @@ impl HummockManager {
         safe_point
     }
 
+    /// Registers a safe point at the given version id, capped at the current version.
+    /// Unlike [`Self::register_safe_point`], this allows pinning a historical version,
+    /// e.g. the one recorded by a named checkpoint. Best-effort: objects of the version
+    /// that have already been vacuumed are not brought back.
+    pub async fn register_safe_point_at(&self, id: HummockVersionId) -> HummockVersionSafePoint {
+        let versioning = self.versioning.read().await;
+        let mut wl = self.context_info.write().await;
+        let safe_point = HummockVersionSafePoint {
+            id: id.min(versioning.current_version.id),
+            event_sender: self.event_sender.clone(),
+        };
+        wl.version_safe_points.push(safe_point.id);
+        trigger_safepoint_stat(&self.metrics, &wl.version_safe_points);
+        safe_point
+    }
+
     pub async fn unregister_safe_point(&self, safe_point: HummockVersionId) {
         let mut wl = self.context_info.write().await;
         let version_safe_points = &mut wl.version_safe_points;
@@ -59,6 +59,7 @@ use versioning::*;
 pub(crate) mod checkpoint;
 mod commit_epoch;
 mod compaction;
+mod retention;
 pub mod sequence;
 pub mod time_travel;
 mod timer_task;
@@ -69,6 +70,7 @@ mod worker;
 pub use commit_epoch::{CommitEpochInfo, NewTableFragmentInfo};
 use compaction::*;
 pub use compaction::{check_cg_write_limit, WriteLimitType};
+pub use retention::{RetentionBlocker, TableRetention};
 pub(crate) use utils::*;
 
 type Snapshot = ArcSwap<HummockSnapshot>;
@@ -113,6 +115,10 @@ pub struct HummockManager {
     // `compaction_state` will record the types of compact tasks that can be triggered in `hummock`
     // and suggest types with a certain priority.
     pub compaction_state: CompactionState,
+
+    // Compaction groups whose pick order is adjusted while backfills read from them,
+    // maintained by the barrier manager.
+    backfill_compaction_groups: parking_lot::RwLock<BackfillCompactionGroups>,
 }
 
 pub type HummockManagerRef = Arc<HummockManager>;
@@ -285,6 +291,7 @@ impl HummockManager {
             history_table_throughput: parking_lot::RwLock::new(HashMap::default()),
             compactor_streams_change_tx,
             compaction_state: CompactionState::new(),
+            backfill_compaction_groups: Default::default(),
         };
         let instance = Arc::new(instance);
         instance.init_time_travel_state().await?;
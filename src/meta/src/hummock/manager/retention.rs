@@ -0,0 +1,148 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use risingwave_common::system_param::reader::SystemParamsRead;
+use risingwave_common::util::epoch::{Epoch, INVALID_EPOCH};
+
+use crate::hummock::error::{Error, Result};
+use crate::hummock::HummockManager;
+
+/// Why the safe GC watermark of a table is held back below its committed epoch.
+#[derive(Debug, Clone)]
+pub enum RetentionBlocker {
+    /// A subscription on the table still needs the epoch range, with the given retention
+    /// in seconds.
+    Subscription { subscription_id: u32, retention_seconds: u64 },
+    /// The cluster-wide time travel retention covers the epoch range.
+    TimeTravel { retention_ms: u64 },
+    /// A hummock context, e.g. a batch query, has pinned an older snapshot.
+    PinnedSnapshot { context_id: u32, pinned_epoch: u64 },
+}
+
+impl std::fmt::Display for RetentionBlocker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Subscription {
+                subscription_id,
+                retention_seconds,
+            } => write!(
+                f,
+                "subscription {} retains {}s",
+                subscription_id, retention_seconds
+            ),
+            Self::TimeTravel { retention_ms } => {
+                write!(f, "time travel retains {}ms", retention_ms)
+            }
+            Self::PinnedSnapshot {
+                context_id,
+                pinned_epoch,
+            } => write!(f, "context {} pins epoch {}", context_id, pinned_epoch),
+        }
+    }
+}
+
+/// The effective retention of a single table, resolved from all retention holders.
+#[derive(Debug, Clone)]
+pub struct TableRetention {
+    /// The minimum epoch that must be retained for the table. Data below it is safe to GC.
+    pub safe_watermark_epoch: u64,
+    /// The holders that keep the watermark below the table's committed epoch, if any.
+    pub blockers: Vec<RetentionBlocker>,
+}
+
+impl HummockManager {
+    /// Resolves the effective safe GC watermark per table from all subscriptions on it,
+    /// the time travel retention and pinned snapshots, so that the holders don't conflict
+    /// with each other. The blockers of each table explain why its watermark is held back.
+    pub async fn resolve_retention_watermarks(&self) -> Result<HashMap<u32, TableRetention>> {
+        let subscriptions = self
+            .metadata_manager
+            .get_mv_depended_subscriptions()
+            .await
+            .map_err(|err| Error::Internal(err.into()))?;
+        let time_travel_retention_ms = self
+            .env
+            .system_params_reader()
+            .await
+            .time_travel_retention_ms();
+        let min_pinned_snapshot = {
+            let context_info = self.context_info.read().await;
+            context_info
+                .pinned_snapshots
+                .iter()
+                .filter(|(_, s)| s.minimal_pinned_snapshot != INVALID_EPOCH)
+                .min_by_key(|(_, s)| s.minimal_pinned_snapshot)
+                .map(|(context_id, s)| (*context_id, s.minimal_pinned_snapshot))
+        };
+
+        let now_ms = Epoch::now().physical_time();
+        let time_travel_watermark = if time_travel_retention_ms > 0 {
+            Epoch::from_physical_time(now_ms.saturating_sub(time_travel_retention_ms)).0
+        } else {
+            u64::MAX
+        };
+
+        let mut retentions = HashMap::new();
+        self.on_current_version(|version| {
+            for (table_id, info) in version.state_table_info.info() {
+                let mut watermark = info.committed_epoch;
+                let mut blockers = vec![];
+
+                if let Some(table_subscriptions) = subscriptions.get(table_id) {
+                    for (subscription_id, retention_seconds) in table_subscriptions {
+                        let subscription_watermark = Epoch::from_physical_time(
+                            now_ms.saturating_sub(retention_seconds * 1000),
+                        )
+                        .0;
+                        if subscription_watermark < info.committed_epoch {
+                            watermark = watermark.min(subscription_watermark);
+                            blockers.push(RetentionBlocker::Subscription {
+                                subscription_id: *subscription_id,
+                                retention_seconds: *retention_seconds,
+                            });
+                        }
+                    }
+                }
+                if time_travel_watermark < watermark {
+                    watermark = time_travel_watermark;
+                    blockers.push(RetentionBlocker::TimeTravel {
+                        retention_ms: time_travel_retention_ms,
+                    });
+                }
+                if let Some((context_id, pinned_epoch)) = min_pinned_snapshot
+                    && pinned_epoch < watermark
+                {
+                    watermark = pinned_epoch;
+                    blockers.push(RetentionBlocker::PinnedSnapshot {
+                        context_id,
+                        pinned_epoch,
+                    });
+                }
+
+                retentions.insert(
+                    table_id.table_id,
+                    TableRetention {
+                        safe_watermark_epoch: watermark,
+                        blockers,
+                    },
+                );
+            }
+        })
+        .await;
+
+        Ok(retentions)
+    }
+}
@@ -31,7 +31,9 @@ use tokio::task::JoinHandle;
 use tokio_stream::wrappers::IntervalStream;
 use tracing::warn;
 
-use crate::hummock::metrics_utils::{trigger_lsm_stat, trigger_mv_stat};
+use crate::hummock::metrics_utils::{
+    trigger_lsm_stat, trigger_mv_stat, trigger_storage_class_stat,
+};
 use crate::hummock::{HummockManager, TASK_NORMAL};
 
 impl HummockManager {
@@ -224,6 +226,26 @@ impl HummockManager {
                                         );
                                     }
 
+                                    match hummock_manager
+                                        .metadata_manager
+                                        .get_table_storage_class_policies()
+                                        .await
+                                    {
+                                        Ok(policies) => {
+                                            trigger_storage_class_stat(
+                                                &hummock_manager.metrics,
+                                                &current_version,
+                                                &policies,
+                                            );
+                                        }
+                                        Err(e) => {
+                                            tracing::warn!(
+                                                error = %e.as_report(),
+                                                "failed to fetch storage class policies"
+                                            );
+                                        }
+                                    }
+
                                     for compaction_group_id in
                                         get_compaction_group_ids(&current_version)
                                     {
@@ -286,6 +308,10 @@ impl HummockManager {
                                     if hummock_manager.env.opts.compaction_deterministic_test {
                                         continue;
                                     }
+                                    // Defer periodic compaction scheduling in maintenance mode.
+                                    if hummock_manager.env.maintenance_mode().is_on() {
+                                        continue;
+                                    }
 
                                     hummock_manager
                                         .on_handle_trigger_multi_group(
@@ -299,6 +325,10 @@ impl HummockManager {
                                     if hummock_manager.env.opts.compaction_deterministic_test {
                                         continue;
                                     }
+                                    // Defer periodic compaction scheduling in maintenance mode.
+                                    if hummock_manager.env.maintenance_mode().is_on() {
+                                        continue;
+                                    }
 
                                     hummock_manager
                                         .on_handle_trigger_multi_group(
@@ -319,10 +349,12 @@ impl HummockManager {
                                     if hummock_manager.env.opts.compaction_deterministic_test {
                                         continue;
                                     }
+                                    // Defer periodic compaction scheduling in maintenance mode.
+                                    if hummock_manager.env.maintenance_mode().is_on() {
+                                        continue;
+                                    }
 
-                                    hummock_manager
-                                        .on_handle_trigger_multi_group(compact_task::TaskType::Ttl)
-                                        .await;
+                                    hummock_manager.on_handle_trigger_ttl_compaction().await;
                                 }
 
                                 HummockTimerEvent::TombstoneCompactionTrigger => {
@@ -330,6 +362,10 @@ impl HummockManager {
                                     if hummock_manager.env.opts.compaction_deterministic_test {
                                         continue;
                                     }
+                                    // Defer periodic compaction scheduling in maintenance mode.
+                                    if hummock_manager.env.maintenance_mode().is_on() {
+                                        continue;
+                                    }
 
                                     hummock_manager
                                         .on_handle_trigger_multi_group(
@@ -339,6 +375,12 @@ impl HummockManager {
                                 }
 
                                 HummockTimerEvent::FullGc => {
+                                    // Defer full GC in maintenance mode, it's a low-priority
+                                    // background job.
+                                    if hummock_manager.env.maintenance_mode().is_on() {
+                                        continue;
+                                    }
+
                                     if hummock_manager
                                         .start_full_gc(Duration::from_secs(3600), None)
                                         .is_ok()
@@ -477,6 +519,9 @@ impl HummockManager {
             )
             .await;
         }
+
+        // Apply the size-based split/merge policy, if enabled via system params.
+        self.schedule_auto_group_split_merge().await;
     }
 
     async fn on_handle_trigger_multi_group(&self, task_type: compact_task::TaskType) {
@@ -491,4 +536,55 @@ impl HummockManager {
             }
         }
     }
+
+    /// Schedules TTL compaction only for the compaction groups that contain at least one
+    /// table with a retention configured, so that groups whose member tables all keep
+    /// their state forever are not repeatedly compacted for nothing.
+    async fn on_handle_trigger_ttl_compaction(&self) {
+        let all_table_id_to_option = match self.metadata_manager.get_all_table_options().await {
+            Ok(table_options) => table_options,
+            Err(e) => {
+                tracing::warn!(
+                    error = %e.as_report(),
+                    "Failed to list table options for Ttl compaction",
+                );
+                return;
+            }
+        };
+        let ttl_table_ids: HashSet<u32> = all_table_id_to_option
+            .into_iter()
+            .filter(|(_, table_option)| table_option.retention_seconds.is_some())
+            .map(|(table_id, _)| table_id)
+            .collect();
+        if ttl_table_ids.is_empty() {
+            return;
+        }
+
+        let group_members = self
+            .versioning
+            .read()
+            .await
+            .current_version
+            .state_table_info
+            .compaction_group_member_tables()
+            .clone();
+        for (cg_id, member_table_ids) in group_members {
+            if !member_table_ids
+                .iter()
+                .any(|table_id| ttl_table_ids.contains(&table_id.table_id))
+            {
+                continue;
+            }
+            if let Err(e) = self
+                .compaction_state
+                .try_sched_compaction(cg_id, compact_task::TaskType::Ttl)
+            {
+                tracing::warn!(
+                    error = %e.as_report(),
+                    "Failed to schedule Ttl compaction for compaction group {}",
+                    cg_id,
+                );
+            }
+        }
+    }
 }
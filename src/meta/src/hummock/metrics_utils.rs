@@ -20,11 +20,13 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use itertools::{enumerate, Itertools};
 use prometheus::core::{AtomicU64, GenericCounter};
 use prometheus::IntGauge;
+use risingwave_common::util::epoch::Epoch;
 use risingwave_hummock_sdk::compaction_group::hummock_version_ext::object_size_map;
 use risingwave_hummock_sdk::level::Levels;
 use risingwave_hummock_sdk::table_stats::PbTableStatsMap;
 use risingwave_hummock_sdk::version::HummockVersion;
 use risingwave_hummock_sdk::{CompactionGroupId, HummockContextId, HummockEpoch, HummockVersionId};
+use risingwave_pb::catalog::PbStorageClassPolicy;
 use risingwave_pb::hummock::write_limits::WriteLimit;
 use risingwave_pb::hummock::{
     CompactionConfig, HummockPinnedSnapshot, HummockPinnedVersion, HummockVersionStats, LevelType,
@@ -109,6 +111,50 @@ pub fn trigger_local_table_stat(
     }
 }
 
+/// Refresh per-table bytes-by-tier gauges for tables with a storage class policy.
+/// Each SST is classified by the age of its newest data; its size is apportioned
+/// evenly among the tables it contains.
+pub fn trigger_storage_class_stat(
+    metrics: &MetaMetrics,
+    version: &HummockVersion,
+    policies: &HashMap<u32, PbStorageClassPolicy>,
+) {
+    metrics.table_storage_class_bytes.reset();
+    if policies.is_empty() {
+        return;
+    }
+    let now_ms = Epoch::physical_now();
+    let mut tiered_bytes: HashMap<(u32, &'static str), u64> = HashMap::new();
+    for level in version.get_combined_levels() {
+        for sst in &level.table_infos {
+            if sst.table_ids.is_empty() {
+                continue;
+            }
+            let age_sec = now_ms.saturating_sub(Epoch(sst.max_epoch).physical_time()) / 1000;
+            let share = sst.sst_size / sst.table_ids.len() as u64;
+            for table_id in &sst.table_ids {
+                let Some(policy) = policies.get(table_id) else {
+                    continue;
+                };
+                let tier = if policy.cold_after_sec > 0 && age_sec >= policy.cold_after_sec {
+                    "cold"
+                } else if policy.warm_after_sec > 0 && age_sec >= policy.warm_after_sec {
+                    "warm"
+                } else {
+                    "hot"
+                };
+                *tiered_bytes.entry((*table_id, tier)).or_default() += share;
+            }
+        }
+    }
+    for ((table_id, tier), bytes) in tiered_bytes {
+        metrics
+            .table_storage_class_bytes
+            .with_label_values(&[&table_id.to_string(), tier])
+            .set(bytes as i64);
+    }
+}
+
 pub fn trigger_mv_stat(
     metrics: &MetaMetrics,
     version_stats: &HummockVersionStats,
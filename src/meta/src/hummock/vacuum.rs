@@ -80,7 +80,7 @@ impl VacuumManager {
         }
 
         let current_epoch_time = Epoch::now().physical_time();
-        let epoch_watermark = Epoch::from_physical_time(
+        let time_travel_watermark = Epoch::from_physical_time(
             current_epoch_time.saturating_sub(
                 self.env
                     .system_params_reader()
@@ -89,6 +89,17 @@ impl VacuumManager {
             ),
         )
         .0;
+        // Subscriptions and pinned snapshots must hold the truncation back as well, so
+        // that metadata a subscription still needs is not collected from under it.
+        let retention_watermark = self
+            .hummock_manager
+            .resolve_retention_watermarks()
+            .await?
+            .values()
+            .map(|retention| retention.safe_watermark_epoch)
+            .min();
+        let epoch_watermark =
+            retention_watermark.map_or(time_travel_watermark, |w| w.min(time_travel_watermark));
         self.hummock_manager
             .truncate_time_travel_metadata(epoch_watermark)
             .await?;
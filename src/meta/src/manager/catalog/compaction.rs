@@ -0,0 +1,258 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use itertools::Itertools;
+use risingwave_pb::connector_service::PendingSinkTransaction;
+use risingwave_pb::ddl_service::ObjectLints;
+use risingwave_pb::meta::RateLimitBoost;
+use thiserror_ext::AsReport;
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
+
+use super::CatalogManager;
+use crate::backup_restore::BackupManagerRef;
+use crate::model::MetadataModel;
+use crate::MetaResult;
+
+/// Orphaned keys observed by the previous scan. A key is only reclaimed after it has
+/// been observed orphaned by two consecutive scans, so that keys of an object between
+/// id allocation and catalog insertion are not mistaken for garbage.
+#[derive(Default)]
+struct CompactionSuspects {
+    lint_relation_ids: HashSet<u32>,
+    rate_limit_boost_job_ids: HashSet<u32>,
+    pending_sink_transaction_keys: HashSet<String>,
+}
+
+/// Reclaimed key counts of one compaction round, per column family.
+#[derive(Debug, Default)]
+struct ReclaimedKeys {
+    object_lints: usize,
+    rate_limit_boosts: usize,
+    pending_sink_transactions: usize,
+}
+
+impl ReclaimedKeys {
+    fn total(&self) -> usize {
+        self.object_lints + self.rate_limit_boosts + self.pending_sink_transactions
+    }
+}
+
+impl CatalogManager {
+    /// Spawns a background task that periodically verifies and physically removes
+    /// meta-store keys left behind by long-dropped catalog objects: lint findings,
+    /// rate limit boosts and pending sink transactions whose owning object no longer
+    /// exists. The catalog entries themselves are deleted transactionally on drop,
+    /// but these auxiliary keys are only cleaned best-effort at drop time and would
+    /// otherwise accumulate for the lifetime of the cluster.
+    ///
+    /// Safety: a round is skipped while a meta backup job is running, so that a burst
+    /// of deletions does not interleave with the snapshot being taken; soft-dropped
+    /// objects stay in the catalog until their retention expires and are therefore
+    /// never reclaimed here. Only meaningful for the kv metadata backend, which is
+    /// the only place these records are written.
+    pub fn start_catalog_compaction(
+        self: Arc<Self>,
+        backup_manager: BackupManagerRef,
+        interval: Duration,
+    ) -> (JoinHandle<()>, Sender<()>) {
+        tracing::info!("Catalog compaction is enabled with interval {:?}", interval);
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+        let join_handle = tokio::spawn(async move {
+            let mut compaction_interval = tokio::time::interval(interval);
+            compaction_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick completes immediately; consume it so that a freshly
+            // started meta node does not scan before the cluster has settled.
+            compaction_interval.tick().await;
+            let mut suspects = CompactionSuspects::default();
+            loop {
+                tokio::select! {
+                    _ = compaction_interval.tick() => {},
+                    _ = &mut shutdown_rx => {
+                        tracing::info!("Catalog compaction is stopped");
+                        return;
+                    }
+                }
+                if backup_manager.is_backup_job_running().await {
+                    tracing::debug!("catalog compaction round skipped: backup job is running");
+                    continue;
+                }
+                match self.compact_dropped_object_keys(&mut suspects).await {
+                    Ok(reclaimed) if reclaimed.total() > 0 => {
+                        tracing::info!(
+                            object_lints = reclaimed.object_lints,
+                            rate_limit_boosts = reclaimed.rate_limit_boosts,
+                            pending_sink_transactions = reclaimed.pending_sink_transactions,
+                            "catalog compaction reclaimed keys of dropped objects"
+                        );
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::warn!(error = %e.as_report(), "failed to compact catalog meta-store keys");
+                    }
+                }
+            }
+        });
+        (join_handle, shutdown_tx)
+    }
+
+    /// Scans the auxiliary column families and removes keys whose owning object has
+    /// been observed dropped by two consecutive scans. Returns the reclaimed key
+    /// counts.
+    async fn compact_dropped_object_keys(
+        &self,
+        suspects: &mut CompactionSuspects,
+    ) -> MetaResult<ReclaimedKeys> {
+        // Collect the live ids under the catalog lock, including in-progress
+        // creations: an object under creation already has its id allocated and may
+        // already own auxiliary keys.
+        let (relation_ids, job_ids, sink_ids) = {
+            let core = &self.core.lock().await.database;
+            let relation_ids: HashSet<u32> = core
+                .tables
+                .keys()
+                .chain(core.sources.keys())
+                .chain(core.sinks.keys())
+                .chain(core.indexes.keys())
+                .chain(core.views.keys())
+                .chain(core.subscriptions.keys())
+                .copied()
+                .chain(core.in_progress_creating_tables.keys().copied())
+                .collect();
+            let job_ids: HashSet<u32> = core
+                .tables
+                .keys()
+                .chain(core.sinks.keys())
+                .copied()
+                .chain(core.in_progress_creating_tables.keys().copied())
+                .collect();
+            let sink_ids: HashSet<u32> = core.sinks.keys().copied().collect();
+            (relation_ids, job_ids, sink_ids)
+        };
+        let store = self.env.meta_store_ref().as_kv();
+        let mut reclaimed = ReclaimedKeys::default();
+
+        // 1. Lint findings of dropped relations. They are cleaned best-effort when
+        // the relation is dropped, so a meta failover in between leaves them behind.
+        let orphaned: HashSet<_> = ObjectLints::list(store)
+            .await?
+            .into_iter()
+            .map(|lints| lints.relation_id)
+            .filter(|id| !relation_ids.contains(id))
+            .collect();
+        for id in orphaned.intersection(&suspects.lint_relation_ids) {
+            ObjectLints::delete(store, id).await?;
+            reclaimed.object_lints += 1;
+        }
+        suspects.lint_relation_ids = orphaned;
+
+        // 2. Rate limit boosts of dropped streaming jobs. They are normally removed
+        // when the boost expires or is reverted, but a job dropped while boosted
+        // leaves its record behind.
+        let orphaned: HashSet<_> = RateLimitBoost::list(store)
+            .await?
+            .into_iter()
+            .map(|boost| boost.table_id)
+            .filter(|id| !job_ids.contains(id))
+            .collect();
+        for id in orphaned.intersection(&suspects.rate_limit_boost_job_ids) {
+            RateLimitBoost::delete(store, id).await?;
+            reclaimed.rate_limit_boosts += 1;
+        }
+        suspects.rate_limit_boost_job_ids = orphaned;
+
+        // 3. Pending transactions of dropped sinks. Nothing will ever commit or
+        // abort them once the sink is gone.
+        let orphaned: HashSet<_> = PendingSinkTransaction::list(store)
+            .await?
+            .into_iter()
+            .filter(|txn| !sink_ids.contains(&txn.sink_id))
+            .map(|txn| txn.key())
+            .try_collect()?;
+        for key in orphaned.intersection(&suspects.pending_sink_transaction_keys) {
+            PendingSinkTransaction::delete(store, key).await?;
+            reclaimed.pending_sink_transactions += 1;
+        }
+        suspects.pending_sink_transaction_keys = orphaned;
+
+        Ok(reclaimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_pb::catalog::Table;
+
+    use super::*;
+    use crate::manager::MetaSrvEnv;
+
+    #[tokio::test]
+    async fn test_compact_dropped_object_keys() -> MetaResult<()> {
+        let env = MetaSrvEnv::for_test().await;
+        let catalog_manager = CatalogManager::new(env.clone()).await?;
+        let store = env.meta_store_ref().as_kv();
+
+        // A lint record owned by a live table and one owned by a dropped relation.
+        let table = Table {
+            id: 1,
+            name: "t".to_string(),
+            ..Default::default()
+        };
+        catalog_manager
+            .core
+            .lock()
+            .await
+            .database
+            .tables
+            .insert(table.id, table);
+        for relation_id in [1, 2] {
+            ObjectLints {
+                relation_id,
+                lints: vec![],
+            }
+            .insert(store)
+            .await?;
+        }
+        RateLimitBoost {
+            table_id: 3,
+            ..Default::default()
+        }
+        .insert(store)
+        .await?;
+
+        let mut suspects = CompactionSuspects::default();
+        // The first scan only records suspects and reclaims nothing.
+        let reclaimed = catalog_manager
+            .compact_dropped_object_keys(&mut suspects)
+            .await?;
+        assert_eq!(reclaimed.total(), 0);
+        assert_eq!(suspects.lint_relation_ids, HashSet::from([2]));
+        assert_eq!(suspects.rate_limit_boost_job_ids, HashSet::from([3]));
+
+        // The second scan confirms and physically removes the orphaned keys.
+        let reclaimed = catalog_manager
+            .compact_dropped_object_keys(&mut suspects)
+            .await?;
+        assert_eq!(reclaimed.object_lints, 1);
+        assert_eq!(reclaimed.rate_limit_boosts, 1);
+        assert_eq!(ObjectLints::list(store).await?.len(), 1);
+        assert!(RateLimitBoost::list(store).await?.is_empty());
+
+        Ok(())
+    }
+}
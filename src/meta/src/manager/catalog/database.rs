@@ -16,6 +16,7 @@ use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, HashMap, HashSet};
 
 use itertools::Itertools;
+use prost::Message;
 use risingwave_common::bail;
 use risingwave_common::catalog::TableOption;
 use risingwave_pb::catalog::subscription::PbSubscriptionState;
@@ -25,6 +26,7 @@ use risingwave_pb::catalog::{
     Source, StreamJobStatus, Subscription, Table, View,
 };
 use risingwave_pb::data::DataType;
+use risingwave_pb::ddl_service::{PbCatalogMemoryStats, StreamingJobQuota};
 use risingwave_pb::user::grant_privilege::PbObject;
 use tokio::sync::oneshot::Sender;
 
@@ -81,6 +83,8 @@ pub struct DatabaseManager {
     pub(super) connections: BTreeMap<ConnectionId, Connection>,
     /// Cached secret information.
     pub(super) secrets: BTreeMap<SecretId, Secret>,
+    /// Streaming job quotas, keyed by their scope (`user/{id}` or `database/{id}`).
+    pub(super) streaming_job_quotas: BTreeMap<String, StreamingJobQuota>,
 
     /// Relation reference count mapping.
     pub(super) relation_ref_count: HashMap<RelationId, usize>,
@@ -118,6 +122,7 @@ impl DatabaseManager {
         let connections = Connection::list(env.meta_store().as_kv()).await?;
         let subscriptions = Subscription::list(env.meta_store().as_kv()).await?;
         let secrets = Secret::list(env.meta_store().as_kv()).await?;
+        let streaming_job_quotas = StreamingJobQuota::list(env.meta_store().as_kv()).await?;
 
         let mut relation_ref_count = HashMap::new();
         let mut connection_ref_count = HashMap::new();
@@ -174,6 +179,10 @@ impl DatabaseManager {
         }));
         let functions = BTreeMap::from_iter(functions.into_iter().map(|f| (f.id, f)));
         let connections = BTreeMap::from_iter(connections.into_iter().map(|c| (c.id, c)));
+        let streaming_job_quotas = BTreeMap::from_iter(streaming_job_quotas.into_iter().map(|q| {
+            let key = q.key().expect("quota scope should be set");
+            (key, q)
+        }));
 
         // todo: scan over stream source info and sink to update secret ref count `_secret_ref_count`
 
@@ -191,6 +200,7 @@ impl DatabaseManager {
             relation_ref_count,
             connection_ref_count,
             secrets,
+            streaming_job_quotas,
             secret_ref_count,
             in_progress_creation_tracker: HashSet::default(),
             in_progress_creating_streaming_job: HashMap::default(),
@@ -233,6 +243,69 @@ impl DatabaseManager {
         )
     }
 
+    /// Byte-level accounting of the cached catalog, grouped by database and object type.
+    /// Sizes are the prost-encoded lengths of the cached definitions, which closely track
+    /// the actual heap footprint of the cache.
+    pub fn memory_stats(&self) -> Vec<PbCatalogMemoryStats> {
+        let mut acc: BTreeMap<(DatabaseId, &'static str), (u64, u64)> = BTreeMap::new();
+        let mut add = |database_id: DatabaseId, object_type: &'static str, bytes: usize| {
+            let entry = acc.entry((database_id, object_type)).or_default();
+            entry.0 += 1;
+            entry.1 += bytes as u64;
+        };
+        for database in self.databases.values() {
+            add(database.id, "database", database.encoded_len());
+        }
+        for schema in self.schemas.values() {
+            add(schema.database_id, "schema", schema.encoded_len());
+        }
+        for table in self.tables.values() {
+            add(table.database_id, "table", table.encoded_len());
+        }
+        for source in self.sources.values() {
+            add(source.database_id, "source", source.encoded_len());
+        }
+        for sink in self.sinks.values() {
+            add(sink.database_id, "sink", sink.encoded_len());
+        }
+        for subscription in self.subscriptions.values() {
+            add(
+                subscription.database_id,
+                "subscription",
+                subscription.encoded_len(),
+            );
+        }
+        for index in self.indexes.values() {
+            add(index.database_id, "index", index.encoded_len());
+        }
+        for view in self.views.values() {
+            add(view.database_id, "view", view.encoded_len());
+        }
+        for function in self.functions.values() {
+            add(function.database_id, "function", function.encoded_len());
+        }
+        for connection in self.connections.values() {
+            add(
+                connection.database_id,
+                "connection",
+                connection.encoded_len(),
+            );
+        }
+        for secret in self.secrets.values() {
+            add(secret.database_id, "secret", secret.encoded_len());
+        }
+        acc.into_iter()
+            .map(
+                |((database_id, object_type), (object_count, total_bytes))| PbCatalogMemoryStats {
+                    database_id,
+                    object_type: object_type.to_string(),
+                    object_count,
+                    total_bytes,
+                },
+            )
+            .collect()
+    }
+
     pub fn get_table_name_and_type_mapping(&self) -> HashMap<TableId, (String, String)> {
         self.tables
             .values()
@@ -424,6 +497,15 @@ impl DatabaseManager {
             .collect_vec()
     }
 
+    /// Lists the table ids of the indexes created on the given table.
+    pub fn list_index_table_ids_on(&self, table_id: TableId) -> Vec<TableId> {
+        self.indexes
+            .values()
+            .filter(|index| index.primary_table_id == table_id)
+            .map(|index| index.index_table_id)
+            .collect_vec()
+    }
+
     pub fn list_sources(&self) -> Vec<Source> {
         self.sources.values().cloned().collect_vec()
     }
@@ -714,6 +796,14 @@ impl DatabaseManager {
         }
     }
 
+    pub fn ensure_secret_id(&self, secret_id: SecretId) -> MetaResult<()> {
+        if self.secrets.contains_key(&secret_id) {
+            Ok(())
+        } else {
+            Err(MetaError::catalog_id_not_found("secret", secret_id))
+        }
+    }
+
     pub fn ensure_table_view_or_source_id(&self, table_id: &TableId) -> MetaResult<()> {
         if self.tables.contains_key(table_id)
             || self.sources.contains_key(table_id)
@@ -728,6 +818,76 @@ impl DatabaseManager {
         }
     }
 
+    /// Returns the database a table, source or view belongs to, together with the
+    /// privilege object referring to it, or `None` if the id does not exist.
+    pub fn find_relation_object(&self, relation_id: RelationId) -> Option<(DatabaseId, PbObject)> {
+        if let Some(table) = self.tables.get(&relation_id) {
+            Some((table.database_id, PbObject::TableId(relation_id)))
+        } else if let Some(source) = self.sources.get(&relation_id) {
+            Some((source.database_id, PbObject::SourceId(relation_id)))
+        } else if let Some(view) = self.views.get(&relation_id) {
+            Some((view.database_id, PbObject::ViewId(relation_id)))
+        } else {
+            None
+        }
+    }
+
+    /// Collects the qualified names of relations outside `database_id` that depend on a
+    /// relation inside it. Such dependents block `DROP DATABASE`, as dropping the
+    /// database would leave their cross-database references dangling.
+    pub fn cross_database_dependents(&self, database_id: DatabaseId) -> Vec<String> {
+        let in_database = |relation_id: &RelationId| {
+            self.find_relation_object(*relation_id)
+                .is_some_and(|(id, _)| id == database_id)
+        };
+        let qualified_name = |db_id: DatabaseId, schema_id: SchemaId, name: &str| {
+            format!(
+                "{}.{}.{}",
+                self.databases
+                    .get(&db_id)
+                    .map_or("?", |database| database.name.as_str()),
+                self.schemas
+                    .get(&schema_id)
+                    .map_or("?", |schema| schema.name.as_str()),
+                name
+            )
+        };
+
+        let mut dependents = vec![];
+        for table in self.tables.values() {
+            if table.database_id != database_id && table.dependent_relations.iter().any(in_database)
+            {
+                dependents.push(qualified_name(
+                    table.database_id,
+                    table.schema_id,
+                    &table.name,
+                ));
+            }
+        }
+        for sink in self.sinks.values() {
+            if sink.database_id != database_id && sink.dependent_relations.iter().any(in_database) {
+                dependents.push(qualified_name(sink.database_id, sink.schema_id, &sink.name));
+            }
+        }
+        for subscription in self.subscriptions.values() {
+            if subscription.database_id != database_id
+                && in_database(&subscription.dependent_table_id)
+            {
+                dependents.push(qualified_name(
+                    subscription.database_id,
+                    subscription.schema_id,
+                    &subscription.name,
+                ));
+            }
+        }
+        for view in self.views.values() {
+            if view.database_id != database_id && view.dependent_relations.iter().any(in_database) {
+                dependents.push(qualified_name(view.database_id, view.schema_id, &view.name));
+            }
+        }
+        dependents
+    }
+
     pub fn get_object_owner(&self, object: &PbObject) -> MetaResult<UserId> {
         match object {
             PbObject::DatabaseId(id) => self
@@ -770,6 +930,11 @@ impl DatabaseManager {
                 .get(id)
                 .map(|f| f.owner)
                 .ok_or_else(|| MetaError::catalog_id_not_found("function", id)),
+            PbObject::SecretId(id) => self
+                .secrets
+                .get(id)
+                .map(|s| s.owner)
+                .ok_or_else(|| MetaError::catalog_id_not_found("secret", id)),
             _ => unreachable!("unexpected object type: {:?}", object),
         }
     }
@@ -1105,6 +1105,122 @@ impl FragmentManager {
         Ok(fragment_to_apply)
     }
 
+    // edit the `rate_limit` of the `Sink` node in given `sink_id`'s fragments
+    // return the actor_ids to be applied
+    pub async fn update_sink_rate_limit_by_sink_id(
+        &self,
+        sink_id: u32,
+        rate_limit: Option<u32>,
+    ) -> MetaResult<HashMap<FragmentId, Vec<ActorId>>> {
+        let map = &mut self.core.write().await.table_fragments;
+
+        let mut table_fragments = BTreeMapTransaction::new(map);
+        let mut fragment = table_fragments
+            .get_mut(TableId::new(sink_id))
+            .ok_or_else(|| MetaError::fragment_not_found(sink_id))?;
+        let mut fragment_to_apply = HashMap::new();
+
+        for fragment in fragment.fragments.values_mut() {
+            if (fragment.get_fragment_type_mask() & FragmentTypeFlag::Sink as u32) != 0 {
+                let mut actor_to_apply = Vec::new();
+                for actor in &mut fragment.actors {
+                    if let Some(node) = actor.nodes.as_mut() {
+                        visit_stream_node(node, |node_body| {
+                            if let NodeBody::Sink(ref mut node) = node_body
+                                && let Some(ref mut sink_desc) = node.sink_desc
+                                && sink_desc.id == sink_id
+                            {
+                                sink_desc.rate_limit = rate_limit;
+                                actor_to_apply.push(actor.actor_id);
+                            }
+                        })
+                    };
+                }
+                fragment_to_apply.insert(fragment.fragment_id, actor_to_apply);
+            }
+        }
+
+        if fragment_to_apply.is_empty() {
+            return Err(MetaError::from(anyhow!(
+                "sink node not found in job id {sink_id}"
+            )));
+        }
+
+        commit_meta!(self, table_fragments)?;
+        tracing::info!(
+            "update sink actor rate limit to: {:?}, actors {:?}",
+            rate_limit,
+            fragment_to_apply
+        );
+
+        Ok(fragment_to_apply)
+    }
+
+    /// Collect the persisted rate limits of the job's source and backfill actors, keyed by
+    /// fragment. Used to freeze and unfreeze a single streaming job without clobbering the
+    /// rate limits configured by the user.
+    pub async fn get_mv_rate_limit_by_table_id(
+        &self,
+        table_id: TableId,
+    ) -> MetaResult<HashMap<FragmentId, HashMap<ActorId, Option<u32>>>> {
+        let map = &self.core.read().await.table_fragments;
+        let table_fragments = map
+            .get(&table_id)
+            .ok_or_else(|| MetaError::fragment_not_found(table_id))?;
+        let mut fragment_rate_limits = HashMap::new();
+
+        for fragment in table_fragments.fragments.values() {
+            if (fragment.get_fragment_type_mask() & FragmentTypeFlag::StreamScan as u32) != 0
+                || (fragment.get_fragment_type_mask() & FragmentTypeFlag::Source as u32) != 0
+            {
+                let mut actor_rate_limits = HashMap::new();
+                for actor in &fragment.actors {
+                    if let Some(node) = actor.nodes.as_ref() {
+                        visit_stream_node_cont(node, |node| {
+                            match node.node_body.as_ref() {
+                                Some(NodeBody::StreamCdcScan(node)) => {
+                                    actor_rate_limits.insert(actor.actor_id, node.rate_limit);
+                                }
+                                Some(NodeBody::StreamScan(node)) => {
+                                    actor_rate_limits.insert(actor.actor_id, node.rate_limit);
+                                }
+                                Some(NodeBody::Source(node)) => {
+                                    if let Some(node_inner) = node.source_inner.as_ref() {
+                                        actor_rate_limits
+                                            .insert(actor.actor_id, node_inner.rate_limit);
+                                    }
+                                }
+                                _ => {}
+                            }
+                            true
+                        })
+                    };
+                }
+                if !actor_rate_limits.is_empty() {
+                    fragment_rate_limits.insert(fragment.fragment_id, actor_rate_limits);
+                }
+            }
+        }
+        Ok(fragment_rate_limits)
+    }
+
+    /// Whether zeroing the job's rate limits actually freezes it. Stream scan executors only
+    /// honor `Throttle` while their backfill loop is running, so a job that has finished
+    /// creating can only be paused if none of its fragments is a backfill fragment; source
+    /// executors honor rate limits for their whole lifetime.
+    pub async fn is_job_pausable_by_rate_limit(&self, table_id: TableId) -> MetaResult<bool> {
+        let map = &self.core.read().await.table_fragments;
+        let table_fragments = map
+            .get(&table_id)
+            .ok_or_else(|| MetaError::fragment_not_found(table_id))?;
+        if table_fragments.state() != State::Created {
+            return Ok(true);
+        }
+        Ok(table_fragments.fragments.values().all(|fragment| {
+            fragment.get_fragment_type_mask() & FragmentTypeFlag::StreamScan as u32 == 0
+        }))
+    }
+
     pub async fn update_actor_splits_by_split_assignment(
         &self,
         split_assignment: &SplitAssignment,
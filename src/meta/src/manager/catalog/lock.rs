@@ -0,0 +1,231 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An instrumented, FIFO-fair wrapper around the catalog core mutex.
+//!
+//! The catalog core is guarded by a single asynchronous mutex, so a long-running
+//! mutation, e.g. a large drop cascade, delays every other DDL queued behind it. The
+//! underlying `tokio::sync::Mutex` hands the lock over in FIFO order, which already
+//! prevents small DDLs from being starved indefinitely by a stream of big ones. This
+//! wrapper adds observability on top: it tracks the current holder and all queued
+//! waiters by the code location that requested the lock, reports wait time to the
+//! `meta_catalog_lock_wait_duration_seconds` metric, and logs acquisitions that waited
+//! longer than [`STARVATION_THRESHOLD`].
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+use std::time::{Duration, Instant};
+
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::rpc::metrics::GLOBAL_META_METRICS;
+
+/// Waiting longer than this for the catalog lock is considered starvation and logged.
+const STARVATION_THRESHOLD: Duration = Duration::from_secs(10);
+
+#[derive(Clone)]
+struct LockRequest {
+    /// The code location that requested the lock, identifying the operation.
+    operation: &'static Location<'static>,
+    enqueued_at: Instant,
+}
+
+#[derive(Default)]
+struct LockStats {
+    next_request_id: u64,
+    /// The request currently holding the lock, with the time of acquisition.
+    holder: Option<(LockRequest, Instant)>,
+    /// Queued requests in FIFO order, i.e. the order the lock will be granted.
+    waiters: BTreeMap<u64, LockRequest>,
+}
+
+/// A snapshot of the current catalog lock holder and queued waiters.
+pub struct CatalogLockStats {
+    /// The operation holding the lock and for how long, or `None` if the lock is free.
+    pub holder: Option<(String, Duration)>,
+    /// Queued operations with their waiting time so far, in FIFO order.
+    pub waiters: Vec<(String, Duration)>,
+}
+
+/// A `tokio::sync::Mutex` that additionally tracks the current holder and queued
+/// waiters. See the module documentation.
+pub(super) struct TrackedMutex<T> {
+    inner: Mutex<T>,
+    stats: std::sync::Mutex<LockStats>,
+}
+
+impl<T> TrackedMutex<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Mutex::new(value),
+            stats: Default::default(),
+        }
+    }
+
+    /// Locks the catalog core, identifying the operation by the caller's code location.
+    #[track_caller]
+    pub fn lock(&self) -> impl Future<Output = TrackedMutexGuard<'_, T>> + '_ {
+        let operation = Location::caller();
+        async move {
+            let request = LockRequest {
+                operation,
+                enqueued_at: Instant::now(),
+            };
+            let request_id = {
+                let mut stats = self.stats.lock().unwrap();
+                let request_id = stats.next_request_id;
+                stats.next_request_id += 1;
+                stats.waiters.insert(request_id, request.clone());
+                request_id
+            };
+            // Ensure a cancelled acquisition, e.g. an aborted DDL RPC, doesn't leave a
+            // stale entry in the queue.
+            let mut cleanup = WaiterCleanup {
+                mutex: self,
+                request_id: Some(request_id),
+            };
+            let guard = self.inner.lock().await;
+            cleanup.request_id = None;
+            let wait_time = request.enqueued_at.elapsed();
+            {
+                let mut stats = self.stats.lock().unwrap();
+                stats.waiters.remove(&request_id);
+                stats.holder = Some((request, Instant::now()));
+            }
+            GLOBAL_META_METRICS
+                .catalog_lock_wait_latency
+                .observe(wait_time.as_secs_f64());
+            if wait_time >= STARVATION_THRESHOLD {
+                tracing::warn!(
+                    operation = %operation,
+                    wait_time_ms = wait_time.as_millis() as u64,
+                    "catalog lock acquisition was starved"
+                );
+            }
+            TrackedMutexGuard { mutex: self, guard }
+        }
+    }
+
+    pub fn stats(&self) -> CatalogLockStats {
+        let stats = self.stats.lock().unwrap();
+        CatalogLockStats {
+            holder: stats.holder.as_ref().map(|(request, acquired_at)| {
+                (request.operation.to_string(), acquired_at.elapsed())
+            }),
+            waiters: stats
+                .waiters
+                .values()
+                .map(|request| (request.operation.to_string(), request.enqueued_at.elapsed()))
+                .collect(),
+        }
+    }
+}
+
+struct WaiterCleanup<'a, T> {
+    mutex: &'a TrackedMutex<T>,
+    request_id: Option<u64>,
+}
+
+impl<T> Drop for WaiterCleanup<'_, T> {
+    fn drop(&mut self) {
+        if let Some(request_id) = self.request_id {
+            self.mutex.stats.lock().unwrap().waiters.remove(&request_id);
+        }
+    }
+}
+
+pub struct TrackedMutexGuard<'a, T> {
+    mutex: &'a TrackedMutex<T>,
+    guard: MutexGuard<'a, T>,
+}
+
+impl<T> Deref for TrackedMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for TrackedMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T> Drop for TrackedMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        // Cleared before the inner guard is released, so the next holder never observes
+        // a stale predecessor.
+        self.mutex.stats.lock().unwrap().holder = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tracked_mutex_stats() {
+        let mutex = std::sync::Arc::new(TrackedMutex::new(0usize));
+        assert!(mutex.stats().holder.is_none());
+        assert!(mutex.stats().waiters.is_empty());
+
+        let guard = mutex.lock().await;
+        let stats = mutex.stats();
+        assert!(stats.holder.is_some());
+        assert!(stats.waiters.is_empty());
+
+        let mutex_clone = mutex.clone();
+        let waiter = tokio::spawn(async move {
+            let _guard = mutex_clone.lock().await;
+        });
+        // Wait until the spawned task is queued.
+        loop {
+            if mutex.stats().waiters.len() == 1 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+
+        drop(guard);
+        waiter.await.unwrap();
+        let stats = mutex.stats();
+        assert!(stats.holder.is_none());
+        assert!(stats.waiters.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_waiter_is_dequeued() {
+        let mutex = std::sync::Arc::new(TrackedMutex::new(0usize));
+        let _guard = mutex.lock().await;
+
+        let mutex_clone = mutex.clone();
+        let waiter = tokio::spawn(async move {
+            let _guard = mutex_clone.lock().await;
+        });
+        loop {
+            if mutex.stats().waiters.len() == 1 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+
+        waiter.abort();
+        let _ = waiter.await;
+        assert!(mutex.stats().waiters.is_empty());
+    }
+}
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod compaction;
 mod database;
 mod fragment;
+mod lock;
 mod user;
 mod utils;
 
@@ -26,6 +28,7 @@ use anyhow::{anyhow, Context};
 pub use database::*;
 pub use fragment::*;
 use itertools::Itertools;
+use prost::Message;
 use risingwave_common::catalog::{
     valid_table_name, TableId as StreamingJobId, TableOption, DEFAULT_DATABASE_NAME,
     DEFAULT_SCHEMA_NAME, DEFAULT_SUPER_USER, DEFAULT_SUPER_USER_FOR_PG,
@@ -33,26 +36,45 @@ use risingwave_common::catalog::{
 };
 use risingwave_common::secret::LocalSecretManager;
 use risingwave_common::{bail, current_cluster_version, ensure};
+use risingwave_connector::sink::CONNECTOR_TYPE_KEY;
 use risingwave_connector::source::cdc::build_cdc_table_id;
 use risingwave_connector::source::{should_copy_to_format_encode_options, UPSTREAM_SOURCE_KEY};
-use risingwave_pb::catalog::subscription::PbSubscriptionState;
-use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, TableType};
+use risingwave_pb::catalog::comment::PbObjectType;
+use risingwave_pb::catalog::connection::PbHealthStatus;
+use risingwave_common::system_param::reader::SystemParamsRead;
+use risingwave_pb::catalog::subscription::{PbRetentionPolicy, PbSubscriptionState};
+use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, PbSchemaChangePolicy, TableType};
+use risingwave_pb::catalog::label::PbJob as LabelJob;
 use risingwave_pb::catalog::{
-    Comment, Connection, CreateType, Database, Function, Index, PbSource, PbStreamJobStatus,
-    Schema, Secret, Sink, Source, StreamJobStatus, Subscription, Table, View,
+    Annotation, Comment, Connection, CreateType, Database, Function, Index, Label, PbSource,
+    PbStorageClassPolicy, PbStreamJobPriority, PbStreamJobStatus, Schema, Secret, Sink, Source,
+    StreamJobStatus, Subscription, Table, View,
 };
-use risingwave_pb::ddl_service::{alter_owner_request, alter_set_schema_request, TableJobType};
+use risingwave_pb::ddl_service::streaming_job_quota::Scope as QuotaScope;
+use risingwave_pb::ddl_service::{
+    alter_owner_request, alter_set_schema_request, PbCatalogMemoryStats, PbDdlAuditLog,
+    StreamingJobQuota, TableJobType,
+};
+use risingwave_pb::hummock::HummockVersionStats;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
+use risingwave_pb::plan_common::PbColumnCatalog;
+use risingwave_pb::user::default_privilege::{Entry as DefaultPrivilegeEntry, RelationKind};
 use risingwave_pb::user::grant_privilege::{Action, ActionWithGrantOption, Object};
 use risingwave_pb::user::update_user_request::UpdateField;
-use risingwave_pb::user::{GrantPrivilege, UserInfo};
+use risingwave_pb::user::{
+    AlterDefaultPrivilegesRequest, DefaultPrivilege, GrantPrivilege, UserInfo,
+};
+use lock::{TrackedMutex, TrackedMutexGuard};
+pub use lock::CatalogLockStats;
+use thiserror_ext::AsReport;
 use tokio::sync::oneshot::Sender;
-use tokio::sync::{Mutex, MutexGuard};
 use user::*;
 
 pub use self::utils::{get_refed_secret_ids_from_sink, get_refed_secret_ids_from_source};
 use crate::manager::{
-    IdCategory, MetaSrvEnv, NotificationVersion, StreamingJob, IGNORED_NOTIFICATION_VERSION,
+    check_dangerous_grant, effective_subscription_retention_seconds, DdlAuditManager, IdCategory,
+    LocalNotification, MetaSrvEnv, NotificationVersion, StreamingJob,
+    IGNORED_NOTIFICATION_VERSION,
 };
 use crate::model::{BTreeMapTransaction, MetadataModel, TableFragments};
 use crate::storage::Transaction;
@@ -73,6 +95,50 @@ pub type SecretId = u32;
 pub type UserId = u32;
 pub type ConnectionId = u32;
 
+/// Maximum byte length of an annotation key.
+pub const MAX_ANNOTATION_KEY_LEN: usize = 256;
+/// Maximum byte length of an annotation value.
+pub const MAX_ANNOTATION_VALUE_LEN: usize = 4096;
+/// Maximum number of annotations attached to a single catalog object.
+pub const MAX_ANNOTATIONS_PER_OBJECT: usize = 64;
+
+/// Maximum byte length of a label key or value.
+pub const MAX_LABEL_LEN: usize = 256;
+/// Maximum number of labels attached to a single streaming job.
+pub const MAX_LABELS_PER_OBJECT: usize = 32;
+
+/// Format version of catalog snapshot blobs produced by [`CatalogManager::export_snapshot`],
+/// bumped on incompatible changes.
+pub const CATALOG_SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Returns the reason why replacing a table's columns breaks relations that refer to the
+/// table by column name, i.e. a visible column was dropped or had its type changed.
+pub fn replace_table_incompatibility(
+    original_columns: &[PbColumnCatalog],
+    new_columns: &[PbColumnCatalog],
+) -> Option<String> {
+    let new_types: HashMap<_, _> = new_columns
+        .iter()
+        .filter(|c| !c.is_hidden)
+        .filter_map(|c| c.column_desc.as_ref())
+        .map(|desc| (desc.name.as_str(), &desc.column_type))
+        .collect();
+    for desc in original_columns
+        .iter()
+        .filter(|c| !c.is_hidden)
+        .filter_map(|c| c.column_desc.as_ref())
+    {
+        match new_types.get(desc.name.as_str()) {
+            None => return Some(format!("column \"{}\" was dropped", desc.name)),
+            Some(new_type) if **new_type != desc.column_type => {
+                return Some(format!("column \"{}\" changed its type", desc.name));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 pub enum RelationIdEnum {
     Table(TableId),
     Index(IndexId),
@@ -128,9 +194,12 @@ macro_rules! commit_meta {
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_common::util::epoch::Epoch;
 use risingwave_pb::meta::cancel_creating_jobs_request::CreatingJobInfo;
+use risingwave_pb::meta::get_dependency_graph_response::{
+    PbDependencyEdge, PbDependencyKind, PbDependencyNode,
+};
 use risingwave_pb::meta::list_object_dependencies_response::PbObjectDependencies;
 use risingwave_pb::meta::relation::RelationInfo;
-use risingwave_pb::meta::{Relation, RelationGroup};
+use risingwave_pb::meta::{PbCatalogSnapshot, PbGetDependencyGraphResponse, Relation, RelationGroup};
 pub(crate) use {commit_meta, commit_meta_with_trx};
 
 use self::utils::{
@@ -143,10 +212,23 @@ use crate::controller::rename::{
 use crate::controller::utils::extract_external_table_name_from_definition;
 use crate::manager::catalog::utils::{refcnt_dec_connection, refcnt_inc_connection};
 use crate::rpc::ddl_controller::DropMode;
+use crate::rpc::property_schema::validate_connector_properties;
 use crate::telemetry::MetaTelemetryJobDesc;
 
 pub type CatalogManagerRef = Arc<CatalogManager>;
 
+/// One operation of a DDL batch submitted through [`CatalogManager::run_ddl_batch`].
+///
+/// The batch is restricted to relations that live purely in the catalog — sources
+/// without a streaming job and views — since streaming jobs involve barrier and
+/// fragment bookkeeping that cannot be rolled back by a catalog transaction alone.
+pub enum DdlBatchOperation {
+    CreateSource(Source),
+    DropSource(SourceId),
+    CreateView(View),
+    DropView(ViewId),
+}
+
 /// `CatalogManager` manages database catalog information and user information, including
 /// authentication and privileges.
 ///
@@ -155,7 +237,7 @@ pub type CatalogManagerRef = Arc<CatalogManager>;
 /// to Meta.
 pub struct CatalogManager {
     env: MetaSrvEnv,
-    core: Mutex<CatalogManagerCore>,
+    core: TrackedMutex<CatalogManagerCore>,
 }
 
 pub struct CatalogManagerCore {
@@ -175,6 +257,9 @@ impl CatalogManagerCore {
         id: TableId,
         sender: Sender<MetaResult<NotificationVersion>>,
     ) {
+        // Piggyback cleanup of notifiers whose waiter has gone away, so that abandoned
+        // entries don't linger until the job finishes.
+        self.cleanup_abandoned_finish_notifiers();
         self.database
             .creating_table_finish_notifier
             .entry(id)
@@ -182,6 +267,27 @@ impl CatalogManagerCore {
             .push(sender);
     }
 
+    /// Drops registered finish notifiers whose receiver side has been dropped, i.e. the
+    /// waiting frontend has disconnected or given up.
+    pub(crate) fn cleanup_abandoned_finish_notifiers(&mut self) {
+        self.database
+            .creating_table_finish_notifier
+            .retain(|_, txs| {
+                txs.retain(|tx| !tx.is_closed());
+                !txs.is_empty()
+            });
+    }
+
+    /// Returns the number of active DDL waiters per creating job, excluding abandoned ones.
+    pub(crate) fn count_ddl_waiters(&self) -> HashMap<TableId, usize> {
+        self.database
+            .creating_table_finish_notifier
+            .iter()
+            .map(|(id, txs)| (*id, txs.iter().filter(|tx| !tx.is_closed()).count()))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
     pub(crate) fn streaming_job_is_finished(&mut self, job: &StreamingJob) -> MetaResult<bool> {
         fn gen_err(job: &StreamingJob, name: &String) -> MetaError {
             MetaError::catalog_id_not_found(
@@ -259,7 +365,7 @@ impl CatalogManagerCore {
 
 impl CatalogManager {
     pub async fn new(env: MetaSrvEnv) -> MetaResult<Self> {
-        let core = Mutex::new(CatalogManagerCore::new(env.clone()).await?);
+        let core = TrackedMutex::new(CatalogManagerCore::new(env.clone()).await?);
         let catalog_manager = Self { env, core };
         catalog_manager.init().await?;
         Ok(catalog_manager)
@@ -278,10 +384,120 @@ impl CatalogManager {
         self.env.notification_manager().current_version().await
     }
 
-    pub async fn get_catalog_core_guard(&self) -> MutexGuard<'_, CatalogManagerCore> {
+    pub async fn get_catalog_core_guard(&self) -> TrackedMutexGuard<'_, CatalogManagerCore> {
         self.core.lock().await
     }
 
+    /// Returns the current catalog lock holder and queued operations, for admin
+    /// inspection. It intentionally doesn't acquire the catalog lock itself.
+    pub fn catalog_lock_stats(&self) -> CatalogLockStats {
+        self.core.stats()
+    }
+
+    /// Returns the number of active DDL waiters per creating job.
+    pub async fn count_ddl_waiters(&self) -> HashMap<TableId, usize> {
+        self.core.lock().await.count_ddl_waiters()
+    }
+
+    /// Serializes the full catalog, including users and dependency-carrying fields, into a
+    /// versioned blob that can be restored into an empty cluster via [`Self::import_snapshot`].
+    pub async fn export_snapshot(&self) -> Vec<u8> {
+        let core = self.core.lock().await;
+        let database = &core.database;
+        let snapshot = PbCatalogSnapshot {
+            format_version: CATALOG_SNAPSHOT_FORMAT_VERSION,
+            databases: database.databases.values().cloned().collect(),
+            schemas: database.schemas.values().cloned().collect(),
+            sources: database.sources.values().cloned().collect(),
+            sinks: database.sinks.values().cloned().collect(),
+            tables: database.tables.values().cloned().collect(),
+            indexes: database.indexes.values().cloned().collect(),
+            views: database.views.values().cloned().collect(),
+            functions: database.functions.values().cloned().collect(),
+            connections: database.connections.values().cloned().collect(),
+            subscriptions: database.subscriptions.values().cloned().collect(),
+            secrets: database.secrets.values().cloned().collect(),
+            users: core.user.user_info.values().cloned().collect(),
+        };
+        snapshot.encode_to_vec()
+    }
+
+    /// Restores a catalog snapshot produced by [`Self::export_snapshot`] into this cluster.
+    ///
+    /// The cluster must not contain any user-created relations; the bootstrapped default
+    /// databases and users are replaced by the snapshot contents. Frontends should
+    /// re-subscribe (i.e. restart) afterwards to pick up the imported catalog.
+    pub async fn import_snapshot(&self, blob: &[u8]) -> MetaResult<()> {
+        let snapshot = PbCatalogSnapshot::decode(blob)
+            .map_err(|e| anyhow!("failed to decode catalog snapshot: {}", e))?;
+        if snapshot.format_version != CATALOG_SNAPSHOT_FORMAT_VERSION {
+            bail!(
+                "unsupported catalog snapshot format version {}, expect {}",
+                snapshot.format_version,
+                CATALOG_SNAPSHOT_FORMAT_VERSION
+            );
+        }
+
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        if !database_core.tables.is_empty()
+            || !database_core.sources.is_empty()
+            || !database_core.sinks.is_empty()
+            || !database_core.indexes.is_empty()
+            || !database_core.views.is_empty()
+            || !database_core.subscriptions.is_empty()
+            || !database_core.functions.is_empty()
+            || !database_core.connections.is_empty()
+            || !database_core.secrets.is_empty()
+        {
+            bail!("catalog snapshot can only be imported into an empty cluster");
+        }
+
+        macro_rules! replace_with_snapshot {
+            ($txn:ident, $map:expr, $items:expr, $id:ident) => {
+                let mut $txn = BTreeMapTransaction::new($map);
+                for id in $txn.tree_ref().keys().cloned().collect_vec() {
+                    $txn.remove(id);
+                }
+                for item in $items {
+                    $txn.insert(item.$id, item);
+                }
+            };
+        }
+        replace_with_snapshot!(databases, &mut database_core.databases, snapshot.databases, id);
+        replace_with_snapshot!(schemas, &mut database_core.schemas, snapshot.schemas, id);
+        replace_with_snapshot!(sources, &mut database_core.sources, snapshot.sources, id);
+        replace_with_snapshot!(sinks, &mut database_core.sinks, snapshot.sinks, id);
+        replace_with_snapshot!(tables, &mut database_core.tables, snapshot.tables, id);
+        replace_with_snapshot!(indexes, &mut database_core.indexes, snapshot.indexes, id);
+        replace_with_snapshot!(views, &mut database_core.views, snapshot.views, id);
+        replace_with_snapshot!(functions, &mut database_core.functions, snapshot.functions, id);
+        replace_with_snapshot!(
+            connections,
+            &mut database_core.connections,
+            snapshot.connections,
+            id
+        );
+        replace_with_snapshot!(
+            subscriptions,
+            &mut database_core.subscriptions,
+            snapshot.subscriptions,
+            id
+        );
+        replace_with_snapshot!(secrets, &mut database_core.secrets, snapshot.secrets, id);
+        let user_core = &mut core.user;
+        replace_with_snapshot!(users, &mut user_core.user_info, snapshot.users, id);
+        commit_meta!(
+            self, databases, schemas, sources, sinks, tables, indexes, views, functions,
+            connections, subscriptions, secrets, users
+        )?;
+
+        // Reload the in-memory core from the meta store to rebuild derived state like
+        // dependency ref counts and grant relations, just as on startup.
+        *core = CatalogManagerCore::new(self.env.clone()).await?;
+        Ok(())
+    }
+
     /// This function is for maintaining backward compatibility with older source formats when `format_encode_options` is
     /// merged into `with_properties`.
     /// Context: <https://github.com/risingwavelabs/risingwave/pull/13762>.
@@ -493,6 +709,18 @@ impl CatalogManager {
             ));
         }
 
+        // Relations in other databases may reference relations in this one. Dropping the
+        // database would leave those references dangling, so the dependents must be
+        // dropped first.
+        let cross_database_dependents = database_core.cross_database_dependents(database_id);
+        if !cross_database_dependents.is_empty() {
+            return Err(MetaError::permission_denied(format!(
+                "Fail to delete database because {} relation(s) in other databases depend on it: {}",
+                cross_database_dependents.len(),
+                cross_database_dependents.join(", ")
+            )));
+        }
+
         let mut databases = BTreeMapTransaction::new(&mut database_core.databases);
         let mut schemas = BTreeMapTransaction::new(&mut database_core.schemas);
         let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
@@ -603,6 +831,12 @@ impl CatalogManager {
                 .for_each(|owner_id| user_core.decrease_ref(owner_id));
 
             // Update relation ref count.
+            let dropped_relation_ids: HashSet<RelationId> = tables_to_drop
+                .iter()
+                .map(|table| table.id)
+                .chain(sources_to_drop.iter().map(|source| source.id))
+                .chain(views_to_drop.iter().map(|view| view.id))
+                .collect();
             for table in &tables_to_drop {
                 database_core.relation_ref_count.remove(&table.id);
             }
@@ -612,6 +846,31 @@ impl CatalogManager {
             for view in &views_to_drop {
                 database_core.relation_ref_count.remove(&view.id);
             }
+            // Release the references that dropped relations hold on relations in other
+            // databases, so that those targets become droppable again.
+            for dependent_relation_id in tables_to_drop
+                .iter()
+                .flat_map(|table| table.dependent_relations.iter())
+                .chain(
+                    sinks_to_drop
+                        .iter()
+                        .flat_map(|sink| sink.dependent_relations.iter()),
+                )
+                .chain(
+                    views_to_drop
+                        .iter()
+                        .flat_map(|view| view.dependent_relations.iter()),
+                )
+                .chain(
+                    subscriptions_to_drop
+                        .iter()
+                        .map(|subscription| &subscription.dependent_table_id),
+                )
+            {
+                if !dropped_relation_ids.contains(dependent_relation_id) {
+                    database_core.decrease_relation_ref_count(*dependent_relation_id);
+                }
+            }
             for connection in &connections_to_drop {
                 database_core.connection_ref_count.remove(&connection.id);
             }
@@ -742,6 +1001,53 @@ impl CatalogManager {
         }
     }
 
+    pub async fn get_secret_name(&self, secret_id: SecretId) -> MetaResult<String> {
+        let core = &mut self.core.lock().await.database;
+        core.secrets
+            .get(&secret_id)
+            .map(|secret| secret.name.clone())
+            .ok_or_else(|| MetaError::catalog_id_not_found("secret", secret_id))
+    }
+
+    /// Replaces the value of an existing secret and bumps its version, then pushes the
+    /// rotated plain value to compute nodes so that connectors referencing the secret
+    /// pick up the new credentials without recreating the jobs.
+    pub async fn rotate_secret(
+        &self,
+        secret_id: SecretId,
+        encrypted_payload: Vec<u8>,
+        secret_plain_payload: Vec<u8>,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let mut secrets = BTreeMapTransaction::new(&mut database_core.secrets);
+
+        let secret = {
+            let mut secret = secrets
+                .get_mut(secret_id)
+                .ok_or_else(|| MetaError::catalog_id_not_found("secret", secret_id))?;
+            secret.value = encrypted_payload;
+            secret.version += 1;
+            secret.clone()
+        };
+        commit_meta!(self, secrets)?;
+
+        // Notify the compute and frontend node plain secret
+        let mut secret_plain = secret;
+        secret_plain.value.clone_from(&secret_plain_payload);
+
+        LocalSecretManager::global().update_secret(secret_id, secret_plain_payload);
+        self.env
+            .notification_manager()
+            .notify_compute_without_version(Operation::Update, Info::Secret(secret_plain.clone()));
+
+        let version = self
+            .notify_frontend(Operation::Update, Info::Secret(secret_plain))
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn create_connection(
         &self,
         connection: Connection,
@@ -813,6 +1119,34 @@ impl CatalogManager {
         }
     }
 
+    /// Record the result of a connection health check and notify frontends, so that
+    /// `SHOW CONNECTIONS` reflects the latest status. Called by the connection health
+    /// checker only when the status or message changed.
+    pub async fn update_connection_health(
+        &self,
+        conn_id: ConnectionId,
+        health_status: PbHealthStatus,
+        health_message: String,
+        checked_at_ms: u64,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_connection_id(conn_id)?;
+
+        let mut connections = BTreeMapTransaction::new(&mut database_core.connections);
+        let mut connection = connections.get_mut(conn_id).unwrap();
+        connection.health_status = health_status as i32;
+        connection.health_message = health_message;
+        connection.last_health_check_ms = checked_at_ms;
+        let connection = connection.clone();
+        commit_meta!(self, connections)?;
+
+        let version = self
+            .notify_frontend(Operation::Update, Info::Connection(connection))
+            .await;
+        Ok(version)
+    }
+
     pub async fn create_schema(&self, schema: &Schema) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
@@ -854,12 +1188,14 @@ impl CatalogManager {
         }
         let mut schemas = BTreeMapTransaction::new(&mut database_core.schemas);
         let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+        let mut default_privileges = BTreeMapTransaction::new(&mut user_core.default_privileges);
         let schema = schemas.remove(schema_id).unwrap();
+        default_privileges.remove(schema_id);
 
         let users_need_update =
             Self::update_user_privileges(&mut users, &[Object::SchemaId(schema_id)]);
 
-        commit_meta!(self, schemas, users)?;
+        commit_meta!(self, schemas, users, default_privileges)?;
 
         user_core.decrease_ref(schema.owner);
 
@@ -874,6 +1210,41 @@ impl CatalogManager {
         Ok(version)
     }
 
+    /// Set or clear the default owner template of a schema. Objects created in the
+    /// schema afterwards are owned by the template owner instead of their creator.
+    pub async fn alter_schema_default_owner(
+        &self,
+        schema_id: SchemaId,
+        default_owner_id: Option<UserId>,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &core.user;
+        database_core.ensure_schema_id(schema_id)?;
+        if let Some(owner_id) = default_owner_id
+            && !user_core.user_info.contains_key(&owner_id)
+        {
+            return Err(MetaError::catalog_id_not_found("user", owner_id));
+        }
+
+        let mut schemas = BTreeMapTransaction::new(&mut database_core.schemas);
+        let schema = {
+            let mut schema = schemas.get_mut(schema_id).unwrap();
+            if schema.default_owner_id == default_owner_id {
+                return Ok(IGNORED_NOTIFICATION_VERSION);
+            }
+            schema.default_owner_id = default_owner_id;
+            schema.clone()
+        };
+        commit_meta!(self, schemas)?;
+
+        let version = self
+            .notify_frontend(Operation::Update, Info::Schema(schema))
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn create_view(&self, view: &View) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
@@ -884,6 +1255,13 @@ impl CatalogManager {
             // TODO(zehua): refactor when using SourceId.
             database_core.ensure_table_view_or_source_id(dependent_id)?;
         }
+        Self::check_cross_database_dependencies(
+            database_core,
+            user_core,
+            view.database_id,
+            view.owner,
+            &view.dependent_relations,
+        )?;
         let key = (view.database_id, view.schema_id, view.name.clone());
         database_core.check_relation_name_duplicated(&key)?;
         #[cfg(not(test))]
@@ -906,6 +1284,295 @@ impl CatalogManager {
         Ok(version)
     }
 
+    /// Applies a batch of DDL operations transactionally: the whole batch is committed
+    /// with a single `commit_meta!`, so either all operations take effect or none do,
+    /// and frontends observe the result as one grouped relation notification per
+    /// direction — dropped relations first, then created ones.
+    ///
+    /// Operations are validated in order against the catalog as it will look once the
+    /// preceding operations of the batch have been applied: a name freed by an earlier
+    /// drop can be reused, and a view may depend on a source created earlier in the
+    /// same batch. Drops follow restrict semantics.
+    ///
+    /// The new sources must have been registered with the source manager by the caller
+    /// beforehand; the ids of the dropped sources are returned so that the caller can
+    /// unregister them afterwards.
+    pub async fn run_ddl_batch(
+        &self,
+        operations: &[DdlBatchOperation],
+    ) -> MetaResult<(NotificationVersion, Vec<SourceId>)> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+
+        // Pass 1: validate all operations upfront, tracking the relations created and
+        // dropped by the preceding operations of the batch.
+        let mut created_ids: HashMap<RelationId, DatabaseId> = HashMap::new();
+        let mut created_keys = HashSet::new();
+        let mut dropped_ids = HashSet::new();
+        let mut dropped_keys = HashSet::new();
+        let mut in_batch_ref_count: HashMap<RelationId, usize> = HashMap::new();
+        for operation in operations {
+            match operation {
+                DdlBatchOperation::CreateSource(source) => {
+                    database_core.ensure_database_id(source.database_id)?;
+                    database_core.ensure_schema_id(source.schema_id)?;
+                    let key = (source.database_id, source.schema_id, source.name.clone());
+                    if created_keys.contains(&key) {
+                        return Err(MetaError::catalog_duplicated("source", &source.name));
+                    }
+                    if !dropped_keys.contains(&key) {
+                        database_core.check_relation_name_duplicated(&key)?;
+                    }
+                    #[cfg(not(test))]
+                    user_core.ensure_user_id(source.owner)?;
+                    if source.info.as_ref().is_some_and(|info| info.is_shared()) {
+                        bail!("shared source `{}` cannot be created in a DDL batch", source.name);
+                    }
+                    if let Some(connection_id) = source.connection_id
+                        && database_core.get_connection(connection_id).is_none()
+                    {
+                        bail!("connection {} not found.", connection_id);
+                    }
+                    // Fails on a malformed source before anything has been applied.
+                    get_refed_secret_ids_from_source(source)?;
+                    created_ids.insert(source.id, source.database_id);
+                    created_keys.insert(key);
+                }
+                DdlBatchOperation::DropSource(source_id) => {
+                    if created_ids.contains_key(source_id) {
+                        bail!("cannot drop a relation created in the same DDL batch");
+                    }
+                    if dropped_ids.contains(source_id) {
+                        return Err(MetaError::catalog_id_not_found("source", *source_id));
+                    }
+                    let source = database_core
+                        .sources
+                        .get(source_id)
+                        .ok_or_else(|| MetaError::catalog_id_not_found("source", *source_id))?;
+                    if source.info.as_ref().is_some_and(|info| info.is_shared()) {
+                        bail!("shared source `{}` cannot be dropped in a DDL batch", source.name);
+                    }
+                    if source.optional_associated_table_id.is_some() {
+                        bail!("source `{}` has an associated table", source.name);
+                    }
+                    let ref_count = database_core
+                        .relation_ref_count
+                        .get(source_id)
+                        .cloned()
+                        .unwrap_or(0)
+                        + in_batch_ref_count.get(source_id).cloned().unwrap_or(0);
+                    if ref_count > 0 {
+                        return Err(MetaError::permission_denied(format!(
+                            "Fail to delete source `{}` because {} other relation(s) depend on it",
+                            source.name, ref_count
+                        )));
+                    }
+                    dropped_keys
+                        .insert((source.database_id, source.schema_id, source.name.clone()));
+                    dropped_ids.insert(*source_id);
+                }
+                DdlBatchOperation::CreateView(view) => {
+                    database_core.ensure_database_id(view.database_id)?;
+                    database_core.ensure_schema_id(view.schema_id)?;
+                    for dependent_id in &view.dependent_relations {
+                        if dropped_ids.contains(dependent_id) {
+                            bail!(
+                                "view `{}` depends on a relation dropped earlier in the batch",
+                                view.name
+                            );
+                        }
+                        if let Some(dependent_database_id) = created_ids.get(dependent_id) {
+                            if *dependent_database_id != view.database_id {
+                                bail!(
+                                    "view `{}` cannot depend on a cross-database relation created \
+                                     in the same batch",
+                                    view.name
+                                );
+                            }
+                        } else {
+                            database_core.ensure_table_view_or_source_id(dependent_id)?;
+                        }
+                    }
+                    Self::check_cross_database_dependencies(
+                        database_core,
+                        user_core,
+                        view.database_id,
+                        view.owner,
+                        view.dependent_relations
+                            .iter()
+                            .filter(|id| !created_ids.contains_key(*id)),
+                    )?;
+                    let key = (view.database_id, view.schema_id, view.name.clone());
+                    if created_keys.contains(&key) {
+                        return Err(MetaError::catalog_duplicated("view", &view.name));
+                    }
+                    if !dropped_keys.contains(&key) {
+                        database_core.check_relation_name_duplicated(&key)?;
+                    }
+                    #[cfg(not(test))]
+                    user_core.ensure_user_id(view.owner)?;
+                    for dependent_id in &view.dependent_relations {
+                        *in_batch_ref_count.entry(*dependent_id).or_default() += 1;
+                    }
+                    created_ids.insert(view.id, view.database_id);
+                    created_keys.insert(key);
+                }
+                DdlBatchOperation::DropView(view_id) => {
+                    if created_ids.contains_key(view_id) {
+                        bail!("cannot drop a relation created in the same DDL batch");
+                    }
+                    if dropped_ids.contains(view_id) {
+                        return Err(MetaError::catalog_id_not_found("view", *view_id));
+                    }
+                    let view = database_core
+                        .views
+                        .get(view_id)
+                        .ok_or_else(|| MetaError::catalog_id_not_found("view", *view_id))?;
+                    let ref_count = database_core
+                        .relation_ref_count
+                        .get(view_id)
+                        .cloned()
+                        .unwrap_or(0)
+                        + in_batch_ref_count.get(view_id).cloned().unwrap_or(0);
+                    if ref_count > 0 {
+                        return Err(MetaError::permission_denied(format!(
+                            "Fail to delete view `{}` because {} other relation(s) depend on it",
+                            view.name, ref_count
+                        )));
+                    }
+                    dropped_keys.insert((view.database_id, view.schema_id, view.name.clone()));
+                    dropped_ids.insert(*view_id);
+                }
+            }
+        }
+
+        // Pass 2: apply all operations to a single transaction and commit it atomically.
+        let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
+        let mut views = BTreeMapTransaction::new(&mut database_core.views);
+        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+
+        let mut added_relations = vec![];
+        let mut dropped_relations = vec![];
+        let mut dropped_objects = vec![];
+        let mut dropped_sources = vec![];
+        let mut dropped_views = vec![];
+        let mut users_need_update: HashMap<UserId, UserInfo> = HashMap::new();
+        for operation in operations {
+            match operation {
+                DdlBatchOperation::CreateSource(source) => {
+                    let mut source = source.clone();
+                    source.created_at_epoch = Some(Epoch::now().0);
+                    sources.insert(source.id, source.clone());
+                    for user in Self::apply_default_privileges(
+                        &mut users,
+                        &user_core.default_privileges,
+                        source.schema_id,
+                        RelationKind::Sources,
+                        Object::SourceId(source.id),
+                        source.owner,
+                    ) {
+                        users_need_update.insert(user.id, user);
+                    }
+                    added_relations.push(RelationInfo::Source(source));
+                }
+                DdlBatchOperation::DropSource(source_id) => {
+                    let source = sources.remove(*source_id).unwrap();
+                    dropped_objects.push(Object::SourceId(source.id));
+                    dropped_relations.push(RelationInfo::Source(source.clone()));
+                    dropped_sources.push(source);
+                }
+                DdlBatchOperation::CreateView(view) => {
+                    views.insert(view.id, view.clone());
+                    added_relations.push(RelationInfo::View(view.clone()));
+                }
+                DdlBatchOperation::DropView(view_id) => {
+                    let view = views.remove(*view_id).unwrap();
+                    dropped_objects.push(Object::ViewId(view.id));
+                    dropped_relations.push(RelationInfo::View(view.clone()));
+                    dropped_views.push(view);
+                }
+            }
+        }
+        // The revoked privileges are computed after the default privileges of the new
+        // sources have been granted, so the states below are the final ones.
+        for user in Self::update_user_privileges(&mut users, &dropped_objects) {
+            users_need_update.insert(user.id, user);
+        }
+        commit_meta!(self, sources, views, users)?;
+
+        // Pass 3: apply the in-memory reference counts, which pass 1 has validated.
+        if !users_need_update.is_empty() {
+            user_core.build_grant_relation_map();
+        }
+        for operation in operations {
+            match operation {
+                DdlBatchOperation::CreateSource(source) => {
+                    user_core.increase_ref(source.owner);
+                    refcnt_inc_source_secret_ref(database_core, source)?;
+                    refcnt_inc_connection(database_core, source.connection_id)?;
+                }
+                DdlBatchOperation::CreateView(view) => {
+                    user_core.increase_ref(view.owner);
+                    for &dependent_relation_id in &view.dependent_relations {
+                        database_core.increase_relation_ref_count(dependent_relation_id);
+                    }
+                }
+                DdlBatchOperation::DropSource(_) | DdlBatchOperation::DropView(_) => {}
+            }
+        }
+        for source in &dropped_sources {
+            user_core.decrease_ref(source.owner);
+            refcnt_dec_connection(database_core, source.connection_id);
+            refcnt_dec_source_secret_ref(database_core, source)?;
+        }
+        for view in &dropped_views {
+            user_core.decrease_ref(view.owner);
+            for &dependent_relation_id in &view.dependent_relations {
+                database_core.decrease_relation_ref_count(dependent_relation_id);
+            }
+        }
+
+        for user in users_need_update.into_values() {
+            self.notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
+        let mut version = self.current_notification_version().await;
+        if !dropped_relations.is_empty() {
+            version = self
+                .notify_frontend(
+                    Operation::Delete,
+                    Info::RelationGroup(RelationGroup {
+                        relations: dropped_relations
+                            .into_iter()
+                            .map(|relation_info| Relation {
+                                relation_info: relation_info.into(),
+                            })
+                            .collect_vec(),
+                    }),
+                )
+                .await;
+        }
+        if !added_relations.is_empty() {
+            version = self
+                .notify_frontend(
+                    Operation::Add,
+                    Info::RelationGroup(RelationGroup {
+                        relations: added_relations
+                            .into_iter()
+                            .map(|relation_info| Relation {
+                                relation_info: relation_info.into(),
+                            })
+                            .collect_vec(),
+                    }),
+                )
+                .await;
+        }
+
+        let dropped_source_ids = dropped_sources.iter().map(|source| source.id).collect();
+        Ok((version, dropped_source_ids))
+    }
+
     pub async fn create_function(&self, function: &Function) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
@@ -936,23 +1603,87 @@ impl CatalogManager {
         Ok(version)
     }
 
-    pub async fn drop_function(&self, function_id: FunctionId) -> MetaResult<NotificationVersion> {
+    /// Replaces the definition of the function with the same signature in place, keeping its id
+    /// and owner, or creates a new one if there is none. The caller should have assigned a fresh
+    /// id to `function`, which is only used in the latter case.
+    pub async fn create_or_replace_function(
+        &self,
+        function: &Function,
+    ) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
         let user_core = &mut core.user;
-        let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
-        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+        database_core.ensure_database_id(function.database_id)?;
+        database_core.ensure_schema_id(function.schema_id)?;
 
-        let function = functions
-            .remove(function_id)
-            .ok_or_else(|| MetaError::catalog_id_not_found("function", function_id))?;
+        #[cfg(not(test))]
+        user_core.ensure_user_id(function.owner)?;
 
-        let objects = &[Object::FunctionId(function_id)];
-        let users_need_update = Self::update_user_privileges(&mut users, objects);
+        let existing_id = database_core
+            .functions
+            .values()
+            .find(|f| {
+                f.database_id == function.database_id
+                    && f.schema_id == function.schema_id
+                    && f.name == function.name
+                    && f.arg_types == function.arg_types
+            })
+            .map(|f| f.id);
 
-        commit_meta!(self, functions, users)?;
+        let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
+        match existing_id {
+            Some(function_id) => {
+                // Swap the definition in place. The function keeps its id and owner, so
+                // references from expressions and privileges stay intact. Compute nodes
+                // cache UDF runtimes by the content of the binary, so a replaced binary
+                // automatically gets a fresh runtime.
+                tracing::debug!("replace function: {:?}", function);
+                let old_owner = functions.get(&function_id).unwrap().owner;
+                let mut function = function.clone();
+                function.id = function_id;
+                function.owner = old_owner;
+                functions.insert(function_id, function.clone());
+                commit_meta!(self, functions)?;
 
-        user_core.decrease_ref(function.owner);
+                let version = self
+                    .notify_frontend(Operation::Update, Info::Function(function))
+                    .await;
+
+                Ok(version)
+            }
+            None => {
+                tracing::debug!("create function: {:?}", function);
+                functions.insert(function.id, function.clone());
+                commit_meta!(self, functions)?;
+
+                user_core.increase_ref(function.owner);
+
+                let version = self
+                    .notify_frontend(Operation::Add, Info::Function(function.to_owned()))
+                    .await;
+
+                Ok(version)
+            }
+        }
+    }
+
+    pub async fn drop_function(&self, function_id: FunctionId) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+        let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
+        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+
+        let function = functions
+            .remove(function_id)
+            .ok_or_else(|| MetaError::catalog_id_not_found("function", function_id))?;
+
+        let objects = &[Object::FunctionId(function_id)];
+        let users_need_update = Self::update_user_privileges(&mut users, objects);
+
+        commit_meta!(self, functions, users)?;
+
+        user_core.decrease_ref(function.owner);
 
         for user in users_need_update {
             self.notify_frontend(Operation::Update, Info::User(user))
@@ -968,11 +1699,211 @@ impl CatalogManager {
 
     /// Marks current relation as "creating" and add reference count to dependent relations.
     /// And persists internal tables for background DDL progress tracking.
+    /// Sets or clears the streaming job quota of a user or database. A quota with all
+    /// limits unset removes the entry of its scope.
+    pub async fn set_streaming_job_quota(&self, quota: StreamingJobQuota) -> MetaResult<()> {
+        let key = quota.key()?;
+        let core = &mut *self.core.lock().await;
+        match quota.scope.expect("checked by key()") {
+            QuotaScope::UserId(user_id) => core.user.ensure_user_id(user_id)?,
+            QuotaScope::DatabaseId(database_id) => {
+                core.database.ensure_database_id(database_id)?
+            }
+        }
+        let mut quotas = BTreeMapTransaction::new(&mut core.database.streaming_job_quotas);
+        if quota.max_mvs.is_none()
+            && quota.max_sinks.is_none()
+            && quota.max_total_parallelism.is_none()
+            && quota.max_state_size_bytes.is_none()
+        {
+            quotas.remove(key);
+        } else {
+            quotas.insert(key, quota);
+        }
+        commit_meta!(self, quotas)
+    }
+
+    pub async fn list_streaming_job_quotas(&self) -> Vec<StreamingJobQuota> {
+        self.core
+            .lock()
+            .await
+            .database
+            .streaming_job_quotas
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Enforces the streaming job quotas of the owner and the database of `stream_job`,
+    /// if any. The parallelism and state size limits are admission checks: a new job is
+    /// rejected once the usage of existing jobs in the scope has reached the limit.
+    async fn check_streaming_job_quota(
+        &self,
+        stream_job: &StreamingJob,
+        fragment_manager: &FragmentManagerRef,
+    ) -> MetaResult<()> {
+        #[derive(Default)]
+        struct QuotaUsage {
+            mvs: u64,
+            sinks: u64,
+            parallelism: u64,
+            state_size: u64,
+        }
+
+        let owner = stream_job.owner();
+        let database_id = stream_job.database_id();
+
+        // (scope description, in-scope predicate, quota, usage)
+        type InScope = Box<dyn Fn(u32, u32) -> bool + Send>;
+        let (mut scopes, table_ownership): (Vec<(String, InScope, StreamingJobQuota, QuotaUsage)>, _) = {
+            let core = self.core.lock().await;
+            let database_core = &core.database;
+            let mut scopes: Vec<(String, InScope, StreamingJobQuota, QuotaUsage)> = vec![];
+            if let Some(quota) = database_core
+                .streaming_job_quotas
+                .get(&format!("user/{owner}"))
+            {
+                scopes.push((
+                    format!("user {owner}"),
+                    Box::new(move |o, _| o == owner),
+                    quota.clone(),
+                    QuotaUsage::default(),
+                ));
+            }
+            if let Some(quota) = database_core
+                .streaming_job_quotas
+                .get(&format!("database/{database_id}"))
+            {
+                scopes.push((
+                    format!("database {database_id}"),
+                    Box::new(move |_, d| d == database_id),
+                    quota.clone(),
+                    QuotaUsage::default(),
+                ));
+            }
+            if scopes.is_empty() {
+                return Ok(());
+            }
+
+            for table in database_core
+                .tables
+                .values()
+                .chain(database_core.in_progress_creating_tables.values())
+            {
+                if table.table_type == TableType::MaterializedView as i32 {
+                    for (_, in_scope, _, usage) in &mut scopes {
+                        if in_scope(table.owner, table.database_id) {
+                            usage.mvs += 1;
+                        }
+                    }
+                }
+            }
+            for sink in database_core.sinks.values() {
+                for (_, in_scope, _, usage) in &mut scopes {
+                    if in_scope(sink.owner, sink.database_id) {
+                        usage.sinks += 1;
+                    }
+                }
+            }
+
+            // All state tables, including internal ones, carry the owner and database of
+            // their job, which is enough to attribute parallelism and state size.
+            let table_ownership: HashMap<u32, (u32, u32)> = database_core
+                .tables
+                .values()
+                .map(|t| (t.id, (t.owner, t.database_id)))
+                .chain(
+                    database_core
+                        .sinks
+                        .values()
+                        .map(|s| (s.id, (s.owner, s.database_id))),
+                )
+                .collect();
+            (scopes, table_ownership)
+        };
+
+        if scopes.iter().any(|(_, _, q, _)| q.max_total_parallelism.is_some()) {
+            let guard = fragment_manager.get_fragment_read_guard().await;
+            for (job_id, table_fragments) in guard.table_fragments() {
+                let Some((job_owner, job_db)) = table_ownership.get(&job_id.table_id) else {
+                    continue;
+                };
+                let actor_count = table_fragments.actor_ids().len() as u64;
+                for (_, in_scope, _, usage) in &mut scopes {
+                    if in_scope(*job_owner, *job_db) {
+                        usage.parallelism += actor_count;
+                    }
+                }
+            }
+        }
+
+        if scopes.iter().any(|(_, _, q, _)| q.max_state_size_bytes.is_some())
+            && let Some(stats) = HummockVersionStats::list(self.env.meta_store().as_kv())
+                .await?
+                .into_iter()
+                .next()
+        {
+            for (table_id, table_stats) in stats.table_stats {
+                let Some((table_owner, table_db)) = table_ownership.get(&table_id) else {
+                    continue;
+                };
+                let size =
+                    (table_stats.total_key_size + table_stats.total_value_size).max(0) as u64;
+                for (_, in_scope, _, usage) in &mut scopes {
+                    if in_scope(*table_owner, *table_db) {
+                        usage.state_size += size;
+                    }
+                }
+            }
+        }
+
+        for (desc, _, quota, usage) in &scopes {
+            if let Some(max_mvs) = quota.max_mvs
+                && matches!(stream_job, StreamingJob::MaterializedView(_))
+                && usage.mvs >= max_mvs as u64
+            {
+                return Err(MetaError::permission_denied(format!(
+                    "materialized view quota of {desc} exceeded: limit {max_mvs}, in use {}",
+                    usage.mvs
+                )));
+            }
+            if let Some(max_sinks) = quota.max_sinks
+                && matches!(stream_job, StreamingJob::Sink(..))
+                && usage.sinks >= max_sinks as u64
+            {
+                return Err(MetaError::permission_denied(format!(
+                    "sink quota of {desc} exceeded: limit {max_sinks}, in use {}",
+                    usage.sinks
+                )));
+            }
+            if let Some(max_parallelism) = quota.max_total_parallelism
+                && usage.parallelism >= max_parallelism as u64
+            {
+                return Err(MetaError::permission_denied(format!(
+                    "total parallelism quota of {desc} exceeded: limit {max_parallelism}, in use {}",
+                    usage.parallelism
+                )));
+            }
+            if let Some(max_state_size) = quota.max_state_size_bytes
+                && usage.state_size >= max_state_size
+            {
+                return Err(MetaError::permission_denied(format!(
+                    "state size quota of {desc} exceeded: limit {max_state_size} bytes, in use {} bytes",
+                    usage.state_size
+                )));
+            }
+        }
+        Ok(())
+    }
+
     pub async fn start_create_stream_job_procedure(
         &self,
         stream_job: &StreamingJob,
         internal_tables: Vec<Table>,
+        fragment_manager: &FragmentManagerRef,
     ) -> MetaResult<()> {
+        self.check_streaming_job_quota(stream_job, fragment_manager)
+            .await?;
         match stream_job {
             StreamingJob::MaterializedView(table) => {
                 self.start_create_materialized_view_procedure(table, internal_tables)
@@ -1040,6 +1971,52 @@ impl CatalogManager {
             .await;
     }
 
+    /// Ensures `owner` may read the dependent relations that live outside `database_id`.
+    /// Same-database references are validated by the frontend during binding, but
+    /// cross-database references resolve against another database's catalog, so the
+    /// owner must be a superuser, own the referenced relation, or hold a SELECT grant
+    /// on it.
+    fn check_cross_database_dependencies<'a>(
+        database_core: &DatabaseManager,
+        user_core: &UserManager,
+        database_id: DatabaseId,
+        owner: UserId,
+        dependent_relations: impl IntoIterator<Item = &'a RelationId>,
+    ) -> MetaResult<()> {
+        for &dependent_relation_id in dependent_relations {
+            let Some((dependent_database_id, object)) =
+                database_core.find_relation_object(dependent_relation_id)
+            else {
+                // Existence is checked separately by `ensure_table_view_or_source_id`.
+                continue;
+            };
+            if dependent_database_id == database_id {
+                continue;
+            }
+            // The owner is ensured to exist by the caller, except in tests.
+            let Some(user) = user_core.user_info.get(&owner) else {
+                continue;
+            };
+            if user.is_super || database_core.get_object_owner(&object)? == owner {
+                continue;
+            }
+            let has_select = user.grant_privileges.iter().any(|privilege| {
+                privilege.object == Some(object.clone())
+                    && privilege
+                        .action_with_opts
+                        .iter()
+                        .any(|option| option.action == Action::Select as i32)
+            });
+            if !has_select {
+                return Err(MetaError::permission_denied(format!(
+                    "SELECT privilege on relation {} is required to reference it from another database",
+                    dependent_relation_id
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// This is used for both `CREATE TABLE`
     pub async fn start_create_table_procedure(&self, table: &Table) -> MetaResult<()> {
         let core = &mut *self.core.lock().await;
@@ -1050,6 +2027,13 @@ impl CatalogManager {
         for dependent_id in &table.dependent_relations {
             database_core.ensure_table_view_or_source_id(dependent_id)?;
         }
+        Self::check_cross_database_dependencies(
+            database_core,
+            user_core,
+            table.database_id,
+            table.owner,
+            &table.dependent_relations,
+        )?;
         #[cfg(not(test))]
         user_core.ensure_user_id(table.owner)?;
         let key = (table.database_id, table.schema_id, table.name.clone());
@@ -1083,6 +2067,13 @@ impl CatalogManager {
         for dependent_id in &table.dependent_relations {
             database_core.ensure_table_view_or_source_id(dependent_id)?;
         }
+        Self::check_cross_database_dependencies(
+            database_core,
+            user_core,
+            table.database_id,
+            table.owner,
+            &table.dependent_relations,
+        )?;
         #[cfg(not(test))]
         user_core.ensure_user_id(table.owner)?;
         let key = (table.database_id, table.schema_id, table.name.clone());
@@ -1337,6 +2328,169 @@ impl CatalogManager {
         Ok(())
     }
 
+    /// Returns the set of internal table ids referenced by any table fragments.
+    async fn referenced_internal_table_ids(
+        fragment_manager: &FragmentManagerRef,
+    ) -> HashSet<TableId> {
+        let guard = fragment_manager.get_fragment_read_guard().await;
+        guard
+            .table_fragments()
+            .values()
+            .flat_map(|tf| tf.internal_table_ids())
+            .collect()
+    }
+
+    /// Lists internal tables in `Created` state that are not referenced by any table
+    /// fragments, i.e. left behind by an interrupted drop. Creating internal tables are
+    /// excluded: they belong to background jobs whose fragments may not be persisted
+    /// yet, and are cleaned by [`Self::clean_dirty_tables`] during recovery instead.
+    pub async fn list_orphaned_internal_table_ids(
+        &self,
+        fragment_manager: FragmentManagerRef,
+    ) -> HashSet<TableId> {
+        let core = self.core.lock().await;
+        let referenced = Self::referenced_internal_table_ids(&fragment_manager).await;
+        core.database
+            .tables
+            .values()
+            .filter(|t| {
+                t.table_type == TableType::Internal as i32
+                    && t.stream_job_status == PbStreamJobStatus::Created as i32
+                    && !referenced.contains(&t.id)
+            })
+            .map(|t| t.id)
+            .collect()
+    }
+
+    /// Removes the given internal tables from the catalog if they are still orphaned,
+    /// re-verifying under the catalog lock, and notifies frontends of the deletion.
+    /// Returns the catalogs of the removed tables.
+    pub async fn clean_orphaned_internal_tables(
+        &self,
+        candidates: &HashSet<TableId>,
+        fragment_manager: FragmentManagerRef,
+    ) -> MetaResult<Vec<Table>> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let referenced = Self::referenced_internal_table_ids(&fragment_manager).await;
+        let tables_to_clean = database_core
+            .tables
+            .values()
+            .filter(|t| {
+                candidates.contains(&t.id)
+                    && t.table_type == TableType::Internal as i32
+                    && t.stream_job_status == PbStreamJobStatus::Created as i32
+                    && !referenced.contains(&t.id)
+            })
+            .cloned()
+            .collect_vec();
+        if tables_to_clean.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        for table in &tables_to_clean {
+            tables.remove(table.id);
+        }
+        commit_meta!(self, tables)?;
+
+        let relations = tables_to_clean
+            .iter()
+            .map(|table| Relation {
+                relation_info: RelationInfo::Table(table.clone()).into(),
+            })
+            .collect_vec();
+        self.notify_frontend(
+            Operation::Delete,
+            Info::RelationGroup(RelationGroup { relations }),
+        )
+        .await;
+        Ok(tables_to_clean)
+    }
+
+    /// Lists in-progress creating streaming jobs that have no table fragments, i.e.
+    /// whose creating procedure apparently died without cleaning up its marker. A job
+    /// is legitimately fragment-less between marking creation and building its graph,
+    /// so callers must only clean entries that stay fragment-less across scans.
+    pub async fn list_dangling_creating_job_ids(
+        &self,
+        fragment_manager: FragmentManagerRef,
+    ) -> HashSet<TableId> {
+        let core = self.core.lock().await;
+        let guard = fragment_manager.get_fragment_read_guard().await;
+        core.database
+            .in_progress_creating_streaming_job
+            .keys()
+            .filter(|id| !guard.table_fragments().contains_key(&(**id).into()))
+            .cloned()
+            .collect()
+    }
+
+    /// Unmarks the given creating streaming jobs if they are still fragment-less,
+    /// notifying any sessions waiting on them with a cancelled error. Returns the
+    /// `(id, name)` of the removed entries.
+    pub async fn clean_dangling_creating_jobs(
+        &self,
+        candidates: &HashSet<TableId>,
+        fragment_manager: FragmentManagerRef,
+    ) -> Vec<(TableId, String)> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let fragment_less: Vec<TableId> = {
+            let guard = fragment_manager.get_fragment_read_guard().await;
+            candidates
+                .iter()
+                .filter(|id| !guard.table_fragments().contains_key(&(**id).into()))
+                .cloned()
+                .collect()
+        };
+        let mut cleaned = vec![];
+        for job_id in fragment_less {
+            let Some(key) = database_core
+                .in_progress_creating_streaming_job
+                .get(&job_id)
+                .cloned()
+            else {
+                continue;
+            };
+            database_core.unmark_creating(&key);
+            database_core.unmark_creating_streaming_job(job_id);
+            cleaned.push((job_id, key.2));
+        }
+        cleaned
+    }
+
+    /// Fences a replayed `finish_create_*` call against the already-committed catalog entry.
+    ///
+    /// After a meta failover, the `finish` of a streaming job may be delivered again even
+    /// though a previous incarnation of the meta node has already committed it. The creation
+    /// epoch recorded in the catalog acts as the fencing token: a replay carries the same
+    /// token and is answered with the prior result, while a mismatch means the id has been
+    /// reused by a different job and the call must not touch it.
+    fn check_finish_fencing(
+        kind: &str,
+        id: u32,
+        committed_token: Option<u64>,
+        incoming_token: Option<u64>,
+    ) -> MetaResult<()> {
+        if committed_token == incoming_token {
+            tracing::info!(
+                kind,
+                id,
+                "catalog entry is already committed, answering replayed finish with the prior result"
+            );
+            Ok(())
+        } else {
+            bail!(
+                "finish of {} {} fenced: creation epoch {:?} does not match the committed {:?}",
+                kind,
+                id,
+                incoming_token,
+                committed_token
+            );
+        }
+    }
+
     /// `finish_stream_job` finishes a stream job and clean some states.
     pub async fn finish_stream_job(
         &self,
@@ -1427,12 +2581,31 @@ impl CatalogManager {
     ) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
+        let user_core = &mut core.user;
         let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
         let key = (table.database_id, table.schema_id, table.name.clone());
+        if !database_core.in_progress_creation_tracker.contains(&key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. Return the prior result instead of asserting.
+            return match tables.get(&table.id) {
+                Some(committed) => {
+                    Self::check_finish_fencing(
+                        "table",
+                        table.id,
+                        committed.initialized_at_epoch,
+                        table.initialized_at_epoch,
+                    )?;
+                    Ok(self.current_notification_version().await)
+                }
+                None => Err(MetaError::cancelled(format!(
+                    "table {} has been cancelled",
+                    table.id
+                ))),
+            };
+        }
         assert!(
-            !tables.contains_key(&table.id)
-                && database_core.in_progress_creation_tracker.contains(&key),
-            "table must be in creating procedure"
+            !tables.contains_key(&table.id),
+            "table must not exist while in creating procedure"
         );
         database_core.in_progress_creation_tracker.remove(&key);
         database_core
@@ -1445,10 +2618,23 @@ impl CatalogManager {
             table.stream_job_status = PbStreamJobStatus::Created.into();
             tables.insert(table.id, table.clone());
         }
-        commit_meta!(self, tables)?;
+        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+        let users_need_update = Self::apply_default_privileges(
+            &mut users,
+            &user_core.default_privileges,
+            table.schema_id,
+            RelationKind::Tables,
+            Object::TableId(table.id),
+            table.owner,
+        );
+        commit_meta!(self, tables, users)?;
+
+        if !users_need_update.is_empty() {
+            user_core.build_grant_relation_map();
+        }
 
         tracing::debug!(id = ?table.id, "notifying frontend");
-        let version = self
+        let mut version = self
             .notify_frontend(
                 Operation::Add,
                 Info::RelationGroup(RelationGroup {
@@ -1463,6 +2649,11 @@ impl CatalogManager {
                 }),
             )
             .await;
+        for user in users_need_update {
+            version = self
+                .notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
 
         Ok(version)
     }
@@ -1477,6 +2668,19 @@ impl CatalogManager {
         let database_core = &mut core.database;
         let tables = &mut database_core.tables;
         if cfg!(not(test)) {
+            if let Some(committed) = tables.get(&table.id)
+                && committed.get_stream_job_status() == Ok(StreamJobStatus::Created)
+            {
+                // The procedure has already been finished, e.g. by a replayed call
+                // after a meta failover. Return the prior result instead of asserting.
+                Self::check_finish_fencing(
+                    "materialized view",
+                    table.id,
+                    committed.initialized_at_epoch,
+                    table.initialized_at_epoch,
+                )?;
+                return Ok(self.current_notification_version().await);
+            }
             Self::check_table_creating(tables, &table)?;
         }
         let mut tables = BTreeMapTransaction::new(tables);
@@ -1612,10 +2816,19 @@ impl CatalogManager {
         let database_core = &mut core.database;
         let user_core = &mut core.user;
         let key = (table.database_id, table.schema_id, table.name.clone());
+        if !database_core.has_in_progress_creation(&key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. The ref counts have been released by the first
+            // cancel, so this must not decrease them again.
+            tracing::warn!(
+                id = table.id,
+                "table is not in creating procedure, ignoring replayed cancel"
+            );
+            return;
+        }
         assert!(
-            !database_core.tables.contains_key(&table.id)
-                && database_core.has_in_progress_creation(&key),
-            "table must be in creating procedure"
+            !database_core.tables.contains_key(&table.id),
+            "table must not exist while in creating procedure"
         );
         database_core.unmark_creating(&key);
         database_core.unmark_creating_streaming_job(table.id);
@@ -1838,6 +3051,9 @@ impl CatalogManager {
                         {
                             // Other relations depend on it.
                             match drop_mode {
+                                DropMode::SoftDelete => {
+                                    unreachable!("soft delete is handled in the ddl controller")
+                                }
                                 DropMode::Restrict => {
                                     return Err(MetaError::permission_denied(format!(
                                         "Fail to delete index table `{}` because {} other relation(s) depend on it",
@@ -1861,6 +3077,9 @@ impl CatalogManager {
                         if ref_count > index_ids.len() {
                             // Other relations depend on it.
                             match drop_mode {
+                                DropMode::SoftDelete => {
+                                    unreachable!("soft delete is handled in the ddl controller")
+                                }
                                 DropMode::Restrict => {
                                     return Err(MetaError::permission_denied(format!(
                                         "Fail to delete table `{}` because {} other relation(s) depend on it",
@@ -1920,6 +3139,9 @@ impl CatalogManager {
                         if ref_count > 0 {
                             // Other relations depend on it.
                             match drop_mode {
+                                DropMode::SoftDelete => {
+                                    unreachable!("soft delete is handled in the ddl controller")
+                                }
                                 DropMode::Restrict => {
                                     return Err(MetaError::permission_denied(format!(
                                         "Fail to delete index `{}` because {} other relation(s) depend on it",
@@ -1958,6 +3180,9 @@ impl CatalogManager {
                         if ref_count > 0 {
                             // Other relations depend on it.
                             match drop_mode {
+                                DropMode::SoftDelete => {
+                                    unreachable!("soft delete is handled in the ddl controller")
+                                }
                                 DropMode::Restrict => {
                                     return Err(MetaError::permission_denied(format!(
                                         "Fail to delete source `{}` because {} other relation(s) depend on it",
@@ -1985,6 +3210,9 @@ impl CatalogManager {
                         if ref_count > 0 {
                             // Other relations depend on it.
                             match drop_mode {
+                                DropMode::SoftDelete => {
+                                    unreachable!("soft delete is handled in the ddl controller")
+                                }
                                 DropMode::Restrict => {
                                     return Err(MetaError::permission_denied(format!(
                                         "Fail to delete view `{}` because {} other relation(s) depend on it",
@@ -2016,6 +3244,9 @@ impl CatalogManager {
                         if ref_count > 0 {
                             // Other relations depend on it.
                             match drop_mode {
+                                DropMode::SoftDelete => {
+                                    unreachable!("soft delete is handled in the ddl controller")
+                                }
                                 DropMode::Restrict => {
                                     return Err(MetaError::permission_denied(format!(
                                         "Fail to delete sink `{}` because {} other relation(s) depend on it",
@@ -2045,6 +3276,9 @@ impl CatalogManager {
                         if ref_count > 0 {
                             // Other relations depend on it.
                             match drop_mode {
+                                DropMode::SoftDelete => {
+                                    unreachable!("soft delete is handled in the ddl controller")
+                                }
                                 DropMode::Restrict => {
                                     return Err(MetaError::permission_denied(format!(
                                         "Fail to delete subscription `{}` because {} other relation(s) depend on it",
@@ -2308,27 +3542,182 @@ impl CatalogManager {
             vec![],
             vec![],
             vec![],
+            vec![],
             source,
         )
         .await
     }
 
-    // TODO: refactor dependency cache in catalog manager for better performance.
-    #[allow(clippy::too_many_arguments)]
-    async fn alter_relation_name_refs_inner(
+    /// Renames a materialized view and cascades into its indexes: indexes following the
+    /// default `<mv>_<col>_idx` naming convention are renamed together with their index
+    /// tables, while custom index names only get their `ON <mv>` reference rewritten. All
+    /// affected relations are committed atomically in one `commit_meta!`.
+    pub async fn alter_mv_name(
         &self,
-        database_mgr: &mut DatabaseManager,
-        relation_id: RelationId,
-        from: &str,
-        to: &str,
-        mut to_update_tables: Vec<Table>,
-        mut to_update_views: Vec<View>,
-        mut to_update_sinks: Vec<Sink>,
-        mut to_update_subscriptions: Vec<Subscription>,
-        to_update_source: Option<Source>,
+        table_id: TableId,
+        mv_name: &str,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_table_id(table_id)?;
+
+        // 1. validate new mv name.
+        let mut table = database_core.tables.get(&table_id).unwrap().clone();
+        let old_name = table.name.clone();
+        database_core.check_relation_name_duplicated(&(
+            table.database_id,
+            table.schema_id,
+            mv_name.to_string(),
+        ))?;
+
+        // 2. rename mv and its definition.
+        table.name = mv_name.to_string();
+        table.definition = alter_relation_rename(&table.definition, mv_name);
+        let mut to_update_tables = vec![table];
+
+        // 3. cascade into indexes on this mv.
+        let mut to_update_indexes = vec![];
+        let convention_prefix = format!("{old_name}_");
+        for index in database_core.indexes.values() {
+            if index.primary_table_id != table_id {
+                continue;
+            }
+            let mut index = index.clone();
+            let mut index_table = database_core
+                .tables
+                .get(&index.index_table_id)
+                .unwrap()
+                .clone();
+            index_table.definition =
+                alter_relation_rename_refs(&index_table.definition, &old_name, mv_name);
+            if let Some(suffix) = index.name.strip_prefix(&convention_prefix) {
+                let new_index_name = format!("{mv_name}_{suffix}");
+                database_core.check_relation_name_duplicated(&(
+                    index.database_id,
+                    index.schema_id,
+                    new_index_name.clone(),
+                ))?;
+                index.name = new_index_name.clone();
+                index_table.name = new_index_name.clone();
+                index_table.definition =
+                    alter_relation_rename(&index_table.definition, &new_index_name);
+                to_update_indexes.push(index);
+            }
+            to_update_tables.push(index_table);
+        }
+
+        // 4. update all other relations that depend on this mv.
+        self.alter_relation_name_refs_inner(
+            database_core,
+            table_id,
+            &old_name,
+            mv_name,
+            to_update_tables,
+            to_update_indexes,
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )
+        .await
+    }
+
+    /// Soft-drops a table or materialized view: marks it dropped and hides it from
+    /// frontends, while keeping its catalog and fragments so that it can be restored via
+    /// [`Self::undrop_table`] until the retention window elapses.
+    pub async fn soft_drop_table(&self, table_id: TableId) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_table_id(table_id)?;
+
+        let mut table = database_core.tables.get(&table_id).unwrap().clone();
+        if table.soft_dropped_at_ms.is_some() {
+            bail!("table {} has already been soft-dropped", table.name);
+        }
+        // Like `DropMode::Restrict`, refuse when other relations still depend on it.
+        if let Some(ref_count) = database_core.relation_ref_count.get(&table_id) {
+            return Err(MetaError::permission_denied(format!(
+                "Fail to soft-drop table {} because {} other relation(s) depend on it",
+                table.name, ref_count
+            )));
+        }
+
+        table.soft_dropped_at_ms = Some(Epoch::physical_now());
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        tables.insert(table_id, table.clone());
+        commit_meta!(self, tables)?;
+
+        // Hide the table from frontends. The catalog entry itself is kept.
+        let version = self
+            .notify_frontend_relation_info(Operation::Delete, RelationInfo::Table(table))
+            .await;
+        Ok(version)
+    }
+
+    /// Restores a soft-dropped table or materialized view, making it visible to frontends
+    /// again.
+    pub async fn undrop_table(&self, table_id: TableId) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_table_id(table_id)?;
+
+        let mut table = database_core.tables.get(&table_id).unwrap().clone();
+        if table.soft_dropped_at_ms.is_none() {
+            bail!("table {} is not soft-dropped", table.name);
+        }
+
+        table.soft_dropped_at_ms = None;
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        tables.insert(table_id, table.clone());
+        commit_meta!(self, tables)?;
+
+        let version = self
+            .notify_frontend_relation_info(Operation::Add, RelationInfo::Table(table))
+            .await;
+        Ok(version)
+    }
+
+    /// Returns the ids of soft-dropped tables whose drop time is older than the cutoff,
+    /// i.e. whose retention window has elapsed and which should be dropped for real.
+    pub async fn list_expired_soft_dropped_tables(&self, cutoff_ms: u64) -> Vec<TableId> {
+        let core = self.core.lock().await;
+        core.database
+            .tables
+            .values()
+            .filter(|table| {
+                table
+                    .soft_dropped_at_ms
+                    .is_some_and(|dropped_at| dropped_at < cutoff_ms)
+            })
+            .map(|table| table.id)
+            .collect()
+    }
+
+    /// Rewrites the definitions of all relations depending on `relation_id`, including
+    /// dependents in other databases: the rewriter matches the trailing identifier of a
+    /// qualified name, so `db.schema.old_name` references are renamed as well.
+    // TODO: refactor dependency cache in catalog manager for better performance.
+    #[allow(clippy::too_many_arguments)]
+    async fn alter_relation_name_refs_inner(
+        &self,
+        database_mgr: &mut DatabaseManager,
+        relation_id: RelationId,
+        from: &str,
+        to: &str,
+        mut to_update_tables: Vec<Table>,
+        to_update_indexes: Vec<Index>,
+        mut to_update_views: Vec<View>,
+        mut to_update_sinks: Vec<Sink>,
+        mut to_update_subscriptions: Vec<Subscription>,
+        to_update_source: Option<Source>,
     ) -> MetaResult<NotificationVersion> {
+        // Tables already rewritten by the caller, e.g. renamed index tables, must not be
+        // overwritten by the stale clones collected below.
+        let updated_table_ids: HashSet<_> = to_update_tables.iter().map(|table| table.id).collect();
         for table in database_mgr.tables.values() {
-            if table.dependent_relations.contains(&relation_id) {
+            if !updated_table_ids.contains(&table.id)
+                && table.dependent_relations.contains(&relation_id)
+            {
                 let mut table = table.clone();
                 table.definition = alter_relation_rename_refs(&table.definition, from, to);
                 to_update_tables.push(table);
@@ -2364,6 +3753,7 @@ impl CatalogManager {
 
         // commit meta.
         let mut tables = BTreeMapTransaction::new(&mut database_mgr.tables);
+        let mut indexes = BTreeMapTransaction::new(&mut database_mgr.indexes);
         let mut views = BTreeMapTransaction::new(&mut database_mgr.views);
         let mut sinks = BTreeMapTransaction::new(&mut database_mgr.sinks);
         let mut subscriptions: BTreeMapTransaction<'_, u32, risingwave_pb::catalog::Subscription> =
@@ -2372,6 +3762,9 @@ impl CatalogManager {
         to_update_tables.iter().for_each(|table| {
             tables.insert(table.id, table.clone());
         });
+        to_update_indexes.iter().for_each(|index| {
+            indexes.insert(index.id, index.clone());
+        });
         to_update_views.iter().for_each(|view| {
             views.insert(view.id, view.clone());
         });
@@ -2384,11 +3777,12 @@ impl CatalogManager {
         if let Some(source) = &to_update_source {
             sources.insert(source.id, source.clone());
         }
-        commit_meta!(self, tables, views, sinks, sources, subscriptions)?;
+        commit_meta!(self, tables, indexes, views, sinks, sources, subscriptions)?;
 
         // 5. notify frontend.
         assert!(
             !to_update_tables.is_empty()
+                || !to_update_indexes.is_empty()
                 || !to_update_views.is_empty()
                 || !to_update_sinks.is_empty()
                 || !to_update_subscriptions.is_empty()
@@ -2403,6 +3797,9 @@ impl CatalogManager {
                         .map(|table| Relation {
                             relation_info: RelationInfo::Table(table).into(),
                         })
+                        .chain(to_update_indexes.into_iter().map(|index| Relation {
+                            relation_info: RelationInfo::Index(index).into(),
+                        }))
                         .chain(to_update_views.into_iter().map(|view| Relation {
                             relation_info: RelationInfo::View(view).into(),
                         }))
@@ -2456,6 +3853,7 @@ impl CatalogManager {
             &old_name,
             view_name,
             vec![],
+            vec![],
             vec![view],
             vec![],
             vec![],
@@ -2570,11 +3968,46 @@ impl CatalogManager {
             vec![],
             vec![],
             vec![],
+            vec![],
             Some(source),
         )
         .await
     }
 
+    pub async fn alter_function_name(
+        &self,
+        function_id: FunctionId,
+        function_name: &str,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_function_id(function_id)?;
+
+        // 1. validate new function name. Overloads of the new name with different argument
+        // types are allowed.
+        let mut function = database_core.functions.get(&function_id).unwrap().clone();
+        database_core.check_function_duplicated(&(
+            function.database_id,
+            function.schema_id,
+            function_name.to_string(),
+            function.arg_types.clone(),
+        ))?;
+
+        // 2. rename function.
+        function.name = function_name.to_string();
+
+        // 3. commit meta.
+        let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
+        functions.insert(function_id, function.clone());
+        commit_meta!(self, functions)?;
+
+        let version = self
+            .notify_frontend(Operation::Update, Info::Function(function))
+            .await;
+
+        Ok(version)
+    }
+
     pub async fn alter_schema_name(
         &self,
         schema_id: SchemaId,
@@ -2632,7 +4065,71 @@ impl CatalogManager {
         Ok(version)
     }
 
-    pub async fn alter_source_column(&self, source: Source) -> MetaResult<NotificationVersion> {
+    pub async fn alter_database_barrier_interval(
+        &self,
+        database_id: DatabaseId,
+        barrier_interval_ms: Option<u32>,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_database_id(database_id)?;
+
+        let mut database = database_core.databases.get(&database_id).unwrap().clone();
+        database.barrier_interval_ms = barrier_interval_ms;
+
+        let mut databases = BTreeMapTransaction::new(&mut database_core.databases);
+        databases.insert(database_id, database.clone());
+        commit_meta!(self, databases)?;
+
+        // Let the barrier loop pick up the new interval without restart.
+        self.env
+            .notification_manager()
+            .notify_local_subscribers(LocalNotification::DatabaseBarrierIntervalChange)
+            .await;
+
+        let version = self
+            .notify_frontend(Operation::Update, Info::Database(database))
+            .await;
+
+        Ok(version)
+    }
+
+    pub async fn alter_database_session_default(
+        &self,
+        database_id: DatabaseId,
+        name: &str,
+        value: Option<String>,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_database_id(database_id)?;
+
+        let mut database = database_core.databases.get(&database_id).unwrap().clone();
+        match name {
+            "timezone" => database.default_timezone = value,
+            "collation" => database.default_collation = value,
+            _ => bail!("unsupported database session default: {}", name),
+        }
+
+        let mut databases = BTreeMapTransaction::new(&mut database_core.databases);
+        databases.insert(database_id, database.clone());
+        commit_meta!(self, databases)?;
+
+        let version = self
+            .notify_frontend(Operation::Update, Info::Database(database))
+            .await;
+
+        Ok(version)
+    }
+
+    /// Replaces the source catalog with the given one, checking that the version is bumped by
+    /// exactly one. Returns the notification version and whether the `StreamSourceInfo`
+    /// (format/encode and their options) was changed, so that the caller can notify the running
+    /// source executors of the new parser config.
+    pub async fn alter_source_column(
+        &self,
+        source: Source,
+    ) -> MetaResult<(NotificationVersion, bool)> {
         let source_id = source.get_id();
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
@@ -2642,6 +4139,67 @@ impl CatalogManager {
         if original_source.get_version() + 1 != source.get_version() {
             bail!("source version is stale");
         }
+        let info_changed = original_source.info != source.info;
+
+        let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
+        sources.insert(source_id, source.clone());
+        commit_meta!(self, sources)?;
+
+        let version = self
+            .notify_frontend_relation_info(Operation::Update, RelationInfo::Source(source))
+            .await;
+
+        Ok((version, info_changed))
+    }
+
+    /// Adds `column` to the source, assigning the next free column id and bumping the source
+    /// version. Unlike [`Self::alter_source_column`], the catalog is mutated on the meta side,
+    /// so a stale frontend cannot clobber unrelated fields of the source.
+    ///
+    /// Downstream streaming jobs keep the schema they were created with and are not affected
+    /// by the new column; only jobs created afterwards see it. Use
+    /// [`Self::list_source_dependent_job_ids`] to report which jobs those are.
+    pub async fn alter_source_add_column(
+        &self,
+        source_id: SourceId,
+        mut column: PbColumnCatalog,
+        definition: String,
+        base_version: u64,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_source_id(source_id)?;
+
+        let mut source = database_core.sources.get(&source_id).unwrap().clone();
+        if source.version != base_version {
+            bail!("source version is stale");
+        }
+
+        let column_desc = column
+            .column_desc
+            .as_mut()
+            .ok_or_else(|| MetaError::invalid_parameter("column desc is missing"))?;
+        if source.columns.iter().any(|c| {
+            c.column_desc
+                .as_ref()
+                .is_some_and(|desc| desc.name == column_desc.name)
+        }) {
+            return Err(MetaError::invalid_parameter(format!(
+                "column \"{}\" of source \"{}\" already exists",
+                column_desc.name, source.name
+            )));
+        }
+        column_desc.column_id = source
+            .columns
+            .iter()
+            .filter_map(|c| c.column_desc.as_ref().map(|desc| desc.column_id))
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        source.columns.push(column);
+        source.definition = definition;
+        source.version += 1;
 
         let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
         sources.insert(source_id, source.clone());
@@ -2654,6 +4212,104 @@ impl CatalogManager {
         Ok(version)
     }
 
+    /// List the ids of all relations (tables, materialized views and sinks) that depend on
+    /// the source. Their schemas were fixed when they were created, so they do not see
+    /// columns added to the source afterwards.
+    pub async fn list_source_dependent_job_ids(
+        &self,
+        source_id: SourceId,
+    ) -> MetaResult<Vec<u32>> {
+        let core = &*self.core.lock().await;
+        core.database.ensure_source_id(source_id)?;
+        let tables = core
+            .database
+            .tables
+            .values()
+            .filter(|table| table.dependent_relations.contains(&source_id))
+            .map(|table| table.id);
+        let sinks = core
+            .database
+            .sinks
+            .values()
+            .filter(|sink| sink.dependent_relations.contains(&source_id))
+            .map(|sink| sink.id);
+        Ok(tables.chain(sinks).collect())
+    }
+
+    /// Merges `changed_props` into the `with_properties` of the given source, validates and
+    /// commits the result, and returns the notification version together with the merged
+    /// properties to be pushed to the source executors through a barrier.
+    pub async fn alter_connector_props_for_source(
+        &self,
+        source_id: SourceId,
+        changed_props: HashMap<String, String>,
+    ) -> MetaResult<(NotificationVersion, HashMap<String, String>)> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_source_id(source_id)?;
+
+        if changed_props.contains_key(UPSTREAM_SOURCE_KEY) {
+            return Err(MetaError::invalid_parameter(
+                "cannot change the connector type of an existing source",
+            ));
+        }
+        let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
+        let mut source = sources.tree_ref().get(&source_id).unwrap().clone();
+        if !source.secret_refs.is_empty() {
+            // The merged plaintext properties are pushed to the executors as-is, which
+            // would drop the resolved secret values.
+            return Err(MetaError::invalid_parameter(
+                "altering connector properties of a source with secret references is not supported",
+            ));
+        }
+        source.with_properties.extend(changed_props);
+        let merged_props: HashMap<_, _> = source.with_properties.clone().into_iter().collect();
+        validate_connector_properties(&merged_props, &HashSet::new())?;
+        sources.insert(source_id, source.clone());
+        commit_meta!(self, sources)?;
+
+        let version = self
+            .notify_frontend_relation_info(Operation::Update, RelationInfo::Source(source))
+            .await;
+
+        Ok((version, merged_props))
+    }
+
+    /// Sink counterpart of [`Self::alter_connector_props_for_source`].
+    pub async fn alter_connector_props_for_sink(
+        &self,
+        sink_id: SinkId,
+        changed_props: HashMap<String, String>,
+    ) -> MetaResult<(NotificationVersion, HashMap<String, String>)> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_sink_id(sink_id)?;
+
+        if changed_props.contains_key(CONNECTOR_TYPE_KEY) {
+            return Err(MetaError::invalid_parameter(
+                "cannot change the connector type of an existing sink",
+            ));
+        }
+        let mut sinks = BTreeMapTransaction::new(&mut database_core.sinks);
+        let mut sink = sinks.tree_ref().get(&sink_id).unwrap().clone();
+        if !sink.secret_refs.is_empty() {
+            return Err(MetaError::invalid_parameter(
+                "altering connector properties of a sink with secret references is not supported",
+            ));
+        }
+        sink.properties.extend(changed_props);
+        let merged_props: HashMap<_, _> = sink.properties.clone().into_iter().collect();
+        validate_connector_properties(&merged_props, &HashSet::new())?;
+        sinks.insert(sink_id, sink.clone());
+        commit_meta!(self, sinks)?;
+
+        let version = self
+            .notify_frontend_relation_info(Operation::Update, RelationInfo::Sink(sink))
+            .await;
+
+        Ok((version, merged_props))
+    }
+
     pub async fn alter_owner(
         &self,
         fragment_manager: FragmentManagerRef,
@@ -2892,23 +4548,71 @@ impl CatalogManager {
                 user_core.increase_ref(owner_id);
                 user_core.decrease_ref(old_owner_id);
             }
-        };
-
-        let version = self.notify_frontend(Operation::Update, relation_info).await;
-
-        Ok(version)
-    }
-
-    pub async fn alter_set_schema(
-        &self,
-        fragment_manager: FragmentManagerRef,
-        object: alter_set_schema_request::Object,
-        new_schema_id: SchemaId,
-    ) -> MetaResult<NotificationVersion> {
-        let core = &mut *self.core.lock().await;
-        let database_core = &mut core.database;
-
-        database_core.ensure_schema_id(new_schema_id)?;
+            alter_owner_request::Object::FunctionId(function_id) => {
+                database_core.ensure_function_id(function_id)?;
+                let mut functions = BTreeMapTransaction::new(&mut database_core.functions);
+                let mut function = functions.get_mut(function_id).unwrap();
+                let old_owner_id = function.owner;
+                if old_owner_id == owner_id {
+                    return Ok(IGNORED_NOTIFICATION_VERSION);
+                }
+                function.owner = owner_id;
+                relation_info = Info::Function(function.clone());
+                commit_meta!(self, functions)?;
+                user_core.increase_ref(owner_id);
+                user_core.decrease_ref(old_owner_id);
+            }
+            alter_owner_request::Object::ConnectionId(connection_id) => {
+                database_core.ensure_connection_id(connection_id)?;
+                let mut connections = BTreeMapTransaction::new(&mut database_core.connections);
+                let mut connection = connections.get_mut(connection_id).unwrap();
+                let old_owner_id = connection.owner;
+                if old_owner_id == owner_id {
+                    return Ok(IGNORED_NOTIFICATION_VERSION);
+                }
+                connection.owner = owner_id;
+                relation_info = Info::Connection(connection.clone());
+                commit_meta!(self, connections)?;
+                user_core.increase_ref(owner_id);
+                user_core.decrease_ref(old_owner_id);
+            }
+            alter_owner_request::Object::SecretId(secret_id) => {
+                database_core.ensure_secret_id(secret_id)?;
+                let mut secrets = BTreeMapTransaction::new(&mut database_core.secrets);
+                let mut secret = secrets.get_mut(secret_id).unwrap();
+                let old_owner_id = secret.owner;
+                if old_owner_id == owner_id {
+                    return Ok(IGNORED_NOTIFICATION_VERSION);
+                }
+                secret.owner = owner_id;
+                let mut secret = secret.clone();
+                // Like secret creation and rotation, the frontend is notified with the
+                // plain value rather than the encrypted one persisted in the meta store.
+                if let Some(plain_value) = LocalSecretManager::global().get_secret(secret.id) {
+                    secret.value = plain_value;
+                }
+                relation_info = Info::Secret(secret);
+                commit_meta!(self, secrets)?;
+                user_core.increase_ref(owner_id);
+                user_core.decrease_ref(old_owner_id);
+            }
+        };
+
+        let version = self.notify_frontend(Operation::Update, relation_info).await;
+
+        Ok(version)
+    }
+
+    pub async fn alter_set_schema(
+        &self,
+        fragment_manager: FragmentManagerRef,
+        object: alter_set_schema_request::Object,
+        new_schema_id: SchemaId,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+
+        database_core.ensure_schema_id(new_schema_id)?;
         let database_id = database_core
             .schemas
             .get(&new_schema_id)
@@ -3245,13 +4949,32 @@ impl CatalogManager {
     ) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let database_core = &mut core.database;
+        let user_core = &mut core.user;
         let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
         let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
         let key = (source.database_id, source.schema_id, source.name.clone());
+        if !database_core.in_progress_creation_tracker.contains(&key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. Return the prior result instead of asserting.
+            return match sources.get(&source.id) {
+                Some(committed) => {
+                    Self::check_finish_fencing(
+                        "source",
+                        source.id,
+                        committed.initialized_at_epoch,
+                        source.initialized_at_epoch,
+                    )?;
+                    Ok(self.current_notification_version().await)
+                }
+                None => Err(MetaError::cancelled(format!(
+                    "source {} has been cancelled",
+                    source.id
+                ))),
+            };
+        }
         assert!(
-            !sources.contains_key(&source.id)
-                && database_core.in_progress_creation_tracker.contains(&key),
-            "source must be in creating procedure"
+            !sources.contains_key(&source.id),
+            "source must not exist while in creating procedure"
         );
         database_core.in_progress_creation_tracker.remove(&key);
 
@@ -3261,9 +4984,22 @@ impl CatalogManager {
             table.stream_job_status = PbStreamJobStatus::Created.into();
             tables.insert(table.id, table.clone());
         }
-        commit_meta!(self, sources, tables)?;
+        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+        let users_need_update = Self::apply_default_privileges(
+            &mut users,
+            &user_core.default_privileges,
+            source.schema_id,
+            RelationKind::Sources,
+            Object::SourceId(source.id),
+            source.owner,
+        );
+        commit_meta!(self, sources, tables, users)?;
 
-        let version = self
+        if !users_need_update.is_empty() {
+            user_core.build_grant_relation_map();
+        }
+
+        let mut version = self
             .notify_frontend(
                 Operation::Add,
                 Info::RelationGroup(RelationGroup {
@@ -3277,6 +5013,11 @@ impl CatalogManager {
                 }),
             )
             .await;
+        for user in users_need_update {
+            version = self
+                .notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
 
         Ok(version)
     }
@@ -3286,10 +5027,19 @@ impl CatalogManager {
         let database_core = &mut core.database;
         let user_core = &mut core.user;
         let key = (source.database_id, source.schema_id, source.name.clone());
+        if !database_core.has_in_progress_creation(&key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. The ref counts have been released by the first
+            // cancel, so this must not decrease them again.
+            tracing::warn!(
+                id = source.id,
+                "source is not in creating procedure, ignoring replayed cancel"
+            );
+            return Ok(());
+        }
         assert!(
-            !database_core.sources.contains_key(&source.id)
-                && database_core.has_in_progress_creation(&key),
-            "source must be in creating procedure"
+            !database_core.sources.contains_key(&source.id),
+            "source must not exist while in creating procedure"
         );
 
         database_core.unmark_creating(&key);
@@ -3348,15 +5098,34 @@ impl CatalogManager {
 
         let source_key = (source.database_id, source.schema_id, source.name.clone());
         let mview_key = (mview.database_id, mview.schema_id, mview.name.clone());
+        if !database_core
+            .in_progress_creation_tracker
+            .contains(&mview_key)
+        {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. Return the prior result instead of asserting.
+            return match tables.get(&mview.id) {
+                Some(committed) => {
+                    Self::check_finish_fencing(
+                        "table",
+                        mview.id,
+                        committed.initialized_at_epoch,
+                        mview.initialized_at_epoch,
+                    )?;
+                    Ok(self.current_notification_version().await)
+                }
+                None => Err(MetaError::cancelled(format!(
+                    "table {} has been cancelled",
+                    mview.id
+                ))),
+            };
+        }
         assert!(
             !sources.contains_key(&source.id)
                 && !tables.contains_key(&mview.id)
                 && database_core
                     .in_progress_creation_tracker
-                    .contains(&source_key)
-                && database_core
-                    .in_progress_creation_tracker
-                    .contains(&mview_key),
+                    .contains(&source_key),
             "table and source must be in creating procedure"
         );
         database_core
@@ -3376,9 +5145,31 @@ impl CatalogManager {
             table.stream_job_status = PbStreamJobStatus::Created.into();
             tables.insert(table.id, table.clone());
         }
-        commit_meta!(self, sources, tables)?;
+        let user_core = &mut core.user;
+        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+        let mut users_need_update = Self::apply_default_privileges(
+            &mut users,
+            &user_core.default_privileges,
+            mview.schema_id,
+            RelationKind::Tables,
+            Object::TableId(mview.id),
+            mview.owner,
+        );
+        users_need_update.extend(Self::apply_default_privileges(
+            &mut users,
+            &user_core.default_privileges,
+            source.schema_id,
+            RelationKind::Sources,
+            Object::SourceId(source.id),
+            source.owner,
+        ));
+        commit_meta!(self, sources, tables, users)?;
+
+        if !users_need_update.is_empty() {
+            user_core.build_grant_relation_map();
+        }
 
-        let version = self
+        let mut version = self
             .notify_frontend(
                 Operation::Add,
                 Info::RelationGroup(RelationGroup {
@@ -3398,6 +5189,11 @@ impl CatalogManager {
                 }),
             )
             .await;
+        for user in users_need_update {
+            version = self
+                .notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
 
         Ok(version)
     }
@@ -3412,6 +5208,16 @@ impl CatalogManager {
         let user_core = &mut core.user;
         let source_key = (source.database_id, source.schema_id, source.name.clone());
         let table_key = (table.database_id, table.schema_id, table.name.clone());
+        if !database_core.has_in_progress_creation(&table_key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. The ref counts have been released by the first
+            // cancel, so this must not decrease them again.
+            tracing::warn!(
+                id = table.id,
+                "table is not in creating procedure, ignoring replayed cancel"
+            );
+            return Ok(());
+        }
         assert!(
             !database_core.sources.contains_key(&source.id)
                 && !database_core.tables.contains_key(&table.id),
@@ -3467,9 +5273,19 @@ impl CatalogManager {
         let database_core = &mut core.database;
         let user_core = &mut core.user;
         let key = (index.database_id, index.schema_id, index.name.clone());
+        if !database_core.has_in_progress_creation(&key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. The ref counts have been released by the first
+            // cancel, so this must not decrease them again.
+            tracing::warn!(
+                id = index.id,
+                "index is not in creating procedure, ignoring replayed cancel"
+            );
+            return;
+        }
         assert!(
             !database_core.indexes.contains_key(&index.id),
-            "index must be in creating procedure"
+            "index must not exist while in creating procedure"
         );
 
         database_core.unmark_creating(&key);
@@ -3493,10 +5309,28 @@ impl CatalogManager {
 
         let mut indexes = BTreeMapTransaction::new(&mut database_core.indexes);
         let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        if !database_core.in_progress_creation_tracker.contains(&key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. Return the prior result instead of asserting.
+            return match indexes.get(&index.id) {
+                Some(committed) => {
+                    Self::check_finish_fencing(
+                        "index",
+                        index.id,
+                        committed.initialized_at_epoch,
+                        index.initialized_at_epoch,
+                    )?;
+                    Ok(self.current_notification_version().await)
+                }
+                None => Err(MetaError::cancelled(format!(
+                    "index {} has been cancelled",
+                    index.id
+                ))),
+            };
+        }
         assert!(
-            !indexes.contains_key(&index.id)
-                && database_core.in_progress_creation_tracker.contains(&key),
-            "index must be in creating procedure"
+            !indexes.contains_key(&index.id),
+            "index must not exist while in creating procedure"
         );
 
         database_core.in_progress_creation_tracker.remove(&key);
@@ -3548,6 +5382,13 @@ impl CatalogManager {
         for dependent_id in &sink.dependent_relations {
             database_core.ensure_table_view_or_source_id(dependent_id)?;
         }
+        Self::check_cross_database_dependencies(
+            database_core,
+            user_core,
+            sink.database_id,
+            sink.owner,
+            &sink.dependent_relations,
+        )?;
         let key = (sink.database_id, sink.schema_id, sink.name.clone());
         database_core.check_relation_name_duplicated(&key)?;
         #[cfg(not(test))]
@@ -3579,10 +5420,28 @@ impl CatalogManager {
         let key = (sink.database_id, sink.schema_id, sink.name.clone());
         let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
         let mut sinks = BTreeMapTransaction::new(&mut database_core.sinks);
+        if !database_core.in_progress_creation_tracker.contains(&key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. Return the prior result instead of asserting.
+            return match sinks.get(&sink.id) {
+                Some(committed) => {
+                    Self::check_finish_fencing(
+                        "sink",
+                        sink.id,
+                        committed.initialized_at_epoch,
+                        sink.initialized_at_epoch,
+                    )?;
+                    Ok(self.current_notification_version().await)
+                }
+                None => Err(MetaError::cancelled(format!(
+                    "sink {} has been cancelled",
+                    sink.id
+                ))),
+            };
+        }
         assert!(
-            !sinks.contains_key(&sink.id)
-                && database_core.in_progress_creation_tracker.contains(&key),
-            "sink must be in creating procedure"
+            !sinks.contains_key(&sink.id),
+            "sink must not exist while in creating procedure"
         );
 
         database_core.in_progress_creation_tracker.remove(&key);
@@ -3596,9 +5455,23 @@ impl CatalogManager {
             table.stream_job_status = PbStreamJobStatus::Created.into();
             tables.insert(table.id, table.clone());
         }
-        commit_meta!(self, sinks, tables)?;
+        let user_core = &mut core.user;
+        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+        let users_need_update = Self::apply_default_privileges(
+            &mut users,
+            &user_core.default_privileges,
+            sink.schema_id,
+            RelationKind::Sinks,
+            Object::SinkId(sink.id),
+            sink.owner,
+        );
+        commit_meta!(self, sinks, tables, users)?;
+
+        if !users_need_update.is_empty() {
+            user_core.build_grant_relation_map();
+        }
 
-        let version = self
+        let mut version = self
             .notify_frontend(
                 Operation::Add,
                 Info::RelationGroup(RelationGroup {
@@ -3613,6 +5486,11 @@ impl CatalogManager {
                 }),
             )
             .await;
+        for user in users_need_update {
+            version = self
+                .notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
 
         Ok(version)
     }
@@ -3626,9 +5504,19 @@ impl CatalogManager {
         let database_core = &mut core.database;
         let user_core = &mut core.user;
         let key = (sink.database_id, sink.schema_id, sink.name.clone());
+        if !database_core.has_in_progress_creation(&key) {
+            // The procedure has already been finished or cancelled, e.g. by a replayed
+            // call after a meta failover. The ref counts have been released by the first
+            // cancel, so this must not decrease them again.
+            tracing::warn!(
+                id = sink.id,
+                "sink is not in creating procedure, ignoring replayed cancel"
+            );
+            return;
+        }
         assert!(
             !database_core.sinks.contains_key(&sink.id),
-            "sink must be in creating procedure"
+            "sink must not exist while in creating procedure"
         );
 
         database_core.unmark_creating(&key);
@@ -3656,6 +5544,13 @@ impl CatalogManager {
         database_core.ensure_schema_id(subscription.schema_id)?;
         database_core
             .ensure_table_view_or_source_id(&TableId::from(subscription.dependent_table_id))?;
+        Self::check_cross_database_dependencies(
+            database_core,
+            user_core,
+            subscription.database_id,
+            subscription.owner,
+            std::slice::from_ref(&subscription.dependent_table_id),
+        )?;
         let key = (
             subscription.database_id,
             subscription.schema_id,
@@ -3698,6 +5593,18 @@ impl CatalogManager {
             subscription.name.clone(),
         );
 
+        if subscription.subscription_state == Into::<i32>::into(PbSubscriptionState::Created)
+            && !database_core.in_progress_creation_tracker.contains(&key)
+        {
+            // The procedure has already been finished, e.g. by a replayed call after a
+            // meta failover. The subscription row persists from start to finish, so the
+            // id cannot have been reused and no extra fencing token is needed.
+            tracing::info!(
+                id = subscription.id,
+                "subscription is already created, ignoring replayed finish"
+            );
+            return Ok(());
+        }
         assert!(
             subscription.subscription_state == Into::<i32>::into(PbSubscriptionState::Init)
                 && database_core.in_progress_creation_tracker.contains(&key),
@@ -3779,6 +5686,164 @@ impl CatalogManager {
         Ok(())
     }
 
+    /// Forcibly drops a streaming job (table/materialized view, index or sink) from the
+    /// catalog, bypassing ref-count and in-progress checks. A last resort for jobs whose
+    /// catalog and fragment state have diverged and that can be neither finished nor
+    /// cancelled. Dependent jobs are deliberately left untouched, and references of
+    /// relations that are already gone are skipped instead of asserted on.
+    ///
+    /// `internal_table_ids` are the job's state tables as discovered from its fragments,
+    /// if any remain; otherwise internal tables are located by their name prefix.
+    /// Returns the ids of all tables removed from the catalog, for hummock
+    /// unregistration.
+    pub async fn force_drop_job(
+        &self,
+        job_id: u32,
+        internal_table_ids: Vec<u32>,
+    ) -> MetaResult<Vec<u32>> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let user_core = &mut core.user;
+
+        let job_key = database_core
+            .tables
+            .get(&job_id)
+            .map(|table| (table.database_id, table.schema_id, table.name.clone()))
+            .or_else(|| {
+                database_core
+                    .sinks
+                    .get(&job_id)
+                    .map(|sink| (sink.database_id, sink.schema_id, sink.name.clone()))
+            });
+
+        // Locate the job's internal tables by name prefix when no fragments are left
+        // to tell them.
+        let mut internal_table_ids = internal_table_ids;
+        if internal_table_ids.is_empty() {
+            if let Some((database_id, _, job_name)) = &job_key {
+                let prefix = format!("__internal_{}_", job_name);
+                internal_table_ids = database_core
+                    .tables
+                    .values()
+                    .filter(|table| {
+                        table.table_type == TableType::Internal as i32
+                            && table.database_id == *database_id
+                            && table.name.starts_with(&prefix)
+                    })
+                    .map(|table| table.id)
+                    .collect();
+            }
+        }
+        // An index job is identified by the id of its index table.
+        let index_id = database_core
+            .indexes
+            .iter()
+            .find(|(_, index)| index.index_table_id == job_id)
+            .map(|(index_id, _)| *index_id);
+
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        let mut indexes = BTreeMapTransaction::new(&mut database_core.indexes);
+        let mut sinks = BTreeMapTransaction::new(&mut database_core.sinks);
+        let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
+
+        let mut removed_relations = vec![];
+        let mut removed_table_ids = vec![];
+        let mut removed_owners = vec![];
+        let mut released_refs = vec![];
+        let mut objects = vec![];
+
+        if let Some(table) = tables.remove(job_id) {
+            removed_owners.push(table.owner);
+            released_refs.extend(table.dependent_relations.clone());
+            removed_table_ids.push(job_id);
+            objects.push(Object::TableId(job_id));
+            removed_relations.push(RelationInfo::Table(table));
+        }
+        if let Some(index_id) = index_id {
+            // unwrap is safe because the entry was found above
+            let index = indexes.remove(index_id).unwrap();
+            removed_owners.push(index.owner);
+            released_refs.push(index.primary_table_id);
+            removed_relations.push(RelationInfo::Index(index));
+        }
+        if let Some(sink) = sinks.remove(job_id) {
+            removed_owners.push(sink.owner);
+            released_refs.extend(sink.dependent_relations.clone());
+            objects.push(Object::SinkId(job_id));
+            removed_relations.push(RelationInfo::Sink(sink));
+        }
+        for internal_table_id in internal_table_ids {
+            if let Some(table) = tables.remove(internal_table_id) {
+                removed_table_ids.push(internal_table_id);
+                objects.push(Object::TableId(internal_table_id));
+                removed_relations.push(RelationInfo::Table(table));
+            }
+        }
+
+        if removed_relations.is_empty() {
+            return Err(MetaError::catalog_id_not_found("streaming job", job_id));
+        }
+
+        let users_need_update = Self::update_user_privileges(&mut users, &objects);
+        commit_meta!(self, tables, indexes, sinks, users)?;
+
+        for owner in removed_owners {
+            user_core.decrease_ref(owner);
+        }
+        // Release the references held by the job; entries of upstreams that are
+        // already gone are skipped. A dangling count on the job itself, from
+        // dependents that outlive it, is dropped outright.
+        for dependent_id in released_refs {
+            if let Some(count) = database_core.relation_ref_count.get_mut(&dependent_id) {
+                *count -= 1;
+                if *count == 0 {
+                    database_core.relation_ref_count.remove(&dependent_id);
+                }
+            }
+        }
+        database_core.relation_ref_count.remove(&job_id);
+        if let Some(key) = &job_key {
+            database_core.unmark_creating(key);
+        }
+        database_core.unmark_creating_streaming_job(job_id);
+
+        for user in users_need_update {
+            self.notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
+        self.notify_frontend(
+            Operation::Delete,
+            Info::RelationGroup(RelationGroup {
+                relations: removed_relations
+                    .into_iter()
+                    .map(|relation_info| Relation {
+                        relation_info: Some(relation_info),
+                    })
+                    .collect(),
+            }),
+        )
+        .await;
+
+        Ok(removed_table_ids)
+    }
+
+    /// Ensures that the given user exists and is a superuser.
+    pub async fn ensure_super_user(&self, user_id: UserId) -> MetaResult<()> {
+        let core = &*self.core.lock().await;
+        let user = core
+            .user
+            .user_info
+            .get(&user_id)
+            .ok_or_else(|| MetaError::catalog_id_not_found("user", user_id))?;
+        if !user.is_super {
+            return Err(MetaError::permission_denied(format!(
+                "user {} is not a superuser",
+                user.name
+            )));
+        }
+        Ok(())
+    }
+
     /// This is used for `ALTER TABLE ADD/DROP COLUMN`.
     pub async fn start_replace_table_procedure(&self, stream_job: &StreamingJob) -> MetaResult<()> {
         let StreamingJob::Table(source, table, job_type) = stream_job else {
@@ -3837,6 +5902,7 @@ impl CatalogManager {
         let mut sources = BTreeMapTransaction::new(&mut database_core.sources);
         let mut indexes = BTreeMapTransaction::new(&mut database_core.indexes);
         let mut sinks = BTreeMapTransaction::new(&mut database_core.sinks);
+        let mut views = BTreeMapTransaction::new(&mut database_core.views);
         let key = (table.database_id, table.schema_id, table.name.clone());
 
         assert!(
@@ -3846,6 +5912,7 @@ impl CatalogManager {
         );
 
         let original_table = tables.get(&table.id).unwrap();
+        let original_columns = original_table.columns.clone();
         let mut updated_sinks = vec![];
         for sink_id in updated_sink_ids {
             let mut sink = sinks.get_mut(sink_id).unwrap();
@@ -3911,7 +5978,26 @@ impl CatalogManager {
 
         tables.insert(table.id, table.clone());
 
-        commit_meta!(self, tables, indexes, sources, sinks)?;
+        // Views read the table by column name at query time, so an incompatible change
+        // breaks them silently. Mark them invalid so that the frontend rejects queries
+        // on them until they are recreated.
+        let mut invalidated_views = vec![];
+        if let Some(reason) = replace_table_incompatibility(&original_columns, &table.columns) {
+            let reason = format!("{} of table \"{}\"", reason, table.name);
+            let view_ids = views
+                .tree_ref()
+                .iter()
+                .filter(|(_, view)| view.dependent_relations.contains(&table.id))
+                .map(|(view_id, _)| *view_id)
+                .collect_vec();
+            for view_id in view_ids {
+                let mut view = views.get_mut(view_id).unwrap();
+                view.invalidated_reason = Some(reason.clone());
+                invalidated_views.push(view.clone());
+            }
+        }
+
+        commit_meta!(self, tables, indexes, sources, sinks, views)?;
 
         // Group notification
         let version = self
@@ -3931,6 +6017,9 @@ impl CatalogManager {
                     .chain(updated_sinks.into_iter().map(|sink| Relation {
                         relation_info: RelationInfo::Sink(sink).into(),
                     }))
+                    .chain(invalidated_views.into_iter().map(|view| Relation {
+                        relation_info: RelationInfo::View(view).into(),
+                    }))
                     .collect_vec(),
                 }),
             )
@@ -3989,33 +6078,392 @@ impl CatalogManager {
 
         database_core.ensure_database_id(comment.database_id)?;
         database_core.ensure_schema_id(comment.schema_id)?;
-        database_core.ensure_table_id(comment.table_id)?;
+        if comment.column_index.is_some() && comment.object_type() != PbObjectType::Unspecified {
+            return Err(MetaError::invalid_parameter(
+                "column comments are only supported on tables",
+            ));
+        }
+
+        // A macro to avoid spelling out the same transaction boilerplate for each
+        // non-table catalog map: set the description, commit and return the
+        // (old, new) protos for the notification below.
+        macro_rules! set_description {
+            ($map:ident, $object:expr) => {{
+                let mut $map = BTreeMapTransaction::new(&mut database_core.$map);
+                let mut object = $map
+                    .get_mut(comment.table_id)
+                    .ok_or_else(|| MetaError::catalog_id_not_found($object, comment.table_id))?;
+                object.description = comment.description.clone();
+                let new_object = object.clone();
+                commit_meta!(self, $map)?;
+                new_object
+            }};
+        }
+
+        let version = match comment.object_type() {
+            PbObjectType::Unspecified => {
+                // A table or one of its columns.
+                database_core.ensure_table_id(comment.table_id)?;
+
+                let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+
+                // unwrap is safe because the table id was ensured before
+                let mut table = tables.get_mut(comment.table_id).unwrap();
+                let old_table = table.clone();
+                if let Some(col_idx) = comment.column_index {
+                    let column = table
+                        .columns
+                        .get_mut(col_idx as usize)
+                        .ok_or_else(|| MetaError::catalog_id_not_found("column", col_idx))?;
+                    let column_desc = column.column_desc.as_mut().ok_or_else(|| {
+                        anyhow!(
+                            "column desc at index {} for table id {} not found",
+                            col_idx,
+                            comment.table_id
+                        )
+                    })?;
+                    column_desc.description = comment.description;
+                } else {
+                    table.description = comment.description;
+                }
+
+                let new_table = table.clone();
+
+                commit_meta!(self, tables)?;
+
+                self.notify_frontend_relation_update(
+                    &RelationInfo::Table(old_table),
+                    RelationInfo::Table(new_table),
+                )
+                .await
+            }
+            PbObjectType::Source => {
+                let source = set_description!(sources, "source");
+                self.notify_frontend(
+                    Operation::Update,
+                    Info::RelationGroup(RelationGroup {
+                        relations: vec![Relation {
+                            relation_info: RelationInfo::Source(source).into(),
+                        }],
+                    }),
+                )
+                .await
+            }
+            PbObjectType::Sink => {
+                let sink = set_description!(sinks, "sink");
+                self.notify_frontend(
+                    Operation::Update,
+                    Info::RelationGroup(RelationGroup {
+                        relations: vec![Relation {
+                            relation_info: RelationInfo::Sink(sink).into(),
+                        }],
+                    }),
+                )
+                .await
+            }
+            PbObjectType::View => {
+                let view = set_description!(views, "view");
+                self.notify_frontend(
+                    Operation::Update,
+                    Info::RelationGroup(RelationGroup {
+                        relations: vec![Relation {
+                            relation_info: RelationInfo::View(view).into(),
+                        }],
+                    }),
+                )
+                .await
+            }
+            PbObjectType::Index => {
+                let index = set_description!(indexes, "index");
+                self.notify_frontend(
+                    Operation::Update,
+                    Info::RelationGroup(RelationGroup {
+                        relations: vec![Relation {
+                            relation_info: RelationInfo::Index(index).into(),
+                        }],
+                    }),
+                )
+                .await
+            }
+            PbObjectType::Function => {
+                let function = set_description!(functions, "function");
+                self.notify_frontend(Operation::Update, Info::Function(function))
+                    .await
+            }
+            PbObjectType::Connection => {
+                let connection = set_description!(connections, "connection");
+                self.notify_frontend(Operation::Update, Info::Connection(connection))
+                    .await
+            }
+            PbObjectType::Secret => {
+                let mut secret = set_description!(secrets, "secret");
+                // Like secret creation and rotation, the frontend is notified with the
+                // plain value rather than the encrypted one persisted in the meta store.
+                if let Some(plain_value) = LocalSecretManager::global().get_secret(secret.id) {
+                    secret.value = plain_value;
+                }
+                self.notify_frontend(Operation::Update, Info::Secret(secret))
+                    .await
+            }
+            PbObjectType::Schema => {
+                let schema = set_description!(schemas, "schema");
+                self.notify_frontend(Operation::Update, Info::Schema(schema))
+                    .await
+            }
+        };
+
+        Ok(version)
+    }
+
+    pub async fn alter_annotation(
+        &self,
+        annotation: Annotation,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+
+        database_core.ensure_database_id(annotation.database_id)?;
+        database_core.ensure_schema_id(annotation.schema_id)?;
+        database_core.ensure_table_id(annotation.table_id)?;
 
         let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
 
         // unwrap is safe because the table id was ensured before
-        let mut table = tables.get_mut(comment.table_id).unwrap();
-        if let Some(col_idx) = comment.column_index {
-            let column = table
-                .columns
-                .get_mut(col_idx as usize)
-                .ok_or_else(|| MetaError::catalog_id_not_found("column", col_idx))?;
-            let column_desc = column.column_desc.as_mut().ok_or_else(|| {
-                anyhow!(
-                    "column desc at index {} for table id {} not found",
-                    col_idx,
-                    comment.table_id
-                )
-            })?;
-            column_desc.description = comment.description;
+        let mut table = tables.get_mut(annotation.table_id).unwrap();
+        let old_table = table.clone();
+        if let Some(value) = annotation.value {
+            if !table.annotations.contains_key(&annotation.key)
+                && table.annotations.len() >= MAX_ANNOTATIONS_PER_OBJECT
+            {
+                bail!(
+                    "table {} already has {} annotations",
+                    annotation.table_id,
+                    MAX_ANNOTATIONS_PER_OBJECT
+                );
+            }
+            table.annotations.insert(annotation.key, value);
         } else {
-            table.description = comment.description;
+            table.annotations.remove(&annotation.key);
         }
 
         let new_table = table.clone();
 
         commit_meta!(self, tables)?;
 
+        let version = self
+            .notify_frontend_relation_update(
+                &RelationInfo::Table(old_table),
+                RelationInfo::Table(new_table),
+            )
+            .await;
+
+        Ok(version)
+    }
+
+    /// Set or remove a single label on a table/materialized view or a sink.
+    pub async fn alter_label(&self, label: Label) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+
+        match label.job {
+            Some(LabelJob::TableId(table_id)) => {
+                database_core.ensure_table_id(table_id)?;
+                let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+                // unwrap is safe because the table id was ensured before
+                let mut table = tables.get_mut(table_id).unwrap();
+                let old_table = table.clone();
+                if let Some(value) = label.value {
+                    if !table.labels.contains_key(&label.key)
+                        && table.labels.len() >= MAX_LABELS_PER_OBJECT
+                    {
+                        bail!(
+                            "table {} already has {} labels",
+                            table_id,
+                            MAX_LABELS_PER_OBJECT
+                        );
+                    }
+                    table.labels.insert(label.key, value);
+                } else {
+                    table.labels.remove(&label.key);
+                }
+                let new_table = table.clone();
+                commit_meta!(self, tables)?;
+
+                let version = self
+                    .notify_frontend_relation_update(
+                        &RelationInfo::Table(old_table),
+                        RelationInfo::Table(new_table),
+                    )
+                    .await;
+                Ok(version)
+            }
+            Some(LabelJob::SinkId(sink_id)) => {
+                database_core.ensure_sink_id(sink_id)?;
+                let mut sinks = BTreeMapTransaction::new(&mut database_core.sinks);
+                // unwrap is safe because the sink id was ensured before
+                let mut sink = sinks.get_mut(sink_id).unwrap();
+                if let Some(value) = label.value {
+                    if !sink.labels.contains_key(&label.key)
+                        && sink.labels.len() >= MAX_LABELS_PER_OBJECT
+                    {
+                        bail!(
+                            "sink {} already has {} labels",
+                            sink_id,
+                            MAX_LABELS_PER_OBJECT
+                        );
+                    }
+                    sink.labels.insert(label.key, value);
+                } else {
+                    sink.labels.remove(&label.key);
+                }
+                let new_sink = sink.clone();
+                commit_meta!(self, sinks)?;
+
+                let version = self
+                    .notify_frontend_relation_info(Operation::Update, RelationInfo::Sink(new_sink))
+                    .await;
+                Ok(version)
+            }
+            None => bail!("no streaming job to label"),
+        }
+    }
+
+    /// Returns the ids of user tables/materialized views and sinks that carry all the
+    /// labels in `selector`.
+    pub async fn list_streaming_job_ids_by_label(
+        &self,
+        selector: &HashMap<String, String>,
+    ) -> (Vec<TableId>, Vec<SinkId>) {
+        let core = self.core.lock().await;
+        let database_core = &core.database;
+        let table_ids = database_core
+            .tables
+            .values()
+            .filter(|table| {
+                valid_table_name(&table.name)
+                    && matches!(
+                        table.table_type(),
+                        TableType::Table | TableType::MaterializedView
+                    )
+                    && selector
+                        .iter()
+                        .all(|(key, value)| table.labels.get(key) == Some(value))
+            })
+            .map(|table| table.id)
+            .collect();
+        let sink_ids = database_core
+            .sinks
+            .values()
+            .filter(|sink| {
+                selector
+                    .iter()
+                    .all(|(key, value)| sink.labels.get(key) == Some(value))
+            })
+            .map(|sink| sink.id)
+            .collect();
+        (table_ids, sink_ids)
+    }
+
+    /// Set or clear the archival tier policy of a table. Besides frontends, compactors
+    /// are notified as well since they drive the placement of aged state on cheaper
+    /// storage classes.
+    pub async fn alter_storage_class_policy(
+        &self,
+        table_id: TableId,
+        policy: Option<PbStorageClassPolicy>,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_table_id(table_id)?;
+
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        // unwrap is safe because the table id was ensured before
+        let mut table = tables.get_mut(table_id).unwrap();
+        table.storage_class_policy = policy;
+        let new_table = table.clone();
+        commit_meta!(self, tables)?;
+
+        self.notify_hummock_and_compactor_relation_info(
+            Operation::Update,
+            RelationInfo::Table(new_table.clone()),
+        )
+        .await;
+        let version = self
+            .notify_frontend_relation_info(Operation::Update, RelationInfo::Table(new_table))
+            .await;
+
+        Ok(version)
+    }
+
+    pub async fn alter_schema_change_policy(
+        &self,
+        table_id: TableId,
+        policy: PbSchemaChangePolicy,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_table_id(table_id)?;
+
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        // unwrap is safe because the table id was ensured before
+        let mut table = tables.get_mut(table_id).unwrap();
+        table.schema_change_policy = policy as i32;
+        let new_table = table.clone();
+        commit_meta!(self, tables)?;
+
+        // The policy is enforced on the meta node; frontends are only notified to
+        // keep their catalog caches consistent.
+        let version = self
+            .notify_frontend_relation_info(Operation::Update, RelationInfo::Table(new_table))
+            .await;
+
+        Ok(version)
+    }
+
+    /// Set the priority class of a streaming job. The barrier manager and the compactor
+    /// scheduler pick it up from the catalog on the next barrier, so no fragment-level
+    /// mutation is needed here.
+    pub async fn alter_stream_job_priority(
+        &self,
+        table_id: TableId,
+        priority: PbStreamJobPriority,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_table_id(table_id)?;
+
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        // unwrap is safe because the table id was ensured before
+        let mut table = tables.get_mut(table_id).unwrap();
+        table.priority = priority as i32;
+        let new_table = table.clone();
+        commit_meta!(self, tables)?;
+
+        let version = self
+            .notify_frontend_relation_info(Operation::Update, RelationInfo::Table(new_table))
+            .await;
+
+        Ok(version)
+    }
+
+    /// Sets or clears the state retention time of the table. The new option takes effect on
+    /// the next TTL compaction of the table's compaction group.
+    pub async fn alter_table_ttl(
+        &self,
+        table_id: TableId,
+        retention_seconds: Option<u32>,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        database_core.ensure_table_id(table_id)?;
+
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        // unwrap is safe because the table id was ensured before
+        let mut table = tables.get_mut(table_id).unwrap();
+        table.retention_seconds = retention_seconds;
+        let new_table = table.clone();
+        commit_meta!(self, tables)?;
+
         let version = self
             .notify_frontend_relation_info(Operation::Update, RelationInfo::Table(new_table))
             .await;
@@ -4128,6 +6576,14 @@ impl CatalogManager {
         self.core.lock().await.database.list_view_ids(schema_id)
     }
 
+    pub async fn list_index_table_ids_on(&self, table_id: TableId) -> Vec<TableId> {
+        self.core
+            .lock()
+            .await
+            .database
+            .list_index_table_ids_on(table_id)
+    }
+
     pub async fn list_sources(&self) -> Vec<Source> {
         self.core.lock().await.database.list_sources()
     }
@@ -4148,6 +6604,11 @@ impl CatalogManager {
         self.core.lock().await.database.list_source_ids(schema_id)
     }
 
+    /// Returns per-database, per-object-type memory accounting of the cached catalog.
+    pub async fn catalog_memory_stats(&self) -> Vec<PbCatalogMemoryStats> {
+        self.core.lock().await.database.memory_stats()
+    }
+
     pub async fn get_table_name_and_type_mapping(&self) -> HashMap<TableId, (String, String)> {
         self.core
             .lock()
@@ -4196,6 +6657,39 @@ impl CatalogManager {
             .collect_vec()
     }
 
+    /// Converts the given creating foreground materialized views to background jobs, so
+    /// that they keep backfilling after the creating session disconnects and survive
+    /// recovery instead of being cleaned up as dirty. Returns the catalogs of the jobs
+    /// that were actually converted.
+    pub async fn convert_creating_jobs_to_background(
+        &self,
+        job_ids: Vec<TableId>,
+    ) -> MetaResult<Vec<Table>> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let mut tables = BTreeMapTransaction::new(&mut database_core.tables);
+        let mut converted = vec![];
+        for job_id in job_ids {
+            let Some(mut table) = tables.get(&job_id).cloned() else {
+                continue;
+            };
+            if table.table_type != TableType::MaterializedView as i32
+                || table.stream_job_status != PbStreamJobStatus::Creating as i32
+                || table.create_type != CreateType::Foreground as i32
+            {
+                continue;
+            }
+            table.create_type = CreateType::Background as i32;
+            tables.insert(job_id, table.clone());
+            converted.push(table);
+        }
+        if converted.is_empty() {
+            return Ok(vec![]);
+        }
+        commit_meta!(self, tables)?;
+        Ok(converted)
+    }
+
     pub async fn list_object_dependencies(&self) -> Vec<PbObjectDependencies> {
         let core = &self.core.lock().await.database;
         let mut dependencies = vec![];
@@ -4219,21 +6713,109 @@ impl CatalogManager {
         }
         for sink in core.sinks.values() {
             for referenced in &sink.dependent_relations {
-                dependencies.push(PbObjectDependencies {
-                    object_id: sink.id,
-                    referenced_object_id: *referenced,
-                });
+                dependencies.push(PbObjectDependencies {
+                    object_id: sink.id,
+                    referenced_object_id: *referenced,
+                });
+            }
+        }
+
+        for subscription in core.subscriptions.values() {
+            dependencies.push(PbObjectDependencies {
+                object_id: subscription.id,
+                referenced_object_id: subscription.dependent_table_id,
+            });
+        }
+
+        dependencies
+    }
+
+    /// Returns the full typed dependency graph of the catalog for lineage rendering: one
+    /// node per object and one edge per dependency, with the edge kind derived from the
+    /// types of its endpoints. Unlike [`Self::list_object_dependencies`], this includes
+    /// views, indexes and references to secrets and connections, and does not filter out
+    /// creating jobs.
+    pub async fn get_dependency_graph(&self) -> PbGetDependencyGraphResponse {
+        fn node(object_id: u32, object_type: &str) -> PbDependencyNode {
+            PbDependencyNode {
+                object_id,
+                object_type: object_type.to_string(),
+            }
+        }
+        fn edge(object_id: u32, referenced_object_id: u32, kind: PbDependencyKind) -> PbDependencyEdge {
+            PbDependencyEdge {
+                object_id,
+                referenced_object_id,
+                kind: kind as _,
+            }
+        }
+
+        let core = &self.core.lock().await.database;
+        let mut nodes = vec![];
+        let mut edges = vec![];
+
+        for table in core.tables.values() {
+            if table.get_table_type().unwrap() == TableType::Internal {
+                continue;
+            }
+            nodes.push(node(table.id, "table"));
+            for referenced in &table.dependent_relations {
+                edges.push(edge(table.id, *referenced, PbDependencyKind::Relation));
+            }
+            for incoming_sink in &table.incoming_sinks {
+                edges.push(edge(table.id, *incoming_sink, PbDependencyKind::SinkIntoTable));
+            }
+        }
+        for index in core.indexes.values() {
+            nodes.push(node(index.id, "index"));
+            edges.push(edge(index.id, index.primary_table_id, PbDependencyKind::Relation));
+        }
+        for view in core.views.values() {
+            nodes.push(node(view.id, "view"));
+            for referenced in &view.dependent_relations {
+                edges.push(edge(view.id, *referenced, PbDependencyKind::Relation));
+            }
+        }
+        for source in core.sources.values() {
+            nodes.push(node(source.id, "source"));
+            if let Some(connection_id) = source.connection_id {
+                edges.push(edge(source.id, connection_id, PbDependencyKind::Connection));
+            }
+            for secret_ref in source.secret_refs.values() {
+                edges.push(edge(source.id, secret_ref.secret_id, PbDependencyKind::Secret));
+            }
+        }
+        for sink in core.sinks.values() {
+            nodes.push(node(sink.id, "sink"));
+            for referenced in &sink.dependent_relations {
+                edges.push(edge(sink.id, *referenced, PbDependencyKind::Relation));
+            }
+            if let Some(connection_id) = sink.connection_id {
+                edges.push(edge(sink.id, connection_id, PbDependencyKind::Connection));
+            }
+            for secret_ref in sink.secret_refs.values() {
+                edges.push(edge(sink.id, secret_ref.secret_id, PbDependencyKind::Secret));
             }
         }
-
         for subscription in core.subscriptions.values() {
-            dependencies.push(PbObjectDependencies {
-                object_id: subscription.id,
-                referenced_object_id: subscription.dependent_table_id,
-            });
+            nodes.push(node(subscription.id, "subscription"));
+            edges.push(edge(
+                subscription.id,
+                subscription.dependent_table_id,
+                PbDependencyKind::Subscription,
+            ));
+        }
+        for function in core.functions.values() {
+            nodes.push(node(function.id, "function"));
+        }
+        for connection in core.connections.values() {
+            nodes.push(node(connection.id, "connection"));
+        }
+        for secret in core.secrets.values() {
+            nodes.push(node(secret.id, "secret"));
         }
 
-        dependencies
+        PbGetDependencyGraphResponse { nodes, edges }
     }
 
     async fn notify_frontend(&self, operation: Operation, info: Info) -> NotificationVersion {
@@ -4254,6 +6836,20 @@ impl CatalogManager {
             .await
     }
 
+    /// Notifies frontends of an updated relation with a field-mask diff when possible,
+    /// falling back to the whole proto. Preferred over [`Self::notify_frontend_relation_info`]
+    /// for updates that touch only cheap fields of potentially wide relations.
+    async fn notify_frontend_relation_update(
+        &self,
+        old_relation_info: &RelationInfo,
+        new_relation_info: RelationInfo,
+    ) -> NotificationVersion {
+        self.env
+            .notification_manager()
+            .notify_frontend_relation_update(old_relation_info, new_relation_info)
+            .await
+    }
+
     pub async fn table_is_created(&self, table_id: TableId) -> bool {
         let guard = self.core.lock().await;
         return if let Some(table) = guard.database.tables.get(&table_id) {
@@ -4276,6 +6872,70 @@ impl CatalogManager {
         tables
     }
 
+    /// Returns the `CREATE` statement of the given relation and, if `include_dependencies` is
+    /// set, those of all its upstream dependencies, sorted so that every statement appears
+    /// after the statements of the relations it depends on.
+    pub async fn export_ddl(
+        &self,
+        relation_id: RelationId,
+        include_dependencies: bool,
+    ) -> MetaResult<Vec<(RelationId, String)>> {
+        let guard = self.core.lock().await;
+        let database = &guard.database;
+
+        // Resolve a relation id to its `CREATE` statement and the relations it depends on.
+        // The statement of an index lives on its index table.
+        let lookup = |id: RelationId| -> MetaResult<(String, Vec<RelationId>)> {
+            if let Some(index) = database.indexes.get(&id) {
+                let index_table = database
+                    .tables
+                    .get(&index.index_table_id)
+                    .ok_or_else(|| MetaError::catalog_id_not_found("table", index.index_table_id))?;
+                Ok((index_table.definition.clone(), vec![index.primary_table_id]))
+            } else if let Some(table) = database.tables.get(&id) {
+                Ok((table.definition.clone(), table.dependent_relations.clone()))
+            } else if let Some(source) = database.sources.get(&id) {
+                Ok((source.definition.clone(), vec![]))
+            } else if let Some(sink) = database.sinks.get(&id) {
+                Ok((sink.definition.clone(), sink.dependent_relations.clone()))
+            } else if let Some(view) = database.views.get(&id) {
+                Ok((view.sql.clone(), view.dependent_relations.clone()))
+            } else if let Some(subscription) = database.subscriptions.get(&id) {
+                Ok((
+                    subscription.definition.clone(),
+                    vec![subscription.dependent_table_id],
+                ))
+            } else {
+                Err(MetaError::catalog_id_not_found("relation", id))
+            }
+        };
+
+        // Post-order DFS so that upstream definitions come first.
+        let mut visited = HashSet::new();
+        let mut statements = vec![];
+        let mut stack = vec![(relation_id, false)];
+        while let Some((id, expanded)) = stack.pop() {
+            if expanded {
+                let (definition, _) = lookup(id)?;
+                statements.push((id, definition));
+                continue;
+            }
+            if !visited.insert(id) {
+                continue;
+            }
+            stack.push((id, true));
+            if include_dependencies {
+                let (_, dependencies) = lookup(id)?;
+                for dependent_id in dependencies {
+                    if !visited.contains(&dependent_id) {
+                        stack.push((dependent_id, false));
+                    }
+                }
+            }
+        }
+        Ok(statements)
+    }
+
     pub async fn get_subscription_by_id(
         &self,
         subscription_id: SubscriptionId,
@@ -4292,6 +6952,9 @@ impl CatalogManager {
     pub async fn get_mv_depended_subscriptions(
         &self,
     ) -> MetaResult<HashMap<risingwave_common::catalog::TableId, HashMap<u32, u64>>> {
+        let params = self.env.system_params_reader().await;
+        let checkpoint_interval_ms =
+            params.barrier_interval_ms() as u64 * params.checkpoint_frequency();
         let guard = self.core.lock().await;
         let mut map = HashMap::new();
         for subscription in guard.database.subscriptions.values() {
@@ -4299,12 +6962,60 @@ impl CatalogManager {
                 subscription.dependent_table_id,
             ))
             .or_insert(HashMap::new())
-            .insert(subscription.id, subscription.retention_seconds);
+            .insert(
+                subscription.id,
+                effective_subscription_retention_seconds(subscription, checkpoint_interval_ms),
+            );
         }
 
         Ok(map)
     }
 
+    /// Record the max epoch that the consumer of a subscription has fully consumed, so
+    /// that log data at or below it can be truncated before the time-based retention
+    /// elapses. Only accepted for subscriptions created with
+    /// `retention_policy = 'consumer_ack'`.
+    pub async fn ack_subscription_epoch(
+        &self,
+        subscription_id: SubscriptionId,
+        acked_epoch: u64,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &mut core.database;
+        let mut subscriptions = BTreeMapTransaction::new(&mut database_core.subscriptions);
+        let mut subscription = subscriptions
+            .get(&subscription_id)
+            .ok_or_else(|| MetaError::catalog_id_not_found("subscription", subscription_id))?
+            .clone();
+        if subscription.retention_policy() != PbRetentionPolicy::ConsumerAck {
+            return Err(MetaError::invalid_parameter(format!(
+                "subscription {} is not created with retention_policy = 'consumer_ack'",
+                subscription_id
+            )));
+        }
+        if acked_epoch < subscription.acked_epoch {
+            return Err(MetaError::invalid_parameter(format!(
+                "acked epoch {} is less than the already acknowledged epoch {}",
+                acked_epoch, subscription.acked_epoch
+            )));
+        }
+        subscription.acked_epoch = acked_epoch;
+        subscriptions.insert(subscription.id, subscription.clone());
+        commit_meta!(self, subscriptions)?;
+
+        let version = self
+            .notify_frontend(
+                Operation::Update,
+                Info::RelationGroup(RelationGroup {
+                    relations: vec![Relation {
+                        relation_info: RelationInfo::Subscription(subscription).into(),
+                    }],
+                }),
+            )
+            .await;
+        Ok(version)
+    }
+
     pub async fn get_sinks(&self, sink_ids: &[SinkId]) -> Vec<Sink> {
         let mut sinks = vec![];
         let guard = self.core.lock().await;
@@ -4507,6 +7218,38 @@ impl CatalogManager {
     pub async fn drop_user(&self, id: UserId) -> MetaResult<NotificationVersion> {
         let core = &mut *self.core.lock().await;
         let user_core = &mut core.user;
+        if user_core
+            .user_info
+            .values()
+            .any(|user| user.granted_roles.contains(&id))
+        {
+            return Err(MetaError::permission_denied(format!(
+                "Cannot drop user {} because it is still granted as a role to other users",
+                id
+            )));
+        }
+        if user_core.default_privileges.values().any(|template| {
+            template
+                .entries
+                .iter()
+                .any(|entry| entry.grantee_id == id || entry.granted_by == id)
+        }) {
+            return Err(MetaError::permission_denied(format!(
+                "Cannot drop user {} because it is still referenced by default privileges",
+                id
+            )));
+        }
+        if core
+            .database
+            .schemas
+            .values()
+            .any(|schema| schema.default_owner_id == Some(id))
+        {
+            return Err(MetaError::permission_denied(format!(
+                "Cannot drop user {} because it is the default owner of some schemas",
+                id
+            )));
+        }
         let mut users = BTreeMapTransaction::new(&mut user_core.user_info);
         if !users.contains_key(&id) {
             bail!("User {} not found", id);
@@ -4617,6 +7360,311 @@ impl CatalogManager {
             .map(|owner_id| owner_id == user_id)
     }
 
+    /// The privileges of a user merged with those inherited from its granted roles,
+    /// resolved transitively. Membership cycles are rejected in [`Self::grant_role`], but
+    /// `visited` guards against them anyway.
+    fn privileges_with_roles(
+        user_info: &BTreeMap<UserId, UserInfo>,
+        user: &UserInfo,
+    ) -> Vec<GrantPrivilege> {
+        let mut merged = user.grant_privileges.clone();
+        let mut visited = HashSet::from([user.id]);
+        let mut queue: VecDeque<UserId> = user.granted_roles.iter().copied().collect();
+        while let Some(role_id) = queue.pop_front() {
+            if !visited.insert(role_id) {
+                continue;
+            }
+            let Some(role) = user_info.get(&role_id) else {
+                continue;
+            };
+            for privilege in &role.grant_privileges {
+                if let Some(merged_privilege) =
+                    merged.iter_mut().find(|p| p.object == privilege.object)
+                {
+                    Self::merge_privilege(merged_privilege, privilege);
+                } else {
+                    merged.push(privilege.clone());
+                }
+            }
+            queue.extend(role.granted_roles.iter().copied());
+        }
+        merged
+    }
+
+    /// Whether `target` is reachable from `from` by following `granted_roles` edges. Used
+    /// to reject role membership cycles.
+    fn role_reaches(user_info: &BTreeMap<UserId, UserInfo>, from: UserId, target: UserId) -> bool {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(role_id) = queue.pop_front() {
+            if role_id == target {
+                return true;
+            }
+            if !visited.insert(role_id) {
+                continue;
+            }
+            if let Some(role) = user_info.get(&role_id) {
+                queue.extend(role.granted_roles.iter().copied());
+            }
+        }
+        false
+    }
+
+    /// Makes `user_ids` members of the roles in `role_ids`. Members inherit the roles'
+    /// privileges, resolved transitively, when access is checked.
+    pub async fn grant_role(
+        &self,
+        role_ids: &[UserId],
+        user_ids: &[UserId],
+        granted_by: UserId,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut self.core.lock().await.user;
+        let grantor = core
+            .user_info
+            .get(&granted_by)
+            .ok_or_else(|| MetaError::catalog_id_not_found("user", granted_by))?;
+        if !grantor.is_super && !grantor.can_create_user {
+            return Err(MetaError::permission_denied(format!(
+                "User {} does not have the privilege to grant roles",
+                grantor.name
+            )));
+        }
+        for role_id in role_ids {
+            if !core.user_info.contains_key(role_id) {
+                return Err(MetaError::catalog_id_not_found("user", role_id));
+            }
+            for user_id in user_ids {
+                // Granting role R to user U would form a cycle iff U is already
+                // (transitively) a role of R.
+                if Self::role_reaches(&core.user_info, *role_id, *user_id) {
+                    return Err(MetaError::permission_denied(format!(
+                        "Cannot grant role {} to user {} because it would create a membership cycle",
+                        role_id, user_id
+                    )));
+                }
+            }
+        }
+
+        let mut users = BTreeMapTransaction::new(&mut core.user_info);
+        let mut user_updated = Vec::with_capacity(user_ids.len());
+        for user_id in user_ids {
+            let mut user = users
+                .get_mut(*user_id)
+                .ok_or_else(|| MetaError::catalog_id_not_found("user", user_id))?;
+            for role_id in role_ids {
+                if !user.granted_roles.contains(role_id) {
+                    user.granted_roles.push(*role_id);
+                }
+            }
+            user_updated.push(user.clone());
+        }
+        commit_meta!(self, users)?;
+
+        let mut version = 0;
+        for user in user_updated {
+            version = self
+                .notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
+        Ok(version)
+    }
+
+    /// Removes `user_ids` from the roles in `role_ids`. Missing memberships are ignored.
+    pub async fn revoke_role(
+        &self,
+        role_ids: &[UserId],
+        user_ids: &[UserId],
+        revoke_by: UserId,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut self.core.lock().await.user;
+        let revoker = core
+            .user_info
+            .get(&revoke_by)
+            .ok_or_else(|| MetaError::catalog_id_not_found("user", revoke_by))?;
+        if !revoker.is_super && !revoker.can_create_user {
+            return Err(MetaError::permission_denied(format!(
+                "User {} does not have the privilege to revoke roles",
+                revoker.name
+            )));
+        }
+
+        let mut users = BTreeMapTransaction::new(&mut core.user_info);
+        let mut user_updated = vec![];
+        for user_id in user_ids {
+            let mut user = users
+                .get_mut(*user_id)
+                .ok_or_else(|| MetaError::catalog_id_not_found("user", user_id))?;
+            let prev_len = user.granted_roles.len();
+            user.granted_roles.retain(|role_id| !role_ids.contains(role_id));
+            if user.granted_roles.len() != prev_len {
+                user_updated.push(user.clone());
+            }
+        }
+        commit_meta!(self, users)?;
+
+        let mut version = 0;
+        for user in user_updated {
+            version = self
+                .notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
+        Ok(version)
+    }
+
+    /// Alter the default privilege templates of the given schemas. Templates only
+    /// affect relations created afterwards; revoking a template entry does not touch
+    /// privileges it has already granted.
+    pub async fn alter_default_privileges(
+        &self,
+        request: AlterDefaultPrivilegesRequest,
+    ) -> MetaResult<()> {
+        let core = &mut *self.core.lock().await;
+        let database_core = &core.database;
+        let user_core = &mut core.user;
+
+        let grantor = user_core
+            .user_info
+            .get(&request.granted_by)
+            .ok_or_else(|| MetaError::catalog_id_not_found("user", request.granted_by))?;
+        for schema_id in &request.schema_ids {
+            let schema = database_core
+                .schemas
+                .get(schema_id)
+                .ok_or_else(|| MetaError::catalog_id_not_found("schema", schema_id))?;
+            if !grantor.is_super && schema.owner != grantor.id {
+                return Err(MetaError::permission_denied(format!(
+                    "User {} is not the owner of schema {}",
+                    grantor.name, schema.name
+                )));
+            }
+        }
+        for grantee_id in &request.grantee_ids {
+            let grantee = user_core
+                .user_info
+                .get(grantee_id)
+                .ok_or_else(|| MetaError::catalog_id_not_found("user", grantee_id))?;
+            if grantee.is_super {
+                return Err(MetaError::permission_denied(format!(
+                    "Cannot alter default privileges for super user {}",
+                    grantee.name
+                )));
+            }
+        }
+
+        let kind = request.kind();
+        let mut default_privileges = BTreeMapTransaction::new(&mut user_core.default_privileges);
+        for &schema_id in &request.schema_ids {
+            if request.revoke {
+                let Some(mut template) = default_privileges.get_mut(schema_id) else {
+                    continue;
+                };
+                template.entries.retain(|entry| {
+                    !(entry.kind() == kind
+                        && entry.granted_by == request.granted_by
+                        && request.actions.contains(&entry.action)
+                        && request.grantee_ids.contains(&entry.grantee_id))
+                });
+                let is_empty = template.entries.is_empty();
+                drop(template);
+                if is_empty {
+                    default_privileges.remove(schema_id);
+                }
+            } else {
+                if default_privileges.get(&schema_id).is_none() {
+                    let database_id = database_core.schemas.get(&schema_id).unwrap().database_id;
+                    default_privileges.insert(
+                        schema_id,
+                        DefaultPrivilege {
+                            schema_id,
+                            database_id,
+                            entries: vec![],
+                        },
+                    );
+                }
+                let mut template = default_privileges.get_mut(schema_id).unwrap();
+                for &grantee_id in &request.grantee_ids {
+                    for &action in &request.actions {
+                        if let Some(entry) = template.entries.iter_mut().find(|entry| {
+                            entry.kind() == kind
+                                && entry.action == action
+                                && entry.grantee_id == grantee_id
+                        }) {
+                            entry.granted_by = request.granted_by;
+                            entry.with_grant_option |= request.with_grant_option;
+                        } else {
+                            template.entries.push(DefaultPrivilegeEntry {
+                                kind: kind as i32,
+                                action,
+                                grantee_id,
+                                granted_by: request.granted_by,
+                                with_grant_option: request.with_grant_option,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        commit_meta!(self, default_privileges)?;
+
+        Ok(())
+    }
+
+    /// Merge the default privileges registered for `schema_id` into the grantees' user
+    /// infos for a newly created relation. Returns the updated user infos; the caller
+    /// is responsible for notifying the frontends about them after the transaction
+    /// commits.
+    fn apply_default_privileges(
+        users: &mut BTreeMapTransaction<'_, UserId, UserInfo>,
+        default_privileges: &BTreeMap<SchemaId, DefaultPrivilege>,
+        schema_id: SchemaId,
+        kind: RelationKind,
+        object: Object,
+        owner: UserId,
+    ) -> Vec<UserInfo> {
+        let Some(template) = default_privileges.get(&schema_id) else {
+            return vec![];
+        };
+        let mut grants: HashMap<UserId, Vec<ActionWithGrantOption>> = HashMap::new();
+        for entry in &template.entries {
+            // The owner has full access to the relation anyway.
+            if entry.kind() == kind && entry.grantee_id != owner {
+                grants
+                    .entry(entry.grantee_id)
+                    .or_default()
+                    .push(ActionWithGrantOption {
+                        action: entry.action,
+                        with_grant_option: entry.with_grant_option,
+                        granted_by: entry.granted_by,
+                    });
+            }
+        }
+
+        let mut user_updated = vec![];
+        for (grantee_id, action_with_opts) in grants {
+            let Some(mut user) = users.get_mut(grantee_id) else {
+                continue;
+            };
+            if user.is_super {
+                continue;
+            }
+            let new_privilege = GrantPrivilege {
+                object: Some(object.clone()),
+                action_with_opts,
+            };
+            if let Some(privilege) = user
+                .grant_privileges
+                .iter_mut()
+                .find(|p| p.object == new_privilege.object)
+            {
+                Self::merge_privilege(privilege, &new_privilege);
+            } else {
+                user.grant_privileges.push(new_privilege);
+            }
+            user_updated.push(user.clone());
+        }
+        user_updated
+    }
+
     pub async fn grant_privilege(
         &self,
         user_ids: &[UserId],
@@ -4632,6 +7680,20 @@ impl CatalogManager {
             .get(&grantor)
             .cloned()
             .ok_or_else(|| MetaError::catalog_id_not_found("user", grantor))?;
+        // Privileges inherited from the grantor's roles also qualify for granting.
+        let grantor_privileges = Self::privileges_with_roles(users.tree_ref(), &grantor_info);
+        let grantable_user_count = users
+            .tree_ref()
+            .values()
+            .filter(|user| !user.is_super)
+            .count();
+        let overridden_warning = check_dangerous_grant(
+            &self.env.opts,
+            &grantor_info.name,
+            user_ids.len(),
+            grantable_user_count,
+            new_grant_privileges,
+        )?;
         for user_id in user_ids {
             let mut user = users
                 .get_mut(*user_id)
@@ -4652,8 +7714,7 @@ impl CatalogManager {
                     )? {
                         continue;
                     }
-                    if let Some(privilege) = grantor_info
-                        .grant_privileges
+                    if let Some(privilege) = grantor_privileges
                         .iter()
                         .find(|p| p.object == new_grant_privilege.object)
                     {
@@ -4701,6 +7762,25 @@ impl CatalogManager {
                 .await;
         }
 
+        if let Some(warning) = overridden_warning {
+            let audit_entry = PbDdlAuditLog {
+                command: "GRANT".into(),
+                user_id: grantor,
+                definition: warning,
+                success: true,
+                ..Default::default()
+            };
+            if let Err(err) = DdlAuditManager::new(self.env.clone())
+                .record(audit_entry)
+                .await
+            {
+                tracing::warn!(
+                    error = %err.as_report(),
+                    "failed to record dangerous grant audit log"
+                );
+            }
+        }
+
         Ok(version)
     }
 
@@ -4760,6 +7840,8 @@ impl CatalogManager {
             .get(&revoke_by)
             .ok_or_else(|| MetaError::catalog_id_not_found("user", revoke_by))?;
         let same_user = granted_by == revoke_by.id;
+        // Privileges inherited from the revoker's roles also qualify for revoking.
+        let revoke_by_privileges = Self::privileges_with_roles(users.tree_ref(), revoke_by);
         if !revoke_by.is_super {
             for privilege in revoke_grant_privileges {
                 if Self::check_owner(
@@ -4769,8 +7851,7 @@ impl CatalogManager {
                 )? {
                     continue;
                 }
-                if let Some(user_privilege) = revoke_by
-                    .grant_privileges
+                if let Some(user_privilege) = revoke_by_privileges
                     .iter()
                     .find(|p| p.object == privilege.object)
                 {
@@ -4898,7 +7979,8 @@ impl CatalogManager {
         source_id: SourceId,
         rate_limit: Option<u32>,
     ) -> MetaResult<()> {
-        let source_relation: PbSource;
+        let old_source: PbSource;
+        let new_source: PbSource;
         {
             let core = &mut *self.core.lock().await;
             let database_core = &mut core.database;
@@ -4907,19 +7989,16 @@ impl CatalogManager {
             let Some(source_catalog) = source.as_mut() else {
                 bail!("source {} not found", source_id)
             };
-            source_relation = source_catalog.clone();
+            old_source = source_catalog.clone();
             source_catalog.rate_limit = rate_limit;
+            new_source = source_catalog.clone();
             commit_meta!(self, sources)?;
         }
 
         let _version = self
-            .notify_frontend(
-                Operation::Update,
-                Info::RelationGroup(RelationGroup {
-                    relations: vec![Relation {
-                        relation_info: RelationInfo::Source(source_relation).into(),
-                    }],
-                }),
+            .notify_frontend_relation_update(
+                &RelationInfo::Source(old_source),
+                RelationInfo::Source(new_source),
             )
             .await;
         Ok(())
@@ -17,10 +17,10 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 
 use anyhow::anyhow;
 use risingwave_pb::catalog::table::TableType;
-use risingwave_pb::user::UserInfo;
+use risingwave_pb::user::{DefaultPrivilege, UserInfo};
 
 use super::database::DatabaseManager;
-use super::UserId;
+use super::{SchemaId, UserId};
 use crate::manager::MetaSrvEnv;
 use crate::model::MetadataModel;
 use crate::MetaResult;
@@ -31,17 +31,25 @@ pub struct UserManager {
     pub(super) user_grant_relation: HashMap<UserId, HashSet<UserId>>,
     /// The number of catalog whose owner is the user.
     pub(super) catalog_create_ref_count: HashMap<UserId, usize>,
+    /// Per-schema default privilege templates, applied on newly created relations.
+    pub(super) default_privileges: BTreeMap<SchemaId, DefaultPrivilege>,
 }
 
 impl UserManager {
     pub async fn new(env: MetaSrvEnv, database: &DatabaseManager) -> MetaResult<Self> {
         let users = UserInfo::list(env.meta_store().as_kv()).await?;
         let user_info = BTreeMap::from_iter(users.into_iter().map(|user| (user.id, user)));
+        let default_privileges = DefaultPrivilege::list(env.meta_store().as_kv())
+            .await?
+            .into_iter()
+            .map(|template| (template.schema_id, template))
+            .collect();
 
         let mut user_manager = Self {
             user_info,
             user_grant_relation: HashMap::new(),
             catalog_create_ref_count: HashMap::new(),
+            default_privileges,
         };
         user_manager.build_grant_relation_map();
 
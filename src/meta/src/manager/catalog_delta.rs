@@ -0,0 +1,166 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use risingwave_pb::catalog::table::TableType;
+use risingwave_pb::meta::pg_catalog_row_delta::Op;
+use risingwave_pb::meta::relation::RelationInfo;
+use risingwave_pb::meta::subscribe_response::{Info, Operation};
+use risingwave_pb::meta::{CatalogDelta, PgCatalogRowDelta};
+use tokio::sync::Mutex;
+
+/// Maximum number of notification versions retained in the log. Clients that fall
+/// further behind have to rebuild from a full catalog snapshot.
+const MAX_CATALOG_DELTAS: usize = 4096;
+
+/// A bounded log of per-notification-version row changes to the PostgreSQL-compatible
+/// system views, fed by the notification manager. Lightweight clients (e.g. BI drivers
+/// behind a thin proxy) poll it to maintain a `pg_catalog`/`information_schema` view
+/// without implementing the full catalog model.
+#[derive(Default)]
+pub struct CatalogDeltaLog {
+    inner: Mutex<CatalogDeltaLogInner>,
+}
+
+#[derive(Default)]
+struct CatalogDeltaLogInner {
+    deltas: VecDeque<CatalogDelta>,
+    /// The highest version that has been evicted from the log.
+    evicted_version: u64,
+}
+
+impl CatalogDeltaLog {
+    /// Records the system view rows affected by a catalog notification. Notifications
+    /// that do not touch any PostgreSQL-compatible view are skipped, so the log may
+    /// contain gaps in the version sequence.
+    pub async fn record(&self, version: u64, operation: Operation, info: &Info) {
+        let rows = pg_rows_of_info(operation, info);
+        if rows.is_empty() {
+            return;
+        }
+        let mut inner = self.inner.lock().await;
+        inner.deltas.push_back(CatalogDelta { version, rows });
+        while inner.deltas.len() > MAX_CATALOG_DELTAS {
+            let evicted = inner.deltas.pop_front().unwrap();
+            inner.evicted_version = evicted.version;
+        }
+    }
+
+    /// Returns all retained deltas with a version strictly greater than `from_version`,
+    /// and whether deltas past `from_version` have already been evicted.
+    pub async fn list(&self, from_version: u64) -> (Vec<CatalogDelta>, bool) {
+        let inner = self.inner.lock().await;
+        let truncated = from_version < inner.evicted_version;
+        let deltas = inner
+            .deltas
+            .iter()
+            .filter(|delta| delta.version > from_version)
+            .cloned()
+            .collect();
+        (deltas, truncated)
+    }
+}
+
+fn pg_rows_of_info(operation: Operation, info: &Info) -> Vec<PgCatalogRowDelta> {
+    let op = match operation {
+        Operation::Add | Operation::Update => Op::Upsert,
+        Operation::Delete => Op::Delete,
+        _ => return vec![],
+    };
+    match info {
+        Info::Database(database) => vec![make_row(
+            op,
+            "pg_database",
+            database.id,
+            vec![
+                ("datname", database.name.clone()),
+                ("datdba", database.owner.to_string()),
+            ],
+        )],
+        Info::Schema(schema) => vec![make_row(
+            op,
+            "pg_namespace",
+            schema.id,
+            vec![
+                ("nspname", schema.name.clone()),
+                ("nspowner", schema.owner.to_string()),
+            ],
+        )],
+        Info::RelationGroup(group) => group
+            .relations
+            .iter()
+            .filter_map(|relation| relation.relation_info.as_ref())
+            .filter_map(|relation_info| pg_row_of_relation(op, relation_info))
+            .collect(),
+        // The partial relation of a diff always carries the fields that back `pg_class`
+        // rows, so diffs can be handled the same way as full relations.
+        Info::RelationDiffGroup(group) => group
+            .diffs
+            .iter()
+            .filter_map(|diff| diff.relation.as_ref())
+            .filter_map(|relation| relation.relation_info.as_ref())
+            .filter_map(|relation_info| pg_row_of_relation(op, relation_info))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn pg_row_of_relation(op: Op, relation: &RelationInfo) -> Option<PgCatalogRowDelta> {
+    let (oid, name, schema_id, owner, relkind) = match relation {
+        RelationInfo::Table(table) => {
+            let relkind = match table.table_type() {
+                TableType::Table => "r",
+                TableType::MaterializedView => "m",
+                // Internal and index tables do not appear in `pg_class`; indexes are
+                // covered by the `Index` relation info.
+                TableType::Index | TableType::Internal | TableType::Unspecified => return None,
+            };
+            (table.id, &table.name, table.schema_id, table.owner, relkind)
+        }
+        RelationInfo::Index(index) => (index.id, &index.name, index.schema_id, index.owner, "i"),
+        RelationInfo::View(view) => (view.id, &view.name, view.schema_id, view.owner, "v"),
+        // Sources, sinks and subscriptions have no PostgreSQL-compatible representation.
+        RelationInfo::Source(_) | RelationInfo::Sink(_) | RelationInfo::Subscription(_) => {
+            return None
+        }
+    };
+    Some(make_row(
+        op,
+        "pg_class",
+        oid,
+        vec![
+            ("relname", name.clone()),
+            ("relnamespace", schema_id.to_string()),
+            ("relowner", owner.to_string()),
+            ("relkind", relkind.to_string()),
+        ],
+    ))
+}
+
+fn make_row(op: Op, view: &str, oid: u32, columns: Vec<(&str, String)>) -> PgCatalogRowDelta {
+    PgCatalogRowDelta {
+        op: op as i32,
+        view: view.to_string(),
+        oid,
+        columns: if op == Op::Delete {
+            Default::default()
+        } else {
+            columns
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect()
+        },
+    }
+}
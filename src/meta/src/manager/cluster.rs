@@ -327,6 +327,7 @@ impl ClusterManager {
 
         // Update core.
         core.delete_worker_node(worker);
+        self.env.actor_liveness().remove_worker(worker_node.id);
 
         // Notify frontends to delete compute node.
         if worker_type == WorkerType::ComputeNode {
@@ -359,6 +360,9 @@ impl ClusterManager {
             if worker.worker_id() == worker_id {
                 worker.update_expire_at(self.max_heartbeat_interval);
                 worker.update_info(info);
+                self.env
+                    .actor_liveness()
+                    .report_alive(worker_id, self.max_heartbeat_interval);
                 return Ok(());
             }
         }
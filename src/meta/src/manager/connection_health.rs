@@ -0,0 +1,127 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use risingwave_pb::catalog::connection::private_link_service::PbPrivateLinkProvider;
+use risingwave_pb::catalog::connection::PbHealthStatus;
+use risingwave_pb::catalog::{connection, Connection};
+use thiserror_ext::AsReport;
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
+
+use crate::manager::MetadataManager;
+use crate::rpc::cloud_provider::AwsEc2Client;
+
+/// Periodically validates `Connection` catalog objects, e.g. checks that the AWS VPC
+/// endpoint behind a private link connection is still available. The result is recorded
+/// in the catalog and pushed to frontends, so that `SHOW CONNECTIONS` reflects it.
+pub struct ConnectionHealthChecker {
+    metadata_manager: MetadataManager,
+    aws_client: Arc<Option<AwsEc2Client>>,
+}
+
+impl ConnectionHealthChecker {
+    pub fn new(metadata_manager: MetadataManager, aws_client: Arc<Option<AwsEc2Client>>) -> Self {
+        Self {
+            metadata_manager,
+            aws_client,
+        }
+    }
+
+    pub fn start(self, check_interval: Duration) -> (JoinHandle<()>, Sender<()>) {
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+        let join_handle = tokio::spawn(async move {
+            let mut min_interval = tokio::time::interval(check_interval);
+            min_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    // Wait for interval
+                    _ = min_interval.tick() => {},
+                    // Shutdown
+                    _ = &mut shutdown_rx => {
+                        tracing::info!("Connection health checker is stopped");
+                        return;
+                    }
+                }
+                self.tick().await;
+            }
+        });
+        (join_handle, shutdown_tx)
+    }
+
+    async fn tick(&self) {
+        let MetadataManager::V1(mgr) = &self.metadata_manager else {
+            // Health results are recorded in the connection catalog, which is not
+            // supported by the sql meta backend yet.
+            return;
+        };
+        for connection in mgr.catalog_manager.list_connections().await {
+            let Some((status, message)) = self.check(&connection).await else {
+                continue;
+            };
+            // Only write the catalog and notify frontends on a state change, to avoid
+            // a catalog write per connection per tick.
+            if connection.health_status == status as i32 && connection.health_message == message {
+                continue;
+            }
+            if status == PbHealthStatus::Unhealthy {
+                tracing::warn!(
+                    connection = connection.name,
+                    error = message,
+                    "connection became unhealthy"
+                );
+            }
+            let checked_at_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("system clock set before UNIX epoch")
+                .as_millis() as u64;
+            if let Err(e) = mgr
+                .catalog_manager
+                .update_connection_health(connection.id, status, message, checked_at_ms)
+                .await
+            {
+                tracing::warn!(
+                    connection = connection.name,
+                    error = %e.as_report(),
+                    "failed to record connection health"
+                );
+            }
+        }
+    }
+
+    /// Check a single connection. Returns `None` if the connection cannot be checked,
+    /// e.g. the AWS client is not configured, leaving its recorded status untouched.
+    async fn check(&self, connection: &Connection) -> Option<(PbHealthStatus, String)> {
+        match &connection.info {
+            Some(connection::Info::PrivateLinkService(svc)) => match svc.get_provider() {
+                Ok(PbPrivateLinkProvider::Mock) => Some((PbHealthStatus::Healthy, String::new())),
+                Ok(PbPrivateLinkProvider::Aws) => {
+                    let aws_client = self.aws_client.as_ref()?;
+                    match aws_client.is_vpc_endpoint_ready(&svc.endpoint_id).await {
+                        Ok(true) => Some((PbHealthStatus::Healthy, String::new())),
+                        Ok(false) => Some((
+                            PbHealthStatus::Unhealthy,
+                            format!("VPC endpoint {} is not available", svc.endpoint_id),
+                        )),
+                        Err(e) => Some((PbHealthStatus::Unhealthy, e.to_report_string())),
+                    }
+                }
+                Ok(PbPrivateLinkProvider::Unspecified) | Err(_) => None,
+            },
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::barrier::BarrierScheduler;
+use crate::hummock::HummockManagerRef;
+use crate::{MetaError, MetaResult};
+
+pub type ConsistencyFenceManagerRef = Arc<ConsistencyFenceManager>;
+
+/// Manages consistency fences for sequencing batch reads after streaming writes.
+///
+/// A fence captures the committed epoch at creation time. Awaiting the fence resolves
+/// once a checkpoint has committed past that epoch, i.e. once every streaming job has
+/// durably processed all data ingested before the fence was created. This lets external
+/// orchestrators safely run batch reads against the results of preceding streaming
+/// writes. Fences are kept in meta memory only and do not survive meta node restarts.
+pub struct ConsistencyFenceManager {
+    hummock_manager: HummockManagerRef,
+    barrier_scheduler: BarrierScheduler,
+    next_token: AtomicU64,
+    /// Fence epochs keyed by token.
+    fences: Mutex<HashMap<u64, u64>>,
+}
+
+impl ConsistencyFenceManager {
+    pub fn new(hummock_manager: HummockManagerRef, barrier_scheduler: BarrierScheduler) -> Self {
+        Self {
+            hummock_manager,
+            barrier_scheduler,
+            next_token: AtomicU64::new(0),
+            fences: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Creates a fence bound to the currently committed epoch, returning its token
+    /// together with the fence epoch.
+    pub fn create_fence(&self) -> (u64, u64) {
+        let fence_epoch = self.hummock_manager.latest_snapshot().committed_epoch;
+        let token = self.next_token.fetch_add(1, Ordering::Relaxed) + 1;
+        self.fences.lock().insert(token, fence_epoch);
+        (token, fence_epoch)
+    }
+
+    /// Resolves once a checkpoint has committed past the fence's epoch, returning the
+    /// committed epoch that resolved the fence. If no checkpoint has committed since
+    /// the fence was created, a checkpoint barrier is forced rather than waiting for
+    /// the periodic one, so the call resolves promptly. Idempotent: awaiting an
+    /// already resolved fence returns immediately.
+    pub async fn await_fence(&self, token: u64) -> MetaResult<u64> {
+        let fence_epoch = self
+            .fences
+            .lock()
+            .get(&token)
+            .copied()
+            .ok_or_else(|| MetaError::invalid_parameter(format!("fence {} not found", token)))?;
+        let committed_epoch = self.hummock_manager.latest_snapshot().committed_epoch;
+        if committed_epoch > fence_epoch {
+            return Ok(committed_epoch);
+        }
+        let snapshot = self.barrier_scheduler.flush(true).await?;
+        Ok(snapshot.committed_epoch)
+    }
+}
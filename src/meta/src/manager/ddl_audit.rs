@@ -0,0 +1,91 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use risingwave_meta_model_v2::ddl_audit_log;
+use risingwave_meta_model_v2::prelude::DdlAuditLog;
+use risingwave_pb::ddl_service::PbDdlAuditLog;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ActiveModelTrait, EntityTrait, QueryOrder};
+
+use crate::manager::{IdCategory, MetaSrvEnv, MetaStoreImpl};
+use crate::model::MetadataModel;
+use crate::MetaResult;
+
+/// `DdlAuditManager` records every DDL executed by the meta node into a dedicated
+/// meta store table, so that operators can audit who changed what and when via
+/// the `rw_ddl_audit` system view.
+///
+/// Audit entries are written after the audited DDL has finished and record its
+/// outcome; a failure to persist an entry is logged by the caller and never
+/// fails the DDL itself.
+pub struct DdlAuditManager {
+    env: MetaSrvEnv,
+}
+
+impl DdlAuditManager {
+    pub fn new(env: MetaSrvEnv) -> Self {
+        Self { env }
+    }
+
+    /// Persists one audit entry, stamping its id and event time.
+    pub async fn record(&self, mut entry: PbDdlAuditLog) -> MetaResult<()> {
+        entry.event_time_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        match self.env.meta_store_ref() {
+            MetaStoreImpl::Kv(meta_store) => {
+                entry.id = self
+                    .env
+                    .id_gen_manager()
+                    .as_kv()
+                    .generate::<{ IdCategory::DdlAuditLog }>()
+                    .await?;
+                entry.insert(meta_store).await?;
+            }
+            MetaStoreImpl::Sql(sql_meta_store) => {
+                ddl_audit_log::ActiveModel {
+                    id: Default::default(),
+                    event_time_ms: Set(entry.event_time_ms as _),
+                    command: Set(entry.command),
+                    object_id: Set(entry.object_id as _),
+                    object_name: Set(entry.object_name),
+                    user_id: Set(entry.user_id as _),
+                    definition: Set(entry.definition),
+                    success: Set(entry.success),
+                    error: Set(entry.error),
+                }
+                .insert(&sql_meta_store.conn)
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists all audit entries in insertion order.
+    pub async fn list_audit_logs(&self) -> MetaResult<Vec<PbDdlAuditLog>> {
+        match self.env.meta_store_ref() {
+            MetaStoreImpl::Kv(meta_store) => Ok(PbDdlAuditLog::list(meta_store).await?),
+            MetaStoreImpl::Sql(sql_meta_store) => Ok(DdlAuditLog::find()
+                .order_by_asc(ddl_audit_log::Column::Id)
+                .all(&sql_meta_store.conn)
+                .await?
+                .into_iter()
+                .map(Into::into)
+                .collect()),
+        }
+    }
+}
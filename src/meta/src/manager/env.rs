@@ -18,8 +18,10 @@ use std::sync::Arc;
 use risingwave_common::config::{
     CompactionConfig, DefaultParallelism, MetaBackend, ObjectStoreConfig,
 };
+use risingwave_common::current_cluster_version;
 use risingwave_common::session_config::SessionConfig;
-use risingwave_common::system_param::reader::SystemParamsReader;
+use risingwave_common::system_param::reader::{SystemParamsRead, SystemParamsReader};
+use risingwave_common::system_param::CHECKPOINT_FREQUENCY_KEY;
 use risingwave_meta_model_migration::{MigrationStatus, Migrator, MigratorTrait};
 use risingwave_meta_model_v2::prelude::Cluster;
 use risingwave_pb::meta::SystemParams;
@@ -40,8 +42,9 @@ use crate::controller::SqlMetaStore;
 use crate::hummock::sequence::SequenceGenerator;
 use crate::manager::event_log::{start_event_log_manager, EventLogManagerRef};
 use crate::manager::{
-    IdGeneratorManager, IdGeneratorManagerRef, IdleManager, IdleManagerRef, NotificationManager,
-    NotificationManagerRef,
+    ActorLivenessMap, ActorLivenessMapRef, IdGeneratorManager, IdGeneratorManagerRef, IdleManager,
+    IdleManagerRef, MaintenanceModeManager, MaintenanceModeManagerRef, NotificationManager,
+    NotificationManagerRef, TableChangeNotifier, TableChangeNotifierRef,
 };
 use crate::model::ClusterId;
 use crate::storage::{MetaStore, MetaStoreRef};
@@ -131,6 +134,15 @@ pub struct MetaSrvEnv {
     /// idle status manager.
     idle_manager: IdleManagerRef,
 
+    /// actor liveness map derived from compute-node heartbeats.
+    actor_liveness: ActorLivenessMapRef,
+
+    /// cluster maintenance mode state.
+    maintenance_mode: MaintenanceModeManagerRef,
+
+    /// per-table committed epoch notifier for external serving caches.
+    table_change_notifier: TableChangeNotifierRef,
+
     event_log_manager: EventLogManagerRef,
 
     /// Unique identifier of the cluster.
@@ -169,6 +181,15 @@ pub struct MetaOpts {
     /// Interval of invoking a vacuum job, to remove stale metadata from meta store and objects
     /// from object store.
     pub vacuum_interval_sec: u64,
+    /// How long a soft-dropped relation is kept restorable before it is dropped for real.
+    /// 0 disables the purge.
+    pub soft_drop_retention_sec: u64,
+    /// Whether to block dangerous privilege grants for users not in
+    /// `dangerous_grant_allowlist`.
+    pub enable_dangerous_grant_check: bool,
+    /// Names of users that may perform dangerous grants even when
+    /// `enable_dangerous_grant_check` is set.
+    pub dangerous_grant_allowlist: Vec<String>,
     /// The spin interval inside a vacuum job. It avoids the vacuum job monopolizing resources of
     /// meta node.
     pub vacuum_spin_interval_ms: u64,
@@ -194,6 +215,25 @@ pub struct MetaOpts {
     pub periodic_compaction_interval_sec: u64,
     /// Interval of reporting the number of nodes in the cluster.
     pub node_num_monitor_interval_sec: u64,
+    /// Interval of the periodic GC for dirty streaming job metadata. 0 disables it.
+    pub dirty_job_gc_interval_sec: u64,
+    /// Interval of the periodic connection health check. 0 disables it.
+    pub connection_health_check_interval_sec: u64,
+    /// Interval of scheduled automatic meta backup. 0 disables it.
+    pub auto_backup_interval_sec: u64,
+    /// Interval of the background compaction of meta-store keys left behind by
+    /// long-dropped catalog objects. 0 disables it.
+    pub catalog_compaction_interval_sec: u64,
+
+    /// Soft limit on the encoded size of the in-memory catalog, in bytes.
+    /// Exceeding it only triggers warnings. 0 disables the check.
+    pub catalog_memory_soft_limit_bytes: u64,
+
+    /// Per-client rate limit on read-heavy catalog RPCs, in requests per second.
+    /// 0 disables rate limiting.
+    pub catalog_read_rate_limit_per_client: u64,
+    /// Per-client cap on in-flight read-heavy catalog RPCs. 0 disables the cap.
+    pub catalog_read_concurrency_per_client: u64,
 
     /// The Prometheus endpoint for Meta Dashboard Service.
     /// The Dashboard service uses this in the following ways:
@@ -314,6 +354,9 @@ impl MetaOpts {
             compaction_deterministic_test: false,
             default_parallelism: DefaultParallelism::Full,
             vacuum_interval_sec: 30,
+            soft_drop_retention_sec: 3600 * 24,
+            enable_dangerous_grant_check: false,
+            dangerous_grant_allowlist: vec![],
             vacuum_spin_interval_ms: 0,
             hummock_version_checkpoint_interval_sec: 30,
             enable_hummock_data_archive: false,
@@ -325,6 +368,13 @@ impl MetaOpts {
             enable_committed_sst_sanity_check: false,
             periodic_compaction_interval_sec: 60,
             node_num_monitor_interval_sec: 10,
+            dirty_job_gc_interval_sec: 0,
+            connection_health_check_interval_sec: 0,
+            auto_backup_interval_sec: 0,
+            catalog_compaction_interval_sec: 0,
+            catalog_memory_soft_limit_bytes: 0,
+            catalog_read_rate_limit_per_client: 0,
+            catalog_read_concurrency_per_client: 0,
             prometheus_endpoint: None,
             prometheus_selector: None,
             vpc_id: None,
@@ -395,6 +445,9 @@ impl MetaSrvEnv {
         meta_store_impl: MetaStoreImpl,
     ) -> MetaResult<Self> {
         let idle_manager = Arc::new(IdleManager::new(opts.max_idle_ms));
+        let actor_liveness = Arc::new(ActorLivenessMap::new());
+        let table_change_notifier = Arc::new(TableChangeNotifier::new());
+        let maintenance_mode = Arc::new(MaintenanceModeManager::new());
         let stream_client_pool = Arc::new(StreamClientPool::new(1)); // typically no need for plural clients
         let frontend_client_pool = Arc::new(FrontendClientPool::new(1));
         let event_log_manager = Arc::new(start_event_log_manager(
@@ -456,6 +509,9 @@ impl MetaSrvEnv {
                     stream_client_pool,
                     frontend_client_pool,
                     idle_manager,
+                    actor_liveness: actor_liveness.clone(),
+                    maintenance_mode: maintenance_mode.clone(),
+                    table_change_notifier: table_change_notifier.clone(),
                     event_log_manager,
                     cluster_id,
                     hummock_seq: None,
@@ -465,10 +521,24 @@ impl MetaSrvEnv {
             MetaStoreImpl::Sql(sql_meta_store) => {
                 let cluster_first_launch =
                     is_first_launch_for_sql_backend_cluster(sql_meta_store).await?;
+                // Refuse to start if the meta store was migrated by a newer binary, as this
+                // binary would misinterpret the migrated data.
+                Migrator::check_migration_compatibility(
+                    &sql_meta_store.conn,
+                    &current_cluster_version(),
+                )
+                .await
+                .expect("Meta store migration compatibility check failed");
                 // Try to upgrade if any new model changes are added.
                 Migrator::up(&sql_meta_store.conn, None)
                     .await
                     .expect("Failed to upgrade models in meta store");
+                Migrator::record_applied_migrations(
+                    &sql_meta_store.conn,
+                    &current_cluster_version(),
+                )
+                .await
+                .expect("Failed to record applied migrations for the migration guard");
 
                 let notification_manager =
                     Arc::new(NotificationManager::new(meta_store_impl.clone()).await);
@@ -516,6 +586,9 @@ impl MetaSrvEnv {
                     stream_client_pool,
                     frontend_client_pool,
                     idle_manager,
+                    actor_liveness: actor_liveness.clone(),
+                    maintenance_mode: maintenance_mode.clone(),
+                    table_change_notifier: table_change_notifier.clone(),
                     event_log_manager,
                     cluster_id,
                     hummock_seq: Some(Arc::new(SequenceGenerator::new(
@@ -556,6 +629,73 @@ impl MetaSrvEnv {
         self.idle_manager.deref()
     }
 
+    pub fn actor_liveness_ref(&self) -> ActorLivenessMapRef {
+        self.actor_liveness.clone()
+    }
+
+    pub fn actor_liveness(&self) -> &ActorLivenessMap {
+        self.actor_liveness.deref()
+    }
+
+    pub fn maintenance_mode(&self) -> &MaintenanceModeManager {
+        self.maintenance_mode.deref()
+    }
+
+    /// Enters maintenance mode: raises the checkpoint frequency and defers periodic compaction
+    /// scheduling and other low-priority background jobs, until [`Self::exit_maintenance_mode`]
+    /// restores the previous settings. The transition is recorded in the event log.
+    pub async fn enter_maintenance_mode(&self) -> MetaResult<()> {
+        let checkpoint_frequency = self.system_params_reader().await.checkpoint_frequency();
+        let Some(raised) = self.maintenance_mode.enter(checkpoint_frequency) else {
+            return Err(anyhow::anyhow!("the cluster is already in maintenance mode").into());
+        };
+        self.set_checkpoint_frequency(raised).await?;
+        self.add_maintenance_mode_event(true, raised);
+        Ok(())
+    }
+
+    /// Exits maintenance mode and restores the settings saved when entering it. The transition
+    /// is recorded in the event log.
+    pub async fn exit_maintenance_mode(&self) -> MetaResult<()> {
+        let Some(restored) = self.maintenance_mode.exit() else {
+            return Err(anyhow::anyhow!("the cluster is not in maintenance mode").into());
+        };
+        self.set_checkpoint_frequency(restored).await?;
+        self.add_maintenance_mode_event(false, restored);
+        Ok(())
+    }
+
+    async fn set_checkpoint_frequency(&self, value: u64) -> MetaResult<()> {
+        let value = Some(value.to_string());
+        match &self.system_param_manager_impl {
+            SystemParamsManagerImpl::Kv(mgr) => {
+                mgr.set_param(CHECKPOINT_FREQUENCY_KEY, value).await?;
+            }
+            SystemParamsManagerImpl::Sql(mgr) => {
+                mgr.set_param(CHECKPOINT_FREQUENCY_KEY, value).await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn add_maintenance_mode_event(&self, entered: bool, checkpoint_frequency: u64) {
+        let event = risingwave_pb::meta::event_log::EventMaintenanceMode {
+            entered,
+            checkpoint_frequency,
+        };
+        self.event_log_manager.add_event_logs(vec![
+            risingwave_pb::meta::event_log::Event::MaintenanceMode(event),
+        ]);
+    }
+
+    pub fn table_change_notifier_ref(&self) -> TableChangeNotifierRef {
+        self.table_change_notifier.clone()
+    }
+
+    pub fn table_change_notifier(&self) -> &TableChangeNotifier {
+        self.table_change_notifier.deref()
+    }
+
     pub async fn system_params_reader(&self) -> SystemParamsReader {
         match &self.system_param_manager_impl {
             SystemParamsManagerImpl::Kv(mgr) => mgr.get_params().await,
@@ -172,6 +172,13 @@ impl From<&EventLog> for ChannelId {
             Event::CollectBarrierFail(_) => 6,
             Event::WorkerNodePanic(_) => 7,
             Event::AutoSchemaChangeFail(_) => 8,
+            Event::MaintenanceMode(_) => 9,
+            Event::SubscriptionLag(_) => 10,
+            Event::ForegroundDdlToBackground(_) => 11,
+            Event::AutoSchemaChangeReject(_) => 12,
+            Event::ClusterCapacityChange(_) => 13,
+            Event::CompactionGroupSchedule(_) => 14,
+            Event::SourceDeadLetter(_) => 15,
         }
     }
 }
@@ -138,6 +138,8 @@ pub mod IdCategory {
     pub const Connection: IdCategoryType = 17;
 
     pub const Secret: IdCategoryType = 18;
+    pub const DdlAuditLog: IdCategoryType = 19;
+    pub const UsageRecord: IdCategoryType = 20;
 }
 
 pub type IdGeneratorManagerRef = Arc<IdGeneratorManager>;
@@ -161,6 +163,8 @@ pub struct IdGeneratorManager {
     compaction_group: Arc<StoredIdGenerator>,
     connection: Arc<StoredIdGenerator>,
     secret: Arc<StoredIdGenerator>,
+    ddl_audit_log: Arc<StoredIdGenerator>,
+    usage_record: Arc<StoredIdGenerator>,
 }
 
 impl IdGeneratorManager {
@@ -208,6 +212,12 @@ impl IdGeneratorManager {
                 StoredIdGenerator::new(meta_store.clone(), "connection", None).await,
             ),
             secret: Arc::new(StoredIdGenerator::new(meta_store.clone(), "secret", None).await),
+            ddl_audit_log: Arc::new(
+                StoredIdGenerator::new(meta_store.clone(), "ddl_audit_log", Some(1)).await,
+            ),
+            usage_record: Arc::new(
+                StoredIdGenerator::new(meta_store.clone(), "usage_record", Some(1)).await,
+            ),
         }
     }
 
@@ -229,6 +239,8 @@ impl IdGeneratorManager {
             IdCategory::CompactionGroup => &self.compaction_group,
             IdCategory::Connection => &self.connection,
             IdCategory::Secret => &self.secret,
+            IdCategory::DdlAuditLog => &self.ddl_audit_log,
+            IdCategory::UsageRecord => &self.usage_record,
             _ => unreachable!(),
         }
     }
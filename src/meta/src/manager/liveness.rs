@@ -0,0 +1,70 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::manager::WorkerId;
+
+/// `ActorLivenessMap` tracks the liveness of the actors hosted on each compute node, derived
+/// from compute-node heartbeats.
+///
+/// Each heartbeat refreshes the deadline of the reporting worker. The barrier manager consults
+/// the map before injecting a barrier: if a worker hosting actors that must collect the barrier
+/// has already missed its heartbeat deadline, injection short-circuits into recovery
+/// immediately instead of waiting an entire barrier round-trip for collection to fail.
+pub struct ActorLivenessMap {
+    /// Heartbeat deadline of each worker. A worker absent from the map has not reported any
+    /// heartbeat yet and is considered alive to avoid false positives on startup.
+    deadlines: parking_lot::RwLock<HashMap<WorkerId, Instant>>,
+}
+
+pub type ActorLivenessMapRef = Arc<ActorLivenessMap>;
+
+impl ActorLivenessMap {
+    pub fn new() -> Self {
+        Self {
+            deadlines: parking_lot::RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Refreshes the heartbeat deadline of the given worker. Called on each heartbeat with the
+    /// cluster-level max heartbeat interval.
+    pub fn report_alive(&self, worker_id: WorkerId, expire_after: Duration) {
+        self.deadlines
+            .write()
+            .insert(worker_id, Instant::now() + expire_after);
+    }
+
+    /// Removes the worker from the map, e.g. when it's unregistered from the cluster.
+    pub fn remove_worker(&self, worker_id: WorkerId) {
+        self.deadlines.write().remove(&worker_id);
+    }
+
+    /// Returns true if the worker has missed its heartbeat deadline, i.e. all actors hosted on
+    /// it are known dead. Workers that have never reported are considered alive.
+    pub fn is_expired(&self, worker_id: WorkerId) -> bool {
+        self.deadlines
+            .read()
+            .get(&worker_id)
+            .map_or(false, |deadline| Instant::now() > *deadline)
+    }
+}
+
+impl Default for ActorLivenessMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
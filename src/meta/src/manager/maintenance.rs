@@ -0,0 +1,77 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+/// Multiplier applied to `checkpoint_frequency` while the cluster is in maintenance mode, so
+/// that checkpoints are taken less often during e.g. rolling upgrades or storage migrations.
+const MAINTENANCE_CHECKPOINT_FREQUENCY_FACTOR: u64 = 10;
+
+/// Settings saved when entering maintenance mode, to be restored on exit.
+struct SavedSettings {
+    checkpoint_frequency: u64,
+}
+
+/// `MaintenanceModeManager` tracks whether the cluster is in maintenance mode.
+///
+/// While maintenance mode is on, periodic compaction scheduling and other low-priority
+/// background jobs are deferred, and the checkpoint frequency is raised. The settings in
+/// effect before entering are saved here and restored on exit.
+pub struct MaintenanceModeManager {
+    saved: Mutex<Option<SavedSettings>>,
+}
+
+pub type MaintenanceModeManagerRef = Arc<MaintenanceModeManager>;
+
+impl MaintenanceModeManager {
+    pub fn new() -> Self {
+        Self {
+            saved: Mutex::new(None),
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.saved.lock().is_some()
+    }
+
+    /// Saves the settings to restore on exit and returns the raised checkpoint frequency to
+    /// apply, or `None` if the cluster is already in maintenance mode.
+    pub fn enter(&self, checkpoint_frequency: u64) -> Option<u64> {
+        let mut saved = self.saved.lock();
+        if saved.is_some() {
+            return None;
+        }
+        *saved = Some(SavedSettings {
+            checkpoint_frequency,
+        });
+        Some(checkpoint_frequency.saturating_mul(MAINTENANCE_CHECKPOINT_FREQUENCY_FACTOR))
+    }
+
+    /// Returns the checkpoint frequency to restore, or `None` if the cluster is not in
+    /// maintenance mode.
+    pub fn exit(&self) -> Option<u64> {
+        self.saved
+            .lock()
+            .take()
+            .map(|saved| saved.checkpoint_frequency)
+    }
+}
+
+impl Default for MaintenanceModeManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -19,13 +19,17 @@ use std::time::Duration;
 use anyhow::anyhow;
 use futures::future::{select, Either};
 use risingwave_common::catalog::{TableId, TableOption};
+use risingwave_common::util::epoch::Epoch;
 use risingwave_meta_model_v2::{ObjectId, SourceId};
-use risingwave_pb::catalog::{PbSink, PbSource, PbTable};
+use risingwave_pb::catalog::subscription::PbRetentionPolicy;
+use risingwave_pb::catalog::{PbSink, PbSource, PbStorageClassPolicy, PbSubscription, PbTable};
 use risingwave_pb::common::worker_node::{PbResource, State};
 use risingwave_pb::common::{HostAddress, PbWorkerNode, PbWorkerType, WorkerNode, WorkerType};
 use risingwave_pb::meta::add_worker_node_request::Property as AddNodeProperty;
 use risingwave_pb::meta::table_fragments::{ActorStatus, Fragment, PbFragment};
 use risingwave_pb::stream_plan::{PbDispatchStrategy, StreamActor};
+use risingwave_pb::user::grant_privilege::PbObject as PbGrantObject;
+use risingwave_pb::user::PbGrantPrivilege;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
 use tokio::sync::oneshot;
 use tokio::time::{sleep, Instant};
@@ -35,13 +39,13 @@ use crate::barrier::Reschedule;
 use crate::controller::catalog::CatalogControllerRef;
 use crate::controller::cluster::{ClusterControllerRef, WorkerExtraInfo};
 use crate::manager::{
-    CatalogManagerRef, ClusterManagerRef, FragmentManagerRef, LocalNotification,
+    CatalogManagerRef, ClusterManagerRef, FragmentManagerRef, LocalNotification, MetaOpts,
     NotificationVersion, StreamingClusterInfo, StreamingJob, WorkerId,
 };
 use crate::model::{
     ActorId, ClusterId, FragmentId, MetadataModel, TableFragments, TableParallelism,
 };
-use crate::stream::SplitAssignment;
+use crate::stream::{SplitAssignment, ThrottleConfig};
 use crate::telemetry::MetaTelemetryJobDesc;
 use crate::{MetaError, MetaResult};
 
@@ -395,6 +399,13 @@ impl MetadataManager {
         }
     }
 
+    pub async fn list_sinks(&self) -> MetaResult<Vec<PbSink>> {
+        match self {
+            MetadataManager::V1(mgr) => Ok(mgr.catalog_manager.list_sinks().await),
+            MetadataManager::V2(mgr) => mgr.catalog_controller.list_sinks().await,
+        }
+    }
+
     pub async fn pre_apply_reschedules(
         &self,
         created_actors: HashMap<FragmentId, HashMap<ActorId, (StreamActor, ActorStatus)>>,
@@ -542,6 +553,27 @@ impl MetadataManager {
         }
     }
 
+    /// Returns the database each table (including internal state tables) belongs to,
+    /// keyed by table id.
+    pub async fn get_table_database_mapping(&self) -> MetaResult<HashMap<u32, u32>> {
+        match &self {
+            MetadataManager::V1(mgr) => Ok(mgr
+                .catalog_manager
+                .list_tables()
+                .await
+                .into_iter()
+                .map(|table| (table.id, table.database_id))
+                .collect()),
+            MetadataManager::V2(mgr) => Ok(mgr
+                .catalog_controller
+                .get_table_database_mapping()
+                .await?
+                .into_iter()
+                .map(|(table_id, database_id)| (table_id as u32, database_id as u32))
+                .collect()),
+        }
+    }
+
     pub async fn get_created_table_ids(&self) -> MetaResult<Vec<u32>> {
         match &self {
             MetadataManager::V1(mgr) => Ok(mgr.catalog_manager.get_created_table_ids().await),
@@ -641,6 +673,28 @@ impl MetadataManager {
         }
     }
 
+    /// Returns the storage class policies of all tables that have one, keyed by table id.
+    pub async fn get_table_storage_class_policies(
+        &self,
+    ) -> MetaResult<HashMap<u32, PbStorageClassPolicy>> {
+        match &self {
+            MetadataManager::V1(mgr) => Ok(mgr
+                .catalog_manager
+                .list_tables()
+                .await
+                .into_iter()
+                .filter_map(|table| table.storage_class_policy.map(|p| (table.id, p)))
+                .collect()),
+            MetadataManager::V2(mgr) => Ok(mgr
+                .catalog_controller
+                .get_table_storage_class_policies()
+                .await?
+                .into_iter()
+                .map(|(table_id, policy)| (table_id as u32, policy))
+                .collect()),
+        }
+    }
+
     pub async fn get_job_id_to_internal_table_ids_mapping(&self) -> Option<Vec<(u32, Vec<u32>)>> {
         match &self {
             MetadataManager::V1(mgr) => mgr
@@ -792,6 +846,53 @@ impl MetadataManager {
         }
     }
 
+    /// Counts the running actors of each database, across all streaming jobs of the
+    /// database.
+    pub async fn count_actors_per_database(&self) -> MetaResult<HashMap<u32, usize>> {
+        match &self {
+            MetadataManager::V1(mgr) => {
+                let mut job_to_database: HashMap<u32, u32> = mgr
+                    .catalog_manager
+                    .list_tables()
+                    .await
+                    .into_iter()
+                    .map(|table| (table.id, table.database_id))
+                    .collect();
+                job_to_database.extend(
+                    mgr.catalog_manager
+                        .list_sinks()
+                        .await
+                        .into_iter()
+                        .map(|sink| (sink.id, sink.database_id)),
+                );
+                job_to_database.extend(
+                    mgr.catalog_manager
+                        .list_sources()
+                        .await
+                        .into_iter()
+                        .map(|source| (source.id, source.database_id)),
+                );
+
+                let mut actor_counts: HashMap<u32, usize> = HashMap::new();
+                let guard = mgr.fragment_manager.get_fragment_read_guard().await;
+                for (job_id, table_fragments) in guard.table_fragments() {
+                    if let Some(database_id) = job_to_database.get(&job_id.table_id) {
+                        *actor_counts.entry(*database_id).or_default() +=
+                            table_fragments.actor_ids().len();
+                    }
+                }
+                Ok(actor_counts)
+            }
+            MetadataManager::V2(mgr) => {
+                let actor_cnt = mgr.catalog_controller.count_actors_per_database().await?;
+                Ok(actor_cnt
+                    .into_iter()
+                    .map(|(id, cnt)| (id as u32, cnt))
+                    .collect())
+            }
+        }
+    }
+
     pub async fn count_streaming_job(&self) -> MetaResult<usize> {
         match self {
             MetadataManager::V1(mgr) => Ok(mgr.fragment_manager.count_streaming_job().await),
@@ -865,6 +966,105 @@ impl MetadataManager {
         }
     }
 
+    pub async fn update_sink_rate_limit_by_sink_id(
+        &self,
+        sink_id: u32,
+        rate_limit: Option<u32>,
+    ) -> MetaResult<HashMap<FragmentId, Vec<ActorId>>> {
+        match self {
+            MetadataManager::V1(mgr) => {
+                mgr.fragment_manager
+                    .update_sink_rate_limit_by_sink_id(sink_id, rate_limit)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                let fragment_actors = mgr
+                    .catalog_controller
+                    .update_sink_rate_limit_by_sink_id(sink_id as _, rate_limit)
+                    .await?;
+                Ok(fragment_actors
+                    .into_iter()
+                    .map(|(id, actors)| (id as _, actors.into_iter().map(|id| id as _).collect()))
+                    .collect())
+            }
+        }
+    }
+
+    /// Collect the persisted rate limits of the job's source and backfill actors, for
+    /// pausing and resuming a single streaming job.
+    pub async fn get_mv_rate_limit_by_table_id(
+        &self,
+        table_id: TableId,
+    ) -> MetaResult<ThrottleConfig> {
+        match self {
+            MetadataManager::V1(mgr) => {
+                mgr.fragment_manager
+                    .get_mv_rate_limit_by_table_id(table_id)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                let fragment_rate_limits = mgr
+                    .catalog_controller
+                    .get_mv_rate_limit_by_job_id(table_id.table_id as _)
+                    .await?;
+                Ok(fragment_rate_limits
+                    .into_iter()
+                    .map(|(id, actors)| {
+                        (
+                            id as _,
+                            actors
+                                .into_iter()
+                                .map(|(id, rate_limit)| (id as _, rate_limit))
+                                .collect(),
+                        )
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Whether zeroing the job's rate limits actually freezes it, i.e. the job is still
+    /// backfilling or reads exclusively from sources. See the per-backend implementations
+    /// for details.
+    pub async fn is_job_pausable_by_rate_limit(&self, table_id: TableId) -> MetaResult<bool> {
+        match self {
+            MetadataManager::V1(mgr) => {
+                mgr.fragment_manager
+                    .is_job_pausable_by_rate_limit(table_id)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .is_job_pausable_by_rate_limit(table_id.table_id as _)
+                    .await
+            }
+        }
+    }
+
+    /// Returns the ids of user tables/materialized views and sinks that carry all the
+    /// labels in `selector`.
+    pub async fn list_streaming_job_ids_by_label(
+        &self,
+        selector: &HashMap<String, String>,
+    ) -> MetaResult<(Vec<u32>, Vec<u32>)> {
+        match self {
+            MetadataManager::V1(mgr) => Ok(mgr
+                .catalog_manager
+                .list_streaming_job_ids_by_label(selector)
+                .await),
+            MetadataManager::V2(mgr) => {
+                let (table_ids, sink_ids) = mgr
+                    .catalog_controller
+                    .list_streaming_job_ids_by_label(selector)
+                    .await?;
+                Ok((
+                    table_ids.into_iter().map(|id| id as _).collect(),
+                    sink_ids.into_iter().map(|id| id as _).collect(),
+                ))
+            }
+        }
+    }
+
     pub async fn update_actor_splits_by_split_assignment(
         &self,
         split_assignment: &SplitAssignment,
@@ -926,6 +1126,21 @@ impl MetadataManager {
             }
         }
     }
+
+    /// Returns the number of active DDL waiters per creating job, excluding waiters whose
+    /// frontend has disconnected.
+    pub async fn count_ddl_waiters(&self) -> HashMap<u32, usize> {
+        match self {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.count_ddl_waiters().await,
+            MetadataManager::V2(mgr) => mgr
+                .catalog_controller
+                .count_ddl_waiters()
+                .await
+                .into_iter()
+                .map(|(id, count)| (id as u32, count))
+                .collect(),
+        }
+    }
 }
 
 impl MetadataManagerV2 {
@@ -971,3 +1186,91 @@ impl MetadataManagerV1 {
         mgr.notify_finish_failed(err);
     }
 }
+
+/// Resolve the retention policy of a subscription to an effective time-based retention
+/// in seconds, so that the barrier manager and the hummock GC only ever deal with a
+/// single representation. `checkpoint_interval_ms` is the expected wall-clock time
+/// between two checkpointed epochs.
+pub fn effective_subscription_retention_seconds(
+    subscription: &PbSubscription,
+    checkpoint_interval_ms: u64,
+) -> u64 {
+    match subscription.retention_policy() {
+        PbRetentionPolicy::Unspecified | PbRetentionPolicy::Time => subscription.retention_seconds,
+        PbRetentionPolicy::EpochCount => subscription
+            .retention_epoch_count
+            .saturating_mul(checkpoint_interval_ms)
+            .div_ceil(1000)
+            .min(subscription.retention_seconds),
+        PbRetentionPolicy::ConsumerAck => {
+            if subscription.acked_epoch == 0 {
+                // Nothing has been acknowledged yet, fall back to the time-based cap.
+                subscription.retention_seconds
+            } else {
+                // Everything at or below the acknowledged epoch can be truncated early;
+                // the time-based retention still caps how long unacknowledged log data
+                // is kept around.
+                let acked_ms = Epoch::from(subscription.acked_epoch).physical_time();
+                let elapsed_seconds = Epoch::physical_now().saturating_sub(acked_ms) / 1000;
+                elapsed_seconds.min(subscription.retention_seconds)
+            }
+        }
+    }
+}
+
+/// Checks a `GRANT` against the dangerous-grant guard rails.
+///
+/// Returns `Ok(None)` if the grant is not dangerous or the check is disabled, and
+/// `Ok(Some(warning))` if it is dangerous but the grantor is on
+/// `dangerous_grant_allowlist` — the caller is expected to record the warning in the
+/// DDL audit log. A dangerous grant by a non-allowlisted user is rejected.
+pub fn check_dangerous_grant(
+    opts: &MetaOpts,
+    grantor_name: &str,
+    target_user_count: usize,
+    grantable_user_count: usize,
+    new_grant_privileges: &[PbGrantPrivilege],
+) -> MetaResult<Option<String>> {
+    if !opts.enable_dangerous_grant_check {
+        return Ok(None);
+    }
+
+    let mut warning = None;
+    if target_user_count > 1 && target_user_count >= grantable_user_count {
+        warning = Some("the grant targets every user of the cluster".to_string());
+    } else if let Some(privilege) = new_grant_privileges.iter().find(|privilege| {
+        let wide_object = matches!(
+            privilege.object,
+            Some(PbGrantObject::DatabaseId(_))
+                | Some(PbGrantObject::AllTablesSchemaId(_))
+                | Some(PbGrantObject::AllSourcesSchemaId(_))
+                | Some(PbGrantObject::AllDmlRelationsSchemaId(_))
+        );
+        wide_object
+            && !privilege.action_with_opts.is_empty()
+            && privilege
+                .action_with_opts
+                .iter()
+                .all(|action| action.with_grant_option)
+    }) {
+        warning = Some(format!(
+            "the grant is superuser-equivalent: every granted action on {:?} carries the grant option",
+            privilege.object.as_ref().unwrap()
+        ));
+    }
+
+    let Some(warning) = warning else {
+        return Ok(None);
+    };
+    if opts
+        .dangerous_grant_allowlist
+        .iter()
+        .any(|name| name == grantor_name)
+    {
+        return Ok(Some(warning));
+    }
+    Err(MetaError::permission_denied(format!(
+        "dangerous grant blocked: {}. Add user {} to `dangerous_grant_allowlist` in the meta configuration to allow it",
+        warning, grantor_name,
+    )))
+}
@@ -13,29 +13,51 @@
 // limitations under the License.
 
 mod catalog;
+mod catalog_delta;
 mod cluster;
+mod connection_health;
+mod consistency_fence;
+mod ddl_audit;
 pub mod diagnose;
 mod env;
 pub mod event_log;
 mod id;
 mod idle;
+mod liveness;
+mod maintenance;
 mod metadata;
+mod named_checkpoint;
 mod notification;
+mod notification_backlog;
 mod notification_version;
+mod rate_limit_boost;
 mod session_params;
 pub mod sink_coordination;
 mod streaming_job;
 mod system_param;
+mod table_change;
+mod usage_report;
 
 pub use catalog::*;
+pub use catalog_delta::*;
 pub use cluster::{WorkerKey, *};
+pub use connection_health::*;
+pub use consistency_fence::*;
+pub use ddl_audit::DdlAuditManager;
 pub use env::{MetaSrvEnv, *};
 pub use event_log::EventLogManagerRef;
 pub use id::*;
 pub use idle::*;
+pub use liveness::*;
+pub use maintenance::*;
 pub use metadata::*;
+pub use named_checkpoint::*;
 pub use notification::{LocalNotification, MessageStatus, NotificationManagerRef, *};
+pub use notification_backlog::*;
+pub use rate_limit_boost::*;
 pub use risingwave_meta_model_v2::prelude;
 pub use session_params::*;
 pub use streaming_job::*;
 pub use system_param::*;
+pub use table_change::*;
+pub use usage_report::{start_usage_report_sampler, UsageReportManager, UsageReportManagerRef};
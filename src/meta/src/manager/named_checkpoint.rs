@@ -0,0 +1,116 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use risingwave_hummock_sdk::HummockVersionId;
+use risingwave_pb::meta::NamedCheckpoint;
+
+use crate::barrier::BarrierScheduler;
+use crate::hummock::{HummockManagerRef, HummockVersionSafePoint};
+use crate::manager::MetaSrvEnv;
+use crate::model::MetadataModel;
+use crate::{MetaError, MetaResult};
+
+pub type NamedCheckpointManagerRef = Arc<NamedCheckpointManager>;
+
+/// Manages savepoint-style manual named checkpoints.
+///
+/// Creating a named checkpoint forces a checkpoint barrier and durably records the
+/// resulting committed epoch together with the hummock version id it produced, so that
+/// the version can later be referenced for backup or time-travel queries. Pinning a
+/// named checkpoint registers a hummock version safe point at its version to keep it
+/// from being vacuumed; pins are held in memory only and do not survive meta node
+/// restarts.
+pub struct NamedCheckpointManager {
+    env: MetaSrvEnv,
+    hummock_manager: HummockManagerRef,
+    barrier_scheduler: BarrierScheduler,
+    /// Safe points held for pinned checkpoints, keyed by checkpoint name.
+    safe_points: Mutex<HashMap<String, HummockVersionSafePoint>>,
+}
+
+impl NamedCheckpointManager {
+    pub fn new(
+        env: MetaSrvEnv,
+        hummock_manager: HummockManagerRef,
+        barrier_scheduler: BarrierScheduler,
+    ) -> Self {
+        Self {
+            env,
+            hummock_manager,
+            barrier_scheduler,
+            safe_points: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Forces a checkpoint barrier and records the resulting epoch and hummock version
+    /// id under the given name.
+    pub async fn create_checkpoint(&self, name: String) -> MetaResult<NamedCheckpoint> {
+        if name.is_empty() {
+            return Err(MetaError::invalid_parameter("checkpoint name is empty"));
+        }
+        let meta_store = self.env.meta_store_ref().as_kv();
+        if NamedCheckpoint::select(meta_store, &name).await?.is_some() {
+            return Err(MetaError::invalid_parameter(format!(
+                "checkpoint {} already exists",
+                name
+            )));
+        }
+
+        let snapshot = self.barrier_scheduler.flush(true).await?;
+        let version_id = self.hummock_manager.get_current_version().await.id;
+        let checkpoint = NamedCheckpoint {
+            name,
+            epoch: snapshot.committed_epoch,
+            hummock_version_id: version_id.to_u64(),
+            pinned: false,
+        };
+        checkpoint.insert(meta_store).await?;
+        Ok(checkpoint)
+    }
+
+    /// Lists all named checkpoints, with the `pinned` flag reflecting whether a safe
+    /// point is currently held.
+    pub async fn list_checkpoints(&self) -> MetaResult<Vec<NamedCheckpoint>> {
+        let mut checkpoints = NamedCheckpoint::list(self.env.meta_store_ref().as_kv()).await?;
+        let safe_points = self.safe_points.lock();
+        for checkpoint in &mut checkpoints {
+            checkpoint.pinned = safe_points.contains_key(&checkpoint.name);
+        }
+        Ok(checkpoints)
+    }
+
+    /// Pins the named checkpoint by registering a hummock version safe point at its
+    /// version. Idempotent for an already pinned checkpoint.
+    pub async fn pin_checkpoint(&self, name: &str) -> MetaResult<()> {
+        let checkpoint =
+            NamedCheckpoint::select(self.env.meta_store_ref().as_kv(), &name.to_string())
+                .await?
+                .ok_or_else(|| {
+                    MetaError::invalid_parameter(format!("checkpoint {} not found", name))
+                })?;
+        if self.safe_points.lock().contains_key(name) {
+            return Ok(());
+        }
+        let safe_point = self
+            .hummock_manager
+            .register_safe_point_at(HummockVersionId::new(checkpoint.hummock_version_id))
+            .await;
+        self.safe_points.lock().insert(name.to_string(), safe_point);
+        Ok(())
+    }
+}
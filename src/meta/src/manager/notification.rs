@@ -20,15 +20,19 @@ use risingwave_common::system_param::reader::SystemParamsReader;
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::meta::relation::RelationInfo;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
+use risingwave_pb::catalog::{PbSource, PbTable};
 use risingwave_pb::meta::{
-    MetaSnapshot, Relation, RelationGroup, SubscribeResponse, SubscribeType,
+    CatchUp, MetaSnapshot, Relation, RelationDiff, RelationDiffGroup, RelationGroup,
+    SubscribeResponse, SubscribeType,
 };
 use thiserror_ext::AsReport;
 use tokio::sync::mpsc::{self, UnboundedSender};
 use tokio::sync::Mutex;
 use tonic::Status;
 
+use crate::manager::catalog_delta::CatalogDeltaLog;
 use crate::manager::cluster::WorkerKey;
+use crate::manager::notification_backlog::NotificationBacklog;
 use crate::manager::notification_version::NotificationVersionGenerator;
 use crate::manager::MetaStoreImpl;
 use crate::model::FragmentId;
@@ -45,6 +49,7 @@ pub enum LocalNotification {
     WorkerNodeDeleted(WorkerNode),
     WorkerNodeActivated(WorkerNode),
     SystemParamsChange(SystemParamsReader),
+    DatabaseBarrierIntervalChange,
     FragmentMappingsUpsert(Vec<FragmentId>),
     FragmentMappingsDelete(Vec<FragmentId>),
     AdhocRecovery,
@@ -72,6 +77,45 @@ struct Task {
     operation: Operation,
     info: Info,
     version: Option<NotificationVersion>,
+    /// The database the info is scoped to and its version within that database, if the
+    /// notification is database-scoped.
+    database_version: Option<(u32, NotificationVersion)>,
+}
+
+/// The database an info is scoped to, if any. Used to maintain per-database notification
+/// version domains, so that frontends can track only the databases they use.
+fn database_id_of_info(info: &Info) -> Option<u32> {
+    match info {
+        Info::Database(database) => Some(database.id),
+        Info::Schema(schema) => Some(schema.database_id),
+        Info::Function(function) => Some(function.database_id),
+        Info::Connection(connection) => Some(connection.database_id),
+        Info::Secret(secret) => Some(secret.database_id),
+        Info::RelationGroup(RelationGroup { relations }) => relations
+            .first()
+            .and_then(|relation| relation.relation_info.as_ref())
+            .map(|info| match info {
+                RelationInfo::Table(table) => table.database_id,
+                RelationInfo::Source(source) => source.database_id,
+                RelationInfo::Sink(sink) => sink.database_id,
+                RelationInfo::Index(index) => index.database_id,
+                RelationInfo::View(view) => view.database_id,
+                RelationInfo::Subscription(subscription) => subscription.database_id,
+            }),
+        Info::RelationDiffGroup(RelationDiffGroup { diffs }) => diffs
+            .first()
+            .and_then(|diff| diff.relation.as_ref())
+            .and_then(|relation| relation.relation_info.as_ref())
+            .map(|info| match info {
+                RelationInfo::Table(table) => table.database_id,
+                RelationInfo::Source(source) => source.database_id,
+                RelationInfo::Sink(sink) => sink.database_id,
+                RelationInfo::Index(index) => index.database_id,
+                RelationInfo::View(view) => view.database_id,
+                RelationInfo::Subscription(subscription) => subscription.database_id,
+            }),
+        _ => None,
+    }
 }
 
 /// [`NotificationManager`] is used to send notification to frontends and compute nodes.
@@ -81,6 +125,16 @@ pub struct NotificationManager {
     task_tx: UnboundedSender<Task>,
     /// The current notification version generator.
     version_generator: Mutex<NotificationVersionGenerator>,
+    /// In-memory per-database version counters, advanced alongside the global version for
+    /// database-scoped notifications. Not persisted: they restart from 0 on meta failover,
+    /// which is fine since frontends resubscribe with a fresh snapshot.
+    database_versions: Mutex<HashMap<u32, NotificationVersion>>,
+    /// Per-version `pg_catalog` row deltas derived from frontend catalog notifications,
+    /// served to lightweight clients via `GetCatalogDeltas`.
+    catalog_deltas: Arc<CatalogDeltaLog>,
+    /// Bounded backlog of recent broadcasts per subscriber class, used to serve
+    /// incremental catch-up to reconnecting subscribers.
+    backlog: Arc<NotificationBacklog>,
 }
 
 impl NotificationManager {
@@ -89,19 +143,51 @@ impl NotificationManager {
         let (task_tx, mut task_rx) = mpsc::unbounded_channel::<Task>();
         let core = Arc::new(Mutex::new(NotificationManagerCore::new()));
         let core_clone = core.clone();
-        let version_generator = NotificationVersionGenerator::new(meta_store_impl)
+        let version_generator = NotificationVersionGenerator::new(meta_store_impl.clone())
             .await
             .unwrap();
 
+        let catalog_deltas = Arc::new(CatalogDeltaLog::default());
+        let catalog_deltas_clone = catalog_deltas.clone();
+
+        let backlog = Arc::new(
+            NotificationBacklog::load(&meta_store_impl, version_generator.current_version()).await,
+        );
+        let backlog_clone = backlog.clone();
+
         tokio::spawn(async move {
             while let Some(task) = task_rx.recv().await {
+                // Catalog notifications broadcast to frontends are the authoritative
+                // record of catalog changes, so derive the system view deltas here.
+                if task.target.subscribe_type == SubscribeType::Frontend
+                    && task.target.worker_key.is_none()
+                    && let Some(version) = task.version
+                {
+                    catalog_deltas_clone
+                        .record(version, task.operation, &task.info)
+                        .await;
+                }
                 let response = SubscribeResponse {
                     status: None,
                     operation: task.operation as i32,
                     info: Some(task.info),
                     version: task.version.unwrap_or_default(),
+                    database_id: task.database_version.map(|(database_id, _)| database_id),
+                    database_version: task
+                        .database_version
+                        .map(|(_, version)| version)
+                        .unwrap_or_default(),
                 };
-                core.lock().await.notify(task.target, response);
+                let mut core_guard = core.lock().await;
+                // Recording a broadcast and dispatching it under the same core lock
+                // keeps the backlog atomic with respect to sender registration, so
+                // catch-up neither loses nor duplicates notifications.
+                if task.target.worker_key.is_none() {
+                    backlog_clone
+                        .record(task.target.subscribe_type, &response)
+                        .await;
+                }
+                core_guard.notify(task.target, response);
             }
         });
 
@@ -109,9 +195,16 @@ impl NotificationManager {
             core: core_clone,
             task_tx,
             version_generator: Mutex::new(version_generator),
+            database_versions: Mutex::new(HashMap::new()),
+            catalog_deltas,
+            backlog,
         }
     }
 
+    pub fn catalog_delta_log(&self) -> &CatalogDeltaLog {
+        &self.catalog_deltas
+    }
+
     pub async fn abort_all(&self) {
         let mut guard = self.core.lock().await;
         *guard = NotificationManagerCore::new();
@@ -125,17 +218,22 @@ impl NotificationManager {
         operation: Operation,
         info: Info,
         version: Option<NotificationVersion>,
+        database_version: Option<(u32, NotificationVersion)>,
     ) {
         let task = Task {
             target,
             operation,
             info,
             version,
+            database_version,
         };
         self.task_tx.send(task).unwrap();
     }
 
-    /// Add a notification to the waiting queue and increase notification version.
+    /// Add a notification to the waiting queue and increase notification version. For
+    /// database-scoped infos, the version counter of that database is advanced as well,
+    /// while the global version is still held for the whole assignment to keep both
+    /// domains consistently ordered.
     async fn notify_with_version(
         &self,
         target: Target,
@@ -145,14 +243,23 @@ impl NotificationManager {
         let mut version_guard = self.version_generator.lock().await;
         version_guard.increase_version().await;
         let version = version_guard.current_version();
-        self.notify(target, operation, info, Some(version));
+        let database_version = match database_id_of_info(&info) {
+            Some(database_id) => {
+                let mut database_versions = self.database_versions.lock().await;
+                let version = database_versions.entry(database_id).or_default();
+                *version += 1;
+                Some((database_id, *version))
+            }
+            None => None,
+        };
+        self.notify(target, operation, info, Some(version), database_version);
         version
     }
 
     /// Add a notification to the waiting queue and return immediately
     #[inline(always)]
     fn notify_without_version(&self, target: Target, operation: Operation, info: Info) {
-        self.notify(target, operation, info, None);
+        self.notify(target, operation, info, None, None);
     }
 
     pub fn notify_snapshot(
@@ -193,6 +300,25 @@ impl NotificationManager {
         .await
     }
 
+    /// Notifies frontends of an updated relation, sending a field-mask diff instead of
+    /// the whole proto when only maskable fields changed. See [`diff_relation_info`].
+    pub async fn notify_frontend_relation_update(
+        &self,
+        old_relation_info: &RelationInfo,
+        new_relation_info: RelationInfo,
+    ) -> NotificationVersion {
+        let info = match diff_relation_info(old_relation_info, &new_relation_info) {
+            Some(diff) => Info::RelationDiffGroup(RelationDiffGroup { diffs: vec![diff] }),
+            None => Info::RelationGroup(RelationGroup {
+                relations: vec![Relation {
+                    relation_info: new_relation_info.into(),
+                }],
+            }),
+        };
+        self.notify_with_version(SubscribeType::Frontend.into(), Operation::Update, info)
+            .await
+    }
+
     pub async fn notify_hummock(&self, operation: Operation, info: Info) -> NotificationVersion {
         self.notify_with_version(SubscribeType::Hummock.into(), operation, info)
             .await
@@ -265,7 +391,7 @@ impl NotificationManager {
         info: Info,
         version: Option<NotificationVersion>,
     ) {
-        self.notify(SubscribeType::Hummock.into(), operation, info, version)
+        self.notify(SubscribeType::Hummock.into(), operation, info, version, None)
     }
 
     pub async fn notify_local_subscribers(&self, notification: LocalNotification) {
@@ -311,6 +437,46 @@ impl NotificationManager {
         senders.insert(worker_key, sender);
     }
 
+    /// Inserts the sender after replaying the broadcasts it missed since
+    /// `last_acked_version`, prefixed with a [`CatchUp`] marker. Returns `false`
+    /// without inserting the sender if the backlog no longer covers that version,
+    /// in which case the caller should fall back to a full snapshot subscription.
+    pub async fn insert_sender_with_catch_up(
+        &self,
+        subscribe_type: SubscribeType,
+        worker_key: WorkerKey,
+        sender: UnboundedSender<Notification>,
+        last_acked_version: NotificationVersion,
+    ) -> bool {
+        // The core lock is held across the replay: broadcasts dispatched before this
+        // point are in the backlog, while later ones will find the sender registered
+        // below. See the dispatch loop in `new`.
+        let mut core_guard = self.core.lock().await;
+        if core_guard.exiting {
+            tracing::warn!("notification manager exiting.");
+            return false;
+        }
+        let Some(responses) = self.backlog.replay(subscribe_type, last_acked_version).await else {
+            return false;
+        };
+
+        let catch_up = SubscribeResponse {
+            status: None,
+            operation: Operation::Unspecified as i32,
+            info: Some(Info::CatchUp(CatchUp {})),
+            version: 0,
+            database_id: None,
+            database_version: 0,
+        };
+        for response in std::iter::once(catch_up).chain(responses) {
+            if sender.send(Ok(response)).is_err() {
+                return false;
+            }
+        }
+        core_guard.senders_of(subscribe_type).insert(worker_key, sender);
+        true
+    }
+
     pub async fn insert_local_sender(&self, sender: UnboundedSender<LocalNotification>) {
         let mut core_guard = self.core.lock().await;
         if core_guard.exiting {
@@ -329,6 +495,13 @@ impl NotificationManager {
         let version_guard = self.version_generator.lock().await;
         version_guard.current_version()
     }
+
+    /// The current version within the given database's own version domain. Returns 0 if no
+    /// database-scoped notification has been sent for it since this meta node started.
+    pub async fn current_database_version(&self, database_id: u32) -> NotificationVersion {
+        let database_versions = self.database_versions.lock().await;
+        database_versions.get(&database_id).copied().unwrap_or(0)
+    }
 }
 
 type SenderMap = HashMap<WorkerKey, UnboundedSender<Notification>>;
@@ -408,6 +581,95 @@ impl NotificationManagerCore {
     }
 }
 
+/// Computes a field-mask diff between the old and new version of a relation, to spare
+/// frontends from re-deserializing the whole proto (e.g. all columns of a wide table)
+/// when only cheap top-level fields changed.
+///
+/// Only a curated set of fields is maskable; returns `None` when any other field
+/// changed (or the relation type carries no maskable fields), in which case the caller
+/// must fall back to a full `RelationGroup` notification. Frontends that do not
+/// understand a field name in the mask recover by re-subscribing for a full snapshot,
+/// so extending the maskable set is upgrade-safe, if noisy during a rolling upgrade.
+#[allow(clippy::clone_on_copy)] // the macro below clones `Copy` fields as well
+fn diff_relation_info(old: &RelationInfo, new: &RelationInfo) -> Option<RelationDiff> {
+    /// For each maskable field: record it in the mask and the partial proto if it
+    /// changed, and align it on the scratch copy so that the final equality check only
+    /// sees non-maskable fields.
+    macro_rules! diff_fields {
+        ($old:ident, $new:ident, $partial:ident, $rest:ident, $updated_fields:ident, [$($field:ident),* $(,)?]) => {
+            $(
+                if $old.$field != $new.$field {
+                    $partial.$field = $new.$field.clone();
+                    $updated_fields.push(stringify!($field).to_string());
+                }
+                $rest.$field = $new.$field.clone();
+            )*
+        };
+    }
+
+    let (relation_info, updated_fields) = match (old, new) {
+        (RelationInfo::Table(old), RelationInfo::Table(new)) => {
+            let mut updated_fields = Vec::new();
+            let mut partial = PbTable {
+                id: new.id,
+                schema_id: new.schema_id,
+                database_id: new.database_id,
+                // Carried even when unchanged, so that `pg_class` row deltas can be
+                // derived from the diff alone.
+                name: new.name.clone(),
+                owner: new.owner,
+                table_type: new.table_type,
+                ..Default::default()
+            };
+            let mut rest = old.clone();
+            diff_fields!(
+                old,
+                new,
+                partial,
+                rest,
+                updated_fields,
+                [name, owner, definition, description, annotations, labels]
+            );
+            if rest != *new {
+                return None;
+            }
+            (RelationInfo::Table(partial), updated_fields)
+        }
+        (RelationInfo::Source(old), RelationInfo::Source(new)) => {
+            let mut updated_fields = Vec::new();
+            let mut partial = PbSource {
+                id: new.id,
+                schema_id: new.schema_id,
+                database_id: new.database_id,
+                name: new.name.clone(),
+                owner: new.owner,
+                ..Default::default()
+            };
+            let mut rest = old.clone();
+            diff_fields!(
+                old,
+                new,
+                partial,
+                rest,
+                updated_fields,
+                [name, owner, definition, rate_limit]
+            );
+            if rest != *new {
+                return None;
+            }
+            (RelationInfo::Source(partial), updated_fields)
+        }
+        _ => return None,
+    };
+
+    Some(RelationDiff {
+        relation: Some(Relation {
+            relation_info: Some(relation_info),
+        }),
+        updated_fields,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_pb::common::HostAddress;
@@ -450,4 +712,38 @@ mod tests {
         assert!(rx2.recv().await.is_some());
         assert!(rx3.recv().await.is_some());
     }
+
+    #[test]
+    fn test_diff_relation_info() {
+        let old = PbTable {
+            id: 1,
+            name: "t".to_string(),
+            columns: vec![Default::default()],
+            ..Default::default()
+        };
+
+        // Only maskable fields changed: a diff carrying the field mask is produced.
+        let mut new = old.clone();
+        new.name = "t2".to_string();
+        new.owner = 42;
+        let diff = diff_relation_info(
+            &RelationInfo::Table(old.clone()),
+            &RelationInfo::Table(new),
+        )
+        .unwrap();
+        assert_eq!(diff.updated_fields, vec!["name", "owner"]);
+        let Some(RelationInfo::Table(partial)) = diff.relation.unwrap().relation_info else {
+            panic!("expected a table diff");
+        };
+        assert_eq!(partial.name, "t2");
+        assert_eq!(partial.owner, 42);
+        assert!(partial.columns.is_empty());
+
+        // A non-maskable field changed: fall back to a full notification.
+        let mut new = old.clone();
+        new.columns.push(Default::default());
+        assert!(
+            diff_relation_info(&RelationInfo::Table(old), &RelationInfo::Table(new)).is_none()
+        );
+    }
 }
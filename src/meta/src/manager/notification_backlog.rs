@@ -0,0 +1,269 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashMap};
+
+use risingwave_pb::meta::{NotificationBacklogEntry, SubscribeResponse, SubscribeType};
+use thiserror_ext::AsReport;
+use tokio::sync::Mutex;
+
+use crate::manager::notification::NotificationVersion;
+use crate::manager::MetaStoreImpl;
+use crate::model::MetadataModel;
+use crate::storage::MetaStoreRef;
+
+/// Maximum number of broadcasts retained per subscriber class. Subscribers falling
+/// further behind fall back to a full snapshot re-subscription.
+const BACKLOG_CAPACITY_PER_CLASS: usize = 1024;
+
+/// A bounded backlog of the most recent broadcast notifications per subscriber class,
+/// used to serve incremental catch-up to subscribers reconnecting within the retention
+/// window instead of forcing a full snapshot re-subscription.
+///
+/// Under the kv meta backend the backlog is also persisted, so that catch-up keeps
+/// working across a meta node failover; under the SQL meta backend it is in-memory
+/// only and failover falls back to snapshots.
+///
+/// Versioned notifications are replayed exactly: a subscriber that acked version `v`
+/// within the window receives every versioned broadcast after `v`, in order.
+/// Unversioned notifications (e.g. hummock stats, serving mappings) are replayed
+/// best-effort, matching their at-most-once semantics on a live channel.
+pub struct NotificationBacklog {
+    /// The kv meta store used to persist entries, or `None` under the SQL backend.
+    meta_store: Option<MetaStoreRef>,
+    state: Mutex<HashMap<SubscribeType, ClassBacklog>>,
+}
+
+struct ClassBacklog {
+    /// Retained broadcasts keyed by sequence number, each with its version watermark:
+    /// the version of the most recent versioned broadcast at or before it.
+    entries: BTreeMap<u64, (NotificationVersion, SubscribeResponse)>,
+    /// Sequence number of the next broadcast to record.
+    next_seq: u64,
+    /// Watermark inherited by unversioned broadcasts.
+    last_watermark: NotificationVersion,
+    /// The oldest acked version the backlog can still serve: for any
+    /// `last_acked >= complete_from`, every versioned broadcast after `last_acked`
+    /// is retained. The versioned broadcast carrying `complete_from` itself is
+    /// never retained.
+    complete_from: NotificationVersion,
+}
+
+impl ClassBacklog {
+    fn new(current_version: NotificationVersion) -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            next_seq: 0,
+            last_watermark: current_version,
+            complete_from: current_version,
+        }
+    }
+}
+
+impl NotificationBacklog {
+    /// Creates the backlog, restoring persisted entries under the kv meta backend.
+    /// `current_version` is the current global notification version: broadcasts sent
+    /// before this meta node started are only replayable if restored from the store.
+    pub async fn load(
+        meta_store_impl: &MetaStoreImpl,
+        current_version: NotificationVersion,
+    ) -> Self {
+        let meta_store = match meta_store_impl {
+            MetaStoreImpl::Kv(meta_store) => Some(meta_store.clone()),
+            MetaStoreImpl::Sql(_) => None,
+        };
+
+        let mut persisted: HashMap<i32, Vec<NotificationBacklogEntry>> = HashMap::new();
+        if let Some(meta_store) = &meta_store {
+            match NotificationBacklogEntry::list(meta_store).await {
+                Ok(entries) => {
+                    for entry in entries {
+                        persisted
+                            .entry(entry.subscribe_type)
+                            .or_default()
+                            .push(entry);
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e.as_report(),
+                        "failed to restore notification backlog, starting empty"
+                    );
+                }
+            }
+        }
+
+        let mut state = HashMap::new();
+        for subscribe_type in [
+            SubscribeType::Frontend,
+            SubscribeType::Hummock,
+            SubscribeType::Compactor,
+            SubscribeType::Compute,
+        ] {
+            let mut class = ClassBacklog::new(current_version);
+            if let Some(mut entries) = persisted.remove(&(subscribe_type as i32)) {
+                entries.sort_unstable_by_key(|entry| entry.seq);
+                class.next_seq = entries.last().map(|entry| entry.seq + 1).unwrap_or(0);
+                // Only the gap-free suffix of the persisted stream is usable: a gap
+                // (e.g. from a failed write) means broadcasts in between are lost.
+                let mut suffix_start = entries.len();
+                while suffix_start > 0
+                    && (suffix_start == entries.len()
+                        || entries[suffix_start - 1].seq + 1 == entries[suffix_start].seq)
+                {
+                    suffix_start -= 1;
+                }
+                // Replay is only exact from a versioned broadcast onwards, so also
+                // drop the suffix's prefix up to and including the first versioned
+                // entry, which defines the coverage floor.
+                let first_versioned = entries[suffix_start..].iter().position(|entry| {
+                    entry
+                        .response
+                        .as_ref()
+                        .is_some_and(|response| response.version > 0)
+                });
+                let retain_from = match first_versioned {
+                    Some(offset) => {
+                        class.complete_from = entries[suffix_start + offset]
+                            .response
+                            .as_ref()
+                            .unwrap()
+                            .version;
+                        suffix_start + offset + 1
+                    }
+                    None => entries.len(),
+                };
+                for entry in entries.drain(retain_from..) {
+                    let Some(response) = entry.response else {
+                        continue;
+                    };
+                    class
+                        .entries
+                        .insert(entry.seq, (entry.version_watermark, response));
+                }
+                class.last_watermark = class
+                    .entries
+                    .values()
+                    .last()
+                    .map(|(watermark, _)| *watermark)
+                    .unwrap_or(class.complete_from);
+                // Clean up the dropped entries so they are not restored again.
+                if let Some(meta_store) = &meta_store {
+                    for entry in entries {
+                        let _ = Self::delete_persisted(meta_store, &entry).await;
+                    }
+                }
+            }
+            state.insert(subscribe_type, class);
+        }
+
+        Self {
+            meta_store,
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Records a broadcast into the class's backlog, evicting the oldest entry when
+    /// the capacity is exceeded. Persistence is best-effort: a failed write only
+    /// shrinks the catch-up window after a failover.
+    pub async fn record(&self, subscribe_type: SubscribeType, response: &SubscribeResponse) {
+        let mut state = self.state.lock().await;
+        let class = state
+            .get_mut(&subscribe_type)
+            .expect("backlog classes are initialized eagerly");
+
+        let watermark = if response.version > 0 {
+            response.version
+        } else {
+            class.last_watermark
+        };
+        let seq = class.next_seq;
+        class.next_seq += 1;
+        class.last_watermark = watermark;
+        class.entries.insert(seq, (watermark, response.clone()));
+
+        let entry = NotificationBacklogEntry {
+            subscribe_type: subscribe_type as i32,
+            seq,
+            version_watermark: watermark,
+            response: Some(response.clone()),
+        };
+        if let Some(meta_store) = &self.meta_store {
+            if let Err(e) = entry.insert(meta_store).await {
+                tracing::warn!(
+                    error = %e.as_report(),
+                    ?subscribe_type,
+                    seq,
+                    "failed to persist notification backlog entry"
+                );
+            }
+        }
+
+        while class.entries.len() > BACKLOG_CAPACITY_PER_CLASS {
+            let (seq, (watermark, response)) = class.entries.pop_first().unwrap();
+            class.complete_from = class.complete_from.max(watermark);
+            if let Some(meta_store) = &self.meta_store {
+                let evicted = NotificationBacklogEntry {
+                    subscribe_type: subscribe_type as i32,
+                    seq,
+                    version_watermark: watermark,
+                    response: Some(response),
+                };
+                let _ = Self::delete_persisted(meta_store, &evicted).await;
+            }
+        }
+    }
+
+    /// Returns the broadcasts the subscriber has missed since `last_acked`, in order,
+    /// or `None` if the backlog no longer covers that version and the subscriber must
+    /// fall back to a full snapshot.
+    pub async fn replay(
+        &self,
+        subscribe_type: SubscribeType,
+        last_acked: NotificationVersion,
+    ) -> Option<Vec<SubscribeResponse>> {
+        let state = self.state.lock().await;
+        let class = state.get(&subscribe_type)?;
+
+        let replay_after_seq = if last_acked == class.complete_from {
+            None
+        } else if last_acked < class.complete_from {
+            return None;
+        } else {
+            // Every versioned broadcast within the window is retained, so a valid
+            // `last_acked` above the floor must match one of them.
+            let (seq, _) = class
+                .entries
+                .iter()
+                .find(|(_, (_, response))| response.version == last_acked)?;
+            Some(*seq)
+        };
+
+        Some(
+            class
+                .entries
+                .range(replay_after_seq.map(|seq| seq + 1).unwrap_or(0)..)
+                .map(|(_, (_, response))| response.clone())
+                .collect(),
+        )
+    }
+
+    async fn delete_persisted(
+        meta_store: &MetaStoreRef,
+        entry: &NotificationBacklogEntry,
+    ) -> crate::model::MetadataModelResult<()> {
+        let key = entry.key()?;
+        NotificationBacklogEntry::delete(meta_store, &key).await
+    }
+}
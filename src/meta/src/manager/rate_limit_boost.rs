@@ -0,0 +1,178 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use risingwave_common::catalog::TableId;
+use risingwave_pb::meta::RateLimitBoost;
+use thiserror_ext::AsReport;
+use tokio::task::JoinHandle;
+
+use crate::barrier::{BarrierScheduler, Command};
+use crate::manager::{MetaSrvEnv, MetadataManager};
+use crate::model::MetadataModel;
+use crate::stream::ThrottleConfig;
+use crate::{MetaError, MetaResult};
+
+pub type RateLimitBoostManagerRef = Arc<RateLimitBoostManager>;
+
+/// Manages temporary rate limit boosts of streaming jobs.
+///
+/// Boosting a job overrides the rate limits of its source and backfill actors without
+/// touching the rate limits persisted in the catalog, and automatically restores the
+/// persisted limits once the given duration elapses. The boost and its expiry are
+/// durably recorded in the meta store, so the revert timer is rebuilt after a meta
+/// node failover and a boost can never be forgotten.
+pub struct RateLimitBoostManager {
+    env: MetaSrvEnv,
+    metadata_manager: MetadataManager,
+    barrier_scheduler: BarrierScheduler,
+    /// Revert timers of active boosts, keyed by the job's table id.
+    revert_timers: Mutex<HashMap<u32, JoinHandle<()>>>,
+}
+
+impl RateLimitBoostManager {
+    pub fn new(
+        env: MetaSrvEnv,
+        metadata_manager: MetadataManager,
+        barrier_scheduler: BarrierScheduler,
+    ) -> Self {
+        Self {
+            env,
+            metadata_manager,
+            barrier_scheduler,
+            revert_timers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Temporarily overrides the rate limits of the job's source and backfill actors
+    /// with `rate` (unset lifts the limits entirely), reverting to the rate limits
+    /// persisted in the catalog after `duration`. Boosting an already boosted job
+    /// replaces the boost and restarts the timer.
+    pub async fn boost_rate_limit(
+        self: &Arc<Self>,
+        table_id: u32,
+        rate: Option<u32>,
+        duration: Duration,
+    ) -> MetaResult<()> {
+        if duration.is_zero() {
+            return Err(MetaError::invalid_parameter("boost duration is zero"));
+        }
+        let rate_limits = self
+            .metadata_manager
+            .get_mv_rate_limit_by_table_id(TableId::from(table_id))
+            .await?;
+        if rate_limits.is_empty() {
+            return Err(MetaError::invalid_parameter(format!(
+                "stream scan node or source node not found in job id {}",
+                table_id
+            )));
+        }
+
+        // Apply the boosted rate without touching the rate limits persisted in the
+        // catalog, so that they can be restored on expiry.
+        let config: ThrottleConfig = rate_limits
+            .into_iter()
+            .map(|(fragment_id, actors)| {
+                (
+                    fragment_id,
+                    actors
+                        .into_keys()
+                        .map(|actor_id| (actor_id, rate))
+                        .collect(),
+                )
+            })
+            .collect();
+        self.barrier_scheduler
+            .run_command(Command::Throttle(config))
+            .await?;
+
+        let boost = RateLimitBoost {
+            table_id,
+            rate,
+            expires_at_ms: now_ms() + duration.as_millis() as u64,
+        };
+        boost.insert(self.env.meta_store_ref().as_kv()).await?;
+        self.schedule_revert(table_id, duration);
+        Ok(())
+    }
+
+    /// Rebuilds revert timers from the persisted boost records. Called once on meta
+    /// node startup, so that boosts survive failover; boosts that expired while the
+    /// meta node was down are reverted immediately.
+    pub async fn schedule_persisted_reverts(self: &Arc<Self>) -> MetaResult<()> {
+        if let MetadataManager::V2(_) = &self.metadata_manager {
+            // Rate limit boosts are only supported by the kv meta backend.
+            return Ok(());
+        }
+        let boosts = RateLimitBoost::list(self.env.meta_store_ref().as_kv()).await?;
+        let now = now_ms();
+        for boost in boosts {
+            let delay = Duration::from_millis(boost.expires_at_ms.saturating_sub(now));
+            self.schedule_revert(boost.table_id, delay);
+        }
+        Ok(())
+    }
+
+    /// (Re)schedules the revert of the job's boost after `delay`, aborting the
+    /// previous timer if any.
+    fn schedule_revert(self: &Arc<Self>, table_id: u32, delay: Duration) {
+        let this = self.clone();
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            if let Err(e) = this.revert(table_id).await {
+                tracing::warn!(
+                    error = %e.as_report(),
+                    table_id,
+                    "failed to revert rate limit boost, will retry on meta node restart"
+                );
+            }
+        });
+        if let Some(prev) = self.revert_timers.lock().insert(table_id, handle) {
+            prev.abort();
+        }
+    }
+
+    /// Restores the rate limits persisted in the job's catalog and deletes the boost
+    /// record. A job dropped in the meantime has nothing left to revert.
+    async fn revert(&self, table_id: u32) -> MetaResult<()> {
+        match self
+            .metadata_manager
+            .get_mv_rate_limit_by_table_id(TableId::from(table_id))
+            .await
+        {
+            Ok(config) if !config.is_empty() => {
+                self.barrier_scheduler
+                    .run_command(Command::Throttle(config))
+                    .await?;
+            }
+            Ok(_) => {}
+            Err(e) if e.is_fragment_not_found() => {}
+            Err(e) => return Err(e),
+        }
+        RateLimitBoost::delete(self.env.meta_store_ref().as_kv(), &table_id).await?;
+        self.revert_timers.lock().remove(&table_id);
+        Ok(())
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
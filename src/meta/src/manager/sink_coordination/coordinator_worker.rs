@@ -35,6 +35,7 @@ use tonic::Status;
 use tracing::{error, warn};
 
 use crate::manager::sink_coordination::handle::SinkWriterCoordinationHandle;
+use crate::manager::sink_coordination::SinkTransactionLog;
 
 async fn run_future_with_periodic_fn<F: Future>(
     future: F,
@@ -183,12 +184,14 @@ impl CoordinationHandleManager {
 pub struct CoordinatorWorker {
     handle_manager: CoordinationHandleManager,
     pending_epochs: BTreeMap<u64, EpochCommitRequests>,
+    txn_log: Option<SinkTransactionLog>,
 }
 
 impl CoordinatorWorker {
     pub async fn run(
         param: SinkParam,
         request_rx: UnboundedReceiver<SinkWriterCoordinationHandle>,
+        txn_log: Option<SinkTransactionLog>,
     ) {
         let sink = match build_sink(param.clone()) {
             Ok(sink) => sink,
@@ -213,7 +216,7 @@ impl CoordinatorWorker {
                     return;
                 }
             };
-            Self::execute_coordinator(param, request_rx, coordinator).await
+            Self::execute_coordinator(param, request_rx, coordinator, txn_log).await
         });
     }
 
@@ -221,6 +224,7 @@ impl CoordinatorWorker {
         param: SinkParam,
         request_rx: UnboundedReceiver<SinkWriterCoordinationHandle>,
         coordinator: impl SinkCommitCoordinator,
+        txn_log: Option<SinkTransactionLog>,
     ) {
         let mut worker = CoordinatorWorker {
             handle_manager: CoordinationHandleManager {
@@ -230,6 +234,7 @@ impl CoordinatorWorker {
                 request_rx,
             },
             pending_epochs: Default::default(),
+            txn_log,
         };
 
         if let Err(e) = worker.run_coordination(coordinator).await {
@@ -247,6 +252,7 @@ impl CoordinatorWorker {
         mut coordinator: impl SinkCommitCoordinator,
     ) -> anyhow::Result<()> {
         coordinator.init().await?;
+        self.resolve_pending_transactions(&mut coordinator).await?;
         loop {
             let (handle_id, vnode_bitmap, epoch, metadata) =
                 self.handle_manager.next_commit_request().await?;
@@ -262,6 +268,12 @@ impl CoordinatorWorker {
                 .can_commit()
             {
                 let (epoch, requests) = self.pending_epochs.pop_first().expect("non-empty");
+                let sink_id = self.handle_manager.param.sink_id.sink_id;
+                if let Some(txn_log) = &self.txn_log {
+                    txn_log
+                        .pre_commit(sink_id, epoch, requests.metadatas.clone())
+                        .await?;
+                }
                 // TODO: measure commit time
                 let start_time = Instant::now();
                 run_future_with_periodic_fn(
@@ -270,15 +282,44 @@ impl CoordinatorWorker {
                     || {
                         warn!(
                             elapsed = ?start_time.elapsed(),
-                            sink_id = self.handle_manager.param.sink_id.sink_id,
+                            sink_id,
                             "committing"
                         );
                     },
                 )
                 .await
                 .map_err(|e| anyhow!(e))?;
+                if let Some(txn_log) = &self.txn_log {
+                    txn_log.commit(sink_id, epoch).await?;
+                }
                 self.handle_manager.ack_commit(epoch, requests.handle_ids)?;
             }
         }
     }
+
+    /// Re-drives the transactions left pending by a previous incarnation of the
+    /// coordinator, whose commit outcome is unknown. Relies on the commit being
+    /// idempotent per epoch.
+    async fn resolve_pending_transactions(
+        &mut self,
+        coordinator: &mut impl SinkCommitCoordinator,
+    ) -> anyhow::Result<()> {
+        let Some(txn_log) = &self.txn_log else {
+            return Ok(());
+        };
+        let sink_id = self.handle_manager.param.sink_id.sink_id;
+        for txn in txn_log.pending_transactions(sink_id).await? {
+            warn!(
+                sink_id,
+                epoch = txn.epoch,
+                "re-driving pending sink transaction with unknown commit outcome"
+            );
+            coordinator
+                .commit(txn.epoch, txn.metadata)
+                .await
+                .map_err(|e| anyhow!(e))?;
+            txn_log.commit(sink_id, txn.epoch).await?;
+        }
+        Ok(())
+    }
 }
@@ -35,7 +35,7 @@ use tracing::{debug, error, info, warn};
 
 use crate::manager::sink_coordination::coordinator_worker::CoordinatorWorker;
 use crate::manager::sink_coordination::handle::SinkWriterCoordinationHandle;
-use crate::manager::sink_coordination::SinkWriterRequestStream;
+use crate::manager::sink_coordination::{SinkTransactionLog, SinkWriterRequestStream};
 
 macro_rules! send_with_err_check {
     ($tx:expr, $msg:expr) => {
@@ -70,9 +70,15 @@ pub struct SinkCoordinatorManager {
 }
 
 impl SinkCoordinatorManager {
-    pub fn start_worker() -> (Self, (JoinHandle<()>, Sender<()>)) {
-        Self::start_worker_with_spawn_worker(|param, manager_request_stream| {
-            tokio::spawn(CoordinatorWorker::run(param, manager_request_stream))
+    pub fn start_worker(
+        txn_log: Option<SinkTransactionLog>,
+    ) -> (Self, (JoinHandle<()>, Sender<()>)) {
+        Self::start_worker_with_spawn_worker(move |param, manager_request_stream| {
+            tokio::spawn(CoordinatorWorker::run(
+                param,
+                manager_request_stream,
+                txn_log.clone(),
+            ))
         })
     }
 
@@ -467,6 +473,7 @@ mod tests {
                                 }
                                 Ok(())
                             }),
+                            None,
                         )
                         .await;
                     })
@@ -623,6 +630,7 @@ mod tests {
                                 }
                                 Ok(())
                             }),
+                            None,
                         )
                         .await;
                     })
@@ -711,6 +719,7 @@ mod tests {
                             param,
                             new_writer_rx,
                             MockCoordinator::new((), |_, _, _| unreachable!()),
+                            None,
                         )
                         .await;
                     })
@@ -792,6 +801,7 @@ mod tests {
                             MockCoordinator::new((), |_, _, _| {
                                 Err(SinkError::Coordinator(anyhow!("failed to commit")))
                             }),
+                            None,
                         )
                         .await;
                     })
@@ -925,6 +935,7 @@ mod tests {
                                 assert_eq!(expected_metadata_list, &metadata_list);
                                 Ok(())
                             }),
+                            None,
                         )
                         .await;
                     })
@@ -15,12 +15,14 @@
 mod coordinator_worker;
 mod handle;
 mod manager;
+mod transaction_log;
 
 use futures::stream::BoxStream;
 pub use manager::SinkCoordinatorManager;
 use risingwave_pb::connector_service::{CoordinateRequest, CoordinateResponse};
 use tokio::sync::mpsc::UnboundedSender;
 use tonic::Status;
+pub use transaction_log::SinkTransactionLog;
 
 pub type SinkWriterRequestStream = BoxStream<'static, Result<CoordinateRequest, Status>>;
 pub type SinkCoordinatorResponseSender = UnboundedSender<Result<CoordinateResponse, Status>>;
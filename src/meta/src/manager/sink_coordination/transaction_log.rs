@@ -0,0 +1,90 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::connector_service::{PendingSinkTransaction, SinkMetadata};
+
+use crate::manager::MetaStoreImpl;
+use crate::model::{pending_sink_transaction_key, MetadataModel};
+use crate::storage::MetaStoreRef;
+use crate::MetaResult;
+
+/// Durable log of sink commits in flight, making meta the coordinator of record for
+/// two-phase-commit sinks.
+///
+/// Before the sink coordinator issues the commit of an epoch to the external system, the
+/// collected writer metadata is persisted as a [`PendingSinkTransaction`] (pre-commit);
+/// once the external system confirms, the record is removed (commit). A record that is
+/// still present when the coordinator restarts marks a commit with an unknown outcome,
+/// and is re-driven to resolution before any new epoch is committed. This relies on
+/// `SinkCommitCoordinator::commit` being idempotent per epoch, which holds for the
+/// transactional sinks that use coordinated commit.
+#[derive(Clone)]
+pub struct SinkTransactionLog {
+    meta_store: MetaStoreRef,
+}
+
+impl SinkTransactionLog {
+    /// Returns `None` for the SQL meta store backend, where coordinated sinks fall back
+    /// to relying on commit idempotency alone.
+    pub fn new(meta_store: &MetaStoreImpl) -> Option<Self> {
+        match meta_store {
+            MetaStoreImpl::Kv(store) => Some(Self {
+                meta_store: store.clone(),
+            }),
+            MetaStoreImpl::Sql(_) => None,
+        }
+    }
+
+    /// Phase one: durably records the fully collected metadata of a sink epoch before
+    /// the commit is issued to the external system.
+    pub async fn pre_commit(
+        &self,
+        sink_id: u32,
+        epoch: u64,
+        metadata: Vec<SinkMetadata>,
+    ) -> MetaResult<()> {
+        PendingSinkTransaction {
+            sink_id,
+            epoch,
+            metadata,
+        }
+        .insert(&self.meta_store)
+        .await?;
+        Ok(())
+    }
+
+    /// Phase two: the external system confirmed the commit of the epoch, so the pending
+    /// record is no longer needed.
+    pub async fn commit(&self, sink_id: u32, epoch: u64) -> MetaResult<()> {
+        PendingSinkTransaction::delete(
+            &self.meta_store,
+            &pending_sink_transaction_key(sink_id, epoch),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Lists the transactions of the given sink whose commit outcome is unknown,
+    /// ordered by epoch.
+    pub async fn pending_transactions(
+        &self,
+        sink_id: u32,
+    ) -> MetaResult<Vec<PendingSinkTransaction>> {
+        Ok(PendingSinkTransaction::list(&self.meta_store)
+            .await?
+            .into_iter()
+            .filter(|txn| txn.sink_id == sink_id)
+            .collect())
+    }
+}
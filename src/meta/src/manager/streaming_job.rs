@@ -17,7 +17,7 @@ use std::collections::HashSet;
 use risingwave_common::catalog::TableVersionId;
 use risingwave_common::current_cluster_version;
 use risingwave_common::util::epoch::Epoch;
-use risingwave_pb::catalog::{CreateType, Index, PbSource, Sink, Table};
+use risingwave_pb::catalog::{CreateType, Index, PbSource, Sink, StreamJobPriority, Table};
 use risingwave_pb::ddl_service::TableJobType;
 use strum::{EnumDiscriminants, EnumIs};
 
@@ -280,11 +280,20 @@ impl StreamingJob {
             Self::MaterializedView(table) => {
                 table.get_create_type().unwrap_or(CreateType::Foreground)
             }
+            Self::Index(_, table) => table.get_create_type().unwrap_or(CreateType::Foreground),
             Self::Sink(s, _) => s.get_create_type().unwrap_or(CreateType::Foreground),
             _ => CreateType::Foreground,
         }
     }
 
+    /// The priority class of the job, defaulting to normal when unset.
+    pub fn priority(&self) -> StreamJobPriority {
+        match self.table().map(|table| table.priority()) {
+            Some(StreamJobPriority::Unspecified) | None => StreamJobPriority::Normal,
+            Some(priority) => priority,
+        }
+    }
+
     // TODO: record all objects instead.
     pub fn dependent_relations(&self) -> Vec<u32> {
         match self {
@@ -0,0 +1,91 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use risingwave_pb::meta::TableChangeNotification;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tonic::Status;
+
+struct Subscriber {
+    /// Table ids the subscriber cares about. An empty set subscribes to all tables.
+    table_ids: HashSet<u32>,
+    tx: UnboundedSender<Result<TableChangeNotification, Status>>,
+}
+
+/// `TableChangeNotifier` streams per-table committed epoch advances to external subscribers,
+/// e.g. serving-layer result caches that invalidate cached results only when the underlying
+/// materialized views actually advanced.
+///
+/// Changes of all tables committed in one hummock epoch are coalesced into a single
+/// notification. Subscribers are dropped as soon as their receiving end is closed.
+pub struct TableChangeNotifier {
+    subscribers: Mutex<Vec<Subscriber>>,
+}
+
+pub type TableChangeNotifierRef = Arc<TableChangeNotifier>;
+
+impl TableChangeNotifier {
+    pub fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Registers a subscriber interested in the given tables, or in all tables if the set is
+    /// empty. Returns the receiving end of the notification stream.
+    pub fn subscribe(
+        &self,
+        table_ids: HashSet<u32>,
+    ) -> UnboundedReceiver<Result<TableChangeNotification, Status>> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.subscribers.lock().push(Subscriber { table_ids, tx });
+        rx
+    }
+
+    /// Notifies all subscribers that the given tables have been committed at new epochs.
+    /// Called once per hummock epoch commit with all tables committed in it.
+    pub fn notify(&self, table_epochs: &HashMap<u32, u64>) {
+        if table_epochs.is_empty() {
+            return;
+        }
+        self.subscribers.lock().retain(|subscriber| {
+            let table_epochs = if subscriber.table_ids.is_empty() {
+                table_epochs.clone()
+            } else {
+                table_epochs
+                    .iter()
+                    .filter(|(table_id, _)| subscriber.table_ids.contains(table_id))
+                    .map(|(table_id, epoch)| (*table_id, *epoch))
+                    .collect()
+            };
+            if table_epochs.is_empty() {
+                // Nothing the subscriber cares about advanced, but keep it registered.
+                return true;
+            }
+            subscriber
+                .tx
+                .send(Ok(TableChangeNotification { table_epochs }))
+                .is_ok()
+        });
+    }
+}
+
+impl Default for TableChangeNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
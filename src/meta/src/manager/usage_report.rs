@@ -0,0 +1,313 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use risingwave_meta_model_v2::prelude::UsageRecord;
+use risingwave_meta_model_v2::usage_record;
+use risingwave_pb::meta::PbUsageRecord;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use thiserror_ext::AsReport;
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
+
+use crate::hummock::HummockManagerRef;
+use crate::manager::{IdCategory, MetaSrvEnv, MetaStoreImpl, MetadataManager};
+use crate::model::MetadataModel;
+use crate::rpc::metrics::MetaMetrics;
+use crate::MetaResult;
+
+pub type UsageReportManagerRef = Arc<UsageReportManager>;
+
+/// `UsageReportManager` persists the periodic per-database usage records sampled by
+/// [`start_usage_report_sampler`] into a dedicated meta store table and serves them
+/// through the `GetUsageReport` RPC, so that shared clusters can charge databases
+/// back for their resource consumption without an external metering pipeline.
+pub struct UsageReportManager {
+    env: MetaSrvEnv,
+}
+
+impl UsageReportManager {
+    pub fn new(env: MetaSrvEnv) -> Self {
+        Self { env }
+    }
+
+    /// Persists one usage record, stamping its id.
+    pub async fn record(&self, mut record: PbUsageRecord) -> MetaResult<()> {
+        match self.env.meta_store_ref() {
+            MetaStoreImpl::Kv(meta_store) => {
+                record.id = self
+                    .env
+                    .id_gen_manager()
+                    .as_kv()
+                    .generate::<{ IdCategory::UsageRecord }>()
+                    .await?;
+                record.insert(meta_store).await?;
+            }
+            MetaStoreImpl::Sql(sql_meta_store) => {
+                usage_record::ActiveModel {
+                    id: Default::default(),
+                    database_id: Set(record.database_id as _),
+                    window_start_ms: Set(record.window_start_ms as _),
+                    window_end_ms: Set(record.window_end_ms as _),
+                    state_bytes: Set(record.state_bytes as _),
+                    actor_seconds: Set(record.actor_seconds as _),
+                    barrier_count: Set(record.barrier_count as _),
+                    sink_egress_bytes: Set(record.sink_egress_bytes as _),
+                }
+                .insert(&sql_meta_store.conn)
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists the usage records of the given database, or of all databases if unset,
+    /// whose window overlaps `[start_time_ms, end_time_ms]`. A zero bound means
+    /// unbounded on that side.
+    pub async fn get_usage_report(
+        &self,
+        database_id: Option<u32>,
+        start_time_ms: u64,
+        end_time_ms: u64,
+    ) -> MetaResult<Vec<PbUsageRecord>> {
+        match self.env.meta_store_ref() {
+            MetaStoreImpl::Kv(meta_store) => {
+                let mut records = PbUsageRecord::list(meta_store).await?;
+                records.retain(|record| {
+                    database_id.map_or(true, |id| record.database_id == id)
+                        && (start_time_ms == 0 || record.window_end_ms >= start_time_ms)
+                        && (end_time_ms == 0 || record.window_start_ms <= end_time_ms)
+                });
+                records.sort_by_key(|record| record.id);
+                Ok(records)
+            }
+            MetaStoreImpl::Sql(sql_meta_store) => {
+                let mut select = UsageRecord::find();
+                if let Some(database_id) = database_id {
+                    select = select.filter(usage_record::Column::DatabaseId.eq(database_id as i32));
+                }
+                if start_time_ms > 0 {
+                    select = select
+                        .filter(usage_record::Column::WindowEndMs.gte(start_time_ms as i64));
+                }
+                if end_time_ms > 0 {
+                    select = select
+                        .filter(usage_record::Column::WindowStartMs.lte(end_time_ms as i64));
+                }
+                Ok(select
+                    .order_by_asc(usage_record::Column::Id)
+                    .all(&sql_meta_store.conn)
+                    .await?
+                    .into_iter()
+                    .map(Into::into)
+                    .collect())
+            }
+        }
+    }
+}
+
+/// A point-in-time snapshot of the cluster-wide usage counters, from which one
+/// sampling window is derived by diffing two consecutive snapshots.
+struct UsageSnapshot {
+    timestamp_ms: u64,
+    /// Approximate bytes of state per database.
+    state_bytes: HashMap<u32, u64>,
+    /// Running actors per database.
+    actor_counts: HashMap<u32, u64>,
+    /// Barriers committed since the meta node started.
+    committed_barriers: u64,
+    /// Cumulative storage size of each sink state table, with the database the sink
+    /// belongs to.
+    sink_table_sizes: HashMap<u32, (u32, u64)>,
+}
+
+async fn collect_usage_snapshot(
+    metadata_manager: &MetadataManager,
+    hummock_manager: &HummockManagerRef,
+    meta_metrics: &MetaMetrics,
+) -> MetaResult<UsageSnapshot> {
+    let timestamp_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    let committed_barriers = meta_metrics.barrier_latency.get_sample_count();
+
+    let table_to_database = metadata_manager.get_table_database_mapping().await?;
+    let version_stats = hummock_manager.get_version_stats().await;
+    let table_sizes: HashMap<u32, u64> = version_stats
+        .table_stats
+        .iter()
+        .map(|(table_id, stats)| {
+            let size = (stats.total_key_size + stats.total_value_size).max(0) as u64;
+            (*table_id, size)
+        })
+        .collect();
+
+    let mut state_bytes: HashMap<u32, u64> = HashMap::new();
+    for (table_id, size) in &table_sizes {
+        if let Some(database_id) = table_to_database.get(table_id) {
+            *state_bytes.entry(*database_id).or_default() += *size;
+        }
+    }
+
+    let actor_counts = metadata_manager
+        .count_actors_per_database()
+        .await?
+        .into_iter()
+        .map(|(database_id, count)| (database_id, count as u64))
+        .collect();
+
+    // The state tables of the sinks, i.e. their log stores: the bytes flushed into
+    // them over a window approximate the bytes the sinks delivered downstream.
+    let sink_databases: HashMap<u32, u32> = metadata_manager
+        .list_sinks()
+        .await?
+        .into_iter()
+        .map(|sink| (sink.id, sink.database_id))
+        .collect();
+    let sink_job_ids: HashSet<u32> = sink_databases.keys().copied().collect();
+    let mut sink_table_sizes = HashMap::new();
+    if let Some(job_internal_tables) = metadata_manager
+        .get_job_id_to_internal_table_ids_mapping()
+        .await
+    {
+        for (job_id, internal_table_ids) in job_internal_tables {
+            if !sink_job_ids.contains(&job_id) {
+                continue;
+            }
+            let database_id = sink_databases[&job_id];
+            for table_id in internal_table_ids {
+                let size = table_sizes.get(&table_id).copied().unwrap_or(0);
+                sink_table_sizes.insert(table_id, (database_id, size));
+            }
+        }
+    }
+
+    Ok(UsageSnapshot {
+        timestamp_ms,
+        state_bytes,
+        actor_counts,
+        committed_barriers,
+        sink_table_sizes,
+    })
+}
+
+/// Derives the usage records of one window from two consecutive snapshots, one record
+/// per database that held state or ran actors in the window.
+fn usage_records_from_snapshots(
+    previous: &UsageSnapshot,
+    current: &UsageSnapshot,
+) -> Vec<PbUsageRecord> {
+    let window_secs = current.timestamp_ms.saturating_sub(previous.timestamp_ms) / 1000;
+    let barrier_count = current
+        .committed_barriers
+        .saturating_sub(previous.committed_barriers);
+
+    let mut sink_egress_bytes: HashMap<u32, u64> = HashMap::new();
+    for (table_id, (database_id, size)) in &current.sink_table_sizes {
+        let previous_size = previous
+            .sink_table_sizes
+            .get(table_id)
+            .map(|(_, size)| *size)
+            .unwrap_or(0);
+        *sink_egress_bytes.entry(*database_id).or_default() +=
+            size.saturating_sub(previous_size);
+    }
+
+    let database_ids: HashSet<u32> = current
+        .state_bytes
+        .keys()
+        .chain(current.actor_counts.keys())
+        .chain(sink_egress_bytes.keys())
+        .copied()
+        .collect();
+
+    database_ids
+        .into_iter()
+        .map(|database_id| PbUsageRecord {
+            id: 0,
+            database_id,
+            window_start_ms: previous.timestamp_ms,
+            window_end_ms: current.timestamp_ms,
+            state_bytes: current.state_bytes.get(&database_id).copied().unwrap_or(0),
+            actor_seconds: current
+                .actor_counts
+                .get(&database_id)
+                .copied()
+                .unwrap_or(0)
+                * window_secs,
+            barrier_count,
+            sink_egress_bytes: sink_egress_bytes.get(&database_id).copied().unwrap_or(0),
+        })
+        .collect()
+}
+
+/// Periodically samples the per-database resource usage of the cluster and persists
+/// one usage record per database and window through the [`UsageReportManager`].
+pub fn start_usage_report_sampler(
+    usage_report_manager: UsageReportManagerRef,
+    metadata_manager: MetadataManager,
+    hummock_manager: HummockManagerRef,
+    meta_metrics: Arc<MetaMetrics>,
+) -> (JoinHandle<()>, Sender<()>) {
+    const SAMPLE_INTERVAL_SECONDS: u64 = 60;
+
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut sample_interval =
+            tokio::time::interval(Duration::from_secs(SAMPLE_INTERVAL_SECONDS));
+        sample_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut previous: Option<UsageSnapshot> = None;
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = sample_interval.tick() => {},
+                // Shutdown sampler
+                _ = &mut shutdown_rx => {
+                    tracing::info!("Usage report sampler is stopped");
+                    return;
+                }
+            }
+
+            let current =
+                match collect_usage_snapshot(&metadata_manager, &hummock_manager, &meta_metrics)
+                    .await
+                {
+                    Ok(snapshot) => snapshot,
+                    Err(err) => {
+                        tracing::warn!(error = %err.as_report(), "fail to sample usage");
+                        continue;
+                    }
+                };
+            // The first snapshot only establishes the baseline of the first window.
+            let Some(previous_snapshot) = &previous else {
+                previous = Some(current);
+                continue;
+            };
+
+            for record in usage_records_from_snapshots(previous_snapshot, &current) {
+                if let Err(err) = usage_report_manager.record(record).await {
+                    tracing::warn!(error = %err.as_report(), "fail to persist usage record");
+                }
+            }
+            previous = Some(current);
+        }
+    });
+
+    (join_handle, shutdown_tx)
+}
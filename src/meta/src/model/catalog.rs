@@ -15,6 +15,8 @@
 use risingwave_pb::catalog::{
     Connection, Database, Function, Index, Schema, Secret, Sink, Source, Subscription, Table, View,
 };
+use risingwave_pb::ddl_service::streaming_job_quota::Scope as QuotaScope;
+use risingwave_pb::ddl_service::{DdlAuditLog, ObjectLints, StreamingJobQuota};
 
 use crate::model::{MetadataModel, MetadataModelResult};
 
@@ -40,6 +42,12 @@ const CATALOG_DATABASE_CF_NAME: &str = "cf/catalog_database";
 const CATALOG_SUBSCRIPTION_CF_NAME: &str = "cf/catalog_subscription";
 /// Column family name for secret catalog.
 const CATALOG_SECRET_CF_NAME: &str = "cf/catalog_secret";
+/// Column family name for streaming job quotas.
+const CATALOG_STREAMING_JOB_QUOTA_CF_NAME: &str = "cf/catalog_streaming_job_quota";
+/// Column family name for object lint findings.
+const CATALOG_OBJECT_LINTS_CF_NAME: &str = "cf/catalog_object_lints";
+/// Column family name for DDL audit log entries.
+const DDL_AUDIT_LOG_CF_NAME: &str = "cf/ddl_audit_log";
 
 macro_rules! impl_model_for_catalog {
     ($name:ident, $cf:ident, $key_ty:ty, $key_fn:ident) => {
@@ -77,6 +85,40 @@ impl_model_for_catalog!(Schema, CATALOG_SCHEMA_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Database, CATALOG_DATABASE_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Subscription, CATALOG_SUBSCRIPTION_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Secret, CATALOG_SECRET_CF_NAME, u32, get_id);
+impl_model_for_catalog!(
+    ObjectLints,
+    CATALOG_OBJECT_LINTS_CF_NAME,
+    u32,
+    get_relation_id
+);
+impl_model_for_catalog!(DdlAuditLog, DDL_AUDIT_LOG_CF_NAME, u64, get_id);
+
+/// `StreamingJobQuota` stores the streaming job quota of a user or a database, keyed
+/// by its scope.
+impl MetadataModel for StreamingJobQuota {
+    type KeyType = String;
+    type PbType = StreamingJobQuota;
+
+    fn cf_name() -> String {
+        CATALOG_STREAMING_JOB_QUOTA_CF_NAME.to_string()
+    }
+
+    fn to_protobuf(&self) -> Self::PbType {
+        self.clone()
+    }
+
+    fn from_protobuf(prost: Self::PbType) -> Self {
+        prost
+    }
+
+    fn key(&self) -> MetadataModelResult<Self::KeyType> {
+        match self.scope {
+            Some(QuotaScope::UserId(id)) => Ok(format!("user/{id}")),
+            Some(QuotaScope::DatabaseId(id)) => Ok(format!("database/{id}")),
+            None => Err(anyhow::anyhow!("streaming job quota scope is not set").into()),
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -90,6 +132,9 @@ mod tests {
             id,
             name: format!("database_{}", id),
             owner: risingwave_common::catalog::DEFAULT_SUPER_USER_ID,
+            barrier_interval_ms: None,
+            default_timezone: None,
+            default_collation: None,
         }
     }
 
@@ -13,11 +13,15 @@
 // limitations under the License.
 
 mod catalog;
+mod checkpoint;
 mod cluster;
 mod error;
 mod migration_plan;
 mod notification;
+mod sink;
 mod stream;
+mod throttle;
+mod usage;
 mod user;
 
 use std::collections::btree_map::{Entry, VacantEntry};
@@ -32,6 +36,7 @@ pub use cluster::*;
 pub use error::*;
 pub use migration_plan::*;
 pub use notification::*;
+pub use sink::*;
 pub use stream::*;
 
 use crate::storage::{MetaStore, MetaStoreError, Snapshot, Transaction};
@@ -231,6 +236,13 @@ macro_rules! for_all_metadata_models {
             { risingwave_hummock_sdk::version::HummockVersionDelta },
             { risingwave_pb::hummock::HummockPinnedSnapshot },
             { risingwave_pb::hummock::HummockPinnedVersion },
+            { risingwave_pb::meta::NamedCheckpoint },
+            { risingwave_pb::meta::RateLimitBoost },
+            { risingwave_pb::meta::NotificationBacklogEntry },
+            { risingwave_pb::ddl_service::StreamingJobQuota },
+            { risingwave_pb::ddl_service::ObjectLints },
+            { risingwave_pb::ddl_service::DdlAuditLog },
+            { risingwave_pb::meta::UsageRecord },
         }
     };
 }
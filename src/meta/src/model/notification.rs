@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risingwave_pb::meta::NotificationBacklogEntry;
 use thiserror_ext::AsReport;
 
+use crate::model::{MetadataModel, MetadataModelResult};
 use crate::storage::{MetaStore, MetaStoreError, DEFAULT_COLUMN_FAMILY};
 
 /// `NotificationVersion` records the last sent notification version, this will be stored
@@ -55,3 +57,30 @@ impl NotificationVersion {
         self.0
     }
 }
+
+/// Column family name for the notification backlog.
+const NOTIFICATION_BACKLOG_CF_NAME: &str = "cf/notification_backlog";
+
+/// `NotificationBacklogEntry` retains a broadcast notification so that subscribers
+/// reconnecting within the retention window can catch up incrementally instead of
+/// re-syncing a full snapshot. See `NotificationBacklog`.
+impl MetadataModel for NotificationBacklogEntry {
+    type KeyType = String;
+    type PbType = Self;
+
+    fn cf_name() -> String {
+        NOTIFICATION_BACKLOG_CF_NAME.to_string()
+    }
+
+    fn to_protobuf(&self) -> Self::PbType {
+        self.clone()
+    }
+
+    fn from_protobuf(prost: Self::PbType) -> Self {
+        prost
+    }
+
+    fn key(&self) -> MetadataModelResult<Self::KeyType> {
+        Ok(format!("{:02}/{:020}", self.subscribe_type, self.seq))
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::connector_service::PendingSinkTransaction;
+
+use crate::model::{MetadataModel, MetadataModelResult};
+
+/// Column family name for pending sink transactions.
+const SINK_PENDING_TRANSACTION_CF_NAME: &str = "cf/sink_pending_transaction";
+
+/// Builds the storage key of a pending sink transaction. The epoch is zero-padded so
+/// that the lexicographic key order matches the epoch order within one sink.
+pub fn pending_sink_transaction_key(sink_id: u32, epoch: u64) -> String {
+    format!("{}/{:020}", sink_id, epoch)
+}
+
+/// `PendingSinkTransaction` is the durable phase-one record of the sink two-phase
+/// commit protocol. See `SinkTransactionLog` for the protocol itself.
+impl MetadataModel for PendingSinkTransaction {
+    type KeyType = String;
+    type PbType = Self;
+
+    fn cf_name() -> String {
+        SINK_PENDING_TRANSACTION_CF_NAME.to_string()
+    }
+
+    fn to_protobuf(&self) -> Self::PbType {
+        self.clone()
+    }
+
+    fn from_protobuf(prost: Self::PbType) -> Self {
+        prost
+    }
+
+    fn key(&self) -> MetadataModelResult<Self::KeyType> {
+        Ok(pending_sink_transaction_key(self.sink_id, self.epoch))
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::meta::RateLimitBoost;
+
+use crate::model::{MetadataModel, MetadataModelResult};
+
+/// Column family name for rate limit boosts.
+const RATE_LIMIT_BOOST_CF_NAME: &str = "cf/rate_limit_boost";
+
+/// `RateLimitBoost` records a temporary rate limit override of a streaming job and
+/// its expiry, keyed by the job's table id, so that the automatic revert timer can be
+/// rebuilt after a meta node failover. See `RateLimitBoostManager`.
+impl MetadataModel for RateLimitBoost {
+    type KeyType = u32;
+    type PbType = Self;
+
+    fn cf_name() -> String {
+        RATE_LIMIT_BOOST_CF_NAME.to_string()
+    }
+
+    fn to_protobuf(&self) -> Self::PbType {
+        self.clone()
+    }
+
+    fn from_protobuf(prost: Self::PbType) -> Self {
+        prost
+    }
+
+    fn key(&self) -> MetadataModelResult<Self::KeyType> {
+        Ok(self.table_id)
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::meta::UsageRecord;
+
+use crate::model::{MetadataModel, MetadataModelResult};
+
+/// Column family name for per-database usage records.
+const USAGE_RECORD_CF_NAME: &str = "cf/usage_record";
+
+/// `UsageRecord` holds the resource usage of one database over one sampling window,
+/// keyed by a dedicated id generator category. See `UsageReportManager`.
+impl MetadataModel for UsageRecord {
+    type KeyType = u64;
+    type PbType = Self;
+
+    fn cf_name() -> String {
+        USAGE_RECORD_CF_NAME.to_string()
+    }
+
+    fn to_protobuf(&self) -> Self::PbType {
+        self.clone()
+    }
+
+    fn from_protobuf(prost: Self::PbType) -> Self {
+        prost
+    }
+
+    fn key(&self) -> MetadataModelResult<Self::KeyType> {
+        Ok(self.id)
+    }
+}
@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_pb::user::UserInfo;
+use risingwave_pb::user::{DefaultPrivilege, UserInfo};
 
 use crate::model::{MetadataModel, MetadataModelResult};
 
 /// Column family name for user info.
 const USER_INFO_CF_NAME: &str = "cf/user_info";
+/// Column family name for per-schema default privilege templates.
+const DEFAULT_PRIVILEGE_CF_NAME: &str = "cf/default_privilege";
 
 /// `UserInfo` stores the user information.
 impl MetadataModel for UserInfo {
@@ -40,3 +42,26 @@ impl MetadataModel for UserInfo {
         Ok(self.id)
     }
 }
+
+/// `DefaultPrivilege` stores the default privilege template of a schema, keyed by the
+/// schema id.
+impl MetadataModel for DefaultPrivilege {
+    type KeyType = u32;
+    type PbType = DefaultPrivilege;
+
+    fn cf_name() -> String {
+        DEFAULT_PRIVILEGE_CF_NAME.to_string()
+    }
+
+    fn to_protobuf(&self) -> Self::PbType {
+        self.clone()
+    }
+
+    fn from_protobuf(prost: Self::PbType) -> Self {
+        prost
+    }
+
+    fn key(&self) -> MetadataModelResult<Self::KeyType> {
+        Ok(self.schema_id)
+    }
+}
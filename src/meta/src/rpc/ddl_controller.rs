@@ -16,7 +16,7 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context};
 use itertools::Itertools;
@@ -25,6 +25,7 @@ use risingwave_common::bitmap::Bitmap;
 use risingwave_common::config::DefaultParallelism;
 use risingwave_common::hash::{ActorMapping, VirtualNode};
 use risingwave_common::secret::SecretEncryption;
+use risingwave_common::session_config::SessionConfig;
 use risingwave_common::system_param::reader::SystemParamsRead;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_common::util::epoch::Epoch;
@@ -33,6 +34,7 @@ use risingwave_common::util::stream_graph_visitor::{
 };
 use risingwave_common::{bail, current_cluster_version, hash, must_match};
 use risingwave_connector::error::ConnectorError;
+use risingwave_connector::sink::schema_publish::publish_sink_schema;
 use risingwave_connector::source::cdc::CdcSourceType;
 use risingwave_connector::source::{
     ConnectorProperties, SourceEnumeratorContext, SourceProperties, SplitEnumerator,
@@ -44,17 +46,22 @@ use risingwave_meta_model_v2::ObjectId;
 use risingwave_pb::catalog::connection::private_link_service::PbPrivateLinkProvider;
 use risingwave_pb::catalog::connection::PrivateLinkService;
 use risingwave_pb::catalog::source::OptionalAssociatedTableId;
-use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
+use risingwave_pb::catalog::table::{OptionalAssociatedSourceId, PbSchemaChangePolicy, PbTableType};
 use risingwave_pb::catalog::{
-    connection, Comment, Connection, CreateType, Database, Function, PbSink, PbSource, PbTable,
-    Schema, Secret, Sink, Source, Subscription, Table, View,
+    connection, Annotation, Comment, Connection, CreateType, Database, Function, Label, PbSink,
+    PbSource,
+    PbStorageClassPolicy, PbStreamJobPriority, PbTable, Schema, Secret, Sink, Source, Subscription,
+    Table, View,
 };
 use risingwave_pb::ddl_service::alter_owner_request::Object;
+use risingwave_pb::ddl_service::alter_connector_props_request::ObjectType as ConnectorPropsObjectType;
 use risingwave_pb::ddl_service::{
-    alter_name_request, alter_set_schema_request, DdlProgress, TableJobType,
+    alter_name_request, alter_set_schema_request, undrop_relation_request, DdlProgress,
+    ObjectLints, PbDdlAuditLog, TableJobType,
 };
 use risingwave_pb::meta::table_fragments::fragment::FragmentDistributionType;
 use risingwave_pb::meta::table_fragments::PbFragment;
+use risingwave_pb::plan_common::PbColumnCatalog;
 use risingwave_pb::meta::PbTableParallelism;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::{
@@ -69,14 +76,17 @@ use tracing::Instrument;
 
 use crate::barrier::BarrierManagerRef;
 use crate::manager::{
-    CatalogManagerRef, ConnectionId, DatabaseId, DdlType, FragmentManagerRef, FunctionId,
-    IdCategory, IdCategoryType, IndexId, LocalNotification, MetaSrvEnv, MetadataManager,
-    MetadataManagerV1, NotificationVersion, RelationIdEnum, SchemaId, SecretId, SinkId, SourceId,
-    StreamingClusterInfo, StreamingJob, StreamingJobDiscriminants, SubscriptionId, TableId, UserId,
-    ViewId, IGNORED_NOTIFICATION_VERSION,
+    CatalogManagerRef, ConnectionId, DatabaseId, DdlAuditManager, DdlBatchOperation, DdlType,
+    FragmentManagerRef, FunctionId, IdCategory, IdCategoryType, IndexId, LocalNotification,
+    MetaSrvEnv, MetadataManager, MetadataManagerV1, NotificationVersion, RelationIdEnum, SchemaId,
+    SecretId, SinkId, SourceId, StreamingClusterInfo, StreamingJob, StreamingJobDiscriminants,
+    SubscriptionId, TableId, UserId, ViewId, IGNORED_NOTIFICATION_VERSION, MAX_ANNOTATION_KEY_LEN,
+    MAX_ANNOTATION_VALUE_LEN, MAX_LABEL_LEN,
 };
-use crate::model::{FragmentId, StreamContext, TableFragments, TableParallelism};
+use crate::model::{FragmentId, MetadataModel, StreamContext, TableFragments, TableParallelism};
 use crate::rpc::cloud_provider::AwsEc2Client;
+use crate::rpc::ddl_lint::lint_fragment_graph;
+use crate::rpc::property_schema::validate_connector_properties;
 use crate::stream::{
     validate_sink, ActorGraphBuildResult, ActorGraphBuilder, CompleteStreamFragmentGraph,
     CreateStreamingJobContext, CreateStreamingJobOption, GlobalStreamManagerRef,
@@ -88,6 +98,10 @@ use crate::{MetaError, MetaResult};
 pub enum DropMode {
     Restrict,
     Cascade,
+    /// Soft-drop: hide the relation from frontends but keep its catalog and fragments
+    /// for the configured retention window, so that it can be restored via
+    /// [`DdlCommand::UndropRelation`].
+    SoftDelete,
 }
 
 impl DropMode {
@@ -108,7 +122,6 @@ pub enum StreamingJobId {
 }
 
 impl StreamingJobId {
-    #[allow(dead_code)]
     fn id(&self) -> TableId {
         match self {
             StreamingJobId::MaterializedView(id)
@@ -124,6 +137,9 @@ pub struct ReplaceTableInfo {
     pub streaming_job: StreamingJob,
     pub fragment_graph: StreamFragmentGraphProto,
     pub col_index_mapping: Option<ColIndexMapping>,
+    /// Lease token from [`DdlController::acquire_alter_lease`]. Zero for callers that
+    /// replace tables without acquiring a lease, e.g. sink-into-table.
+    pub alter_lease_token: u64,
 }
 
 pub enum DdlCommand {
@@ -133,7 +149,7 @@ pub enum DdlCommand {
     DropSchema(SchemaId),
     CreateSource(Source),
     DropSource(SourceId, DropMode),
-    CreateFunction(Function),
+    CreateFunction(Function, bool),
     DropFunction(FunctionId),
     CreateView(View),
     DropView(ViewId, DropMode),
@@ -144,18 +160,32 @@ pub enum DdlCommand {
         Option<ReplaceTableInfo>,
     ),
     DropStreamingJob(StreamingJobId, DropMode, Option<ReplaceTableInfo>),
+    UndropRelation(undrop_relation_request::Relation),
     AlterName(alter_name_request::Object, String),
     ReplaceTable(ReplaceTableInfo),
     AlterSourceColumn(Source),
+    AlterSourceAddColumn(SourceId, PbColumnCatalog, String, u64),
+    AlterConnectorProps(u32, ConnectorPropsObjectType, HashMap<String, String>),
     AlterObjectOwner(Object, UserId),
+    AlterSchemaDefaultOwner(SchemaId, Option<UserId>),
     AlterSetSchema(alter_set_schema_request::Object, SchemaId),
     CreateConnection(Connection),
     DropConnection(ConnectionId),
     CreateSecret(Secret),
     DropSecret(SecretId),
+    RotateSecret(SecretId, Vec<u8>),
     CommentOn(Comment),
+    AlterAnnotation(Annotation),
+    AlterLabel(Label),
+    AlterStorageClassPolicy(u32, Option<PbStorageClassPolicy>),
+    AlterSchemaChangePolicy(u32, PbSchemaChangePolicy),
+    AlterStreamJobPriority(u32, PbStreamJobPriority),
+    AlterTableTtl(u32, Option<u32>),
+    AlterDatabaseBarrierInterval(DatabaseId, Option<u32>),
+    AlterDatabaseSessionDefault(DatabaseId, String, Option<String>),
     CreateSubscription(Subscription),
     DropSubscription(SubscriptionId, DropMode),
+    RunDdlBatch(Vec<DdlBatchOperation>),
 }
 
 impl DdlCommand {
@@ -174,6 +204,318 @@ impl DdlCommand {
             _ => false,
         }
     }
+
+    /// Derives the audit log entry for this command, to be completed with the outcome
+    /// once the command has finished.
+    ///
+    /// Note that for creations the object id may still be zero here, as ids are only
+    /// assigned during execution.
+    fn audit_entry(&self) -> PbDdlAuditLog {
+        use risingwave_pb::ddl_service::alter_name_request::Object as NameObject;
+        use risingwave_pb::ddl_service::alter_set_schema_request::Object as SetSchemaObject;
+
+        let (command, object_id, object_name, user_id, definition) = match self {
+            DdlCommand::CreateDatabase(database) => (
+                "CREATE_DATABASE",
+                database.id,
+                database.name.clone(),
+                database.owner,
+                String::new(),
+            ),
+            DdlCommand::DropDatabase(database_id) => (
+                "DROP_DATABASE",
+                *database_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::CreateSchema(schema) => (
+                "CREATE_SCHEMA",
+                schema.id,
+                schema.name.clone(),
+                schema.owner,
+                String::new(),
+            ),
+            DdlCommand::DropSchema(schema_id) => {
+                ("DROP_SCHEMA", *schema_id, String::new(), 0, String::new())
+            }
+            DdlCommand::CreateSource(source) => (
+                "CREATE_SOURCE",
+                source.id,
+                source.name.clone(),
+                source.owner,
+                source.definition.clone(),
+            ),
+            DdlCommand::DropSource(source_id, _) => {
+                ("DROP_SOURCE", *source_id, String::new(), 0, String::new())
+            }
+            DdlCommand::CreateFunction(function, _) => (
+                "CREATE_FUNCTION",
+                function.id,
+                function.name.clone(),
+                function.owner,
+                String::new(),
+            ),
+            DdlCommand::DropFunction(function_id) => (
+                "DROP_FUNCTION",
+                *function_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::CreateView(view) => (
+                "CREATE_VIEW",
+                view.id,
+                view.name.clone(),
+                view.owner,
+                view.sql.clone(),
+            ),
+            DdlCommand::DropView(view_id, _) => {
+                ("DROP_VIEW", *view_id, String::new(), 0, String::new())
+            }
+            DdlCommand::CreateStreamingJob(job, _, _, _) => (
+                match job {
+                    StreamingJob::MaterializedView(_) => "CREATE_MATERIALIZED_VIEW",
+                    StreamingJob::Sink(_, _) => "CREATE_SINK",
+                    StreamingJob::Table(_, _, _) => "CREATE_TABLE",
+                    StreamingJob::Index(_, _) => "CREATE_INDEX",
+                    StreamingJob::Source(_) => "CREATE_SOURCE",
+                },
+                job.id(),
+                job.name(),
+                job.owner(),
+                job.definition(),
+            ),
+            DdlCommand::DropStreamingJob(job_id, _, _) => (
+                match job_id {
+                    StreamingJobId::MaterializedView(_) => "DROP_MATERIALIZED_VIEW",
+                    StreamingJobId::Sink(_) => "DROP_SINK",
+                    StreamingJobId::Table(_, _) => "DROP_TABLE",
+                    StreamingJobId::Index(_) => "DROP_INDEX",
+                },
+                job_id.id(),
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::UndropRelation(undrop_relation_request::Relation::TableId(table_id)) => (
+                "UNDROP_RELATION",
+                *table_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::AlterName(object, name) => (
+                "ALTER_NAME",
+                match *object {
+                    NameObject::TableId(id)
+                    | NameObject::ViewId(id)
+                    | NameObject::IndexId(id)
+                    | NameObject::SinkId(id)
+                    | NameObject::SourceId(id)
+                    | NameObject::SchemaId(id)
+                    | NameObject::DatabaseId(id)
+                    | NameObject::SubscriptionId(id)
+                    | NameObject::FunctionId(id) => id,
+                },
+                name.clone(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::ReplaceTable(info) => (
+                "REPLACE_TABLE",
+                info.streaming_job.id(),
+                info.streaming_job.name(),
+                info.streaming_job.owner(),
+                info.streaming_job.definition(),
+            ),
+            DdlCommand::AlterSourceColumn(source) => (
+                "ALTER_SOURCE_COLUMN",
+                source.id,
+                source.name.clone(),
+                source.owner,
+                source.definition.clone(),
+            ),
+            DdlCommand::AlterSourceAddColumn(source_id, _, definition, _) => (
+                "ALTER_SOURCE_ADD_COLUMN",
+                *source_id,
+                String::new(),
+                0,
+                definition.clone(),
+            ),
+            DdlCommand::AlterConnectorProps(object_id, _, _) => (
+                "ALTER_CONNECTOR_PROPS",
+                *object_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::AlterObjectOwner(object, owner_id) => (
+                "ALTER_OWNER",
+                match *object {
+                    Object::TableId(id)
+                    | Object::ViewId(id)
+                    | Object::SourceId(id)
+                    | Object::SinkId(id)
+                    | Object::SchemaId(id)
+                    | Object::DatabaseId(id)
+                    | Object::SubscriptionId(id)
+                    | Object::FunctionId(id)
+                    | Object::ConnectionId(id)
+                    | Object::SecretId(id) => id,
+                },
+                String::new(),
+                *owner_id,
+                String::new(),
+            ),
+            DdlCommand::AlterSchemaDefaultOwner(schema_id, default_owner_id) => (
+                "ALTER_SCHEMA_DEFAULT_OWNER",
+                *schema_id,
+                String::new(),
+                default_owner_id.unwrap_or_default(),
+                String::new(),
+            ),
+            DdlCommand::AlterSetSchema(object, _) => (
+                "ALTER_SET_SCHEMA",
+                match *object {
+                    SetSchemaObject::TableId(id)
+                    | SetSchemaObject::ViewId(id)
+                    | SetSchemaObject::SourceId(id)
+                    | SetSchemaObject::SinkId(id)
+                    | SetSchemaObject::FunctionId(id)
+                    | SetSchemaObject::ConnectionId(id)
+                    | SetSchemaObject::SubscriptionId(id) => id,
+                },
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::CreateConnection(connection) => (
+                "CREATE_CONNECTION",
+                connection.id,
+                connection.name.clone(),
+                connection.owner,
+                String::new(),
+            ),
+            DdlCommand::DropConnection(connection_id) => (
+                "DROP_CONNECTION",
+                *connection_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            // Secret values are never recorded.
+            DdlCommand::CreateSecret(secret) => (
+                "CREATE_SECRET",
+                secret.id,
+                secret.name.clone(),
+                secret.owner,
+                String::new(),
+            ),
+            DdlCommand::DropSecret(secret_id) => {
+                ("DROP_SECRET", *secret_id, String::new(), 0, String::new())
+            }
+            DdlCommand::RotateSecret(secret_id, _) => {
+                ("ROTATE_SECRET", *secret_id, String::new(), 0, String::new())
+            }
+            DdlCommand::CommentOn(comment) => (
+                "COMMENT_ON",
+                comment.table_id,
+                String::new(),
+                0,
+                comment.description.clone().unwrap_or_default(),
+            ),
+            DdlCommand::AlterAnnotation(annotation) => (
+                "ALTER_ANNOTATION",
+                annotation.table_id,
+                String::new(),
+                0,
+                annotation.key.clone(),
+            ),
+            DdlCommand::AlterLabel(label) => (
+                "ALTER_LABEL",
+                match label.job {
+                    Some(risingwave_pb::catalog::label::Job::TableId(id))
+                    | Some(risingwave_pb::catalog::label::Job::SinkId(id)) => id,
+                    None => 0,
+                },
+                String::new(),
+                0,
+                label.key.clone(),
+            ),
+            DdlCommand::AlterStorageClassPolicy(table_id, _) => (
+                "ALTER_STORAGE_CLASS_POLICY",
+                *table_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::AlterSchemaChangePolicy(table_id, _) => (
+                "ALTER_SCHEMA_CHANGE_POLICY",
+                *table_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::AlterStreamJobPriority(table_id, _) => (
+                "ALTER_STREAM_JOB_PRIORITY",
+                *table_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::AlterTableTtl(table_id, _) => (
+                "ALTER_TABLE_TTL",
+                *table_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::AlterDatabaseBarrierInterval(database_id, _) => (
+                "ALTER_DATABASE_BARRIER_INTERVAL",
+                *database_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::AlterDatabaseSessionDefault(database_id, name, _) => (
+                "ALTER_DATABASE_SESSION_DEFAULT",
+                *database_id,
+                String::new(),
+                0,
+                name.clone(),
+            ),
+            DdlCommand::CreateSubscription(subscription) => (
+                "CREATE_SUBSCRIPTION",
+                subscription.id,
+                subscription.name.clone(),
+                subscription.owner,
+                subscription.definition.clone(),
+            ),
+            DdlCommand::DropSubscription(subscription_id, _) => (
+                "DROP_SUBSCRIPTION",
+                *subscription_id,
+                String::new(),
+                0,
+                String::new(),
+            ),
+            DdlCommand::RunDdlBatch(operations) => (
+                "RUN_DDL_BATCH",
+                0,
+                format!("{} operations", operations.len()),
+                0,
+                String::new(),
+            ),
+        };
+        PbDdlAuditLog {
+            command: command.into(),
+            object_id,
+            object_name,
+            user_id,
+            definition,
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -188,8 +530,23 @@ pub struct DdlController {
     aws_client: Arc<Option<AwsEc2Client>>,
     // The semaphore is used to limit the number of concurrent streaming job creation.
     pub(crate) creating_streaming_job_permits: Arc<CreatingStreamingJobPermit>,
+    ddl_audit_manager: Arc<DdlAuditManager>,
+    // In-memory alter leases keyed by table id, coordinating concurrent `ALTER TABLE`s
+    // across frontends. See [`Self::acquire_alter_lease`].
+    alter_leases: Arc<parking_lot::Mutex<HashMap<u32, AlterLease>>>,
+}
+
+/// An exclusive lease for replacing a table, handed out by
+/// [`DdlController::acquire_alter_lease`].
+struct AlterLease {
+    token: u64,
+    expires_at: Instant,
 }
 
+/// How long an alter lease stays valid if the replace procedure holding it does not reach
+/// the meta service in time, e.g. because the frontend died during planning.
+const ALTER_LEASE_TTL: Duration = Duration::from_secs(60);
+
 #[derive(Clone)]
 pub struct CreatingStreamingJobPermit {
     pub(crate) semaphore: Arc<Semaphore>,
@@ -258,7 +615,8 @@ impl DdlController {
         aws_client: Arc<Option<AwsEc2Client>>,
     ) -> Self {
         let creating_streaming_job_permits = Arc::new(CreatingStreamingJobPermit::new(&env).await);
-        Self {
+        let ddl_audit_manager = Arc::new(DdlAuditManager::new(env.clone()));
+        let ctrl = Self {
             env,
             metadata_manager,
             stream_manager,
@@ -266,7 +624,31 @@ impl DdlController {
             barrier_manager,
             aws_client,
             creating_streaming_job_permits,
+            ddl_audit_manager,
+            alter_leases: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+        };
+        if ctrl.env.opts.soft_drop_retention_sec > 0 {
+            ctrl.start_soft_drop_purger();
         }
+        ctrl
+    }
+
+    /// Spawns the background task that periodically drops soft-dropped relations whose
+    /// retention window has elapsed.
+    fn start_soft_drop_purger(&self) {
+        const PURGE_INTERVAL: Duration = Duration::from_secs(60);
+        let ctrl = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(PURGE_INTERVAL);
+            // The first tick completes immediately.
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if let Err(err) = ctrl.purge_expired_soft_dropped().await {
+                    tracing::warn!(error = %err.as_report(), "failed to purge soft-dropped relations");
+                }
+            }
+        });
     }
 
     async fn gen_unique_id<const C: IdCategoryType>(&self) -> MetaResult<u32> {
@@ -284,7 +666,8 @@ impl DdlController {
         }
         let ctrl = self.clone();
         let fut = async move {
-            match command {
+            let mut audit_entry = command.audit_entry();
+            let result = match command {
                 DdlCommand::CreateDatabase(database) => ctrl.create_database(database).await,
                 DdlCommand::DropDatabase(database_id) => ctrl.drop_database(database_id).await,
                 DdlCommand::CreateSchema(schema) => ctrl.create_schema(schema).await,
@@ -293,7 +676,9 @@ impl DdlController {
                 DdlCommand::DropSource(source_id, drop_mode) => {
                     ctrl.drop_source(source_id, drop_mode).await
                 }
-                DdlCommand::CreateFunction(function) => ctrl.create_function(function).await,
+                DdlCommand::CreateFunction(function, or_replace) => {
+                    ctrl.create_function(function, or_replace).await
+                }
                 DdlCommand::DropFunction(function_id) => ctrl.drop_function(function_id).await,
                 DdlCommand::CreateView(view) => ctrl.create_view(view).await,
                 DdlCommand::DropView(view_id, drop_mode) => {
@@ -313,6 +698,7 @@ impl DdlController {
                     )
                     .await
                 }
+                DdlCommand::UndropRelation(relation) => ctrl.undrop_relation(relation).await,
                 DdlCommand::DropStreamingJob(job_id, drop_mode, target_replace_info) => {
                     ctrl.drop_streaming_job(job_id, drop_mode, target_replace_info)
                         .await
@@ -321,14 +707,24 @@ impl DdlController {
                     streaming_job,
                     fragment_graph,
                     col_index_mapping,
+                    alter_lease_token,
                 }) => {
-                    ctrl.replace_table(streaming_job, fragment_graph, col_index_mapping)
-                        .await
+                    ctrl.replace_table(
+                        streaming_job,
+                        fragment_graph,
+                        col_index_mapping,
+                        alter_lease_token,
+                    )
+                    .await
                 }
                 DdlCommand::AlterName(relation, name) => ctrl.alter_name(relation, &name).await,
                 DdlCommand::AlterObjectOwner(object, owner_id) => {
                     ctrl.alter_owner(object, owner_id).await
                 }
+                DdlCommand::AlterSchemaDefaultOwner(schema_id, default_owner_id) => {
+                    ctrl.alter_schema_default_owner(schema_id, default_owner_id)
+                        .await
+                }
                 DdlCommand::AlterSetSchema(object, new_schema_id) => {
                     ctrl.alter_set_schema(object, new_schema_id).await
                 }
@@ -340,15 +736,60 @@ impl DdlController {
                 }
                 DdlCommand::CreateSecret(secret) => ctrl.create_secret(secret).await,
                 DdlCommand::DropSecret(secret_id) => ctrl.drop_secret(secret_id).await,
+                DdlCommand::RotateSecret(secret_id, secret_value) => {
+                    ctrl.rotate_secret(secret_id, secret_value).await
+                }
                 DdlCommand::AlterSourceColumn(source) => ctrl.alter_source_column(source).await,
+                DdlCommand::AlterSourceAddColumn(source_id, column, definition, base_version) => {
+                    ctrl.alter_source_add_column(source_id, column, definition, base_version)
+                        .await
+                }
+                DdlCommand::AlterConnectorProps(object_id, object_type, changed_props) => {
+                    ctrl.alter_connector_props(object_id, object_type, changed_props)
+                        .await
+                }
                 DdlCommand::CommentOn(comment) => ctrl.comment_on(comment).await,
+                DdlCommand::AlterAnnotation(annotation) => {
+                    ctrl.alter_annotation(annotation).await
+                }
+                DdlCommand::AlterLabel(label) => ctrl.alter_label(label).await,
+                DdlCommand::AlterStorageClassPolicy(table_id, policy) => {
+                    ctrl.alter_storage_class_policy(table_id, policy).await
+                }
+                DdlCommand::AlterSchemaChangePolicy(table_id, policy) => {
+                    ctrl.alter_schema_change_policy(table_id, policy).await
+                }
+                DdlCommand::AlterStreamJobPriority(table_id, priority) => {
+                    ctrl.alter_stream_job_priority(table_id, priority).await
+                }
+                DdlCommand::AlterTableTtl(table_id, retention_seconds) => {
+                    ctrl.alter_table_ttl(table_id, retention_seconds).await
+                }
+                DdlCommand::AlterDatabaseBarrierInterval(database_id, barrier_interval_ms) => {
+                    ctrl.alter_database_barrier_interval(database_id, barrier_interval_ms)
+                        .await
+                }
+                DdlCommand::AlterDatabaseSessionDefault(database_id, name, value) => {
+                    ctrl.alter_database_session_default(database_id, name, value)
+                        .await
+                }
                 DdlCommand::CreateSubscription(subscription) => {
                     ctrl.create_subscription(subscription).await
                 }
                 DdlCommand::DropSubscription(subscription_id, drop_mode) => {
                     ctrl.drop_subscription(subscription_id, drop_mode).await
                 }
+                DdlCommand::RunDdlBatch(operations) => ctrl.run_ddl_batch(operations).await,
+            };
+            match &result {
+                Ok(_) => audit_entry.success = true,
+                Err(err) => audit_entry.error = err.to_report_string(),
+            }
+            // A failed audit write must not fail the already-finished DDL.
+            if let Err(err) = ctrl.ddl_audit_manager.record(audit_entry).await {
+                tracing::warn!(error = %err.as_report(), "failed to record ddl audit log");
             }
+            result
         }
         .in_current_span();
         tokio::spawn(fut).await.unwrap()
@@ -358,6 +799,10 @@ impl DdlController {
         self.barrier_manager.get_ddl_progress().await
     }
 
+    pub async fn list_ddl_audit_logs(&self) -> MetaResult<Vec<PbDdlAuditLog>> {
+        self.ddl_audit_manager.list_audit_logs().await
+    }
+
     async fn create_database(&self, mut database: Database) -> MetaResult<NotificationVersion> {
         match &self.metadata_manager {
             MetadataManager::V1(mgr) => {
@@ -459,6 +904,9 @@ impl DdlController {
     }
 
     async fn create_source(&self, mut source: Source) -> MetaResult<NotificationVersion> {
+        let secret_ref_keys = source.secret_refs.keys().map(|k| k.as_str()).collect();
+        validate_connector_properties(&source.with_properties, &secret_ref_keys)?;
+
         match &self.metadata_manager {
             MetadataManager::V1(mgr) => {
                 source.id = self.gen_unique_id::<{ IdCategory::Table }>().await?;
@@ -524,20 +972,118 @@ impl DdlController {
     }
 
     // Maybe we can unify `alter_source_column` and `alter_source_name`.
+    /// Replaces the source catalog with the frontend-provided one. If the `StreamSourceInfo`
+    /// (format/encode and their options) was changed, a `SourceFormatChange` barrier is issued
+    /// afterwards so that the running source executors switch to the new parser config at a
+    /// well-defined epoch, without recreating downstream jobs.
     async fn alter_source_column(&self, source: Source) -> MetaResult<NotificationVersion> {
+        let source_id = source.get_id();
+        let source_info = source.info.clone();
+        let (version, info_changed) = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.alter_source_column(source).await?,
+            MetadataManager::V2(mgr) => mgr.catalog_controller.alter_source_column(source).await?,
+        };
+
+        if info_changed && let Some(source_info) = source_info {
+            self.stream_manager
+                .barrier_scheduler
+                .run_command(Command::SourceFormatChange(HashMap::from([(
+                    source_id,
+                    source_info,
+                )])))
+                .await?;
+        }
+
+        Ok(version)
+    }
+
+    async fn alter_source_add_column(
+        &self,
+        source_id: SourceId,
+        column: PbColumnCatalog,
+        definition: String,
+        base_version: u64,
+    ) -> MetaResult<NotificationVersion> {
         match &self.metadata_manager {
-            MetadataManager::V1(mgr) => mgr.catalog_manager.alter_source_column(source).await,
-            MetadataManager::V2(mgr) => mgr.catalog_controller.alter_source_column(source).await,
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .alter_source_add_column(source_id, column, definition, base_version)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .alter_source_add_column(source_id as _, column, definition, base_version)
+                    .await
+            }
         }
     }
 
-    async fn create_function(&self, mut function: Function) -> MetaResult<NotificationVersion> {
+    /// Merges `changed_props` into the connector `with_properties` of an existing source or
+    /// sink: the merged properties are validated, committed to the catalog, and then pushed to
+    /// the running executors through a `ConnectorPropsChange` barrier so that they take effect
+    /// without dropping and recreating the object.
+    async fn alter_connector_props(
+        &self,
+        object_id: u32,
+        object_type: ConnectorPropsObjectType,
+        changed_props: HashMap<String, String>,
+    ) -> MetaResult<NotificationVersion> {
+        let mgr = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr,
+            MetadataManager::V2(_) => {
+                bail!("alter connector props is not supported by the sql meta backend yet");
+            }
+        };
+        let (version, merged_props) = match object_type {
+            ConnectorPropsObjectType::Source => {
+                mgr.catalog_manager
+                    .alter_connector_props_for_source(object_id, changed_props)
+                    .await?
+            }
+            ConnectorPropsObjectType::Sink => {
+                mgr.catalog_manager
+                    .alter_connector_props_for_sink(object_id, changed_props)
+                    .await?
+            }
+            ConnectorPropsObjectType::Unspecified => {
+                return Err(MetaError::invalid_parameter("object type is not specified"));
+            }
+        };
+
+        self.stream_manager
+            .barrier_scheduler
+            .run_command(Command::ConnectorPropsChange(HashMap::from([(
+                object_id,
+                merged_props,
+            )])))
+            .await?;
+
+        Ok(version)
+    }
+
+    async fn create_function(
+        &self,
+        mut function: Function,
+        or_replace: bool,
+    ) -> MetaResult<NotificationVersion> {
         match &self.metadata_manager {
             MetadataManager::V1(mgr) => {
+                // The id is only used if there is no function to replace. Otherwise the
+                // existing function keeps its id.
                 function.id = self.gen_unique_id::<{ IdCategory::Function }>().await?;
-                mgr.catalog_manager.create_function(&function).await
+                if or_replace {
+                    mgr.catalog_manager
+                        .create_or_replace_function(&function)
+                        .await
+                } else {
+                    mgr.catalog_manager.create_function(&function).await
+                }
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .create_function(function, or_replace)
+                    .await
             }
-            MetadataManager::V2(mgr) => mgr.catalog_controller.create_function(function).await,
         }
     }
 
@@ -584,6 +1130,85 @@ impl DdlController {
         Ok(version)
     }
 
+    /// Applies a batch of catalog-only DDL operations atomically. The catalog changes
+    /// are committed in a single transaction by the catalog manager; this only takes
+    /// care of id assignment, connector validation and the source manager bookkeeping
+    /// around the commit.
+    async fn run_ddl_batch(
+        &self,
+        mut operations: Vec<DdlBatchOperation>,
+    ) -> MetaResult<NotificationVersion> {
+        if operations.is_empty() {
+            bail!("DDL batch is empty");
+        }
+        for operation in &operations {
+            if let DdlBatchOperation::CreateSource(source) = operation {
+                let secret_ref_keys = source.secret_refs.keys().map(|k| k.as_str()).collect();
+                validate_connector_properties(&source.with_properties, &secret_ref_keys)?;
+            }
+        }
+
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                for operation in &mut operations {
+                    match operation {
+                        DdlBatchOperation::CreateSource(source) => {
+                            source.id = self.gen_unique_id::<{ IdCategory::Table }>().await?;
+                            source.initialized_at_epoch = Some(Epoch::now().0);
+                            source.initialized_at_cluster_version =
+                                Some(current_cluster_version());
+                        }
+                        DdlBatchOperation::CreateView(view) => {
+                            view.id = self.gen_unique_id::<{ IdCategory::Table }>().await?;
+                        }
+                        DdlBatchOperation::DropSource(_) | DdlBatchOperation::DropView(_) => {}
+                    }
+                }
+
+                // Register the connector workers of the new sources before the commit,
+                // mirroring `create_source`; they are unregistered again if the batch
+                // fails to commit.
+                let mut registered_source_ids = vec![];
+                for operation in &operations {
+                    if let DdlBatchOperation::CreateSource(source) = operation {
+                        if let Err(e) = self.source_manager.register_source(source).await {
+                            self.source_manager
+                                .unregister_sources(registered_source_ids)
+                                .await;
+                            return Err(e);
+                        }
+                        registered_source_ids.push(source.id);
+                    }
+                }
+
+                match mgr.catalog_manager.run_ddl_batch(&operations).await {
+                    Ok((version, dropped_source_ids)) => {
+                        self.source_manager
+                            .unregister_sources(dropped_source_ids)
+                            .await;
+                        Ok(version)
+                    }
+                    Err(e) => {
+                        self.source_manager
+                            .unregister_sources(registered_source_ids)
+                            .await;
+                        Err(e)
+                    }
+                }
+            }
+            MetadataManager::V2(mgr) => {
+                let (version, dropped_source_ids) = mgr
+                    .catalog_controller
+                    .run_ddl_batch(operations, self.source_manager.clone())
+                    .await?;
+                self.source_manager
+                    .unregister_sources(dropped_source_ids.into_iter().map(|id| id as _).collect())
+                    .await;
+                Ok(version)
+            }
+        }
+    }
+
     async fn create_connection(
         &self,
         mut connection: Connection,
@@ -620,10 +1245,8 @@ impl DdlController {
         }
     }
 
-    async fn create_secret(&self, mut secret: Secret) -> MetaResult<NotificationVersion> {
-        // The 'secret' part of the request we receive from the frontend is in plaintext;
-        // here, we need to encrypt it before storing it in the catalog.
-        let secret_plain_payload = secret.value.clone();
+    /// Encrypts a plaintext secret payload with the configured secret store private key.
+    fn encrypt_secret_payload(&self, secret_name: &str, plain_payload: &[u8]) -> MetaResult<Vec<u8>> {
         let secret_store_private_key = self
             .env
             .opts
@@ -631,17 +1254,19 @@ impl DdlController {
             .clone()
             .ok_or_else(|| anyhow!("secret_store_private_key is not configured"))?;
 
-        let encrypted_payload = {
-            let encrypted_secret = SecretEncryption::encrypt(
-                secret_store_private_key.as_slice(),
-                secret.get_value().as_slice(),
-            )
-            .context(format!("failed to encrypt secret {}", secret.name))?;
-            encrypted_secret
-                .serialize()
-                .context(format!("failed to serialize secret {}", secret.name))?
-        };
-        secret.value = encrypted_payload;
+        let encrypted_secret =
+            SecretEncryption::encrypt(secret_store_private_key.as_slice(), plain_payload)
+                .context(format!("failed to encrypt secret {}", secret_name))?;
+        Ok(encrypted_secret
+            .serialize()
+            .context(format!("failed to serialize secret {}", secret_name))?)
+    }
+
+    async fn create_secret(&self, mut secret: Secret) -> MetaResult<NotificationVersion> {
+        // The 'secret' part of the request we receive from the frontend is in plaintext;
+        // here, we need to encrypt it before storing it in the catalog.
+        let secret_plain_payload = secret.value.clone();
+        secret.value = self.encrypt_secret_payload(&secret.name, &secret_plain_payload)?;
 
         match &self.metadata_manager {
             MetadataManager::V1(mgr) => {
@@ -665,6 +1290,31 @@ impl DdlController {
         }
     }
 
+    async fn rotate_secret(
+        &self,
+        secret_id: SecretId,
+        secret_plain_payload: Vec<u8>,
+    ) -> MetaResult<NotificationVersion> {
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                let secret_name = mgr.catalog_manager.get_secret_name(secret_id).await?;
+                let encrypted_payload =
+                    self.encrypt_secret_payload(&secret_name, &secret_plain_payload)?;
+                mgr.catalog_manager
+                    .rotate_secret(secret_id, encrypted_payload, secret_plain_payload)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                let secret = mgr.catalog_controller.get_secret_by_id(secret_id as _).await?;
+                let encrypted_payload =
+                    self.encrypt_secret_payload(&secret.name, &secret_plain_payload)?;
+                mgr.catalog_controller
+                    .rotate_secret(secret_id as _, encrypted_payload, secret_plain_payload)
+                    .await
+            }
+        }
+    }
+
     pub(crate) async fn delete_vpc_endpoint(&self, connection: &Connection) -> MetaResult<()> {
         // delete AWS vpc endpoint
         if let Some(connection::Info::PrivateLinkService(svc)) = &connection.info
@@ -814,6 +1464,18 @@ impl DdlController {
         create_type: CreateType,
         affected_table_replace_info: Option<ReplaceTableInfo>,
     ) -> MetaResult<NotificationVersion> {
+        match &stream_job {
+            StreamingJob::Sink(sink, _) => {
+                let secret_ref_keys = sink.secret_refs.keys().map(|k| k.as_str()).collect();
+                validate_connector_properties(&sink.properties, &secret_ref_keys)?;
+            }
+            StreamingJob::Table(Some(src), _, _) => {
+                let secret_ref_keys = src.secret_refs.keys().map(|k| k.as_str()).collect();
+                validate_connector_properties(&src.with_properties, &secret_ref_keys)?;
+            }
+            _ => {}
+        }
+
         let MetadataManager::V1(mgr) = &self.metadata_manager else {
             return self
                 .create_streaming_job_v2(stream_job, fragment_graph, affected_table_replace_info)
@@ -840,9 +1502,31 @@ impl DdlController {
                     });
                 }
             }
+            StreamingJob::Sink(sink, _) => {
+                // Export the sink schema before the catalog is persisted, so the publish
+                // status is recorded along with the sink.
+                if let Some(status) = publish_sink_schema(sink).await {
+                    sink.schema_registry_publish_status = status as i32;
+                }
+            }
             _ => {}
         }
 
+        // Run the lint pass over the fragment graph and persist any findings with the
+        // created object, so that risks can be reviewed after the fact.
+        let lints = lint_fragment_graph(&fragment_graph);
+        if !lints.is_empty() {
+            for lint in &lints {
+                tracing::info!(id, code = %lint.code, "ddl lint: {}", lint.message);
+            }
+            ObjectLints {
+                relation_id: id,
+                lints,
+            }
+            .insert(self.env.meta_store().as_kv())
+            .await?;
+        }
+
         tracing::debug!(
             id = stream_job.id(),
             definition = stream_job.definition(),
@@ -872,7 +1556,11 @@ impl DdlController {
 
         // 3. Persist tables.
         mgr.catalog_manager
-            .start_create_stream_job_procedure(&stream_job, internal_tables.clone())
+            .start_create_stream_job_procedure(
+                &stream_job,
+                internal_tables.clone(),
+                &mgr.fragment_manager,
+            )
             .await?;
         let affected_table_replace_info = match affected_table_replace_info {
             Some(replace_table_info) => {
@@ -1370,10 +2058,22 @@ impl DdlController {
         drop_mode: DropMode,
         target_replace_info: Option<ReplaceTableInfo>,
     ) -> MetaResult<NotificationVersion> {
+        if let DropMode::SoftDelete = drop_mode {
+            return self.soft_drop_streaming_job(job_id).await;
+        }
         match &self.metadata_manager {
             MetadataManager::V1(_) => {
-                self.drop_streaming_job_v1(job_id, drop_mode, target_replace_info)
-                    .await
+                let relation_id = job_id.id();
+                let version = self
+                    .drop_streaming_job_v1(job_id, drop_mode, target_replace_info)
+                    .await?;
+                // Best-effort cleanup of the lint findings persisted at creation.
+                if let Err(e) =
+                    ObjectLints::delete(self.env.meta_store().as_kv(), &relation_id).await
+                {
+                    tracing::warn!(error = %e.as_report(), relation_id, "failed to clean up object lints");
+                }
+                Ok(version)
             }
             MetadataManager::V2(_) => {
                 let (object_id, object_type) = match job_id {
@@ -1392,6 +2092,80 @@ impl DdlController {
         }
     }
 
+    /// Soft-drops a streaming job: the relation is hidden from frontends while its catalog
+    /// and fragments are kept, so that it stays restorable via [`Self::undrop_relation`]
+    /// until the retention window (`soft_drop_retention_sec`) elapses.
+    async fn soft_drop_streaming_job(
+        &self,
+        job_id: StreamingJobId,
+    ) -> MetaResult<NotificationVersion> {
+        let table_id = match job_id {
+            StreamingJobId::MaterializedView(table_id) | StreamingJobId::Table(None, table_id) => {
+                table_id
+            }
+            StreamingJobId::Table(Some(_), _)
+            | StreamingJobId::Sink(_)
+            | StreamingJobId::Index(_) => {
+                bail!("soft drop is only supported for tables and materialized views")
+            }
+        };
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.soft_drop_table(table_id).await,
+            MetadataManager::V2(mgr) => mgr.catalog_controller.soft_drop_table(table_id as _).await,
+        }
+    }
+
+    /// Restores a soft-dropped relation, making it visible to frontends again.
+    async fn undrop_relation(
+        &self,
+        relation: undrop_relation_request::Relation,
+    ) -> MetaResult<NotificationVersion> {
+        match relation {
+            undrop_relation_request::Relation::TableId(table_id) => {
+                match &self.metadata_manager {
+                    MetadataManager::V1(mgr) => mgr.catalog_manager.undrop_table(table_id).await,
+                    MetadataManager::V2(mgr) => {
+                        mgr.catalog_controller.undrop_table(table_id as _).await
+                    }
+                }
+            }
+        }
+    }
+
+    /// Drops soft-dropped relations whose retention window has elapsed for real.
+    async fn purge_expired_soft_dropped(&self) -> MetaResult<()> {
+        let retention_ms = self.env.opts.soft_drop_retention_sec * 1000;
+        let cutoff_ms = Epoch::physical_now().saturating_sub(retention_ms);
+        let table_ids = match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .list_expired_soft_dropped_tables(cutoff_ms)
+                    .await
+            }
+            MetadataManager::V2(mgr) => mgr
+                .catalog_controller
+                .list_expired_soft_dropped_tables(cutoff_ms)
+                .await?
+                .into_iter()
+                .map(|table_id| table_id as u32)
+                .collect(),
+        };
+        for table_id in table_ids {
+            tracing::info!(table_id, "dropping soft-dropped table whose retention elapsed");
+            if let Err(err) = self
+                .drop_streaming_job(
+                    StreamingJobId::MaterializedView(table_id),
+                    DropMode::Cascade,
+                    None,
+                )
+                .await
+            {
+                tracing::warn!(error = %err.as_report(), table_id, "failed to drop expired soft-dropped table");
+            }
+        }
+        Ok(())
+    }
+
     async fn drop_streaming_job_v1(
         &self,
         job_id: StreamingJobId,
@@ -1861,7 +2635,82 @@ impl DdlController {
         }
     }
 
+    /// Acquires an exclusive lease for altering the given table, so that concurrent `ALTER`s
+    /// from different frontends fail fast before expensive planning instead of racing until
+    /// `start_replace_table_procedure` rejects one of them late. The returned token must be
+    /// presented in `ReplaceTablePlan` and stays valid for [`ALTER_LEASE_TTL`], or until the
+    /// replace procedure presenting it completes. Pass `force` to take over an unexpired
+    /// lease, e.g. when its holder is known to have died.
+    pub fn acquire_alter_lease(&self, table_id: u32, force: bool) -> MetaResult<(u64, Duration)> {
+        let mut leases = self.alter_leases.lock();
+        if !force
+            && let Some(lease) = leases.get(&table_id)
+            && lease.expires_at > Instant::now()
+        {
+            return Err(MetaError::permission_denied(format!(
+                "another ALTER on table {table_id} is in progress, retry later or acquire the lease with force to take it over",
+            )));
+        }
+        let token = rand::thread_rng().gen_range(1..u64::MAX);
+        leases.insert(
+            table_id,
+            AlterLease {
+                token,
+                expires_at: Instant::now() + ALTER_LEASE_TTL,
+            },
+        );
+        Ok((token, ALTER_LEASE_TTL))
+    }
+
+    /// Checks that the given token still holds the alter lease on the table. A zero token is
+    /// accepted as long as no other session holds a lease, for callers that replace tables
+    /// without acquiring one, e.g. sink-into-table.
+    fn validate_alter_lease(&self, table_id: u32, token: u64) -> MetaResult<()> {
+        let mut leases = self.alter_leases.lock();
+        if let Some(lease) = leases.get(&table_id)
+            && lease.expires_at <= Instant::now()
+        {
+            leases.remove(&table_id);
+        }
+        match leases.get(&table_id) {
+            Some(lease) if lease.token == token => Ok(()),
+            Some(_) => Err(MetaError::permission_denied(format!(
+                "the alter lease on table {table_id} is held by another session",
+            ))),
+            None if token == 0 => Ok(()),
+            None => Err(MetaError::permission_denied(format!(
+                "the alter lease on table {table_id} has expired or been taken over, acquire a new one",
+            ))),
+        }
+    }
+
+    /// Releases the alter lease on the table if it is still held by the given token.
+    fn release_alter_lease(&self, table_id: u32, token: u64) {
+        let mut leases = self.alter_leases.lock();
+        if let Some(lease) = leases.get(&table_id)
+            && lease.token == token
+        {
+            leases.remove(&table_id);
+        }
+    }
+
     async fn replace_table(
+        &self,
+        stream_job: StreamingJob,
+        fragment_graph: StreamFragmentGraphProto,
+        table_col_index_mapping: Option<ColIndexMapping>,
+        alter_lease_token: u64,
+    ) -> MetaResult<NotificationVersion> {
+        let table_id = stream_job.id();
+        self.validate_alter_lease(table_id, alter_lease_token)?;
+        let result = self
+            .replace_table_inner(stream_job, fragment_graph, table_col_index_mapping)
+            .await;
+        self.release_alter_lease(table_id, alter_lease_token);
+        result
+    }
+
+    async fn replace_table_inner(
         &self,
         mut stream_job: StreamingJob,
         fragment_graph: StreamFragmentGraphProto,
@@ -1953,6 +2802,10 @@ impl DdlController {
                 if sink.original_target_columns.is_empty() {
                     updated_sink_catalogs.push(sink.id);
                 }
+
+                // The sink follows the target table's schema, which is being replaced;
+                // re-export it to the schema registry if publishing is enabled.
+                let _ = publish_sink_schema(&sink).await;
             }
 
             // Add table fragments to meta store with state: `State::Initial`.
@@ -2198,9 +3051,22 @@ impl DdlController {
         match &self.metadata_manager {
             MetadataManager::V1(mgr) => match relation {
                 alter_name_request::Object::TableId(table_id) => {
-                    mgr.catalog_manager
-                        .alter_table_name(table_id, new_name)
+                    // Renaming a materialized view also cascades into its indexes.
+                    let is_mv = mgr
+                        .catalog_manager
+                        .get_tables(&[table_id])
                         .await
+                        .first()
+                        .is_some_and(|table| {
+                            table.table_type == PbTableType::MaterializedView as i32
+                        });
+                    if is_mv {
+                        mgr.catalog_manager.alter_mv_name(table_id, new_name).await
+                    } else {
+                        mgr.catalog_manager
+                            .alter_table_name(table_id, new_name)
+                            .await
+                    }
                 }
                 alter_name_request::Object::ViewId(view_id) => {
                     mgr.catalog_manager.alter_view_name(view_id, new_name).await
@@ -2233,6 +3099,11 @@ impl DdlController {
                         .alter_subscription_name(subscription_id, new_name)
                         .await
                 }
+                alter_name_request::Object::FunctionId(function_id) => {
+                    mgr.catalog_manager
+                        .alter_function_name(function_id, new_name)
+                        .await
+                }
             },
             MetadataManager::V2(mgr) => {
                 let (obj_type, id) = match relation {
@@ -2252,6 +3123,9 @@ impl DdlController {
                     alter_name_request::Object::SubscriptionId(id) => {
                         (ObjectType::Subscription, id as ObjectId)
                     }
+                    alter_name_request::Object::FunctionId(id) => {
+                        (ObjectType::Function, id as ObjectId)
+                    }
                 };
                 mgr.catalog_controller
                     .alter_name(obj_type, id, new_name)
@@ -2280,6 +3154,9 @@ impl DdlController {
                     Object::SchemaId(id) => (ObjectType::Schema, id as ObjectId),
                     Object::DatabaseId(id) => (ObjectType::Database, id as ObjectId),
                     Object::SubscriptionId(id) => (ObjectType::Subscription, id as ObjectId),
+                    Object::FunctionId(id) => (ObjectType::Function, id as ObjectId),
+                    Object::ConnectionId(id) => (ObjectType::Connection, id as ObjectId),
+                    Object::SecretId(id) => (ObjectType::Secret, id as ObjectId),
                 };
                 mgr.catalog_controller
                     .alter_owner(obj_type, id, owner_id as _)
@@ -2288,6 +3165,25 @@ impl DdlController {
         }
     }
 
+    async fn alter_schema_default_owner(
+        &self,
+        schema_id: SchemaId,
+        default_owner_id: Option<UserId>,
+    ) -> MetaResult<NotificationVersion> {
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .alter_schema_default_owner(schema_id, default_owner_id)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .alter_schema_default_owner(schema_id as _, default_owner_id.map(|id| id as _))
+                    .await
+            }
+        }
+    }
+
     async fn alter_set_schema(
         &self,
         object: alter_set_schema_request::Object,
@@ -2369,6 +3265,190 @@ impl DdlController {
             MetadataManager::V2(mgr) => mgr.catalog_controller.comment_on(comment).await,
         }
     }
+
+    async fn alter_annotation(&self, annotation: Annotation) -> MetaResult<NotificationVersion> {
+        if annotation.key.is_empty() || annotation.key.len() > MAX_ANNOTATION_KEY_LEN {
+            bail!(
+                "annotation key must be non-empty and at most {} bytes",
+                MAX_ANNOTATION_KEY_LEN
+            );
+        }
+        if let Some(value) = &annotation.value
+            && value.len() > MAX_ANNOTATION_VALUE_LEN
+        {
+            bail!(
+                "annotation value must be at most {} bytes",
+                MAX_ANNOTATION_VALUE_LEN
+            );
+        }
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.alter_annotation(annotation).await,
+            MetadataManager::V2(mgr) => mgr.catalog_controller.alter_annotation(annotation).await,
+        }
+    }
+
+    async fn alter_label(&self, label: Label) -> MetaResult<NotificationVersion> {
+        if label.key.is_empty() || label.key.len() > MAX_LABEL_LEN {
+            bail!(
+                "label key must be non-empty and at most {} bytes",
+                MAX_LABEL_LEN
+            );
+        }
+        if let Some(value) = &label.value
+            && value.len() > MAX_LABEL_LEN
+        {
+            bail!("label value must be at most {} bytes", MAX_LABEL_LEN);
+        }
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => mgr.catalog_manager.alter_label(label).await,
+            MetadataManager::V2(mgr) => mgr.catalog_controller.alter_label(label).await,
+        }
+    }
+
+    async fn alter_storage_class_policy(
+        &self,
+        table_id: u32,
+        policy: Option<PbStorageClassPolicy>,
+    ) -> MetaResult<NotificationVersion> {
+        if let Some(policy) = &policy {
+            if policy.warm_after_sec == 0 && policy.cold_after_sec == 0 {
+                bail!("at least one of warm_after_sec and cold_after_sec must be positive");
+            }
+            if policy.warm_after_sec > 0
+                && policy.cold_after_sec > 0
+                && policy.cold_after_sec <= policy.warm_after_sec
+            {
+                bail!("cold_after_sec must be greater than warm_after_sec");
+            }
+        }
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .alter_storage_class_policy(table_id, policy)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .alter_storage_class_policy(table_id as _, policy)
+                    .await
+            }
+        }
+    }
+
+    async fn alter_schema_change_policy(
+        &self,
+        table_id: u32,
+        policy: PbSchemaChangePolicy,
+    ) -> MetaResult<NotificationVersion> {
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .alter_schema_change_policy(table_id, policy)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .alter_schema_change_policy(table_id as _, policy)
+                    .await
+            }
+        }
+    }
+
+    async fn alter_stream_job_priority(
+        &self,
+        table_id: u32,
+        priority: PbStreamJobPriority,
+    ) -> MetaResult<NotificationVersion> {
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .alter_stream_job_priority(table_id, priority)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .alter_stream_job_priority(table_id as _, priority)
+                    .await
+            }
+        }
+    }
+
+    async fn alter_table_ttl(
+        &self,
+        table_id: u32,
+        retention_seconds: Option<u32>,
+    ) -> MetaResult<NotificationVersion> {
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .alter_table_ttl(table_id, retention_seconds)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .alter_table_ttl(table_id as _, retention_seconds)
+                    .await
+            }
+        }
+    }
+
+    async fn alter_database_barrier_interval(
+        &self,
+        database_id: DatabaseId,
+        barrier_interval_ms: Option<u32>,
+    ) -> MetaResult<NotificationVersion> {
+        if let Some(interval) = barrier_interval_ms
+            && interval == 0
+        {
+            bail!("barrier interval must be positive");
+        }
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .alter_database_barrier_interval(database_id, barrier_interval_ms)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .alter_database_barrier_interval(database_id as _, barrier_interval_ms)
+                    .await
+            }
+        }
+    }
+
+    async fn alter_database_session_default(
+        &self,
+        database_id: DatabaseId,
+        name: String,
+        value: Option<String>,
+    ) -> MetaResult<NotificationVersion> {
+        let name = name.to_lowercase();
+        match name.as_str() {
+            "timezone" => {
+                if let Some(value) = &value {
+                    // Reuse the session parameter check so an invalid timezone is rejected
+                    // up front instead of being warned about on every connect.
+                    SessionConfig::default()
+                        .set("timezone", value.clone(), &mut ())
+                        .map_err(|e| anyhow!(e))?;
+                }
+            }
+            "collation" => {}
+            _ => bail!("unsupported database session default: {}", name),
+        }
+        match &self.metadata_manager {
+            MetadataManager::V1(mgr) => {
+                mgr.catalog_manager
+                    .alter_database_session_default(database_id, &name, value)
+                    .await
+            }
+            MetadataManager::V2(mgr) => {
+                mgr.catalog_controller
+                    .alter_database_session_default(database_id as _, &name, value)
+                    .await
+            }
+        }
+    }
 }
 
 /// Fill in necessary information for `Table` stream graph.
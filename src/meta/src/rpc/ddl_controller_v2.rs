@@ -15,6 +15,7 @@
 use itertools::Itertools;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_common::util::stream_graph_visitor::{visit_fragment, visit_stream_node};
+use risingwave_connector::sink::schema_publish::publish_sink_schema;
 use risingwave_meta_model_v2::object::ObjectType;
 use risingwave_meta_model_v2::ObjectId;
 use risingwave_pb::catalog::CreateType;
@@ -65,6 +66,12 @@ impl DdlController {
                     });
                 }
             }
+            StreamingJob::Sink(sink, _) => {
+                // Export the sink schema to the external schema registry, if enabled.
+                if let Some(status) = publish_sink_schema(sink).await {
+                    sink.schema_registry_publish_status = status as i32;
+                }
+            }
             _ => {}
         }
 
@@ -507,6 +514,10 @@ impl DdlController {
                 if sink.original_target_columns.is_empty() {
                     updated_sink_catalogs.push(sink.id);
                 }
+
+                // The sink follows the target table's schema, which is being replaced;
+                // re-export it to the schema registry if publishing is enabled.
+                let _ = publish_sink_schema(&sink).await;
             }
 
             let merge_updates = ctx.merge_updates.clone();
@@ -0,0 +1,172 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A lightweight lint pass over the stream fragment graph of a streaming job to be
+//! created. The pass flags plans that are valid but risky, e.g. joins whose state is
+//! never cleaned up. Findings are persisted with the created object and can be reviewed
+//! after the fact via the `ListObjectLints` RPC.
+
+use risingwave_pb::ddl_service::object_lint::Severity;
+use risingwave_pb::ddl_service::ObjectLint;
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+use risingwave_pb::stream_plan::{StreamFragmentGraph as StreamFragmentGraphProto, StreamNode};
+
+/// Runs all lint rules against the fragment graph of a streaming job.
+pub fn lint_fragment_graph(fragment_graph: &StreamFragmentGraphProto) -> Vec<ObjectLint> {
+    let mut ctx = LintContext::default();
+    for fragment in fragment_graph.fragments.values() {
+        if let Some(node) = &fragment.node {
+            ctx.visit(node);
+        }
+    }
+    ctx.finish()
+}
+
+#[derive(Default)]
+struct LintContext {
+    has_source: bool,
+    has_watermark_filter: bool,
+    has_time_windowing: bool,
+    lints: Vec<ObjectLint>,
+}
+
+impl LintContext {
+    fn visit(&mut self, node: &StreamNode) {
+        match node.node_body.as_ref() {
+            Some(NodeBody::Source(source)) if source.source_inner.is_some() => {
+                self.has_source = true;
+            }
+            Some(NodeBody::WatermarkFilter(_)) => {
+                self.has_watermark_filter = true;
+            }
+            Some(NodeBody::HopWindow(_)) => {
+                self.has_time_windowing = true;
+            }
+            Some(NodeBody::HashJoin(join)) => {
+                if join.left_key.is_empty() {
+                    self.lint(
+                        Severity::Warning,
+                        "CARTESIAN_PRODUCT",
+                        "the join has no equality condition, so every row of one side is \
+                         matched against every row of the other",
+                    );
+                } else if join.inequality_pairs.is_empty() && !join.is_append_only {
+                    self.lint(
+                        Severity::Notice,
+                        "UNBOUNDED_JOIN_STATE",
+                        "the join has no condition that allows watermark-driven state \
+                         cleaning, so its state tables can grow unboundedly",
+                    );
+                }
+            }
+            _ => {}
+        }
+        for input in &node.input {
+            self.visit(input);
+        }
+    }
+
+    fn lint(&mut self, severity: Severity, code: &str, message: &str) {
+        self.lints.push(ObjectLint {
+            severity: severity as i32,
+            code: code.to_string(),
+            message: message.to_string(),
+        });
+    }
+
+    fn finish(mut self) -> Vec<ObjectLint> {
+        if self.has_source && !self.has_watermark_filter {
+            // Without a watermark the source is still usable, but time-windowed
+            // operators downstream can never emit or clean up their state.
+            let severity = if self.has_time_windowing {
+                Severity::Warning
+            } else {
+                Severity::Notice
+            };
+            self.lint(
+                severity,
+                "MISSING_WATERMARK",
+                "the source has no watermark column; downstream time-windowed operators \
+                 cannot make progress or clean up their state",
+            );
+        }
+        self.lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use risingwave_pb::stream_plan::stream_fragment_graph::StreamFragment;
+    use risingwave_pb::stream_plan::{HashJoinNode, SourceNode, StreamSource};
+
+    use super::*;
+
+    fn graph_of(node_bodies: Vec<NodeBody>) -> StreamFragmentGraphProto {
+        let fragments = node_bodies
+            .into_iter()
+            .enumerate()
+            .map(|(id, node_body)| {
+                (
+                    id as u32,
+                    StreamFragment {
+                        fragment_id: id as u32,
+                        node: Some(StreamNode {
+                            node_body: Some(node_body),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        StreamFragmentGraphProto {
+            fragments,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_lint_fragment_graph() {
+        let source = NodeBody::Source(SourceNode {
+            source_inner: Some(StreamSource::default()),
+        });
+        let cross_join = NodeBody::HashJoin(HashJoinNode::default());
+        let equi_join = NodeBody::HashJoin(HashJoinNode {
+            left_key: vec![0],
+            right_key: vec![0],
+            ..Default::default()
+        });
+
+        let lints = lint_fragment_graph(&graph_of(vec![source.clone()]));
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].code, "MISSING_WATERMARK");
+        assert_eq!(lints[0].severity(), Severity::Notice);
+
+        let lints = lint_fragment_graph(&graph_of(vec![source, cross_join, equi_join]));
+        let mut codes = lints.iter().map(|l| l.code.as_str()).collect::<Vec<_>>();
+        codes.sort_unstable();
+        assert_eq!(
+            codes,
+            vec![
+                "CARTESIAN_PRODUCT",
+                "MISSING_WATERMARK",
+                "UNBOUNDED_JOIN_STATE"
+            ]
+        );
+
+        assert!(lint_fragment_graph(&graph_of(vec![])).is_empty());
+    }
+}
@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
 use futures::Future;
+use parking_lot::Mutex;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tonic::body::BoxBody;
+use tonic::Status;
 use tower::{Layer, Service};
 
 use crate::rpc::metrics::MetaMetrics;
@@ -87,3 +92,219 @@ where
         }
     }
 }
+
+/// The gRPC metadata key that identifies the client for per-client rate limiting. Requests
+/// without this metadata share a single "unknown" budget.
+pub const CLIENT_IDENTITY_KEY: &str = "x-rw-client-identity";
+
+/// Read-heavy catalog RPCs that are subject to per-client rate limiting. Write RPCs are
+/// deliberately excluded so that DDL is never starved by a throttled client.
+const RATE_LIMITED_PATHS: &[&str] = &[
+    "/ddl_service.DdlService/GetTable",
+    "/ddl_service.DdlService/GetTables",
+    "/ddl_service.DdlService/GetDdlProgress",
+    "/ddl_service.DdlService/ListConnections",
+];
+
+/// Client entries idle for longer than this may be evicted. A returning client starts
+/// over with a full token bucket, so eviction can only briefly under-throttle it.
+const CLIENT_IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Cap on tracked client identities. The identity is a caller-supplied header, so the
+/// map must stay bounded even when every request carries a fresh identity.
+const MAX_TRACKED_CLIENTS: usize = 1024;
+
+struct ClientLimit {
+    /// Remaining tokens of the token bucket. Refilled at `rate_limit` per second and capped
+    /// at `rate_limit`, i.e. at most one second of requests can be bursted.
+    tokens: f64,
+    last_refill: Instant,
+    /// When the client was last admitted or rejected, for idle eviction.
+    last_seen: Instant,
+    concurrency: Arc<Semaphore>,
+}
+
+/// Per-client token buckets and concurrency caps for read-heavy catalog RPCs.
+///
+/// The map is bounded at [`MAX_TRACKED_CLIENTS`]: inserting a new identity into a full
+/// map first evicts idle entries, then the least recently seen entry without in-flight
+/// requests. It can only exceed the cap when more than that many clients hold in-flight
+/// requests at once, which the per-client concurrency cap itself keeps small.
+pub struct CatalogRateLimiter {
+    /// Requests per second allowed for each client. 0 disables rate limiting.
+    rate_limit: u64,
+    /// In-flight requests allowed for each client. 0 disables the cap.
+    max_concurrency: u64,
+    clients: Mutex<HashMap<String, ClientLimit>>,
+    metrics: Arc<MetaMetrics>,
+}
+
+impl CatalogRateLimiter {
+    fn enabled(&self) -> bool {
+        self.rate_limit != 0 || self.max_concurrency != 0
+    }
+
+    /// Admits a request, returning the concurrency permit to hold until the response is
+    /// ready, or the `RESOURCE_EXHAUSTED` status to reject the request with.
+    fn try_acquire(
+        &self,
+        req: &http::Request<BoxBody>,
+    ) -> Result<Option<OwnedSemaphorePermit>, Status> {
+        let path = req.uri().path();
+        if !self.enabled() || !RATE_LIMITED_PATHS.contains(&path) {
+            return Ok(None);
+        }
+        let client = req
+            .headers()
+            .get(CLIENT_IDENTITY_KEY)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("unknown");
+
+        let now = Instant::now();
+        let mut clients = self.clients.lock();
+        if !clients.contains_key(client) && clients.len() >= MAX_TRACKED_CLIENTS {
+            Self::evict(&mut clients, now, self.max_concurrency as usize);
+        }
+        let limit = clients
+            .entry(client.to_string())
+            .or_insert_with(|| ClientLimit {
+                tokens: self.rate_limit as f64,
+                last_refill: now,
+                last_seen: now,
+                concurrency: Arc::new(Semaphore::new(self.max_concurrency as usize)),
+            });
+        limit.last_seen = now;
+
+        if self.rate_limit != 0 {
+            let elapsed = now.duration_since(limit.last_refill).as_secs_f64();
+            limit.tokens =
+                (limit.tokens + elapsed * self.rate_limit as f64).min(self.rate_limit as f64);
+            limit.last_refill = now;
+            if limit.tokens < 1.0 {
+                self.metrics
+                    .grpc_rate_limited_count
+                    .with_label_values(&[path, client])
+                    .inc();
+                return Err(Status::resource_exhausted(format!(
+                    "client {client} exceeds the rate limit of {} requests per second on {path}",
+                    self.rate_limit
+                )));
+            }
+            limit.tokens -= 1.0;
+        }
+
+        if self.max_concurrency != 0 {
+            match limit.concurrency.clone().try_acquire_owned() {
+                Ok(permit) => Ok(Some(permit)),
+                Err(_) => {
+                    self.metrics
+                        .grpc_rate_limited_count
+                        .with_label_values(&[path, client])
+                        .inc();
+                    Err(Status::resource_exhausted(format!(
+                        "client {client} exceeds the limit of {} in-flight requests on {path}",
+                        self.max_concurrency
+                    )))
+                }
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Makes room for a new client entry in a full map: drops every entry that has been
+    /// idle for [`CLIENT_IDLE_TIMEOUT`], or the least recently seen entry without
+    /// in-flight requests when none is idle. Entries holding in-flight requests are
+    /// never evicted, so their concurrency caps stay intact.
+    fn evict(clients: &mut HashMap<String, ClientLimit>, now: Instant, max_concurrency: usize) {
+        let no_inflight =
+            |limit: &ClientLimit| limit.concurrency.available_permits() == max_concurrency;
+        let before = clients.len();
+        clients.retain(|_, limit| {
+            now.duration_since(limit.last_seen) < CLIENT_IDLE_TIMEOUT || !no_inflight(limit)
+        });
+        if clients.len() < before {
+            return;
+        }
+        if let Some(lru) = clients
+            .iter()
+            .filter(|(_, limit)| no_inflight(limit))
+            .min_by_key(|(_, limit)| limit.last_seen)
+            .map(|(client, _)| client.clone())
+        {
+            clients.remove(&lru);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CatalogRateLimitLayer {
+    limiter: Arc<CatalogRateLimiter>,
+}
+
+impl CatalogRateLimitLayer {
+    pub fn new(rate_limit: u64, max_concurrency: u64, metrics: Arc<MetaMetrics>) -> Self {
+        Self {
+            limiter: Arc::new(CatalogRateLimiter {
+                rate_limit,
+                max_concurrency,
+                clients: Mutex::new(HashMap::new()),
+                metrics,
+            }),
+        }
+    }
+}
+
+impl<S> Layer<S> for CatalogRateLimitLayer {
+    type Service = CatalogRateLimitMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        CatalogRateLimitMiddleware {
+            inner: service,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CatalogRateLimitMiddleware<S> {
+    inner: S,
+    limiter: Arc<CatalogRateLimiter>,
+}
+
+impl<S> Service<http::Request<BoxBody>> for CatalogRateLimitMiddleware<S>
+where
+    S: Service<http::Request<BoxBody>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Error = S::Error;
+    type Response = S::Response;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<BoxBody>) -> Self::Future {
+        // See `MetricsMiddleware::call` for why the inner service is cloned.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let limiter = self.limiter.clone();
+
+        async move {
+            let permit = match limiter.try_acquire(&req) {
+                Ok(permit) => permit,
+                Err(status) => return Ok(status.into_http()),
+            };
+
+            let response = inner.call(req).await?;
+
+            // Release the concurrency permit once the response is ready.
+            drop(permit);
+
+            Ok(response)
+        }
+    }
+}
@@ -62,6 +62,8 @@ pub struct MetaMetrics {
     // ********************************** gRPC ************************************
     /// gRPC latency of meta services
     pub grpc_latency: HistogramVec,
+    /// gRPC request count rejected by per-client rate limiting, with path and client label
+    pub grpc_rate_limited_count: IntCounterVec,
 
     // ********************************** Barrier ************************************
     /// The duration from barrier injection to commit
@@ -135,6 +137,8 @@ pub struct MetaMetrics {
     pub version_stats: IntGaugeVec,
     /// Hummock version stats
     pub materialized_view_stats: IntGaugeVec,
+    /// Per-table bytes by archival tier, according to the table's storage class policy
+    pub table_storage_class_bytes: IntGaugeVec,
     /// Total number of objects that is no longer referenced by versions.
     pub stale_object_count: IntGauge,
     /// Total size of objects that is no longer referenced by versions.
@@ -196,6 +200,14 @@ pub struct MetaMetrics {
     /// A dummy gauge metrics with its label to be the mapping from actor id to sink id
     pub sink_info: IntGaugeVec,
 
+    // ********************************** Catalog ************************************
+    /// Encoded size of the in-memory catalog, per database and object type.
+    pub catalog_memory_bytes: IntGaugeVec,
+    /// Number of cached catalog objects, per database and object type.
+    pub catalog_object_count: IntGaugeVec,
+    /// Time spent waiting for the catalog core lock.
+    pub catalog_lock_wait_latency: Histogram,
+
     /// Write throughput of commit epoch for each stable
     pub table_write_throughput: IntCounterVec,
 
@@ -218,6 +230,14 @@ impl MetaMetrics {
         let grpc_latency =
             register_histogram_vec_with_registry!(opts, &["path"], registry).unwrap();
 
+        let grpc_rate_limited_count = register_int_counter_vec_with_registry!(
+            "meta_grpc_rate_limited_count",
+            "The number of gRPC requests rejected by per-client rate limiting",
+            &["path", "client"],
+            registry
+        )
+        .unwrap();
+
         let opts = histogram_opts!(
             "meta_barrier_duration_seconds",
             "barrier latency",
@@ -443,6 +463,14 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let table_storage_class_bytes = register_int_gauge_vec_with_registry!(
+            "storage_table_storage_class_bytes",
+            "per table bytes by archival tier in current hummock version",
+            &["table_id", "tier"],
+            registry
+        )
+        .unwrap();
+
         let stale_object_count = register_int_gauge_with_registry!(
             "storage_stale_object_count",
             "total number of objects that is no longer referenced by versions.",
@@ -649,6 +677,29 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let catalog_memory_bytes = register_int_gauge_vec_with_registry!(
+            "catalog_memory_bytes",
+            "Encoded size of the in-memory catalog, per database and object type",
+            &["database_id", "object_type"],
+            registry
+        )
+        .unwrap();
+
+        let catalog_object_count = register_int_gauge_vec_with_registry!(
+            "catalog_object_count",
+            "Number of cached catalog objects, per database and object type",
+            &["database_id", "object_type"],
+            registry
+        )
+        .unwrap();
+
+        let opts = histogram_opts!(
+            "meta_catalog_lock_wait_duration_seconds",
+            "Time spent waiting for the catalog core lock",
+            exponential_buckets(0.001, 2.0, 16).unwrap() // max 32s
+        );
+        let catalog_lock_wait_latency = register_histogram_with_registry!(opts, registry).unwrap();
+
         let l0_compact_level_count = register_histogram_vec_with_registry!(
             "storage_l0_compact_level_count",
             "level_count of l0 compact task",
@@ -731,6 +782,7 @@ impl MetaMetrics {
 
         Self {
             grpc_latency,
+            grpc_rate_limited_count,
             barrier_latency,
             barrier_wait_commit_latency,
             barrier_send_latency,
@@ -756,6 +808,7 @@ impl MetaMetrics {
             version_size,
             version_stats,
             materialized_view_stats,
+            table_storage_class_bytes,
             stale_object_count,
             stale_object_size,
             old_version_object_count,
@@ -789,6 +842,9 @@ impl MetaMetrics {
             actor_info,
             table_info,
             sink_info,
+            catalog_memory_bytes,
+            catalog_object_count,
+            catalog_lock_wait_latency,
             l0_compact_level_count,
             compact_task_size,
             compact_task_file_count,
@@ -874,6 +930,68 @@ pub fn start_worker_info_monitor(
     (join_handle, shutdown_tx)
 }
 
+/// Periodically refreshes the catalog memory accounting metrics and warns when the
+/// total size exceeds the configured soft limit. Only effective with the etcd meta
+/// backend, where the full catalog is cached in memory on the meta node.
+pub fn start_catalog_memory_monitor(
+    metadata_manager: MetadataManager,
+    meta_metrics: Arc<MetaMetrics>,
+    soft_limit_bytes: u64,
+) -> (JoinHandle<()>, Sender<()>) {
+    const COLLECT_INTERVAL_SECONDS: u64 = 60;
+
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    let join_handle = tokio::spawn(async move {
+        let mut monitor_interval =
+            tokio::time::interval(Duration::from_secs(COLLECT_INTERVAL_SECONDS));
+        monitor_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = monitor_interval.tick() => {},
+                // Shutdown monitor
+                _ = &mut shutdown_rx => {
+                    tracing::info!("Catalog memory monitor is stopped");
+                    return;
+                }
+            }
+
+            let MetadataManager::V1(mgr) = &metadata_manager else {
+                // The SQL meta backend does not keep the catalog in memory.
+                continue;
+            };
+            let stats = mgr.catalog_manager.catalog_memory_stats().await;
+
+            // Reset metrics to clean the labels of dropped databases.
+            meta_metrics.catalog_memory_bytes.reset();
+            meta_metrics.catalog_object_count.reset();
+            let mut total_bytes = 0;
+            for stat in stats {
+                let database_id_str = stat.database_id.to_string();
+                meta_metrics
+                    .catalog_memory_bytes
+                    .with_label_values(&[&database_id_str, &stat.object_type])
+                    .set(stat.total_bytes as i64);
+                meta_metrics
+                    .catalog_object_count
+                    .with_label_values(&[&database_id_str, &stat.object_type])
+                    .set(stat.object_count as i64);
+                total_bytes += stat.total_bytes;
+            }
+            if soft_limit_bytes != 0 && total_bytes > soft_limit_bytes {
+                tracing::warn!(
+                    total_bytes,
+                    soft_limit_bytes,
+                    "in-memory catalog exceeds the configured soft limit, \
+                    consider dropping unused relations"
+                );
+            }
+        }
+    });
+
+    (join_handle, shutdown_tx)
+}
+
 pub async fn refresh_fragment_info_metrics_v2(
     catalog_controller: &CatalogControllerRef,
     cluster_controller: &ClusterControllerRef,
@@ -15,9 +15,11 @@
 pub mod cloud_provider;
 pub mod ddl_controller;
 mod ddl_controller_v2;
+pub mod ddl_lint;
 pub mod election;
 pub mod intercept;
 pub mod metrics;
+pub mod property_schema;
 
 pub type ElectionClientRef = std::sync::Arc<dyn ElectionClient>;
 
@@ -0,0 +1,448 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed property schemas for the common connectors, consulted during source/sink
+//! creation so that property typos are rejected on the meta node instead of surfacing
+//! as cryptic errors on compute nodes later.
+//!
+//! The schemas are intentionally not exhaustive: they cover the required keys and the
+//! frequently mistyped optional keys of each connector. Keys not covered by a schema
+//! are passed through untouched, unless they closely resemble a schema key, in which
+//! case they are rejected as a likely typo with a suggestion.
+
+use std::collections::{HashMap, HashSet};
+
+use risingwave_connector::source::UPSTREAM_SOURCE_KEY;
+use risingwave_pb::ddl_service::list_connector_property_schemas_response::{
+    ConnectorSchema as PbConnectorSchema, PropertySchema as PbPropertySchema,
+    PropertyType as PbPropertyType,
+};
+
+use crate::{MetaError, MetaResult};
+
+/// The expected type of a property value.
+#[derive(Clone, Copy, Debug)]
+pub enum PropertyType {
+    String,
+    Int,
+    Bool,
+    Duration,
+    Enum(&'static [&'static str]),
+}
+
+pub struct PropertySchema {
+    pub name: &'static str,
+    pub ty: PropertyType,
+    pub required: bool,
+    /// Whether the property may reference a secret instead of a plain value.
+    pub secret_allowed: bool,
+}
+
+pub struct ConnectorSchema {
+    pub connector: &'static str,
+    pub properties: &'static [PropertySchema],
+}
+
+const fn prop(name: &'static str, ty: PropertyType, required: bool) -> PropertySchema {
+    PropertySchema {
+        name,
+        ty,
+        required,
+        secret_allowed: false,
+    }
+}
+
+const fn secret_prop(name: &'static str, ty: PropertyType, required: bool) -> PropertySchema {
+    PropertySchema {
+        name,
+        ty,
+        required,
+        secret_allowed: true,
+    }
+}
+
+pub static CONNECTOR_SCHEMAS: &[ConnectorSchema] = &[
+    ConnectorSchema {
+        connector: "kafka",
+        properties: &[
+            prop("properties.bootstrap.server", PropertyType::String, true),
+            prop("topic", PropertyType::String, true),
+            prop(
+                "properties.security.protocol",
+                PropertyType::Enum(&["plaintext", "ssl", "sasl_plaintext", "sasl_ssl"]),
+                false,
+            ),
+            prop(
+                "properties.sasl.mechanism",
+                PropertyType::Enum(&[
+                    "PLAIN",
+                    "SCRAM-SHA-256",
+                    "SCRAM-SHA-512",
+                    "GSSAPI",
+                    "OAUTHBEARER",
+                ]),
+                false,
+            ),
+            secret_prop("properties.sasl.username", PropertyType::String, false),
+            secret_prop("properties.sasl.password", PropertyType::String, false),
+            prop(
+                "scan.startup.mode",
+                PropertyType::Enum(&["earliest", "latest", "timestamp"]),
+                false,
+            ),
+            prop("scan.startup.timestamp.millis", PropertyType::Int, false),
+            prop("properties.fetch.max.bytes", PropertyType::Int, false),
+            prop(
+                "properties.receive.message.max.bytes",
+                PropertyType::Int,
+                false,
+            ),
+        ],
+    },
+    ConnectorSchema {
+        connector: "pulsar",
+        properties: &[
+            prop("topic", PropertyType::String, true),
+            prop("service.url", PropertyType::String, true),
+            prop(
+                "scan.startup.mode",
+                PropertyType::Enum(&["earliest", "latest"]),
+                false,
+            ),
+            secret_prop("auth.token", PropertyType::String, false),
+        ],
+    },
+    ConnectorSchema {
+        connector: "kinesis",
+        properties: &[
+            prop("stream", PropertyType::String, true),
+            prop("aws.region", PropertyType::String, true),
+            prop("endpoint", PropertyType::String, false),
+            secret_prop(
+                "aws.credentials.access_key_id",
+                PropertyType::String,
+                false,
+            ),
+            secret_prop(
+                "aws.credentials.secret_access_key",
+                PropertyType::String,
+                false,
+            ),
+            prop(
+                "scan.startup.mode",
+                PropertyType::Enum(&["earliest", "latest"]),
+                false,
+            ),
+        ],
+    },
+    ConnectorSchema {
+        connector: "mysql-cdc",
+        properties: &[
+            prop("hostname", PropertyType::String, true),
+            prop("port", PropertyType::Int, true),
+            secret_prop("username", PropertyType::String, true),
+            secret_prop("password", PropertyType::String, true),
+            prop("database.name", PropertyType::String, true),
+            prop("table.name", PropertyType::String, false),
+            prop("server.id", PropertyType::Int, false),
+        ],
+    },
+    ConnectorSchema {
+        connector: "postgres-cdc",
+        properties: &[
+            prop("hostname", PropertyType::String, true),
+            prop("port", PropertyType::Int, true),
+            secret_prop("username", PropertyType::String, true),
+            secret_prop("password", PropertyType::String, true),
+            prop("database.name", PropertyType::String, true),
+            prop("schema.name", PropertyType::String, false),
+            prop("table.name", PropertyType::String, false),
+            prop("slot.name", PropertyType::String, false),
+        ],
+    },
+    ConnectorSchema {
+        connector: "jdbc",
+        properties: &[
+            secret_prop("jdbc.url", PropertyType::String, true),
+            prop("table.name", PropertyType::String, true),
+            prop(
+                "type",
+                PropertyType::Enum(&["append-only", "upsert"]),
+                false,
+            ),
+        ],
+    },
+];
+
+impl PropertyType {
+    fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            PropertyType::String => Ok(()),
+            PropertyType::Int => value
+                .parse::<i64>()
+                .map(|_| ())
+                .map_err(|_| "an integer".to_string()),
+            PropertyType::Bool => value
+                .parse::<bool>()
+                .map(|_| ())
+                .map_err(|_| "a boolean".to_string()),
+            PropertyType::Duration => {
+                let stripped = value
+                    .trim()
+                    .trim_end_matches(|c: char| c.is_ascii_alphabetic());
+                let unit = &value.trim()[stripped.len()..];
+                if matches!(unit, "" | "ms" | "s" | "m" | "h") && stripped.parse::<u64>().is_ok() {
+                    Ok(())
+                } else {
+                    Err("a duration, e.g. '5s'".to_string())
+                }
+            }
+            PropertyType::Enum(allowed) => {
+                if allowed.iter().any(|a| a.eq_ignore_ascii_case(value)) {
+                    Ok(())
+                } else {
+                    Err(format!("one of {}", allowed.join(", ")))
+                }
+            }
+        }
+    }
+
+    fn to_protobuf(self) -> PbPropertyType {
+        match self {
+            PropertyType::String => PbPropertyType::String,
+            PropertyType::Int => PbPropertyType::Int,
+            PropertyType::Bool => PbPropertyType::Bool,
+            PropertyType::Duration => PbPropertyType::Duration,
+            PropertyType::Enum(_) => PbPropertyType::Enum,
+        }
+    }
+}
+
+/// Builds the protobuf representation of all embedded connector schemas, for UI
+/// autocompletion.
+pub fn connector_schemas() -> Vec<PbConnectorSchema> {
+    CONNECTOR_SCHEMAS
+        .iter()
+        .map(|schema| PbConnectorSchema {
+            connector: schema.connector.to_string(),
+            properties: schema
+                .properties
+                .iter()
+                .map(|p| PbPropertySchema {
+                    name: p.name.to_string(),
+                    r#type: p.ty.to_protobuf() as i32,
+                    required: p.required,
+                    allowed_values: match p.ty {
+                        PropertyType::Enum(allowed) => {
+                            allowed.iter().map(|a| a.to_string()).collect()
+                        }
+                        _ => vec![],
+                    },
+                    secret_allowed: p.secret_allowed,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Validates the `WITH` properties of a source or sink against the schema embedded for
+/// its connector, if any. `secret_ref_keys` are the property names that are bound to
+/// secrets rather than plain values.
+pub fn validate_connector_properties(
+    with_properties: &HashMap<String, String>,
+    secret_ref_keys: &HashSet<&str>,
+) -> MetaResult<()> {
+    let Some(connector) = with_properties.get(UPSTREAM_SOURCE_KEY) else {
+        return Ok(());
+    };
+    let Some(schema) = CONNECTOR_SCHEMAS
+        .iter()
+        .find(|s| s.connector.eq_ignore_ascii_case(connector))
+    else {
+        return Ok(());
+    };
+
+    let provided_keys: Vec<&str> = with_properties
+        .keys()
+        .map(|k| k.as_str())
+        .filter(|k| *k != UPSTREAM_SOURCE_KEY)
+        .chain(secret_ref_keys.iter().copied())
+        .collect();
+
+    for prop in schema.properties {
+        if secret_ref_keys.contains(prop.name) {
+            if !prop.secret_allowed {
+                return Err(MetaError::invalid_parameter(format!(
+                    "property '{}' of connector '{}' cannot reference a secret",
+                    prop.name, connector
+                )));
+            }
+            continue;
+        }
+        match with_properties.get(prop.name) {
+            Some(value) => {
+                if let Err(expected) = prop.ty.check(value) {
+                    return Err(MetaError::invalid_parameter(format!(
+                        "invalid value '{}' for property '{}' of connector '{}': expected {}",
+                        value, prop.name, connector, expected
+                    )));
+                }
+            }
+            None if prop.required => {
+                let suggestion = closest_key(prop.name, &provided_keys)
+                    .map(|k| format!(", did you mean to set it instead of '{}'?", k))
+                    .unwrap_or_default();
+                return Err(MetaError::invalid_parameter(format!(
+                    "connector '{}' requires property '{}'{}",
+                    connector, prop.name, suggestion
+                )));
+            }
+            None => {}
+        }
+    }
+
+    // Reject provided keys that closely resemble a schema key but do not match any:
+    // near-certainly a typo of the schema key.
+    for key in provided_keys {
+        if schema.properties.iter().any(|p| p.name == key) {
+            continue;
+        }
+        if let Some(suggestion) = closest_key(key, &schema_keys(schema))
+            && !with_properties.contains_key(suggestion)
+            && !secret_ref_keys.contains(suggestion)
+        {
+            return Err(MetaError::invalid_parameter(format!(
+                "unknown property '{}' for connector '{}', did you mean '{}'?",
+                key, connector, suggestion
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn schema_keys(schema: &ConnectorSchema) -> Vec<&'static str> {
+    schema.properties.iter().map(|p| p.name).collect()
+}
+
+/// Returns the candidate closest to `key` within edit distance 2, if any.
+fn closest_key<'a>(key: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .filter(|c| **c != key)
+        .map(|c| (edit_distance(key, c), *c))
+        .filter(|(d, _)| *d <= 2)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn props(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_missing_required_property() {
+        let properties = props(&[
+            ("connector", "kafka"),
+            ("properties.bootstrap.server", "broker:9092"),
+        ]);
+        let err = validate_connector_properties(&properties, &HashSet::new()).unwrap_err();
+        assert!(err.to_string().contains("requires property 'topic'"));
+    }
+
+    #[test]
+    fn test_typo_suggestion() {
+        let properties = props(&[
+            ("connector", "kafka"),
+            ("properties.bootstrap.server", "broker:9092"),
+            ("topci", "t"),
+        ]);
+        let err = validate_connector_properties(&properties, &HashSet::new()).unwrap_err();
+        assert!(err.to_string().contains("did you mean"), "{}", err);
+    }
+
+    #[test]
+    fn test_enum_and_int_values() {
+        let properties = props(&[
+            ("connector", "kafka"),
+            ("properties.bootstrap.server", "broker:9092"),
+            ("topic", "t"),
+            ("scan.startup.mode", "sometime"),
+        ]);
+        let err = validate_connector_properties(&properties, &HashSet::new()).unwrap_err();
+        assert!(err.to_string().contains("one of"));
+
+        let properties = props(&[
+            ("connector", "kafka"),
+            ("properties.bootstrap.server", "broker:9092"),
+            ("topic", "t"),
+            ("properties.fetch.max.bytes", "abc"),
+        ]);
+        let err = validate_connector_properties(&properties, &HashSet::new()).unwrap_err();
+        assert!(err.to_string().contains("expected an integer"));
+    }
+
+    #[test]
+    fn test_secret_ref() {
+        let properties = props(&[
+            ("connector", "kafka"),
+            ("properties.bootstrap.server", "broker:9092"),
+            ("topic", "t"),
+        ]);
+        let secret_refs = HashSet::from(["properties.sasl.password"]);
+        validate_connector_properties(&properties, &secret_refs).unwrap();
+
+        let secret_refs = HashSet::from(["topic"]);
+        let err = validate_connector_properties(&properties, &secret_refs).unwrap_err();
+        assert!(err.to_string().contains("cannot reference a secret"));
+    }
+
+    #[test]
+    fn test_unknown_connector_and_extra_keys() {
+        let properties = props(&[("connector", "nats"), ("whatever", "1")]);
+        validate_connector_properties(&properties, &HashSet::new()).unwrap();
+
+        // Unrelated extra keys of a known connector pass through.
+        let properties = props(&[
+            ("connector", "kafka"),
+            ("properties.bootstrap.server", "broker:9092"),
+            ("topic", "t"),
+            ("properties.client.id", "rw"),
+        ]);
+        validate_connector_properties(&properties, &HashSet::new()).unwrap();
+    }
+}
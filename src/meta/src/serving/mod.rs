@@ -20,7 +20,9 @@ use risingwave_common::hash::{VirtualNode, WorkerSlotMapping};
 use risingwave_common::vnode_mapping::vnode_placement::place_vnode;
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
-use risingwave_pb::meta::{FragmentWorkerSlotMapping, FragmentWorkerSlotMappings};
+use risingwave_pb::meta::{
+    FragmentWorkerSlotMapping, FragmentWorkerSlotMappings, RelationReadStats,
+};
 use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
 
@@ -82,6 +84,39 @@ impl ServingVnodeMapping {
     }
 }
 
+pub type RelationReadStatsAggregatorRef = Arc<RelationReadStatsAggregator>;
+
+/// Aggregates per-relation batch read statistics reported by frontends. The stats are
+/// kept in memory only and reset on meta restart: they are meant as a rough signal for
+/// identifying unused relations (drop candidates) and hot ones (serving replica
+/// candidates), not as an accurate accounting.
+#[derive(Default)]
+pub struct RelationReadStatsAggregator {
+    stats: RwLock<HashMap<u32, RelationReadStats>>,
+}
+
+impl RelationReadStatsAggregator {
+    /// Merge stats reported by a frontend into the aggregated view.
+    pub fn report(&self, reported: Vec<RelationReadStats>) {
+        let mut stats = self.stats.write();
+        for reported in reported {
+            let entry = stats
+                .entry(reported.relation_id)
+                .or_insert_with(|| RelationReadStats {
+                    relation_id: reported.relation_id,
+                    ..Default::default()
+                });
+            entry.read_count += reported.read_count;
+            entry.total_latency_ms += reported.total_latency_ms;
+            entry.last_read_at_ms = entry.last_read_at_ms.max(reported.last_read_at_ms);
+        }
+    }
+
+    pub fn list(&self) -> Vec<RelationReadStats> {
+        self.stats.read().values().cloned().collect()
+    }
+}
+
 pub(crate) fn to_fragment_worker_slot_mapping(
     mappings: &HashMap<FragmentId, WorkerSlotMapping>,
 ) -> Vec<FragmentWorkerSlotMapping> {
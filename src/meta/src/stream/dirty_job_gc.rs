@@ -0,0 +1,190 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use itertools::Itertools;
+use thiserror_ext::AsReport;
+use tokio::sync::oneshot::Sender;
+use tokio::task::JoinHandle;
+
+use crate::stream::GlobalStreamManager;
+use crate::MetaResult;
+
+/// Dirty metadata observed by the previous scan. An entity is only cleaned after it has
+/// been observed dirty by two consecutive scans, so that metadata in a transient state
+/// (e.g. a job between marking creation and building its fragments) is not mistaken for
+/// garbage.
+#[derive(Default)]
+struct GcSuspects {
+    dirty_fragment_job_ids: HashSet<u32>,
+    orphaned_internal_table_ids: HashSet<u32>,
+    dangling_creating_job_ids: HashSet<u32>,
+}
+
+impl GlobalStreamManager {
+    /// Spawns a background task that periodically reconciles streaming job metadata and
+    /// cleans entities left behind by interrupted DDL: table fragments without catalog
+    /// entries, orphaned internal tables and dangling in-progress creating markers.
+    /// These are otherwise only cleaned during recovery.
+    ///
+    /// Only meaningful for the kv metadata backend: under the SQL backend, foreign keys
+    /// prevent fragments and internal tables from outliving their catalog entries, and
+    /// creating-job states are persisted and cleaned during recovery.
+    pub fn start_dirty_job_gc(self: Arc<Self>, interval: Duration) -> (JoinHandle<()>, Sender<()>) {
+        tracing::info!("Dirty job GC is enabled with interval {:?}", interval);
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+        let join_handle = tokio::spawn(async move {
+            let mut gc_interval = tokio::time::interval(interval);
+            gc_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick completes immediately; consume it so that a freshly
+            // started meta node does not scan before the cluster has settled.
+            gc_interval.tick().await;
+            let mut suspects = GcSuspects::default();
+            loop {
+                tokio::select! {
+                    _ = gc_interval.tick() => {},
+                    _ = &mut shutdown_rx => {
+                        tracing::info!("Dirty job GC is stopped");
+                        return;
+                    }
+                }
+                if let Err(e) = self.gc_dirty_jobs(&mut suspects).await {
+                    tracing::warn!(error = %e.as_report(), "failed to gc dirty streaming job metadata");
+                }
+            }
+        });
+        (join_handle, shutdown_tx)
+    }
+
+    async fn gc_dirty_jobs(&self, suspects: &mut GcSuspects) -> MetaResult<()> {
+        let mgr = self.metadata_manager.as_v1_ref();
+
+        // 1. Table fragments without a catalog entry. `list_stream_job_ids` includes
+        // the in-progress creating jobs, so fragments of an in-flight foreground
+        // create are not considered dirty.
+        let stream_job_ids = mgr.catalog_manager.list_stream_job_ids().await?;
+        let dirty_table_fragments = mgr
+            .fragment_manager
+            .list_dirty_table_fragments(|tf| !stream_job_ids.contains(&tf.table_id().table_id))
+            .await;
+        let (to_drop_table_fragments, suspected): (Vec<_>, Vec<_>) =
+            dirty_table_fragments.into_iter().partition(|tf| {
+                suspects
+                    .dirty_fragment_job_ids
+                    .contains(&tf.table_id().table_id)
+            });
+        suspects.dirty_fragment_job_ids =
+            suspected.iter().map(|tf| tf.table_id().table_id).collect();
+        if !to_drop_table_fragments.is_empty() {
+            let to_drop_streaming_ids: HashSet<_> = to_drop_table_fragments
+                .iter()
+                .map(|tf| tf.table_id())
+                .collect();
+            tracing::info!(
+                ?to_drop_streaming_ids,
+                "dirty job gc: dropping table fragments without catalog entries"
+            );
+            let _unregister_table_ids = mgr
+                .fragment_manager
+                .drop_table_fragments_vec(&to_drop_streaming_ids)
+                .await?;
+            self.source_manager
+                .drop_source_fragments(&to_drop_table_fragments)
+                .await;
+            let event_logs = to_drop_table_fragments
+                .iter()
+                .map(|tf| {
+                    let event = risingwave_pb::meta::event_log::EventDirtyStreamJobClear {
+                        id: tf.table_id().table_id,
+                        name: "".to_string(),
+                        definition: "".to_string(),
+                        error: "clear by dirty job gc".to_string(),
+                    };
+                    risingwave_pb::meta::event_log::Event::DirtyStreamJobClear(event)
+                })
+                .collect_vec();
+            self.env.event_log_manager_ref().add_event_logs(event_logs);
+        }
+
+        // 2. Internal tables in the catalog that no fragment references anymore.
+        let orphaned_internal_table_ids = mgr
+            .catalog_manager
+            .list_orphaned_internal_table_ids(mgr.fragment_manager.clone())
+            .await;
+        let confirmed: HashSet<_> = suspects
+            .orphaned_internal_table_ids
+            .intersection(&orphaned_internal_table_ids)
+            .cloned()
+            .collect();
+        if !confirmed.is_empty() {
+            let cleaned = mgr
+                .catalog_manager
+                .clean_orphaned_internal_tables(&confirmed, mgr.fragment_manager.clone())
+                .await?;
+            tracing::info!(
+                cleaned_internal_table_ids = ?cleaned.iter().map(|t| t.id).collect_vec(),
+                "dirty job gc: cleaned orphaned internal tables"
+            );
+        }
+        suspects.orphaned_internal_table_ids = orphaned_internal_table_ids
+            .difference(&confirmed)
+            .cloned()
+            .collect();
+
+        // 3. In-progress creating markers whose procedure died without cleanup.
+        let dangling_creating_job_ids = mgr
+            .catalog_manager
+            .list_dangling_creating_job_ids(mgr.fragment_manager.clone())
+            .await;
+        let confirmed: HashSet<_> = suspects
+            .dangling_creating_job_ids
+            .intersection(&dangling_creating_job_ids)
+            .cloned()
+            .collect();
+        if !confirmed.is_empty() {
+            let cleaned = mgr
+                .catalog_manager
+                .clean_dangling_creating_jobs(&confirmed, mgr.fragment_manager.clone())
+                .await;
+            if !cleaned.is_empty() {
+                tracing::info!(
+                    ?cleaned,
+                    "dirty job gc: cleaned dangling creating job markers"
+                );
+                let event_logs = cleaned
+                    .into_iter()
+                    .map(|(id, name)| {
+                        let event = risingwave_pb::meta::event_log::EventDirtyStreamJobClear {
+                            id,
+                            name,
+                            definition: "".to_string(),
+                            error: "clear by dirty job gc".to_string(),
+                        };
+                        risingwave_pb::meta::event_log::Event::DirtyStreamJobClear(event)
+                    })
+                    .collect_vec();
+                self.env.event_log_manager_ref().add_event_logs(event_logs);
+            }
+        }
+        suspects.dangling_creating_job_ids = dangling_creating_job_ids
+            .difference(&confirmed)
+            .cloned()
+            .collect();
+
+        Ok(())
+    }
+}
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod dirty_job_gc;
 mod scale;
 mod sink;
 mod source_manager;
+mod state_dedup;
 mod stream_graph;
 mod stream_manager;
 #[cfg(test)]
@@ -24,5 +26,6 @@ mod test_scale;
 pub use scale::*;
 pub use sink::*;
 pub use source_manager::*;
+pub use state_dedup::*;
 pub use stream_graph::*;
 pub use stream_manager::*;
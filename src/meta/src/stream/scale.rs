@@ -38,7 +38,7 @@ use risingwave_pb::meta::table_fragments::fragment::{
     FragmentDistributionType, PbFragmentDistributionType,
 };
 use risingwave_pb::meta::table_fragments::{self, ActorStatus, PbFragment, State};
-use risingwave_pb::meta::FragmentWorkerSlotMappings;
+use risingwave_pb::meta::{event_log, FragmentWorkerSlotMappings};
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::{
     Dispatcher, DispatcherType, FragmentTypeFlag, PbDispatcher, PbStreamActor, StreamNode,
@@ -2624,6 +2624,14 @@ impl GlobalStreamManager {
                 "skipping parallelism control due to background jobs {:?}",
                 background_streaming_jobs
             );
+            self.report_capacity_event(event_log::EventClusterCapacityChange {
+                kind: "PARALLELISM_CONTROL".into(),
+                decision: format!(
+                    "skipped: background streaming jobs in progress: {:?}",
+                    background_streaming_jobs
+                ),
+                ..Default::default()
+            });
             // skip if there are background creating jobs
             return Ok(true);
         }
@@ -2632,7 +2640,8 @@ impl GlobalStreamManager {
 
         let _reschedule_job_lock = self.reschedule_lock_write_guard().await;
 
-        let (schedulable_worker_ids, table_parallelisms) = match &self.metadata_manager {
+        let (schedulable_worker_ids, table_parallelisms, total_slots) = match &self.metadata_manager
+        {
             MetadataManager::V1(mgr) => {
                 let table_parallelisms: HashMap<u32, TableParallelism> = {
                     let guard = mgr.fragment_manager.get_fragment_read_guard().await;
@@ -2662,7 +2671,12 @@ impl GlobalStreamManager {
                     .map(|worker| worker.id)
                     .collect();
 
-                (schedulable_worker_ids, table_parallelisms)
+                let total_slots = workers
+                    .iter()
+                    .map(|worker| worker.get_parallelism() as u64)
+                    .sum();
+
+                (schedulable_worker_ids, table_parallelisms, total_slots)
             }
             MetadataManager::V2(mgr) => {
                 let table_parallelisms: HashMap<_, _> = {
@@ -2702,7 +2716,12 @@ impl GlobalStreamManager {
                     .map(|worker| worker.id)
                     .collect();
 
-                (schedulable_worker_ids, table_parallelisms)
+                let total_slots = workers
+                    .iter()
+                    .map(|worker| worker.get_parallelism() as u64)
+                    .sum();
+
+                (schedulable_worker_ids, table_parallelisms, total_slots)
             }
         };
 
@@ -2731,6 +2750,7 @@ impl GlobalStreamManager {
             .collect();
 
         let mut reschedules = None;
+        let mut affected_job_ids = vec![];
 
         for batch in batches {
             let parallelisms: HashMap<_, _> = batch.into_iter().collect();
@@ -2750,6 +2770,7 @@ impl GlobalStreamManager {
                     "reschedule plan generated for streaming jobs {:?}",
                     parallelisms
                 );
+                affected_job_ids = parallelisms.keys().copied().collect_vec();
                 reschedules = Some(plan);
                 break;
             }
@@ -2757,6 +2778,13 @@ impl GlobalStreamManager {
 
         let Some(reschedules) = reschedules else {
             tracing::info!("no reschedule plan generated");
+            self.report_capacity_event(event_log::EventClusterCapacityChange {
+                kind: "PARALLELISM_CONTROL".into(),
+                prev_total_slots: total_slots,
+                new_total_slots: total_slots,
+                decision: "skipped: no reschedule plan generated, all jobs already balanced".into(),
+                ..Default::default()
+            });
             return Ok(false);
         };
 
@@ -2770,9 +2798,50 @@ impl GlobalStreamManager {
         )
         .await?;
 
+        self.report_capacity_event(event_log::EventClusterCapacityChange {
+            kind: "PARALLELISM_CONTROL".into(),
+            prev_total_slots: total_slots,
+            new_total_slots: total_slots,
+            affected_job_ids,
+            decision: "reschedule plan applied".into(),
+            ..Default::default()
+        });
+
         Ok(true)
     }
 
+    /// Records a cluster capacity change and the scheduling decision it led to in the
+    /// event log, so operators can audit why parallelism changed.
+    fn report_capacity_event(&self, event: event_log::EventClusterCapacityChange) {
+        self.env
+            .event_log_manager_ref()
+            .add_event_logs(vec![event_log::Event::ClusterCapacityChange(event)]);
+    }
+
+    /// Convenience wrapper of [`Self::report_capacity_event`] for membership changes.
+    fn report_membership_event(
+        &self,
+        kind: &str,
+        worker: &WorkerNode,
+        prev_total_slots: u64,
+        new_total_slots: u64,
+        decision: &str,
+    ) {
+        self.report_capacity_event(event_log::EventClusterCapacityChange {
+            kind: kind.into(),
+            worker_id: worker.id,
+            host: worker
+                .host
+                .as_ref()
+                .map(|host| format!("{}:{}", host.host, host.port))
+                .unwrap_or_default(),
+            prev_total_slots,
+            new_total_slots,
+            affected_job_ids: vec![],
+            decision: decision.into(),
+        });
+    }
+
     /// Handles notification of worker node activation and deletion, and triggers parallelism control.
     async fn run(&self, mut shutdown_rx: Receiver<()>) {
         tracing::info!("starting automatic parallelism control monitor");
@@ -2809,6 +2878,13 @@ impl GlobalStreamManager {
             .map(|worker| (worker.id, worker))
             .collect();
 
+        let total_slots = |cache: &BTreeMap<u32, WorkerNode>| {
+            cache
+                .values()
+                .map(|worker| worker.get_parallelism() as u64)
+                .sum::<u64>()
+        };
+
         let mut should_trigger = false;
 
         loop {
@@ -2857,16 +2933,31 @@ impl GlobalStreamManager {
 
                             tracing::info!(worker = worker.id, "worker activated notification received");
 
+                            let prev_total_slots = total_slots(&worker_cache);
                             let prev_worker = worker_cache.insert(worker.id, worker.clone());
 
                             match prev_worker {
                                 Some(prev_worker) if prev_worker.get_parallelism() != worker.get_parallelism()  => {
                                     tracing::info!(worker = worker.id, "worker parallelism changed");
                                     should_trigger = true;
+                                    self.report_membership_event(
+                                        "WORKER_PARALLELISM_CHANGED",
+                                        &worker,
+                                        prev_total_slots,
+                                        total_slots(&worker_cache),
+                                        "reschedule scheduled for the next tick",
+                                    );
                                 }
                                 None => {
                                     tracing::info!(worker = worker.id, "new worker joined");
                                     should_trigger = true;
+                                    self.report_membership_event(
+                                        "WORKER_JOINED",
+                                        &worker,
+                                        prev_total_slots,
+                                        total_slots(&worker_cache),
+                                        "reschedule scheduled for the next tick",
+                                    );
                                 }
                                 _ => {}
                             }
@@ -2879,9 +2970,17 @@ impl GlobalStreamManager {
                                 continue;
                             }
 
+                            let prev_total_slots = total_slots(&worker_cache);
                             match worker_cache.remove(&worker.id) {
                                 Some(prev_worker) => {
                                     tracing::info!(worker = prev_worker.id, "worker removed from stream manager cache");
+                                    self.report_membership_event(
+                                        "WORKER_LEFT",
+                                        &prev_worker,
+                                        prev_total_slots,
+                                        total_slots(&worker_cache),
+                                        "passive scale-in is handled by the barrier manager during recovery",
+                                    );
                                 }
                                 None => {
                                     tracing::warn!(worker = worker.id, "worker not found in stream manager cache, but it was removed");
@@ -14,13 +14,15 @@
 
 use std::borrow::BorrowMut;
 use std::cmp::Ordering;
+use std::collections::btree_map::Entry as BTreeMapEntry;
 use std::collections::hash_map::Entry;
-use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque};
 use std::ops::Deref;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::Context;
+use risingwave_common::bail;
 use risingwave_common::catalog::TableId;
 use risingwave_common::metrics::LabelGuardedIntGauge;
 use risingwave_connector::error::ConnectorResult;
@@ -30,6 +32,7 @@ use risingwave_connector::source::{
 };
 use risingwave_connector::{dispatch_source_prop, WithOptionsSecResolved};
 use risingwave_pb::catalog::Source;
+use risingwave_pb::meta::{SourceErrorStats, SourcePartition, SourceSplitDiscovery};
 use risingwave_pb::source::{ConnectorSplit, ConnectorSplits};
 use risingwave_pb::stream_plan::Dispatcher;
 use thiserror_ext::AsReport;
@@ -55,13 +58,59 @@ pub struct SourceManager {
     pub paused: Mutex<()>,
     barrier_scheduler: BarrierScheduler,
     core: Mutex<SourceManagerCore>,
+    /// Accumulated decode-error statistics per source, merged from the deltas that
+    /// compute nodes report. Kept in meta memory only.
+    error_stats: Mutex<HashMap<SourceId, SourceErrorStats>>,
     metrics: Arc<MetaMetrics>,
 }
 
 const MAX_FAIL_CNT: u32 = 10;
+/// The maximum number of split discoveries kept in the history of each source.
+const MAX_SPLIT_DISCOVERY_HISTORY: usize = 64;
 
 struct SharedSplitMap {
     splits: Option<BTreeMap<SplitId, SplitImpl>>,
+    /// Bounded history of split discoveries, recorded whenever the discovered split set
+    /// changes. Kept in meta memory for debugging partition expansion events.
+    discovery_history: VecDeque<SourceSplitDiscovery>,
+    /// Known partitions of the source, keyed by partition name. For partitioned external
+    /// sources like files or iceberg, each discovered split corresponds to a partition.
+    partitions: BTreeMap<String, SourcePartition>,
+}
+
+impl SharedSplitMap {
+    /// Records a new split discovery, dropping the oldest entry once the history is full.
+    fn record_discovery(&mut self, split_ids: Vec<String>) {
+        let generation = self.discovery_history.back().map_or(0, |d| d.generation) + 1;
+        let discovered_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        if self.discovery_history.len() >= MAX_SPLIT_DISCOVERY_HISTORY {
+            self.discovery_history.pop_front();
+        }
+        for split_id in &split_ids {
+            match self.partitions.entry(split_id.clone()) {
+                BTreeMapEntry::Occupied(mut o) => {
+                    // A re-discovered partition is valid again.
+                    o.get_mut().invalidated = false;
+                }
+                BTreeMapEntry::Vacant(v) => {
+                    v.insert(SourcePartition {
+                        partition_name: split_id.clone(),
+                        discovered_at,
+                        manually_added: false,
+                        invalidated: false,
+                    });
+                }
+            }
+        }
+        self.discovery_history.push_back(SourceSplitDiscovery {
+            generation,
+            discovered_at,
+            split_ids,
+        });
+    }
 }
 
 type SharedSplitMapRef = Arc<Mutex<SharedSplitMap>>;
@@ -194,13 +243,19 @@ impl<P: SourceProperties> ConnectorSourceWorker<P> {
         })?;
         source_is_up(1);
         self.fail_cnt = 0;
+        let splits: BTreeMap<_, _> = splits
+            .into_iter()
+            .map(|split| (split.id(), P::Split::into(split)))
+            .collect();
         let mut current_splits = self.current_splits.lock().await;
-        current_splits.splits.replace(
-            splits
-                .into_iter()
-                .map(|split| (split.id(), P::Split::into(split)))
-                .collect(),
-        );
+        let changed = !current_splits
+            .splits
+            .as_ref()
+            .is_some_and(|prev| prev.keys().eq(splits.keys()));
+        if changed {
+            current_splits.record_discovery(splits.keys().map(|id| id.to_string()).collect());
+        }
+        current_splits.splits = Some(splits);
 
         Ok(())
     }
@@ -707,6 +762,7 @@ impl SourceManager {
             barrier_scheduler,
             core,
             paused: Mutex::new(()),
+            error_stats: Mutex::new(HashMap::new()),
             metrics,
         })
     }
@@ -974,10 +1030,12 @@ impl SourceManager {
     /// Unregister connector worker for source.
     pub async fn unregister_sources(&self, source_ids: Vec<SourceId>) {
         let mut core = self.core.lock().await;
+        let mut error_stats = self.error_stats.lock().await;
         for source_id in source_ids {
             if let Some(handle) = core.managed_sources.remove(&source_id) {
                 handle.handle.abort();
             }
+            error_stats.remove(&source_id);
         }
     }
 
@@ -989,7 +1047,11 @@ impl SourceManager {
     ) -> MetaResult<()> {
         tracing::info!("spawning new watcher for source {}", source.id);
 
-        let splits = Arc::new(Mutex::new(SharedSplitMap { splits: None }));
+        let splits = Arc::new(Mutex::new(SharedSplitMap {
+            splits: None,
+            discovery_history: VecDeque::new(),
+            partitions: BTreeMap::new(),
+        }));
         let current_splits_ref = splits.clone();
         let source_id = source.id;
 
@@ -1048,7 +1110,11 @@ impl SourceManager {
     ) -> MetaResult<()> {
         tracing::info!("spawning new watcher for source {}", source.id);
 
-        let splits = Arc::new(Mutex::new(SharedSplitMap { splits: None }));
+        let splits = Arc::new(Mutex::new(SharedSplitMap {
+            splits: None,
+            discovery_history: VecDeque::new(),
+            partitions: BTreeMap::new(),
+        }));
         let current_splits_ref = splits.clone();
         let source_id = source.id;
 
@@ -1096,6 +1162,132 @@ impl SourceManager {
         Ok(())
     }
 
+    /// Returns the recorded history of split discoveries for the given source, oldest first.
+    /// The history is kept in meta memory only and bounded to the most recent
+    /// [`MAX_SPLIT_DISCOVERY_HISTORY`] changes.
+    pub async fn get_source_split_history(
+        &self,
+        source_id: SourceId,
+    ) -> MetaResult<Vec<SourceSplitDiscovery>> {
+        let core = self.core.lock().await;
+        let handle = core
+            .managed_sources
+            .get(&source_id)
+            .with_context(|| format!("source {source_id} not managed"))?;
+        Ok(handle
+            .splits
+            .lock()
+            .await
+            .discovery_history
+            .iter()
+            .cloned()
+            .collect())
+    }
+
+    /// Returns the known partitions of the given source, both discovered and manually
+    /// added, ordered by partition name.
+    pub async fn list_source_partitions(
+        &self,
+        source_id: SourceId,
+    ) -> MetaResult<Vec<SourcePartition>> {
+        let core = self.core.lock().await;
+        let handle = core
+            .managed_sources
+            .get(&source_id)
+            .with_context(|| format!("source {source_id} not managed"))?;
+        Ok(handle
+            .splits
+            .lock()
+            .await
+            .partitions
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    /// Manually registers a partition for the given source, e.g. one that the periodic
+    /// discovery has not picked up yet.
+    pub async fn add_source_partition(
+        &self,
+        source_id: SourceId,
+        partition_name: String,
+    ) -> MetaResult<()> {
+        let core = self.core.lock().await;
+        let handle = core
+            .managed_sources
+            .get(&source_id)
+            .with_context(|| format!("source {source_id} not managed"))?;
+        let mut splits = handle.splits.lock().await;
+        match splits.partitions.entry(partition_name.clone()) {
+            BTreeMapEntry::Occupied(_) => {
+                bail!("partition {partition_name} already exists for source {source_id}")
+            }
+            BTreeMapEntry::Vacant(v) => {
+                v.insert(SourcePartition {
+                    partition_name,
+                    discovered_at: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap()
+                        .as_millis() as u64,
+                    manually_added: true,
+                    invalidated: false,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks a partition of the given source as invalidated, excluding it from pruning
+    /// decisions until it is discovered again.
+    pub async fn invalidate_source_partition(
+        &self,
+        source_id: SourceId,
+        partition_name: &str,
+    ) -> MetaResult<()> {
+        let core = self.core.lock().await;
+        let handle = core
+            .managed_sources
+            .get(&source_id)
+            .with_context(|| format!("source {source_id} not managed"))?;
+        let mut splits = handle.splits.lock().await;
+        let partition = splits.partitions.get_mut(partition_name).with_context(|| {
+            format!("partition {partition_name} not found for source {source_id}")
+        })?;
+        partition.invalidated = true;
+        Ok(())
+    }
+
+    /// Merges decode-error statistic deltas reported by a compute node into the
+    /// per-source accumulated counters.
+    pub async fn report_source_error_stats(&self, stats: Vec<SourceErrorStats>) {
+        let mut error_stats = self.error_stats.lock().await;
+        for delta in stats {
+            let entry = error_stats
+                .entry(delta.source_id)
+                .or_insert_with(|| SourceErrorStats {
+                    source_id: delta.source_id,
+                    ..Default::default()
+                });
+            entry.dlq_record_count = entry.dlq_record_count.saturating_add(delta.dlq_record_count);
+            entry.dlq_byte_count = entry.dlq_byte_count.saturating_add(delta.dlq_byte_count);
+            if !delta.last_error.is_empty()
+                && delta.last_error_timestamp_ms >= entry.last_error_timestamp_ms
+            {
+                entry.last_error = delta.last_error;
+                entry.last_error_timestamp_ms = delta.last_error_timestamp_ms;
+            }
+        }
+    }
+
+    /// Returns the accumulated decode-error statistics of all sources that have reported
+    /// errors, ordered by source id.
+    pub async fn list_source_error_stats(&self) -> Vec<SourceErrorStats> {
+        let error_stats = self.error_stats.lock().await;
+        let mut stats: Vec<_> = error_stats.values().cloned().collect();
+        stats.sort_unstable_by_key(|s| s.source_id);
+        stats
+    }
+
     pub async fn list_assignments(&self) -> HashMap<ActorId, Vec<SplitImpl>> {
         let core = self.core.lock().await;
         core.actor_splits.clone()
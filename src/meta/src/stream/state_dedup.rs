@@ -0,0 +1,174 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Detection of duplicate internal state across identical fragments.
+//!
+//! This is deliberately report-only: it groups fragments by a state fingerprint and
+//! estimates the storage reclaimable by keeping a single copy, but no shared-state
+//! mode exists — state tables are never merged or rewritten, and deduplicating them
+//! physically would require fragments to read another job's state tables, which the
+//! storage layer does not support today. The report tells operators how much a
+//! future shared-state mode (or a manual pipeline restructuring) could save.
+
+use std::collections::HashMap;
+use std::mem::Discriminant;
+
+use prost::Message;
+use risingwave_pb::catalog::Table;
+use risingwave_pb::hummock::HummockVersionStats;
+use risingwave_pb::meta::analyze_duplicate_state_response::{DuplicateGroup, FragmentRef};
+use risingwave_pb::meta::table_fragments::Fragment;
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+use risingwave_pb::stream_plan::{FragmentTypeFlag, StreamNode};
+
+use crate::model::TableFragments;
+
+/// Identity of a fragment's internal state, used to detect fragments whose state
+/// tables hold byte-wise interchangeable data. Two fragments share a fingerprint iff
+/// they run the same operators over the same upstream relations and their state
+/// tables have the same schemas, which is what template-stamped pipelines produce.
+#[derive(Hash, PartialEq, Eq)]
+struct StateFingerprint {
+    /// Pre-order sequence of operator kinds in the fragment's plan.
+    operators: Vec<Discriminant<NodeBody>>,
+    /// Tables and sources the fragment reads from, in plan order.
+    upstream_ids: Vec<u32>,
+    /// Encoded columns and pk of each state table, in state table order.
+    state_table_schemas: Vec<Vec<u8>>,
+    distribution_type: i32,
+}
+
+impl StateFingerprint {
+    fn of(fragment: &Fragment, state_tables: &HashMap<u32, Table>) -> Option<Self> {
+        let mut operators = Vec::new();
+        let mut upstream_ids = Vec::new();
+        let node = fragment.actors.first()?.nodes.as_ref()?;
+        collect_plan_identity(node, &mut operators, &mut upstream_ids);
+
+        let state_table_schemas = fragment
+            .state_table_ids
+            .iter()
+            .map(|table_id| {
+                let table = state_tables.get(table_id)?;
+                let mut schema = Vec::new();
+                for column in &table.columns {
+                    schema.extend(column.encode_to_vec());
+                }
+                for pk in &table.pk {
+                    schema.extend(pk.encode_to_vec());
+                }
+                Some(schema)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            operators,
+            upstream_ids,
+            state_table_schemas,
+            distribution_type: fragment.distribution_type,
+        })
+    }
+}
+
+fn collect_plan_identity(
+    node: &StreamNode,
+    operators: &mut Vec<Discriminant<NodeBody>>,
+    upstream_ids: &mut Vec<u32>,
+) {
+    if let Some(body) = node.node_body.as_ref() {
+        operators.push(std::mem::discriminant(body));
+        match body {
+            NodeBody::StreamScan(scan) => upstream_ids.push(scan.table_id),
+            NodeBody::Source(source) => {
+                if let Some(inner) = &source.source_inner {
+                    upstream_ids.push(inner.source_id);
+                }
+            }
+            _ => {}
+        }
+    }
+    for input in &node.input {
+        collect_plan_identity(input, operators, upstream_ids);
+    }
+}
+
+/// Scans the fragments of all streaming jobs for duplicate internal state: groups of
+/// fragments that run the same operators over the same upstream relations with the
+/// same state table schemas, as produced by template-stamped pipelines replicating
+/// e.g. a dimension-table join per job.
+///
+/// Returns one group per fingerprint with at least two fragments, together with an
+/// estimate of the storage reclaimable by keeping a single shared copy of the state,
+/// derived from the latest hummock version stats. This is a report-only pass: it
+/// does not rewrite any job.
+pub fn find_duplicate_state(
+    table_fragments: &[TableFragments],
+    state_tables: &HashMap<u32, Table>,
+    version_stats: &HummockVersionStats,
+) -> Vec<DuplicateGroup> {
+    let mut groups: HashMap<StateFingerprint, Vec<FragmentRef>> = HashMap::new();
+
+    for job in table_fragments {
+        for fragment in job.fragments.values() {
+            if fragment.state_table_ids.is_empty() {
+                continue;
+            }
+            // Materialized state is a user-visible relation, not internal state.
+            if fragment.fragment_type_mask & FragmentTypeFlag::Mview as u32 != 0 {
+                continue;
+            }
+            let Some(fingerprint) = StateFingerprint::of(fragment, state_tables) else {
+                continue;
+            };
+            let state_size_bytes: u64 = fragment
+                .state_table_ids
+                .iter()
+                .filter_map(|table_id| version_stats.table_stats.get(table_id))
+                .map(|stats| (stats.total_key_size + stats.total_value_size).max(0) as u64)
+                .sum();
+            groups.entry(fingerprint).or_default().push(FragmentRef {
+                job_id: job.table_id().table_id,
+                fragment_id: fragment.fragment_id,
+                state_table_ids: fragment.state_table_ids.clone(),
+                state_size_bytes,
+            });
+        }
+    }
+
+    let mut groups: Vec<_> = groups
+        .into_values()
+        .filter(|fragments| fragments.len() > 1)
+        .map(|fragments| {
+            // Keeping a single copy reclaims everything but the largest replica.
+            let total: u64 = fragments.iter().map(|f| f.state_size_bytes).sum();
+            let largest = fragments
+                .iter()
+                .map(|f| f.state_size_bytes)
+                .max()
+                .unwrap_or(0);
+            DuplicateGroup {
+                fragments,
+                approx_reclaimable_bytes: total - largest,
+            }
+        })
+        .collect();
+    // Largest savings first, with a stable tie-break for deterministic output.
+    groups.sort_by_key(|group| {
+        (
+            std::cmp::Reverse(group.approx_reclaimable_bytes),
+            group.fragments.first().map(|f| (f.job_id, f.fragment_id)),
+        )
+    });
+    groups
+}
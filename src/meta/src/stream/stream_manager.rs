@@ -28,7 +28,9 @@ use tokio::sync::mpsc::Sender;
 use tokio::sync::{oneshot, Mutex};
 use tracing::Instrument;
 
-use super::{Locations, RescheduleOptions, ScaleControllerRef, TableResizePolicy};
+use super::{
+    Locations, RescheduleOptions, ScaleControllerRef, TableResizePolicy, WorkerReschedule,
+};
 use crate::barrier::{
     BarrierScheduler, Command, CreateStreamingJobCommandInfo, CreateStreamingJobType,
     ReplaceTablePlan, SnapshotBackfillInfo,
@@ -591,6 +593,64 @@ impl GlobalStreamManager {
         Ok(())
     }
 
+    /// Forcibly drops a streaming job, bypassing the normal ref-count and in-progress
+    /// checks. A last resort for jobs whose fragment and catalog state have diverged
+    /// and that can be neither finished nor cancelled; every cleanup step is attempted
+    /// even when an earlier one found nothing left to do.
+    pub async fn force_drop_job(&self, table_id: TableId) -> MetaResult<()> {
+        let mgr = self.metadata_manager.as_v1_ref();
+
+        // Abort any in-flight creation of the job, waking up its stuck notifiers.
+        let _ = self.creating_job_info.cancel_jobs(vec![table_id]).await;
+
+        // Drop the remaining fragments, if any, through the normal drop path, which
+        // also unregisters their state tables from hummock via the drop barrier.
+        let (fragments_dropped, internal_table_ids) = match mgr
+            .fragment_manager
+            .select_table_fragments_by_ids(&[table_id])
+            .await
+        {
+            Ok(table_fragments_vec) => {
+                let internal_table_ids = table_fragments_vec
+                    .iter()
+                    .flat_map(|tf| tf.internal_table_ids())
+                    .collect_vec();
+                self.drop_streaming_jobs_impl(vec![table_id]).await?;
+                (true, internal_table_ids)
+            }
+            Err(e) if e.is_fragment_not_found() => (false, vec![]),
+            Err(e) => return Err(e),
+        };
+
+        // Remove whatever catalog state remains, regardless of ref counts or the
+        // job's creation status.
+        let removed_table_ids = mgr
+            .catalog_manager
+            .force_drop_job(table_id.table_id, internal_table_ids)
+            .await?;
+
+        // With the fragments already gone, no drop barrier has unregistered the
+        // job's state tables from hummock; do it explicitly.
+        if !fragments_dropped && !removed_table_ids.is_empty() {
+            let _ = self
+                .barrier_scheduler
+                .run_command(Command::DropStreamingJobs {
+                    actors: vec![],
+                    unregistered_state_table_ids: removed_table_ids
+                        .into_iter()
+                        .map(TableId::new)
+                        .collect(),
+                    unregistered_fragment_ids: HashSet::new(),
+                })
+                .await
+                .inspect_err(|err| {
+                    tracing::error!(error = ?err.as_report(), "failed to run drop command");
+                });
+        }
+
+        Ok(())
+    }
+
     /// Cancel streaming jobs and return the canceled table ids.
     /// 1. Send cancel message to stream jobs (via `cancel_jobs`).
     /// 2. Send cancel message to recovered stream jobs (via `barrier_scheduler`).
@@ -655,6 +715,38 @@ impl GlobalStreamManager {
         cancelled_ids
     }
 
+    /// Computes the reschedule plan that would bring the given streaming job to the target
+    /// parallelism, without applying it. The plan only contains per-worker actor diffs and
+    /// keeps existing actors in place, so applying it moves as few actors as possible.
+    pub async fn plan_reschedule(
+        &self,
+        job_id: u32,
+        target_parallelism: usize,
+    ) -> MetaResult<HashMap<FragmentId, WorkerReschedule>> {
+        let _reschedule_job_lock = self.reschedule_lock_read_guard().await;
+
+        let worker_nodes = self
+            .metadata_manager
+            .list_active_streaming_compute_nodes()
+            .await?;
+
+        let worker_ids = worker_nodes
+            .iter()
+            .filter(|w| w.property.as_ref().map_or(true, |p| !p.is_unschedulable))
+            .map(|node| node.id)
+            .collect::<BTreeSet<_>>();
+
+        self.scale_controller
+            .generate_table_resize_plan(TableResizePolicy {
+                worker_ids,
+                table_parallelisms: HashMap::from([(
+                    job_id,
+                    TableParallelism::Fixed(target_parallelism),
+                )]),
+            })
+            .await
+    }
+
     pub(crate) async fn alter_table_parallelism(
         &self,
         table_id: u32,
@@ -876,6 +968,13 @@ mod tests {
         ) -> std::result::Result<Response<WaitEpochCommitResponse>, Status> {
             Ok(Response::new(WaitEpochCommitResponse::default()))
         }
+
+        async fn sample_changelog(
+            &self,
+            _request: Request<SampleChangelogRequest>,
+        ) -> std::result::Result<Response<SampleChangelogResponse>, Status> {
+            Ok(Response::new(SampleChangelogResponse::default()))
+        }
     }
 
     struct MockServices {
@@ -963,6 +1062,11 @@ mod tests {
                 hummock_manager.clone(),
                 meta_metrics.clone(),
                 system_params.checkpoint_frequency() as usize,
+                (
+                    system_params.adaptive_checkpoint_frequency_min() as usize,
+                    system_params.adaptive_checkpoint_frequency_max() as usize,
+                ),
+                system_params.barrier_backpressure_max_stretch() as u32,
             );
 
             let source_manager = Arc::new(
@@ -47,9 +47,11 @@ use risingwave_hummock_sdk::{
 };
 use risingwave_pb::backup_service::backup_service_client::BackupServiceClient;
 use risingwave_pb::backup_service::*;
+use risingwave_pb::catalog::table::PbSchemaChangePolicy;
 use risingwave_pb::catalog::{
-    Connection, PbComment, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource,
-    PbSubscription, PbTable, PbView, Table,
+    Connection, PbAnnotation, PbComment, PbDatabase, PbFunction, PbIndex, PbLabel, PbSchema,
+    PbSink, PbSource, PbStorageClassPolicy, PbStreamJobPriority, PbSubscription, PbTable, PbView,
+    Table,
 };
 use risingwave_pb::cloud_service::cloud_service_client::CloudServiceClient;
 use risingwave_pb::cloud_service::*;
@@ -88,6 +90,7 @@ use risingwave_pb::meta::system_params_service_client::SystemParamsServiceClient
 use risingwave_pb::meta::telemetry_info_service_client::TelemetryInfoServiceClient;
 use risingwave_pb::meta::update_worker_node_schedulability_request::Schedulability;
 use risingwave_pb::meta::*;
+use risingwave_pb::plan_common::PbColumnCatalog;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_pb::user::update_user_request::UpdateField;
 use risingwave_pb::user::user_service_client::UserServiceClient;
@@ -141,15 +144,19 @@ impl MetaClient {
         &self.cluster_id
     }
 
-    /// Subscribe to notification from meta.
+    /// Subscribe to notification from meta. `last_acked_version` asks for an
+    /// incremental catch-up from that version instead of a full snapshot, which the
+    /// meta node may or may not be able to serve.
     pub async fn subscribe(
         &self,
         subscribe_type: SubscribeType,
+        last_acked_version: Option<u64>,
     ) -> Result<Streaming<SubscribeResponse>> {
         let request = SubscribeRequest {
             subscribe_type: subscribe_type as i32,
             host: Some(self.host_addr.to_protobuf()),
             worker_id: self.worker_id(),
+            last_acked_version,
         };
 
         let retry_strategy = GrpcMetaClient::retry_strategy_to_bound(
@@ -164,6 +171,22 @@ impl MetaClient {
         .await
     }
 
+    /// Subscribe to per-table committed epoch advances from meta, e.g. for invalidating
+    /// external query result caches. An empty `table_ids` subscribes to all tables.
+    pub async fn subscribe_table_change(
+        &self,
+        table_ids: Vec<u32>,
+    ) -> Result<Streaming<TableChangeNotification>> {
+        let request = SubscribeTableChangeRequest { table_ids };
+        self.inner.subscribe_table_change(request).await
+    }
+
+    /// Fetch `pg_catalog` row deltas for all notification versions after `from_version`.
+    pub async fn get_catalog_deltas(&self, from_version: u64) -> Result<GetCatalogDeltasResponse> {
+        let request = GetCatalogDeltasRequest { from_version };
+        self.inner.get_catalog_deltas(request).await
+    }
+
     pub async fn create_connection(
         &self,
         connection_name: String,
@@ -222,6 +245,19 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn rotate_secret(
+        &self,
+        secret_id: SecretId,
+        value: Vec<u8>,
+    ) -> Result<CatalogVersion> {
+        let request = RotateSecretRequest {
+            secret_id: secret_id.into(),
+            value,
+        };
+        let resp = self.inner.rotate_secret(request).await?;
+        Ok(resp.version)
+    }
+
     /// Register the current node to the cluster and set the corresponding worker id.
     ///
     /// Retry if there's connection issue with the meta node. Exit the process if the registration fails.
@@ -404,12 +440,25 @@ impl MetaClient {
         let request = DropMaterializedViewRequest {
             table_id: table_id.table_id(),
             cascade,
+            soft: false,
         };
 
         let resp = self.inner.drop_materialized_view(request).await?;
         Ok(resp.version)
     }
 
+    pub async fn undrop_relation(
+        &self,
+        relation: undrop_relation_request::Relation,
+    ) -> Result<CatalogVersion> {
+        let request = UndropRelationRequest {
+            relation: Some(relation),
+        };
+
+        let resp = self.inner.undrop_relation(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn create_source(&self, source: PbSource) -> Result<CatalogVersion> {
         let request = CreateSourceRequest {
             source: Some(source),
@@ -462,9 +511,14 @@ impl MetaClient {
         Ok(resp.version)
     }
 
-    pub async fn create_function(&self, function: PbFunction) -> Result<CatalogVersion> {
+    pub async fn create_function(
+        &self,
+        function: PbFunction,
+        or_replace: bool,
+    ) -> Result<CatalogVersion> {
         let request = CreateFunctionRequest {
             function: Some(function),
+            or_replace,
         };
         let resp = self.inner.create_function(request).await?;
         Ok(resp.version)
@@ -496,6 +550,71 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn alter_annotation(&self, annotation: PbAnnotation) -> Result<CatalogVersion> {
+        let request = AlterAnnotationRequest {
+            annotation: Some(annotation),
+        };
+        let resp = self.inner.alter_annotation(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn alter_label(&self, label: PbLabel) -> Result<CatalogVersion> {
+        let request = AlterLabelRequest { label: Some(label) };
+        let resp = self.inner.alter_label(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn alter_storage_class_policy(
+        &self,
+        table_id: u32,
+        policy: Option<PbStorageClassPolicy>,
+    ) -> Result<CatalogVersion> {
+        let request = AlterStorageClassPolicyRequest { table_id, policy };
+        let resp = self.inner.alter_storage_class_policy(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn alter_schema_change_policy(
+        &self,
+        table_id: u32,
+        policy: PbSchemaChangePolicy,
+    ) -> Result<CatalogVersion> {
+        let request = AlterSchemaChangePolicyRequest {
+            table_id,
+            policy: policy as i32,
+        };
+        let resp = self.inner.alter_schema_change_policy(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn alter_database_barrier_interval(
+        &self,
+        database_id: u32,
+        barrier_interval_ms: Option<u32>,
+    ) -> Result<CatalogVersion> {
+        let request = AlterDatabaseBarrierIntervalRequest {
+            database_id,
+            barrier_interval_ms,
+        };
+        let resp = self.inner.alter_database_barrier_interval(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn alter_database_session_default(
+        &self,
+        database_id: u32,
+        name: String,
+        value: Option<String>,
+    ) -> Result<CatalogVersion> {
+        let request = AlterDatabaseSessionDefaultRequest {
+            database_id,
+            name,
+            value,
+        };
+        let resp = self.inner.alter_database_session_default(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn alter_name(
         &self,
         object: alter_name_request::Object,
@@ -518,6 +637,38 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn alter_source_add_column(
+        &self,
+        source_id: u32,
+        column: PbColumnCatalog,
+        definition: String,
+        base_version: u64,
+    ) -> Result<AlterSourceAddColumnResponse> {
+        let request = AlterSourceAddColumnRequest {
+            source_id,
+            column: Some(column),
+            definition,
+            base_version,
+        };
+        let resp = self.inner.alter_source_add_column(request).await?;
+        Ok(resp)
+    }
+
+    pub async fn alter_connector_props(
+        &self,
+        object_id: u32,
+        object_type: alter_connector_props_request::ObjectType,
+        changed_props: HashMap<String, String>,
+    ) -> Result<CatalogVersion> {
+        let request = AlterConnectorPropsRequest {
+            object_id,
+            object_type: object_type as _,
+            changed_props,
+        };
+        let resp = self.inner.alter_connector_props(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn alter_owner(&self, object: Object, owner_id: u32) -> Result<CatalogVersion> {
         let request = AlterOwnerRequest {
             object: Some(object),
@@ -527,6 +678,19 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn alter_schema_default_owner(
+        &self,
+        schema_id: u32,
+        default_owner_id: Option<u32>,
+    ) -> Result<CatalogVersion> {
+        let request = AlterSchemaDefaultOwnerRequest {
+            schema_id,
+            default_owner_id,
+        };
+        let resp = self.inner.alter_schema_default_owner(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn alter_set_schema(
         &self,
         object: alter_set_schema_request::Object,
@@ -564,6 +728,34 @@ impl MetaClient {
         Ok(())
     }
 
+    pub async fn alter_stream_job_priority(
+        &self,
+        table_id: u32,
+        priority: PbStreamJobPriority,
+    ) -> Result<CatalogVersion> {
+        let request = AlterStreamJobPriorityRequest {
+            table_id,
+            priority: priority as _,
+        };
+
+        let resp = self.inner.alter_stream_job_priority(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn alter_table_ttl(
+        &self,
+        table_id: u32,
+        retention_seconds: Option<u32>,
+    ) -> Result<CatalogVersion> {
+        let request = AlterTableTtlRequest {
+            table_id,
+            retention_seconds,
+        };
+
+        let resp = self.inner.alter_table_ttl(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn replace_table(
         &self,
         source: Option<PbSource>,
@@ -571,6 +763,7 @@ impl MetaClient {
         graph: StreamFragmentGraph,
         table_col_index_mapping: ColIndexMapping,
         job_type: PbTableJobType,
+        alter_lease_token: u64,
     ) -> Result<CatalogVersion> {
         let request = ReplaceTablePlanRequest {
             plan: Some(ReplaceTablePlan {
@@ -579,6 +772,7 @@ impl MetaClient {
                 fragment_graph: Some(graph),
                 table_col_index_mapping: Some(table_col_index_mapping.to_protobuf()),
                 job_type: job_type as _,
+                alter_lease_token,
             }),
         };
         let resp = self.inner.replace_table_plan(request).await?;
@@ -586,6 +780,14 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    /// Acquires an exclusive lease for altering the given table, returning the lease token to
+    /// present in the subsequent replace-table plan.
+    pub async fn acquire_alter_lease(&self, table_id: u32, force: bool) -> Result<u64> {
+        let request = AcquireAlterLeaseRequest { table_id, force };
+        let resp = self.inner.acquire_alter_lease(request).await?;
+        Ok(resp.lease_token)
+    }
+
     pub async fn auto_schema_change(&self, schema_change: SchemaChangeEnvelope) -> Result<()> {
         let request = AutoSchemaChangeRequest {
             schema_change: Some(schema_change),
@@ -594,6 +796,59 @@ impl MetaClient {
         Ok(())
     }
 
+    /// Returns the property schemas embedded in the meta node for the common
+    /// connectors, e.g. for UI autocompletion.
+    pub async fn list_connector_property_schemas(
+        &self,
+    ) -> Result<Vec<list_connector_property_schemas_response::ConnectorSchema>> {
+        let request = ListConnectorPropertySchemasRequest {};
+        let resp = self.inner.list_connector_property_schemas(request).await?;
+        Ok(resp.schemas)
+    }
+
+    /// Lists the lint findings persisted when the given relation was created.
+    /// Only supported by the kv meta backend.
+    pub async fn list_object_lints(&self, relation_id: u32) -> Result<Vec<ObjectLint>> {
+        let request = ListObjectLintsRequest { relation_id };
+        let resp = self.inner.list_object_lints(request).await?;
+        Ok(resp.lints)
+    }
+
+    /// Returns the current meta catalog lock holder and queued operations.
+    /// Only supported by the kv meta backend.
+    pub async fn get_catalog_lock_stats(&self) -> Result<GetCatalogLockStatsResponse> {
+        let request = GetCatalogLockStatsRequest {};
+        let resp = self.inner.get_catalog_lock_stats(request).await?;
+        Ok(resp)
+    }
+
+    pub async fn set_streaming_job_quota(&self, quota: StreamingJobQuota) -> Result<()> {
+        let request = SetStreamingJobQuotaRequest { quota: Some(quota) };
+        let _resp = self.inner.set_streaming_job_quota(request).await?;
+        Ok(())
+    }
+
+    pub async fn list_streaming_job_quotas(&self) -> Result<Vec<StreamingJobQuota>> {
+        let request = ListStreamingJobQuotasRequest {};
+        let resp = self.inner.list_streaming_job_quotas(request).await?;
+        Ok(resp.quotas)
+    }
+
+    pub async fn list_ddl_audit_logs(&self) -> Result<Vec<PbDdlAuditLog>> {
+        let request = ListDdlAuditLogsRequest {};
+        let resp = self.inner.list_ddl_audit_logs(request).await?;
+        Ok(resp.audit_logs)
+    }
+
+    /// Runs a batch of catalog-only DDL commands that is applied atomically: either
+    /// all commands take effect, or none do.
+    pub async fn run_ddl_batch(&self, commands: Vec<DdlBatchCommand>) -> Result<CatalogVersion> {
+        let request = RunDdlBatchRequest { commands };
+        let resp = self.inner.run_ddl_batch(request).await?;
+        // TODO: handle error in `resp.status` here
+        Ok(resp.version)
+    }
+
     pub async fn create_view(&self, view: PbView) -> Result<CatalogVersion> {
         let request = CreateViewRequest { view: Some(view) };
         let resp = self.inner.create_view(request).await?;
@@ -627,6 +882,7 @@ impl MetaClient {
             source_id: source_id.map(SourceId::Id),
             table_id: table_id.table_id(),
             cascade,
+            soft: false,
         };
 
         let resp = self.inner.drop_table(request).await?;
@@ -673,6 +929,23 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    /// Acknowledge that the consumer of a subscription has fully consumed log data up to
+    /// `acked_epoch`, so that it can be truncated before the time-based retention
+    /// elapses. Only accepted for subscriptions created with
+    /// `retention_policy = 'consumer_ack'`.
+    pub async fn ack_subscription_epoch(
+        &self,
+        subscription_id: u32,
+        acked_epoch: u64,
+    ) -> Result<CatalogVersion> {
+        let request = AckSubscriptionEpochRequest {
+            subscription_id,
+            acked_epoch,
+        };
+        let resp = self.inner.ack_subscription_epoch(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn list_change_log_epochs(
         &self,
         table_id: u32,
@@ -752,12 +1025,14 @@ impl MetaClient {
         privileges: Vec<GrantPrivilege>,
         with_grant_option: bool,
         granted_by: u32,
+        expand_relations: bool,
     ) -> Result<u64> {
         let request = GrantPrivilegeRequest {
             user_ids,
             privileges,
             with_grant_option,
             granted_by,
+            expand_relations,
         };
         let resp = self.inner.grant_privilege(request).await?;
         Ok(resp.version)
@@ -784,6 +1059,44 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn grant_role(
+        &self,
+        role_ids: Vec<u32>,
+        user_ids: Vec<u32>,
+        granted_by: u32,
+    ) -> Result<u64> {
+        let request = GrantRoleRequest {
+            role_ids,
+            user_ids,
+            granted_by,
+        };
+        let resp = self.inner.grant_role(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn revoke_role(
+        &self,
+        role_ids: Vec<u32>,
+        user_ids: Vec<u32>,
+        revoke_by: u32,
+    ) -> Result<u64> {
+        let request = RevokeRoleRequest {
+            role_ids,
+            user_ids,
+            revoke_by,
+        };
+        let resp = self.inner.revoke_role(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn alter_default_privileges(
+        &self,
+        request: AlterDefaultPrivilegesRequest,
+    ) -> Result<()> {
+        self.inner.alter_default_privileges(request).await?;
+        Ok(())
+    }
+
     /// Unregister the current node from the cluster.
     pub async fn unregister(&self) -> Result<()> {
         let request = DeleteWorkerNodeRequest {
@@ -841,6 +1154,36 @@ impl MetaClient {
         Ok(resp.nodes)
     }
 
+    pub async fn enter_maintenance_mode(&self) -> Result<()> {
+        let request = EnterMaintenanceModeRequest {};
+        let _resp = self.inner.enter_maintenance_mode(request).await?;
+        Ok(())
+    }
+
+    pub async fn exit_maintenance_mode(&self) -> Result<()> {
+        let request = ExitMaintenanceModeRequest {};
+        let _resp = self.inner.exit_maintenance_mode(request).await?;
+        Ok(())
+    }
+
+    /// Lists the usage records of the given database, or of all databases if unset,
+    /// whose window overlaps `[start_time_ms, end_time_ms]`. A zero bound means
+    /// unbounded on that side.
+    pub async fn get_usage_report(
+        &self,
+        database_id: Option<u32>,
+        start_time_ms: u64,
+        end_time_ms: u64,
+    ) -> Result<Vec<PbUsageRecord>> {
+        let request = GetUsageReportRequest {
+            database_id,
+            start_time_ms,
+            end_time_ms,
+        };
+        let resp = self.inner.get_usage_report(request).await?;
+        Ok(resp.records)
+    }
+
     /// Starts a heartbeat worker.
     ///
     /// When sending heartbeat RPC, it also carries extra info from `extra_info_sources`.
@@ -904,6 +1247,40 @@ impl MetaClient {
         Ok(resp.snapshot.unwrap())
     }
 
+    pub async fn create_named_checkpoint(&self, name: String) -> Result<NamedCheckpoint> {
+        let request = CreateNamedCheckpointRequest { name };
+        let resp = self.inner.create_named_checkpoint(request).await?;
+        Ok(resp.checkpoint.unwrap())
+    }
+
+    pub async fn list_named_checkpoints(&self) -> Result<Vec<NamedCheckpoint>> {
+        let request = ListNamedCheckpointsRequest {};
+        let resp = self.inner.list_named_checkpoints(request).await?;
+        Ok(resp.checkpoints)
+    }
+
+    pub async fn pin_named_checkpoint(&self, name: String) -> Result<()> {
+        let request = PinNamedCheckpointRequest { name };
+        self.inner.pin_named_checkpoint(request).await?;
+        Ok(())
+    }
+
+    /// Creates a consistency fence bound to the currently committed epoch, returning
+    /// its token together with the fence epoch.
+    pub async fn create_fence(&self) -> Result<(u64, u64)> {
+        let request = CreateFenceRequest {};
+        let resp = self.inner.create_fence(request).await?;
+        Ok((resp.token, resp.fence_epoch))
+    }
+
+    /// Resolves once a checkpoint has committed past the fence's epoch, returning the
+    /// committed epoch that resolved the fence.
+    pub async fn await_fence(&self, token: u64) -> Result<u64> {
+        let request = AwaitFenceRequest { token };
+        let resp = self.inner.await_fence(request).await?;
+        Ok(resp.committed_epoch)
+    }
+
     pub async fn wait(&self) -> Result<()> {
         let request = WaitRequest {};
         self.inner.wait(request).await?;
@@ -916,12 +1293,146 @@ impl MetaClient {
         Ok(())
     }
 
-    pub async fn cancel_creating_jobs(&self, jobs: PbJobs) -> Result<Vec<u32>> {
-        let request = CancelCreatingJobsRequest { jobs: Some(jobs) };
+    pub async fn get_source_split_history(
+        &self,
+        source_id: u32,
+    ) -> Result<Vec<SourceSplitDiscovery>> {
+        let request = GetSourceSplitHistoryRequest { source_id };
+        let resp = self.inner.get_source_split_history(request).await?;
+        Ok(resp.discoveries)
+    }
+
+    /// Returns the time-to-collect of the worker's recently collected barriers in
+    /// microseconds, oldest first.
+    pub async fn get_worker_barrier_latency(&self, worker_id: u32) -> Result<Vec<u64>> {
+        let request = GetWorkerBarrierLatencyRequest { worker_id };
+        let resp = self.inner.get_worker_barrier_latency(request).await?;
+        Ok(resp.latency_us)
+    }
+
+    /// Returns the trace ids of recently injected barriers, keyed by the barrier's
+    /// `curr_epoch`. Empty unless distributed tracing is enabled on the meta node.
+    pub async fn get_epoch_trace_ids(&self) -> Result<HashMap<u64, String>> {
+        let request = GetEpochTraceIdsRequest {};
+        let resp = self.inner.get_epoch_trace_ids(request).await?;
+        Ok(resp.trace_ids)
+    }
+
+    /// Returns a snapshot of the in-flight barriers on the meta node, oldest first.
+    pub async fn list_inflight_barriers(
+        &self,
+    ) -> Result<Vec<list_inflight_barriers_response::InflightBarrier>> {
+        let request = ListInflightBarriersRequest {};
+        let resp = self.inner.list_inflight_barriers(request).await?;
+        Ok(resp.barriers)
+    }
+
+    /// Returns the smoothed per-actor barrier collect latency reported by compute nodes.
+    pub async fn list_actor_backpressure(
+        &self,
+    ) -> Result<Vec<list_actor_backpressure_response::ActorBackpressure>> {
+        let request = ListActorBackpressureRequest {};
+        let resp = self.inner.list_actor_backpressure(request).await?;
+        Ok(resp.stats)
+    }
+
+    pub async fn list_source_partitions(&self, source_id: u32) -> Result<Vec<SourcePartition>> {
+        let request = ListSourcePartitionsRequest { source_id };
+        let resp = self.inner.list_source_partitions(request).await?;
+        Ok(resp.partitions)
+    }
+
+    pub async fn add_source_partition(&self, source_id: u32, partition_name: String) -> Result<()> {
+        let request = AddSourcePartitionRequest {
+            source_id,
+            partition_name,
+        };
+        self.inner.add_source_partition(request).await?;
+        Ok(())
+    }
+
+    pub async fn invalidate_source_partition(
+        &self,
+        source_id: u32,
+        partition_name: String,
+    ) -> Result<()> {
+        let request = InvalidateSourcePartitionRequest {
+            source_id,
+            partition_name,
+        };
+        self.inner.invalidate_source_partition(request).await?;
+        Ok(())
+    }
+
+    /// Reports decode-error statistic deltas accumulated since the previous report.
+    pub async fn report_source_error_stats(&self, stats: Vec<SourceErrorStats>) -> Result<()> {
+        let request = ReportSourceErrorStatsRequest { stats };
+        self.inner.report_source_error_stats(request).await?;
+        Ok(())
+    }
+
+    /// Returns the accumulated decode-error statistics of all sources, ordered by source id.
+    pub async fn list_source_error_stats(&self) -> Result<Vec<SourceErrorStats>> {
+        let request = ListSourceErrorStatsRequest {};
+        let resp = self.inner.list_source_error_stats(request).await?;
+        Ok(resp.stats)
+    }
+
+    pub async fn pause_streaming_job(&self, table_id: u32) -> Result<()> {
+        let request = PauseStreamingJobRequest { table_id };
+        self.inner.pause_streaming_job(request).await?;
+        Ok(())
+    }
+
+    pub async fn resume_streaming_job(&self, table_id: u32) -> Result<()> {
+        let request = ResumeStreamingJobRequest { table_id };
+        self.inner.resume_streaming_job(request).await?;
+        Ok(())
+    }
+
+        Ok(())
+    }
+
+    /// Captures the next `max_records` change records of the relation's materialize or
+    /// sink executor, starting at a barrier boundary.
+    pub async fn sample_changelog(
+        &self,
+        relation_id: u32,
+        max_records: u32,
+        filter: String,
+        timeout_ms: u64,
+    ) -> Result<Vec<sample_changelog_response::Record>> {
+        let request = SampleChangelogRequest {
+            relation_id,
+            max_records,
+            filter,
+            timeout_ms,
+        };
+        let resp = self.inner.sample_changelog(request).await?;
+        Ok(resp.records)
+    }
+
+    pub async fn cancel_creating_jobs(&self, jobs: PbJobs, suspend: bool) -> Result<Vec<u32>> {
+        let request = CancelCreatingJobsRequest {
+            jobs: Some(jobs),
+            suspend,
+        };
         let resp = self.inner.cancel_creating_jobs(request).await?;
         Ok(resp.canceled_jobs)
     }
 
+    pub async fn convert_creating_jobs_to_background(
+        &self,
+        jobs: convert_creating_jobs_to_background_request::Jobs,
+    ) -> Result<Vec<u32>> {
+        let request = ConvertCreatingJobsToBackgroundRequest { jobs: Some(jobs) };
+        let resp = self
+            .inner
+            .convert_creating_jobs_to_background(request)
+            .await?;
+        Ok(resp.converted_jobs)
+    }
+
     pub async fn list_table_fragments(
         &self,
         table_ids: &[u32],
@@ -965,6 +1476,19 @@ impl MetaClient {
         Ok(resp.dependencies)
     }
 
+    pub async fn get_dependency_graph(
+        &self,
+        depends_on_object_id: Option<u32>,
+    ) -> Result<GetDependencyGraphResponse> {
+        let resp = self
+            .inner
+            .get_dependency_graph(GetDependencyGraphRequest {
+                depends_on_object_id,
+            })
+            .await?;
+        Ok(resp)
+    }
+
     pub async fn pause(&self) -> Result<PauseResponse> {
         let request = PauseRequest {};
         let resp = self.inner.pause(request).await?;
@@ -977,6 +1501,27 @@ impl MetaClient {
         Ok(resp)
     }
 
+    pub async fn get_leader_lease(&self) -> Result<GetLeaderLeaseResponse> {
+        let request = GetLeaderLeaseRequest {};
+        let resp = self.inner.get_leader_lease(request).await?;
+        Ok(resp)
+    }
+
+    pub async fn trigger_leader_handover(&self) -> Result<()> {
+        let request = TriggerLeaderHandoverRequest {};
+        self.inner.trigger_leader_handover(request).await?;
+        Ok(())
+    }
+
+    /// Proactively refreshes the meta member list and the leader channel, e.g. when notified
+    /// that the current leader is resigning. Errors are swallowed since the periodic member
+    /// refresh will catch up eventually anyway.
+    pub async fn try_force_refresh_leader(&self) {
+        if let Err(e) = self.inner.force_refresh_leader().await {
+            tracing::warn!(error = %e.as_report(), "force refresh meta leader failed");
+        }
+    }
+
     pub async fn apply_throttle(
         &self,
         kind: PbThrottleTarget,
@@ -992,6 +1537,72 @@ impl MetaClient {
         Ok(resp)
     }
 
+    pub async fn force_drop_job(
+        &self,
+        job_id: u32,
+        user_id: u32,
+        confirmation: String,
+    ) -> Result<()> {
+        let request = ForceDropJobRequest {
+            job_id,
+            user_id,
+            confirmation,
+        };
+        let _resp = self.inner.force_drop_job(request).await?;
+        Ok(())
+    }
+
+    pub async fn analyze_duplicate_state(&self) -> Result<AnalyzeDuplicateStateResponse> {
+        let resp = self
+            .inner
+            .analyze_duplicate_state(AnalyzeDuplicateStateRequest {})
+            .await?;
+        Ok(resp)
+    }
+
+    pub async fn boost_rate_limit(
+        &self,
+        table_id: u32,
+        rate: Option<u32>,
+        duration_ms: u64,
+    ) -> Result<()> {
+        let request = BoostRateLimitRequest {
+            table_id,
+            rate,
+            duration_ms,
+        };
+        let _resp = self.inner.boost_rate_limit(request).await?;
+        Ok(())
+    }
+
+    pub async fn list_jobs_by_label(
+        &self,
+        selector: HashMap<String, String>,
+    ) -> Result<ListJobsByLabelResponse> {
+        let request = ListJobsByLabelRequest { selector };
+        let resp = self.inner.list_jobs_by_label(request).await?;
+        Ok(resp)
+    }
+
+    pub async fn apply_throttle_by_label(
+        &self,
+        selector: HashMap<String, String>,
+        rate: Option<u32>,
+    ) -> Result<ApplyThrottleByLabelResponse> {
+        let request = ApplyThrottleByLabelRequest { selector, rate };
+        let resp = self.inner.apply_throttle_by_label(request).await?;
+        Ok(resp)
+    }
+
+    pub async fn pause_jobs_by_label(
+        &self,
+        selector: HashMap<String, String>,
+    ) -> Result<PauseJobsByLabelResponse> {
+        let request = PauseJobsByLabelRequest { selector };
+        let resp = self.inner.pause_jobs_by_label(request).await?;
+        Ok(resp)
+    }
+
     pub async fn get_cluster_recovery_status(&self) -> Result<RecoveryStatus> {
         let resp = self
             .inner
@@ -1021,6 +1632,42 @@ impl MetaClient {
         Ok((resp.success, resp.revision))
     }
 
+    pub async fn plan_reschedule(
+        &self,
+        job_id: u32,
+        target_parallelism: u32,
+    ) -> Result<PlanRescheduleResponse> {
+        let request = PlanRescheduleRequest {
+            job_id,
+            target_parallelism,
+        };
+        let resp = self.inner.plan_reschedule(request).await?;
+        Ok(resp)
+    }
+
+    pub async fn apply_reschedule(&self, plan_id: u64) -> Result<(bool, u64)> {
+        let request = ApplyRescheduleRequest { plan_id };
+        let resp = self.inner.apply_reschedule(request).await?;
+        Ok((resp.success, resp.revision))
+    }
+
+    pub async fn migrate_actors(
+        &self,
+        fragment_id: u32,
+        from_worker_id: u32,
+        to_worker_id: u32,
+        count: u32,
+    ) -> Result<(bool, u64)> {
+        let request = MigrateActorsRequest {
+            fragment_id,
+            from_worker_id,
+            to_worker_id,
+            count,
+        };
+        let resp = self.inner.migrate_actors(request).await?;
+        Ok((resp.success, resp.revision))
+    }
+
     pub async fn risectl_get_pinned_versions_summary(
         &self,
     ) -> Result<RiseCtlGetPinnedVersionsSummaryResponse> {
@@ -1202,6 +1849,11 @@ impl MetaClient {
         Ok(resp.manifest.expect("should exist"))
     }
 
+    pub async fn restore_meta(&self, req: RestoreMetaRequest) -> Result<()> {
+        let _resp = self.inner.restore_meta(req).await?;
+        Ok(())
+    }
+
     pub async fn get_telemetry_info(&self) -> Result<TelemetryInfoResponse> {
         let req = GetTelemetryInfoRequest {};
         let resp = self.inner.get_telemetry_info(req).await?;
@@ -1242,6 +1894,13 @@ impl MetaClient {
         Ok(resp.ddl_progress)
     }
 
+    /// Returns the creation progress of indexes that are still being created.
+    pub async fn get_index_progress(&self) -> Result<Vec<IndexProgress>> {
+        let req = GetIndexProgressRequest {};
+        let resp = self.inner.get_index_progress(req).await?;
+        Ok(resp.progress)
+    }
+
     pub async fn split_compaction_group(
         &self,
         group_id: CompactionGroupId,
@@ -1265,6 +1924,29 @@ impl MetaClient {
         Ok(resp.tables)
     }
 
+    pub async fn get_catalog_memory_stats(&self) -> Result<Vec<CatalogMemoryStats>> {
+        let resp = self
+            .inner
+            .get_catalog_memory_stats(GetCatalogMemoryStatsRequest {})
+            .await?;
+        Ok(resp.stats)
+    }
+
+    /// Fetches the `CREATE` statements for the given relation and, optionally, all its
+    /// upstream dependencies, sorted in creation order.
+    pub async fn export_ddl(
+        &self,
+        relation_id: u32,
+        include_dependencies: bool,
+    ) -> Result<Vec<export_ddl_response::DdlStatement>> {
+        let req = ExportDdlRequest {
+            relation_id,
+            include_dependencies,
+        };
+        let resp = self.inner.export_ddl(req).await?;
+        Ok(resp.statements)
+    }
+
     pub async fn list_serving_vnode_mappings(
         &self,
     ) -> Result<HashMap<u32, (u32, WorkerSlotMapping)>> {
@@ -1289,6 +1971,18 @@ impl MetaClient {
         Ok(mappings)
     }
 
+    pub async fn report_relation_read_stats(&self, stats: Vec<RelationReadStats>) -> Result<()> {
+        let req = ReportRelationReadStatsRequest { stats };
+        self.inner.report_relation_read_stats(req).await?;
+        Ok(())
+    }
+
+    pub async fn list_relation_read_stats(&self) -> Result<Vec<RelationReadStats>> {
+        let req = ListRelationReadStatsRequest {};
+        let resp = self.inner.list_relation_read_stats(req).await?;
+        Ok(resp.stats)
+    }
+
     pub async fn risectl_list_compaction_status(
         &self,
     ) -> Result<(
@@ -1378,6 +2072,18 @@ impl MetaClient {
         Ok(resp.event_logs)
     }
 
+    /// Reports that a subscription consumer is lagging close to the retention limit.
+    pub async fn add_subscription_lag_event(
+        &self,
+        event: event_log::EventSubscriptionLag,
+    ) -> Result<()> {
+        let req = AddEventLogRequest {
+            event: Some(add_event_log_request::Event::SubscriptionLag(event)),
+        };
+        self.inner.add_event_log(req).await?;
+        Ok(())
+    }
+
     pub async fn list_compact_task_progress(&self) -> Result<Vec<CompactTaskProgress>> {
         let req = ListCompactTaskProgressRequest {};
         let resp = self.inner.list_compact_task_progress(req).await?;
@@ -1464,6 +2170,14 @@ impl MetaClient {
         self.inner.merge_compaction_group(req).await?;
         Ok(())
     }
+
+    /// List the effective safe GC watermark per state table and the holders (subscriptions,
+    /// time travel, pinned snapshots) that keep each watermark back.
+    pub async fn list_retention_watermarks(&self) -> Result<Vec<TableRetentionWatermark>> {
+        let req = ListRetentionWatermarksRequest {};
+        let resp = self.inner.list_retention_watermarks(req).await?;
+        Ok(resp.watermarks)
+    }
 }
 
 #[async_trait]
@@ -2058,23 +2772,57 @@ macro_rules! for_all_meta_rpc {
             ,{ cluster_client, update_worker_node_schedulability, UpdateWorkerNodeSchedulabilityRequest, UpdateWorkerNodeSchedulabilityResponse }
             ,{ cluster_client, list_all_nodes, ListAllNodesRequest, ListAllNodesResponse }
             ,{ cluster_client, get_cluster_recovery_status, GetClusterRecoveryStatusRequest, GetClusterRecoveryStatusResponse }
+            ,{ cluster_client, enter_maintenance_mode, EnterMaintenanceModeRequest, EnterMaintenanceModeResponse }
+            ,{ cluster_client, exit_maintenance_mode, ExitMaintenanceModeRequest, ExitMaintenanceModeResponse }
+            ,{ cluster_client, get_usage_report, GetUsageReportRequest, GetUsageReportResponse }
+            ,{ meta_member_client, get_leader_lease, GetLeaderLeaseRequest, GetLeaderLeaseResponse }
+            ,{ meta_member_client, trigger_leader_handover, TriggerLeaderHandoverRequest, TriggerLeaderHandoverResponse }
             ,{ heartbeat_client, heartbeat, HeartbeatRequest, HeartbeatResponse }
             ,{ stream_client, flush, FlushRequest, FlushResponse }
             ,{ stream_client, pause, PauseRequest, PauseResponse }
             ,{ stream_client, resume, ResumeRequest, ResumeResponse }
             ,{ stream_client, apply_throttle, ApplyThrottleRequest, ApplyThrottleResponse }
+            ,{ stream_client, boost_rate_limit, BoostRateLimitRequest, BoostRateLimitResponse }
+            ,{ stream_client, list_jobs_by_label, ListJobsByLabelRequest, ListJobsByLabelResponse }
+            ,{ stream_client, apply_throttle_by_label, ApplyThrottleByLabelRequest, ApplyThrottleByLabelResponse }
+            ,{ stream_client, pause_jobs_by_label, PauseJobsByLabelRequest, PauseJobsByLabelResponse }
             ,{ stream_client, cancel_creating_jobs, CancelCreatingJobsRequest, CancelCreatingJobsResponse }
+            ,{ stream_client, force_drop_job, ForceDropJobRequest, ForceDropJobResponse }
+            ,{ stream_client, analyze_duplicate_state, AnalyzeDuplicateStateRequest, AnalyzeDuplicateStateResponse }
+            ,{ stream_client, convert_creating_jobs_to_background, ConvertCreatingJobsToBackgroundRequest, ConvertCreatingJobsToBackgroundResponse }
             ,{ stream_client, list_table_fragments, ListTableFragmentsRequest, ListTableFragmentsResponse }
             ,{ stream_client, list_table_fragment_states, ListTableFragmentStatesRequest, ListTableFragmentStatesResponse }
             ,{ stream_client, list_fragment_distribution, ListFragmentDistributionRequest, ListFragmentDistributionResponse }
             ,{ stream_client, list_actor_states, ListActorStatesRequest, ListActorStatesResponse }
             ,{ stream_client, list_object_dependencies, ListObjectDependenciesRequest, ListObjectDependenciesResponse }
+            ,{ stream_client, get_dependency_graph, GetDependencyGraphRequest, GetDependencyGraphResponse }
             ,{ stream_client, recover, RecoverRequest, RecoverResponse }
+            ,{ stream_client, get_source_split_history, GetSourceSplitHistoryRequest, GetSourceSplitHistoryResponse }
+            ,{ stream_client, get_worker_barrier_latency, GetWorkerBarrierLatencyRequest, GetWorkerBarrierLatencyResponse }
+            ,{ stream_client, get_epoch_trace_ids, GetEpochTraceIdsRequest, GetEpochTraceIdsResponse }
+            ,{ stream_client, list_inflight_barriers, ListInflightBarriersRequest, ListInflightBarriersResponse }
+            ,{ stream_client, list_actor_backpressure, ListActorBackpressureRequest, ListActorBackpressureResponse }
+            ,{ stream_client, list_source_partitions, ListSourcePartitionsRequest, ListSourcePartitionsResponse }
+            ,{ stream_client, add_source_partition, AddSourcePartitionRequest, AddSourcePartitionResponse }
+            ,{ stream_client, invalidate_source_partition, InvalidateSourcePartitionRequest, InvalidateSourcePartitionResponse }
+            ,{ stream_client, report_source_error_stats, ReportSourceErrorStatsRequest, ReportSourceErrorStatsResponse }
+            ,{ stream_client, list_source_error_stats, ListSourceErrorStatsRequest, ListSourceErrorStatsResponse }
+            ,{ stream_client, pause_streaming_job, PauseStreamingJobRequest, PauseStreamingJobResponse }
+            ,{ stream_client, resume_streaming_job, ResumeStreamingJobRequest, ResumeStreamingJobResponse }
+            ,{ stream_client, sample_changelog, SampleChangelogRequest, SampleChangelogResponse }
+            ,{ stream_client, create_named_checkpoint, CreateNamedCheckpointRequest, CreateNamedCheckpointResponse }
+            ,{ stream_client, list_named_checkpoints, ListNamedCheckpointsRequest, ListNamedCheckpointsResponse }
+            ,{ stream_client, pin_named_checkpoint, PinNamedCheckpointRequest, PinNamedCheckpointResponse }
+            ,{ stream_client, create_fence, CreateFenceRequest, CreateFenceResponse }
+            ,{ stream_client, await_fence, AwaitFenceRequest, AwaitFenceResponse }
             ,{ ddl_client, create_table, CreateTableRequest, CreateTableResponse }
             ,{ ddl_client, alter_name, AlterNameRequest, AlterNameResponse }
             ,{ ddl_client, alter_owner, AlterOwnerRequest, AlterOwnerResponse }
+            ,{ ddl_client, alter_schema_default_owner, AlterSchemaDefaultOwnerRequest, AlterSchemaDefaultOwnerResponse }
             ,{ ddl_client, alter_set_schema, AlterSetSchemaRequest, AlterSetSchemaResponse }
             ,{ ddl_client, alter_parallelism, AlterParallelismRequest, AlterParallelismResponse }
+            ,{ ddl_client, alter_stream_job_priority, AlterStreamJobPriorityRequest, AlterStreamJobPriorityResponse }
+            ,{ ddl_client, alter_table_ttl, AlterTableTtlRequest, AlterTableTtlResponse }
             ,{ ddl_client, create_materialized_view, CreateMaterializedViewRequest, CreateMaterializedViewResponse }
             ,{ ddl_client, create_view, CreateViewRequest, CreateViewResponse }
             ,{ ddl_client, create_source, CreateSourceRequest, CreateSourceResponse }
@@ -2087,26 +2835,48 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, create_function, CreateFunctionRequest, CreateFunctionResponse }
             ,{ ddl_client, drop_table, DropTableRequest, DropTableResponse }
             ,{ ddl_client, drop_materialized_view, DropMaterializedViewRequest, DropMaterializedViewResponse }
+            ,{ ddl_client, undrop_relation, UndropRelationRequest, UndropRelationResponse }
             ,{ ddl_client, drop_view, DropViewRequest, DropViewResponse }
             ,{ ddl_client, drop_source, DropSourceRequest, DropSourceResponse }
              , {ddl_client, drop_secret, DropSecretRequest, DropSecretResponse}
+             , {ddl_client, rotate_secret, RotateSecretRequest, RotateSecretResponse}
             ,{ ddl_client, drop_sink, DropSinkRequest, DropSinkResponse }
             ,{ ddl_client, drop_subscription, DropSubscriptionRequest, DropSubscriptionResponse }
+            ,{ ddl_client, ack_subscription_epoch, AckSubscriptionEpochRequest, AckSubscriptionEpochResponse }
             ,{ ddl_client, drop_database, DropDatabaseRequest, DropDatabaseResponse }
             ,{ ddl_client, drop_schema, DropSchemaRequest, DropSchemaResponse }
             ,{ ddl_client, drop_index, DropIndexRequest, DropIndexResponse }
             ,{ ddl_client, drop_function, DropFunctionRequest, DropFunctionResponse }
             ,{ ddl_client, replace_table_plan, ReplaceTablePlanRequest, ReplaceTablePlanResponse }
+            ,{ ddl_client, acquire_alter_lease, AcquireAlterLeaseRequest, AcquireAlterLeaseResponse }
             ,{ ddl_client, alter_source, AlterSourceRequest, AlterSourceResponse }
+            ,{ ddl_client, alter_source_add_column, AlterSourceAddColumnRequest, AlterSourceAddColumnResponse }
+            ,{ ddl_client, alter_connector_props, AlterConnectorPropsRequest, AlterConnectorPropsResponse }
             ,{ ddl_client, risectl_list_state_tables, RisectlListStateTablesRequest, RisectlListStateTablesResponse }
             ,{ ddl_client, get_ddl_progress, GetDdlProgressRequest, GetDdlProgressResponse }
+            ,{ ddl_client, get_index_progress, GetIndexProgressRequest, GetIndexProgressResponse }
             ,{ ddl_client, create_connection, CreateConnectionRequest, CreateConnectionResponse }
             ,{ ddl_client, list_connections, ListConnectionsRequest, ListConnectionsResponse }
             ,{ ddl_client, drop_connection, DropConnectionRequest, DropConnectionResponse }
             ,{ ddl_client, comment_on, CommentOnRequest, CommentOnResponse }
+            ,{ ddl_client, alter_annotation, AlterAnnotationRequest, AlterAnnotationResponse }
+            ,{ ddl_client, alter_label, AlterLabelRequest, AlterLabelResponse }
+            ,{ ddl_client, alter_storage_class_policy, AlterStorageClassPolicyRequest, AlterStorageClassPolicyResponse }
+            ,{ ddl_client, alter_schema_change_policy, AlterSchemaChangePolicyRequest, AlterSchemaChangePolicyResponse }
+            ,{ ddl_client, alter_database_barrier_interval, AlterDatabaseBarrierIntervalRequest, AlterDatabaseBarrierIntervalResponse }
+            ,{ ddl_client, alter_database_session_default, AlterDatabaseSessionDefaultRequest, AlterDatabaseSessionDefaultResponse }
             ,{ ddl_client, get_tables, GetTablesRequest, GetTablesResponse }
+            ,{ ddl_client, get_catalog_memory_stats, GetCatalogMemoryStatsRequest, GetCatalogMemoryStatsResponse }
+            ,{ ddl_client, export_ddl, ExportDdlRequest, ExportDdlResponse }
             ,{ ddl_client, wait, WaitRequest, WaitResponse }
             ,{ ddl_client, auto_schema_change, AutoSchemaChangeRequest, AutoSchemaChangeResponse }
+            ,{ ddl_client, list_connector_property_schemas, ListConnectorPropertySchemasRequest, ListConnectorPropertySchemasResponse }
+            ,{ ddl_client, list_object_lints, ListObjectLintsRequest, ListObjectLintsResponse }
+            ,{ ddl_client, get_catalog_lock_stats, GetCatalogLockStatsRequest, GetCatalogLockStatsResponse }
+            ,{ ddl_client, set_streaming_job_quota, SetStreamingJobQuotaRequest, SetStreamingJobQuotaResponse }
+            ,{ ddl_client, list_streaming_job_quotas, ListStreamingJobQuotasRequest, ListStreamingJobQuotasResponse }
+            ,{ ddl_client, list_ddl_audit_logs, ListDdlAuditLogsRequest, ListDdlAuditLogsResponse }
+            ,{ ddl_client, run_ddl_batch, RunDdlBatchRequest, RunDdlBatchResponse }
             ,{ hummock_client, unpin_version_before, UnpinVersionBeforeRequest, UnpinVersionBeforeResponse }
             ,{ hummock_client, get_current_version, GetCurrentVersionRequest, GetCurrentVersionResponse }
             ,{ hummock_client, replay_version_delta, ReplayVersionDeltaRequest, ReplayVersionDeltaResponse }
@@ -2146,24 +2916,36 @@ macro_rules! for_all_meta_rpc {
             ,{ hummock_client, list_change_log_epochs, ListChangeLogEpochsRequest, ListChangeLogEpochsResponse }
             ,{ hummock_client, get_version_by_epoch, GetVersionByEpochRequest, GetVersionByEpochResponse }
             ,{ hummock_client, merge_compaction_group, MergeCompactionGroupRequest, MergeCompactionGroupResponse }
+            ,{ hummock_client, list_retention_watermarks, ListRetentionWatermarksRequest, ListRetentionWatermarksResponse }
             ,{ user_client, create_user, CreateUserRequest, CreateUserResponse }
             ,{ user_client, update_user, UpdateUserRequest, UpdateUserResponse }
             ,{ user_client, drop_user, DropUserRequest, DropUserResponse }
             ,{ user_client, grant_privilege, GrantPrivilegeRequest, GrantPrivilegeResponse }
             ,{ user_client, revoke_privilege, RevokePrivilegeRequest, RevokePrivilegeResponse }
+            ,{ user_client, grant_role, GrantRoleRequest, GrantRoleResponse }
+            ,{ user_client, revoke_role, RevokeRoleRequest, RevokeRoleResponse }
+            ,{ user_client, alter_default_privileges, AlterDefaultPrivilegesRequest, AlterDefaultPrivilegesResponse }
             ,{ scale_client, get_cluster_info, GetClusterInfoRequest, GetClusterInfoResponse }
             ,{ scale_client, reschedule, RescheduleRequest, RescheduleResponse }
+            ,{ scale_client, plan_reschedule, PlanRescheduleRequest, PlanRescheduleResponse }
+            ,{ scale_client, apply_reschedule, ApplyRescheduleRequest, ApplyRescheduleResponse }
+            ,{ scale_client, migrate_actors, MigrateActorsRequest, MigrateActorsResponse }
             ,{ notification_client, subscribe, SubscribeRequest, Streaming<SubscribeResponse> }
+            ,{ notification_client, subscribe_table_change, SubscribeTableChangeRequest, Streaming<TableChangeNotification> }
+            ,{ notification_client, get_catalog_deltas, GetCatalogDeltasRequest, GetCatalogDeltasResponse }
             ,{ backup_client, backup_meta, BackupMetaRequest, BackupMetaResponse }
             ,{ backup_client, get_backup_job_status, GetBackupJobStatusRequest, GetBackupJobStatusResponse }
             ,{ backup_client, delete_meta_snapshot, DeleteMetaSnapshotRequest, DeleteMetaSnapshotResponse}
             ,{ backup_client, get_meta_snapshot_manifest, GetMetaSnapshotManifestRequest, GetMetaSnapshotManifestResponse}
+            ,{ backup_client, restore_meta, RestoreMetaRequest, RestoreMetaResponse}
             ,{ telemetry_client, get_telemetry_info, GetTelemetryInfoRequest, TelemetryInfoResponse}
             ,{ system_params_client, get_system_params, GetSystemParamsRequest, GetSystemParamsResponse }
             ,{ system_params_client, set_system_param, SetSystemParamRequest, SetSystemParamResponse }
             ,{ session_params_client, get_session_params, GetSessionParamsRequest, GetSessionParamsResponse }
             ,{ session_params_client, set_session_param, SetSessionParamRequest, SetSessionParamResponse }
             ,{ serving_client, get_serving_vnode_mappings, GetServingVnodeMappingsRequest, GetServingVnodeMappingsResponse }
+            ,{ serving_client, report_relation_read_stats, ReportRelationReadStatsRequest, ReportRelationReadStatsResponse }
+            ,{ serving_client, list_relation_read_stats, ListRelationReadStatsRequest, ListRelationReadStatsResponse }
             ,{ cloud_client, rw_cloud_validate_source, RwCloudValidateSourceRequest, RwCloudValidateSourceResponse }
             ,{ event_log_client, list_event_log, ListEventLogRequest, ListEventLogResponse }
             ,{ event_log_client, add_event_log, AddEventLogRequest, AddEventLogResponse }
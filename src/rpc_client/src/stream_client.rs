@@ -74,6 +74,7 @@ macro_rules! for_all_stream_rpc {
     ($macro:ident) => {
         $macro! {
             { 0, wait_epoch_commit, WaitEpochCommitRequest, WaitEpochCommitResponse }
+            ,{ 0, sample_changelog, SampleChangelogRequest, SampleChangelogResponse }
         }
     };
 }
@@ -20,9 +20,10 @@ use core::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::ConnectorSchema;
+use super::{value, ConnectorSchema};
 use crate::ast::{
-    display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName, SetVariableValue,
+    display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName,
+    SetVariableValue, SqlOption,
 };
 use crate::tokenizer::Token;
 
@@ -36,8 +37,17 @@ pub enum AlterDatabaseOperation {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlterSchemaOperation {
-    ChangeOwner { new_owner_name: Ident },
-    RenameSchema { schema_name: ObjectName },
+    ChangeOwner {
+        new_owner_name: Ident,
+    },
+    RenameSchema {
+        schema_name: ObjectName,
+    },
+    /// `DEFAULT OWNER TO { new_owner | NONE }`. `None` clears the template so that
+    /// objects created in the schema are owned by their creator again.
+    ChangeDefaultOwner {
+        new_owner_name: Option<Ident>,
+    },
 }
 
 /// An `ALTER TABLE` (`Statement::AlterTable`) operation
@@ -110,6 +120,14 @@ pub enum AlterTableOperation {
     SetBackfillRateLimit {
         rate_limit: i32,
     },
+    /// `SET TTL TO '<interval>' | DEFAULT`
+    SetTtl {
+        retention: Option<String>,
+    },
+    /// `SET DISTRIBUTED BY (<column_name> [, ...])`
+    SetDistributedBy {
+        columns: Vec<Ident>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -146,6 +164,10 @@ pub enum AlterViewOperation {
     SetBackfillRateLimit {
         rate_limit: i32,
     },
+    /// `SET PRIORITY TO <priority>`
+    SetPriority {
+        priority: Ident,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -165,6 +187,10 @@ pub enum AlterSinkOperation {
         parallelism: SetVariableValue,
         deferred: bool,
     },
+    /// `SET SINK_RATE_LIMIT TO <rate_limit>`
+    SetSinkRateLimit {
+        rate_limit: i32,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -185,18 +211,28 @@ pub enum AlterSourceOperation {
     FormatEncode { connector_schema: ConnectorSchema },
     RefreshSchema,
     SetSourceRateLimit { rate_limit: i32 },
+    SetWithProperties { with_properties: Vec<SqlOption> },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlterFunctionOperation {
+    RenameFunction { function_name: ObjectName },
     SetSchema { new_schema_name: ObjectName },
+    ChangeOwner { new_owner_name: Ident },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlterConnectionOperation {
     SetSchema { new_schema_name: ObjectName },
+    ChangeOwner { new_owner_name: Ident },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlterSecretOperation {
+    ChangeOwner { new_owner_name: Ident },
 }
 
 impl fmt::Display for AlterDatabaseOperation {
@@ -221,6 +257,10 @@ impl fmt::Display for AlterSchemaOperation {
             AlterSchemaOperation::RenameSchema { schema_name } => {
                 write!(f, "RENAME TO {}", schema_name)
             }
+            AlterSchemaOperation::ChangeDefaultOwner { new_owner_name } => match new_owner_name {
+                Some(new_owner_name) => write!(f, "DEFAULT OWNER TO {}", new_owner_name),
+                None => write!(f, "DEFAULT OWNER TO NONE"),
+            },
         }
     }
 }
@@ -300,6 +340,19 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::SetBackfillRateLimit { rate_limit } => {
                 write!(f, "SET BACKFILL_RATE_LIMIT TO {}", rate_limit)
             }
+            AlterTableOperation::SetTtl { retention } => match retention {
+                Some(retention) => {
+                    write!(f, "SET TTL TO '{}'", value::escape_single_quote_string(retention))
+                }
+                None => write!(f, "SET TTL TO DEFAULT"),
+            },
+            AlterTableOperation::SetDistributedBy { columns } => {
+                write!(
+                    f,
+                    "SET DISTRIBUTED BY ({})",
+                    display_comma_separated(columns)
+                )
+            }
         }
     }
 }
@@ -351,6 +404,9 @@ impl fmt::Display for AlterViewOperation {
             AlterViewOperation::SetBackfillRateLimit { rate_limit } => {
                 write!(f, "SET BACKFILL_RATE_LIMIT TO {}", rate_limit)
             }
+            AlterViewOperation::SetPriority { priority } => {
+                write!(f, "SET PRIORITY TO {}", priority)
+            }
         }
     }
 }
@@ -378,6 +434,9 @@ impl fmt::Display for AlterSinkOperation {
                     if *deferred { " DEFERRED" } else { "" }
                 )
             }
+            AlterSinkOperation::SetSinkRateLimit { rate_limit } => {
+                write!(f, "SET SINK_RATE_LIMIT TO {}", rate_limit)
+            }
         }
     }
 }
@@ -422,6 +481,13 @@ impl fmt::Display for AlterSourceOperation {
             AlterSourceOperation::SetSourceRateLimit { rate_limit } => {
                 write!(f, "SET SOURCE_RATE_LIMIT TO {}", rate_limit)
             }
+            AlterSourceOperation::SetWithProperties { with_properties } => {
+                write!(
+                    f,
+                    "SET PROPERTIES ({})",
+                    display_comma_separated(with_properties)
+                )
+            }
         }
     }
 }
@@ -429,9 +495,15 @@ impl fmt::Display for AlterSourceOperation {
 impl fmt::Display for AlterFunctionOperation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            AlterFunctionOperation::RenameFunction { function_name } => {
+                write!(f, "RENAME TO {function_name}")
+            }
             AlterFunctionOperation::SetSchema { new_schema_name } => {
                 write!(f, "SET SCHEMA {new_schema_name}")
             }
+            AlterFunctionOperation::ChangeOwner { new_owner_name } => {
+                write!(f, "OWNER TO {new_owner_name}")
+            }
         }
     }
 }
@@ -442,6 +514,19 @@ impl fmt::Display for AlterConnectionOperation {
             AlterConnectionOperation::SetSchema { new_schema_name } => {
                 write!(f, "SET SCHEMA {new_schema_name}")
             }
+            AlterConnectionOperation::ChangeOwner { new_owner_name } => {
+                write!(f, "OWNER TO {new_owner_name}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for AlterSecretOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlterSecretOperation::ChangeOwner { new_owner_name } => {
+                write!(f, "OWNER TO {new_owner_name}")
+            }
         }
     }
 }
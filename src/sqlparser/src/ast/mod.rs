@@ -38,6 +38,7 @@ use winnow::PResult;
 pub use self::data_type::{DataType, StructField};
 pub use self::ddl::{
     AlterColumnOperation, AlterConnectionOperation, AlterDatabaseOperation, AlterFunctionOperation,
+    AlterSecretOperation,
     AlterSchemaOperation, AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef,
     ReferentialAction, SourceWatermark, TableConstraint,
 };
@@ -1017,9 +1018,11 @@ pub enum ShowObject {
     Secret { schema: Option<Ident> },
     Function { schema: Option<Ident> },
     Indexes { table: ObjectName },
+    IndexProgress,
     Cluster,
     Jobs,
     ProcessList,
+    Backpressure,
     Cursor,
     SubscriptionCursor,
 }
@@ -1059,11 +1062,13 @@ impl fmt::Display for ShowObject {
             ShowObject::Connection { schema } => write!(f, "CONNECTIONS{}", fmt_schema(schema)),
             ShowObject::Function { schema } => write!(f, "FUNCTIONS{}", fmt_schema(schema)),
             ShowObject::Indexes { table } => write!(f, "INDEXES FROM {}", table),
+            ShowObject::IndexProgress => write!(f, "INDEX PROGRESS"),
             ShowObject::Cluster => {
                 write!(f, "CLUSTER")
             }
             ShowObject::Jobs => write!(f, "JOBS"),
             ShowObject::ProcessList => write!(f, "PROCESSLIST"),
+            ShowObject::Backpressure => write!(f, "BACKPRESSURE"),
             ShowObject::Subscription { schema } => write!(f, "SUBSCRIPTIONS{}", fmt_schema(schema)),
             ShowObject::Secret { schema } => write!(f, "SECRETS{}", fmt_schema(schema)),
             ShowObject::Cursor => write!(f, "CURSORS"),
@@ -1105,6 +1110,14 @@ impl fmt::Display for ShowCreateType {
 pub enum CommentObject {
     Column,
     Table,
+    Source,
+    Sink,
+    View,
+    Index,
+    Function,
+    Connection,
+    Secret,
+    Schema,
 }
 
 impl fmt::Display for CommentObject {
@@ -1112,6 +1125,14 @@ impl fmt::Display for CommentObject {
         match self {
             CommentObject::Column => f.write_str("COLUMN"),
             CommentObject::Table => f.write_str("TABLE"),
+            CommentObject::Source => f.write_str("SOURCE"),
+            CommentObject::Sink => f.write_str("SINK"),
+            CommentObject::View => f.write_str("VIEW"),
+            CommentObject::Index => f.write_str("INDEX"),
+            CommentObject::Function => f.write_str("FUNCTION"),
+            CommentObject::Connection => f.write_str("CONNECTION"),
+            CommentObject::Secret => f.write_str("SECRET"),
+            CommentObject::Schema => f.write_str("SCHEMA"),
         }
     }
 }
@@ -1141,6 +1162,8 @@ pub struct ExplainOptions {
     pub verbose: bool,
     // Trace plan transformation of the optimizer step by step
     pub trace: bool,
+    // Estimate the resource usage of the streaming job instead of showing the plan
+    pub estimate: bool,
     // explain's plan type
     pub explain_type: ExplainType,
 }
@@ -1150,6 +1173,7 @@ impl Default for ExplainOptions {
         Self {
             verbose: false,
             trace: false,
+            estimate: false,
             explain_type: ExplainType::Physical,
         }
     }
@@ -1168,6 +1192,9 @@ impl fmt::Display for ExplainOptions {
             if self.trace {
                 option_strs.push("TRACE".to_string());
             }
+            if self.estimate {
+                option_strs.push("ESTIMATE".to_string());
+            }
             if self.explain_type == default.explain_type {
                 option_strs.push(self.explain_type.to_string());
             }
@@ -1410,6 +1437,12 @@ pub enum Statement {
         name: ObjectName,
         operation: AlterConnectionOperation,
     },
+    /// ALTER SECRET
+    AlterSecret {
+        /// Secret name
+        name: ObjectName,
+        operation: AlterSecretOperation,
+    },
     /// DESCRIBE TABLE OR SOURCE
     Describe {
         /// Table or Source name
@@ -1532,6 +1565,28 @@ pub enum Statement {
         revoke_grant_option: bool,
         cascade: bool,
     },
+    /// GRANT roles TO grantees
+    GrantRole {
+        roles: Vec<Ident>,
+        grantees: Vec<Ident>,
+        granted_by: Option<Ident>,
+    },
+    /// REVOKE roles FROM grantees
+    RevokeRole {
+        roles: Vec<Ident>,
+        grantees: Vec<Ident>,
+        granted_by: Option<Ident>,
+    },
+    /// ALTER DEFAULT PRIVILEGES IN SCHEMA schemas GRANT/REVOKE privileges ON objects TO/FROM grantees
+    AlterDefaultPrivileges {
+        schemas: Vec<ObjectName>,
+        /// `true` for `GRANT`, `false` for `REVOKE`.
+        grant: bool,
+        privileges: Privileges,
+        objects: DefaultPrivilegeObjects,
+        grantees: Vec<Ident>,
+        with_grant_option: bool,
+    },
     /// `DEALLOCATE [ PREPARE ] { name | ALL }`
     ///
     /// Note: this is a PostgreSQL-specific statement.
@@ -1576,6 +1631,14 @@ pub enum Statement {
     ///
     /// Note: RisingWave specific statement.
     Flush,
+    /// `CREATE CHECKPOINT name`
+    ///
+    /// Forces a checkpoint barrier and records it as a named savepoint.
+    ///
+    /// Note: RisingWave specific statement.
+    CreateCheckpoint {
+        name: Ident,
+    },
     /// WAIT for ALL running stream jobs to finish.
     /// It will block the current session the condition is met.
     Wait,
@@ -1950,6 +2013,9 @@ impl fmt::Display for Statement {
             Statement::AlterConnection { name, operation } => {
                 write!(f, "ALTER CONNECTION {} {}", name, operation)
             }
+            Statement::AlterSecret { name, operation } => {
+                write!(f, "ALTER SECRET {} {}", name, operation)
+            }
             Statement::Discard(t) => write!(f, "DISCARD {}", t),
             Statement::Drop(stmt) => write!(f, "DROP {}", stmt),
             Statement::DropFunction {
@@ -2109,6 +2175,65 @@ impl fmt::Display for Statement {
                 write!(f, " {}", if *cascade { "CASCADE" } else { "RESTRICT" })?;
                 Ok(())
             }
+            Statement::GrantRole {
+                roles,
+                grantees,
+                granted_by,
+            } => {
+                write!(f, "GRANT {} ", display_comma_separated(roles))?;
+                write!(f, "TO {}", display_comma_separated(grantees))?;
+                if let Some(grantor) = granted_by {
+                    write!(f, " GRANTED BY {}", grantor)?;
+                }
+                Ok(())
+            }
+            Statement::RevokeRole {
+                roles,
+                grantees,
+                granted_by,
+            } => {
+                write!(f, "REVOKE {} ", display_comma_separated(roles))?;
+                write!(f, "FROM {}", display_comma_separated(grantees))?;
+                if let Some(grantor) = granted_by {
+                    write!(f, " GRANTED BY {}", grantor)?;
+                }
+                Ok(())
+            }
+            Statement::AlterDefaultPrivileges {
+                schemas,
+                grant,
+                privileges,
+                objects,
+                grantees,
+                with_grant_option,
+            } => {
+                write!(
+                    f,
+                    "ALTER DEFAULT PRIVILEGES IN SCHEMA {} ",
+                    display_comma_separated(schemas)
+                )?;
+                if *grant {
+                    write!(
+                        f,
+                        "GRANT {} ON {} TO {}",
+                        privileges,
+                        objects,
+                        display_comma_separated(grantees)
+                    )?;
+                    if *with_grant_option {
+                        write!(f, " WITH GRANT OPTION")?;
+                    }
+                } else {
+                    write!(
+                        f,
+                        "REVOKE {} ON {} FROM {}",
+                        privileges,
+                        objects,
+                        display_comma_separated(grantees)
+                    )?;
+                }
+                Ok(())
+            }
             Statement::Deallocate { name, prepare } => write!(
                 f,
                 "DEALLOCATE {prepare}{name}",
@@ -2161,6 +2286,9 @@ impl fmt::Display for Statement {
             Statement::Flush => {
                 write!(f, "FLUSH")
             }
+            Statement::CreateCheckpoint { name } => {
+                write!(f, "CREATE CHECKPOINT {}", name)
+            }
             Statement::Wait => {
                 write!(f, "WAIT")
             }
@@ -2318,6 +2446,10 @@ pub enum GrantObjects {
     Tables(Vec<ObjectName>),
     /// Grant privileges on specific sinks
     Sinks(Vec<ObjectName>),
+    /// Grant privileges on specific subscriptions
+    Subscriptions(Vec<ObjectName>),
+    /// Grant privileges on specific secrets
+    Secrets(Vec<ObjectName>),
 }
 
 impl fmt::Display for GrantObjects {
@@ -2372,6 +2504,31 @@ impl fmt::Display for GrantObjects {
             GrantObjects::Sinks(sinks) => {
                 write!(f, "SINK {}", display_comma_separated(sinks))
             }
+            GrantObjects::Subscriptions(subscriptions) => {
+                write!(f, "SUBSCRIPTION {}", display_comma_separated(subscriptions))
+            }
+            GrantObjects::Secrets(secrets) => {
+                write!(f, "SECRET {}", display_comma_separated(secrets))
+            }
+        }
+    }
+}
+
+/// The kind of relations an `ALTER DEFAULT PRIVILEGES` statement applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DefaultPrivilegeObjects {
+    Tables,
+    Sources,
+    Sinks,
+}
+
+impl fmt::Display for DefaultPrivilegeObjects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefaultPrivilegeObjects::Tables => f.write_str("TABLES"),
+            DefaultPrivilegeObjects::Sources => f.write_str("SOURCES"),
+            DefaultPrivilegeObjects::Sinks => f.write_str("SINKS"),
         }
     }
 }
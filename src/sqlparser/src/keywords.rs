@@ -94,6 +94,7 @@ define_keywords!(
     ATOMIC,
     AUTHORIZATION,
     AUTO,
+    BACKPRESSURE,
     AVG,
     BASE64,
     BEGIN,
@@ -127,6 +128,7 @@ define_keywords!(
     CHARACTER_LENGTH,
     CHAR_LENGTH,
     CHECK,
+    CHECKPOINT,
     CLOB,
     CLOSE,
     CLUSTER,
@@ -218,6 +220,7 @@ define_keywords!(
     EQUALS,
     ERROR,
     ESCAPE,
+    ESTIMATE,
     EVENT,
     EVERY,
     EXCEPT,
@@ -399,9 +402,11 @@ define_keywords!(
     PRECISION,
     PREPARE,
     PRIMARY,
+    PRIORITY,
     PRIVILEGES,
     PROCEDURE,
     PROCESSLIST,
+    PROGRESS,
     PURGE,
     RANGE,
     RANK,
@@ -437,6 +442,7 @@ define_keywords!(
     RETURNS,
     REVOKE,
     RIGHT,
+    ROLE,
     ROLLBACK,
     ROLLUP,
     ROW,
@@ -2033,8 +2033,13 @@ impl Parser<'_> {
             self.parse_create_database()
         } else if self.parse_keyword(Keyword::USER) {
             self.parse_create_user()
+        } else if self.parse_keyword(Keyword::ROLE) {
+            self.parse_create_role()
         } else if self.parse_keyword(Keyword::SECRET) {
             self.parse_create_secret()
+        } else if self.parse_keyword(Keyword::CHECKPOINT) {
+            let name = self.parse_identifier_non_reserved()?;
+            Ok(Statement::CreateCheckpoint { name })
         } else {
             self.expected("an object type after CREATE")
         }
@@ -2417,6 +2422,23 @@ impl Parser<'_> {
         Ok(Statement::CreateUser(CreateUserStatement::parse_to(self)?))
     }
 
+    // CREATE ROLE name [ [ WITH ] option [ ... ] ]
+    //
+    // A role is a user that cannot log in unless LOGIN is given explicitly, following the
+    // PostgreSQL convention that `CREATE USER` and `CREATE ROLE` only differ in the
+    // default of the LOGIN option.
+    fn parse_create_role(&mut self) -> PResult<Statement> {
+        let mut stmt = CreateUserStatement::parse_to(self)?;
+        let options = &mut stmt.with_options.0;
+        if !options
+            .iter()
+            .any(|option| matches!(option, UserOption::Login | UserOption::NoLogin))
+        {
+            options.push(UserOption::NoLogin);
+        }
+        Ok(Statement::CreateUser(stmt))
+    }
+
     fn parse_create_secret(&mut self) -> PResult<Statement> {
         Ok(Statement::CreateSecret {
             stmt: CreateSecretStatement::parse_to(self)?,
@@ -3022,15 +3044,19 @@ impl Parser<'_> {
             self.parse_alter_function()
         } else if self.parse_keyword(Keyword::CONNECTION) {
             self.parse_alter_connection()
+        } else if self.parse_keyword(Keyword::SECRET) {
+            self.parse_alter_secret()
         } else if self.parse_keyword(Keyword::USER) {
             self.parse_alter_user()
         } else if self.parse_keyword(Keyword::SYSTEM) {
             self.parse_alter_system()
         } else if self.parse_keyword(Keyword::SUBSCRIPTION) {
             self.parse_alter_subscription()
+        } else if self.parse_keywords(&[Keyword::DEFAULT, Keyword::PRIVILEGES]) {
+            self.parse_alter_default_privileges()
         } else {
             self.expected(
-                "DATABASE, SCHEMA, TABLE, INDEX, MATERIALIZED, VIEW, SINK, SUBSCRIPTION, SOURCE, FUNCTION, USER or SYSTEM after ALTER"
+                "DATABASE, SCHEMA, TABLE, INDEX, MATERIALIZED, VIEW, SINK, SUBSCRIPTION, SOURCE, FUNCTION, CONNECTION, SECRET, USER, SYSTEM or DEFAULT PRIVILEGES after ALTER"
             )
         }
     }
@@ -3066,12 +3092,19 @@ impl Parser<'_> {
             AlterSchemaOperation::ChangeOwner {
                 new_owner_name: owner_name,
             }
+        } else if self.parse_keywords(&[Keyword::DEFAULT, Keyword::OWNER, Keyword::TO]) {
+            let new_owner_name = if self.parse_keyword(Keyword::NONE) {
+                None
+            } else {
+                Some(self.parse_identifier()?)
+            };
+            AlterSchemaOperation::ChangeDefaultOwner { new_owner_name }
         } else if self.parse_keyword(Keyword::RENAME) {
             self.expect_keyword(Keyword::TO)?;
             let schema_name = self.parse_object_name()?;
             AlterSchemaOperation::RenameSchema { schema_name }
         } else {
-            return self.expected("RENAME OR OWNER TO after ALTER SCHEMA");
+            return self.expected("RENAME, OWNER TO or DEFAULT OWNER TO after ALTER SCHEMA");
         };
 
         Ok(Statement::AlterSchema {
@@ -3142,10 +3175,34 @@ impl Parser<'_> {
                     parallelism: value,
                     deferred,
                 }
+            } else if self.parse_keywords(&[Keyword::DISTRIBUTED, Keyword::BY]) {
+                let columns = self.parse_parenthesized_column_list(Mandatory)?;
+                AlterTableOperation::SetDistributedBy { columns }
+            } else if self.parse_keywords(&[Keyword::APPEND, Keyword::ONLY]) {
+                let append_only = if self.parse_keyword(Keyword::TRUE) {
+                    true
+                } else if self.parse_keyword(Keyword::FALSE) {
+                    false
+                } else {
+                    return self.expected("TRUE or FALSE after SET APPEND ONLY");
+                };
+                AlterTableOperation::SetAppendOnly { append_only }
             } else if let Some(rate_limit) = self.parse_alter_source_rate_limit(true)? {
                 AlterTableOperation::SetSourceRateLimit { rate_limit }
             } else if let Some(rate_limit) = self.parse_alter_backfill_rate_limit()? {
                 AlterTableOperation::SetBackfillRateLimit { rate_limit }
+            } else if self.parse_word("TTL") {
+                if self.expect_keyword(Keyword::TO).is_err()
+                    && self.expect_token(&Token::Eq).is_err()
+                {
+                    return self.expected("TO or = after ALTER TABLE SET TTL");
+                }
+                let retention = if self.parse_keyword(Keyword::DEFAULT) {
+                    None
+                } else {
+                    Some(self.parse_literal_string()?)
+                };
+                AlterTableOperation::SetTtl { retention }
             } else {
                 return self.expected("SCHEMA/PARALLELISM/SOURCE_RATE_LIMIT after SET");
             }
@@ -3221,6 +3278,28 @@ impl Parser<'_> {
         Ok(Some(rate_limit))
     }
 
+    /// SINK_RATE_LIMIT = default | NUMBER
+    /// SINK_RATE_LIMIT TO default | NUMBER
+    pub fn parse_alter_sink_rate_limit(&mut self) -> PResult<Option<i32>> {
+        if !self.parse_word("SINK_RATE_LIMIT") {
+            return Ok(None);
+        }
+        if self.expect_keyword(Keyword::TO).is_err() && self.expect_token(&Token::Eq).is_err() {
+            return self.expected("TO or = after ALTER SINK SET SINK_RATE_LIMIT");
+        }
+        let rate_limit = if self.parse_keyword(Keyword::DEFAULT) {
+            -1
+        } else {
+            let s = self.parse_number_value()?;
+            if let Ok(n) = s.parse::<i32>() {
+                n
+            } else {
+                return self.expected("number or DEFAULT");
+            }
+        };
+        Ok(Some(rate_limit))
+    }
+
     /// SOURCE_RATE_LIMIT = default | NUMBER
     /// SOURCE_RATE_LIMIT TO default | NUMBER
     pub fn parse_alter_source_rate_limit(&mut self, is_table: bool) -> PResult<Option<i32>> {
@@ -3321,8 +3400,18 @@ impl Parser<'_> {
                 && let Some(rate_limit) = self.parse_alter_backfill_rate_limit()?
             {
                 AlterViewOperation::SetBackfillRateLimit { rate_limit }
+            } else if self.parse_keyword(Keyword::PRIORITY) && materialized {
+                if self.expect_keyword(Keyword::TO).is_err()
+                    && self.expect_token(&Token::Eq).is_err()
+                {
+                    return self.expected("TO or = after ALTER MATERIALIZED VIEW SET PRIORITY");
+                }
+
+                let priority = self.parse_identifier()?;
+
+                AlterViewOperation::SetPriority { priority }
             } else {
-                return self.expected("SCHEMA/PARALLELISM/BACKFILL_RATE_LIMIT after SET");
+                return self.expected("SCHEMA/PARALLELISM/BACKFILL_RATE_LIMIT/PRIORITY after SET");
             }
         } else {
             return self.expected(&format!(
@@ -3372,8 +3461,10 @@ impl Parser<'_> {
                     parallelism: value,
                     deferred,
                 }
+            } else if let Some(rate_limit) = self.parse_alter_sink_rate_limit()? {
+                AlterSinkOperation::SetSinkRateLimit { rate_limit }
             } else {
-                return self.expected("SCHEMA/PARALLELISM after SET");
+                return self.expected("SCHEMA/PARALLELISM/SINK_RATE_LIMIT after SET");
             }
         } else {
             return self.expected("RENAME or OWNER TO or SET after ALTER SINK");
@@ -3443,10 +3534,14 @@ impl Parser<'_> {
                 AlterSourceOperation::SetSchema {
                     new_schema_name: schema_name,
                 }
+            } else if self.parse_word("PROPERTIES") {
+                self.expect_token(&Token::LParen)?;
+                let with_properties = self.parse_options_inner()?;
+                AlterSourceOperation::SetWithProperties { with_properties }
             } else if let Some(rate_limit) = self.parse_alter_source_rate_limit(false)? {
                 AlterSourceOperation::SetSourceRateLimit { rate_limit }
             } else {
-                return self.expected("SCHEMA after SET");
+                return self.expected("SCHEMA, PROPERTIES or SOURCE_RATE_LIMIT after SET");
             }
         } else if self.peek_nth_any_of_keywords(0, &[Keyword::FORMAT]) {
             let connector_schema = self.parse_schema()?.unwrap();
@@ -3471,7 +3566,14 @@ impl Parser<'_> {
     pub fn parse_alter_function(&mut self) -> PResult<Statement> {
         let FunctionDesc { name, args } = self.parse_function_desc()?;
 
-        let operation = if self.parse_keyword(Keyword::SET) {
+        let operation = if self.parse_keyword(Keyword::RENAME) {
+            if self.parse_keyword(Keyword::TO) {
+                let function_name = self.parse_object_name()?;
+                AlterFunctionOperation::RenameFunction { function_name }
+            } else {
+                return self.expected("TO after RENAME");
+            }
+        } else if self.parse_keyword(Keyword::SET) {
             if self.parse_keyword(Keyword::SCHEMA) {
                 let schema_name = self.parse_object_name()?;
                 AlterFunctionOperation::SetSchema {
@@ -3480,8 +3582,13 @@ impl Parser<'_> {
             } else {
                 return self.expected("SCHEMA after SET");
             }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            let owner_name: Ident = self.parse_identifier()?;
+            AlterFunctionOperation::ChangeOwner {
+                new_owner_name: owner_name,
+            }
         } else {
-            return self.expected("SET after ALTER FUNCTION");
+            return self.expected("RENAME, SET or OWNER TO after ALTER FUNCTION");
         };
 
         Ok(Statement::AlterFunction {
@@ -3502,8 +3609,13 @@ impl Parser<'_> {
             } else {
                 return self.expected("SCHEMA after SET");
             }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            let owner_name: Ident = self.parse_identifier()?;
+            AlterConnectionOperation::ChangeOwner {
+                new_owner_name: owner_name,
+            }
         } else {
-            return self.expected("SET after ALTER CONNECTION");
+            return self.expected("SET or OWNER TO after ALTER CONNECTION");
         };
 
         Ok(Statement::AlterConnection {
@@ -3512,6 +3624,23 @@ impl Parser<'_> {
         })
     }
 
+    pub fn parse_alter_secret(&mut self) -> PResult<Statement> {
+        let secret_name = self.parse_object_name()?;
+        let operation = if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            let owner_name: Ident = self.parse_identifier()?;
+            AlterSecretOperation::ChangeOwner {
+                new_owner_name: owner_name,
+            }
+        } else {
+            return self.expected("OWNER TO after ALTER SECRET");
+        };
+
+        Ok(Statement::AlterSecret {
+            name: secret_name,
+            operation,
+        })
+    }
+
     pub fn parse_alter_system(&mut self) -> PResult<Statement> {
         self.expect_keyword(Keyword::SET)?;
         let param = self.parse_identifier()?;
@@ -3522,6 +3651,41 @@ impl Parser<'_> {
         Ok(Statement::AlterSystem { param, value })
     }
 
+    pub fn parse_alter_default_privileges(&mut self) -> PResult<Statement> {
+        self.expect_keywords(&[Keyword::IN, Keyword::SCHEMA])?;
+        let schemas = self.parse_comma_separated(Parser::parse_object_name)?;
+        let grant = if self.parse_keyword(Keyword::GRANT) {
+            true
+        } else {
+            self.expect_keyword(Keyword::REVOKE)?;
+            false
+        };
+        let privileges = self.parse_privileges()?;
+        self.expect_keyword(Keyword::ON)?;
+        let objects = if self.parse_keyword(Keyword::TABLES) {
+            DefaultPrivilegeObjects::Tables
+        } else if self.parse_keyword(Keyword::SOURCES) {
+            DefaultPrivilegeObjects::Sources
+        } else if self.parse_keyword(Keyword::SINKS) {
+            DefaultPrivilegeObjects::Sinks
+        } else {
+            return self.expected("TABLES, SOURCES or SINKS after ON");
+        };
+        self.expect_keyword(if grant { Keyword::TO } else { Keyword::FROM })?;
+        let grantees = self.parse_comma_separated(Parser::parse_identifier)?;
+        let with_grant_option =
+            grant && self.parse_keywords(&[Keyword::WITH, Keyword::GRANT, Keyword::OPTION]);
+
+        Ok(Statement::AlterDefaultPrivileges {
+            schemas,
+            grant,
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
+        })
+    }
+
     /// Parse a copy statement
     pub fn parse_copy(&mut self) -> PResult<Statement> {
         let table_name = self.parse_object_name()?;
@@ -3985,6 +4149,7 @@ impl Parser<'_> {
         let explain_key_words = [
             Keyword::VERBOSE,
             Keyword::TRACE,
+            Keyword::ESTIMATE,
             Keyword::TYPE,
             Keyword::LOGICAL,
             Keyword::PHYSICAL,
@@ -3996,6 +4161,7 @@ impl Parser<'_> {
             match keyword {
                 Keyword::VERBOSE => options.verbose = parser.parse_optional_boolean(true),
                 Keyword::TRACE => options.trace = parser.parse_optional_boolean(true),
+                Keyword::ESTIMATE => options.estimate = parser.parse_optional_boolean(true),
                 Keyword::TYPE => {
                     let explain_type = parser.expect_one_of_keywords(&[
                         Keyword::LOGICAL,
@@ -4465,6 +4631,16 @@ impl Parser<'_> {
                         return self.expected("from after indexes");
                     }
                 }
+                Keyword::INDEX => {
+                    if self.parse_keyword(Keyword::PROGRESS) {
+                        return Ok(Statement::ShowObjects {
+                            object: ShowObject::IndexProgress,
+                            filter: self.parse_show_statement_filter()?,
+                        });
+                    } else {
+                        return self.expected("PROGRESS after INDEX");
+                    }
+                }
                 Keyword::CLUSTER => {
                     return Ok(Statement::ShowObjects {
                         object: ShowObject::Cluster,
@@ -4483,6 +4659,12 @@ impl Parser<'_> {
                         filter: self.parse_show_statement_filter()?,
                     });
                 }
+                Keyword::BACKPRESSURE => {
+                    return Ok(Statement::ShowObjects {
+                        object: ShowObject::Backpressure,
+                        filter: self.parse_show_statement_filter()?,
+                    });
+                }
                 Keyword::TRANSACTION => {
                     self.expect_keywords(&[Keyword::ISOLATION, Keyword::LEVEL])?;
                     return Ok(Statement::ShowTransactionIsolationLevel);
@@ -4793,6 +4975,23 @@ impl Parser<'_> {
 
     /// Parse a GRANT statement.
     pub fn parse_grant(&mut self) -> PResult<Statement> {
+        // `GRANT role [, ...] TO user [, ...]` has no `ON` clause; disambiguate from a
+        // privilege grant by the leading token being a plain identifier rather than a
+        // privilege keyword.
+        if self.peek_role_name() {
+            let roles = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_keyword(Keyword::TO)?;
+            let grantees = self.parse_comma_separated(Parser::parse_identifier)?;
+            let granted_by = self
+                .parse_keywords(&[Keyword::GRANTED, Keyword::BY])
+                .then(|| self.parse_identifier().unwrap());
+            return Ok(Statement::GrantRole {
+                roles,
+                grantees,
+                granted_by,
+            });
+        }
+
         let (privileges, objects) = self.parse_grant_revoke_privileges_objects()?;
 
         self.expect_keyword(Keyword::TO)?;
@@ -4814,7 +5013,7 @@ impl Parser<'_> {
         })
     }
 
-    fn parse_grant_revoke_privileges_objects(&mut self) -> PResult<(Privileges, GrantObjects)> {
+    fn parse_privileges(&mut self) -> PResult<Privileges> {
         let privileges = if self.parse_keyword(Keyword::ALL) {
             Privileges::All {
                 with_privileges_keyword: self.parse_keyword(Keyword::PRIVILEGES),
@@ -4841,6 +5040,11 @@ impl Parser<'_> {
                     .collect(),
             )
         };
+        Ok(privileges)
+    }
+
+    fn parse_grant_revoke_privileges_objects(&mut self) -> PResult<(Privileges, GrantObjects)> {
+        let privileges = self.parse_privileges()?;
 
         self.expect_keyword(Keyword::ON)?;
 
@@ -4890,6 +5094,8 @@ impl Parser<'_> {
                 Keyword::SCHEMA,
                 Keyword::TABLE,
                 Keyword::SOURCE,
+                Keyword::SUBSCRIPTION,
+                Keyword::SECRET,
             ]);
             let objects = self.parse_comma_separated(Parser::parse_object_name);
             match object_type {
@@ -4897,6 +5103,8 @@ impl Parser<'_> {
                 Some(Keyword::SCHEMA) => GrantObjects::Schemas(objects?),
                 Some(Keyword::SEQUENCE) => GrantObjects::Sequences(objects?),
                 Some(Keyword::SOURCE) => GrantObjects::Sources(objects?),
+                Some(Keyword::SUBSCRIPTION) => GrantObjects::Subscriptions(objects?),
+                Some(Keyword::SECRET) => GrantObjects::Secrets(objects?),
                 Some(Keyword::TABLE) | None => GrantObjects::Tables(objects?),
                 _ => unreachable!(),
             }
@@ -4935,7 +5143,31 @@ impl Parser<'_> {
     }
 
     /// Parse a REVOKE statement
+    /// Whether the next token starts a role name in `GRANT`/`REVOKE`, i.e. a plain or
+    /// quoted identifier that is not a privilege keyword.
+    fn peek_role_name(&mut self) -> bool {
+        match self.peek_token().token {
+            Token::Word(w) => w.keyword == Keyword::NoKeyword,
+            _ => false,
+        }
+    }
+
     pub fn parse_revoke(&mut self) -> PResult<Statement> {
+        // `REVOKE role [, ...] FROM user [, ...]`, the counterpart of `GRANT role TO`.
+        if self.peek_role_name() {
+            let roles = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_keyword(Keyword::FROM)?;
+            let grantees = self.parse_comma_separated(Parser::parse_identifier)?;
+            let granted_by = self
+                .parse_keywords(&[Keyword::GRANTED, Keyword::BY])
+                .then(|| self.parse_identifier().unwrap());
+            return Ok(Statement::RevokeRole {
+                roles,
+                grantees,
+                granted_by,
+            });
+        }
+
         let revoke_grant_option =
             self.parse_keywords(&[Keyword::GRANT, Keyword::OPTION, Keyword::FOR]);
         let (privileges, objects) = self.parse_grant_revoke_privileges_objects()?;
@@ -5298,6 +5530,38 @@ impl Parser<'_> {
                 let object_name = self.parse_object_name()?;
                 (CommentObject::Table, object_name)
             }
+            Token::Word(w) if w.keyword == Keyword::SOURCE => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::Source, object_name)
+            }
+            Token::Word(w) if w.keyword == Keyword::SINK => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::Sink, object_name)
+            }
+            Token::Word(w) if w.keyword == Keyword::VIEW => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::View, object_name)
+            }
+            Token::Word(w) if w.keyword == Keyword::INDEX => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::Index, object_name)
+            }
+            Token::Word(w) if w.keyword == Keyword::FUNCTION => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::Function, object_name)
+            }
+            Token::Word(w) if w.keyword == Keyword::CONNECTION => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::Connection, object_name)
+            }
+            Token::Word(w) if w.keyword == Keyword::SECRET => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::Secret, object_name)
+            }
+            Token::Word(w) if w.keyword == Keyword::SCHEMA => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::Schema, object_name)
+            }
             _ => self.expected_at(checkpoint, "comment object_type")?,
         };
 
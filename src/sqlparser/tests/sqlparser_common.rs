@@ -1858,6 +1858,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: true,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
     run_explain_analyze(
@@ -1867,6 +1868,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: false,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
     run_explain_analyze(
@@ -1876,6 +1878,15 @@ fn parse_explain_analyze_with_simple_select() {
             trace: false,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
+        },
+    );
+    run_explain_analyze(
+        "EXPLAIN (ESTIMATE) SELECT sqrt(id) FROM foo",
+        false,
+        ExplainOptions {
+            estimate: true,
+            ..Default::default()
         },
     );
 }
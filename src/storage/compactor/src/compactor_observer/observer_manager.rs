@@ -16,7 +16,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use risingwave_common::system_param::local_manager::LocalSystemParamsManagerRef;
-use risingwave_common_service::ObserverState;
+use risingwave_common_service::{ObserverError, ObserverState};
 use risingwave_pb::catalog::Table;
 use risingwave_pb::meta::relation::RelationInfo;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
@@ -36,9 +36,9 @@ impl ObserverState for CompactorObserverNode {
         risingwave_pb::meta::SubscribeType::Compactor
     }
 
-    fn handle_notification(&mut self, resp: SubscribeResponse) {
+    fn handle_notification(&mut self, resp: SubscribeResponse) -> Result<(), ObserverError> {
         let Some(info) = resp.info.as_ref() else {
-            return;
+            return Ok(());
         };
 
         match info.to_owned() {
@@ -69,6 +69,7 @@ impl ObserverState for CompactorObserverNode {
                 panic!("error type notification");
             }
         }
+        Ok(())
     }
 
     fn handle_initialization_notification(&mut self, resp: SubscribeResponse) {
@@ -298,6 +298,7 @@ impl NotificationClient for ReplayNotificationClient {
     async fn subscribe(
         &self,
         subscribe_type: SubscribeType,
+        _last_acked_version: Option<u64>,
     ) -> std::result::Result<Self::Channel, ObserverError> {
         let (tx, rx) = unbounded_channel();
 
@@ -52,6 +52,7 @@ impl NotificationClient for MockNotificationClient {
     async fn subscribe(
         &self,
         subscribe_type: SubscribeType,
+        _last_acked_version: Option<u64>,
     ) -> Result<Self::Channel, ObserverError> {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
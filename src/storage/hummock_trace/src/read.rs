@@ -146,6 +146,7 @@ mod test {
             info: None,
             operation: 1,
             version: 100,
+            ..Default::default()
         });
         let op = Operation::MetaMessage(Box::new(resp));
         let expected = Record::new_local_none(123, op);
@@ -554,6 +554,12 @@ mod tests {
             initialized_at_cluster_version: None,
             created_at_cluster_version: None,
             cdc_table_id: None,
+            priority: 0,
+            annotations: Default::default(),
+            soft_dropped_at_ms: None,
+            storage_class_policy: None,
+            schema_change_policy: 0,
+            labels: Default::default(),
         }
     }
 
@@ -15,7 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use risingwave_common_service::ObserverState;
+use risingwave_common_service::{ObserverError, ObserverState};
 use risingwave_hummock_sdk::version::{HummockVersion, HummockVersionDelta};
 use risingwave_hummock_trace::TraceSpan;
 use risingwave_pb::catalog::Table;
@@ -42,9 +42,9 @@ impl ObserverState for HummockObserverNode {
         risingwave_pb::meta::SubscribeType::Hummock
     }
 
-    fn handle_notification(&mut self, resp: SubscribeResponse) {
+    fn handle_notification(&mut self, resp: SubscribeResponse) -> Result<(), ObserverError> {
         let Some(info) = resp.info.as_ref() else {
-            return;
+            return Ok(());
         };
 
         let _span: risingwave_hummock_trace::MayTraceSpan =
@@ -98,6 +98,7 @@ impl ObserverState for HummockObserverNode {
                 panic!("error type notification");
             }
         }
+        Ok(())
     }
 
     fn handle_initialization_notification(&mut self, resp: SubscribeResponse) {
@@ -33,16 +33,19 @@ use risingwave_common::util::tracing::TracingContext;
 use risingwave_common::util::value_encoding::{DatumFromProtoExt, DatumToProtoExt};
 use risingwave_connector::source::SplitImpl;
 use risingwave_expr::expr::{Expression, NonStrictExpression};
+use risingwave_pb::catalog::PbStreamSourceInfo;
 use risingwave_pb::data::PbEpoch;
 use risingwave_pb::expr::PbInputRef;
 use risingwave_pb::stream_plan::barrier::BarrierKind;
 use risingwave_pb::stream_plan::barrier_mutation::Mutation as PbMutation;
 use risingwave_pb::stream_plan::stream_message::StreamMessage;
 use risingwave_pb::stream_plan::update_mutation::{DispatcherUpdate, MergeUpdate};
+use risingwave_pb::stream_plan::connector_props_change_mutation::ConnectorProps;
 use risingwave_pb::stream_plan::{
-    BarrierMutation, CombinedMutation, Dispatchers, DropSubscriptionsMutation, PauseMutation,
-    PbAddMutation, PbBarrier, PbBarrierMutation, PbDispatcher, PbStreamMessage, PbUpdateMutation,
-    PbWatermark, ResumeMutation, SourceChangeSplitMutation, StopMutation, SubscriptionUpstreamInfo,
+    BarrierMutation, CombinedMutation, ConnectorPropsChangeMutation, Dispatchers,
+    DropSubscriptionsMutation, PauseMutation, PbAddMutation, PbBarrier, PbBarrierMutation,
+    PbDispatcher, PbStreamMessage, PbUpdateMutation, PbWatermark, ResumeMutation,
+    SourceChangeSplitMutation, SourceFormatChangeMutation, StopMutation, SubscriptionUpstreamInfo,
     ThrottleMutation,
 };
 use smallvec::SmallVec;
@@ -303,6 +306,8 @@ pub enum Mutation {
     Pause,
     Resume,
     Throttle(HashMap<ActorId, Option<u32>>),
+    ConnectorPropsChange(HashMap<u32, HashMap<String, String>>),
+    SourceFormatChange(HashMap<u32, PbStreamSourceInfo>),
     AddAndUpdate(AddMutation, UpdateMutation),
     DropSubscriptions {
         /// `subscriber` -> `upstream_mv_table_id`
@@ -504,6 +509,8 @@ impl Barrier {
             | Mutation::Resume
             | Mutation::SourceChangeSplit(_)
             | Mutation::Throttle(_)
+            | Mutation::ConnectorPropsChange(_)
+            | Mutation::SourceFormatChange(_)
             | Mutation::DropSubscriptions { .. } => false,
         }
     }
@@ -712,6 +719,26 @@ impl Mutation {
                     .collect(),
             }),
 
+            Mutation::ConnectorPropsChange(map) => {
+                PbMutation::ConnectorPropsChange(ConnectorPropsChangeMutation {
+                    object_props: map
+                        .iter()
+                        .map(|(object_id, props)| {
+                            (
+                                *object_id,
+                                ConnectorProps {
+                                    props: props.clone(),
+                                },
+                            )
+                        })
+                        .collect(),
+                })
+            }
+            Mutation::SourceFormatChange(map) => {
+                PbMutation::SourceFormatChange(SourceFormatChangeMutation {
+                    source_infos: map.clone(),
+                })
+            }
             Mutation::AddAndUpdate(add, update) => PbMutation::Combined(CombinedMutation {
                 mutations: vec![
                     BarrierMutation {
@@ -844,6 +871,18 @@ impl Mutation {
                     .map(|(actor_id, limit)| (*actor_id, limit.rate_limit))
                     .collect(),
             ),
+            PbMutation::ConnectorPropsChange(alter_connector_props) => {
+                Mutation::ConnectorPropsChange(
+                    alter_connector_props
+                        .object_props
+                        .iter()
+                        .map(|(object_id, props)| (*object_id, props.props.clone()))
+                        .collect(),
+                )
+            }
+            PbMutation::SourceFormatChange(format_change) => {
+                Mutation::SourceFormatChange(format_change.source_infos.clone())
+            }
             PbMutation::DropSubscriptions(drop) => Mutation::DropSubscriptions {
                 subscriptions_to_drop: drop
                     .info
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::mem;
+use std::num::NonZeroU32;
 
 use anyhow::anyhow;
 use futures::stream::select;
@@ -20,21 +21,25 @@ use futures::{FutureExt, TryFutureExt, TryStreamExt};
 use itertools::Itertools;
 use risingwave_common::array::stream_chunk::StreamChunkMut;
 use risingwave_common::array::Op;
+use risingwave_common::bitmap::Bitmap;
 use risingwave_common::catalog::{ColumnCatalog, Field};
 use risingwave_common::metrics::{LabelGuardedIntGauge, GLOBAL_ERROR_METRICS};
 use risingwave_common_estimate_size::collections::EstimatedVec;
 use risingwave_common_estimate_size::EstimateSize;
 use risingwave_connector::dispatch_sink;
-use risingwave_connector::sink::catalog::SinkType;
+use risingwave_connector::sink::catalog::{SinkId, SinkType};
 use risingwave_connector::sink::log_store::{
-    LogReader, LogReaderExt, LogStoreFactory, LogWriter, LogWriterExt,
+    LogReader, LogReaderExt, LogStoreFactory, LogStoreReadItem, LogStoreResult, LogWriter,
+    LogWriterExt, TruncateOffset,
 };
 use risingwave_connector::sink::{
     build_sink, LogSinker, Sink, SinkImpl, SinkParam, SinkWriterParam,
 };
 use thiserror_ext::AsReport;
+use tokio::sync::watch;
 
 use crate::common::compact_chunk::{merge_chunk_row, StreamChunkCompactor};
+use crate::executor::backfill::utils::{create_limiter, BackfillRateLimiter};
 use crate::executor::prelude::*;
 
 pub struct SinkExecutor<F: LogStoreFactory> {
@@ -51,6 +56,7 @@ pub struct SinkExecutor<F: LogStoreFactory> {
     need_advance_delete: bool,
     re_construct_with_sink_pk: bool,
     compact_chunk: bool,
+    rate_limit: Option<u32>,
 }
 
 // Drop all the DELETE messages in this chunk and convert UPDATE INSERT into INSERT.
@@ -92,6 +98,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
         log_store_factory: F,
         chunk_size: usize,
         input_data_types: Vec<DataType>,
+        rate_limit: Option<u32>,
     ) -> StreamExecutorResult<Self> {
         let sink = build_sink(sink_param.clone())
             .map_err(|e| StreamExecutorError::from((e, sink_param.sink_id.sink_id)))?;
@@ -162,6 +169,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
             need_advance_delete,
             re_construct_with_sink_pk,
             compact_chunk,
+            rate_limit = ?rate_limit,
             "Sink executor info"
         );
 
@@ -179,6 +187,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
             need_advance_delete,
             re_construct_with_sink_pk,
             compact_chunk,
+            rate_limit,
         })
     }
 
@@ -218,6 +227,10 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
         if self.sink.is_sink_into_table() {
             processed_input.boxed()
         } else {
+            // The write side observes the throttle mutation and forwards the new rate
+            // limit to the consume side, which owns the rate limiter.
+            let (rate_limit_tx, rate_limit_rx) = watch::channel(self.rate_limit);
+
             self.log_store_factory
                 .build()
                 .map(move |(log_reader, log_writer)| {
@@ -225,6 +238,8 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                         processed_input,
                         log_writer.monitored(self.sink_writer_param.sink_metrics.clone()),
                         actor_id,
+                        sink_id,
+                        rate_limit_tx,
                     );
 
                     let consume_log_stream_future = dispatch_sink!(self.sink, sink, {
@@ -235,6 +250,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                             self.sink_param,
                             self.sink_writer_param,
                             self.actor_context,
+                            rate_limit_rx,
                         )
                         .instrument_await(format!("consume_log (sink_id {sink_id})"))
                         .map_ok(|never| match never {}); // unify return type to `Message`
@@ -254,6 +270,8 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
         input: impl MessageStream,
         mut log_writer: impl LogWriter,
         actor_id: ActorId,
+        sink_id: SinkId,
+        rate_limit_tx: watch::Sender<Option<u32>>,
     ) {
         pin_mut!(input);
         let barrier = expect_first_barrier(&mut input).await?;
@@ -293,6 +311,35 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
                                 log_writer.resume()?;
                                 is_paused = false;
                             }
+                            Mutation::Throttle(actor_to_apply) => {
+                                if let Some(new_rate_limit) = actor_to_apply.get(&actor_id)
+                                    && *new_rate_limit != *rate_limit_tx.borrow()
+                                {
+                                    tracing::info!(
+                                        rate_limit = ?new_rate_limit,
+                                        actor_id,
+                                        sink_id = sink_id.sink_id,
+                                        "sink rate limit changed",
+                                    );
+                                    rate_limit_tx.send(*new_rate_limit).map_err(|_| {
+                                        anyhow!("fail to send sink rate limit update")
+                                    })?;
+                                }
+                            }
+                            Mutation::ConnectorPropsChange(object_props) => {
+                                if object_props.contains_key(&sink_id.sink_id) {
+                                    // The sink writer is built from the catalog when the actor
+                                    // starts and cannot swap its connector on the fly yet, so
+                                    // the updated properties take effect when the writer is
+                                    // rebuilt, e.g. on recovery or error retry.
+                                    tracing::info!(
+                                        actor_id,
+                                        sink_id = sink_id.sink_id,
+                                        "connector props change received, \
+                                         will take effect when the sink writer is rebuilt"
+                                    );
+                                }
+                            }
                             _ => (),
                         }
                     }
@@ -442,6 +489,7 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
         sink_param: SinkParam,
         mut sink_writer_param: SinkWriterParam,
         actor_context: ActorContextRef,
+        rate_limit_rx: watch::Receiver<Option<u32>>,
     ) -> StreamExecutorResult<!> {
         let metrics = sink_writer_param.sink_metrics.clone();
 
@@ -451,17 +499,20 @@ impl<F: LogStoreFactory> SinkExecutor<F> {
             .filter_map(|(idx, column)| (!column.is_hidden).then_some(idx))
             .collect_vec();
 
-        let mut log_reader = log_reader
-            .transform_chunk(move |chunk| {
-                if visible_columns.len() != columns.len() {
-                    // Do projection here because we may have columns that aren't visible to
-                    // the downstream.
-                    chunk.project(&visible_columns)
-                } else {
-                    chunk
-                }
-            })
-            .monitored(metrics);
+        let mut log_reader = RateLimitedLogReader::new(
+            log_reader
+                .transform_chunk(move |chunk| {
+                    if visible_columns.len() != columns.len() {
+                        // Do projection here because we may have columns that aren't visible to
+                        // the downstream.
+                        chunk.project(&visible_columns)
+                    } else {
+                        chunk
+                    }
+                })
+                .monitored(metrics),
+            rate_limit_rx,
+        );
 
         log_reader.init().await?;
 
@@ -509,6 +560,107 @@ impl<F: LogStoreFactory> Execute for SinkExecutor<F> {
     }
 }
 
+/// Limits the rate at which chunks are consumed from the log store, so that throttling
+/// a sink does not prevent the upstream from writing into the log store. For a sink
+/// with a kv log store the upstream thus keeps running at full speed; with the bounded
+/// in-memory log store the backpressure propagates to the upstream once the buffer is
+/// full.
+struct RateLimitedLogReader<R: LogReader> {
+    inner: R,
+    rate_limit_rx: watch::Receiver<Option<u32>>,
+    rate_limit: Option<u32>,
+    limiter: Option<BackfillRateLimiter>,
+    /// An item emitted by the inner reader but not yet released by the limiter. Kept
+    /// here so that a cancelled `next_item` future does not lose it.
+    pending: Option<(u64, LogStoreReadItem)>,
+}
+
+impl<R: LogReader> RateLimitedLogReader<R> {
+    fn new(inner: R, rate_limit_rx: watch::Receiver<Option<u32>>) -> Self {
+        let rate_limit = *rate_limit_rx.borrow();
+        Self {
+            inner,
+            rate_limit_rx,
+            rate_limit,
+            limiter: rate_limit.and_then(|limit| create_limiter(limit as _)),
+            pending: None,
+        }
+    }
+
+    /// Waits until the limiter allows `cardinality` more rows to be emitted. A rate
+    /// limit of 0 blocks until the rate limit is changed.
+    async fn wait_quota(&mut self, cardinality: usize) {
+        loop {
+            let rate_limit = *self.rate_limit_rx.borrow_and_update();
+            if rate_limit != self.rate_limit {
+                self.rate_limit = rate_limit;
+                self.limiter = rate_limit.and_then(|limit| create_limiter(limit as _));
+            }
+            match rate_limit {
+                None => return,
+                Some(0) => {
+                    // Block until the rate limit is changed. The channel is closed when
+                    // the write side of the executor exits, in which case there is no
+                    // further rate limit change to wait for.
+                    if self.rate_limit_rx.changed().await.is_err() {
+                        return;
+                    }
+                }
+                Some(limit) => {
+                    let limiter = self.limiter.as_ref().expect("limiter should exist");
+                    // A chunk may carry more rows than the limiter's burst size, so
+                    // acquire the quota in batches capped by it.
+                    let mut remaining = cardinality;
+                    while remaining > 0 {
+                        let n = remaining.min(limit as usize);
+                        remaining -= n;
+                        let n = NonZeroU32::new(n as u32).unwrap();
+                        // `InsufficientCapacity` cannot happen as `n` is capped by the
+                        // burst size.
+                        limiter.until_n_ready(n).await.unwrap();
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl<R: LogReader> LogReader for RateLimitedLogReader<R> {
+    async fn init(&mut self) -> LogStoreResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next_item(&mut self) -> LogStoreResult<(u64, LogStoreReadItem)> {
+        let (epoch, item) = match self.pending.take() {
+            Some(pending) => pending,
+            None => self.inner.next_item().await?,
+        };
+        let cardinality = match &item {
+            LogStoreReadItem::StreamChunk { chunk, .. } => chunk.cardinality(),
+            LogStoreReadItem::Barrier { .. } | LogStoreReadItem::UpdateVnodeBitmap(_) => 0,
+        };
+        if cardinality == 0 {
+            return Ok((epoch, item));
+        }
+        // Stash the item while waiting so that cancellation does not lose it.
+        self.pending = Some((epoch, item));
+        self.wait_quota(cardinality).await;
+        Ok(self.pending.take().unwrap())
+    }
+
+    fn truncate(&mut self, offset: TruncateOffset) -> LogStoreResult<()> {
+        self.inner.truncate(offset)
+    }
+
+    async fn rewind(&mut self) -> LogStoreResult<(bool, Option<Bitmap>)> {
+        // The items emitted since the truncate offset will be emitted again, including
+        // the stashed one.
+        self.pending = None;
+        self.inner.rewind().await
+    }
+}
+
 #[cfg(test)]
 mod test {
     use risingwave_common::catalog::{ColumnDesc, ColumnId};
@@ -607,6 +759,7 @@ mod test {
             BoundedInMemLogStoreFactory::new(1),
             1024,
             vec![DataType::Int32, DataType::Int32, DataType::Int32],
+            None,
         )
         .await
         .unwrap();
@@ -736,6 +889,7 @@ mod test {
             BoundedInMemLogStoreFactory::new(1),
             1024,
             vec![DataType::Int64, DataType::Int64, DataType::Int64],
+            None,
         )
         .await
         .unwrap();
@@ -838,6 +992,7 @@ mod test {
             BoundedInMemLogStoreFactory::new(1),
             1024,
             vec![DataType::Int64, DataType::Int64],
+            None,
         )
         .await
         .unwrap();
@@ -430,9 +430,11 @@ impl<S: StateStore> SourceExecutor<S> {
 
         let mut core = self.stream_source_core.unwrap();
 
-        // Build source description from the builder.
-        let source_desc_builder: SourceDescBuilder = core.source_desc_builder.take().unwrap();
-        let source_desc = source_desc_builder
+        // Build source description from the builder. The builder is kept around so that the
+        // description can be rebuilt when the format/encode of the source is altered.
+        let mut source_desc_builder: SourceDescBuilder = core.source_desc_builder.take().unwrap();
+        let mut source_desc = source_desc_builder
+            .clone()
             .build()
             .map_err(StreamExecutorError::connector_error)?;
 
@@ -601,6 +603,53 @@ impl<S: StateStore> SourceExecutor<S> {
                                         .await?;
                                 }
                             }
+                            Mutation::ConnectorPropsChange(object_props) => {
+                                let source_id = self
+                                    .stream_source_core
+                                    .as_ref()
+                                    .unwrap()
+                                    .source_id
+                                    .table_id;
+                                if let Some(new_props) = object_props.get(&source_id) {
+                                    tracing::info!(
+                                        actor_id = self.actor_ctx.id,
+                                        source_id,
+                                        "connector props change received"
+                                    );
+                                    source_desc
+                                        .update_reader(new_props.clone())
+                                        .map_err(StreamExecutorError::connector_error)?;
+                                    // recreate the reader from latest_split_info with the
+                                    // new connector config
+                                    self.rebuild_stream_reader(&source_desc, &mut stream)
+                                        .await?;
+                                }
+                            }
+                            Mutation::SourceFormatChange(source_infos) => {
+                                let source_id = self
+                                    .stream_source_core
+                                    .as_ref()
+                                    .unwrap()
+                                    .source_id
+                                    .table_id;
+                                if let Some(new_source_info) = source_infos.get(&source_id) {
+                                    tracing::info!(
+                                        actor_id = self.actor_ctx.id,
+                                        source_id,
+                                        "source format change received"
+                                    );
+                                    source_desc_builder
+                                        .update_source_info(new_source_info.clone());
+                                    source_desc = source_desc_builder
+                                        .clone()
+                                        .build()
+                                        .map_err(StreamExecutorError::connector_error)?;
+                                    // recreate the reader from latest_split_info with the
+                                    // new parser config
+                                    self.rebuild_stream_reader(&source_desc, &mut stream)
+                                        .await?;
+                                }
+                            }
                             _ => {}
                         }
                     }
@@ -22,6 +22,7 @@ use risingwave_pb::stream_plan::{ArrangeNode, MaterializeNode};
 
 use super::*;
 use crate::executor::MaterializeExecutor;
+use crate::task::sample_changelog;
 
 pub struct MaterializeExecutorBuilder;
 
@@ -73,6 +74,9 @@ impl ExecutorBuilder for MaterializeExecutorBuilder {
         } else {
             new_executor!(BasicSerde)
         };
+        // Allow the change records of the table or materialized view to be sampled
+        // on demand for debugging.
+        let exec = sample_changelog(params.env.changelog_sampler_registry(), table.id, exec);
 
         Ok((params.info, exec).into())
     }
@@ -35,6 +35,7 @@ use crate::common::log_store_impl::kv_log_store::{
     KvLogStoreFactory, KvLogStoreMetrics, KvLogStorePkInfo, KV_LOG_STORE_V2_INFO,
 };
 use crate::executor::{SinkExecutor, StreamExecutorError};
+use crate::task::sample_changelog;
 use crate::telemetry::report_event;
 
 pub struct SinkExecutorBuilder;
@@ -256,6 +257,7 @@ impl ExecutorBuilder for SinkExecutorBuilder {
                     factory,
                     chunk_size,
                     input_data_types,
+                    sink_desc.rate_limit,
                 )
                 .await?
                 .boxed()
@@ -293,11 +295,19 @@ impl ExecutorBuilder for SinkExecutorBuilder {
                     factory,
                     chunk_size,
                     input_data_types,
+                    sink_desc.rate_limit,
                 )
                 .await?
                 .boxed()
             }
         };
+        // Allow the change records fed into the sink to be sampled on demand for
+        // debugging.
+        let exec = sample_changelog(
+            params.env.changelog_sampler_registry(),
+            sink_id.sink_id,
+            exec,
+        );
 
         Ok((params.info, exec).into())
     }
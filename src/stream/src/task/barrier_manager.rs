@@ -24,7 +24,7 @@ use futures::StreamExt;
 use itertools::Itertools;
 use risingwave_common::error::tonic::extra::Score;
 use risingwave_pb::stream_service::barrier_complete_response::{
-    GroupedSstableInfo, PbCreateMviewProgress,
+    GroupedSstableInfo, PbActorBackpressureStats, PbCreateMviewProgress,
 };
 use risingwave_rpc_client::error::{ToTonicStatus, TonicStatusWrapper};
 use thiserror_ext::AsReport;
@@ -78,6 +78,10 @@ pub struct BarrierCompleteResult {
 
     /// The updated creation progress of materialized view after this barrier.
     pub create_mview_progress: Vec<PbCreateMviewProgress>,
+
+    /// Per-actor barrier collect latency, reported to the meta node for backpressure
+    /// monitoring.
+    pub actor_backpressure: Vec<PbActorBackpressureStats>,
 }
 
 pub(super) struct ControlStreamHandle {
@@ -466,6 +470,7 @@ impl LocalBarrierWorker {
 
         let BarrierCompleteResult {
             create_mview_progress,
+            actor_backpressure,
             sync_result,
         } = result;
 
@@ -488,6 +493,7 @@ impl LocalBarrierWorker {
                         epoch,
                         status: None,
                         create_mview_progress,
+                        actor_backpressure,
                         synced_sstables: synced_sstables
                             .into_iter()
                             .map(
@@ -20,6 +20,7 @@ use std::future::{pending, poll_fn, Future};
 use std::mem::replace;
 use std::sync::Arc;
 use std::task::{ready, Context, Poll};
+use std::time::Instant;
 
 use anyhow::anyhow;
 use await_tree::InstrumentAwait;
@@ -32,6 +33,7 @@ use risingwave_common::must_match;
 use risingwave_common::util::epoch::EpochPair;
 use risingwave_hummock_sdk::SyncResult;
 use risingwave_pb::stream_plan::barrier::BarrierKind;
+use risingwave_pb::stream_service::barrier_complete_response::PbActorBackpressureStats;
 use risingwave_storage::{dispatch_state_store, StateStore, StateStoreImpl};
 use thiserror_ext::AsReport;
 use tokio::sync::mpsc;
@@ -51,6 +53,13 @@ struct IssuedState {
 
     pub barrier_inflight_latency: HistogramTimer,
 
+    /// When the barrier was issued on this worker, for per-actor collect latency stats.
+    pub issued_at: Instant,
+
+    /// Per-actor collect latency of the actors collected so far, reported to the meta
+    /// node in `BarrierCompleteResponse` for backpressure monitoring.
+    pub actor_backpressure: Vec<PbActorBackpressureStats>,
+
     /// Only be `Some(_)` when `kind` is `Checkpoint`
     pub table_ids: Option<HashSet<TableId>>,
 
@@ -94,7 +103,9 @@ mod await_epoch_completed_future {
     use futures::future::BoxFuture;
     use futures::FutureExt;
     use risingwave_hummock_sdk::SyncResult;
-    use risingwave_pb::stream_service::barrier_complete_response::PbCreateMviewProgress;
+    use risingwave_pb::stream_service::barrier_complete_response::{
+        PbActorBackpressureStats, PbCreateMviewProgress,
+    };
 
     use crate::error::StreamResult;
     use crate::executor::Barrier;
@@ -108,6 +119,7 @@ mod await_epoch_completed_future {
         barrier: Barrier,
         barrier_await_tree_reg: Option<&await_tree::Registry>,
         create_mview_progress: Vec<PbCreateMviewProgress>,
+        actor_backpressure: Vec<PbActorBackpressureStats>,
     ) -> AwaitEpochCompletedFuture {
         let prev_epoch = barrier.epoch.prev;
         let future = async move {
@@ -124,6 +136,7 @@ mod await_epoch_completed_future {
                 result.map(|sync_result| BarrierCompleteResult {
                     sync_result,
                     create_mview_progress,
+                    actor_backpressure,
                 }),
             )
         });
@@ -725,14 +738,15 @@ impl PartialGraphManagedBarrierState {
                 ManagedBarrierStateInner::AllCollected,
             );
 
-            let (kind, table_ids) = must_match!(prev_state, ManagedBarrierStateInner::Issued(IssuedState {
+            let (kind, table_ids, actor_backpressure) = must_match!(prev_state, ManagedBarrierStateInner::Issued(IssuedState {
                 barrier_inflight_latency: timer,
                 kind,
                 table_ids,
+                actor_backpressure,
                 ..
             }) => {
                 timer.observe_duration();
-                (kind, table_ids)
+                (kind, table_ids, actor_backpressure)
             });
 
             let create_mview_progress = self
@@ -781,6 +795,7 @@ impl PartialGraphManagedBarrierState {
                     barrier,
                     self.barrier_await_tree_reg.as_ref(),
                     create_mview_progress,
+                    actor_backpressure,
                 )
             });
         }
@@ -809,6 +824,8 @@ impl PartialGraphManagedBarrierState {
                 inner:
                     ManagedBarrierStateInner::Issued(IssuedState {
                         ref mut remaining_actors,
+                        issued_at,
+                        ref mut actor_backpressure,
                         ..
                     }),
                 ..
@@ -820,6 +837,10 @@ impl PartialGraphManagedBarrierState {
                     actor_id, epoch.curr
                 );
                 assert_eq!(barrier.epoch.curr, epoch.curr);
+                actor_backpressure.push(PbActorBackpressureStats {
+                    actor_id,
+                    collect_latency_ms: issued_at.elapsed().as_millis() as u64,
+                });
                 self.may_have_collected_all(epoch.prev);
             }
             Some(BarrierState { inner, .. }) => {
@@ -906,6 +927,8 @@ impl PartialGraphManagedBarrierState {
                     remaining_actors: BTreeSet::from_iter(actor_ids_to_collect),
                     mutation: barrier.mutation.clone(),
                     barrier_inflight_latency: timer,
+                    issued_at: Instant::now(),
+                    actor_backpressure: Vec::new(),
                     kind: barrier.kind,
                     table_ids,
                 }),
@@ -0,0 +1,204 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use futures::StreamExt;
+use futures_async_stream::{for_await, try_stream};
+use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::row::Row;
+use risingwave_pb::stream_service::sample_changelog_response::Record;
+use tokio::sync::oneshot;
+
+use crate::executor::error::StreamExecutorError;
+use crate::executor::{BoxedMessageStream, Execute, Message};
+
+/// In-flight changelog sampling requests on this worker, keyed by the relation
+/// (table or sink) id. Requests are registered by the `SampleChangelog` RPC and
+/// served by the materialize or sink executors hosting the relation.
+#[derive(Debug, Default)]
+pub struct ChangelogSamplerRegistry {
+    samplers: Mutex<HashMap<u32, Arc<ChangelogSampler>>>,
+}
+
+pub type ChangelogSamplerRegistryRef = Arc<ChangelogSamplerRegistry>;
+
+impl ChangelogSamplerRegistry {
+    /// Registers a sampling request for the relation, replacing any previous one.
+    /// The returned receiver resolves once `max_records` records are captured.
+    pub fn register(
+        &self,
+        relation_id: u32,
+        max_records: usize,
+        filter: String,
+    ) -> oneshot::Receiver<Vec<Record>> {
+        let (tx, rx) = oneshot::channel();
+        let sampler = Arc::new(ChangelogSampler {
+            max_records: max_records.max(1),
+            filter,
+            state: Mutex::new(SamplerState {
+                records: vec![],
+                tx: Some(tx),
+            }),
+        });
+        self.samplers.lock().unwrap().insert(relation_id, sampler);
+        rx
+    }
+
+    /// Removes the sampling request of the relation and returns the records captured
+    /// so far. Called by the requester when it stops waiting, so that a request whose
+    /// relation sees little traffic does not linger forever.
+    pub fn finish(&self, relation_id: u32) -> Vec<Record> {
+        self.samplers
+            .lock()
+            .unwrap()
+            .remove(&relation_id)
+            .map(|sampler| sampler.state.lock().unwrap().records.clone())
+            .unwrap_or_default()
+    }
+
+    fn get(&self, relation_id: u32) -> Option<Arc<ChangelogSampler>> {
+        self.samplers.lock().unwrap().get(&relation_id).cloned()
+    }
+}
+
+/// A single sampling request. Parallel actors of the sampled relation share one
+/// sampler, so the captured records may interleave across parallelisms.
+#[derive(Debug)]
+struct ChangelogSampler {
+    max_records: usize,
+    filter: String,
+    state: Mutex<SamplerState>,
+}
+
+#[derive(Debug)]
+struct SamplerState {
+    records: Vec<Record>,
+    tx: Option<oneshot::Sender<Vec<Record>>>,
+}
+
+impl ChangelogSampler {
+    /// Captures the records of the chunk. Returns `true` once `max_records` records
+    /// have been captured, i.e. the sampler is exhausted.
+    fn sample_chunk(&self, chunk: &StreamChunk) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.tx.is_none() {
+            return true;
+        }
+        let mut pending_update: Option<String> = None;
+        for (op, row) in chunk.rows() {
+            let value = row.display().to_string();
+            let record = match op {
+                Op::Insert => Record {
+                    op: "insert".into(),
+                    old_value: String::new(),
+                    new_value: value,
+                },
+                Op::Delete => Record {
+                    op: "delete".into(),
+                    old_value: value,
+                    new_value: String::new(),
+                },
+                Op::UpdateDelete => {
+                    pending_update = Some(value);
+                    continue;
+                }
+                Op::UpdateInsert => Record {
+                    op: "update".into(),
+                    old_value: pending_update.take().unwrap_or_default(),
+                    new_value: value,
+                },
+            };
+            if !self.filter.is_empty()
+                && !record.old_value.contains(&self.filter)
+                && !record.new_value.contains(&self.filter)
+            {
+                continue;
+            }
+            state.records.push(record);
+            if state.records.len() >= self.max_records {
+                let records = state.records.clone();
+                let _ = state.tx.take().unwrap().send(records);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Wraps the output of a materialize or sink executor so that its change records can
+/// be sampled on demand. Capture starts at a barrier boundary so that the records
+/// align with an epoch.
+pub fn sample_changelog(
+    registry: ChangelogSamplerRegistryRef,
+    relation_id: u32,
+    inner: Box<dyn Execute>,
+) -> Box<dyn Execute> {
+    Box::new(SampledExecute {
+        registry,
+        relation_id,
+        inner,
+    })
+}
+
+struct SampledExecute {
+    registry: ChangelogSamplerRegistryRef,
+    relation_id: u32,
+    inner: Box<dyn Execute>,
+}
+
+impl Execute for SampledExecute {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        sample_stream(self.registry, self.relation_id, self.inner.execute()).boxed()
+    }
+
+    fn execute_with_epoch(self: Box<Self>, epoch: u64) -> BoxedMessageStream {
+        sample_stream(
+            self.registry,
+            self.relation_id,
+            self.inner.execute_with_epoch(epoch),
+        )
+        .boxed()
+    }
+}
+
+#[try_stream(ok = Message, error = StreamExecutorError)]
+async fn sample_stream(
+    registry: ChangelogSamplerRegistryRef,
+    relation_id: u32,
+    input: BoxedMessageStream,
+) {
+    let mut active: Option<Arc<ChangelogSampler>> = None;
+    #[for_await]
+    for msg in input {
+        let msg = msg?;
+        match &msg {
+            Message::Barrier(_) => {
+                // Check for a pending sampling request at every barrier so that
+                // capture starts at an epoch boundary.
+                active = registry.get(relation_id);
+            }
+            Message::Chunk(chunk) => {
+                if let Some(sampler) = &active
+                    && sampler.sample_chunk(chunk)
+                {
+                    active = None;
+                }
+            }
+            Message::Watermark(_) => {}
+        }
+        yield msg;
+    }
+}
@@ -23,6 +23,8 @@ use risingwave_dml::dml_manager::DmlManagerRef;
 use risingwave_rpc_client::{ComputeClientPoolRef, MetaClient};
 use risingwave_storage::StateStoreImpl;
 
+use crate::task::{ChangelogSamplerRegistry, ChangelogSamplerRegistryRef};
+
 pub(crate) type WorkerNodeId = u32;
 
 /// The global environment for task execution.
@@ -58,6 +60,9 @@ pub struct StreamEnvironment {
 
     /// Compute client pool for streaming gRPC exchange.
     client_pool: ComputeClientPoolRef,
+
+    /// In-flight changelog sampling requests on this worker.
+    changelog_sampler_registry: ChangelogSamplerRegistryRef,
 }
 
 impl StreamEnvironment {
@@ -84,6 +89,7 @@ impl StreamEnvironment {
             total_mem_val: Arc::new(TrAdder::new()),
             meta_client: Some(meta_client),
             client_pool,
+            changelog_sampler_registry: Arc::new(ChangelogSamplerRegistry::default()),
         }
     }
 
@@ -107,6 +113,7 @@ impl StreamEnvironment {
             total_mem_val: Arc::new(TrAdder::new()),
             meta_client: None,
             client_pool: Arc::new(ComputeClientPool::for_test()),
+            changelog_sampler_registry: Arc::new(ChangelogSamplerRegistry::default()),
         }
     }
 
@@ -149,4 +156,8 @@ impl StreamEnvironment {
     pub fn client_pool(&self) -> ComputeClientPoolRef {
         self.client_pool.clone()
     }
+
+    pub fn changelog_sampler_registry(&self) -> ChangelogSamplerRegistryRef {
+        self.changelog_sampler_registry.clone()
+    }
 }
@@ -25,10 +25,12 @@ use crate::error::StreamResult;
 use crate::executor::exchange::permit::{self, Receiver, Sender};
 
 mod barrier_manager;
+mod changelog_sampler;
 mod env;
 mod stream_manager;
 
 pub use barrier_manager::*;
+pub use changelog_sampler::*;
 pub use env::*;
 pub use stream_manager::*;
 
@@ -58,6 +58,7 @@ pub enum StatementType {
     CREATE_FUNCTION,
     CREATE_CONNECTION,
     CREATE_SECRET,
+    CREATE_CHECKPOINT,
     COMMENT,
     DECLARE_CURSOR,
     DESCRIBE,
@@ -88,7 +89,9 @@ pub enum StatementType {
     ALTER_SOURCE,
     ALTER_FUNCTION,
     ALTER_CONNECTION,
+    ALTER_SECRET,
     ALTER_SYSTEM,
+    ALTER_DEFAULT_PRIVILEGES,
     REVOKE_PRIVILEGE,
     // Introduce ORDER_BY statement type cuz Calcite unvalidated AST has SqlKind.ORDER_BY. Note
     // that Statement Type is not designed to be one to one mapping with SqlKind.
@@ -291,6 +294,7 @@ impl StatementType {
             Statement::Rollback { .. } => Ok(StatementType::ROLLBACK),
             Statement::Grant { .. } => Ok(StatementType::GRANT_PRIVILEGE),
             Statement::Revoke { .. } => Ok(StatementType::REVOKE_PRIVILEGE),
+            Statement::AlterDefaultPrivileges { .. } => Ok(StatementType::ALTER_DEFAULT_PRIVILEGES),
             Statement::Describe { .. } => Ok(StatementType::DESCRIBE),
             Statement::ShowCreateObject { .. } | Statement::ShowObjects { .. } => {
                 Ok(StatementType::SHOW_COMMAND)
@@ -320,6 +324,7 @@ impl StatementType {
             Statement::FetchCursor { .. } => Ok(StatementType::FETCH_CURSOR),
             Statement::CloseCursor { .. } => Ok(StatementType::CLOSE_CURSOR),
             Statement::Flush => Ok(StatementType::FLUSH),
+            Statement::CreateCheckpoint { .. } => Ok(StatementType::CREATE_CHECKPOINT),
             Statement::Wait => Ok(StatementType::WAIT),
             _ => Err("unsupported statement type".to_string()),
         }